@@ -0,0 +1,107 @@
+//! Similarity and distance kernels between two vectors of the same length.
+//!
+//! The products are accumulated in `LANES` independent sums, so that the
+//! loops are vectorized without reordering the floating point additions of a
+//! single accumulator, and compiled for the SIMD instruction sets of the CPU.
+use multiversion::multiversion;
+
+// 8 f64's are 512 bits, the widest SIMD registers
+const LANES: usize = 8;
+
+/// Compute `sum(a[i] * b[i])`.
+///
+/// # Panics
+/// Panics if `a` and `b` have a different length.
+#[multiversion(targets = "simd")]
+pub fn dot(a: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(a.len(), b.len());
+    let mut acc = [0.0; LANES];
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(a, b)| a * b)
+        .sum::<f64>();
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for ((acc, a), b) in acc.iter_mut().zip(a).zip(b) {
+            *acc += a * b;
+        }
+    }
+    acc.iter().sum::<f64>() + tail
+}
+
+/// Compute `sum((a[i] - b[i])^2)`.
+///
+/// # Panics
+/// Panics if `a` and `b` have a different length.
+#[multiversion(targets = "simd")]
+pub fn squared_l2_distance(a: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(a.len(), b.len());
+    let mut acc = [0.0; LANES];
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>();
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for ((acc, a), b) in acc.iter_mut().zip(a).zip(b) {
+            *acc += (a - b) * (a - b);
+        }
+    }
+    acc.iter().sum::<f64>() + tail
+}
+
+/// Compute the cosine of the angle between `a` and `b`, i.e. their dot
+/// product divided by the product of their norms. This is NaN if one of the
+/// norms is zero.
+///
+/// # Panics
+/// Panics if `a` and `b` have a different length.
+#[multiversion(targets = "simd")]
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(a.len(), b.len());
+    let mut dot = [0.0; LANES];
+    let mut norm_a = [0.0; LANES];
+    let mut norm_b = [0.0; LANES];
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let (mut dot_tail, mut norm_a_tail, mut norm_b_tail) = (0.0, 0.0, 0.0);
+    for (a, b) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        dot_tail += a * b;
+        norm_a_tail += a * a;
+        norm_b_tail += b * b;
+    }
+    for (a, b) in a_chunks.zip(b_chunks) {
+        let accs = dot.iter_mut().zip(&mut norm_a).zip(&mut norm_b);
+        for (((dot, norm_a), norm_b), (a, b)) in accs.zip(a.iter().zip(b)) {
+            *dot += a * b;
+            *norm_a += a * a;
+            *norm_b += b * b;
+        }
+    }
+    let dot = dot.iter().sum::<f64>() + dot_tail;
+    let norm_a = norm_a.iter().sum::<f64>() + norm_a_tail;
+    let norm_b = norm_b.iter().sum::<f64>() + norm_b_tail;
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_distance_kernels() {
+        // longer than `LANES`, so both the chunks and the remainder are used
+        let a = (0..11).map(|v| v as f64).collect::<Vec<_>>();
+        let b = (0..11).map(|v| (10 - v) as f64).collect::<Vec<_>>();
+
+        let expected_dot = a.iter().zip(&b).map(|(a, b)| a * b).sum::<f64>();
+        assert_eq!(dot(&a, &b), expected_dot);
+        let expected_l2 = a.iter().zip(&b).map(|(a, b)| (a - b).powi(2)).sum::<f64>();
+        assert_eq!(squared_l2_distance(&a, &b), expected_l2);
+        assert!((cosine_similarity(&a, &a) - 1.0).abs() < 1e-12);
+        assert!(cosine_similarity(&a, &[0.0; 11]).is_nan());
+    }
+}
@@ -5,6 +5,7 @@ use arrow::bitmap::utils::BitChunks;
 #[cfg(feature = "simd")]
 pub mod agg_mean;
 pub mod concatenate;
+pub mod distance;
 pub mod ewm;
 pub mod float;
 pub mod list;
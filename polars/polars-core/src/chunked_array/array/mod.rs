@@ -0,0 +1,232 @@
+//! Special fixed-size list utility methods
+use std::borrow::Cow;
+
+use arrow::compute::take::take;
+use arrow::offset::OffsetsBuffer;
+
+use crate::prelude::*;
+use crate::series::IsSorted;
+use crate::utils::align_chunks_binary;
+
+impl ArrayChunked {
+    /// Get the number of values in every element.
+    pub fn width(&self) -> usize {
+        match self.dtype() {
+            DataType::Array(_, width) => *width,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the inner data type of the fixed-size list.
+    pub fn inner_dtype(&self) -> DataType {
+        match self.dtype() {
+            DataType::Array(dt, _) => *dt.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the inner values as `Series`. Element `i` holds the values
+    /// `i * width..(i + 1) * width`.
+    pub fn get_inner(&self) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        // safety: inner dtype is passed correctly
+        unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                self.name(),
+                vec![arr.values().clone()],
+                &self.inner_dtype(),
+            )
+        }
+    }
+
+    /// Append in place. This is done by adding the chunks of `other` to this [`ArrayChunked`].
+    pub fn append(&mut self, other: &Self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.width() == other.width(),
+            SchemaMismatch: "cannot append array of width {} to array of width {}",
+            other.width(), self.width()
+        );
+        let dtype = merge_dtypes(self.dtype(), other.dtype())?;
+        self.field = Arc::new(Field::new(self.name(), dtype));
+
+        let len = self.len();
+        self.length += other.length;
+        crate::chunked_array::ops::append::new_chunks(&mut self.chunks, &other.chunks, len);
+        self.set_sorted_flag(IsSorted::Not);
+        Ok(())
+    }
+
+    /// Gather the elements at `indices`, a null index produces a null element.
+    ///
+    /// # Safety
+    /// The indices must be in bounds.
+    pub unsafe fn take_unchecked_idx(&self, indices: &IdxCa) -> Self {
+        let ca = self.rechunk();
+        let indices = indices.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let idx = indices.downcast_iter().next().unwrap();
+        let out = take(arr, idx).unwrap();
+        self.copy_with_chunks(vec![out], false, false)
+    }
+
+    /// Gather the elements at `indices`, a null index produces a null element.
+    pub fn take_idx(&self, indices: &IdxCa) -> PolarsResult<Self> {
+        if let Some(max) = indices.max() {
+            polars_ensure!((max as usize) < self.len(), oob = max, self.len());
+        }
+        Ok(unsafe { self.take_unchecked_idx(indices) })
+    }
+
+    pub fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Self> {
+        // broadcast
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(self.slice(0, 0)),
+            };
+        }
+        polars_ensure!(
+            self.len() == filter.len(),
+            ShapeMismatch: "filter's length: {} differs from that of the series: {}",
+            filter.len(), self.len()
+        );
+        let (left, filter) = align_chunks_binary(self, filter);
+        let chunks = left
+            .downcast_iter()
+            .zip(filter.downcast_iter())
+            .map(|(left, mask)| arrow::compute::filter::filter(left, mask).unwrap())
+            .collect::<Vec<_>>();
+        Ok(self.copy_with_chunks(chunks, true, true))
+    }
+
+    pub fn reverse(&self) -> Self {
+        let len = self.len() as IdxSize;
+        let idx: NoNull<IdxCa> = (0..len).rev().collect();
+        unsafe { self.take_unchecked_idx(&idx.into_inner()) }
+    }
+
+    pub fn new_from_index(&self, index: usize, length: usize) -> Self {
+        let idx: NoNull<IdxCa> = std::iter::repeat(index as IdxSize).take(length).collect();
+        let mut out = self.take_idx(&idx.into_inner()).unwrap();
+        out.rename(self.name());
+        out
+    }
+
+    pub fn shift(&self, periods: i64) -> Self {
+        let len = self.len() as i64;
+        let idx: IdxCa = (0..len)
+            .map(|i| {
+                let src = i - periods;
+                (src >= 0 && src < len).then_some(src as IdxSize)
+            })
+            .collect();
+        unsafe { self.take_unchecked_idx(&idx) }
+    }
+
+    pub fn take_every(&self, n: usize) -> Self {
+        let idx: NoNull<IdxCa> = (0..self.len() as IdxSize).step_by(n).collect();
+        unsafe { self.take_unchecked_idx(&idx.into_inner()) }
+    }
+
+    /// Cast to a list, this materializes the offsets.
+    pub fn to_list(&self) -> ListChunked {
+        let ca = self.rechunk();
+        let width = self.width() as i64;
+        let chunks = ca
+            .downcast_iter()
+            .map(|arr| {
+                let offsets = (0..=arr.len() as i64)
+                    .map(|i| i * width)
+                    .collect::<Vec<_>>();
+                // safety: monotonically increasing
+                let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+                let data_type =
+                    ListArray::<i64>::default_datatype(arr.values().data_type().clone());
+                Box::new(ListArray::<i64>::new(
+                    data_type,
+                    offsets,
+                    arr.values().clone(),
+                    arr.validity().cloned(),
+                )) as ArrayRef
+            })
+            .collect();
+        // safety: the inner dtype is unchanged
+        unsafe {
+            ListChunked::from_chunks_and_dtype_unchecked(
+                self.name(),
+                chunks,
+                DataType::List(Box::new(self.inner_dtype())),
+            )
+        }
+    }
+}
+
+impl ListChunked {
+    /// Cast to a fixed-size list of `width`. Every non-null list must
+    /// have exactly `width` values.
+    pub fn to_array(&self, width: usize) -> PolarsResult<ArrayChunked> {
+        polars_ensure!(width > 0, ComputeError: "array width should be positive");
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let offsets = arr.offsets().as_slice();
+
+        let mut fast_path = true;
+        for (i, w) in offsets.windows(2).enumerate() {
+            let len = (w[1] - w[0]) as usize;
+            if arr.is_valid(i) {
+                polars_ensure!(
+                    len == width,
+                    ComputeError: "cannot cast list of length {} to array of width {}", len, width
+                );
+            }
+            fast_path &= len == width;
+        }
+
+        let values = if fast_path && offsets[0] == 0 && arr.values().len() == arr.len() * width {
+            Cow::Borrowed(arr.values())
+        } else {
+            // gather the values, null lists get `width` null values
+            let inner = self.get_inner();
+            let idx: IdxCa = offsets
+                .windows(2)
+                .enumerate()
+                .flat_map(|(i, w)| {
+                    let valid = arr.is_valid(i);
+                    (0..width as i64).map(move |j| valid.then_some((w[0] + j) as IdxSize))
+                })
+                .collect();
+            let values = unsafe { inner.take_unchecked(&idx)? }.rechunk();
+            Cow::Owned(values.chunks()[0].clone())
+        };
+
+        let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+        let out = FixedSizeListArray::new(data_type, values.into_owned(), arr.validity().cloned());
+        // safety: the inner dtype is unchanged
+        unsafe {
+            Ok(ArrayChunked::from_chunks_and_dtype_unchecked(
+                self.name(),
+                vec![Box::new(out)],
+                DataType::Array(Box::new(self.inner_dtype()), width),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_list_array_round_trip() -> PolarsResult<()> {
+        let s = Series::new("a", [1i32, 2, 3, 4, 5, 6]).reshape(&[-1, 3])?;
+        let arr = s.list()?.to_array(3)?;
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.dtype(), &DataType::Array(Box::new(DataType::Int32), 3));
+        assert!(s.list()?.to_array(4).is_err());
+
+        let out = arr.to_list().into_series();
+        assert!(out.series_equal(&s));
+        Ok(())
+    }
+}
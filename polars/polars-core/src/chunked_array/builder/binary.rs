@@ -47,6 +47,7 @@ impl BinaryChunkedBuilder {
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length,
         }
     }
@@ -28,6 +28,7 @@ impl ChunkedBuilder<bool, BooleanType> for BooleanChunkedBuilder {
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length,
         }
     }
@@ -33,6 +33,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length,
         }
     }
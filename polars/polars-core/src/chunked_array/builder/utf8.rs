@@ -48,6 +48,7 @@ impl Utf8ChunkedBuilder {
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length,
         }
     }
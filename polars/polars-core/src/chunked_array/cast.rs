@@ -145,6 +145,10 @@ where
 impl ChunkCast for Utf8Chunked {
     fn cast(&self, data_type: &DataType) -> PolarsResult<Series> {
         match data_type {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rev_map)) if rev_map.is_enum() => {
+                Ok(CategoricalChunked::from_utf8_to_enum(self, rev_map).into_series())
+            }
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => {
                 let iter = self.into_iter();
@@ -155,6 +159,7 @@ impl ChunkCast for Utf8Chunked {
             }
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => cast_single_to_struct(self.name(), &self.chunks, fields),
+            DataType::Binary => Ok(self.as_binary().into_series()),
             _ => cast_impl(self.name(), &self.chunks, data_type),
         }
     }
@@ -184,7 +189,10 @@ impl BinaryChunked {
             .downcast_iter()
             .map(|arr| Box::new(binary_to_utf8_unchecked(arr)) as ArrayRef)
             .collect();
-        Utf8Chunked::from_chunks(self.name(), chunks)
+        let mut out = Utf8Chunked::from_chunks(self.name(), chunks);
+        // strings are ordered by their bytes
+        out.set_sorted_flag(self.is_sorted_flag2());
+        out
     }
 }
 
@@ -199,7 +207,11 @@ impl Utf8Chunked {
                 )) as ArrayRef
             })
             .collect();
-        unsafe { BinaryChunked::from_chunks(self.name(), chunks) }
+        let mut out = unsafe { BinaryChunked::from_chunks(self.name(), chunks) };
+        self.carry_hash_cache(&mut out, 0);
+        // strings are ordered by their bytes
+        out.set_sorted_flag(self.is_sorted_flag2());
+        out
     }
 }
 
@@ -276,6 +288,10 @@ impl ChunkCast for ListChunked {
                     }
                 }
             }
+            #[cfg(feature = "dtype-array")]
+            Array(child_type, width) => self
+                .to_array(*width)?
+                .cast(&Array(child_type.clone(), *width)),
             _ => polars_bail!(ComputeError: "cannot cast list type"),
         }
     }
@@ -285,6 +301,47 @@ impl ChunkCast for ListChunked {
     }
 }
 
+#[cfg(feature = "dtype-array")]
+impl ChunkCast for ArrayChunked {
+    fn cast(&self, data_type: &DataType) -> PolarsResult<Series> {
+        use DataType::*;
+        match data_type {
+            Array(child_type, width) => {
+                polars_ensure!(
+                    *width == self.width(),
+                    ComputeError: "cannot cast array of width {} to width {}", self.width(), width
+                );
+                if **child_type == self.inner_dtype() {
+                    return Ok(self.clone().into_series());
+                }
+                let ca = self.rechunk();
+                let arr = ca.downcast_iter().next().unwrap();
+                let new_inner = ca.get_inner().cast(child_type)?;
+                let new_values = new_inner.array_ref(0).clone();
+
+                let data_type =
+                    FixedSizeListArray::default_datatype(new_values.data_type().clone(), *width);
+                let new_arr =
+                    FixedSizeListArray::new(data_type, new_values, arr.validity().cloned());
+                // Safety: we just casted so the dtype matches.
+                unsafe {
+                    Ok(Series::from_chunks_and_dtype_unchecked(
+                        self.name(),
+                        vec![Box::new(new_arr)],
+                        &Array(Box::new(new_inner.dtype().clone()), *width),
+                    ))
+                }
+            }
+            List(child_type) => self.to_list().cast(&List(child_type.clone())),
+            _ => polars_bail!(ComputeError: "cannot cast array type"),
+        }
+    }
+
+    unsafe fn cast_unchecked(&self, data_type: &DataType) -> PolarsResult<Series> {
+        self.cast(data_type)
+    }
+}
+
 // Returns inner data type. This is needed because a cast can instantiate the dtype inner
 // values for instance with categoricals
 fn cast_list(ca: &ListChunked, child_type: &DataType) -> PolarsResult<(ArrayRef, DataType)> {
@@ -14,7 +14,7 @@ fn from_chunks_list_dtype(chunks: &mut Vec<ArrayRef>, dtype: DataType) -> DataTy
         // arrow dictionaries are not nested as dictionaries, but only by their keys, so we must
         // change the list-value array to the keys and store the dictionary values in the datatype.
         // if a global string cache is set, we also must modify the keys.
-        DataType::List(inner) if *inner == DataType::Categorical(None) => {
+        DataType::List(inner) if matches!(**inner, DataType::Categorical(_)) => {
             let array = concatenate_owned_unchecked(chunks).unwrap();
             let list_arr = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
             let values_arr = list_arr.values();
@@ -55,6 +55,11 @@ where
     pub unsafe fn from_chunks(name: &str, mut chunks: Vec<ArrayRef>) -> Self {
         let dtype = match T::get_dtype() {
             dtype @ DataType::List(_) => from_chunks_list_dtype(&mut chunks, dtype),
+            #[cfg(feature = "dtype-array")]
+            dtype @ DataType::Array(_, _) => chunks
+                .get(0)
+                .map(|arr| arr.data_type().into())
+                .unwrap_or(dtype),
             dt => dt,
         };
         let field = Arc::new(Field::new(name, dtype));
@@ -63,6 +68,7 @@ where
             chunks,
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length: 0,
         };
         out.compute_len();
@@ -78,6 +84,28 @@ where
             chunks,
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
+            length: 0,
+        };
+        out.compute_len();
+        out
+    }
+}
+
+#[cfg(feature = "dtype-array")]
+impl ArrayChunked {
+    pub(crate) unsafe fn from_chunks_and_dtype_unchecked(
+        name: &str,
+        chunks: Vec<ArrayRef>,
+        dtype: DataType,
+    ) -> Self {
+        let field = Arc::new(Field::new(name, dtype));
+        let mut out = ChunkedArray {
+            field,
+            chunks,
+            phantom: PhantomData,
+            bit_settings: Default::default(),
+            hash_cache: None,
             length: 0,
         };
         out.compute_len();
@@ -97,6 +125,7 @@ impl ListChunked {
             chunks,
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length: 0,
         };
         out.compute_len();
@@ -54,6 +54,9 @@ pub enum RevMapping {
     Global(PlHashMap<u32, u32>, Utf8Array<i64>, u128),
     /// Utf8Array: caches the string values
     Local(Utf8Array<i64>),
+    /// Utf8Array: the fixed categories of an enum, in their declared order.
+    /// The indexes are ordered like the categories, so they compare and sort in that order.
+    Enum(Utf8Array<i64>),
 }
 
 impl Debug for RevMapping {
@@ -65,6 +68,9 @@ impl Debug for RevMapping {
             RevMapping::Local(_) => {
                 write!(f, "local")
             }
+            RevMapping::Enum(_) => {
+                write!(f, "enum")
+            }
         }
     }
 }
@@ -74,8 +80,7 @@ impl Default for RevMapping {
         let slice: &[Option<&str>] = &[];
         let cats = Utf8Array::<i64>::from(slice);
         if using_string_cache() {
-            let cache = &mut crate::STRING_CACHE.lock_map();
-            let id = cache.uuid;
+            let id = crate::STRING_CACHE.read_map().uuid;
             RevMapping::Global(Default::default(), cats, id)
         } else {
             RevMapping::Local(cats)
@@ -85,15 +90,45 @@ impl Default for RevMapping {
 
 #[allow(clippy::len_without_is_empty)]
 impl RevMapping {
+    /// Create the [`RevMapping`] of an enum with the fixed `categories`, which must be unique
+    /// and not null.
+    pub fn new_enum(categories: Utf8Array<i64>) -> PolarsResult<Self> {
+        polars_ensure!(
+            categories.null_count() == 0,
+            ComputeError: "the categories of an enum cannot be null"
+        );
+        let mut seen = PlHashSet::with_capacity(categories.len());
+        for cat in categories.values_iter() {
+            polars_ensure!(
+                seen.insert(cat),
+                Duplicate: "category '{}' is given more than once for an enum", cat
+            );
+        }
+        Ok(RevMapping::Enum(categories))
+    }
+
     pub fn is_global(&self) -> bool {
         matches!(self, Self::Global(_, _, _))
     }
 
+    /// Check if this is the mapping of an enum, see [`RevMapping::new_enum`].
+    pub fn is_enum(&self) -> bool {
+        matches!(self, Self::Enum(_))
+    }
+
+    /// Get the categories of an enum in their declared order.
+    pub fn get_enum_categories(&self) -> Option<&Utf8Array<i64>> {
+        match self {
+            Self::Enum(categories) => Some(categories),
+            _ => None,
+        }
+    }
+
     /// Get the length of the [`RevMapping`]
     pub fn len(&self) -> usize {
         match self {
             Self::Global(_, a, _) => a.len(),
-            Self::Local(a) => a.len(),
+            Self::Local(a) | Self::Enum(a) => a.len(),
         }
     }
 
@@ -104,7 +139,7 @@ impl RevMapping {
                 let idx = *map.get(&idx).unwrap();
                 a.value(idx as usize)
             }
-            Self::Local(a) => a.value(idx as usize),
+            Self::Local(a) | Self::Enum(a) => a.value(idx as usize),
         }
     }
 
@@ -114,7 +149,7 @@ impl RevMapping {
                 let idx = *map.get(&idx)?;
                 a.get(idx as usize)
             }
-            Self::Local(a) => a.get(idx as usize),
+            Self::Local(a) | Self::Enum(a) => a.get(idx as usize),
         }
     }
 
@@ -128,16 +163,18 @@ impl RevMapping {
                 let idx = *map.get(&idx).unwrap();
                 a.value_unchecked(idx as usize)
             }
-            Self::Local(a) => a.value_unchecked(idx as usize),
+            Self::Local(a) | Self::Enum(a) => a.value_unchecked(idx as usize),
         }
     }
     /// Check if the categoricals are created under the same global string cache.
+    /// Enums are of the same source if they have the same categories.
     pub fn same_src(&self, other: &Self) -> bool {
         match (self, other) {
             (RevMapping::Global(_, _, l), RevMapping::Global(_, _, r)) => *l == *r,
             (RevMapping::Local(l), RevMapping::Local(r)) => {
                 std::ptr::eq(l as *const Utf8Array<_>, r as *const Utf8Array<_>)
             }
+            (RevMapping::Enum(l), RevMapping::Enum(r)) => l == r,
             _ => false,
         }
     }
@@ -160,7 +197,7 @@ impl RevMapping {
                     .find(|(_k, &v)| (unsafe { a.value_unchecked(v as usize) } == value))
                     .map(|(k, _v)| *k)
             }
-            Self::Local(a) => {
+            Self::Local(a) | Self::Enum(a) => {
                 // Safety: within bounds
                 unsafe { (0..a.len()).find(|idx| a.value_unchecked(*idx) == value) }
                     .map(|idx| idx as u32)
@@ -302,7 +339,7 @@ impl<'a> CategoricalChunkedBuilder<'a> {
 
         // in a separate scope so that we drop the global cache as soon as we are finished
         {
-            let cache = &mut crate::STRING_CACHE.lock_map();
+            let cache = crate::STRING_CACHE.read_map();
             id = cache.uuid;
 
             for s in values.values_iter() {
@@ -312,9 +349,6 @@ impl<'a> CategoricalChunkedBuilder<'a> {
                 // we allocated enough
                 unsafe { local_to_global.push_unchecked(global_idx) }
             }
-            if cache.len() > u32::MAX as usize {
-                panic!("not more than {} categories supported", u32::MAX)
-            };
         }
         // we now know the exact size
         // no reallocs
@@ -369,7 +403,7 @@ impl<'a> CategoricalChunkedBuilder<'a> {
 
         // in a separate scope so that we drop the global cache as soon as we are finished
         {
-            let cache = &mut crate::STRING_CACHE.lock_map();
+            let cache = crate::STRING_CACHE.read_map();
             id = cache.uuid;
 
             for (s, h) in values.values_iter().zip(hashes.into_iter()) {
@@ -378,9 +412,6 @@ impl<'a> CategoricalChunkedBuilder<'a> {
                 // we allocated enough
                 unsafe { local_to_global.push_unchecked(global_idx) }
             }
-            if cache.len() > u32::MAX as usize {
-                panic!("not more than {} categories supported", u32::MAX)
-            };
         }
         // we now know the exact size
         // no reallocs
@@ -450,8 +481,12 @@ impl CategoricalChunked {
     /// Create a [`CategoricalChunked`] from a categorical indices. The indices will
     /// probe the global string cache.
     pub(crate) fn from_global_indices(cats: UInt32Chunked) -> PolarsResult<CategoricalChunked> {
-        let len = crate::STRING_CACHE.read_map().len() as u32;
-        let oob = cats.into_iter().flatten().any(|cat| cat >= len);
+        let oob = {
+            let cache = crate::STRING_CACHE.read_map();
+            cats.into_iter()
+                .flatten()
+                .any(|cat| cache.get(cat).is_none())
+        };
         polars_ensure!(
             !oob,
             ComputeError:
@@ -10,13 +10,14 @@ impl From<&CategoricalChunked> for DictionaryArray<u32> {
         let keys = ca.logical().rechunk();
         let keys = keys.downcast_iter().next().unwrap();
         let map = &**ca.get_rev_map();
+        // an enum is exported as an ordered dictionary
         let dtype = ArrowDataType::Dictionary(
             IntegerType::UInt32,
             Box::new(ArrowDataType::LargeUtf8),
-            false,
+            map.is_enum(),
         );
         match map {
-            RevMapping::Local(arr) => {
+            RevMapping::Local(arr) | RevMapping::Enum(arr) => {
                 // Safety:
                 // the keys are in bounds
                 unsafe {
@@ -48,12 +49,12 @@ impl From<&CategoricalChunked> for DictionaryArray<i64> {
         let dtype = ArrowDataType::Dictionary(
             IntegerType::UInt32,
             Box::new(ArrowDataType::LargeUtf8),
-            false,
+            map.is_enum(),
         );
         match map {
             // Safety:
             // the keys are in bounds
-            RevMapping::Local(arr) => unsafe {
+            RevMapping::Local(arr) | RevMapping::Enum(arr) => unsafe {
                 DictionaryArray::try_new_unchecked(
                     dtype,
                     cast(keys, &ArrowDataType::Int64)
@@ -103,4 +104,58 @@ impl CategoricalChunked {
             )
         }
     }
+
+    /// Cast the strings of `ca` to the enum of `rev_map`, values that are not a category
+    /// become null.
+    pub(crate) fn from_utf8_to_enum(ca: &Utf8Chunked, rev_map: &Arc<RevMapping>) -> Self {
+        let cat_idx = (0..rev_map.len() as u32)
+            .map(|idx| (rev_map.get(idx), idx))
+            .collect::<PlHashMap<_, _>>();
+        let mut cats: UInt32Chunked = ca
+            .into_iter()
+            .map(|opt_v| opt_v.and_then(|v| cat_idx.get(v).copied()))
+            .collect();
+        cats.rename(ca.name());
+        // Safety:
+        // the indexes are taken from the rev map
+        unsafe { CategoricalChunked::from_cats_and_rev_map_unchecked(cats, rev_map.clone()) }
+    }
+
+    /// Create an enum from the keys and values of an ordered dictionary. The values repeat if
+    /// the dictionaries of several chunks were concatenated, those keys are mapped to the first
+    /// occurrence of their value so the declared order of the categories is kept.
+    ///
+    /// # Safety
+    /// The caller must ensure that index values in the `keys` are in within bounds of the `values` length.
+    pub(crate) unsafe fn from_keys_and_values_enum(
+        name: &str,
+        keys: &PrimitiveArray<u32>,
+        values: &Utf8Array<i64>,
+    ) -> PolarsResult<Self> {
+        let mut cat_idx = PlHashMap::with_capacity(values.len());
+        let mut categories = MutableUtf8Array::<i64>::with_capacity(values.len());
+        let new_idx = values
+            .iter()
+            .map(|opt_v| {
+                let v = opt_v.ok_or_else(
+                    || polars_err!(ComputeError: "the categories of an enum cannot be null"),
+                )?;
+                let next = cat_idx.len() as u32;
+                Ok(*cat_idx.entry(v).or_insert_with(|| {
+                    categories.push(Some(v));
+                    next
+                }))
+            })
+            .collect::<PolarsResult<Vec<u32>>>()?;
+        let keys = PrimitiveArray::from_trusted_len_iter(
+            keys.iter()
+                .map(|opt_k| opt_k.map(|k| *new_idx.get_unchecked(*k as usize))),
+        );
+        let rev_map = RevMapping::new_enum(categories.into())?;
+        let cats = UInt32Chunked::from_chunks(name, vec![Box::new(keys)]);
+        Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
+            cats,
+            Arc::new(rev_map),
+        ))
+    }
 }
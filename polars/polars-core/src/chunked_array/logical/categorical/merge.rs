@@ -57,6 +57,13 @@ pub(crate) fn merge_categorical_map(
             let new_rev = RevMapping::Global(new_map, new_slots.into(), *l_id);
             Ok(Arc::new(new_rev))
         }
+        (RevMapping::Enum(_), RevMapping::Enum(_)) => {
+            polars_ensure!(
+                left.same_src(right),
+                ComputeError: "cannot combine enums with different categories"
+            );
+            Ok(left.clone())
+        }
         (RevMapping::Local(arr_l), RevMapping::Local(arr_r)) => {
             // they are from the same source, just clone
             if std::ptr::eq(arr_l, arr_r) {
@@ -31,8 +31,8 @@ pub struct CategoricalChunked {
 
 impl CategoricalChunked {
     pub(crate) fn field(&self) -> Field {
-        let name = self.logical().name();
-        Field::new(name, self.dtype().clone())
+        let field = self.logical().ref_field();
+        Field::new(field.name(), self.dtype().clone()).with_metadata(field.metadata().clone())
     }
 
     pub fn is_empty(&self) -> bool {
@@ -84,7 +84,8 @@ impl CategoricalChunked {
     }
 
     pub(crate) fn use_lexical_sort(&self) -> bool {
-        self.bit_settings.contains(BitSettings::LEXICAL_SORT)
+        // an enum always sorts in the order of its categories
+        self.bit_settings.contains(BitSettings::LEXICAL_SORT) && !self.get_rev_map().is_enum()
     }
 
     /// Create a [`CategoricalChunked`] from an array of `idx` and an existing [`RevMapping`]:  `rev_map`.
@@ -189,6 +190,13 @@ impl LogicalType for CategoricalChunked {
                 };
                 Ok(ca.into_series())
             }
+            // the categories of an enum are fixed, so the values are looked up in them
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rev_map))
+                if rev_map.is_enum() && !rev_map.same_src(self.get_rev_map()) =>
+            {
+                self.cast(&DataType::Utf8)?.cast(dtype)
+            }
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => Ok(self.clone().into_series()),
             _ => self.logical.cast(dtype),
@@ -228,7 +236,7 @@ mod test {
     use std::convert::TryFrom;
 
     use super::*;
-    use crate::{enable_string_cache, reset_string_cache, SINGLE_LOCK};
+    use crate::{enable_string_cache, populate_string_cache, reset_string_cache, SINGLE_LOCK};
 
     #[test]
     fn test_categorical_round_trip() -> PolarsResult<()> {
@@ -255,6 +263,37 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_enum() -> PolarsResult<()> {
+        let dtype = DataType::new_enum(["low", "mid", "high"])?;
+        assert!(dtype.is_enum());
+        assert!(DataType::new_enum(["a", "a"]).is_err());
+
+        let s = Series::new("a", [Some("high"), None, Some("low"), Some("mid")]);
+        let s = s.strict_cast(&dtype)?;
+        assert!(Series::new("a", ["low", "unknown"])
+            .strict_cast(&dtype)
+            .is_err());
+
+        // compared and sorted in the order of the categories
+        let mask = s.gt("low")?;
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(true), None, Some(false), Some(true)]
+        );
+        let sorted = s.sort(false).cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(sorted.utf8()?),
+            &[None, Some("low"), Some("mid"), Some("high")]
+        );
+
+        let arr: DictionaryArray<u32> = s.categorical()?.into();
+        let out = Series::try_from(("a", Box::new(arr) as ArrayRef))?;
+        assert!(out.dtype().is_enum());
+        assert!(out.series_equal_missing(&s));
+        Ok(())
+    }
+
     #[test]
     fn test_append_categorical() {
         let _lock = SINGLE_LOCK.lock();
@@ -274,6 +313,43 @@ mod test {
         assert_eq!(appended.str_value(5).unwrap(), "y");
     }
 
+    #[test]
+    fn test_populate_string_cache() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        assert!(populate_string_cache(["a"]).is_err());
+
+        enable_string_cache(true);
+        assert_eq!(populate_string_cache(["c", "a", "b", "a"])?, &[0, 1, 2, 1]);
+
+        // categoricals that are built concurrently share the populated categories
+        let out = std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|i| {
+                    scope.spawn(move || {
+                        let new = format!("new_{i}");
+                        Series::new("a", ["a", "b", "c", new.as_str()])
+                            .cast(&DataType::Categorical(None))
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for s in &out {
+            let phys = s.to_physical_repr();
+            assert_eq!(&Vec::from(phys.u32()?)[..3], &[Some(1), Some(2), Some(0)]);
+        }
+        let mut appended = out[0].clone();
+        appended.append(&out[3])?;
+        assert_eq!(appended.str_value(7)?, "new_3");
+
+        enable_string_cache(false);
+        Ok(())
+    }
+
     #[test]
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
@@ -23,7 +23,7 @@ impl<'a> CategoricalTakeRandomLocal<'a> {
     pub(crate) fn new(ca: &'a CategoricalChunked) -> Self {
         // should be rechunked upstream
         assert_eq!(ca.logical.chunks.len(), 1, "implementation error");
-        if let RevMapping::Local(rev_map) = &**ca.get_rev_map() {
+        if let RevMapping::Local(rev_map) | RevMapping::Enum(rev_map) = &**ca.get_rev_map() {
             let cats = ca.logical().take_rand();
             Self { rev_map, cats }
         } else {
@@ -1,15 +1,15 @@
 use std::hash::{Hash, Hasher};
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use ahash::RandomState;
 use hashbrown::hash_map::RawEntryMut;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 use polars_utils::HashSingle;
-use smartstring::{LazyCompact, SmartString};
 
 use crate::datatypes::PlIdHashMap;
+use crate::error::{polars_ensure, PolarsResult};
 use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
 use crate::prelude::InitHashMaps;
 
@@ -79,17 +79,42 @@ pub fn using_string_cache() -> bool {
     USE_STRING_CACHE.load(Ordering::Acquire) > 0
 }
 
-// This is the hash and the Index offset in the linear buffer
-#[derive(Copy, Clone)]
+/// Insert the `categories` in the global string cache, in the given order, and get their
+/// physical values.
+///
+/// New categories get increasing values, so populating the cache with a known dictionary before
+/// any categorical is built maps every category on the same value as long as the cache is alive.
+pub fn populate_string_cache<I, S>(categories: I) -> PolarsResult<Vec<u32>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    polars_ensure!(
+        using_string_cache(),
+        ComputeError: "the global string cache must be enabled to populate it"
+    );
+    let cache = STRING_CACHE.read_map();
+    Ok(categories
+        .into_iter()
+        .map(|s| cache.insert(s.as_ref()))
+        .collect())
+}
+
+/// The number of shards of the global string cache, must be a power of two.
+const N_SHARDS: usize = 16;
+
+// This is the hash, the index in the payloads and the string itself, which is shared
+// with the payloads, so that a shard can be probed without touching the payloads.
 struct Key {
     pub(super) hash: u64,
     pub(super) idx: u32,
+    pub(super) value: Arc<str>,
 }
 
 impl Key {
     #[inline]
-    pub(super) fn new(hash: u64, idx: u32) -> Self {
-        Self { hash, idx }
+    pub(super) fn new(hash: u64, idx: u32, value: Arc<str>) -> Self {
+        Self { hash, idx, value }
     }
 }
 
@@ -100,69 +125,141 @@ impl Hash for Key {
     }
 }
 
+type Shard = PlIdHashMap<Key, ()>;
+
+#[inline]
+fn shard_idx(h: u64) -> usize {
+    // the low bits select the bucket and the high bits the tag in the hashmap of a shard,
+    // so we select the shard with the bits in between
+    (h >> 32) as usize & (N_SHARDS - 1)
+}
+
+/// The number of slots of the first segment of the payloads, every next segment is twice as
+/// large.
+const FIRST_SEGMENT: usize = HASHMAP_INIT_SIZE;
+/// Enough segments to hold `u32::MAX` categories.
+const N_SEGMENTS: usize = 32;
+
+/// The strings of the global string cache, indexed by their categorical value.
+///
+/// The strings are stored in segments that are never moved, and every slot is set once, so
+/// categories can be appended and read concurrently without a lock.
+#[derive(Default)]
+struct Payloads {
+    segments: [OnceCell<Box<[OnceCell<Arc<str>>]>>; N_SEGMENTS],
+    len: AtomicUsize,
+}
+
+impl Payloads {
+    /// The segment and the offset in that segment of category `idx`.
+    #[inline]
+    fn locate(idx: usize) -> (usize, usize) {
+        let segment = (usize::BITS - 1 - (idx / FIRST_SEGMENT + 1).leading_zeros()) as usize;
+        (segment, idx - FIRST_SEGMENT * ((1 << segment) - 1))
+    }
+
+    /// Append `value` and get its category.
+    fn push(&self, value: Arc<str>) -> u32 {
+        let idx = self.len.fetch_add(1, Ordering::AcqRel);
+        if idx >= u32::MAX as usize {
+            panic!("not more than {} categories supported", u32::MAX)
+        };
+        let (segment, offset) = Self::locate(idx);
+        let segment = self.segments[segment].get_or_init(|| {
+            (0..FIRST_SEGMENT << segment)
+                .map(|_| OnceCell::new())
+                .collect()
+        });
+        // every index is handed out once, so the slot is still empty
+        let _ = segment[offset].set(value);
+        idx as u32
+    }
+
+    #[inline]
+    fn get(&self, cat: u32) -> Option<&str> {
+        let (segment, offset) = Self::locate(cat as usize);
+        self.segments
+            .get(segment)?
+            .get()?
+            .get(offset)?
+            .get()
+            .map(|s| s.as_ref())
+    }
+
+    fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire).min(u32::MAX as usize)
+    }
+}
+
+/// The strings are hashed into shards that are locked separately, so that threads that
+/// build categoricals concurrently only contend when they insert into the same shard.
+/// The categorical values are assigned in the order of insertion, so they stay dense.
 pub(crate) struct SCacheInner {
-    map: PlIdHashMap<Key, ()>,
+    shards: Vec<RwLock<Shard>>,
     pub(crate) uuid: u128,
-    payloads: Vec<StrHashGlobal>,
+    payloads: Payloads,
 }
 
 impl SCacheInner {
+    /// # Safety
+    /// `cat` must be a category of the string cache.
     #[inline]
     pub(crate) unsafe fn get_unchecked(&self, cat: u32) -> &str {
-        self.payloads.get_unchecked(cat as usize).as_str()
+        self.payloads.get(cat).unwrap_unchecked()
+    }
+
+    /// Get the string of category `cat`, `None` if it isn't in the string cache.
+    #[inline]
+    pub(crate) fn get(&self, cat: u32) -> Option<&str> {
+        self.payloads.get(cat)
     }
 
     pub(crate) fn len(&self) -> usize {
-        self.map.len()
+        self.payloads.len()
     }
 
     #[inline]
-    pub(crate) fn insert_from_hash(&mut self, h: u64, s: &str) -> u32 {
-        let mut global_idx = self.payloads.len() as u32;
-        // Note that we don't create the StrHashGlobal to search the key in the hashmap
-        // as StrHashGlobal may allocate a string
-        let entry = self.map.raw_entry_mut().from_hash(h, |key| {
-            (key.hash == h) && {
-                let pos = key.idx as usize;
-                let value = unsafe { self.payloads.get_unchecked(pos) };
-                s == value.as_str()
-            }
-        });
+    fn probe(shard: &Shard, h: u64, s: &str) -> Option<u32> {
+        shard
+            .raw_entry()
+            .from_hash(h, |key| (key.hash == h) && s == &*key.value)
+            .map(|(k, _)| k.idx)
+    }
+
+    #[inline]
+    pub(crate) fn insert_from_hash(&self, h: u64, s: &str) -> u32 {
+        let shard = &self.shards[shard_idx(h)];
+        // most strings are already cached, those only need the shared lock of their shard
+        if let Some(idx) = Self::probe(&shard.read().unwrap(), h, s) {
+            return idx;
+        }
+
+        let mut shard = shard.write().unwrap();
+        let entry = shard
+            .raw_entry_mut()
+            .from_hash(h, |key| (key.hash == h) && s == &*key.value);
 
         match entry {
-            RawEntryMut::Occupied(entry) => {
-                global_idx = entry.key().idx;
-            }
+            // inserted by another thread since we probed
+            RawEntryMut::Occupied(entry) => entry.key().idx,
             RawEntryMut::Vacant(entry) => {
-                let idx = self.payloads.len() as u32;
-                let key = Key::new(h, idx);
-                entry.insert_hashed_nocheck(h, key, ());
-
-                // only just now we allocate the string
-                self.payloads.push(s.into());
+                let value: Arc<str> = Arc::from(s);
+                // the shard stays locked, so no other thread can insert the same string meanwhile
+                let idx = self.payloads.push(value.clone());
+                entry.insert_hashed_nocheck(h, Key::new(h, idx, value), ());
+                idx
             }
         }
-        global_idx
     }
 
     #[inline]
     pub(crate) fn get_cat(&self, s: &str) -> Option<u32> {
         let h = StringCache::get_hash_builder().hash_single(s);
-        // as StrHashGlobal may allocate a string
-        self.map
-            .raw_entry()
-            .from_hash(h, |key| {
-                (key.hash == h) && {
-                    let pos = key.idx as usize;
-                    let value = unsafe { self.payloads.get_unchecked(pos) };
-                    s == value.as_str()
-                }
-            })
-            .map(|(k, _)| k.idx)
+        Self::probe(&self.shards[shard_idx(h)].read().unwrap(), h, s)
     }
 
     #[inline]
-    pub(crate) fn insert(&mut self, s: &str) -> u32 {
+    pub(crate) fn insert(&self, s: &str) -> u32 {
         let h = StringCache::get_hash_builder().hash_single(s);
         self.insert_from_hash(h, s)
     }
@@ -171,7 +268,9 @@ impl SCacheInner {
 impl Default for SCacheInner {
     fn default() -> Self {
         Self {
-            map: PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE),
+            shards: (0..N_SHARDS)
+                .map(|_| RwLock::new(Shard::with_capacity(HASHMAP_INIT_SIZE / N_SHARDS)))
+                .collect(),
             #[cfg(not(target_family = "wasm"))]
             uuid: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -182,7 +281,7 @@ impl Default for SCacheInner {
                 .duration_since(wasm_timer::UNIX_EPOCH)
                 .unwrap()
                 .as_nanos(),
-            payloads: Vec::with_capacity(HASHMAP_INIT_SIZE),
+            payloads: Default::default(),
         }
     }
 }
@@ -191,6 +290,9 @@ impl Default for SCacheInner {
 /// In *eager* you need to specifically toggle global string cache to have a global effect.
 /// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
 /// result is produced.
+///
+/// Inserting and reading only needs the shared [`StringCache::read_map`] lock, the exclusive
+/// [`StringCache::lock_map`] lock is taken to clear the cache.
 #[derive(Default)]
 pub(crate) struct StringCache(pub(crate) RwLock<SCacheInner>);
 
@@ -219,4 +321,23 @@ impl StringCache {
 
 pub(crate) static STRING_CACHE: Lazy<StringCache> = Lazy::new(Default::default);
 
-type StrHashGlobal = SmartString<LazyCompact>;
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_payload_segments() {
+        assert_eq!(Payloads::locate(0), (0, 0));
+        assert_eq!(Payloads::locate(FIRST_SEGMENT - 1), (0, FIRST_SEGMENT - 1));
+        assert_eq!(Payloads::locate(FIRST_SEGMENT), (1, 0));
+        assert_eq!(Payloads::locate(3 * FIRST_SEGMENT), (2, 0));
+
+        let payloads = Payloads::default();
+        for i in 0..4 * FIRST_SEGMENT {
+            assert_eq!(payloads.push(Arc::from(i.to_string())), i as u32);
+        }
+        assert_eq!(payloads.len(), 4 * FIRST_SEGMENT);
+        assert_eq!(payloads.get(3 * FIRST_SEGMENT as u32), Some("1536"));
+        assert_eq!(payloads.get(4 * FIRST_SEGMENT as u32), None);
+    }
+}
@@ -1,3 +1,5 @@
+use num_traits::ToPrimitive;
+
 use super::*;
 use crate::chunked_array::cast::cast_chunks;
 use crate::prelude::*;
@@ -124,3 +126,382 @@ impl DecimalChunked {
         }
     }
 }
+
+impl DecimalChunked {
+    fn scale_factor(&self) -> i128 {
+        10_i128.pow(self.scale() as u32)
+    }
+
+    /// Apply `op` elementwise to the values of `self` and `rhs`, which have the same scale.
+    /// The result has that scale and no fixed precision; overflows are null.
+    fn arithmetic<F>(&self, rhs: &DecimalChunked, op: F) -> PolarsResult<DecimalChunked>
+    where
+        F: Fn(i128, i128) -> Option<i128>,
+    {
+        polars_ensure!(
+            self.scale() == rhs.scale(),
+            ComputeError: "decimal arithmetic needs equal scales, got {} and {}", self.scale(), rhs.scale()
+        );
+        let op = |l: Option<i128>, r: Option<i128>| match (l, r) {
+            (Some(l), Some(r)) => op(l, r),
+            _ => None,
+        };
+        let mut out: Int128Chunked = match (self.len(), rhs.len()) {
+            (_, 1) => {
+                let r = rhs.0.get(0);
+                self.0.into_iter().map(|l| op(l, r)).collect()
+            }
+            (1, _) => {
+                let l = self.0.get(0);
+                rhs.0.into_iter().map(|r| op(l, r)).collect()
+            }
+            (a, b) => {
+                polars_ensure!(a == b, ShapeMismatch: "cannot apply decimal arithmetic on series of lengths {} and {}", a, b);
+                self.0
+                    .into_iter()
+                    .zip(rhs.0.into_iter())
+                    .map(|(l, r)| op(l, r))
+                    .collect()
+            }
+        };
+        out.rename(self.name());
+        Ok(out.into_decimal_unchecked(None, self.scale()))
+    }
+
+    pub fn try_add(&self, rhs: &DecimalChunked) -> PolarsResult<DecimalChunked> {
+        self.arithmetic(rhs, |l, r| l.checked_add(r))
+    }
+
+    pub fn try_sub(&self, rhs: &DecimalChunked) -> PolarsResult<DecimalChunked> {
+        self.arithmetic(rhs, |l, r| l.checked_sub(r))
+    }
+
+    /// Multiply, truncating the digits beyond the scale.
+    pub fn try_mul(&self, rhs: &DecimalChunked) -> PolarsResult<DecimalChunked> {
+        let factor = self.scale_factor();
+        self.arithmetic(rhs, |l, r| l.checked_mul(r).map(|v| v / factor))
+    }
+
+    /// Divide, truncating the digits beyond the scale. Division by zero is null.
+    pub fn try_div(&self, rhs: &DecimalChunked) -> PolarsResult<DecimalChunked> {
+        let factor = self.scale_factor();
+        self.arithmetic(rhs, |l, r| {
+            if r == 0 {
+                None
+            } else {
+                l.checked_mul(factor).map(|v| v / r)
+            }
+        })
+    }
+
+    /// Aggregate the valid values of every group with `f`.
+    fn agg_groups<F>(&self, groups: &GroupsProxy, dtype: DataType, f: F) -> Series
+    where
+        F: Fn(&mut dyn Iterator<Item = i128>) -> Option<i128>,
+    {
+        let ca = self.0.rechunk();
+        let mut out: Int128Chunked = match groups {
+            GroupsProxy::Idx(groups) => groups
+                .iter()
+                .map(|(_, idx)| f(&mut idx.iter().filter_map(|i| ca.get(*i as usize))))
+                .collect(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .iter()
+                .map(
+                    |&[first, len]| f(&mut (first..first + len).filter_map(|i| ca.get(i as usize))),
+                )
+                .collect(),
+        };
+        out.rename(self.name());
+        let DataType::Decimal(precision, _) = dtype else {
+            unreachable!()
+        };
+        out.into_decimal_unchecked(precision, self.scale())
+            .into_series()
+    }
+
+    pub(crate) unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        self.agg_groups(groups, self.dtype().clone(), |values| values.min())
+    }
+
+    pub(crate) unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.agg_groups(groups, self.dtype().clone(), |values| values.max())
+    }
+
+    /// The sums of the groups. Groups without valid values and overflowing sums are null.
+    pub(crate) unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.agg_groups(groups, self.sum_dtype(), |values| {
+            let first = values.next()?;
+            values.try_fold(first, |acc, v| acc.checked_add(v))
+        })
+    }
+
+    /// The means of the groups, with the scale of `self`. Overflowing sums are null.
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        self.agg_groups(groups, self.sum_dtype(), |values| {
+            let (sum, count) = values.try_fold((0i128, 0usize), |(sum, count), v| {
+                Some((sum.checked_add(v)?, count + 1))
+            })?;
+            decimal_mean(sum, count, 0)
+        })
+    }
+
+    /// Sums may not fit the precision of the input, so they don't have a fixed precision.
+    fn sum_dtype(&self) -> DataType {
+        DataType::Decimal(None, Some(self.scale()))
+    }
+
+    /// The sum of the valid values, `None` if it overflows.
+    fn checked_sum(&self) -> Option<i128> {
+        self.0
+            .into_iter()
+            .flatten()
+            .try_fold(0i128, |sum, v| sum.checked_add(v))
+    }
+
+    pub(crate) fn sum_as_series(&self) -> Series {
+        let sum = self.checked_sum();
+        Int128Chunked::from_slice_options(self.name(), &[sum])
+            .into_decimal_unchecked(None, self.scale())
+            .into_series()
+    }
+
+    /// The mean with the scale of `self`, rounded half away from zero. An overflowing sum is null.
+    pub(crate) fn mean_as_series(&self) -> Series {
+        let mean = self
+            .checked_sum()
+            .and_then(|sum| decimal_mean(sum, self.len() - self.null_count(), 0));
+        Int128Chunked::from_slice_options(self.name(), &[mean])
+            .into_decimal_unchecked(None, self.scale())
+            .into_series()
+    }
+
+    /// The mean as a float, `None` if the sum overflows.
+    pub(crate) fn mean(&self) -> Option<f64> {
+        let count = self.len() - self.null_count();
+        if count == 0 {
+            return None;
+        }
+        let sum = self.checked_sum()?;
+        Some(sum as f64 / count as f64 / self.scale_factor() as f64)
+    }
+}
+
+/// `sum / count` with `scale` digits after the decimal point, rounded half away from zero.
+///
+/// The integer part and the remainder are scaled separately, so that this doesn't overflow for
+/// sums of 64-bit integers and the scales that [`set_agg_dtype_policy`] allows.
+///
+/// [`set_agg_dtype_policy`]: crate::config::set_agg_dtype_policy
+fn decimal_mean(sum: i128, count: usize, scale: usize) -> Option<i128> {
+    if count == 0 {
+        return None;
+    }
+    let factor = 10_i128.pow(scale as u32);
+    let d = count as i128;
+    let (q, r) = (sum / d, sum % d);
+    let r = r * factor;
+    let (frac, rem) = (r / d, r % d);
+    let frac = if 2 * rem.abs() >= d {
+        frac + r.signum()
+    } else {
+        frac
+    };
+    Some(q * factor + frac)
+}
+
+fn decimal_mean_idx<T: PolarsIntegerType>(
+    ca: &ChunkedArray<T>,
+    idx: impl Iterator<Item = usize>,
+    scale: usize,
+) -> Option<i128> {
+    let (sum, count) = idx
+        .filter_map(|i| ca.get(i))
+        .fold((0i128, 0usize), |(sum, count), v| {
+            (sum + v.to_i128().unwrap(), count + 1)
+        });
+    decimal_mean(sum, count, scale)
+}
+
+impl Series {
+    /// The mean of integer data as a `Decimal` with `scale` digits after the decimal point.
+    pub(crate) fn decimal_mean_as_series(&self, scale: usize) -> Series {
+        let mean = with_match_physical_integer_polars_type!(self.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = self.as_ref().as_ref().as_ref();
+            let sum = ca
+                .downcast_iter()
+                .flat_map(|arr| arr.iter().flatten())
+                .map(|v| v.to_i128().unwrap())
+                .sum();
+            decimal_mean(sum, ca.len() - ca.null_count(), scale)
+        });
+        Int128Chunked::from_slice_options(self.name(), &[mean])
+            .into_decimal_unchecked(None, scale)
+            .into_series()
+    }
+
+    /// The means of the groups of integer data as a `Decimal` with `scale` digits after the
+    /// decimal point.
+    pub(crate) fn agg_decimal_mean(&self, groups: &GroupsProxy, scale: usize) -> Series {
+        let s = self.rechunk();
+        let mut means: Int128Chunked = with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            match groups {
+                GroupsProxy::Idx(groups) => groups
+                    .iter()
+                    .map(|(_, idx)| decimal_mean_idx(ca, idx.iter().map(|i| *i as usize), scale))
+                    .collect(),
+                GroupsProxy::Slice { groups, .. } => groups
+                    .iter()
+                    .map(|[first, len]| {
+                        let first = *first as usize;
+                        decimal_mean_idx(ca, first..first + *len as usize, scale)
+                    })
+                    .collect(),
+            }
+        });
+        means.rename(self.name());
+        means.into_decimal_unchecked(None, scale).into_series()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config::{AggDtypePolicy, MeanDtype, SumDtype};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_agg_dtype_policy() {
+        let default = AggDtypePolicy::default();
+        assert_eq!(default.sum_dtype(&DataType::Int8), DataType::Int64);
+        assert_eq!(default.mean_dtype(&DataType::Int32), DataType::Float64);
+        let policy = AggDtypePolicy {
+            sum: SumDtype::Input,
+            mean: MeanDtype::Decimal { scale: 2 },
+        };
+        assert_eq!(policy.sum_dtype(&DataType::Int8), DataType::Int8);
+        assert_eq!(
+            policy.mean_dtype(&DataType::Int32),
+            DataType::Decimal(None, Some(2))
+        );
+        assert_eq!(policy.mean_dtype(&DataType::Float32), DataType::Float32);
+
+        let s = Series::new("a", &[Some(1i32), Some(2), None, Some(2)]);
+        let mean = s.decimal_mean_as_series(2);
+        assert_eq!(mean.dtype(), &DataType::Decimal(None, Some(2)));
+        // 5 / 3 is rounded up
+        assert_eq!(mean.get(0).unwrap(), AnyValue::Decimal(167, 2));
+        // halves are rounded away from zero
+        let mean = Series::new("a", &[-1i64, -2]).decimal_mean_as_series(0);
+        assert_eq!(mean.get(0).unwrap(), AnyValue::Decimal(-2, 0));
+
+        let too_large = AggDtypePolicy {
+            sum: SumDtype::Int64,
+            mean: MeanDtype::Decimal { scale: 19 },
+        };
+        assert!(crate::config::set_agg_dtype_policy(too_large).is_err());
+        let mean = Series::new("a", &[i64::MAX, i64::MAX - 1]).decimal_mean_as_series(18);
+        assert_eq!(
+            mean.get(0).unwrap(),
+            AnyValue::Decimal(
+                (i64::MAX as i128) * 10_i128.pow(18) - 5 * 10_i128.pow(17),
+                18
+            )
+        );
+
+        let groups = GroupsProxy::Idx(vec![(0, vec![0, 1]), (2, vec![2]), (3, vec![3, 0])].into());
+        let means = s.agg_decimal_mean(&groups, 1);
+        let means = means.decimal().unwrap();
+        assert_eq!(means.name(), "a");
+        assert_eq!(
+            means.0.into_iter().collect::<Vec<_>>(),
+            &[Some(15), None, Some(15)]
+        );
+    }
+
+    fn decimal(values: &[Option<i128>], precision: Option<usize>, scale: usize) -> Series {
+        Int128Chunked::from_slice_options("a", values)
+            .into_decimal(precision, scale)
+            .unwrap()
+            .into_series()
+    }
+
+    fn values(s: &Series) -> Vec<Option<i128>> {
+        s.decimal().unwrap().0.into_iter().collect()
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() -> PolarsResult<()> {
+        // 1.50, 2.25, null
+        let a = decimal(&[Some(150), Some(225), None], Some(5), 2);
+        // 0.5, 1.0, 1.0
+        let b = decimal(&[Some(5), Some(10), Some(10)], Some(3), 1);
+
+        let out = a.try_add(&b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(None, Some(2)));
+        assert_eq!(values(&out), &[Some(200), Some(325), None]);
+        assert_eq!(values(&(&a - &b)), &[Some(100), Some(125), None]);
+        assert_eq!(values(&(&a * &b)), &[Some(75), Some(225), None]);
+        assert_eq!(values(&(&a / &b)), &[Some(300), Some(225), None]);
+        // integers are cast to the decimal type
+        let two = Series::new("", &[2i32]);
+        assert_eq!(values(&(&a * &two)), &[Some(300), Some(450), None]);
+        // division by zero is null
+        let zero = decimal(&[Some(0)], None, 2);
+        assert_eq!(values(&(&a / &zero)), &[None, None, None]);
+
+        let mask = a.gt(&b)?;
+        assert_eq!(Vec::from(&mask), &[Some(true), Some(true), None]);
+        let mask = a.equal(&decimal(&[Some(15)], None, 1))?;
+        assert_eq!(Vec::from(&mask), &[Some(true), Some(false), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_aggregations() {
+        let s = decimal(&[Some(150), Some(225), None, Some(-100)], Some(5), 2);
+        assert_eq!(s.sum_as_series().get(0).unwrap(), AnyValue::Decimal(275, 2));
+        assert_eq!(
+            s.min_as_series().get(0).unwrap(),
+            AnyValue::Decimal(-100, 2)
+        );
+        assert_eq!(s.max_as_series().get(0).unwrap(), AnyValue::Decimal(225, 2));
+        // 2.75 / 3 is rounded to 0.92
+        assert_eq!(s.mean_as_series().get(0).unwrap(), AnyValue::Decimal(92, 2));
+        assert!((s.mean().unwrap() - 2.75 / 3.0).abs() < 1e-12);
+        assert_eq!(
+            s.cast(&DataType::Float64).unwrap().f64().unwrap().get(0),
+            Some(1.5)
+        );
+
+        let groups = GroupsProxy::Idx(vec![(0, vec![0, 1]), (2, vec![2]), (3, vec![3, 0])].into());
+        unsafe {
+            // the group of only nulls has no sum
+            assert_eq!(values(&s.agg_sum(&groups)), &[Some(375), None, Some(50)]);
+            assert_eq!(values(&s.agg_min(&groups)), &[Some(150), None, Some(-100)]);
+            assert_eq!(values(&s.agg_max(&groups)), &[Some(225), None, Some(150)]);
+            assert_eq!(values(&s.agg_mean(&groups)), &[Some(188), None, Some(25)]);
+        }
+
+        // overflowing sums are null
+        let s = decimal(&[Some(i128::MAX), Some(1), Some(-1)], None, 2);
+        let groups = GroupsProxy::Idx(vec![(0, vec![0, 1]), (0, vec![0, 2])].into());
+        unsafe {
+            assert_eq!(values(&s.agg_sum(&groups)), &[None, Some(i128::MAX - 1)]);
+            assert_eq!(values(&s.agg_mean(&groups)), &[None, Some(i128::MAX / 2)]);
+        }
+        let s = decimal(&[Some(i128::MAX - 1), None, Some(2)], None, 2);
+        assert_eq!(s.sum_as_series().get(0).unwrap(), AnyValue::Null);
+        assert_eq!(s.mean_as_series().get(0).unwrap(), AnyValue::Null);
+        assert_eq!(s.mean(), None);
+        let s = decimal(&[Some(i128::MAX - 1), Some(1)], None, 2);
+        assert_eq!(
+            s.sum_as_series().get(0).unwrap(),
+            AnyValue::Decimal(i128::MAX, 2)
+        );
+        assert_eq!(
+            s.mean_as_series().get(0).unwrap(),
+            AnyValue::Decimal(i128::MAX / 2 + 1, 2)
+        );
+    }
+}
@@ -240,6 +240,10 @@ impl StructChunked {
         self.field.set_name(name.into())
     }
 
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.field.metadata = metadata
+    }
+
     pub(crate) fn try_apply_fields<F>(&self, func: F) -> PolarsResult<Self>
     where
         F: Fn(&Series) -> PolarsResult<Series>,
@@ -19,8 +19,10 @@ pub mod float;
 pub mod iterator;
 pub mod kernels;
 #[cfg(feature = "ndarray")]
-mod ndarray;
+pub(crate) mod ndarray;
 
+#[cfg(feature = "dtype-array")]
+pub(crate) mod array;
 mod bitwise;
 #[cfg(feature = "object")]
 mod drop;
@@ -48,6 +50,7 @@ use bitflags::bitflags;
 use polars_arrow::kernels::concatenate::concatenate_owned_unchecked;
 use polars_arrow::prelude::*;
 
+use crate::hashing::HashCache;
 use crate::series::IsSorted;
 use crate::utils::{first_non_null, last_non_null, CustomIterTools};
 
@@ -148,6 +151,7 @@ pub struct ChunkedArray<T: PolarsDataType> {
     pub(crate) chunks: Vec<ArrayRef>,
     phantom: PhantomData<T>,
     pub(crate) bit_settings: Settings,
+    pub(crate) hash_cache: Option<Arc<HashCache>>,
     length: IdxSize,
 }
 
@@ -298,6 +302,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     /// The caller must ensure to not change the `DataType` or `length` of any of the chunks.
     #[inline]
     pub unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.hash_cache = None;
         &mut self.chunks
     }
 
@@ -324,6 +329,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
             chunks,
             phantom: PhantomData,
             bit_settings: self.bit_settings,
+            hash_cache: None,
             length: 0,
         };
         out.compute_len();
@@ -358,7 +364,14 @@ impl<T: PolarsDataType> ChunkedArray<T> {
 
     /// Rename this ChunkedArray.
     pub fn rename(&mut self, name: &str) {
-        self.field = Arc::new(Field::new(name, self.field.data_type().clone()))
+        let mut field = self.field.as_ref().clone();
+        field.set_name(name.into());
+        self.field = Arc::new(field)
+    }
+
+    /// Set the metadata of the field of this ChunkedArray.
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.field = Arc::new(self.field.as_ref().clone().with_metadata(metadata))
     }
 }
 
@@ -430,6 +443,8 @@ where
 
 impl AsSinglePtr for BooleanChunked {}
 impl AsSinglePtr for ListChunked {}
+#[cfg(feature = "dtype-array")]
+impl AsSinglePtr for ArrayChunked {}
 impl AsSinglePtr for Utf8Chunked {}
 impl AsSinglePtr for BinaryChunked {}
 #[cfg(feature = "object")]
@@ -495,6 +510,7 @@ impl<T: PolarsDataType> Clone for ChunkedArray<T> {
             chunks: self.chunks.clone(),
             phantom: PhantomData,
             bit_settings: self.bit_settings,
+            hash_cache: self.hash_cache.clone(),
             length: self.length,
         }
     }
@@ -4,6 +4,17 @@ use rayon::prelude::*;
 use crate::prelude::*;
 use crate::POOL;
 
+/// The memory layout of the `ndarray::Array2` created by [`DataFrame::to_ndarray`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IndexOrder {
+    /// Row-major, the elements of a row are contiguous.
+    C,
+    /// Column-major, the elements of a column are contiguous. This is the cheapest to create,
+    /// as every column is copied at once.
+    #[default]
+    Fortran,
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -67,7 +78,7 @@ impl ListChunked {
 impl DataFrame {
     /// Create a 2D `ndarray::Array` from this `DataFrame`. This requires all columns in the
     /// `DataFrame` to be non-null and numeric. They will be casted to the same data type
-    /// (if they aren't already).
+    /// (if they aren't already). The array is contiguous in the given `ordering`.
     ///
     /// For floating point data we implicitly convert `None` to `NaN` without failure.
     ///
@@ -77,16 +88,16 @@ impl DataFrame {
     /// let b = Float64Chunked::new("b", &[10., 8., 6.]).into_series();
     ///
     /// let df = DataFrame::new(vec![a, b]).unwrap();
-    /// let ndarray = df.to_ndarray::<Float64Type>().unwrap();
+    /// let ndarray = df.to_ndarray::<Float64Type>(IndexOrder::C).unwrap();
     /// println!("{:?}", ndarray);
     /// ```
     /// Outputs:
     /// ```text
     /// [[1.0, 10.0],
     ///  [2.0, 8.0],
-    ///  [3.0, 6.0]], shape=[3, 2], strides=[2, 1], layout=C (0x1), const ndim=2/
+    ///  [3.0, 6.0]], shape=[3, 2], strides=[2, 1], layout=Cc (0x5), const ndim=2
     /// ```
-    pub fn to_ndarray<N>(&self) -> PolarsResult<Array2<N::Native>>
+    pub fn to_ndarray<N>(&self, ordering: IndexOrder) -> PolarsResult<Array2<N::Native>>
     where
         N: PolarsNumericType,
     {
@@ -112,7 +123,7 @@ impl DataFrame {
         })?;
 
         let shape = self.shape();
-        let height = self.height();
+        let (height, width) = shape;
         let mut membuf = Vec::with_capacity(shape.0 * shape.1);
         let ptr = membuf.as_ptr() as usize;
 
@@ -134,13 +145,22 @@ impl DataFrame {
                     // Safety:
                     // we get parallel access to the vector
                     // but we make sure that we don't get aliased access by offsetting the column indices + length
-                    unsafe {
-                        let offset_ptr = (ptr as *mut N::Native).add(col_idx * height);
-                        // Safety:
-                        // this is uninitialized memory, so we must never read from this data
-                        // copy_from_slice does not read
-                        let buf = std::slice::from_raw_parts_mut(offset_ptr, height);
-                        buf.copy_from_slice(vals)
+                    match ordering {
+                        IndexOrder::Fortran => unsafe {
+                            let offset_ptr = (ptr as *mut N::Native).add(col_idx * height);
+                            // Safety:
+                            // this is uninitialized memory, so we must never read from this data
+                            // copy_from_slice does not read
+                            let buf = std::slice::from_raw_parts_mut(offset_ptr, height);
+                            buf.copy_from_slice(vals)
+                        },
+                        // every column writes to the elements `col_idx + row_idx * width`
+                        IndexOrder::C => unsafe {
+                            let offset_ptr = (ptr as *mut N::Native).add(col_idx);
+                            for (row_idx, v) in vals.iter().enumerate() {
+                                offset_ptr.add(row_idx * width).write(*v);
+                            }
+                        },
                     }
 
                     Ok(())
@@ -151,10 +171,40 @@ impl DataFrame {
         // Safety:
         // we have written all data, so we can now safely set length
         unsafe {
-            membuf.set_len(shape.0 * shape.1);
+            membuf.set_len(height * width);
         }
-        let ndarr = Array2::from_shape_vec((shape.1, shape.0), membuf).unwrap();
-        Ok(ndarr.reversed_axes())
+        Ok(match ordering {
+            IndexOrder::C => Array2::from_shape_vec((height, width), membuf).unwrap(),
+            IndexOrder::Fortran => Array2::from_shape_vec((width, height), membuf)
+                .unwrap()
+                .reversed_axes(),
+        })
+    }
+
+    /// Create a `DataFrame` from a 2D `ndarray::Array`, with a column for every column of the
+    /// array. The columns are named `column_0`, `column_1`, etc. and can be renamed with
+    /// [`DataFrame::set_column_names`]. The array may have any memory layout.
+    ///
+    /// ```rust
+    /// use ndarray::array;
+    /// use polars_core::prelude::*;
+    /// let ndarray = array![[1.0, 10.0], [2.0, 8.0]];
+    /// let df = DataFrame::from_ndarray::<Float64Type>(ndarray.view());
+    /// assert_eq!(df.shape(), (2, 2));
+    /// ```
+    pub fn from_ndarray<N>(array: ArrayView2<N::Native>) -> Self
+    where
+        N: PolarsNumericType,
+    {
+        let columns = array
+            .columns()
+            .into_iter()
+            .enumerate()
+            .map(|(i, column)| {
+                ChunkedArray::<N>::from_vec(&format!("column_{i}"), column.to_vec()).into_series()
+            })
+            .collect();
+        DataFrame::new_no_checks(columns)
     }
 }
 
@@ -196,10 +246,34 @@ mod test {
             "b" => [2.0, 3.0, 4.0]
         ]?;
 
-        let ndarr = df.to_ndarray::<Float64Type>()?;
+        let ndarr = df.to_ndarray::<Float64Type>(IndexOrder::Fortran)?;
         let expected = array![[1.0, 2.0], [2.0, 3.0], [3.0, 4.0]];
         assert_eq!(ndarr, expected);
+        assert!(ndarr.t().is_standard_layout());
+
+        let ndarr = df.to_ndarray::<Float64Type>(IndexOrder::C)?;
+        assert_eq!(ndarr, expected);
+        assert!(ndarr.is_standard_layout());
+
+        Ok(())
+    }
 
+    #[test]
+    fn test_df_from_ndarray() -> PolarsResult<()> {
+        let ndarr = array![[1.0, 2.0], [2.0, 3.0], [3.0, 4.0]];
+        let df = DataFrame::from_ndarray::<Float64Type>(ndarr.view());
+        let expected = df!["column_0"=> [1.0, 2.0, 3.0],
+            "column_1" => [2.0, 3.0, 4.0]
+        ]?;
+        assert!(df.frame_equal(&expected));
+
+        // a view with a Fortran layout
+        let df = DataFrame::from_ndarray::<Float64Type>(ndarr.t());
+        assert_eq!(df.shape(), (2, 3));
+        assert_eq!(
+            df.to_ndarray::<Float64Type>(IndexOrder::C)?,
+            ndarr.t().to_owned()
+        );
         Ok(())
     }
 }
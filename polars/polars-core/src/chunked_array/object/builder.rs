@@ -71,6 +71,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length: len as IdxSize,
         }
     }
@@ -135,6 +136,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length: len as IdxSize,
         }
     }
@@ -5,6 +5,7 @@ mod var;
 use std::cmp::Ordering;
 use std::ops::Add;
 
+use arrow::bitmap::Bitmap;
 use arrow::compute;
 use arrow::types::simd::Simd;
 use arrow::types::NativeType;
@@ -219,6 +220,18 @@ where
     }
 }
 
+/// Count the bits that are set in both `values` and `validity`, a word at a time.
+fn count_valid_true(values: &Bitmap, validity: &Bitmap) -> usize {
+    let mut values = values.chunks::<u64>();
+    let mut validity = validity.chunks::<u64>();
+    let n = values
+        .by_ref()
+        .zip(validity.by_ref())
+        .map(|(v, m)| (v & m).count_ones() as usize)
+        .sum::<usize>();
+    n + (values.remainder() & validity.remainder()).count_ones() as usize
+}
+
 /// Booleans are casted to 1 or 0.
 impl BooleanChunked {
     /// Returns `None` if the array is empty or only contains null values.
@@ -229,9 +242,7 @@ impl BooleanChunked {
             Some(
                 self.downcast_iter()
                     .map(|arr| match arr.validity() {
-                        Some(validity) => {
-                            (arr.len() - (validity & arr.values()).unset_bits()) as IdxSize
-                        }
+                        Some(validity) => count_valid_true(arr.values(), validity) as IdxSize,
                         None => (arr.len() - arr.values().unset_bits()) as IdxSize,
                     })
                     .sum(),
@@ -1011,4 +1022,18 @@ mod test {
         assert!(a.median_as_series().series_equal_missing(&expected));
         assert_eq!(a.median(), Some(2.0f64))
     }
+
+    #[test]
+    fn test_bool_sum() {
+        let values = (0..150)
+            .map(|i| (i % 5 != 0).then_some(i % 3 == 0))
+            .collect::<Vec<_>>();
+        let ca = BooleanChunked::new("a", &values);
+        let expected = |values: &[Option<bool>]| {
+            values.iter().filter(|v| **v == Some(true)).count() as IdxSize
+        };
+        assert_eq!(ca.sum(), Some(expected(&values)));
+        // an offset that isn't aligned to a byte or word
+        assert_eq!(ca.slice(3, 140).sum(), Some(expected(&values[3..143])));
+    }
 }
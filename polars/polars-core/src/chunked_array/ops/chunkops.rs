@@ -116,6 +116,10 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         let (chunks, len) = slice(&self.chunks, offset, length, self.len());
         let mut out = self.copy_with_chunks(chunks, true, true);
         out.length = len as IdxSize;
+        if self.hash_cache.is_some() {
+            let (raw_offset, _) = slice_offsets(offset, length, self.len());
+            self.carry_hash_cache(&mut out, raw_offset);
+        }
         out
     }
 
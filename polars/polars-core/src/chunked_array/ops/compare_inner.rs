@@ -307,7 +307,9 @@ impl<'a> IntoPartialOrdInner<'a> for &'a BooleanChunked {
 impl<'a> IntoPartialOrdInner<'a> for &'a CategoricalChunked {
     fn into_partial_ord_inner(self) -> Box<dyn PartialOrdInner + 'a> {
         match &**self.get_rev_map() {
-            RevMapping::Local(_) => Box::new(CategoricalTakeRandomLocal::new(self)),
+            RevMapping::Local(_) | RevMapping::Enum(_) => {
+                Box::new(CategoricalTakeRandomLocal::new(self))
+            }
             RevMapping::Global(_, _, _) => Box::new(CategoricalTakeRandomGlobal::new(self)),
         }
     }
@@ -1,73 +1,89 @@
 use std::iter::FromIterator;
-use std::ops::{Add, AddAssign, Mul};
+use std::ops::{Add, Mul};
 
 use num_traits::Bounded;
 
 use crate::prelude::*;
 use crate::utils::CustomIterTools;
 
-fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
+fn det_max<T>(state: &mut T, v: Option<T>) -> Option<T>
 where
-    T: Copy + PartialOrd + AddAssign + Add<Output = T>,
+    T: Copy + PartialOrd,
 {
-    match v {
-        Some(v) => {
-            if v > *state {
-                *state = v
-            }
-            Some(Some(*state))
+    v.map(|v| {
+        if v > *state {
+            *state = v
         }
-        None => Some(None),
-    }
+        *state
+    })
 }
 
-fn det_min<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
+fn det_min<T>(state: &mut T, v: Option<T>) -> Option<T>
 where
-    T: Copy + PartialOrd + AddAssign + Add<Output = T>,
+    T: Copy + PartialOrd,
 {
-    match v {
-        Some(v) => {
-            if v < *state {
-                *state = v
-            }
-            Some(Some(*state))
+    v.map(|v| {
+        if v < *state {
+            *state = v
         }
-        None => Some(None),
-    }
+        *state
+    })
 }
 
-fn det_sum<T>(state: &mut Option<T>, v: Option<T>) -> Option<Option<T>>
+fn det_sum<T>(state: &mut Option<T>, v: Option<T>) -> Option<T>
 where
-    T: Copy + PartialOrd + AddAssign + Add<Output = T>,
+    T: Copy + Add<Output = T>,
 {
-    match (*state, v) {
-        (Some(state_inner), Some(v)) => {
-            *state = Some(state_inner + v);
-            Some(*state)
-        }
-        (None, Some(v)) => {
-            *state = Some(v);
-            Some(*state)
-        }
-        (_, None) => Some(None),
-    }
+    let v = v?;
+    *state = Some(match *state {
+        Some(state_inner) => state_inner + v,
+        None => v,
+    });
+    *state
+}
+
+fn det_prod<T>(state: &mut Option<T>, v: Option<T>) -> Option<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    let v = v?;
+    *state = Some(match *state {
+        Some(state_inner) => state_inner * v,
+        None => v,
+    });
+    *state
 }
 
-fn det_prod<T>(state: &mut Option<T>, v: Option<T>) -> Option<Option<T>>
+/// Apply the cumulative function `f` chunk by chunk. The `state` is carried
+/// over the chunk boundaries, so the output has the chunks of the input and
+/// nothing has to be rechunked.
+fn cum_scan<T, S, F>(ca: &ChunkedArray<T>, reverse: bool, mut state: S, mut f: F) -> ChunkedArray<T>
 where
-    T: Copy + PartialOrd + Mul<Output = T>,
+    T: PolarsNumericType,
+    ChunkedArray<T>: FromIterator<Option<T::Native>>,
+    F: FnMut(&mut S, Option<T::Native>) -> Option<T::Native>,
 {
-    match (*state, v) {
-        (Some(state_inner), Some(v)) => {
-            *state = Some(state_inner * v);
-            Some(*state)
+    let mut chunks = Vec::with_capacity(ca.chunks().len());
+    if reverse {
+        for arr in ca.downcast_iter().rev() {
+            let out: ChunkedArray<T> = arr
+                .into_iter()
+                .rev()
+                .map(|v| f(&mut state, v.copied()))
+                .collect_reversed();
+            chunks.extend(out.chunks);
         }
-        (None, Some(v)) => {
-            *state = Some(v);
-            Some(*state)
+        chunks.reverse();
+    } else {
+        for arr in ca.downcast_iter() {
+            let out: ChunkedArray<T> = arr
+                .into_iter()
+                .map(|v| f(&mut state, v.copied()))
+                .collect_trusted();
+            chunks.extend(out.chunks);
         }
-        (_, None) => Some(None),
     }
+    ca.copy_with_chunks(chunks, false, false)
 }
 
 impl<T> ChunkCumAgg<T> for ChunkedArray<T>
@@ -76,64 +92,59 @@ where
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     fn cummax(&self, reverse: bool) -> ChunkedArray<T> {
-        let init = Bounded::min_value();
-
-        let mut ca: Self = match reverse {
-            false => self.into_iter().scan(init, det_max).collect_trusted(),
-            true => self
-                .into_iter()
-                .rev()
-                .scan(init, det_max)
-                .collect_reversed(),
-        };
-
-        ca.rename(self.name());
-        ca
+        let init: T::Native = Bounded::min_value();
+        cum_scan(self, reverse, init, det_max)
     }
 
     fn cummin(&self, reverse: bool) -> ChunkedArray<T> {
-        let init = Bounded::max_value();
-        let mut ca: Self = match reverse {
-            false => self.into_iter().scan(init, det_min).collect_trusted(),
-            true => self
-                .into_iter()
-                .rev()
-                .scan(init, det_min)
-                .collect_reversed(),
-        };
-
-        ca.rename(self.name());
-        ca
+        let init: T::Native = Bounded::max_value();
+        cum_scan(self, reverse, init, det_min)
     }
 
     fn cumsum(&self, reverse: bool) -> ChunkedArray<T> {
-        let init = None;
-        let mut ca: Self = match reverse {
-            false => self.into_iter().scan(init, det_sum).collect_trusted(),
-            true => self
-                .into_iter()
-                .rev()
-                .scan(init, det_sum)
-                .collect_reversed(),
-        };
-
-        ca.rename(self.name());
-        ca
+        cum_scan(self, reverse, None, det_sum)
     }
 
     fn cumprod(&self, reverse: bool) -> ChunkedArray<T> {
-        let init = None;
-        let mut ca: Self = match reverse {
-            false => self.into_iter().scan(init, det_prod).collect_trusted(),
-            true => self
-                .into_iter()
-                .rev()
-                .scan(init, det_prod)
-                .collect_reversed(),
-        };
+        cum_scan(self, reverse, None, det_prod)
+    }
+}
+
+impl BooleanChunked {
+    fn cum_bool(&self, reverse: bool, mut f: impl FnMut(&mut bool, bool) -> bool) -> Self {
+        // the state is `None` until the first valid value is seen
+        let mut state: Option<bool> = None;
+        let mut step = |v: Option<bool>| v.map(|v| f(state.get_or_insert(v), v));
+        let mut chunks = Vec::with_capacity(self.chunks().len());
+        if reverse {
+            for arr in self.downcast_iter().rev() {
+                let out: BooleanChunked = arr.into_iter().rev().map(&mut step).collect_reversed();
+                chunks.extend(out.chunks);
+            }
+            chunks.reverse();
+        } else {
+            for arr in self.downcast_iter() {
+                let out: BooleanChunked = arr.into_iter().map(&mut step).collect_trusted();
+                chunks.extend(out.chunks);
+            }
+        }
+        self.copy_with_chunks(chunks, false, false)
+    }
+}
 
-        ca.rename(self.name());
-        ca
+impl ChunkCumAgg<BooleanType> for BooleanChunked {
+    fn cummax(&self, reverse: bool) -> BooleanChunked {
+        self.cum_bool(reverse, |state, v| {
+            *state |= v;
+            *state
+        })
+    }
+
+    fn cummin(&self, reverse: bool) -> BooleanChunked {
+        self.cum_bool(reverse, |state, v| {
+            *state &= v;
+            *state
+        })
     }
 }
 
@@ -173,4 +184,38 @@ mod test {
         let ca = Float32Chunked::new("foo", &[None, Some(1.0), Some(3.0), None, Some(1.0)]);
         let _out = ca.cumsum(false);
     }
+
+    #[test]
+    fn test_cumsum_chunked() {
+        let mut ca = Int32Chunked::new("foo", &[Some(1), None, Some(2)]);
+        ca.append(&Int32Chunked::new("foo", &[Some(3), Some(4)]));
+        let out = ca.cumsum(false);
+        assert_eq!(out.chunks().len(), 2);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), None, Some(3), Some(6), Some(10)]
+        );
+        let out = ca.cumsum(true);
+        assert_eq!(out.chunks().len(), 2);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(10), None, Some(9), Some(7), Some(4)]
+        );
+    }
+
+    #[test]
+    fn test_cummax_bool() {
+        let mut ca = BooleanChunked::new("foo", &[Some(false), None, Some(true)]);
+        ca.append(&BooleanChunked::new("foo", &[Some(false)]));
+        let out = ca.cummax(false);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), None, Some(true), Some(true)]
+        );
+        let out = ca.cummin(true);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), None, Some(false), Some(false)]
+        );
+    }
 }
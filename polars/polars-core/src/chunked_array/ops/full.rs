@@ -114,6 +114,31 @@ impl ListChunked {
         unsafe { ListChunked::from_chunks(name, vec![arr]) }
     }
 }
+#[cfg(feature = "dtype-array")]
+impl ArrayChunked {
+    pub fn full_null_with_dtype(
+        name: &str,
+        length: usize,
+        inner_dtype: &DataType,
+        width: usize,
+    ) -> ArrayChunked {
+        let arr = new_null_array(
+            ArrowDataType::FixedSizeList(
+                Box::new(ArrowField::new("item", inner_dtype.to_arrow(), true)),
+                width,
+            ),
+            length,
+        );
+        unsafe {
+            ArrayChunked::from_chunks_and_dtype_unchecked(
+                name,
+                vec![arr],
+                DataType::Array(Box::new(inner_dtype.clone()), width),
+            )
+        }
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 impl ChunkFullNull for StructChunked {
     fn full_null(name: &str, length: usize) -> StructChunked {
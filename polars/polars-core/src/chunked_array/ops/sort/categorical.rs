@@ -47,7 +47,7 @@ impl CategoricalChunked {
                         )
                     }
                 }
-                RevMapping::Global(_, _, _) => {
+                RevMapping::Global(_, _, _) | RevMapping::Enum(_) => {
                     // a global rev map must always point to the same string values
                     // so we cannot sort the categories.
 
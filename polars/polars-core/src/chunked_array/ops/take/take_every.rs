@@ -1,5 +1,4 @@
 use crate::prelude::*;
-use crate::utils::NoNull;
 
 impl<T> ChunkTakeEvery<T> for ChunkedArray<T>
 where
@@ -7,8 +6,20 @@ where
 {
     fn take_every(&self, n: usize) -> ChunkedArray<T> {
         let mut ca = if !self.has_validity() {
-            let a: NoNull<_> = self.into_no_null_iter().step_by(n).collect();
-            a.into_inner()
+            // stride over the value buffers, so that only the selected
+            // values are visited
+            let mut values = Vec::with_capacity(self.len() / n + 1);
+            let mut phase = 0;
+            for arr in self.downcast_iter() {
+                let slice = arr.values().as_slice();
+                if phase < slice.len() {
+                    values.extend(slice[phase..].iter().step_by(n).copied());
+                    phase = (n - (slice.len() - phase) % n) % n;
+                } else {
+                    phase -= slice.len();
+                }
+            }
+            ChunkedArray::from_vec(self.name(), values)
         } else {
             self.into_iter().step_by(n).collect()
         };
@@ -65,3 +76,18 @@ impl<T: PolarsObject> ChunkTakeEvery<ObjectType<T>> for ObjectChunked<T> {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_take_every_chunked() {
+        let mut ca = Int32Chunked::from_slice("a", &[0, 1, 2, 3, 4]);
+        ca.append(&Int32Chunked::from_slice("a", &[5, 6]));
+        ca.append(&Int32Chunked::from_slice("a", &[7, 8, 9, 10]));
+        let out = ca.take_every(3);
+        assert_eq!(Vec::from(&out), &[Some(0), Some(3), Some(6), Some(9)]);
+        assert_eq!(out.name(), "a");
+    }
+}
@@ -8,12 +8,16 @@ use arrow::bitmap::MutableBitmap;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::ObjectType;
 use crate::datatypes::PlHashSet;
-use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
-use crate::frame::groupby::GroupsProxy;
+#[cfg(feature = "mode")]
+use crate::frame::groupby::hashing::count_threaded;
+use crate::frame::groupby::hashing::{unique_threaded, HASHMAP_INIT_SIZE};
 #[cfg(feature = "mode")]
 use crate::frame::groupby::IntoGroupsProxy;
+use crate::frame::groupby::{group_multithreaded, GroupsProxy};
+use crate::hashing::bytes_hash_builder;
 use crate::prelude::*;
 use crate::series::IsSorted;
+use crate::utils::_set_partition_size;
 
 fn finish_is_unique_helper(
     unique_idx: Vec<IdxSize>,
@@ -186,6 +190,9 @@ where
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
+        if self.is_sorted_flag2() != IsSorted::Not {
+            return Ok(self.unique()?.len());
+        }
         if self.null_count() > 0 {
             Ok(fill_set(self.into_iter().flatten()).len() + 1)
         } else {
@@ -220,8 +227,67 @@ impl ChunkUnique<Utf8Type> for Utf8Chunked {
     }
 }
 
+impl BinaryChunked {
+    /// Collect the distinct values in parallel, partitioned in the same way as a groupby.
+    fn unique_threaded(&self) -> Vec<PlHashSet<Option<&[u8]>>> {
+        let n_partitions = _set_partition_size();
+        let byte_hashes = self.to_bytes_hashes_threaded(n_partitions, bytes_hash_builder(&[self]));
+        let byte_hashes = byte_hashes.iter().collect::<Vec<_>>();
+        unique_threaded(&byte_hashes, n_partitions as u64)
+            .into_iter()
+            .map(|set| set.into_iter().map(|bh| bh.payload()).collect())
+            .collect()
+    }
+
+    /// The most occurring values, counted in parallel with the same partitioning as a groupby.
+    #[cfg(feature = "mode")]
+    fn mode_threaded(&self) -> Self {
+        let n_partitions = _set_partition_size();
+        let byte_hashes = self.to_bytes_hashes_threaded(n_partitions, bytes_hash_builder(&[self]));
+        let byte_hashes = byte_hashes.iter().collect::<Vec<_>>();
+        let counts = count_threaded(&byte_hashes, n_partitions as u64);
+        let max_occur = counts
+            .iter()
+            .flat_map(|counts| counts.values())
+            .max()
+            .copied()
+            .unwrap_or(0);
+        BinaryChunked::from_iter_options(
+            self.name(),
+            counts.iter().flat_map(|counts| {
+                counts
+                    .iter()
+                    .filter(move |(_, count)| **count == max_occur)
+                    .map(|(bh, _)| bh.payload())
+            }),
+        )
+    }
+
+    /// The first value of every run of equal values.
+    fn unique_sorted(&self) -> impl Iterator<Item = Option<&[u8]>> + '_ {
+        let mut prev = None;
+        self.into_iter().filter(move |v| {
+            let is_new = prev != Some(*v);
+            prev = Some(*v);
+            is_new
+        })
+    }
+}
+
 impl ChunkUnique<BinaryType> for BinaryChunked {
     fn unique(&self) -> PolarsResult<Self> {
+        if self.is_sorted_flag2() != IsSorted::Not {
+            let mut out = BinaryChunked::from_iter_options(self.name(), self.unique_sorted());
+            out.set_sorted_flag(self.is_sorted_flag2());
+            return Ok(out);
+        }
+        if group_multithreaded(self) {
+            let sets = self.unique_threaded();
+            return Ok(BinaryChunked::from_iter_options(
+                self.name(),
+                sets.iter().flatten().copied(),
+            ));
+        }
         match self.null_count() {
             0 => {
                 let mut set =
@@ -253,6 +319,12 @@ impl ChunkUnique<BinaryType> for BinaryChunked {
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
+        if self.is_sorted_flag2() != IsSorted::Not {
+            return Ok(self.unique_sorted().count());
+        }
+        if group_multithreaded(self) {
+            return Ok(self.unique_threaded().iter().map(|set| set.len()).sum());
+        }
         if self.null_count() > 0 {
             Ok(fill_set(self.into_iter().flatten()).len() + 1)
         } else {
@@ -262,6 +334,10 @@ impl ChunkUnique<BinaryType> for BinaryChunked {
 
     #[cfg(feature = "mode")]
     fn mode(&self) -> PolarsResult<Self> {
+        // sorted data is grouped by its runs
+        if self.is_sorted_flag2() == IsSorted::Not && group_multithreaded(self) {
+            return Ok(self.mode_threaded());
+        }
         Ok(mode(self))
     }
 }
@@ -325,6 +401,7 @@ impl ChunkUnique<Float64Type> for Float64Chunked {
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use crate::series::IsSorted;
 
     #[test]
     fn unique() {
@@ -350,6 +427,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn unique_utf8_threaded_and_sorted() {
+        let values = (0..5000)
+            .map(|i| {
+                if i % 7 == 0 {
+                    None
+                } else {
+                    Some(format!("{}", i % 100))
+                }
+            })
+            .collect::<Vec<_>>();
+        let ca = Utf8Chunked::new("", &values);
+        assert_eq!(ca.n_unique().unwrap(), 101);
+        let unique = ca.unique().unwrap().sort(false);
+        assert_eq!(unique.len(), 101);
+        assert_eq!(unique.null_count(), 1);
+
+        let mut sorted = ca.sort(false);
+        sorted.set_sorted_flag(IsSorted::Ascending);
+        assert_eq!(sorted.n_unique().unwrap(), 101);
+        assert_eq!(Vec::from(&sorted.unique().unwrap()), Vec::from(&unique));
+        sorted.set_sorted_flag(IsSorted::Not);
+        assert_eq!(sorted.n_unique().unwrap(), 101);
+    }
+
     #[test]
     fn arg_unique() {
         let ca = ChunkedArray::<Int32Type>::from_slice("a", &[1, 2, 1, 1, 3]);
@@ -375,5 +477,31 @@ mod test {
         let ca3 = Int32Chunked::from_slice("c", &[]);
         let result3 = Vec::from(&ca3.mode().unwrap());
         assert_eq!(result3, &[]);
+
+        // counted in partitions
+        let values = (0..5000)
+            .map(|i| match i % 10 {
+                0 | 1 => Some("a".to_string()),
+                2 | 3 => None,
+                i => Some(i.to_string()),
+            })
+            .collect::<Vec<_>>();
+        let ca4 = Utf8Chunked::new("d", &values);
+        let mut result4 = Vec::from(&ca4.mode().unwrap());
+        result4.sort();
+        assert_eq!(result4, &[None, Some("a")]);
+    }
+
+    #[test]
+    fn as_binary_keeps_sorted_flag() {
+        let mut ca = Utf8Chunked::from_slice("a", &["a", "b", "b"]);
+        ca.set_sorted_flag(IsSorted::Ascending);
+        let binary = ca.as_binary();
+        assert_eq!(binary.is_sorted_flag2(), IsSorted::Ascending);
+        assert_eq!(ca.unique().unwrap().is_sorted_flag2(), IsSorted::Ascending);
+        assert_eq!(
+            unsafe { binary.to_utf8() }.is_sorted_flag2(),
+            IsSorted::Ascending
+        );
     }
 }
@@ -26,6 +26,7 @@ fn prepare_mask(mask: &BooleanArray) -> BooleanArray {
     }
 }
 
+// A null in the mask takes from `other`, like `prepare_mask` does in the kernel path.
 macro_rules! impl_ternary_broadcast {
     ($self:ident, $self_len:expr, $other_len:expr, $other:expr, $mask:expr, $ty:ty) => {{
         match ($self_len, $other_len) {
@@ -33,8 +34,8 @@ macro_rules! impl_ternary_broadcast {
                 let left = $self.get(0);
                 let right = $other.get(0);
                 let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
-                    .map(|mask_val| ternary_apply(mask_val, left, right))
+                    .into_iter()
+                    .map(|mask_val| ternary_apply(mask_val.unwrap_or(false), left, right))
                     .collect_trusted();
                 val.rename($self.name());
                 Ok(val)
@@ -42,9 +43,9 @@ macro_rules! impl_ternary_broadcast {
             (_, 1) => {
                 let right = $other.get(0);
                 let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
+                    .into_iter()
                     .zip($self)
-                    .map(|(mask_val, left)| ternary_apply(mask_val, left, right))
+                    .map(|(mask_val, left)| ternary_apply(mask_val.unwrap_or(false), left, right))
                     .collect_trusted();
                 val.rename($self.name());
                 Ok(val)
@@ -52,9 +53,9 @@ macro_rules! impl_ternary_broadcast {
             (1, _) => {
                 let left = $self.get(0);
                 let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
+                    .into_iter()
                     .zip($other)
-                    .map(|(mask_val, right)| ternary_apply(mask_val, left, right))
+                    .map(|(mask_val, right)| ternary_apply(mask_val.unwrap_or(false), left, right))
                     .collect_trusted();
                 val.rename($self.name());
                 Ok(val)
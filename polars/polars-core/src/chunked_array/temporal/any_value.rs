@@ -0,0 +1,347 @@
+//! Conversions between temporal [`AnyValue`]s and the types of the `chrono` crate and, with the
+//! `time` feature, the `time` crate, so that single values can be read and written without
+//! computing with timestamps.
+use arrow::temporal_conversions::*;
+#[cfg(all(feature = "timezones", feature = "dtype-datetime"))]
+use chrono::{DateTime, TimeZone as _};
+#[cfg(feature = "time")]
+use chrono::{Datelike, Timelike};
+use chrono::{Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime};
+#[cfg(all(feature = "timezones", feature = "dtype-datetime"))]
+use chrono_tz::Tz;
+
+#[cfg(feature = "dtype-date")]
+use super::date::naive_date_to_date;
+#[cfg(feature = "dtype-time")]
+use super::time::time_to_time64ns;
+use crate::prelude::*;
+
+/// A datetime in nanoseconds, or in microseconds if it is too far from the epoch.
+#[cfg(feature = "dtype-datetime")]
+fn datetime_to_any_value(ndt: NaiveDateTime, time_zone: &Option<TimeZone>) -> AnyValue<'_> {
+    // i64 nanoseconds cover 1677-09-21 up to 2262-04-11, so only whole years within those
+    if (1678..=2261).contains(&chrono::Datelike::year(&ndt)) {
+        AnyValue::Datetime(ndt.timestamp_nanos(), TimeUnit::Nanoseconds, time_zone)
+    } else {
+        AnyValue::Datetime(ndt.timestamp_micros(), TimeUnit::Microseconds, time_zone)
+    }
+}
+
+impl<'a> AnyValue<'a> {
+    /// The date of a `Date`, or of a `Datetime` in UTC.
+    pub fn as_naive_date(&self) -> Option<NaiveDate> {
+        match self {
+            #[cfg(feature = "dtype-date")]
+            AnyValue::Date(v) => Some(date32_to_date(*v)),
+            #[cfg(feature = "dtype-datetime")]
+            AnyValue::Datetime(..) => self.as_naive_datetime().map(|ndt| ndt.date()),
+            _ => None,
+        }
+    }
+
+    /// A `Datetime` in UTC, or a `Date` at midnight.
+    pub fn as_naive_datetime(&self) -> Option<NaiveDateTime> {
+        match self {
+            #[cfg(feature = "dtype-date")]
+            AnyValue::Date(_) => Some(NaiveDateTime::from(self)),
+            #[cfg(feature = "dtype-datetime")]
+            AnyValue::Datetime(..) => Some(NaiveDateTime::from(self)),
+            _ => None,
+        }
+    }
+
+    /// A tz-aware `Datetime` in its time zone.
+    #[cfg(all(feature = "timezones", feature = "dtype-datetime"))]
+    pub fn as_datetime_tz(&self) -> Option<DateTime<Tz>> {
+        match self {
+            AnyValue::Datetime(_, _, Some(tz)) => {
+                let tz = tz.parse::<Tz>().ok()?;
+                Some(tz.from_utc_datetime(&self.as_naive_datetime()?))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_naive_time(&self) -> Option<NaiveTime> {
+        match self {
+            #[cfg(feature = "dtype-time")]
+            AnyValue::Time(v) => Some(time64ns_to_time(*v)),
+            _ => None,
+        }
+    }
+
+    pub fn as_chrono_duration(&self) -> Option<ChronoDuration> {
+        match self {
+            #[cfg(feature = "dtype-duration")]
+            AnyValue::Duration(v, tu) => Some(match tu {
+                TimeUnit::Nanoseconds => ChronoDuration::nanoseconds(*v),
+                TimeUnit::Microseconds => ChronoDuration::microseconds(*v),
+                TimeUnit::Milliseconds => ChronoDuration::milliseconds(*v),
+            }),
+            _ => None,
+        }
+    }
+
+    /// A tz-aware `Datetime` of `dt`. `time_zone` is the name of the time zone of `dt`.
+    #[cfg(all(feature = "timezones", feature = "dtype-datetime"))]
+    pub fn from_datetime_tz<T: chrono::TimeZone>(
+        dt: &DateTime<T>,
+        time_zone: &'a Option<TimeZone>,
+    ) -> Self {
+        datetime_to_any_value(dt.naive_utc(), time_zone)
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl From<NaiveDate> for AnyValue<'_> {
+    fn from(v: NaiveDate) -> Self {
+        AnyValue::Date(naive_date_to_date(v))
+    }
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl From<NaiveDateTime> for AnyValue<'_> {
+    fn from(v: NaiveDateTime) -> Self {
+        datetime_to_any_value(v, &None)
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl From<NaiveTime> for AnyValue<'_> {
+    fn from(v: NaiveTime) -> Self {
+        AnyValue::Time(time_to_time64ns(&v))
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl From<ChronoDuration> for AnyValue<'_> {
+    fn from(v: ChronoDuration) -> Self {
+        // a chrono duration always fits in milliseconds
+        if let Some(ns) = v.num_nanoseconds() {
+            AnyValue::Duration(ns, TimeUnit::Nanoseconds)
+        } else if let Some(us) = v.num_microseconds() {
+            AnyValue::Duration(us, TimeUnit::Microseconds)
+        } else {
+            AnyValue::Duration(v.num_milliseconds(), TimeUnit::Milliseconds)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+fn naive_to_time_date(d: NaiveDate) -> Option<time::Date> {
+    let month = time::Month::try_from(d.month() as u8).ok()?;
+    time::Date::from_calendar_date(d.year(), month, d.day() as u8).ok()
+}
+
+#[cfg(feature = "time")]
+fn naive_to_time_time(t: NaiveTime) -> Option<time::Time> {
+    time::Time::from_hms_nano(
+        t.hour() as u8,
+        t.minute() as u8,
+        t.second() as u8,
+        t.nanosecond(),
+    )
+    .ok()
+}
+
+#[cfg(feature = "time")]
+fn time_date_to_naive(d: time::Date) -> NaiveDate {
+    NaiveDate::from_ymd_opt(d.year(), d.month() as u32, d.day() as u32).unwrap()
+}
+
+#[cfg(feature = "time")]
+fn time_time_to_naive(t: time::Time) -> NaiveTime {
+    NaiveTime::from_hms_nano_opt(
+        t.hour() as u32,
+        t.minute() as u32,
+        t.second() as u32,
+        t.nanosecond(),
+    )
+    .unwrap()
+}
+
+#[cfg(feature = "time")]
+impl<'a> AnyValue<'a> {
+    /// The date of a `Date`, or of a `Datetime` in UTC.
+    pub fn as_time_date(&self) -> Option<time::Date> {
+        naive_to_time_date(self.as_naive_date()?)
+    }
+
+    /// A `Datetime` in UTC, or a `Date` at midnight.
+    pub fn as_primitive_date_time(&self) -> Option<time::PrimitiveDateTime> {
+        let ndt = self.as_naive_datetime()?;
+        Some(time::PrimitiveDateTime::new(
+            naive_to_time_date(ndt.date())?,
+            naive_to_time_time(ndt.time())?,
+        ))
+    }
+
+    /// A tz-aware `Datetime` with the UTC offset of its time zone at that instant.
+    #[cfg(all(feature = "timezones", feature = "dtype-datetime"))]
+    pub fn as_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        use chrono::Offset;
+
+        let dt = self.as_datetime_tz()?;
+        let offset =
+            time::UtcOffset::from_whole_seconds(dt.offset().fix().local_minus_utc()).ok()?;
+        let local = dt.naive_local();
+        Some(
+            time::PrimitiveDateTime::new(
+                naive_to_time_date(local.date())?,
+                naive_to_time_time(local.time())?,
+            )
+            .assume_offset(offset),
+        )
+    }
+
+    pub fn as_time_time(&self) -> Option<time::Time> {
+        naive_to_time_time(self.as_naive_time()?)
+    }
+
+    pub fn as_time_duration(&self) -> Option<time::Duration> {
+        match self {
+            #[cfg(feature = "dtype-duration")]
+            AnyValue::Duration(v, tu) => Some(match tu {
+                TimeUnit::Nanoseconds => time::Duration::nanoseconds(*v),
+                TimeUnit::Microseconds => time::Duration::microseconds(*v),
+                TimeUnit::Milliseconds => time::Duration::milliseconds(*v),
+            }),
+            _ => None,
+        }
+    }
+
+    /// A tz-aware `Datetime` of `dt`. `time_zone` is the name of the time zone of `dt`.
+    #[cfg(feature = "dtype-datetime")]
+    pub fn from_offset_date_time(
+        dt: time::OffsetDateTime,
+        time_zone: &'a Option<TimeZone>,
+    ) -> Self {
+        let utc = dt.to_offset(time::UtcOffset::UTC);
+        let ndt = NaiveDateTime::new(
+            time_date_to_naive(utc.date()),
+            time_time_to_naive(utc.time()),
+        );
+        datetime_to_any_value(ndt, time_zone)
+    }
+}
+
+#[cfg(all(feature = "time", feature = "dtype-date"))]
+impl From<time::Date> for AnyValue<'_> {
+    fn from(v: time::Date) -> Self {
+        time_date_to_naive(v).into()
+    }
+}
+
+#[cfg(all(feature = "time", feature = "dtype-datetime"))]
+impl From<time::PrimitiveDateTime> for AnyValue<'_> {
+    fn from(v: time::PrimitiveDateTime) -> Self {
+        NaiveDateTime::new(time_date_to_naive(v.date()), time_time_to_naive(v.time())).into()
+    }
+}
+
+#[cfg(all(feature = "time", feature = "dtype-time"))]
+impl From<time::Time> for AnyValue<'_> {
+    fn from(v: time::Time) -> Self {
+        time_time_to_naive(v).into()
+    }
+}
+
+#[cfg(all(feature = "time", feature = "dtype-duration"))]
+impl From<time::Duration> for AnyValue<'_> {
+    fn from(v: time::Duration) -> Self {
+        if let Ok(ns) = i64::try_from(v.whole_nanoseconds()) {
+            AnyValue::Duration(ns, TimeUnit::Nanoseconds)
+        } else if let Ok(us) = i64::try_from(v.whole_microseconds()) {
+            AnyValue::Duration(us, TimeUnit::Microseconds)
+        } else {
+            // saturate the durations that don't fit in milliseconds either
+            let ms = v
+                .whole_milliseconds()
+                .clamp(i64::MIN as i128, i64::MAX as i128);
+            AnyValue::Duration(ms as i64, TimeUnit::Milliseconds)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time",
+    feature = "dtype-duration"
+))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chrono_any_value() {
+        let ndt = NaiveDate::from_ymd_opt(2023, 5, 17)
+            .unwrap()
+            .and_hms_nano_opt(13, 45, 1, 123_456_789)
+            .unwrap();
+        let av = AnyValue::from(ndt);
+        assert!(matches!(
+            av,
+            AnyValue::Datetime(_, TimeUnit::Nanoseconds, None)
+        ));
+        assert_eq!(av.as_naive_datetime(), Some(ndt));
+        assert_eq!(av.as_naive_date(), Some(ndt.date()));
+        assert_eq!(AnyValue::from(ndt.date()).as_naive_date(), Some(ndt.date()));
+        assert_eq!(AnyValue::from(ndt.time()).as_naive_time(), Some(ndt.time()));
+        // out of the nanosecond range
+        let far = NaiveDate::from_ymd_opt(3000, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(AnyValue::from(far).as_naive_datetime(), Some(far));
+        // at the bounds of the nanosecond range
+        for (y, m, d, unit) in [
+            (1677, 9, 22, TimeUnit::Microseconds),
+            (1678, 1, 1, TimeUnit::Nanoseconds),
+            (2261, 12, 31, TimeUnit::Nanoseconds),
+            (2262, 4, 10, TimeUnit::Microseconds),
+        ] {
+            let ndt = NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_nano_opt(23, 59, 59, 999_999_000)
+                .unwrap();
+            let av = AnyValue::from(ndt);
+            assert!(matches!(av, AnyValue::Datetime(_, tu, None) if tu == unit));
+            assert_eq!(av.as_naive_datetime(), Some(ndt));
+        }
+
+        let duration = ChronoDuration::milliseconds(-1500);
+        assert_eq!(
+            AnyValue::from(duration).as_chrono_duration(),
+            Some(duration)
+        );
+        // too long for nanoseconds and microseconds
+        let duration = ChronoDuration::milliseconds(-i64::MAX);
+        assert_eq!(
+            AnyValue::from(duration),
+            AnyValue::Duration(-i64::MAX, TimeUnit::Milliseconds)
+        );
+        let ca = DurationChunked::from_duration("a", [duration], TimeUnit::Microseconds);
+        assert_eq!(ca.get(0), Some(i64::MIN));
+        assert_eq!(AnyValue::Int32(1).as_naive_date(), None);
+        assert_eq!(AnyValue::Null.as_naive_time(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "timezones", feature = "dtype-datetime"))]
+    fn test_chrono_any_value_tz() {
+        use chrono::TimeZone as _;
+
+        let tz = Some("Europe/Amsterdam".to_string());
+        let dt = "Europe/Amsterdam"
+            .parse::<Tz>()
+            .unwrap()
+            .with_ymd_and_hms(2023, 7, 1, 12, 0, 0)
+            .unwrap();
+        let av = AnyValue::from_datetime_tz(&dt, &tz);
+        assert_eq!(av.as_datetime_tz(), Some(dt));
+        assert_eq!(av.as_naive_datetime(), Some(dt.naive_utc()));
+        // tz-naive
+        assert_eq!(AnyValue::from(dt.naive_utc()).as_datetime_tz(), None);
+    }
+}
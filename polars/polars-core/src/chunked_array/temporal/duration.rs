@@ -2,6 +2,20 @@ use crate::export::chrono::Duration as ChronoDuration;
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
 
+/// `v` in the time unit `tu`, saturated at the bounds of an `i64`.
+fn chrono_duration_to_int(v: ChronoDuration, tu: TimeUnit) -> i64 {
+    let out = match tu {
+        TimeUnit::Nanoseconds => v.num_nanoseconds(),
+        TimeUnit::Microseconds => v.num_microseconds(),
+        TimeUnit::Milliseconds => Some(v.num_milliseconds()),
+    };
+    out.unwrap_or(if v < ChronoDuration::zero() {
+        i64::MIN
+    } else {
+        i64::MAX
+    })
+}
+
 impl DurationChunked {
     pub fn time_unit(&self) -> TimeUnit {
         match self.2.as_ref().unwrap() {
@@ -60,17 +74,14 @@ impl DurationChunked {
         self.2 = Some(Duration(tu))
     }
 
-    /// Construct a new [`DurationChunked`] from an iterator over [`ChronoDuration`].
+    /// Construct a new [`DurationChunked`] from an iterator over [`ChronoDuration`]. Durations
+    /// that don't fit in `tu` saturate.
     pub fn from_duration<I: IntoIterator<Item = ChronoDuration>>(
         name: &str,
         v: I,
         tu: TimeUnit,
     ) -> Self {
-        let func = match tu {
-            TimeUnit::Nanoseconds => |v: ChronoDuration| v.num_nanoseconds().unwrap(),
-            TimeUnit::Microseconds => |v: ChronoDuration| v.num_microseconds().unwrap(),
-            TimeUnit::Milliseconds => |v: ChronoDuration| v.num_milliseconds(),
-        };
+        let func = |v| chrono_duration_to_int(v, tu);
         let vals = v.into_iter().map(func).collect::<Vec<_>>();
         Int64Chunked::from_vec(name, vals).into_duration(tu)
     }
@@ -81,11 +92,7 @@ impl DurationChunked {
         v: I,
         tu: TimeUnit,
     ) -> Self {
-        let func = match tu {
-            TimeUnit::Nanoseconds => |v: ChronoDuration| v.num_nanoseconds().unwrap(),
-            TimeUnit::Microseconds => |v: ChronoDuration| v.num_microseconds().unwrap(),
-            TimeUnit::Milliseconds => |v: ChronoDuration| v.num_milliseconds(),
-        };
+        let func = |v| chrono_duration_to_int(v, tu);
         let vals = v.into_iter().map(|opt| opt.map(func));
         Int64Chunked::from_iter_options(name, vals).into_duration(tu)
     }
@@ -1,4 +1,5 @@
 //! Traits and utilities for temporal data.
+mod any_value;
 pub mod conversion;
 #[cfg(feature = "dtype-date")]
 mod date;
@@ -26,6 +26,7 @@ impl<T: PolarsDataType> Default for ChunkedArray<T> {
             chunks: Default::default(),
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length: 0,
         }
     }
@@ -335,6 +336,7 @@ impl<T: PolarsObject> FromIterator<Option<T>> for ObjectChunked<T> {
             chunks: vec![arr],
             phantom: PhantomData,
             bit_settings: Default::default(),
+            hash_cache: None,
             length: 0,
         };
         out.compute_len();
@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use std::time::Duration;
 
 #[cfg(feature = "aws")]
 use object_store::aws::AmazonS3Builder;
@@ -14,6 +15,8 @@ use object_store::gcp::GoogleCloudStorageBuilder;
 pub use object_store::gcp::GoogleConfigKey;
 #[cfg(feature = "async")]
 use object_store::ObjectStore;
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+use object_store::{BackoffConfig, ClientOptions, RetryConfig};
 use polars_error::{polars_bail, polars_err};
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
@@ -31,6 +34,44 @@ use crate::error::{PolarsError, PolarsResult};
 #[allow(dead_code)]
 type Configs<T> = Vec<(T, String)>;
 
+/// The default size of the parts of a multipart upload.
+pub const DEFAULT_UPLOAD_PART_SIZE: usize = 10 * 1024 * 1024;
+/// The default number of parts of a multipart upload that are in flight at the same time.
+pub const DEFAULT_MAX_UPLOAD_CONCURRENCY: usize = 8;
+
+/// How failed requests to cloud storage are retried.
+///
+/// Requests that fail because of a connection error or a server error are
+/// retried, requests that are rejected (e.g. a missing object or invalid
+/// credentials) fail immediately. The wait before a retry starts at
+/// `init_backoff` and grows by a factor `backoff_base` up to `max_backoff`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct CloudRetryConfig {
+    /// The maximum number of retries of a request.
+    pub max_retries: usize,
+    /// Stop retrying a request if it was first sent longer than this ago.
+    pub retry_timeout: Duration,
+    /// The wait before the first retry.
+    pub init_backoff: Duration,
+    /// The maximum wait between two retries.
+    pub max_backoff: Duration,
+    /// The factor by which the wait grows after every retry.
+    pub backoff_base: f64,
+}
+
+impl Default for CloudRetryConfig {
+    fn default() -> Self {
+        CloudRetryConfig {
+            max_retries: 10,
+            retry_timeout: Duration::from_secs(3 * 60),
+            init_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(15),
+            backoff_base: 2.0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 /// Options to connect to various cloud providers.
@@ -41,6 +82,12 @@ pub struct CloudOptions {
     azure: Option<Configs<AzureConfigKey>>,
     #[cfg(feature = "gcp")]
     gcp: Option<Configs<GoogleConfigKey>>,
+    upload_part_size: Option<usize>,
+    max_upload_concurrency: Option<usize>,
+    retry: Option<CloudRetryConfig>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
 }
 
 #[allow(dead_code)]
@@ -79,7 +126,7 @@ impl FromStr for CloudType {
         Ok(match parsed.scheme() {
             "s3" => Self::Aws,
             "az" | "adl" | "abfs" => Self::Azure,
-            "gs" | "gcp" => Self::Gcp,
+            "gs" | "gcs" | "gcp" => Self::Gcp,
             "file" => Self::File,
             _ => polars_bail!(ComputeError: "unknown url scheme"),
         })
@@ -92,6 +139,100 @@ impl FromStr for CloudType {
 }
 
 impl CloudOptions {
+    /// Set the size in bytes of the parts of multipart uploads, which must be at least 5 MiB.
+    /// The written bytes are buffered until a part is full, so every part but the last one has
+    /// this size. Defaults to [`DEFAULT_UPLOAD_PART_SIZE`].
+    pub fn with_upload_part_size(mut self, part_size: usize) -> Self {
+        self.upload_part_size = Some(part_size);
+        self
+    }
+
+    /// Set the number of parts of a multipart upload that are handed to the object store before
+    /// the writer waits until all of them are uploaded, which bounds the memory of the parts in
+    /// flight. The object store uploads at most 8 of them at the same time. Defaults to
+    /// [`DEFAULT_MAX_UPLOAD_CONCURRENCY`].
+    pub fn with_max_upload_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_upload_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// The size of the parts of multipart uploads.
+    pub fn upload_part_size(&self) -> usize {
+        self.upload_part_size.unwrap_or(DEFAULT_UPLOAD_PART_SIZE)
+    }
+
+    /// The number of parts of a multipart upload that are handed to the object store before the
+    /// writer waits until they are uploaded.
+    pub fn max_upload_concurrency(&self) -> usize {
+        self.max_upload_concurrency
+            .unwrap_or(DEFAULT_MAX_UPLOAD_CONCURRENCY)
+    }
+
+    /// Set how failed requests are retried.
+    pub fn with_retry(mut self, retry: CloudRetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Set the timeout of a single request, from connecting until the response body
+    /// has been read. Requests that time out are retried.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for connecting to the cloud provider.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of idle connections per host that are kept in the
+    /// connection pool.
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How failed requests are retried.
+    pub fn retry(&self) -> CloudRetryConfig {
+        self.retry.clone().unwrap_or_default()
+    }
+
+    #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+    fn retry_config(&self) -> RetryConfig {
+        let retry = self.retry();
+        RetryConfig {
+            backoff: BackoffConfig {
+                init_backoff: retry.init_backoff,
+                max_backoff: retry.max_backoff,
+                base: retry.backoff_base,
+            },
+            max_retries: retry.max_retries,
+            retry_timeout: retry.retry_timeout,
+        }
+    }
+
+    /// The HTTP client options of the timeouts and the connection pool that were set.
+    ///
+    /// These are given to the object store builder before the config keys, so a config key
+    /// that configures the HTTP client as well (e.g. allowing plain HTTP) takes precedence
+    /// over these settings. Settings that were not set keep the default of `object_store`.
+    #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+    fn client_options(&self) -> ClientOptions {
+        let mut options = ClientOptions::new();
+        if let Some(timeout) = self.timeout {
+            options = options.with_timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            options = options.with_connect_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            options = options.with_pool_max_idle_per_host(max);
+        }
+        options
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -107,16 +248,27 @@ impl CloudOptions {
         self
     }
 
-    /// Build the ObjectStore implementation for AWS.
+    /// The builder of the ObjectStore implementation for AWS. See [`CloudOptions::client_options`]
+    /// for the precedence of the config keys over the client settings.
     #[cfg(feature = "aws")]
-    pub fn build_aws(&self, bucket_name: &str) -> PolarsResult<impl ObjectStore> {
+    fn aws_builder(&self, bucket_name: &str) -> PolarsResult<AmazonS3Builder> {
         let options = self
             .aws
             .as_ref()
             .ok_or_else(|| polars_err!(ComputeError: "`aws` configuration missing"))?;
         AmazonS3Builder::new()
+            .with_client_options(self.client_options())
+            .with_retry(self.retry_config())
             .try_with_options(options.clone().into_iter())
-            .and_then(|b| b.with_bucket_name(bucket_name).build())
+            .map(|b| b.with_bucket_name(bucket_name))
+            .map_err(polars_error::to_compute_err)
+    }
+
+    /// Build the ObjectStore implementation for AWS.
+    #[cfg(feature = "aws")]
+    pub fn build_aws(&self, bucket_name: &str) -> PolarsResult<impl ObjectStore> {
+        self.aws_builder(bucket_name)?
+            .build()
             .map_err(polars_error::to_compute_err)
     }
 
@@ -135,16 +287,27 @@ impl CloudOptions {
         self
     }
 
-    /// Build the ObjectStore implementation for Azure.
+    /// The builder of the ObjectStore implementation for Azure. See [`CloudOptions::client_options`]
+    /// for the precedence of the config keys over the client settings.
     #[cfg(feature = "azure")]
-    pub fn build_azure(&self, container_name: &str) -> PolarsResult<impl ObjectStore> {
+    fn azure_builder(&self, container_name: &str) -> PolarsResult<MicrosoftAzureBuilder> {
         let options = self
             .azure
             .as_ref()
             .ok_or_else(|| polars_err!(ComputeError: "`azure` configuration missing"))?;
         MicrosoftAzureBuilder::new()
+            .with_client_options(self.client_options())
+            .with_retry(self.retry_config())
             .try_with_options(options.clone().into_iter())
-            .and_then(|b| b.with_container_name(container_name).build())
+            .map(|b| b.with_container_name(container_name))
+            .map_err(polars_error::to_compute_err)
+    }
+
+    /// Build the ObjectStore implementation for Azure.
+    #[cfg(feature = "azure")]
+    pub fn build_azure(&self, container_name: &str) -> PolarsResult<impl ObjectStore> {
+        self.azure_builder(container_name)?
+            .build()
             .map_err(polars_error::to_compute_err)
     }
 
@@ -163,16 +326,27 @@ impl CloudOptions {
         self
     }
 
-    /// Build the ObjectStore implementation for GCP.
+    /// The builder of the ObjectStore implementation for GCP. See [`CloudOptions::client_options`]
+    /// for the precedence of the config keys over the client settings.
     #[cfg(feature = "gcp")]
-    pub fn build_gcp(&self, bucket_name: &str) -> PolarsResult<impl ObjectStore> {
+    fn gcp_builder(&self, bucket_name: &str) -> PolarsResult<GoogleCloudStorageBuilder> {
         let options = self
             .gcp
             .as_ref()
             .ok_or_else(|| polars_err!(ComputeError: "`gcp` configuration missing"))?;
         GoogleCloudStorageBuilder::new()
+            .with_client_options(self.client_options())
+            .with_retry(self.retry_config())
             .try_with_options(options.clone().into_iter())
-            .and_then(|b| b.with_bucket_name(bucket_name).build())
+            .map(|b| b.with_bucket_name(bucket_name))
+            .map_err(polars_error::to_compute_err)
+    }
+
+    /// Build the ObjectStore implementation for GCP.
+    #[cfg(feature = "gcp")]
+    pub fn build_gcp(&self, bucket_name: &str) -> PolarsResult<impl ObjectStore> {
+        self.gcp_builder(bucket_name)?
+            .build()
             .map_err(polars_error::to_compute_err)
     }
 
@@ -220,3 +394,67 @@ impl CloudOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_untyped_config() {
+        let options = CloudOptions::from_untyped_config(
+            "s3://bucket/file.parquet",
+            [("aws_region", "eu-west-1")],
+        )
+        .unwrap();
+        assert_eq!(
+            options.aws,
+            Some(vec![(AmazonS3ConfigKey::Region, "eu-west-1".to_string())])
+        );
+        assert!(CloudOptions::from_untyped_config(
+            "s3://bucket/file.parquet",
+            [("not_a_key", "x")]
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+    fn test_client_options() {
+        // settings that were not set keep the defaults of `object_store`
+        assert_eq!(
+            format!("{:?}", CloudOptions::default().client_options()),
+            format!("{:?}", ClientOptions::new())
+        );
+        let options = CloudOptions::default()
+            .with_timeout(Duration::from_secs(5))
+            .with_pool_max_idle_per_host(2);
+        assert_eq!(
+            format!("{:?}", options.client_options()),
+            format!(
+                "{:?}",
+                ClientOptions::new()
+                    .with_timeout(Duration::from_secs(5))
+                    .with_pool_max_idle_per_host(2)
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_client_options_precedence() -> PolarsResult<()> {
+        let options = CloudOptions::default()
+            .with_aws([
+                (AmazonS3ConfigKey::Region, "eu-west-1"),
+                (AmazonS3ConfigKey::Endpoint, "http://localhost:9000"),
+            ])
+            .with_timeout(Duration::from_secs(5));
+        // the config keys are applied after the client settings, neither of them is lost
+        let builder = format!("{:?}", options.aws_builder("bucket")?);
+        assert!(builder.contains("http://localhost:9000"));
+        assert!(builder.contains("eu-west-1"));
+        assert!(builder.contains("timeout: Some(5s)"));
+        assert!(builder.contains("connect_timeout: None"));
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use crate::prelude::*;
+
 // Formatting environment variables (typically referenced/set from the python-side Config object)
 pub(crate) const FMT_MAX_COLS: &str = "POLARS_FMT_MAX_COLS";
 pub(crate) const FMT_MAX_ROWS: &str = "POLARS_FMT_MAX_ROWS";
@@ -29,3 +34,151 @@ pub(crate) fn decimal_is_active() -> bool {
 pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("0") == "1"
 }
+
+pub(crate) const TEMP_DIR: &str = "POLARS_TEMP_DIR";
+
+static TEMP_DIR_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Set the directory that the out-of-core operations of the streaming engine spill to.
+///
+/// This takes precedence over the `POLARS_TEMP_DIR` environment variable. Pass `None` to
+/// fall back to the environment variable again.
+pub fn set_temp_dir(dir: Option<PathBuf>) {
+    *TEMP_DIR_OVERRIDE.write().unwrap() = dir;
+}
+
+/// The directory set with [`set_temp_dir`] or else by the `POLARS_TEMP_DIR` environment
+/// variable, if any.
+pub fn get_temp_dir() -> Option<PathBuf> {
+    TEMP_DIR_OVERRIDE
+        .read()
+        .unwrap()
+        .clone()
+        .or_else(|| std::env::var_os(TEMP_DIR).map(PathBuf::from))
+}
+
+/// The data type of the `sum` of `Int8`, `UInt8`, `Int16` and `UInt16` data.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SumDtype {
+    /// Sum as `Int64`, so that the sum doesn't overflow.
+    #[default]
+    Int64,
+    /// Keep the data type of the input. The sum may overflow.
+    Input,
+}
+
+/// The data type of the `mean` of integers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MeanDtype {
+    #[default]
+    Float64,
+    /// A `Decimal` with `scale` digits after the decimal point, rounded half away from zero.
+    /// Unlike a `Float64`, this is exact for means of large integers.
+    #[cfg(feature = "dtype-decimal")]
+    Decimal { scale: usize },
+}
+
+/// The data types of the aggregations that trade precision for size.
+///
+/// The policy is global, as the planner needs to know the output data types before the
+/// aggregations run. Set it before building a query.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AggDtypePolicy {
+    pub sum: SumDtype,
+    pub mean: MeanDtype,
+}
+
+static AGG_DTYPE_POLICY: RwLock<AggDtypePolicy> = RwLock::new(AggDtypePolicy {
+    sum: SumDtype::Int64,
+    mean: MeanDtype::Float64,
+});
+
+pub fn get_agg_dtype_policy() -> AggDtypePolicy {
+    *AGG_DTYPE_POLICY.read().unwrap()
+}
+
+/// Set the global [`AggDtypePolicy`].
+///
+/// The scale of a `Decimal` mean is at most 18, so that the mean of any 64-bit integers can be
+/// represented.
+pub fn set_agg_dtype_policy(policy: AggDtypePolicy) -> PolarsResult<()> {
+    #[cfg(feature = "dtype-decimal")]
+    if let MeanDtype::Decimal { scale } = policy.mean {
+        if u32::try_from(scale)
+            .ok()
+            .and_then(|scale| 10_i64.checked_pow(scale))
+            .is_none()
+        {
+            polars_bail!(ComputeError: "the scale of a decimal mean can be at most 18, got {}", scale);
+        }
+    }
+    *AGG_DTYPE_POLICY.write().unwrap() = policy;
+    Ok(())
+}
+
+fn is_primitive_integer(dtype: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        dtype,
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+    )
+}
+
+impl AggDtypePolicy {
+    /// The data type of the `sum` of data of type `dtype`.
+    pub fn sum_dtype(&self, dtype: &DataType) -> DataType {
+        use DataType::*;
+        match dtype {
+            Boolean => IDX_DTYPE,
+            Int8 | UInt8 | Int16 | UInt16 if self.sum == SumDtype::Int64 => Int64,
+            // sums may not fit the precision of the input
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, scale) => Decimal(None, *scale),
+            dt => dt.clone(),
+        }
+    }
+
+    /// The data type of the `mean` of data of type `dtype`.
+    pub fn mean_dtype(&self, dtype: &DataType) -> DataType {
+        use DataType::*;
+        match dtype {
+            Float32 => Float32,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, scale) => Decimal(None, *scale),
+            dt if is_primitive_integer(dt) => match self.mean {
+                MeanDtype::Float64 => Float64,
+                #[cfg(feature = "dtype-decimal")]
+                MeanDtype::Decimal { scale } => Decimal(None, Some(scale)),
+            },
+            dt if dt.is_numeric() => Float64,
+            dt => dt.clone(),
+        }
+    }
+
+    /// The scale of the `Decimal` mean of data of type `dtype`, if its mean is a `Decimal`.
+    #[allow(unused_variables)]
+    pub fn mean_decimal_scale(&self, dtype: &DataType) -> Option<usize> {
+        match self.mean {
+            #[cfg(feature = "dtype-decimal")]
+            MeanDtype::Decimal { scale } if is_primitive_integer(dtype) => Some(scale),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir() {
+        let dir = PathBuf::from("/data/polars_spill");
+        set_temp_dir(Some(dir.clone()));
+        assert_eq!(get_temp_dir(), Some(dir));
+        set_temp_dir(None);
+        assert_eq!(
+            get_temp_dir(),
+            std::env::var_os(TEMP_DIR).map(PathBuf::from)
+        );
+    }
+}
@@ -13,7 +13,9 @@ impl<'a> Deserialize<'a> for DataType {
     where
         D: Deserializer<'a>,
     {
-        Ok(SerializableDataType::deserialize(deserializer)?.into())
+        SerializableDataType::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -22,7 +24,7 @@ impl Serialize for DataType {
     where
         S: Serializer,
     {
-        let dt: SerializableDataType = self.into();
+        let dt = SerializableDataType::try_from(self).map_err(serde::ser::Error::custom)?;
         dt.serialize(serializer)
     }
 }
@@ -54,17 +56,29 @@ pub enum SerializableDataType {
     /// A 64-bit time representing elapsed time since midnight in the given TimeUnit.
     Time,
     List(Box<SerializableDataType>),
+    #[cfg(feature = "dtype-array")]
+    Array(Box<SerializableDataType>, usize),
     Null,
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown,
+    /// The global string cache of a categorical is not serialized.
+    #[cfg(feature = "dtype-categorical")]
+    Categorical,
+    #[cfg(feature = "dtype-decimal")]
+    Decimal(Option<usize>, Option<usize>),
+    /// The categories of an enum, in their declared order.
+    #[cfg(feature = "dtype-categorical")]
+    Enum(Vec<String>),
 }
 
-impl From<&DataType> for SerializableDataType {
-    fn from(dt: &DataType) -> Self {
+impl TryFrom<&DataType> for SerializableDataType {
+    type Error = PolarsError;
+
+    fn try_from(dt: &DataType) -> PolarsResult<Self> {
         use DataType::*;
-        match dt {
+        let dt = match dt {
             Boolean => Self::Boolean,
             UInt8 => Self::UInt8,
             UInt16 => Self::UInt16,
@@ -82,19 +96,36 @@ impl From<&DataType> for SerializableDataType {
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
             Time => Self::Time,
-            List(dt) => Self::List(Box::new(dt.as_ref().into())),
+            List(dt) => Self::List(Box::new(dt.as_ref().try_into()?)),
+            #[cfg(feature = "dtype-array")]
+            Array(dt, width) => Self::Array(Box::new(dt.as_ref().try_into()?), *width),
             Null => Self::Null,
             Unknown => Self::Unknown,
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds.clone()),
-            _ => todo!(),
-        }
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(Some(rev_map)) if rev_map.is_enum() => {
+                let categories = rev_map.get_enum_categories().unwrap();
+                Self::Enum(categories.values_iter().map(str::to_string).collect())
+            }
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(_) => Self::Categorical,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => Self::Decimal(*precision, *scale),
+            #[cfg(feature = "object")]
+            Object(_) => {
+                polars_bail!(ComputeError: "serialization of dtype {} is not supported", dt)
+            }
+        };
+        Ok(dt)
     }
 }
-impl From<SerializableDataType> for DataType {
-    fn from(dt: SerializableDataType) -> Self {
+impl TryFrom<SerializableDataType> for DataType {
+    type Error = PolarsError;
+
+    fn try_from(dt: SerializableDataType) -> PolarsResult<Self> {
         use SerializableDataType::*;
-        match dt {
+        let dt = match dt {
             Boolean => Self::Boolean,
             UInt8 => Self::UInt8,
             UInt16 => Self::UInt16,
@@ -112,11 +143,20 @@ impl From<SerializableDataType> for DataType {
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
             Time => Self::Time,
-            List(dt) => Self::List(Box::new((*dt).into())),
+            List(dt) => Self::List(Box::new((*dt).try_into()?)),
+            #[cfg(feature = "dtype-array")]
+            Array(dt, width) => Self::Array(Box::new((*dt).try_into()?), width),
             Null => Self::Null,
             Unknown => Self::Unknown,
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds),
-        }
+            #[cfg(feature = "dtype-categorical")]
+            Categorical => Self::Categorical(None),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => Self::Decimal(precision, scale),
+            #[cfg(feature = "dtype-categorical")]
+            Enum(categories) => Self::new_enum(categories)?,
+        };
+        Ok(dt)
     }
 }
@@ -73,6 +73,9 @@ pub enum AnyValue<'a> {
     Categorical(u32, &'a RevMapping, SyncPtr<Utf8Array<i64>>),
     /// Nested type, contains arrays that are filled with one of the datetypes.
     List(Series),
+    /// Fixed size nested type, contains the values of one element and the width.
+    #[cfg(feature = "dtype-array")]
+    Array(Series, usize),
     #[cfg(feature = "object")]
     /// Can be used to fmt and implements Any, so can be downcasted to the proper value type.
     #[cfg(feature = "object")]
@@ -126,11 +129,82 @@ impl Serialize for AnyValue<'_> {
             AnyValue::BinaryOwned(v) => {
                 serializer.serialize_newtype_variant(name, 14, "BinaryOwned", v)
             }
-            _ => todo!(),
+            #[cfg(feature = "dtype-date")]
+            AnyValue::Date(v) => serializer.serialize_newtype_variant(name, 15, "Date", v),
+            #[cfg(feature = "dtype-datetime")]
+            AnyValue::Datetime(v, tu, tz) => {
+                serializer.serialize_newtype_variant(name, 16, "Datetime", &(v, tu, tz))
+            }
+            #[cfg(feature = "dtype-duration")]
+            AnyValue::Duration(v, tu) => {
+                serializer.serialize_newtype_variant(name, 17, "Duration", &(v, tu))
+            }
+            #[cfg(feature = "dtype-time")]
+            AnyValue::Time(v) => serializer.serialize_newtype_variant(name, 18, "Time", v),
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal(v, scale) => {
+                serializer.serialize_newtype_variant(name, 19, "Decimal", &(v, scale))
+            }
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::Struct(idx, arr, fields) => {
+                let avs = struct_to_avs_static(*idx, arr, fields);
+                serializer.serialize_newtype_variant(name, 20, "StructOwned", &(avs, fields))
+            }
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::StructOwned(payload) => {
+                serializer.serialize_newtype_variant(name, 20, "StructOwned", payload.as_ref())
+            }
+            #[cfg(feature = "dtype-array")]
+            AnyValue::Array(v, width) => {
+                serializer.serialize_newtype_variant(name, 21, "Array", &(v, width))
+            }
+            // the rev-map is not serialized, so a categorical is deserialized as a string
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::Categorical(_, _, _) => {
+                serializer.serialize_newtype_variant(name, 13, "Utf8Owned", self.get_str().unwrap())
+            }
+            #[cfg(feature = "object")]
+            AnyValue::Object(_) | AnyValue::ObjectOwned(_) => Err(serde::ser::Error::custom(
+                "serialization of object values is not supported",
+            )),
         }
     }
 }
 
+/// Get a `'static` reference to `tz`, as [`AnyValue::Datetime`] borrows its time zone.
+///
+/// Every distinct time zone is leaked once. Only the names of the time zone database and
+/// fixed offsets in their canonical form are accepted, so the number of leaks is bounded.
+#[cfg(all(feature = "serde", feature = "dtype-datetime"))]
+fn static_time_zone(tz: Option<TimeZone>) -> PolarsResult<&'static Option<TimeZone>> {
+    static NAIVE: Option<TimeZone> = None;
+
+    let Some(tz) = tz else {
+        return Ok(&NAIVE);
+    };
+    #[cfg(feature = "timezones")]
+    {
+        static TIME_ZONES: once_cell::sync::Lazy<
+            std::sync::Mutex<PlHashMap<TimeZone, &'static Option<TimeZone>>>,
+        > = once_cell::sync::Lazy::new(Default::default);
+
+        let valid = match arrow::temporal_conversions::parse_offset(&tz) {
+            // one spelling per offset
+            Ok(offset) => offset.to_string() == tz,
+            Err(_) => tz.parse::<chrono_tz::Tz>().is_ok(),
+        };
+        polars_ensure!(valid, ComputeError: "unable to parse time zone: '{}'", tz);
+        let mut time_zones = TIME_ZONES.lock().unwrap();
+        Ok(*time_zones
+            .entry(tz.clone())
+            .or_insert_with(|| Box::leak(Box::new(Some(tz)))))
+    }
+    #[cfg(not(feature = "timezones"))]
+    polars_bail!(
+        ComputeError: "deserializing the time zone '{}' requires the 'timezones' feature", tz
+    )
+}
+
 #[cfg(feature = "serde")]
 impl<'a> Deserialize<'a> for AnyValue<'static> {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
@@ -154,6 +228,13 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             Bool,
             Utf8Owned,
             BinaryOwned,
+            Date,
+            Datetime,
+            Duration,
+            Time,
+            Decimal,
+            StructOwned,
+            Array,
         }
         const VARIANTS: &[&str] = &[
             "Null",
@@ -171,8 +252,15 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             "Boolean",
             "Utf8Owned",
             "BinaryOwned",
+            "Date",
+            "Datetime",
+            "Duration",
+            "Time",
+            "Decimal",
+            "StructOwned",
+            "Array",
         ];
-        const LAST: u8 = unsafe { std::mem::transmute::<_, u8>(AvField::BinaryOwned) };
+        const LAST: u8 = unsafe { std::mem::transmute::<_, u8>(AvField::Array) };
 
         struct FieldVisitor;
 
@@ -236,6 +324,13 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                     b"Bool" => AvField::Bool,
                     b"Utf8Owned" | b"Utf8" => AvField::Utf8Owned,
                     b"BinaryOwned" | b"Binary" => AvField::BinaryOwned,
+                    b"Date" => AvField::Date,
+                    b"Datetime" => AvField::Datetime,
+                    b"Duration" => AvField::Duration,
+                    b"Time" => AvField::Time,
+                    b"Decimal" => AvField::Decimal,
+                    b"StructOwned" | b"Struct" => AvField::StructOwned,
+                    b"Array" => AvField::Array,
                     _ => {
                         return Err(serde::de::Error::unknown_variant(
                             &String::from_utf8_lossy(v),
@@ -327,6 +422,50 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                         let value = variant.newtype_variant()?;
                         AnyValue::BinaryOwned(value)
                     }
+                    #[cfg(feature = "dtype-date")]
+                    (AvField::Date, variant) => {
+                        let value = variant.newtype_variant()?;
+                        AnyValue::Date(value)
+                    }
+                    #[cfg(feature = "dtype-datetime")]
+                    (AvField::Datetime, variant) => {
+                        let (value, tu, tz): (i64, TimeUnit, Option<TimeZone>) =
+                            variant.newtype_variant()?;
+                        let tz = static_time_zone(tz).map_err(A::Error::custom)?;
+                        AnyValue::Datetime(value, tu, tz)
+                    }
+                    #[cfg(feature = "dtype-duration")]
+                    (AvField::Duration, variant) => {
+                        let (value, tu) = variant.newtype_variant()?;
+                        AnyValue::Duration(value, tu)
+                    }
+                    #[cfg(feature = "dtype-time")]
+                    (AvField::Time, variant) => {
+                        let value = variant.newtype_variant()?;
+                        AnyValue::Time(value)
+                    }
+                    #[cfg(feature = "dtype-decimal")]
+                    (AvField::Decimal, variant) => {
+                        let (value, scale) = variant.newtype_variant()?;
+                        AnyValue::Decimal(value, scale)
+                    }
+                    #[cfg(feature = "dtype-struct")]
+                    (AvField::StructOwned, variant) => {
+                        let payload = variant.newtype_variant()?;
+                        AnyValue::StructOwned(Box::new(payload))
+                    }
+                    #[cfg(feature = "dtype-array")]
+                    (AvField::Array, variant) => {
+                        let (value, width) = variant.newtype_variant()?;
+                        AnyValue::Array(value, width)
+                    }
+                    #[allow(unreachable_patterns)]
+                    (field, _) => {
+                        return Err(A::Error::custom(format!(
+                            "the dtype of AnyValue variant {} is not enabled",
+                            field as u8
+                        )))
+                    }
                 };
                 Ok(out)
             }
@@ -363,6 +502,8 @@ impl<'a> AnyValue<'a> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _, _) => DataType::Categorical(None),
             List(s) => DataType::List(Box::new(s.dtype().clone())),
+            #[cfg(feature = "dtype-array")]
+            Array(s, width) => DataType::Array(Box::new(s.dtype().clone()), width),
             #[cfg(feature = "dtype-struct")]
             Struct(_, _, fields) => DataType::Struct(fields.to_vec()),
             #[cfg(feature = "dtype-struct")]
@@ -545,6 +686,8 @@ impl<'a> Hash for AnyValue<'a> {
             BinaryOwned(v) => state.write(v),
             Boolean(v) => state.write_u8(*v as u8),
             List(v) => Hash::hash(&Wrap(v.clone()), state),
+            #[cfg(feature = "dtype-array")]
+            Array(v, _) => Hash::hash(&Wrap(v.clone()), state),
             _ => unimplemented!(),
         }
     }
@@ -650,6 +793,8 @@ impl<'a> AnyValue<'a> {
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
             List(v) => List(v),
+            #[cfg(feature = "dtype-array")]
+            Array(v, width) => Array(v, width),
             Utf8(v) => Utf8Owned(v.into()),
             Utf8Owned(v) => Utf8Owned(v),
             Binary(v) => BinaryOwned(v.to_vec()),
@@ -702,6 +847,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Null => true,
             AnyValue::List(s) => s.dtype().is_nested_null(),
+            #[cfg(feature = "dtype-array")]
+            AnyValue::Array(s, _) => s.dtype().is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             AnyValue::Struct(_, _, _) => self._iter_struct_av().all(|av| av.is_nested_null()),
             _ => false,
@@ -745,6 +892,8 @@ impl PartialEq for AnyValue<'_> {
             (Datetime(l, tul, tzl), Datetime(r, tur, tzr)) => l == r && tul == tur && tzl == tzr,
             (Boolean(l), Boolean(r)) => l == r,
             (List(l), List(r)) => l == r,
+            #[cfg(feature = "dtype-array")]
+            (Array(l, width_l), Array(r, width_r)) => width_l == width_r && l == r,
             (Binary(l), Binary(r)) => l == r,
             (Utf8(l), Utf8(r)) => l == r,
             #[cfg(feature = "object")]
@@ -759,6 +908,9 @@ impl PartialEq for AnyValue<'_> {
                 (RevMapping::Local(arr_l), RevMapping::Local(arr_r)) => {
                     std::ptr::eq(arr_l, arr_r) && idx_l == idx_r
                 }
+                (RevMapping::Enum(arr_l), RevMapping::Enum(arr_r)) => {
+                    arr_l == arr_r && idx_l == idx_r
+                }
                 _ => false,
             },
             #[cfg(feature = "dtype-duration")]
@@ -33,6 +33,10 @@ pub enum DataType {
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     List(Box<DataType>),
+    /// Fixed size list: every element has exactly `width` values of the inner
+    /// type, so no offsets have to be stored.
+    #[cfg(feature = "dtype-array")]
+    Array(Box<DataType>, usize),
     #[cfg(feature = "object")]
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
@@ -64,11 +68,24 @@ impl PartialEq for DataType {
         use DataType::*;
         {
             match (self, other) {
-                // Don't include rev maps in comparisons
+                // Don't include rev maps in comparisons, unless they are the fixed categories
+                // of an enum
                 #[cfg(feature = "dtype-categorical")]
-                (Categorical(_), Categorical(_)) => true,
+                (Categorical(l), Categorical(r)) => {
+                    let enum_l = l.as_ref().filter(|rev_map| rev_map.is_enum());
+                    let enum_r = r.as_ref().filter(|rev_map| rev_map.is_enum());
+                    match (enum_l, enum_r) {
+                        (None, None) => true,
+                        (Some(l), Some(r)) => l.same_src(r),
+                        _ => false,
+                    }
+                }
                 (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r)) => tu_l == tu_r && tz_l == tz_r,
                 (List(left_inner), List(right_inner)) => left_inner == right_inner,
+                #[cfg(feature = "dtype-array")]
+                (Array(left_inner, left_width), Array(right_inner, right_width)) => {
+                    left_width == right_width && left_inner == right_inner
+                }
                 #[cfg(feature = "dtype-duration")]
                 (Duration(tu_l), Duration(tu_r)) => tu_l == tu_r,
                 #[cfg(feature = "object")]
@@ -102,11 +119,38 @@ impl DataType {
         }
     }
 
+    /// Create the dtype of an enum: a categorical with the fixed `categories`, which compare
+    /// and sort in the given order. Casting a value that is not a category gives a null, or an
+    /// error with a strict cast.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn new_enum<I, S>(categories: I) -> PolarsResult<DataType>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let categories = Utf8Array::<i64>::from_iter_values(categories.into_iter());
+        let rev_map = RevMapping::new_enum(categories)?;
+        Ok(DataType::Categorical(Some(Arc::new(rev_map))))
+    }
+
+    /// Check if this is the dtype of an enum, see [`DataType::new_enum`].
+    pub fn is_enum(&self) -> bool {
+        #[cfg(feature = "dtype-categorical")]
+        {
+            matches!(self, DataType::Categorical(Some(rev_map)) if rev_map.is_enum())
+        }
+        #[cfg(not(feature = "dtype-categorical"))]
+        {
+            false
+        }
+    }
+
     pub fn inner_dtype(&self) -> Option<&DataType> {
-        if let DataType::List(inner) = self {
-            Some(inner)
-        } else {
-            None
+        match self {
+            DataType::List(inner) => Some(inner),
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner, _) => Some(inner),
+            _ => None,
         }
     }
 
@@ -122,6 +166,8 @@ impl DataType {
             #[cfg(feature = "dtype-categorical")]
             Categorical(_) => UInt32,
             List(dt) => List(Box::new(dt.to_physical())),
+            #[cfg(feature = "dtype-array")]
+            Array(dt, width) => Array(Box::new(dt.to_physical()), *width),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
                 let new_fields = fields
@@ -166,6 +212,8 @@ impl DataType {
             | DataType::Unknown
             | DataType::Null => false,
             DataType::Binary => false,
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(_, _) => false,
             #[cfg(feature = "object")]
             DataType::Object(_) => false,
             #[cfg(feature = "dtype-categorical")]
@@ -233,14 +281,19 @@ impl DataType {
                 dt.to_arrow(),
                 true,
             ))),
+            #[cfg(feature = "dtype-array")]
+            Array(dt, width) => ArrowDataType::FixedSizeList(
+                Box::new(arrow::datatypes::Field::new("item", dt.to_arrow(), true)),
+                *width,
+            ),
             Null => ArrowDataType::Null,
             #[cfg(feature = "object")]
             Object(_) => panic!("cannot convert object to arrow"),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => ArrowDataType::Dictionary(
+            Categorical(rev_map) => ArrowDataType::Dictionary(
                 IntegerType::UInt32,
                 Box::new(ArrowDataType::LargeUtf8),
-                false,
+                matches!(rev_map, Some(rev_map) if rev_map.is_enum()),
             ),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
@@ -256,6 +309,8 @@ impl DataType {
         match self {
             Null => true,
             List(field) => field.is_nested_null(),
+            #[cfg(feature = "dtype-array")]
+            Array(field, _) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
             _ => false,
@@ -308,9 +363,13 @@ impl Display for DataType {
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
             DataType::List(tp) => return write!(f, "list[{tp}]"),
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(tp, width) => return write!(f, "array[{tp}, {width}]"),
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rev_map)) if rev_map.is_enum() => "enum",
+            #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => "cat",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
@@ -333,6 +392,11 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             List(Box::new(merged))
         }
+        #[cfg(feature = "dtype-array")]
+        (Array(inner_l, width_l), Array(inner_r, width_r)) if width_l == width_r => {
+            let merged = merge_dtypes(inner_l, inner_r)?;
+            Array(Box::new(merged), *width_l)
+        }
         (left, right) if left == right => left.clone(),
         _ => polars_bail!(ComputeError: "unable to merge datatypes"),
     })
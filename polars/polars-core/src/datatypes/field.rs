@@ -1,15 +1,35 @@
+use std::collections::BTreeMap;
+
 use smartstring::alias::String as SmartString;
 
 use super::*;
 
+/// Key-value metadata of a [`Field`], e.g. the unit or a description of a column.
+///
+/// This is the same type as the metadata of an arrow field, so it is written to and read from
+/// IPC and Parquet files.
+pub type FieldMetadata = BTreeMap<String, String>;
+
 /// Characterizes the name and the [`DataType`] of a column.
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Field {
     pub name: SmartString,
     pub dtype: DataType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: FieldMetadata,
+}
+
+// The metadata only describes the column, so it is not compared. Otherwise the dtypes of
+// structs would differ by the metadata of their fields.
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.dtype == other.dtype
+    }
 }
 
+impl Eq for Field {}
+
 impl Field {
     /// Creates a new `Field`.
     ///
@@ -26,11 +46,38 @@ impl Field {
         Field {
             name: name.into(),
             dtype,
+            metadata: Default::default(),
         }
     }
 
     pub fn from_owned(name: SmartString, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: Default::default(),
+        }
+    }
+
+    /// Sets the metadata of the `Field`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let metadata = FieldMetadata::from([("unit".to_string(), "m/s".to_string())]);
+    /// let f = Field::new("Speed", DataType::Float64).with_metadata(metadata);
+    ///
+    /// assert_eq!(f.metadata()["unit"], "m/s");
+    /// ```
+    pub fn with_metadata(mut self, metadata: FieldMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Returns a reference to the `Field` metadata.
+    #[inline]
+    pub fn metadata(&self) -> &FieldMetadata {
+        &self.metadata
     }
 
     /// Returns a reference to the `Field` name.
@@ -106,6 +153,7 @@ impl Field {
     /// ```
     pub fn to_arrow(&self) -> ArrowField {
         ArrowField::new(self.name.as_str(), self.dtype.to_arrow(), true)
+            .with_metadata(self.metadata.clone())
     }
 }
 
@@ -125,6 +173,8 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             ArrowDataType::LargeList(f) | ArrowDataType::List(f) => DataType::List(Box::new(f.data_type().into())),
+            #[cfg(feature = "dtype-array")]
+            ArrowDataType::FixedSizeList(f, width) => DataType::Array(Box::new(f.data_type().into()), *width),
             ArrowDataType::Date32 => DataType::Date,
             ArrowDataType::Timestamp(tu, tz) => DataType::Datetime(tu.into(), tz.clone()),
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
@@ -157,6 +207,6 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(&f.name, f.data_type().into())
+        Field::new(&f.name, f.data_type().into()).with_metadata(f.metadata.clone())
     }
 }
@@ -119,6 +119,17 @@ impl PolarsDataType for ListType {
     }
 }
 
+#[cfg(feature = "dtype-array")]
+pub struct FixedSizeListType {}
+
+#[cfg(feature = "dtype-array")]
+impl PolarsDataType for FixedSizeListType {
+    fn get_dtype() -> DataType {
+        // null as we cannot no anything without self.
+        DataType::Array(Box::new(DataType::Null), 0)
+    }
+}
+
 #[cfg(feature = "dtype-decimal")]
 pub struct Int128Type {}
 
@@ -151,6 +162,8 @@ impl PolarsSingleType for Utf8Type {}
 impl PolarsSingleType for BinaryType {}
 
 pub type ListChunked = ChunkedArray<ListType>;
+#[cfg(feature = "dtype-array")]
+pub type ArrayChunked = ChunkedArray<FixedSizeListType>;
 pub type BooleanChunked = ChunkedArray<BooleanType>;
 pub type UInt8Chunked = ChunkedArray<UInt8Type>;
 pub type UInt16Chunked = ChunkedArray<UInt16Type>;
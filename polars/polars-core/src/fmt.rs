@@ -789,6 +789,8 @@ impl Display for AnyValue<'_> {
                 write!(f, "\"{s}\"")
             }
             AnyValue::List(s) => write!(f, "{}", s.fmt_list()),
+            #[cfg(feature = "dtype-array")]
+            AnyValue::Array(s, _) => write!(f, "{}", s.fmt_list()),
             #[cfg(feature = "object")]
             AnyValue::Object(v) => write!(f, "{v}"),
             #[cfg(feature = "object")]
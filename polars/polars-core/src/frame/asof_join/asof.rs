@@ -208,6 +208,56 @@ pub(super) fn join_asof_forward<T: PartialOrd + Copy + Debug>(
     out
 }
 
+fn join_asof_nearest_impl<T, F>(left: &[T], right: &[T], in_tolerance: F) -> Vec<Option<IdxSize>>
+where
+    T: PartialOrd + Copy + Debug + Sub<Output = T>,
+    F: Fn(T) -> bool,
+{
+    if right.is_empty() {
+        return vec![None; left.len()];
+    }
+    let mut out = Vec::with_capacity(left.len());
+    // the first right value that is larger than the left value
+    let mut offset = 0;
+
+    for &val_l in left {
+        while offset < right.len() && right[offset] <= val_l {
+            offset += 1;
+        }
+        // on equal distance the backward match wins
+        let (idx, dist) = match (offset.checked_sub(1), right.get(offset)) {
+            (Some(prev), Some(&val_r)) => {
+                let dist_prev = val_l - right[prev];
+                let dist_next = val_r - val_l;
+                if dist_prev <= dist_next {
+                    (prev, dist_prev)
+                } else {
+                    (offset, dist_next)
+                }
+            }
+            (Some(prev), None) => (prev, val_l - right[prev]),
+            (None, _) => (0, right[0] - val_l),
+        };
+        out.push(in_tolerance(dist).then_some(idx as IdxSize));
+    }
+    out
+}
+
+pub(super) fn join_asof_nearest<T: PartialOrd + Copy + Debug + Sub<Output = T>>(
+    left: &[T],
+    right: &[T],
+) -> Vec<Option<IdxSize>> {
+    join_asof_nearest_impl(left, right, |_| true)
+}
+
+pub(super) fn join_asof_nearest_with_tolerance<T: PartialOrd + Copy + Debug + Sub<Output = T>>(
+    left: &[T],
+    right: &[T],
+    tolerance: T,
+) -> Vec<Option<IdxSize>> {
+    join_asof_nearest_impl(left, right, |dist| dist <= tolerance)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -259,4 +309,32 @@ mod test {
         assert_eq!(tuples.len(), a.len());
         assert_eq!(tuples, &[Some(0), Some(0), Some(1), Some(2), None]);
     }
+
+    #[test]
+    fn test_asof_nearest() {
+        let a = [-1, 1, 2, 3, 4, 6, 9];
+        let b = [1, 2, 2, 5];
+
+        let tuples = join_asof_nearest(&a, &b);
+        assert_eq!(tuples.len(), a.len());
+        assert_eq!(
+            tuples,
+            &[
+                Some(0),
+                Some(0),
+                Some(2),
+                Some(2),
+                Some(3),
+                Some(3),
+                Some(3)
+            ]
+        );
+
+        let tuples = join_asof_nearest_with_tolerance(&a, &b, 1);
+        assert_eq!(
+            tuples,
+            &[None, Some(0), Some(2), Some(2), Some(3), Some(3), None]
+        );
+        assert_eq!(join_asof_nearest::<i32>(&a, &[]), &[None; 7]);
+    }
 }
@@ -2,7 +2,6 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::Sub;
 
-use ahash::RandomState;
 use arrow::types::NativeType;
 use num_traits::Zero;
 use rayon::prelude::*;
@@ -15,7 +14,7 @@ use crate::frame::hash_join::_check_categorical_src;
 use crate::frame::hash_join::{
     create_probe_table, get_hash_tbl_threaded_join_partitioned, multiple_keys as mk, prepare_bytes,
 };
-use crate::hashing::{df_rows_to_hashes_threaded_vertical, AsU64};
+use crate::hashing::{bytes_hash_builder, df_rows_to_hashes_threaded_vertical, AsU64};
 use crate::utils::{split_ca, split_df};
 use crate::POOL;
 
@@ -140,6 +139,66 @@ pub(super) unsafe fn join_asof_forward_with_indirection<T: PartialOrd + Copy + D
     (None, offsets.len())
 }
 
+pub(super) unsafe fn join_asof_nearest_with_indirection<
+    T: PartialOrd + Copy + Sub<Output = T> + Debug,
+>(
+    val_l: T,
+    right: &[T],
+    offsets: &[IdxSize],
+    // only there to have the same function signature
+    _: T,
+) -> (Option<IdxSize>, usize) {
+    if offsets.is_empty() {
+        return (None, 0);
+    }
+    // the first right value that is larger than the left value
+    let mut idx = 0;
+    while idx < offsets.len() && *right.get_unchecked(*offsets.get_unchecked(idx) as usize) <= val_l
+    {
+        idx += 1;
+    }
+    let nearest = if idx == 0 {
+        *offsets.get_unchecked(0)
+    } else if idx == offsets.len() {
+        *offsets.get_unchecked(idx - 1)
+    } else {
+        let previous = *offsets.get_unchecked(idx - 1);
+        let next = *offsets.get_unchecked(idx);
+        // on equal distance the backward match wins
+        if val_l - *right.get_unchecked(previous as usize)
+            <= *right.get_unchecked(next as usize) - val_l
+        {
+            previous
+        } else {
+            next
+        }
+    };
+    // the previous value stays in the remaining offsets,
+    // it can still be the nearest for the next left value
+    (Some(nearest), idx.saturating_sub(1))
+}
+
+pub(super) unsafe fn join_asof_nearest_with_indirection_and_tolerance<
+    T: PartialOrd + Copy + Sub<Output = T> + Debug,
+>(
+    val_l: T,
+    right: &[T],
+    offsets: &[IdxSize],
+    tolerance: T,
+) -> (Option<IdxSize>, usize) {
+    let (join_idx, offset) = join_asof_nearest_with_indirection(val_l, right, offsets, tolerance);
+    let join_idx = join_idx.filter(|&idx| {
+        let val_r = *right.get_unchecked(idx as usize);
+        let dist = if val_r > val_l {
+            val_r - val_l
+        } else {
+            val_l - val_r
+        };
+        dist <= tolerance
+    });
+    (join_idx, offset)
+}
+
 // process the group taken by the `by` operation and keep track of the offset.
 // we don't process a group at once but per `index_left` we find the `right_index` and keep track
 // of the offsets we have already processed in a separate hashmap. Then on a next iteration we can
@@ -195,25 +254,18 @@ fn process_group<K, T>(
     }
 }
 
-fn asof_join_by_numeric<T, S>(
-    by_left: &ChunkedArray<S>,
-    by_right: &ChunkedArray<S>,
-    left_asof: &ChunkedArray<T>,
-    right_asof: &ChunkedArray<T>,
+/// The join function with indirection of `strategy`, the tolerance and whether the function
+/// searches forward, in which case it must not fall back to the previous match.
+#[allow(clippy::type_complexity)]
+fn join_asof_fn_with_indirection<T: PolarsNumericType>(
     tolerance: Option<AnyValue<'static>>,
     strategy: AsofStrategy,
-) -> PolarsResult<Vec<Option<IdxSize>>>
-where
-    T: PolarsNumericType,
-    S: PolarsNumericType,
-    S::Native: Hash + Eq + AsU64,
-{
-    #[allow(clippy::type_complexity)]
-    let (join_asof_fn, tolerance, forward): (
-        unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
-        _,
-        _,
-    ) = match (tolerance, strategy) {
+) -> (
+    unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
+    T::Native,
+    bool,
+) {
+    match (tolerance, strategy) {
         (Some(tolerance), AsofStrategy::Backward) => {
             let tol = tolerance.extract::<T::Native>().unwrap();
             (
@@ -234,7 +286,33 @@ where
         (None, AsofStrategy::Forward) => {
             (join_asof_forward_with_indirection, T::Native::zero(), true)
         }
-    };
+        // the nearest match is always found in the remaining offsets,
+        // so like forward it must not fall back to the previous match
+        (Some(tolerance), AsofStrategy::Nearest) => {
+            let tol = tolerance.extract::<T::Native>().unwrap();
+            (join_asof_nearest_with_indirection_and_tolerance, tol, true)
+        }
+        (None, AsofStrategy::Nearest) => {
+            (join_asof_nearest_with_indirection, T::Native::zero(), true)
+        }
+    }
+}
+
+fn asof_join_by_numeric<T, S>(
+    by_left: &ChunkedArray<S>,
+    by_right: &ChunkedArray<S>,
+    left_asof: &ChunkedArray<T>,
+    right_asof: &ChunkedArray<T>,
+    tolerance: Option<AnyValue<'static>>,
+    strategy: AsofStrategy,
+) -> PolarsResult<Vec<Option<IdxSize>>>
+where
+    T: PolarsNumericType,
+    S: PolarsNumericType,
+    S::Native: Hash + Eq + AsU64,
+{
+    let (join_asof_fn, tolerance, forward) =
+        join_asof_fn_with_indirection::<T>(tolerance, strategy);
 
     let left_asof = left_asof.rechunk();
     let err = |_: PolarsError| {
@@ -339,33 +417,8 @@ fn asof_join_by_binary<T>(
 where
     T: PolarsNumericType,
 {
-    #[allow(clippy::type_complexity)]
-    let (join_asof_fn, tolerance, forward): (
-        unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
-        _,
-        _,
-    ) = match (tolerance, strategy) {
-        (Some(tolerance), AsofStrategy::Backward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (
-                join_asof_backward_with_indirection_and_tolerance,
-                tol,
-                false,
-            )
-        }
-        (None, AsofStrategy::Backward) => (
-            join_asof_backward_with_indirection,
-            T::Native::zero(),
-            false,
-        ),
-        (Some(tolerance), AsofStrategy::Forward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (join_asof_forward_with_indirection_and_tolerance, tol, true)
-        }
-        (None, AsofStrategy::Forward) => {
-            (join_asof_forward_with_indirection, T::Native::zero(), true)
-        }
-    };
+    let (join_asof_fn, tolerance, forward) =
+        join_asof_fn_with_indirection::<T>(tolerance, strategy);
 
     let left_asof = left_asof.rechunk();
     let left_asof = left_asof.cont_slice().unwrap();
@@ -377,7 +430,7 @@ where
     let splitted_by_left = split_ca(by_left, n_threads).unwrap();
     let splitted_right = split_ca(by_right, n_threads).unwrap();
 
-    let hb = RandomState::default();
+    let hb = bytes_hash_builder(&[by_left, by_right]);
     let vals_left = prepare_bytes(&splitted_by_left, &hb);
     let vals_right = prepare_bytes(&splitted_right, &hb);
 
@@ -462,33 +515,8 @@ fn asof_join_by_multiple<T>(
 where
     T: PolarsNumericType,
 {
-    #[allow(clippy::type_complexity)]
-    let (join_asof_fn, tolerance, forward): (
-        unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
-        _,
-        _,
-    ) = match (tolerance, strategy) {
-        (Some(tolerance), AsofStrategy::Backward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (
-                join_asof_backward_with_indirection_and_tolerance,
-                tol,
-                false,
-            )
-        }
-        (None, AsofStrategy::Backward) => (
-            join_asof_backward_with_indirection,
-            T::Native::zero(),
-            false,
-        ),
-        (Some(tolerance), AsofStrategy::Forward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (join_asof_forward_with_indirection_and_tolerance, tol, true)
-        }
-        (None, AsofStrategy::Forward) => {
-            (join_asof_forward_with_indirection, T::Native::zero(), true)
-        }
-    };
+    let (join_asof_fn, tolerance, forward) =
+        join_asof_fn_with_indirection::<T>(tolerance, strategy);
     let left_asof = left_asof.rechunk();
     let left_asof = left_asof.cont_slice().unwrap();
 
@@ -963,4 +991,42 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_by_nearest() -> PolarsResult<()> {
+        let a = df![
+            "a" => [ -1,   2,   2,   3,   5,   9],
+            "b" => ["a", "a", "b", "a", "b", "c"]
+        ]?;
+
+        let b = df![
+            "a" => [  1,   2,   3,   4,   6],
+            "b" => ["a", "b", "a", "a", "b"],
+            "right_vals" => [1, 2, 3, 4, 5]
+        ]?;
+
+        let out = a.join_asof_by(&b, "a", "a", ["b"], ["b"], AsofStrategy::Nearest, None)?;
+        let out = out.column("right_vals")?.i32()?;
+        assert_eq!(
+            Vec::from(out),
+            &[Some(1), Some(1), Some(2), Some(3), Some(5), None]
+        );
+
+        let out = a.join_asof_by(
+            &b,
+            "a",
+            "a",
+            ["b"],
+            ["b"],
+            AsofStrategy::Nearest,
+            Some(AnyValue::Int32(1)),
+        )?;
+        let out = out.column("right_vals")?.i32()?;
+        assert_eq!(
+            Vec::from(out),
+            &[None, Some(1), Some(2), Some(3), Some(5), None]
+        );
+
+        Ok(())
+    }
 }
@@ -52,6 +52,9 @@ pub enum AsofStrategy {
     Backward,
     /// selects the first row in the right DataFrame whose ‘on’ key is greater than or equal to the left’s key.
     Forward,
+    /// selects the row in the right DataFrame whose ‘on’ key is nearest to the left’s key.
+    /// On equal distance the backward match is selected.
+    Nearest,
 }
 
 impl<T> ChunkedArray<T>
@@ -88,7 +91,18 @@ where
                 Some(tolerance) => {
                     let tolerance = tolerance.extract::<T::Native>().unwrap();
                     join_asof_backward_with_tolerance(
-                        self.cont_slice().unwrap(),
+                        ca.cont_slice().unwrap(),
+                        other.cont_slice().unwrap(),
+                        tolerance,
+                    )
+                }
+            },
+            AsofStrategy::Nearest => match tolerance {
+                None => join_asof_nearest(ca.cont_slice().unwrap(), other.cont_slice().unwrap()),
+                Some(tolerance) => {
+                    let tolerance = tolerance.extract::<T::Native>().unwrap();
+                    join_asof_nearest_with_tolerance(
+                        ca.cont_slice().unwrap(),
                         other.cont_slice().unwrap(),
                         tolerance,
                     )
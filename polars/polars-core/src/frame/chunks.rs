@@ -13,7 +13,13 @@ impl std::convert::TryFrom<(ArrowChunk, &[ArrowField])> for DataFrame {
             .columns()
             .iter()
             .zip(arg.1)
-            .map(|(arr, field)| Series::try_from((field.name.as_ref(), arr.clone())))
+            .map(|(arr, field)| {
+                let mut s = Series::try_from((field.name.as_ref(), arr.clone()))?;
+                if !field.metadata.is_empty() {
+                    s.set_metadata(field.metadata.clone());
+                }
+                Ok(s)
+            })
             .collect();
 
         DataFrame::new(columns?)
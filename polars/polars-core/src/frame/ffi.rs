@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use arrow::array::{Array, StructArray};
+use arrow::chunk::Chunk;
+use arrow::ffi::{export_iterator, ArrowArrayStream, ArrowArrayStreamReader};
+
+use crate::prelude::*;
+use crate::utils::accumulate_dataframes_vertical_unchecked;
+
+impl DataFrame {
+    /// Import a `DataFrame` from an [Arrow C stream](https://arrow.apache.org/docs/format/CStreamInterface.html),
+    /// e.g. one exported by DuckDB, DataFusion or PyArrow. Every array of the stream must be a
+    /// struct array of which the fields are the columns. The buffers are not copied, except
+    /// for the arrays that are converted to the arrays polars uses, e.g. strings and binaries
+    /// with 32-bit offsets are converted to their large variants.
+    ///
+    /// # Safety
+    /// The stream must be a valid Arrow C stream. A stream that is received as a pointer can
+    /// be moved out with `std::ptr::replace(ptr, ArrowArrayStream::empty())`.
+    pub unsafe fn from_arrow_c_stream(stream: Box<ArrowArrayStream>) -> PolarsResult<Self> {
+        let mut reader = ArrowArrayStreamReader::try_new(stream)?;
+        let fields = match reader.field().data_type() {
+            ArrowDataType::Struct(fields) => fields.clone(),
+            dt => polars_bail!(
+                ComputeError: "expected an arrow stream of struct arrays, got arrays of {:?}", dt
+            ),
+        };
+
+        let mut dfs = vec![];
+        while let Some(array) = reader.next() {
+            let array = array?;
+            let array = array
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .ok_or_else(|| polars_err!(ComputeError: "expected a struct array"))?;
+            polars_ensure!(
+                array.null_count() == 0,
+                ComputeError: "the struct arrays of an arrow stream cannot have null values"
+            );
+            let chunk = Chunk::try_new(array.values().to_vec())?;
+            dfs.push(DataFrame::try_from((chunk, fields.as_slice()))?);
+        }
+        if dfs.is_empty() {
+            let schema = fields.iter().map(Field::from).collect::<Schema>();
+            return Ok(DataFrame::from(&schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    /// Export the `DataFrame` as an [Arrow C stream](https://arrow.apache.org/docs/format/CStreamInterface.html)
+    /// of struct arrays, one for every chunk. The arrays are not copied, unless the chunks of
+    /// the columns are not aligned (see [`DataFrame::should_rechunk`]), then the columns are
+    /// rechunked to a single chunk first.
+    pub fn to_arrow_c_stream(&self) -> ArrowArrayStream {
+        let df = if self.should_rechunk() {
+            let mut df = self.clone();
+            df.as_single_chunk_par();
+            Cow::Owned(df)
+        } else {
+            Cow::Borrowed(self)
+        };
+        let fields = df.schema().to_arrow().fields;
+        let dtype = ArrowDataType::Struct(fields.clone());
+        let arrays = df
+            .iter_chunks()
+            .map(|chunk| {
+                let array: ArrayRef =
+                    Box::new(StructArray::new(dtype.clone(), chunk.into_arrays(), None));
+                Ok(array)
+            })
+            .collect::<Vec<_>>();
+        let field = ArrowField::new("", dtype, false);
+        export_iterator(Box::new(arrays.into_iter()), field)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arrow_c_stream_roundtrip() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i32, 2, 3],
+            "b" => [Some("x"), None, Some("z")],
+        ]?;
+        let other = df.clone();
+        df.vstack_mut(&other)?;
+        assert_eq!(df.n_chunks(), 2);
+
+        let stream = Box::new(df.to_arrow_c_stream());
+        let out = unsafe { DataFrame::from_arrow_c_stream(stream)? };
+        assert_eq!(out.n_chunks(), 2);
+        assert!(out.frame_equal_missing(&df));
+
+        // the chunks are not aligned
+        let mut unaligned = df.head(Some(4));
+        unaligned.with_column(Series::new("a", [1i32, 2, 3, 4]))?;
+        assert!(unaligned.should_rechunk());
+        let stream = Box::new(unaligned.to_arrow_c_stream());
+        let out = unsafe { DataFrame::from_arrow_c_stream(stream)? };
+        assert_eq!(out.n_chunks(), 1);
+        assert!(out.frame_equal_missing(&unaligned));
+
+        let empty = df.head(Some(0));
+        let stream = Box::new(empty.to_arrow_c_stream());
+        let out = unsafe { DataFrame::from_arrow_c_stream(stream)? };
+        assert_eq!(out.schema(), empty.schema());
+        Ok(())
+    }
+}
@@ -148,190 +148,105 @@ where
     }
 }
 
-impl AggList for BooleanChunked {
-    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
-        match groups {
-            GroupsProxy::Idx(groups) => {
-                let mut builder =
-                    ListBooleanChunkedBuilder::new(self.name(), groups.len(), self.len());
-                for idx in groups.all().iter() {
-                    let ca = { self.take_unchecked(idx.into()) };
-                    builder.append(&ca)
+/// Gather the values of all groups with a single `take` (or `slice` if the groups are
+/// consecutive) and build the list offsets in the same pass over the groups.
+///
+/// # Safety
+///
+/// groups should be in bounds
+unsafe fn agg_list_by_take<T>(ca: &ChunkedArray<T>, groups: &GroupsProxy) -> ListChunked
+where
+    T: PolarsDataType,
+    ChunkedArray<T>: ChunkTake,
+{
+    let mut can_fast_explode = true;
+    let mut offsets = Vec::<i64>::with_capacity(groups.len() + 1);
+    let mut length_so_far = 0i64;
+    offsets.push(length_so_far);
+
+    let values = match groups {
+        GroupsProxy::Idx(groups) => {
+            let mut idx = Vec::with_capacity(ca.len());
+            for (_, group) in groups.iter() {
+                if group.is_empty() {
+                    can_fast_explode = false;
                 }
-                builder.finish().into_series()
+                length_so_far += group.len() as i64;
+                idx.extend_from_slice(group);
+                offsets.push_unchecked(length_so_far);
             }
-            GroupsProxy::Slice { groups, .. } => {
-                let mut builder =
-                    ListBooleanChunkedBuilder::new(self.name(), groups.len(), self.len());
-                for [first, len] in groups {
-                    let ca = self.slice(*first as i64, *len as usize);
-                    builder.append(&ca)
+            let idx = IdxCa::from_vec("", idx);
+            ca.take_unchecked((&idx).into())
+        }
+        GroupsProxy::Slice { groups, .. } => {
+            for &[_, len] in groups {
+                if len == 0 {
+                    can_fast_explode = false;
                 }
-                builder.finish().into_series()
+                length_so_far += len as i64;
+                offsets.push_unchecked(length_so_far);
+            }
+            let consecutive = groups.windows(2).all(|w| w[0][0] + w[0][1] == w[1][0]);
+            if consecutive {
+                let first = groups.first().map(|g| g[0]).unwrap_or(0);
+                ca.slice(first as i64, length_so_far as usize)
+            } else {
+                // e.g. overlapping rolling windows
+                let idx = groups
+                    .iter()
+                    .flat_map(|&[first, len]| first..first + len)
+                    .collect::<Vec<_>>();
+                let idx = IdxCa::from_vec("", idx);
+                ca.take_unchecked((&idx).into())
             }
         }
+    };
+    let values = values.rechunk();
+    let values = values.chunks()[0].clone();
+
+    let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+    // Safety:
+    // offsets are monotonically increasing
+    let arr = ListArray::<i64>::new(
+        data_type,
+        Offsets::new_unchecked(offsets).into(),
+        values,
+        None,
+    );
+    let mut listarr = ListChunked::from_chunks(ca.name(), vec![Box::new(arr)]);
+    if can_fast_explode {
+        listarr.set_fast_explode()
     }
+    listarr
 }
 
-impl AggList for Utf8Chunked {
+impl AggList for BooleanChunked {
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
-        // TODO: dispatch via binary
-        match groups {
-            GroupsProxy::Idx(groups) => {
-                let mut builder =
-                    ListUtf8ChunkedBuilder::new(self.name(), groups.len(), self.len());
-                for idx in groups.all().iter() {
-                    let ca = { self.take_unchecked(idx.into()) };
-                    builder.append(&ca)
-                }
-                builder.finish().into_series()
-            }
-            GroupsProxy::Slice { groups, .. } => {
-                let mut builder =
-                    ListUtf8ChunkedBuilder::new(self.name(), groups.len(), self.len());
-                for [first, len] in groups {
-                    let ca = self.slice(*first as i64, *len as usize);
-                    builder.append(&ca)
-                }
-                builder.finish().into_series()
-            }
-        }
+        agg_list_by_take(self, groups).into_series()
     }
 }
 
-impl AggList for BinaryChunked {
+impl AggList for Utf8Chunked {
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
-        match groups {
-            GroupsProxy::Idx(groups) => {
-                let mut builder =
-                    ListBinaryChunkedBuilder::new(self.name(), groups.len(), self.len());
-                for idx in groups.all().iter() {
-                    let ca = { self.take_unchecked(idx.into()) };
-                    builder.append(&ca)
-                }
-                builder.finish().into_series()
-            }
-            GroupsProxy::Slice { groups, .. } => {
-                let mut builder =
-                    ListBinaryChunkedBuilder::new(self.name(), groups.len(), self.len());
-                for [first, len] in groups {
-                    let ca = self.slice(*first as i64, *len as usize);
-                    builder.append(&ca)
-                }
-                builder.finish().into_series()
-            }
-        }
+        agg_list_by_take(self, groups).into_series()
     }
 }
 
-fn agg_list_list<F: Fn(&ListChunked, bool, &mut Vec<i64>, &mut i64, &mut Vec<ArrayRef>) -> bool>(
-    ca: &ListChunked,
-    groups_len: usize,
-    func: F,
-) -> Series {
-    let inner_dtype = ca.inner_dtype();
-    let inner_dtype_physical = inner_dtype.to_physical();
-    let can_fast_explode = true;
-    let mut offsets = Vec::<i64>::with_capacity(groups_len + 1);
-    let mut length_so_far = 0i64;
-    offsets.push(length_so_far);
-
-    let mut list_values = Vec::with_capacity(groups_len);
-
-    let can_fast_explode = func(
-        ca,
-        can_fast_explode,
-        &mut offsets,
-        &mut length_so_far,
-        &mut list_values,
-    );
-    if groups_len == 0 {
-        list_values.push(ca.chunks[0].sliced(0, 0))
-    }
-    let arrays = list_values.iter().map(|arr| &**arr).collect::<Vec<_>>();
-    let list_values: ArrayRef = arrow::compute::concatenate::concatenate(&arrays).unwrap();
-    let data_type = ListArray::<i64>::default_datatype(list_values.data_type().clone());
-    // Safety:
-    // offsets are monotonically increasing
-    let arr = unsafe {
-        Box::new(ListArray::<i64>::new(
-            data_type,
-            Offsets::new_unchecked(offsets).into(),
-            list_values,
-            None,
-        )) as ArrayRef
-    };
-    let mut listarr = unsafe { ListChunked::from_chunks(ca.name(), vec![arr]) };
-    if can_fast_explode {
-        listarr.set_fast_explode()
-    }
-    if inner_dtype_physical != inner_dtype {
-        listarr.to_logical(DataType::List(Box::new(inner_dtype)));
+impl AggList for BinaryChunked {
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        agg_list_by_take(self, groups).into_series()
     }
-    listarr.into_series()
 }
 
 impl AggList for ListChunked {
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
-        match groups {
-            GroupsProxy::Idx(groups) => {
-                let func = |ca: &ListChunked,
-                            mut can_fast_explode: bool,
-                            offsets: &mut Vec<i64>,
-                            length_so_far: &mut i64,
-                            list_values: &mut Vec<ArrayRef>| {
-                    groups.iter().for_each(|(_, idx)| {
-                        let idx_len = idx.len();
-                        if idx_len == 0 {
-                            can_fast_explode = false;
-                        }
-
-                        *length_so_far += idx_len as i64;
-                        // Safety:
-                        // group tuples are in bounds
-                        {
-                            let mut s = ca.take_unchecked(idx.into());
-                            let arr = s.chunks.pop().unwrap();
-                            list_values.push(arr);
-
-                            // Safety:
-                            // we know that offsets has allocated enough slots
-                            offsets.push_unchecked(*length_so_far);
-                        }
-                    });
-                    can_fast_explode
-                };
-
-                agg_list_list(self, groups.len(), func)
-            }
-            GroupsProxy::Slice { groups, .. } => {
-                let func = |ca: &ListChunked,
-                            mut can_fast_explode: bool,
-                            offsets: &mut Vec<i64>,
-                            length_so_far: &mut i64,
-                            list_values: &mut Vec<ArrayRef>| {
-                    groups.iter().for_each(|&[first, len]| {
-                        if len == 0 {
-                            can_fast_explode = false;
-                        }
-
-                        *length_so_far += len as i64;
-                        let mut s = ca.slice(first as i64, len as usize);
-                        let arr = s.chunks.pop().unwrap();
-                        list_values.push(arr);
-
-                        {
-                            // Safety:
-                            // we know that offsets has allocated enough slots
-                            offsets.push_unchecked(*length_so_far);
-                        }
-                    });
-                    can_fast_explode
-                };
-
-                agg_list_list(self, groups.len(), func)
-            }
+        let mut listarr = agg_list_by_take(self, groups);
+        // the arrow arrays only hold the physical inner type
+        let inner_dtype = self.inner_dtype();
+        if inner_dtype.to_physical() != inner_dtype {
+            listarr.to_logical(self.dtype().clone());
         }
+        listarr.into_series()
     }
 }
 
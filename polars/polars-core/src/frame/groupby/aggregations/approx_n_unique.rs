@@ -0,0 +1,350 @@
+use ahash::RandomState;
+
+use super::*;
+use crate::frame::groupby::GroupsIndicator;
+use crate::utils::NoNull;
+
+/// Number of bits of a hash that select the register of a [`HyperLogLog`].
+const PRECISION: u32 = 12;
+const N_REGISTERS: usize = 1 << PRECISION;
+/// A sketch stays sparse while at most this many registers are set. A sparse register
+/// takes 4 bytes, so a sparse sketch is always smaller than a dense one.
+const SPARSE_MAX_LEN: usize = N_REGISTERS / 8;
+/// Groups up to this length count their distinct hashes exactly, which is cheaper
+/// than allocating and estimating a sketch.
+const EXACT_MAX_LEN: usize = 256;
+
+/// The hashes must be equal over calls, so that sketches of different partitions can be merged.
+fn random_state() -> RandomState {
+    RandomState::with_seeds(
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+        0x082e_fa98_ec4e_6c89,
+    )
+}
+
+/// Spread the bits of the hashes, as integers are hashed with a single multiplication.
+#[inline]
+fn mix(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Registers {
+    /// The registers that are set, as `index << 8 | rank`, sorted by index.
+    Sparse(Vec<u32>),
+    Dense(Vec<u8>),
+}
+
+#[inline]
+fn sparse_index(register: u32) -> usize {
+    (register >> 8) as usize
+}
+
+#[inline]
+fn sparse_rank(register: u32) -> u8 {
+    register as u8
+}
+
+/// A HyperLogLog sketch that estimates the number of distinct hashes added to it,
+/// with a standard error of about 1.6%, in constant memory.
+///
+/// A sketch starts with a sparse representation that only holds the registers that are
+/// set, so that the sketches of many small groups stay small.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Registers,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            registers: Registers::Sparse(vec![]),
+        }
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a sketch from the bytes returned by [`HyperLogLog::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut hll = Self::new();
+        hll.merge_bytes(bytes)?;
+        Ok(hll)
+    }
+
+    /// The registers of a dense sketch, or the little-endian registers that are set of a
+    /// sparse sketch.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match &self.registers {
+            Registers::Sparse(registers) => {
+                registers.iter().flat_map(|r| r.to_le_bytes()).collect()
+            }
+            Registers::Dense(registers) => registers.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn add(&mut self, hash: u64) {
+        let hash = mix(hash);
+        let idx = (hash >> (64 - PRECISION)) as usize;
+        // the set bit bounds the rank if all remaining bits are zero
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+        self.set_register(idx, rank)
+    }
+
+    /// Raise register `idx` to `rank`. `idx` must be smaller than [`N_REGISTERS`].
+    #[inline]
+    fn set_register(&mut self, idx: usize, rank: u8) {
+        match &mut self.registers {
+            Registers::Dense(registers) => {
+                let register = unsafe { registers.get_unchecked_mut(idx) };
+                *register = std::cmp::max(*register, rank);
+            }
+            Registers::Sparse(registers) => {
+                let register = (idx as u32) << 8 | rank as u32;
+                match registers.binary_search_by_key(&idx, |r| sparse_index(*r)) {
+                    // the index is equal, so the larger register has the larger rank
+                    Ok(i) => registers[i] = std::cmp::max(registers[i], register),
+                    Err(i) => {
+                        registers.insert(i, register);
+                        if registers.len() > SPARSE_MAX_LEN {
+                            self.densify()
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn densify(&mut self) {
+        if let Registers::Sparse(sparse) = &self.registers {
+            let mut registers = vec![0; N_REGISTERS];
+            for r in sparse {
+                registers[sparse_index(*r)] = sparse_rank(*r);
+            }
+            self.registers = Registers::Dense(registers);
+        }
+    }
+
+    fn merge_dense(&mut self, other: &[u8]) {
+        self.densify();
+        if let Registers::Dense(registers) = &mut self.registers {
+            registers
+                .iter_mut()
+                .zip(other)
+                .for_each(|(l, r)| *l = std::cmp::max(*l, *r))
+        }
+    }
+
+    /// Merge the registers of `other` into this sketch.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        match &other.registers {
+            Registers::Sparse(registers) => registers
+                .iter()
+                .for_each(|r| self.set_register(sparse_index(*r), sparse_rank(*r))),
+            Registers::Dense(registers) => self.merge_dense(registers),
+        }
+    }
+
+    /// Merge a sketch in the format of [`HyperLogLog::to_bytes`] into this sketch.
+    fn merge_bytes(&mut self, bytes: &[u8]) -> PolarsResult<()> {
+        if bytes.len() == N_REGISTERS {
+            self.merge_dense(bytes);
+            return Ok(());
+        }
+        polars_ensure!(
+            bytes.len() % 4 == 0 && bytes.len() / 4 <= SPARSE_MAX_LEN,
+            ComputeError: "expected a HyperLogLog sketch of {} registers, or at most {} sparse registers, got {} bytes",
+            N_REGISTERS, SPARSE_MAX_LEN, bytes.len()
+        );
+        for chunk in bytes.chunks_exact(4) {
+            let r = u32::from_le_bytes(chunk.try_into().unwrap());
+            polars_ensure!(
+                sparse_index(r) < N_REGISTERS,
+                ComputeError: "invalid register {} in a sparse HyperLogLog sketch", r
+            );
+            self.set_register(sparse_index(r), sparse_rank(r));
+        }
+        Ok(())
+    }
+
+    pub fn estimate(&self) -> usize {
+        let m = N_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let (sum, zeros) = match &self.registers {
+            Registers::Sparse(registers) => {
+                let zeros = N_REGISTERS - registers.len();
+                let sum = registers
+                    .iter()
+                    .map(|r| 1.0 / (1u64 << sparse_rank(*r)) as f64)
+                    .sum::<f64>();
+                // every register that is not set adds 1 / 2^0
+                (sum + zeros as f64, zeros)
+            }
+            Registers::Dense(registers) => {
+                registers.iter().fold((0.0, 0usize), |(sum, zeros), &r| {
+                    (sum + 1.0 / (1u64 << r) as f64, zeros + (r == 0) as usize)
+                })
+            }
+        };
+        let raw = alpha * m * m / sum;
+        // small range correction
+        if raw <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            raw.round() as usize
+        }
+    }
+}
+
+fn estimate_distinct<I>(hashes: I, len: usize) -> usize
+where
+    I: Iterator<Item = u64>,
+{
+    if len <= EXACT_MAX_LEN {
+        let mut hashes = hashes.collect::<Vec<_>>();
+        hashes.sort_unstable();
+        hashes.dedup();
+        hashes.len()
+    } else {
+        let mut hll = HyperLogLog::new();
+        hashes.for_each(|h| hll.add(h));
+        hll.estimate()
+    }
+}
+
+impl Series {
+    fn hll_hashes(&self) -> PolarsResult<Vec<u64>> {
+        let mut hashes = Vec::with_capacity(self.len());
+        self.vec_hash(random_state(), &mut hashes)?;
+        Ok(hashes)
+    }
+
+    /// Estimate the number of unique values with a HyperLogLog sketch.
+    pub fn approx_n_unique(&self) -> PolarsResult<usize> {
+        let hashes = self.hll_hashes()?;
+        Ok(estimate_distinct(hashes.iter().copied(), hashes.len()))
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn agg_approx_n_unique(&self, groups: &GroupsProxy) -> PolarsResult<Series> {
+        let hashes = self.hll_hashes()?;
+        let out = match groups {
+            GroupsProxy::Idx(groups) => agg_helper_idx_on_all::<IdxType, _>(groups, |idx| {
+                debug_assert!(idx.len() <= self.len());
+                if idx.is_empty() {
+                    None
+                } else {
+                    let iter = idx.iter().map(|i| *hashes.get_unchecked(*i as usize));
+                    Some(estimate_distinct(iter, idx.len()) as IdxSize)
+                }
+            }),
+            GroupsProxy::Slice { groups, .. } => {
+                _agg_helper_slice::<IdxType, _>(groups, |[first, len]| {
+                    debug_assert!(len <= self.len() as IdxSize);
+                    if len == 0 {
+                        None
+                    } else {
+                        let group = &hashes[first as usize..(first + len) as usize];
+                        Some(estimate_distinct(group.iter().copied(), group.len()) as IdxSize)
+                    }
+                })
+            }
+        };
+        Ok(out)
+    }
+
+    /// Create a [`HyperLogLog`] sketch for every group. The sketches can be combined
+    /// with [`Series::agg_merge_hll_sketches`].
+    #[doc(hidden)]
+    pub unsafe fn agg_hll_sketches(&self, groups: &GroupsProxy) -> PolarsResult<Series> {
+        let hashes = self.hll_hashes()?;
+        let sketch = |idx: &mut dyn Iterator<Item = usize>| {
+            let mut hll = HyperLogLog::new();
+            idx.for_each(|i| hll.add(*hashes.get_unchecked(i)));
+            hll
+        };
+        let mut ca: BinaryChunked = groups
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((_, idx)) => {
+                    sketch(&mut idx.iter().map(|i| *i as usize)).to_bytes()
+                }
+                GroupsIndicator::Slice([first, len]) => {
+                    sketch(&mut (first as usize..(first + len) as usize)).to_bytes()
+                }
+            })
+            .map(Some)
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Merge the [`HyperLogLog`] sketches of every group and estimate the number of unique values.
+    #[doc(hidden)]
+    pub unsafe fn agg_merge_hll_sketches(&self, groups: &GroupsProxy) -> PolarsResult<Series> {
+        let sketches = self.binary()?.into_iter().collect::<Vec<_>>();
+        let merge = |idx: &mut dyn Iterator<Item = usize>| -> PolarsResult<IdxSize> {
+            let mut hll = HyperLogLog::new();
+            for i in idx {
+                if let Some(bytes) = sketches.get_unchecked(i) {
+                    hll.merge_bytes(bytes)?
+                }
+            }
+            Ok(hll.estimate() as IdxSize)
+        };
+        let ca: NoNull<IdxCa> = groups
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((_, idx)) => merge(&mut idx.iter().map(|i| *i as usize)),
+                GroupsIndicator::Slice([first, len]) => {
+                    merge(&mut (first as usize..(first + len) as usize))
+                }
+            })
+            .collect::<PolarsResult<_>>()?;
+        let mut ca = ca.into_inner();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sparse_hll() -> PolarsResult<()> {
+        let mut sparse = HyperLogLog::new();
+        let mut dense = HyperLogLog::new();
+        dense.densify();
+        for h in 0..100u64 {
+            sparse.add(h);
+            dense.add(h);
+        }
+        assert!(matches!(sparse.registers, Registers::Sparse(_)));
+        assert!(sparse.to_bytes().len() < N_REGISTERS);
+        assert_eq!(sparse.estimate(), dense.estimate());
+        assert_eq!(HyperLogLog::from_bytes(&sparse.to_bytes())?, sparse);
+
+        // a sketch becomes dense once many registers are set
+        let mut merged = HyperLogLog::from_bytes(&dense.to_bytes())?;
+        for h in 100..10_000u64 {
+            sparse.add(h);
+        }
+        assert!(matches!(sparse.registers, Registers::Dense(_)));
+        merged.merge(&sparse);
+        assert_eq!(merged, sparse);
+
+        assert!(HyperLogLog::from_bytes(&[0; 3]).is_err());
+        Ok(())
+    }
+}
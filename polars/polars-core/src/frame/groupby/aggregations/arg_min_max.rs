@@ -0,0 +1,129 @@
+use super::*;
+
+/// The row of the smallest (`max == false`) or largest valid value of `ca` among `rows`,
+/// the first one on ties. Values that can't be ordered, such as NaN, are skipped.
+///
+/// # Safety
+/// The rows must be in bounds.
+#[inline]
+unsafe fn arg_min_max<C>(ca: &C, rows: impl Iterator<Item = IdxSize>, max: bool) -> Option<IdxSize>
+where
+    C: TakeRandom,
+    C::Item: PartialOrd,
+{
+    let mut best: Option<(IdxSize, C::Item)> = None;
+    for row in rows {
+        let Some(value) = ca.get_unchecked(row as usize) else {
+            continue;
+        };
+        if value.partial_cmp(&value).is_none() {
+            continue;
+        }
+        let is_better = match &best {
+            None => true,
+            Some((_, best)) if max => value > *best,
+            Some((_, best)) => value < *best,
+        };
+        if is_better {
+            best = Some((row, value));
+        }
+    }
+    best.map(|(row, _)| row)
+}
+
+unsafe fn agg_arg_min_max<C>(ca: &C, groups: &GroupsProxy, max: bool) -> IdxCa
+where
+    C: TakeRandom + Sync,
+    C::Item: PartialOrd,
+{
+    let out = match groups {
+        GroupsProxy::Idx(groups) => _agg_helper_idx::<IdxType, _>(groups, |(_, idx)| {
+            arg_min_max(ca, idx.iter().copied(), max)
+        }),
+        GroupsProxy::Slice { groups, .. } => {
+            _agg_helper_slice::<IdxType, _>(groups, |[first, len]| {
+                arg_min_max(ca, first..first + len, max)
+            })
+        }
+    };
+    out.idx().unwrap().clone()
+}
+
+impl Series {
+    unsafe fn agg_arg_min_max(&self, groups: &GroupsProxy, max: bool) -> PolarsResult<IdxCa> {
+        let s = self.rechunk();
+        let s = s.to_physical_repr();
+        let out = match self.dtype() {
+            DataType::Boolean => agg_arg_min_max(s.bool().unwrap(), groups, max),
+            DataType::Utf8 => agg_arg_min_max(&s.utf8().unwrap(), groups, max),
+            DataType::Binary => agg_arg_min_max(&s.binary().unwrap(), groups, max),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                polars_bail!(opq = arg_min_max, self.dtype())
+            }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_) => {
+                polars_bail!(opq = arg_min_max, self.dtype())
+            }
+            dt if dt.is_numeric() || dt.is_temporal() => {
+                with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    agg_arg_min_max(&ca, groups, max)
+                })
+            }
+            dt => polars_bail!(opq = arg_min_max, dt),
+        };
+        Ok(out)
+    }
+
+    /// The row index of the minimum of every group, or null if the group has no valid
+    /// values. On ties, the first row is taken.
+    ///
+    /// # Safety
+    /// The groups must be in bounds.
+    pub unsafe fn agg_arg_min(&self, groups: &GroupsProxy) -> PolarsResult<IdxCa> {
+        self.agg_arg_min_max(groups, false)
+    }
+
+    /// The row index of the maximum of every group, or null if the group has no valid
+    /// values. On ties, the first row is taken.
+    ///
+    /// # Safety
+    /// The groups must be in bounds.
+    pub unsafe fn agg_arg_max(&self, groups: &GroupsProxy) -> PolarsResult<IdxCa> {
+        self.agg_arg_min_max(groups, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_agg_arg_min_max() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[Some(3.0), None, Some(1.0), Some(f64::NAN), Some(3.0), None],
+        );
+        let groups = GroupsProxy::Idx(GroupsIdx::from(vec![
+            (0, vec![0, 1, 2, 3]),
+            (4, vec![4, 0]),
+            (5, vec![5, 1]),
+        ]));
+        let arg_min = unsafe { s.agg_arg_min(&groups)? };
+        let arg_max = unsafe { s.agg_arg_max(&groups)? };
+        assert_eq!(Vec::from(&arg_min), &[Some(2), Some(4), None]);
+        assert_eq!(Vec::from(&arg_max), &[Some(0), Some(4), None]);
+
+        let s = Series::new("a", &["b", "a", "c", "a"]);
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 2], [1, 3]],
+            rolling: true,
+        };
+        let arg_min = unsafe { s.agg_arg_min(&groups)? };
+        let arg_max = unsafe { s.agg_arg_max(&groups)? };
+        assert_eq!(Vec::from(&arg_min), &[Some(1), Some(1)]);
+        assert_eq!(Vec::from(&arg_max), &[Some(0), Some(2)]);
+        Ok(())
+    }
+}
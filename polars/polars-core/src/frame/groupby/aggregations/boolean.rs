@@ -104,7 +104,42 @@ impl BooleanChunked {
             }),
         }
     }
+    /// Count the `true` values of every group; groups without valid values are `null`.
     pub(crate) unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
-        self.cast(&IDX_DTYPE).unwrap().agg_sum(groups)
+        let ca_self = self.rechunk();
+        let arr = ca_self.downcast_iter().next().unwrap();
+        let values = arr.values();
+        match groups {
+            GroupsProxy::Idx(groups) => agg_helper_idx_on_all::<IdxType, _>(groups, |idx| {
+                debug_assert!(idx.len() <= self.len());
+                let (n_true, n_valid) = match arr.validity() {
+                    None => {
+                        let n_true = idx
+                            .iter()
+                            .filter(|i| values.get_bit_unchecked(**i as usize))
+                            .count();
+                        (n_true, idx.len())
+                    }
+                    Some(validity) => idx.iter().fold((0, 0), |(n_true, n_valid), i| {
+                        let valid = validity.get_bit_unchecked(*i as usize);
+                        let v = valid && values.get_bit_unchecked(*i as usize);
+                        (n_true + v as usize, n_valid + valid as usize)
+                    }),
+                };
+                (n_valid > 0).then_some(n_true as IdxSize)
+            }),
+            GroupsProxy::Slice {
+                groups: groups_slice,
+                ..
+            } => _agg_helper_slice::<IdxType, _>(groups_slice, |[first, len]| {
+                debug_assert!(len <= self.len() as IdxSize);
+                let arr_group = _slice_from_offsets(&ca_self, first, len);
+                if arr_group.null_count() == len as usize {
+                    None
+                } else {
+                    arr_group.sum()
+                }
+            }),
+        }
     }
 }
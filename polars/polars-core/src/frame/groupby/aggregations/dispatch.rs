@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-decimal")]
+use crate::config::get_agg_dtype_policy;
 
 // implemented on the series because we don't need types
 impl Series {
@@ -160,9 +162,15 @@ impl Series {
     #[doc(hidden)]
     pub unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series {
         use DataType::*;
+        #[cfg(feature = "dtype-decimal")]
+        if let Some(scale) = get_agg_dtype_policy().mean_decimal_scale(self.dtype()) {
+            return self.agg_decimal_mean(groups, scale);
+        }
 
         match self.dtype() {
             Boolean => self.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => self.decimal().unwrap().agg_mean(groups),
             Float32 => SeriesWrap(self.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(self.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_numeric() => {
@@ -205,4 +213,52 @@ impl Series {
         };
         self.restore_logical(out)
     }
+
+    /// The first, or `last`, non-null value of every group, or its position within the
+    /// group if `arg` is set. Null for groups of which all values are null.
+    #[doc(hidden)]
+    pub unsafe fn agg_non_null(&self, groups: &GroupsProxy, last: bool, arg: bool) -> Series {
+        if self.null_count() == 0 && !arg {
+            return if last {
+                self.agg_last(groups)
+            } else {
+                self.agg_first(groups)
+            };
+        }
+        let validity = self.is_not_null().rechunk();
+        let validity = validity.downcast_iter().next().unwrap().values();
+        let found = groups.iter().map(|g| match g {
+            GroupsIndicator::Idx((_, idx)) => {
+                find_non_null(idx.iter().map(|i| *i as usize).enumerate(), validity, last)
+            }
+            GroupsIndicator::Slice([first, len]) => find_non_null(
+                (first as usize..(first + len) as usize).enumerate(),
+                validity,
+                last,
+            ),
+        });
+        if arg {
+            let mut ca: IdxCa = found.map(|f| f.map(|(pos, _)| pos as IdxSize)).collect();
+            ca.rename(self.name());
+            ca.into_series()
+        } else {
+            let rows: IdxCa = found.map(|f| f.map(|(_, row)| row as IdxSize)).collect();
+            // Safety:
+            // groups are always in bounds
+            self.take_unchecked(&rows).unwrap()
+        }
+    }
+}
+
+/// Find the first, or `last`, `(position, row)` of which the row is valid.
+fn find_non_null<I>(mut rows: I, validity: &Bitmap, last: bool) -> Option<(usize, usize)>
+where
+    I: DoubleEndedIterator<Item = (usize, usize)>,
+{
+    let is_valid = |(_, row): &(usize, usize)| validity.get_bit(*row);
+    if last {
+        rows.rfind(is_valid)
+    } else {
+        rows.find(is_valid)
+    }
 }
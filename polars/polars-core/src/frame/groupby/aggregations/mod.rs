@@ -1,9 +1,12 @@
 mod agg_list;
+mod approx_n_unique;
+mod arg_min_max;
 mod boolean;
 mod dispatch;
 mod utf8;
 
 pub use agg_list::*;
+pub use approx_n_unique::*;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::types::simd::Simd;
 use arrow::types::NativeType;
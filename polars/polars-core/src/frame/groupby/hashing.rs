@@ -7,10 +7,11 @@ use polars_utils::HashSingle;
 use rayon::prelude::*;
 
 use super::GroupsProxy;
-use crate::datatypes::PlHashMap;
+use crate::datatypes::{PlHashMap, PlHashSet};
 use crate::frame::groupby::GroupsIdx;
 use crate::hashing::{
-    df_rows_to_hashes_threaded_vertical, this_partition, AsU64, IdBuildHasher, IdxHash,
+    df_rows_to_hashes_threaded_vertical, estimate_n_partitions, heavy_hitter_partition,
+    sample_heavy_hitters, this_partition, AsU64, IdBuildHasher, IdxHash,
 };
 use crate::prelude::compare_inner::PartialEqInner;
 use crate::prelude::*;
@@ -129,10 +130,15 @@ where
 {
     assert!(n_partitions.is_power_of_two());
 
+    // Keys that dominate the data would serialize on the thread of their partition.
+    // Their rows are instead divided over all threads by row range.
+    let heavy_hitters = sample_heavy_hitters(keys, n_partitions);
+    let total_len = keys.iter().map(|k| k.into_iter().len()).sum::<usize>();
+
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
-    let v = POOL.install(|| {
+    let (mut v, heavy_hitter_vals): (Vec<_>, Vec<_>) = POOL.install(|| {
         (0..n_partitions)
             .into_par_iter()
             .map(|thread_no| {
@@ -140,6 +146,7 @@ where
                     PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
                 let mut first_vals = Vec::with_capacity(HASHMAP_INIT_SIZE);
                 let mut all_vals = Vec::with_capacity(HASHMAP_INIT_SIZE);
+                let mut heavy_hitter_vals = vec![vec![]; heavy_hitters.len()];
 
                 let mut offset = 0;
                 for keys in keys {
@@ -152,7 +159,12 @@ where
                         let row_idx = cnt + offset;
                         cnt += 1;
 
-                        if this_partition(k.as_u64(), thread_no, n_partitions) {
+                        if let Some(&i) = heavy_hitters.get(&k) {
+                            if heavy_hitter_partition(row_idx, total_len, n_partitions) == thread_no
+                            {
+                                unsafe { heavy_hitter_vals.get_unchecked_mut(i).push(row_idx) }
+                            }
+                        } else if this_partition(k.as_u64(), thread_no, n_partitions) {
                             let hash = hasher.hash_single(k);
                             let entry = hash_tbl.raw_entry_mut().from_key_hashed_nocheck(hash, &k);
 
@@ -180,13 +192,293 @@ where
                     });
                     offset += len;
                 }
-                (first_vals, all_vals)
+                ((first_vals, all_vals), heavy_hitter_vals)
             })
-            .collect::<Vec<_>>()
+            .unzip()
     });
+
+    // The row ranges are in thread order, so concatenating keeps the indices sorted.
+    if !heavy_hitters.is_empty() {
+        let all_vals = (0..heavy_hitters.len())
+            .map(|i| {
+                heavy_hitter_vals
+                    .iter()
+                    .flat_map(|vals| vals[i].iter().copied())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|all| !all.is_empty())
+            .collect::<Vec<_>>();
+        let first_vals = all_vals.iter().map(|all| all[0]).collect();
+        v.push((first_vals, all_vals));
+    }
     finish_group_order_vecs(v, sorted)
 }
 
+/// Like [`groupby_threaded_num2`], but only stores the first and the last index of every group.
+///
+/// The indices of a group are `[first, last]`, or `[first]` if the group has a single row,
+/// so the groups are only valid for the `first` and `last` aggregations.
+///
+/// Every partition keeps an index into its own `first` and `last` vectors per key, they are
+/// written into the two output buffers at the offset of the partition.
+pub(crate) fn groupby_threaded_first_last<T, I>(
+    keys: &[I],
+    n_partitions: u64,
+    sorted: bool,
+) -> (Vec<IdxSize>, Vec<IdxSize>)
+where
+    I: IntoIterator<Item = T> + Send + Sync + Copy,
+    I::IntoIter: ExactSizeIterator,
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+{
+    assert!(n_partitions.is_power_of_two());
+
+    let v = POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|thread_no| {
+                let mut hash_tbl: PlHashMap<T, IdxSize> =
+                    PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
+                let mut first_vals = Vec::with_capacity(HASHMAP_INIT_SIZE);
+                let mut last_vals = Vec::with_capacity(HASHMAP_INIT_SIZE);
+
+                let mut offset = 0;
+                for keys in keys {
+                    let keys = keys.into_iter();
+                    let len = keys.len() as IdxSize;
+                    let hasher = hash_tbl.hasher().clone();
+
+                    let mut cnt = 0;
+                    keys.for_each(|k| {
+                        let row_idx = cnt + offset;
+                        cnt += 1;
+
+                        if this_partition(k.as_u64(), thread_no, n_partitions) {
+                            let hash = hasher.hash_single(k);
+                            let entry = hash_tbl.raw_entry_mut().from_key_hashed_nocheck(hash, &k);
+
+                            match entry {
+                                RawEntryMut::Vacant(entry) => {
+                                    let offset_idx = first_vals.len() as IdxSize;
+                                    first_vals.push(row_idx);
+                                    last_vals.push(row_idx);
+
+                                    entry.insert_with_hasher(hash, k, offset_idx, |k| {
+                                        hasher.hash_single(k)
+                                    });
+                                }
+                                RawEntryMut::Occupied(entry) => {
+                                    let offset_idx = *entry.get();
+                                    unsafe {
+                                        *last_vals.get_unchecked_mut(offset_idx as usize) = row_idx
+                                    }
+                                }
+                            }
+                        }
+                    });
+                    offset += len;
+                }
+                (first_vals, last_vals)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let cap = v.iter().map(|(first, _)| first.len()).sum::<usize>();
+    let offsets = v
+        .iter()
+        .scan(0_usize, |acc, (first, _)| {
+            let out = *acc;
+            *acc += first.len();
+            Some(out)
+        })
+        .collect::<Vec<_>>();
+    let mut first = Vec::<IdxSize>::with_capacity(cap);
+    let mut last = Vec::<IdxSize>::with_capacity(cap);
+    let first_ptr = unsafe { SyncPtr::new(first.as_mut_ptr()) };
+    let last_ptr = unsafe { SyncPtr::new(last.as_mut_ptr()) };
+    POOL.install(|| {
+        v.into_par_iter()
+            .zip(offsets)
+            .for_each(|((first_vals, last_vals), offset)| unsafe {
+                let first_ptr: *mut IdxSize = first_ptr.get();
+                let last_ptr: *mut IdxSize = last_ptr.get();
+                std::ptr::copy_nonoverlapping(
+                    first_vals.as_ptr(),
+                    first_ptr.add(offset),
+                    first_vals.len(),
+                );
+                std::ptr::copy_nonoverlapping(
+                    last_vals.as_ptr(),
+                    last_ptr.add(offset),
+                    last_vals.len(),
+                );
+            })
+    });
+    unsafe {
+        first.set_len(cap);
+        last.set_len(cap);
+    }
+
+    if sorted {
+        // order the groups by their first row, the first rows are unique
+        let mut order = (0..cap as IdxSize).collect::<Vec<_>>();
+        POOL.install(|| {
+            order.par_sort_unstable_by_key(|i| unsafe { *first.get_unchecked(*i as usize) })
+        });
+        let take = |vals: &[IdxSize]| {
+            order
+                .iter()
+                .map(|i| unsafe { *vals.get_unchecked(*i as usize) })
+                .collect::<Vec<_>>()
+        };
+        let (sorted_first, sorted_last) = POOL.join(|| take(&first), || take(&last));
+        return (sorted_first, sorted_last);
+    }
+    (first, last)
+}
+
+/// Group keys that map to a small range of slots with a direct index from slot to group,
+/// i.e. a perfect hash table.
+///
+/// The groups are created in the order of their first row, so they are always sorted.
+pub(crate) fn groupby_perfect<T, I, F>(keys: &[I], n_slots: usize, to_slot: F) -> GroupsProxy
+where
+    I: IntoIterator<Item = T> + Copy,
+    F: Fn(T) -> usize,
+{
+    const EMPTY: IdxSize = IdxSize::MAX;
+    let mut slot_to_group = vec![EMPTY; n_slots];
+    let mut first = vec![];
+    let mut all: Vec<Vec<IdxSize>> = vec![];
+
+    let mut row_idx = 0 as IdxSize;
+    for keys in keys {
+        for k in keys.into_iter() {
+            let group = unsafe { slot_to_group.get_unchecked_mut(to_slot(k)) };
+            if *group == EMPTY {
+                *group = all.len() as IdxSize;
+                first.push(row_idx);
+                all.push(vec![row_idx]);
+            } else {
+                unsafe { all.get_unchecked_mut(*group as usize) }.push(row_idx);
+            }
+            row_idx += 1;
+        }
+    }
+    GroupsProxy::Idx(GroupsIdx::new(first, all, true))
+}
+
+/// Group keys that map to a small range of slots, without hashing.
+///
+/// Every partition owns a contiguous range of slots and scans all keys, pushing the rows
+/// of the keys that fall in its range. The groups come out ordered by slot.
+pub(crate) fn groupby_radix<T, I, F>(
+    keys: &[I],
+    n_slots: usize,
+    n_partitions: usize,
+    to_slot: F,
+    sorted: bool,
+) -> GroupsProxy
+where
+    I: IntoIterator<Item = T> + Send + Sync + Copy,
+    F: Fn(T) -> usize + Send + Sync,
+{
+    let n_partitions = std::cmp::max(std::cmp::min(n_partitions, n_slots), 1);
+    let slots_per_partition = (n_slots + n_partitions - 1) / n_partitions;
+
+    let partitions = POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|thread_no| {
+                let start = thread_no * slots_per_partition;
+                let end = std::cmp::min(start + slots_per_partition, n_slots);
+                let mut groups: Vec<Vec<IdxSize>> = vec![vec![]; end.saturating_sub(start)];
+
+                let mut row_idx = 0 as IdxSize;
+                for keys in keys {
+                    for k in keys.into_iter() {
+                        let slot = to_slot(k);
+                        if slot >= start && slot < end {
+                            unsafe { groups.get_unchecked_mut(slot - start).push(row_idx) }
+                        }
+                        row_idx += 1;
+                    }
+                }
+                groups
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let (first, all) = partitions
+        .into_iter()
+        .flatten()
+        .filter(|group| !group.is_empty())
+        .map(|group| (group[0], group))
+        .unzip();
+    let mut groups = GroupsIdx::new(first, all, false);
+    if sorted {
+        groups.sort()
+    }
+    GroupsProxy::Idx(groups)
+}
+
+/// Collect the distinct keys with the same partitioning as [`groupby_threaded_num2`].
+///
+/// Every thread only keeps the keys of its partition, so the returned sets are disjoint.
+pub(crate) fn unique_threaded<T, I>(keys: &[I], n_partitions: u64) -> Vec<PlHashSet<T>>
+where
+    I: IntoIterator<Item = T> + Send + Sync + Copy,
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+{
+    assert!(n_partitions.is_power_of_two());
+
+    POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|thread_no| {
+                let mut set = PlHashSet::with_capacity(HASHMAP_INIT_SIZE);
+                for keys in keys {
+                    for k in keys.into_iter() {
+                        if this_partition(k.as_u64(), thread_no, n_partitions) {
+                            set.insert(k);
+                        }
+                    }
+                }
+                set
+            })
+            .collect()
+    })
+}
+
+/// Count the occurrences of the keys with the same partitioning as [`groupby_threaded_num2`].
+///
+/// Every thread only counts the keys of its partition, so the returned maps are disjoint.
+#[cfg(feature = "mode")]
+pub(crate) fn count_threaded<T, I>(keys: &[I], n_partitions: u64) -> Vec<PlHashMap<T, IdxSize>>
+where
+    I: IntoIterator<Item = T> + Send + Sync + Copy,
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+{
+    assert!(n_partitions.is_power_of_two());
+
+    POOL.install(|| {
+        (0..n_partitions)
+            .into_par_iter()
+            .map(|thread_no| {
+                let mut counts = PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
+                for keys in keys {
+                    for k in keys.into_iter() {
+                        if this_partition(k.as_u64(), thread_no, n_partitions) {
+                            *counts.entry(k).or_insert(0) += 1;
+                        }
+                    }
+                }
+                counts
+            })
+            .collect()
+    })
+}
+
 /// Utility function used as comparison function in the hashmap.
 /// The rationale is that equality is an AND operation and therefore its probability of success
 /// declines rapidly with the number of keys. Instead of first copying an entire row from both
@@ -325,7 +617,14 @@ pub(crate) fn groupby_threaded_multiple_keys_flat(
 ) -> PolarsResult<GroupsProxy> {
     let dfs = split_df(&mut keys, n_partitions).unwrap();
     let (hashes, _random_state) = df_rows_to_hashes_threaded_vertical(&dfs, None)?;
-    let n_partitions = n_partitions as u64;
+    let n_partitions = estimate_n_partitions(
+        hashes
+            .iter()
+            .flat_map(|h| h.downcast_iter())
+            .flat_map(|arr| arr.values().iter().copied()),
+        keys.height(),
+        n_partitions as u64,
+    );
 
     // trait object to compare inner types.
     let keys_cmp = keys
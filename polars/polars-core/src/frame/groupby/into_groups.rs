@@ -18,18 +18,103 @@ pub trait IntoGroupsProxy {
     }
 }
 
-fn group_multithreaded<T: PolarsDataType>(ca: &ChunkedArray<T>) -> bool {
+pub(crate) fn group_multithreaded<T: PolarsDataType>(ca: &ChunkedArray<T>) -> bool {
     // TODO! change to something sensible
     ca.len() > 1000
 }
 
-fn num_groups_proxy<T>(ca: &ChunkedArray<T>, multithreaded: bool, sorted: bool) -> GroupsProxy
+/// Keys that span more slots are grouped by hashing.
+const RADIX_MAX_SLOTS: u64 = 1 << 16;
+
+/// Group dense integer keys by their offset to the minimum key, which is cheaper than hashing.
+/// Single threaded, the offsets index the groups directly; multithreaded, they are
+/// partitioned by [`groupby_radix`].
+///
+/// `signed` tells that the keys are the bits of signed integers, of which the sign bit is
+/// flipped so that keys around zero map to a small range.
+///
+/// Returns `None` if the keys span more slots than [`RADIX_MAX_SLOTS`] or than there are rows.
+fn radix_groups_proxy<T>(
+    ca: &ChunkedArray<T>,
+    multithreaded: bool,
+    sorted: bool,
+    signed: bool,
+) -> Option<GroupsProxy>
 where
     T: PolarsIntegerType,
-    T::Native: Hash + Eq + Send + AsU64,
+    T::Native: Send + Sync + AsU64,
+{
+    let sign_bit = if signed {
+        1u64 << (std::mem::size_of::<T::Native>() * 8 - 1)
+    } else {
+        0
+    };
+    let to_key = |v: T::Native| v.as_u64() ^ sign_bit;
+
+    let mut min_max: Option<(u64, u64)> = None;
+    let mut update = |v: T::Native| {
+        let k = to_key(v);
+        min_max = Some(match min_max {
+            Some((min, max)) => (std::cmp::min(min, k), std::cmp::max(max, k)),
+            None => (k, k),
+        })
+    };
+    for arr in ca.downcast_iter() {
+        if arr.null_count() == 0 {
+            arr.values().iter().for_each(|v| update(*v))
+        } else {
+            arr.iter().flatten().for_each(|v| update(*v))
+        }
+    }
+    let (min, max) = min_max?;
+    let range = max - min;
+    if range >= RADIX_MAX_SLOTS || range as usize >= ca.len() {
+        return None;
+    }
+    let to_slot = |v: T::Native| (to_key(v) - min) as usize;
+    let n_partitions = _set_partition_size();
+
+    let groups = if ca.null_count() == 0 {
+        let keys = ca
+            .downcast_iter()
+            .map(|arr| arr.values().as_slice())
+            .collect::<Vec<_>>();
+        let to_slot = |v: &T::Native| to_slot(*v);
+        if multithreaded {
+            groupby_radix(&keys, range as usize + 1, n_partitions, to_slot, sorted)
+        } else {
+            groupby_perfect(&keys, range as usize + 1, to_slot)
+        }
+    } else {
+        // the last slot holds the nulls
+        let null_slot = range as usize + 1;
+        let keys = ca.downcast_iter().collect::<Vec<_>>();
+        let to_slot = |opt_v: Option<&T::Native>| opt_v.map_or(null_slot, |v| to_slot(*v));
+        if multithreaded {
+            groupby_radix(&keys, null_slot + 1, n_partitions, to_slot, sorted)
+        } else {
+            groupby_perfect(&keys, null_slot + 1, to_slot)
+        }
+    };
+    Some(groups)
+}
+
+fn num_groups_proxy<T>(
+    ca: &ChunkedArray<T>,
+    multithreaded: bool,
+    sorted: bool,
+    signed: bool,
+) -> GroupsProxy
+where
+    T: PolarsIntegerType,
+    T::Native: Hash + Eq + Send + Sync + AsU64,
     Option<T::Native>: AsU64,
 {
-    if multithreaded && group_multithreaded(ca) {
+    let multithreaded = multithreaded && group_multithreaded(ca);
+    if let Some(groups) = radix_groups_proxy(ca, multithreaded, sorted, signed) {
+        return groups;
+    }
+    if multithreaded {
         let n_partitions = _set_partition_size() as u64;
 
         // use the arrays as iterators
@@ -127,6 +212,38 @@ where
     }
 }
 
+/// Create the groups of sorted values by scanning for runs of equal values.
+///
+/// Nulls are either all at the start or all at the end, so they form a single run.
+pub(super) fn groups_from_sorted_runs<T: PartialEq>(
+    values: impl Iterator<Item = T>,
+) -> GroupsSlice {
+    if verbose() {
+        eprintln!("groupby keys are sorted; running sorted key fast path");
+    }
+    let mut groups = GroupsSlice::default();
+    let mut prev = None;
+    let mut first = 0 as IdxSize;
+    let mut len = 0 as IdxSize;
+    for (i, v) in values.enumerate() {
+        match &prev {
+            Some(prev) if *prev == v => {}
+            _ => {
+                if i > 0 {
+                    groups.push([first, i as IdxSize - first]);
+                }
+                first = i as IdxSize;
+            }
+        }
+        prev = Some(v);
+        len += 1;
+    }
+    if len > 0 {
+        groups.push([first, len - first]);
+    }
+    groups
+}
+
 #[cfg(all(feature = "dtype-categorical", feature = "performant"))]
 impl IntoGroupsProxy for CategoricalChunked {
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
@@ -149,28 +266,30 @@ where
             });
         }
 
+        // the signed keys are grouped by their bits
+        let signed = self.dtype().is_signed();
         let out = match self.dtype() {
             DataType::UInt64 => {
                 // convince the compiler that we are this type.
                 let ca: &UInt64Chunked = unsafe {
                     &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt64Type>)
                 };
-                num_groups_proxy(ca, multithreaded, sorted)
+                num_groups_proxy(ca, multithreaded, sorted, signed)
             }
             DataType::UInt32 => {
                 // convince the compiler that we are this type.
                 let ca: &UInt32Chunked = unsafe {
                     &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt32Type>)
                 };
-                num_groups_proxy(ca, multithreaded, sorted)
+                num_groups_proxy(ca, multithreaded, sorted, signed)
             }
             DataType::Int64 | DataType::Float64 => {
                 let ca = self.bit_repr_large();
-                num_groups_proxy(&ca, multithreaded, sorted)
+                num_groups_proxy(&ca, multithreaded, sorted, signed)
             }
             DataType::Int32 | DataType::Float32 => {
                 let ca = self.bit_repr_small();
-                num_groups_proxy(&ca, multithreaded, sorted)
+                num_groups_proxy(&ca, multithreaded, sorted, signed)
             }
             #[cfg(feature = "performant")]
             DataType::Int8 => {
@@ -178,14 +297,14 @@ where
                 let ca: &Int8Chunked =
                     unsafe { &*(self as *const ChunkedArray<T> as *const ChunkedArray<Int8Type>) };
                 let ca = ca.reinterpret_unsigned();
-                num_groups_proxy(&ca, multithreaded, sorted)
+                num_groups_proxy(&ca, multithreaded, sorted, signed)
             }
             #[cfg(feature = "performant")]
             DataType::UInt8 => {
                 // convince the compiler that we are this type.
                 let ca: &UInt8Chunked =
                     unsafe { &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt8Type>) };
-                num_groups_proxy(ca, multithreaded, sorted)
+                num_groups_proxy(ca, multithreaded, sorted, signed)
             }
             #[cfg(feature = "performant")]
             DataType::Int16 => {
@@ -193,7 +312,7 @@ where
                 let ca: &Int16Chunked =
                     unsafe { &*(self as *const ChunkedArray<T> as *const ChunkedArray<Int16Type>) };
                 let ca = ca.reinterpret_unsigned();
-                num_groups_proxy(&ca, multithreaded, sorted)
+                num_groups_proxy(&ca, multithreaded, sorted, signed)
             }
             #[cfg(feature = "performant")]
             DataType::UInt16 => {
@@ -201,31 +320,53 @@ where
                 let ca: &UInt16Chunked = unsafe {
                     &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt16Type>)
                 };
-                num_groups_proxy(ca, multithreaded, sorted)
+                num_groups_proxy(ca, multithreaded, sorted, signed)
             }
             _ => {
                 let ca = unsafe { self.cast_unchecked(&DataType::UInt32).unwrap() };
                 let ca = ca.u32().unwrap();
-                num_groups_proxy(ca, multithreaded, sorted)
+                num_groups_proxy(ca, multithreaded, sorted, signed)
             }
         };
         Ok(out)
     }
 }
 impl IntoGroupsProxy for BooleanChunked {
-    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
-        #[cfg(feature = "performant")]
-        {
-            let ca = self.cast(&DataType::UInt8).unwrap();
-            let ca = ca.u8().unwrap();
-            ca.group_tuples(multithreaded, sorted)
-        }
-        #[cfg(not(feature = "performant"))]
-        {
-            let ca = self.cast(&DataType::UInt32).unwrap();
-            let ca = ca.u32().unwrap();
-            ca.group_tuples(multithreaded, sorted)
+    fn group_tuples(&self, _multithreaded: bool, _sorted: bool) -> PolarsResult<GroupsProxy> {
+        // There are at most three groups: false, true and null. Their sizes are known
+        // from the counts, so the rows are pushed directly in their bucket.
+        let n_null = self.null_count();
+        let n_true = self.sum().unwrap_or(0) as usize;
+        let n_false = self.len() - n_true - n_null;
+        let mut buckets = [n_false, n_true, n_null].map(Vec::with_capacity);
+        let mut idx = 0 as IdxSize;
+        for arr in self.downcast_iter() {
+            match arr.validity() {
+                None => arr.values().iter().for_each(|v| {
+                    unsafe { buckets.get_unchecked_mut(v as usize) }.push(idx);
+                    idx += 1;
+                }),
+                Some(validity) => {
+                    arr.values()
+                        .iter()
+                        .zip(validity.iter())
+                        .for_each(|(v, valid)| {
+                            let bucket = if valid { v as usize } else { 2 };
+                            unsafe { buckets.get_unchecked_mut(bucket) }.push(idx);
+                            idx += 1;
+                        })
+                }
+            }
         }
+        // the groups are ordered by their first occurrence
+        let mut groups = buckets
+            .into_iter()
+            .filter_map(|all| Some((*all.first()?, all)))
+            .collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|g| g.0);
+        let mut groups: GroupsIdx = groups.into_iter().collect();
+        groups.sorted = true;
+        Ok(GroupsProxy::Idx(groups))
     }
 }
 
@@ -236,53 +377,52 @@ impl IntoGroupsProxy for Utf8Chunked {
     }
 }
 
+impl BinaryChunked {
+    /// Hash the values of `n_partitions` slices of the array in parallel.
+    ///
+    /// `hb` must be created by [`bytes_hash_builder`], so that cached hashes can be reused.
+    #[allow(clippy::needless_lifetimes)]
+    pub(crate) fn to_bytes_hashes_threaded<'a>(
+        &'a self,
+        n_partitions: usize,
+        hb: RandomState,
+    ) -> Vec<Vec<BytesHash<'a>>> {
+        let split = _split_offsets(self.len(), n_partitions);
+
+        POOL.install(|| {
+            split
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    let ca = self.slice(offset as i64, len);
+                    let hashes = ca.to_bytes_hashes(&hb);
+                    // Safety:
+                    // the underlying data is tied to self
+                    unsafe { std::mem::transmute::<Vec<BytesHash<'_>>, Vec<BytesHash<'a>>>(hashes) }
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
 impl IntoGroupsProxy for BinaryChunked {
     #[allow(clippy::needless_lifetimes)]
     fn group_tuples<'a>(&'a self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
-        let hb = RandomState::default();
-        let null_h = get_null_hash_value(hb.clone());
+        if self.is_sorted_ascending_flag() || self.is_sorted_descending_flag() {
+            return Ok(GroupsProxy::Slice {
+                groups: groups_from_sorted_runs(self.into_iter()),
+                rolling: false,
+            });
+        }
+        let hb = bytes_hash_builder(&[self]);
 
         let out = if multithreaded {
             let n_partitions = _set_partition_size();
 
-            let split = _split_offsets(self.len(), n_partitions);
-
-            let byte_hashes = POOL.install(|| {
-                split
-                    .into_par_iter()
-                    .map(|(offset, len)| {
-                        let ca = self.slice(offset as i64, len);
-                        ca.into_iter()
-                            .map(|opt_b| {
-                                let hash = match opt_b {
-                                    Some(s) => hb.hash_single(s),
-                                    None => null_h,
-                                };
-                                // Safety:
-                                // the underlying data is tied to self
-                                unsafe {
-                                    std::mem::transmute::<BytesHash<'_>, BytesHash<'a>>(
-                                        BytesHash::new(opt_b, hash),
-                                    )
-                                }
-                            })
-                            .collect_trusted::<Vec<_>>()
-                    })
-                    .collect::<Vec<_>>()
-            });
+            let byte_hashes = self.to_bytes_hashes_threaded(n_partitions, hb);
             let byte_hashes = byte_hashes.iter().collect::<Vec<_>>();
             groupby_threaded_num2(&byte_hashes, n_partitions as u64, sorted)
         } else {
-            let byte_hashes = self
-                .into_iter()
-                .map(|opt_b| {
-                    let hash = match opt_b {
-                        Some(s) => hb.hash_single(s),
-                        None => null_h,
-                    };
-                    BytesHash::new(opt_b, hash)
-                })
-                .collect_trusted::<Vec<_>>();
+            let byte_hashes = self.to_bytes_hashes(&hb);
             groupby(byte_hashes.iter(), sorted)
         };
         Ok(out)
@@ -4,11 +4,13 @@ use std::hash::Hash;
 use ahash::RandomState;
 use num_traits::NumCast;
 use polars_arrow::prelude::QuantileInterpolOptions;
+use polars_utils::HashSingle;
 use rayon::prelude::*;
 
 use self::hashing::*;
-use crate::hashing::{get_null_hash_value, AsU64, BytesHash};
+use crate::hashing::{bytes_hash_builder, get_null_hash_value, AsU64, BytesHash};
 use crate::prelude::*;
+use crate::series::IsSorted;
 use crate::utils::{_set_partition_size, accumulate_dataframes_vertical};
 use crate::POOL;
 
@@ -22,7 +24,6 @@ mod proxy;
 pub use into_groups::*;
 pub use proxy::*;
 
-#[cfg(feature = "dtype-struct")]
 use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical;
 
 // This will remove the sorted flag on signed integers
@@ -49,6 +50,33 @@ fn prepare_dataframe_unsorted(by: &[Series]) -> DataFrame {
     )
 }
 
+/// Find the first and the last row of every group of the row encoded keys `by`.
+fn groupby_rows_first_last(
+    by: &[Series],
+    n_partitions: usize,
+    sorted: bool,
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+    let rows = encode_rows_vertical(by)?;
+    let hb = RandomState::default();
+    let hashes = POOL.install(|| {
+        rows.downcast_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|arr| {
+                arr.values_iter()
+                    .map(|row| BytesHash::new(Some(row), hb.hash_single(row)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    let hashes = hashes.iter().collect::<Vec<_>>();
+    Ok(groupby_threaded_first_last(
+        &hashes,
+        n_partitions as u64,
+        sorted,
+    ))
+}
+
 impl DataFrame {
     pub fn groupby_with_series(
         &self,
@@ -86,12 +114,120 @@ impl DataFrame {
                     return Ok(GroupBy::new(self, by, groups, None));
                 }
             }
+            // If every key is sorted, equal rows are adjacent and the groups are the runs of
+            // equal rows. A new run starts wherever any of the keys starts a new run.
+            if by.iter().all(|s| s.is_sorted_flag() != IsSorted::Not) {
+                let mut starts = Vec::with_capacity(by.len());
+                for s in &by {
+                    match s.group_tuples(multithreaded, false)? {
+                        GroupsProxy::Slice { groups, .. } => {
+                            starts.extend(groups.into_iter().map(|[first, _]| first))
+                        }
+                        // dtypes without a sorted fast path
+                        _ => {
+                            starts.clear();
+                            break;
+                        }
+                    }
+                }
+                if !starts.is_empty() {
+                    starts.sort_unstable();
+                    starts.dedup();
+                    let len = by[0].len() as IdxSize;
+                    let ends = starts.iter().skip(1).copied().chain([len]);
+                    let groups = starts
+                        .iter()
+                        .zip(ends)
+                        .map(|(first, end)| [*first, end - first])
+                        .collect();
+                    let groups = GroupsProxy::Slice {
+                        groups,
+                        rolling: false,
+                    };
+                    return Ok(GroupBy::new(self, by, groups, None));
+                }
+            }
             let keys_df = prepare_dataframe_unsorted(&by);
             groupby_threaded_multiple_keys_flat(keys_df, n_partitions, sorted)
         };
         Ok(GroupBy::new(self, by, groups?, None))
     }
 
+    /// Group DataFrame by `by`, only finding the first and the last row index of every group.
+    ///
+    /// This skips collecting the indices of all rows in a group, which is all the `first` and
+    /// `last` aggregations need. The groups are in the order of their first row if `sorted`.
+    #[doc(hidden)]
+    pub fn _groupby_first_last_with_series(
+        &self,
+        by: &[Series],
+        sorted: bool,
+    ) -> PolarsResult<(IdxCa, IdxCa)> {
+        // sorted keys and broadcasted keys don't hash at all
+        if by.is_empty()
+            || by[0].len() != self.height()
+            || by.iter().all(|s| s.is_sorted_flag() != IsSorted::Not)
+        {
+            let groups = self
+                .groupby_with_series(by.to_vec(), true, sorted)?
+                .take_groups();
+            let (first, last): (Vec<_>, Vec<_>) = match &groups {
+                GroupsProxy::Idx(groups) => groups
+                    .iter()
+                    .map(|(first, all)| (first, *all.last().unwrap()))
+                    .unzip(),
+                GroupsProxy::Slice { groups, .. } => groups
+                    .iter()
+                    .map(|[first, len]| (*first, *first + *len - 1))
+                    .unzip(),
+            };
+            return Ok((IdxCa::from_vec("", first), IdxCa::from_vec("", last)));
+        }
+
+        let n_partitions = _set_partition_size();
+        let s = by[0].to_physical_repr();
+        let (first, last) = match s.dtype() {
+            // the decimals don't have a bit representation
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => groupby_rows_first_last(by, n_partitions, sorted)?,
+            dt if by.len() == 1 && dt.is_numeric() => {
+                // group on the bits of the values, the arrays are used as iterators
+                macro_rules! first_last {
+                    ($ca:expr) => {{
+                        let ca = $ca;
+                        if ca.null_count() == 0 {
+                            let keys = ca
+                                .downcast_iter()
+                                .map(|arr| arr.values().as_slice())
+                                .collect::<Vec<_>>();
+                            groupby_threaded_first_last(&keys, n_partitions as u64, sorted)
+                        } else {
+                            let keys = ca.downcast_iter().collect::<Vec<_>>();
+                            groupby_threaded_first_last(&keys, n_partitions as u64, sorted)
+                        }
+                    }};
+                }
+                if s.bit_repr_is_large() {
+                    first_last!(s.bit_repr_large())
+                } else {
+                    first_last!(s.bit_repr_small())
+                }
+            }
+            DataType::Utf8 | DataType::Binary if by.len() == 1 => {
+                let ca = match s.dtype() {
+                    DataType::Utf8 => s.utf8().unwrap().as_binary(),
+                    _ => s.binary().unwrap().clone(),
+                };
+                let hb = bytes_hash_builder(&[&ca]);
+                let hashes = ca.to_bytes_hashes_threaded(n_partitions, hb);
+                let hashes = hashes.iter().collect::<Vec<_>>();
+                groupby_threaded_first_last(&hashes, n_partitions as u64, sorted)
+            }
+            _ => groupby_rows_first_last(by, n_partitions, sorted)?,
+        };
+        Ok((IdxCa::from_vec("", first), IdxCa::from_vec("", last)))
+    }
+
     /// Group DataFrame using a Series column.
     ///
     /// # Example
@@ -123,6 +259,39 @@ impl DataFrame {
         let selected_keys = self.select_series(by)?;
         self.groupby_with_series(selected_keys, true, true)
     }
+
+    /// Group the `DataFrame` by the columns `by` without aggregating.
+    ///
+    /// Returns a `DataFrame` with the unique keys and the [`GroupsProxy`] that holds the
+    /// row indices of every group, in the same order as the keys. This is meant for
+    /// implementing aggregations on top of the groups.
+    ///
+    /// If `maintain_order` is set, the groups are ordered by their first row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn group_lengths(df: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     let (mut keys, groups) = df.group_by_indices(["a"], true)?;
+    ///     keys.with_column(groups.group_lengths("len"))?;
+    ///     Ok(keys)
+    /// }
+    /// ```
+    pub fn group_by_indices<I, S>(
+        &self,
+        by: I,
+        maintain_order: bool,
+    ) -> PolarsResult<(DataFrame, GroupsProxy)>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let selected_keys = self.select_series(by)?;
+        let gb = self.groupby_with_series(selected_keys, true, maintain_order)?;
+        let keys = DataFrame::new_no_checks(gb.keys());
+        Ok((keys, gb.take_groups()))
+    }
 }
 
 /// Returned by a groupby operation on a DataFrame. This struct supports
@@ -579,6 +748,30 @@ impl<'df> GroupBy<'df> {
         DataFrame::new(cols)
     }
 
+    /// Aggregate grouped `Series` by estimating the number of unique values.
+    ///
+    /// Unlike [`GroupBy::n_unique`] this doesn't materialize the unique values of a group,
+    /// but uses a [`HyperLogLog`](aggregations::HyperLogLog) sketch of constant size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.groupby(["date"])?.select(["temp"]).approx_n_unique()
+    /// }
+    /// ```
+    pub fn approx_n_unique(&self) -> PolarsResult<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::ApproxNUnique);
+            let mut agg = unsafe { agg_col.agg_approx_n_unique(&self.groups)? };
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped `Series` and determine the quantile per group.
     ///
     /// # Example
@@ -689,7 +882,12 @@ impl<'df> GroupBy<'df> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
 
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Count);
+            let new_name = fmt_groupby_column(
+                agg_col.name(),
+                GroupByMethod::Count {
+                    include_nulls: true,
+                },
+            );
             let mut ca = self.groups.group_count();
             ca.rename(&new_name);
             cols.push(ca.into_series());
@@ -848,11 +1046,22 @@ pub enum GroupByMethod {
     Mean,
     First,
     Last,
+    /// The first non-null value, or its position in the group if `arg` is set.
+    FirstNonNull {
+        arg: bool,
+    },
+    /// The last non-null value, or its position in the group if `arg` is set.
+    LastNonNull {
+        arg: bool,
+    },
     Sum,
     Groups,
     NUnique,
+    ApproxNUnique,
     Quantile(f64, QuantileInterpolOptions),
-    Count,
+    Count {
+        include_nulls: bool,
+    },
     Implode,
     Std(u8),
     Var(u8),
@@ -870,11 +1079,16 @@ impl Display for GroupByMethod {
             Mean => "mean",
             First => "first",
             Last => "last",
+            FirstNonNull { arg: false } => "first_non_null",
+            FirstNonNull { arg: true } => "arg_first_non_null",
+            LastNonNull { arg: false } => "last_non_null",
+            LastNonNull { arg: true } => "arg_last_non_null",
             Sum => "sum",
             Groups => "groups",
             NUnique => "n_unique",
+            ApproxNUnique => "approx_n_unique",
             Quantile(_, _) => "quantile",
-            Count => "count",
+            Count { .. } => "count",
             Implode => "list",
             Std(_) => "std",
             Var(_) => "var",
@@ -895,10 +1109,15 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
         Mean => format!("{name}_mean"),
         First => format!("{name}_first"),
         Last => format!("{name}_last"),
+        FirstNonNull { arg: false } => format!("{name}_first_non_null"),
+        FirstNonNull { arg: true } => format!("{name}_arg_first_non_null"),
+        LastNonNull { arg: false } => format!("{name}_last_non_null"),
+        LastNonNull { arg: true } => format!("{name}_arg_last_non_null"),
         Sum => format!("{name}_sum"),
         Groups => "groups".to_string(),
         NUnique => format!("{name}_n_unique"),
-        Count => format!("{name}_count"),
+        ApproxNUnique => format!("{name}_approx_n_unique"),
+        Count { .. } => format!("{name}_count"),
         Implode => format!("{name}_agg_list"),
         Quantile(quantile, _interpol) => format!("{name}_quantile_{quantile:.2}"),
         Std(_) => format!("{name}_agg_std"),
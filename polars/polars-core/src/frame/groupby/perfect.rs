@@ -100,14 +100,16 @@ where
 impl CategoricalChunked {
     // Use the indexes as perfect groups
     pub fn group_tuples_perfect(&self, multithreaded: bool, sorted: bool) -> GroupsProxy {
-        let DataType::Categorical(Some(rev_map)) = self.dtype() else { unreachable!()};
+        let DataType::Categorical(Some(rev_map)) = self.dtype() else {
+            unreachable!()
+        };
         if self.is_empty() {
             return GroupsProxy::Idx(GroupsIdx::new(vec![], vec![], true));
         }
         let cats = self.logical();
 
         let mut out = match &**rev_map {
-            RevMapping::Local(cached) => {
+            RevMapping::Local(cached) | RevMapping::Enum(cached) => {
                 let len = if cats.null_count() > 0 {
                     // we add one to store the null sentinel group
                     cached.len() + 1
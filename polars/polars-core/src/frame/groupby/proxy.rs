@@ -413,6 +413,60 @@ impl GroupsProxy {
         }
     }
 
+    /// Take the groups at the positions yielded by `indices`.
+    ///
+    /// The groups stay sorted if they were sorted and `indices` is increasing.
+    fn take_groups<I>(&self, indices: I, increasing: bool) -> GroupsProxy
+    where
+        I: Iterator<Item = usize> + Clone,
+    {
+        match self {
+            GroupsProxy::Idx(groups) => {
+                let first = indices.clone().map(|i| groups.first()[i]).collect();
+                let all = indices.map(|i| groups.all()[i].clone()).collect();
+                GroupsProxy::Idx(GroupsIdx::new(
+                    first,
+                    all,
+                    groups.is_sorted_flag() && increasing,
+                ))
+            }
+            GroupsProxy::Slice { groups, rolling } => GroupsProxy::Slice {
+                groups: indices.map(|i| groups[i]).collect(),
+                rolling: *rolling,
+            },
+        }
+    }
+
+    /// Get the first `n` groups.
+    pub fn head(&self, n: usize) -> GroupsProxy {
+        self.take_groups(0..std::cmp::min(n, self.len()), true)
+    }
+
+    /// Get the groups at `indices`, in the order of `indices`.
+    pub fn take(&self, indices: &[IdxSize]) -> PolarsResult<GroupsProxy> {
+        let len = self.len();
+        if let Some(idx) = indices.iter().find(|idx| **idx as usize >= len) {
+            return Err(polars_err!(oob = idx, len));
+        }
+        let increasing = indices.windows(2).all(|w| w[0] < w[1]);
+        Ok(self.take_groups(indices.iter().map(|idx| *idx as usize), increasing))
+    }
+
+    /// Only keep the groups for which `mask` is `true`. Null values in `mask` drop the group.
+    pub fn filter(&self, mask: &BooleanChunked) -> PolarsResult<GroupsProxy> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "filter's length: {} differs from the number of groups: {}",
+            mask.len(), self.len()
+        );
+        let indices = mask
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, keep)| keep.unwrap_or(false).then_some(i))
+            .collect::<Vec<_>>();
+        Ok(self.take_groups(indices.iter().copied(), true))
+    }
+
     pub fn slice(&self, offset: i64, len: usize) -> SlicedGroups {
         // Safety:
         // we create new `Vec`s from the sliced groups. But we wrap them in ManuallyDrop
@@ -9,7 +9,7 @@ mod single_keys_semi_anti;
 pub(super) mod sort_merge;
 
 use std::fmt::{Debug, Display, Formatter};
-use std::hash::{BuildHasher, Hash, Hasher};
+use std::hash::Hash;
 
 use ahash::RandomState;
 #[cfg(feature = "chunked_ids")]
@@ -28,6 +28,8 @@ use single_keys_left::*;
 use single_keys_outer::*;
 #[cfg(feature = "semi_anti_join")]
 use single_keys_semi_anti::*;
+#[cfg(feature = "interval_join")]
+use smartstring::alias::String as SmartString;
 pub use sort_merge::*;
 
 #[cfg(feature = "private")]
@@ -42,8 +44,8 @@ pub use crate::frame::hash_join::multiple_keys::{
     _left_anti_multiple_keys, _left_semi_multiple_keys,
 };
 use crate::hashing::{
-    create_hash_and_keys_threaded_vectorized, prepare_hashed_relation_threaded, this_partition,
-    AsU64, BytesHash,
+    create_hash_and_keys_threaded_vectorized, heavy_hitter_partition,
+    prepare_hashed_relation_threaded, sample_heavy_hitters, this_partition, AsU64, BytesHash,
 };
 use crate::prelude::*;
 use crate::utils::{_set_partition_size, slice_slice, split_ca};
@@ -114,6 +116,173 @@ pub fn _check_categorical_src(l: &DataType, r: &DataType) -> PolarsResult<()> {
     Ok(())
 }
 
+/// The comparison between a left and a right key of a range join.
+#[cfg(feature = "range_join")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    /// `left < right`
+    Lt,
+    /// `left <= right`
+    LtEq,
+    /// `left > right`
+    Gt,
+    /// `left >= right`
+    GtEq,
+}
+
+#[cfg(feature = "range_join")]
+impl InequalityOperator {
+    /// The operator that holds if the operands are swapped.
+    pub fn flip(self) -> Self {
+        use InequalityOperator::*;
+        match self {
+            Lt => Gt,
+            LtEq => GtEq,
+            Gt => Lt,
+            GtEq => LtEq,
+        }
+    }
+}
+
+/// The bounds of the intervals of an interval join that are included.
+#[cfg(feature = "interval_join")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClosedInterval {
+    /// `start <= point < end`
+    Left,
+    /// `start < point <= end`
+    #[default]
+    Right,
+    /// `start <= point <= end`
+    Both,
+    /// `start < point < end`
+    None,
+}
+
+/// Options of an interval join, which joins the intervals `left_on = [start, end]` of the left
+/// table to the points `right_on = [point]` of the right table that fall within them.
+#[cfg(feature = "interval_join")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntervalJoinOptions {
+    pub closed: ClosedInterval,
+    /// Only join the rows that are equal on these columns of the left table ...
+    pub left_by: Option<Vec<SmartString>>,
+    /// ... and these columns of the right table. These columns are not added to the output.
+    pub right_by: Option<Vec<SmartString>>,
+}
+
+/// The algorithm used to find the matching keys of an inner or a left join.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinAlgorithm {
+    /// Use a sort-merge join if the keys are sorted, or if sorting one of the
+    /// keys is expected to be cheaper than a hash join.
+    #[default]
+    Auto,
+    /// Always build and probe a hash table.
+    Hash,
+    /// Sort the keys that are not yet sorted and merge them. Falls back to a
+    /// hash join if the keys are not numeric or contain nulls.
+    SortMerge,
+}
+
+/// The uniqueness of the join keys that is checked before joining, to catch
+/// joins that unexpectedly multiply rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinValidation {
+    /// No check.
+    #[default]
+    ManyToMany,
+    /// The right keys must be unique.
+    ManyToOne,
+    /// The left keys must be unique.
+    OneToMany,
+    /// The keys of both tables must be unique.
+    OneToOne,
+}
+
+impl JoinValidation {
+    /// Whether the keys of the left table must be unique.
+    pub fn unique_left(self) -> bool {
+        matches!(self, JoinValidation::OneToMany | JoinValidation::OneToOne)
+    }
+
+    /// Whether the keys of the right table must be unique.
+    pub fn unique_right(self) -> bool {
+        matches!(self, JoinValidation::ManyToOne | JoinValidation::OneToOne)
+    }
+
+    /// Check the keys of the `left` or right table, if they must be unique. `has_duplicates`
+    /// is only called if they must.
+    pub(super) fn validate_side(
+        self,
+        left: bool,
+        has_duplicates: impl FnOnce() -> bool,
+    ) -> PolarsResult<()> {
+        let must_be_unique = if left {
+            self.unique_left()
+        } else {
+            self.unique_right()
+        };
+        polars_ensure!(
+            !must_be_unique || !has_duplicates(),
+            ComputeError: "join keys did not fulfill {} validation: the {} keys are not unique",
+            self, if left { "left" } else { "right" }
+        );
+        Ok(())
+    }
+}
+
+/// A join key that may be null. Multiple rows with a null key don't make the
+/// keys of a table non-unique.
+pub(super) trait NullableJoinKey {
+    fn is_null_key(&self) -> bool;
+}
+
+macro_rules! impl_non_null_join_key {
+    ($($ty:ty),*) => {
+        $(impl NullableJoinKey for $ty {
+            #[inline]
+            fn is_null_key(&self) -> bool {
+                false
+            }
+        })*
+    };
+}
+
+impl_non_null_join_key!(u32, u64, &[u8]);
+
+impl<T> NullableJoinKey for Option<T> {
+    #[inline]
+    fn is_null_key(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl NullableJoinKey for BytesHash<'_> {
+    #[inline]
+    fn is_null_key(&self) -> bool {
+        self.payload().is_none()
+    }
+}
+
+impl Display for JoinValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use JoinValidation::*;
+        let val = match self {
+            ManyToMany => "m:m",
+            ManyToOne => "m:1",
+            OneToMany => "1:m",
+            OneToOne => "1:1",
+        };
+        write!(f, "{val}")
+    }
+}
+
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinType {
@@ -127,6 +296,14 @@ pub enum JoinType {
     Semi,
     #[cfg(feature = "semi_anti_join")]
     Anti,
+    /// Inner join where every pair of keys `left_on[i]`, `right_on[i]`
+    /// should satisfy the `i`-th operator.
+    #[cfg(feature = "range_join")]
+    Range(Vec<InequalityOperator>),
+    /// Inner join of the intervals `left_on = [start, end]` to the points
+    /// `right_on = [point]` that fall within them.
+    #[cfg(feature = "interval_join")]
+    Interval(IntervalJoinOptions),
 }
 
 impl Display for JoinType {
@@ -143,6 +320,10 @@ impl Display for JoinType {
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
             Anti => "ANTI",
+            #[cfg(feature = "range_join")]
+            Range(_) => "RANGE",
+            #[cfg(feature = "interval_join")]
+            Interval(_) => "INTERVAL",
         };
         write!(f, "{val}")
     }
@@ -447,6 +628,7 @@ impl DataFrame {
         _finish_join(df_left, df_right, suffix.as_deref())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn _left_join_from_series(
         &self,
         other: &DataFrame,
@@ -455,6 +637,8 @@ impl DataFrame {
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         verbose: bool,
+        algorithm: JoinAlgorithm,
+        validate: JoinValidation,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
@@ -472,7 +656,7 @@ impl DataFrame {
             right.as_single_chunk_par();
             s_right = s_right.rechunk();
         }
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose);
+        let ids = sort_or_hash_left(&s_left, &s_right, verbose, algorithm, validate)?;
         left._finish_left_join(ids, &right.drop(s_right.name()).unwrap(), suffix, slice)
     }
 
@@ -514,6 +698,7 @@ impl DataFrame {
         s_right: &Series,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        validate: JoinValidation,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
@@ -522,7 +707,7 @@ impl DataFrame {
         let join_column_index = self.iter().position(|s| s.name() == s_left.name()).unwrap();
 
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
+        let opt_join_tuples = s_left.hash_join_outer(s_right, validate)?;
         let mut opt_join_tuples = &*opt_join_tuples;
 
         if let Some((offset, len)) = slice {
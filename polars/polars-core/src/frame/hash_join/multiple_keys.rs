@@ -28,6 +28,37 @@ pub(crate) unsafe fn compare_df_rows2(
     true
 }
 
+/// Whether the row `idx` of `keys` has a null key.
+fn row_has_null(keys: &DataFrame, idx: IdxSize) -> bool {
+    keys.get_columns()
+        .iter()
+        // Safety: the indices in the hash tables are in bounds.
+        .any(|s| matches!(unsafe { s.get_unchecked(idx as usize) }, AnyValue::Null))
+}
+
+/// Whether the hash tables of a join on multiple `keys` map a row without null keys to more
+/// than one row. `rows` gets the rows of an entry of the tables.
+fn has_duplicate_rows<V>(
+    hash_tbls: &[HashMap<IdxHash, V, IdBuildHasher>],
+    keys: &DataFrame,
+    rows: impl Fn(&V) -> &[IdxSize],
+) -> bool {
+    hash_tbls.iter().any(|tbl| {
+        tbl.values().any(|v| {
+            let rows = rows(v);
+            rows.len() > 1 && !row_has_null(keys, rows[0])
+        })
+    })
+}
+
+/// Whether a row without null keys occurs more than once in `keys`. This builds the same hash
+/// tables as the build side of a join, for the side that is only probed.
+fn keys_have_duplicate_rows(hashes: &[UInt64Chunked], keys: &DataFrame) -> bool {
+    has_duplicate_rows(&create_probe_table(hashes, keys), keys, |rows| {
+        rows.as_slice()
+    })
+}
+
 pub(crate) fn create_probe_table(
     hashes: &[UInt64Chunked],
     keys: &DataFrame,
@@ -179,7 +210,8 @@ pub fn _inner_join_multiple_keys(
     a: &mut DataFrame,
     b: &mut DataFrame,
     swap: bool,
-) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    validate: JoinValidation,
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
     // we assume that the b DataFrame is the shorter relation.
     // b will be used for the build phase.
 
@@ -195,11 +227,17 @@ pub fn _inner_join_multiple_keys(
     // early drop to reduce memory pressure
     drop(build_hashes);
 
+    // `b` is the right table, unless the tables were swapped
+    validate.validate_side(swap, || {
+        has_duplicate_rows(&hash_tbls, b, |rows| rows.as_slice())
+    })?;
+    validate.validate_side(!swap, || keys_have_duplicate_rows(&probe_hashes, a))?;
+
     let n_tables = hash_tbls.len() as u64;
     let offsets = get_offsets(&probe_hashes);
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    Ok(POOL.install(|| {
         probe_hashes
             .into_par_iter()
             .zip(offsets)
@@ -237,7 +275,7 @@ pub fn _inner_join_multiple_keys(
                 results
             })
             .unzip()
-    })
+    }))
 }
 
 #[cfg(feature = "private")]
@@ -251,7 +289,14 @@ pub fn private_left_join_multiple_keys(
 ) -> LeftJoinIds {
     let mut a = DataFrame::new_no_checks(_to_physical_and_bit_repr(a.get_columns()));
     let mut b = DataFrame::new_no_checks(_to_physical_and_bit_repr(b.get_columns()));
-    _left_join_multiple_keys(&mut a, &mut b, chunk_mapping_left, chunk_mapping_right)
+    _left_join_multiple_keys(
+        &mut a,
+        &mut b,
+        chunk_mapping_left,
+        chunk_mapping_right,
+        JoinValidation::ManyToMany,
+    )
+    .unwrap()
 }
 
 pub fn _left_join_multiple_keys(
@@ -261,7 +306,8 @@ pub fn _left_join_multiple_keys(
     // only needed if we have non contiguous memory
     chunk_mapping_left: Option<&[ChunkId]>,
     chunk_mapping_right: Option<&[ChunkId]>,
-) -> LeftJoinIds {
+    validate: JoinValidation,
+) -> PolarsResult<LeftJoinIds> {
     // we should not join on logical types
     debug_assert!(!a.iter().any(|s| s.dtype().is_logical()));
     debug_assert!(!b.iter().any(|s| s.dtype().is_logical()));
@@ -278,6 +324,11 @@ pub fn _left_join_multiple_keys(
     // early drop to reduce memory pressure
     drop(build_hashes);
 
+    validate.validate_side(false, || {
+        has_duplicate_rows(&hash_tbls, b, |rows| rows.as_slice())
+    })?;
+    validate.validate_side(true, || keys_have_duplicate_rows(&probe_hashes, a))?;
+
     let n_tables = hash_tbls.len() as u64;
     let offsets = get_offsets(&probe_hashes);
 
@@ -337,7 +388,7 @@ pub fn _left_join_multiple_keys(
             })
             .collect::<Vec<_>>()
     });
-    flatten_left_join_ids(results)
+    Ok(flatten_left_join_ids(results))
 }
 
 #[cfg(feature = "semi_anti_join")]
@@ -545,7 +596,8 @@ pub fn _outer_join_multiple_keys(
     a: &mut DataFrame,
     b: &mut DataFrame,
     swap: bool,
-) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+    validate: JoinValidation,
+) -> PolarsResult<Vec<(Option<IdxSize>, Option<IdxSize>)>> {
     // we assume that the b DataFrame is the shorter relation.
     // b will be used for the build phase.
 
@@ -564,6 +616,12 @@ pub fn _outer_join_multiple_keys(
     // early drop to reduce memory pressure
     drop(build_hashes);
 
+    // `b` is the right table, unless the tables were swapped
+    validate.validate_side(swap, || {
+        has_duplicate_rows(&hash_tbls, b, |(_, rows)| rows.as_slice())
+    })?;
+    validate.validate_side(!swap, || keys_have_duplicate_rows(&probe_hashes, a))?;
+
     let n_tables = hash_tbls.len() as u64;
     // probe the hash table.
     // Note: indexes from b that are not matched will be None, Some(idx_b)
@@ -595,5 +653,5 @@ pub fn _outer_join_multiple_keys(
             |idx_b| (None, Some(idx_b)),
         )
     }
-    results
+    Ok(results)
 }
@@ -9,10 +9,19 @@ where
 {
     let n_partitions = _set_partition_size();
 
+    // The indices of keys that dominate the data are collected by all threads, each
+    // taking a row range, instead of by the single thread of their partition.
+    let slices = keys.iter().map(|k| k.as_ref()).collect::<Vec<_>>();
+    let heavy_hitters = sample_heavy_hitters(&slices, n_partitions as u64)
+        .into_iter()
+        .map(|(k, i)| (*k, i))
+        .collect::<PlHashMap<_, _>>();
+    let total_len = slices.iter().map(|k| k.len()).sum::<usize>();
+
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
-    POOL.install(|| {
+    let (mut hash_tbls, heavy_hitter_idx): (Vec<_>, Vec<_>) = POOL.install(|| {
         (0..n_partitions)
             .into_par_iter()
             .map(|partition_no| {
@@ -20,6 +29,7 @@ where
 
                 let mut hash_tbl: PlHashMap<T, Vec<IdxSize>> =
                     PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
+                let mut heavy_hitter_idx = vec![vec![]; heavy_hitters.len()];
 
                 let n_partitions = n_partitions as u64;
                 let mut offset = 0;
@@ -32,7 +42,12 @@ where
                         let idx = cnt + offset;
                         cnt += 1;
 
-                        if this_partition(k.as_u64(), partition_no, n_partitions) {
+                        if let Some(&i) = heavy_hitters.get(k) {
+                            if heavy_hitter_partition(idx, total_len, n_partitions) == partition_no
+                            {
+                                unsafe { heavy_hitter_idx.get_unchecked_mut(i).push(idx) }
+                            }
+                        } else if this_partition(k.as_u64(), partition_no, n_partitions) {
                             let entry = hash_tbl.entry(*k);
 
                             match entry {
@@ -48,10 +63,43 @@ where
                     });
                     offset += len;
                 }
-                hash_tbl
+                (hash_tbl, heavy_hitter_idx)
             })
-            .collect()
-    })
+            .unzip()
+    });
+
+    // The row ranges are in thread order, so concatenating keeps the indices sorted.
+    for (k, i) in heavy_hitters {
+        let idx = heavy_hitter_idx
+            .iter()
+            .flat_map(|idx| idx[i].iter().copied())
+            .collect::<Vec<_>>();
+        let partition_no = (0..n_partitions)
+            .find(|i| this_partition(k.as_u64(), *i as u64, n_partitions as u64))
+            .unwrap();
+        hash_tbls[partition_no].insert(k, idx);
+    }
+    hash_tbls
+}
+
+/// Whether the hash tables of a join map a non-null key to more than one row.
+pub(super) fn has_duplicate_keys<T: NullableJoinKey>(
+    hash_tbls: &[PlHashMap<T, Vec<IdxSize>>],
+) -> bool {
+    hash_tbls
+        .iter()
+        .any(|tbl| tbl.iter().any(|(k, idx)| idx.len() > 1 && !k.is_null_key()))
+}
+
+/// Whether a non-null key occurs more than once in `keys`. This builds the same hash tables
+/// as the build side of a join, for the side that is only probed.
+pub(super) fn keys_have_duplicates<T, IntoSlice>(keys: &[IntoSlice]) -> bool
+where
+    T: Send + Hash + Eq + Sync + Copy + AsU64 + NullableJoinKey,
+    IntoSlice: AsRef<[T]> + Send + Sync,
+{
+    let keys = keys.iter().map(|k| k.as_ref()).collect::<Vec<_>>();
+    has_duplicate_keys(&create_probe_table(keys))
 }
 
 // we determine the offset so that we later know which index to store in the join tuples
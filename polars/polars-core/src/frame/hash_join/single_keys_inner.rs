@@ -1,7 +1,7 @@
 use polars_utils::iter::EnumerateIdxTrait;
 use polars_utils::sync::SyncPtr;
 
-use super::single_keys::create_probe_table;
+use super::single_keys::{create_probe_table, has_duplicate_keys, keys_have_duplicates};
 use super::*;
 use crate::frame::hash_join::single_keys::probe_to_offsets;
 use crate::utils::flatten;
@@ -39,15 +39,19 @@ pub(super) fn hash_join_tuples_inner<T, IntoSlice>(
     build: Vec<IntoSlice>,
     // Because b should be the shorter relation we could need to swap to keep left left and right right.
     swap: bool,
-) -> (Vec<IdxSize>, Vec<IdxSize>)
+    validate: JoinValidation,
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)>
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
-    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    T: Send + Hash + Eq + Sync + Copy + AsU64 + NullableJoinKey,
 {
     // NOTE: see the left join for more elaborate comments
 
+    // the probe side is the left table, unless the tables were swapped
+    validate.validate_side(!swap, || keys_have_duplicates(&probe))?;
     // first we hash one relation
     let hash_tbls = create_probe_table(build);
+    validate.validate_side(swap, || has_duplicate_keys(&hash_tbls))?;
 
     let n_tables = hash_tbls.len() as u64;
     debug_assert!(n_tables.is_power_of_two());
@@ -122,6 +126,6 @@ where
             right.set_len(cap);
         }
 
-        (left, right)
+        Ok((left, right))
     })
 }
@@ -1,6 +1,6 @@
 use polars_utils::flatten;
 
-use super::single_keys::create_probe_table;
+use super::single_keys::{create_probe_table, has_duplicate_keys, keys_have_duplicates};
 use super::*;
 use crate::frame::hash_join::single_keys::probe_to_offsets;
 
@@ -104,13 +104,16 @@ pub(super) fn hash_join_tuples_left<T, IntoSlice>(
     // only needed if we have non contiguous memory
     chunk_mapping_left: Option<&[ChunkId]>,
     chunk_mapping_right: Option<&[ChunkId]>,
-) -> LeftJoinIds
+    validate: JoinValidation,
+) -> PolarsResult<LeftJoinIds>
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
-    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    T: Send + Hash + Eq + Sync + Copy + AsU64 + NullableJoinKey,
 {
+    validate.validate_side(true, || keys_have_duplicates(&probe))?;
     // first we hash one relation
     let hash_tbls = create_probe_table(build);
+    validate.validate_side(false, || has_duplicate_keys(&hash_tbls))?;
 
     // we determine the offset so that we later know which index to store in the join tuples
     let offsets = probe_to_offsets(&probe);
@@ -167,5 +170,5 @@ where
             .collect()
     });
 
-    flatten_left_join_ids(result)
+    Ok(flatten_left_join_ids(result))
 }
@@ -64,11 +64,12 @@ pub(super) fn hash_join_tuples_outer<T, I, J>(
     a: Vec<I>,
     b: Vec<J>,
     swap: bool,
-) -> Vec<(Option<IdxSize>, Option<IdxSize>)>
+    validate: JoinValidation,
+) -> PolarsResult<Vec<(Option<IdxSize>, Option<IdxSize>)>>
 where
     I: Iterator<Item = T> + Send + TrustedLen,
     J: Iterator<Item = T> + Send + TrustedLen,
-    T: Hash + Eq + Copy + Sync + Send,
+    T: Hash + Eq + Copy + Sync + Send + NullableJoinKey,
 {
     // This function is partially multi-threaded.
     // Parts that are done in parallel:
@@ -89,6 +90,22 @@ where
     // we pre hash the probing values
     let (probe_hashes, _) = create_hash_and_keys_threaded_vectorized(a, Some(random_state));
 
+    // `a` is the left table, unless the tables were swapped
+    validate.validate_side(swap, || {
+        hash_tbls.iter().any(|tbl| {
+            tbl.iter()
+                .any(|(k, (_, idx))| idx.len() > 1 && !k.is_null_key())
+        })
+    })?;
+    validate.validate_side(!swap, || {
+        let mut seen = PlHashSet::with_capacity(HASHMAP_INIT_SIZE);
+        !probe_hashes
+            .iter()
+            .flatten()
+            .filter(|(_, k)| !k.is_null_key())
+            .all(|(_, k)| seen.insert(*k))
+    })?;
+
     let n_tables = hash_tbls.len() as u64;
 
     // probe the hash table.
@@ -117,5 +134,5 @@ where
             |idx_b| (None, Some(idx_b)),
         )
     }
-    results
+    Ok(results)
 }
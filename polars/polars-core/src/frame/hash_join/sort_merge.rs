@@ -1,3 +1,6 @@
+#[cfg(feature = "performant")]
+use std::borrow::Cow;
+
 #[cfg(feature = "performant")]
 use polars_arrow::kernels::sorted_join;
 #[cfg(feature = "performant")]
@@ -173,148 +176,252 @@ fn create_reverse_map_from_arg_sort(mut arg_sort: IdxCa) -> Vec<IdxSize> {
     primitive_to_vec::<IdxSize>(arr).unwrap()
 }
 
+/// Sort `s` ascending. Returns the sorted keys and, for every sorted
+/// position, the position in `s` it came from.
+#[cfg(feature = "performant")]
+fn sort_for_merge(s: &Series) -> (Series, Vec<IdxSize>) {
+    let sort_idx = s.arg_sort(SortOptions {
+        descending: false,
+        nulls_last: false,
+        multithreaded: true,
+    });
+    // safety: arg_sort indices are in bounds
+    let sorted = unsafe { s.take_unchecked(&sort_idx).unwrap() };
+    (sorted, create_reverse_map_from_arg_sort(sort_idx))
+}
+
+/// Map positions in a sorted key back to positions in the original key.
+#[cfg(feature = "performant")]
+fn remap_ids(ids: &mut [IdxSize], reverse_idx_map: &[IdxSize]) {
+    POOL.install(|| {
+        ids.par_iter_mut().for_each(|idx| {
+            *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
+        });
+    });
+}
+
+#[cfg(feature = "performant")]
+fn size_factor_acceptable() -> f32 {
+    std::env::var("POLARS_JOIN_SORT_FACTOR")
+        .map(|s| s.parse::<f32>().unwrap())
+        .unwrap_or(1.0)
+}
+
+/// Whether a merge join can be done on these keys at all.
+#[cfg(feature = "performant")]
+fn can_merge(s_left: &Series, s_right: &Series) -> bool {
+    s_left.dtype().to_physical().is_numeric()
+        && s_left.null_count() == 0
+        && s_right.null_count() == 0
+}
+
 #[cfg(not(feature = "performant"))]
 pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
     _verbose: bool,
-) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
-    s_left.hash_join_inner(s_right)
+    _algorithm: JoinAlgorithm,
+    validate: JoinValidation,
+) -> PolarsResult<((Vec<IdxSize>, Vec<IdxSize>), bool)> {
+    s_left.hash_join_inner(s_right, validate)
 }
 
+/// Compute the join tuples of an inner join. The returned flag indicates
+/// whether the left tuples are in the order of the left key.
+///
+/// The keys are validated with the hash tables of the hash join, so a join
+/// that is validated is always a hash join.
 #[cfg(feature = "performant")]
 pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
     verbose: bool,
-) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
+    algorithm: JoinAlgorithm,
+    validate: JoinValidation,
+) -> PolarsResult<((Vec<IdxSize>, Vec<IdxSize>), bool)> {
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
     // If one of the keys is not, it can still be faster to sort that key and use
     // the `arg_sort` indices to revert the sort once the join keys are determined.
+    if validate != JoinValidation::ManyToMany {
+        if verbose && algorithm == JoinAlgorithm::SortMerge {
+            eprintln!("inner join: keys are validated: use hash join");
+        }
+        return s_left.hash_join_inner(s_right, validate);
+    }
+    if algorithm == JoinAlgorithm::Hash || !can_merge(s_left, s_right) {
+        if verbose && algorithm == JoinAlgorithm::SortMerge {
+            eprintln!("inner join: keys are not numeric or have nulls: use hash join");
+        }
+        return s_left.hash_join_inner(s_right, validate);
+    }
+    let force = algorithm == JoinAlgorithm::SortMerge;
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
     let size_factor_lhs = s_left.len() as f32 / s_right.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
-    let is_numeric = s_left.dtype().to_physical().is_numeric();
-
-    let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
-    match (s_left.is_sorted_flag(), s_right.is_sorted_flag(), no_nulls) {
-        (IsSorted::Ascending, IsSorted::Ascending, true) if is_numeric => {
-            if verbose {
-                eprintln!("inner join: keys are sorted: use sorted merge join");
-            }
-            (par_sorted_merge_inner_no_nulls(s_left, s_right), true)
-        }
-        (IsSorted::Ascending, _, true)
-            if is_numeric && size_factor_rhs < size_factor_acceptable =>
-        {
-            if verbose {
-                eprintln!("right key will be descending sorted in inner join operation.")
-            }
-
-            let sort_idx = s_right.arg_sort(SortOptions {
-                descending: false,
-                nulls_last: false,
-                multithreaded: true,
-            });
-            let s_right = unsafe { s_right.take_unchecked(&sort_idx).unwrap() };
-            let ids = par_sorted_merge_inner_no_nulls(s_left, &s_right);
-            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
-
-            let (left, mut right) = ids;
-
-            POOL.install(|| {
-                right.par_iter_mut().for_each(|idx| {
-                    *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
-                });
-            });
-
-            ((left, right), true)
+    let size_factor_acceptable = size_factor_acceptable();
+
+    let left_sorted = s_left.is_sorted_flag() == IsSorted::Ascending;
+    let right_sorted = s_right.is_sorted_flag() == IsSorted::Ascending;
+    let (sort_left, sort_right) = match (left_sorted, right_sorted) {
+        (true, true) => (false, false),
+        (true, false) if force || size_factor_rhs < size_factor_acceptable => (false, true),
+        (false, true) if force || size_factor_lhs < size_factor_acceptable => (true, false),
+        (false, false) if force => (true, true),
+        _ => return s_left.hash_join_inner(s_right, validate),
+    };
+    if verbose {
+        match (sort_left, sort_right) {
+            (false, false) => eprintln!("inner join: keys are sorted: use sorted merge join"),
+            (false, true) => eprintln!("right key will be sorted in inner join operation."),
+            (true, false) => eprintln!("left key will be sorted in inner join operation."),
+            (true, true) => eprintln!("both keys will be sorted in inner join operation."),
         }
-        (_, IsSorted::Ascending, true)
-            if is_numeric && size_factor_lhs < size_factor_acceptable =>
-        {
-            if verbose {
-                eprintln!("left key will be descending sorted in inner join operation.")
-            }
-
-            let sort_idx = s_left.arg_sort(SortOptions {
-                descending: false,
-                nulls_last: false,
-                multithreaded: true,
-            });
-            let s_left = unsafe { s_left.take_unchecked(&sort_idx).unwrap() };
-            let ids = par_sorted_merge_inner_no_nulls(&s_left, s_right);
-            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
-
-            let (mut left, right) = ids;
-
-            POOL.install(|| {
-                left.par_iter_mut().for_each(|idx| {
-                    *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
-                });
-            });
+    }
 
-            // set sorted to `false` as we descending sorted the left key.
-            ((left, right), false)
-        }
-        _ => s_left.hash_join_inner(s_right),
+    let (s_left, left_map) = if sort_left {
+        let (s, map) = sort_for_merge(s_left);
+        (Cow::Owned(s), Some(map))
+    } else {
+        (Cow::Borrowed(s_left), None)
+    };
+    let (s_right, right_map) = if sort_right {
+        let (s, map) = sort_for_merge(s_right);
+        (Cow::Owned(s), Some(map))
+    } else {
+        (Cow::Borrowed(s_right), None)
+    };
+
+    let (mut left, mut right) = par_sorted_merge_inner_no_nulls(&s_left, &s_right);
+    if let Some(map) = &left_map {
+        remap_ids(&mut left, map);
+    }
+    if let Some(map) = &right_map {
+        remap_ids(&mut right, map);
     }
+    // the left tuples are not in order if we sorted the left key.
+    Ok(((left, right), !sort_left))
 }
 
 #[cfg(not(feature = "performant"))]
-pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, _verbose: bool) -> LeftJoinIds {
-    s_left.hash_join_left(s_right)
+pub(super) fn sort_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    _verbose: bool,
+    _algorithm: JoinAlgorithm,
+    validate: JoinValidation,
+) -> PolarsResult<LeftJoinIds> {
+    s_left.hash_join_left(s_right, validate)
 }
 
 #[cfg(feature = "performant")]
-pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, verbose: bool) -> LeftJoinIds {
-    let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
-    let is_numeric = s_left.dtype().to_physical().is_numeric();
-
-    let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
-
-    match (s_left.is_sorted_flag(), s_right.is_sorted_flag(), no_nulls) {
-        (IsSorted::Ascending, IsSorted::Ascending, true) if is_numeric => {
-            if verbose {
-                eprintln!("left join: keys are sorted: use sorted merge join");
-            }
-            let (left_idx, right_idx) = par_sorted_merge_left(s_left, s_right);
-            to_left_join_ids(left_idx, right_idx)
+pub(super) fn sort_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    verbose: bool,
+    algorithm: JoinAlgorithm,
+    validate: JoinValidation,
+) -> PolarsResult<LeftJoinIds> {
+    if validate != JoinValidation::ManyToMany {
+        if verbose && algorithm == JoinAlgorithm::SortMerge {
+            eprintln!("left join: keys are validated: use hash join");
         }
-        (IsSorted::Ascending, _, true)
-            if is_numeric && size_factor_rhs < size_factor_acceptable =>
-        {
-            if verbose {
-                eprintln!("right key will be reverse sorted in left join operation.")
-            }
-
-            let sort_idx = s_right.arg_sort(SortOptions {
-                descending: false,
-                nulls_last: false,
-                multithreaded: true,
-            });
-            let s_right = unsafe { s_right.take_unchecked(&sort_idx).unwrap() };
+        return s_left.hash_join_left(s_right, validate);
+    }
+    if algorithm == JoinAlgorithm::Hash || !can_merge(s_left, s_right) {
+        if verbose && algorithm == JoinAlgorithm::SortMerge {
+            eprintln!("left join: keys are not numeric or have nulls: use hash join");
+        }
+        return s_left.hash_join_left(s_right, validate);
+    }
+    let force = algorithm == JoinAlgorithm::SortMerge;
+    let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
 
-            let ids = par_sorted_merge_left(s_left, &s_right);
-            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
-            let (left, mut right) = ids;
+    let left_sorted = s_left.is_sorted_flag() == IsSorted::Ascending;
+    let right_sorted = s_right.is_sorted_flag() == IsSorted::Ascending;
+    let sort_right = match (left_sorted, right_sorted) {
+        (true, true) => false,
+        (true, false) if force || size_factor_rhs < size_factor_acceptable() => true,
+        // the output of a left join must be in the order of the left key, so
+        // in `Auto` mode we don't sort the left key.
+        (false, _) if force => !right_sorted,
+        _ => return s_left.hash_join_left(s_right, validate),
+    };
+    if verbose {
+        match (left_sorted, sort_right) {
+            (true, false) => eprintln!("left join: keys are sorted: use sorted merge join"),
+            (true, true) => eprintln!("right key will be sorted in left join operation."),
+            (false, _) => eprintln!("left key will be sorted in left join operation."),
+        }
+    }
 
-            POOL.install(|| {
-                right.par_iter_mut().for_each(|opt_idx| {
-                    *opt_idx =
-                        opt_idx.map(|idx| unsafe { *reverse_idx_map.get_unchecked(idx as usize) });
-                });
+    let (s_left, left_map) = if left_sorted {
+        (Cow::Borrowed(s_left), None)
+    } else {
+        let (s, map) = sort_for_merge(s_left);
+        (Cow::Owned(s), Some(map))
+    };
+    let (s_right, right_map) = if sort_right {
+        let (s, map) = sort_for_merge(s_right);
+        (Cow::Owned(s), Some(map))
+    } else {
+        (Cow::Borrowed(s_right), None)
+    };
+
+    let (mut left, mut right) = par_sorted_merge_left(&s_left, &s_right);
+    if let Some(map) = &right_map {
+        POOL.install(|| {
+            right.par_iter_mut().for_each(|opt_idx| {
+                *opt_idx = opt_idx.map(|idx| unsafe { *map.get_unchecked(idx as usize) });
             });
+        });
+    }
+    if let Some(map) = &left_map {
+        remap_ids(&mut left, map);
+        // restore the order of the left key, the sort is stable so the
+        // matches of a left row stay in the order of the right key.
+        let mut tuples = left.into_iter().zip(right).collect::<Vec<_>>();
+        POOL.install(|| tuples.par_sort_by_key(|t| t.0));
+        (left, right) = tuples.into_iter().unzip();
+    }
+    Ok(to_left_join_ids(left, right))
+}
 
-            to_left_join_ids(left, right)
+#[cfg(all(test, feature = "performant"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_forced_sort_merge_join() {
+        let s_left = Series::new("a", [3i32, 1, 2, 1]);
+        let s_right = Series::new("b", [1i32, 3, 1]);
+
+        let ((left, right), _) = _sort_or_hash_inner(
+            &s_left,
+            &s_right,
+            false,
+            JoinAlgorithm::SortMerge,
+            JoinValidation::ManyToMany,
+        )
+        .unwrap();
+        let mut tuples = left.into_iter().zip(right).collect::<Vec<_>>();
+        tuples.sort_unstable();
+        assert_eq!(tuples, &[(0, 1), (1, 0), (1, 2), (3, 0), (3, 2)]);
+
+        // the output of a left join keeps the order of the left key
+        #[cfg(feature = "chunked_ids")]
+        {
+            let (left, right) = sort_or_hash_left(
+                &s_left,
+                &s_right,
+                false,
+                JoinAlgorithm::SortMerge,
+                JoinValidation::ManyToMany,
+            )
+            .unwrap();
+            let left = left.left().unwrap();
+            let right = right.left().unwrap();
+            assert_eq!(left, &[0, 1, 1, 2, 3, 3]);
+            assert_eq!(right, &[Some(1), Some(0), Some(2), None, Some(0), Some(2)]);
         }
-        // don't reverse sort a left join key yet. Have to figure out how to set sorted flag
-        _ => s_left.hash_join_left(s_right),
     }
 }
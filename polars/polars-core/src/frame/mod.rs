@@ -21,9 +21,12 @@ mod chunks;
 #[cfg(feature = "cross_join")]
 pub(crate) mod cross_join;
 pub mod explode;
+mod ffi;
 mod from;
 pub mod groupby;
 pub mod hash_join;
+#[cfg(feature = "arrow-rs")]
+mod record_batch;
 #[cfg(feature = "rows")]
 pub mod row;
 mod top_k;
@@ -41,9 +44,14 @@ use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
 use crate::series::IsSorted;
 use crate::POOL;
 
-#[derive(Copy, Clone, Debug)]
+/// How null values are handled when they are combined with other values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NullStrategy {
+    /// Skip the null values, in arithmetic they count as zero.
+    #[default]
     Ignore,
+    /// A null value makes the result null.
     Propagate,
 }
 
@@ -62,6 +70,102 @@ pub enum UniqueKeepStrategy {
     Any,
 }
 
+/// Arguments for [`DataFrame::unnest_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct UnnestOptions {
+    /// Only insert these fields of the struct columns, in this order.
+    /// All fields are inserted if `None`.
+    pub fields: Option<Vec<SmartString>>,
+    /// Name the new columns `{column}{separator}{field}` instead of `{field}`.
+    pub separator: Option<SmartString>,
+    /// The number of struct levels to flatten. Fields that are structs themselves
+    /// are unnested recursively up to this depth; `usize::MAX` flattens all levels.
+    pub max_depth: usize,
+}
+
+impl Default for UnnestOptions {
+    fn default() -> Self {
+        Self {
+            fields: None,
+            separator: None,
+            max_depth: 1,
+        }
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+impl UnnestOptions {
+    fn field_name(&self, parent: &str, name: &str) -> Option<String> {
+        self.separator
+            .as_ref()
+            .map(|sep| format!("{parent}{sep}{name}"))
+    }
+
+    fn unnest_series(&self, s: &Series, depth: usize, out: &mut Vec<Series>) -> PolarsResult<()> {
+        let ca = s.struct_()?;
+        let fields = match (&self.fields, depth) {
+            (Some(names), 0) => names
+                .iter()
+                .map(|name| ca.field_by_name(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            _ => ca.fields().to_vec(),
+        };
+        for mut fld in fields {
+            if let Some(name) = self.field_name(s.name(), fld.name()) {
+                fld.rename(&name);
+            }
+            if depth + 1 < self.max_depth && matches!(fld.dtype(), DataType::Struct(_)) {
+                self.unnest_series(&fld, depth + 1, out)?;
+            } else {
+                out.push(fld);
+            }
+        }
+        Ok(())
+    }
+
+    fn unnest_field_impl(
+        &self,
+        field: &Field,
+        depth: usize,
+        out: &mut Vec<Field>,
+    ) -> PolarsResult<()> {
+        let DataType::Struct(flds) = field.data_type() else {
+            polars_bail!(SchemaMismatch: "expected struct dtype, got: `{}`", field.data_type());
+        };
+        let flds = match (&self.fields, depth) {
+            (Some(names), 0) => names
+                .iter()
+                .map(|name| {
+                    flds.iter()
+                        .find(|fld| fld.name() == name)
+                        .cloned()
+                        .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+            _ => flds.clone(),
+        };
+        for mut fld in flds {
+            if let Some(name) = self.field_name(field.name(), fld.name()) {
+                fld.set_name(name.into());
+            }
+            if depth + 1 < self.max_depth && matches!(fld.data_type(), DataType::Struct(_)) {
+                self.unnest_field_impl(&fld, depth + 1, out)?;
+            } else {
+                out.push(fld);
+            }
+        }
+        Ok(())
+    }
+
+    /// The fields that unnesting a column with this `field` produces.
+    pub fn unnest_field(&self, field: &Field) -> PolarsResult<Vec<Field>> {
+        let mut out = vec![];
+        self.unnest_field_impl(field, 0, &mut out)?;
+        Ok(out)
+    }
+}
+
 /// A contiguous growable collection of `Series` that have the same length.
 ///
 /// ## Use declarations
@@ -2367,6 +2471,22 @@ impl DataFrame {
         DataFrame::new_no_checks(col)
     }
 
+    /// Take every `n`th row, starting at row `offset`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => &[1, 2, 3, 4, 5, 6, 7])?;
+    /// let out = df.gather_every(3, 1)?;
+    /// assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(2), Some(5)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn gather_every(&self, n: usize, offset: usize) -> PolarsResult<Self> {
+        let columns = self.try_apply_columns_par(&|s| s.gather_every(n, offset))?;
+        Ok(DataFrame::new_no_checks(columns))
+    }
+
     /// Iterator over the rows in this `DataFrame` as Arrow RecordBatches.
     ///
     /// # Panics
@@ -3308,18 +3428,32 @@ impl DataFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoVec<String>>(&self, cols: I) -> PolarsResult<DataFrame> {
+        self.unnest_with_options(cols, &UnnestOptions::default())
+    }
+
+    /// Unnest the given `Struct` columns, controlling which fields are inserted, how the
+    /// new columns are named and how deep nested structs are flattened.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options<I: IntoVec<String>>(
+        &self,
+        cols: I,
+        options: &UnnestOptions,
+    ) -> PolarsResult<DataFrame> {
         let cols = cols.into_vec();
-        self.unnest_impl(cols.into_iter().collect())
+        self.unnest_impl(cols.into_iter().collect(), options)
     }
 
     #[cfg(feature = "dtype-struct")]
-    fn unnest_impl(&self, cols: PlHashSet<String>) -> PolarsResult<DataFrame> {
+    fn unnest_impl(
+        &self,
+        cols: PlHashSet<String>,
+        options: &UnnestOptions,
+    ) -> PolarsResult<DataFrame> {
         let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
         let mut count = 0;
         for s in &self.columns {
             if cols.contains(s.name()) {
-                let ca = s.struct_()?;
-                new_cols.extend_from_slice(ca.fields());
+                options.unnest_series(s, 0, &mut new_cols)?;
                 count += 1;
             } else {
                 new_cols.push(s.clone())
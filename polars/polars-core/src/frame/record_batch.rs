@@ -0,0 +1,93 @@
+//! Conversions to and from the [`RecordBatch`] of arrow-rs. The buffers are moved through the
+//! [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+//! so they are only copied if the arrays are converted, see
+//! [`DataFrame::from_arrow_c_stream`].
+use arrow::ffi::ArrowArrayStream;
+use arrow_rs::datatypes::SchemaRef;
+use arrow_rs::error::ArrowError;
+use arrow_rs::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow_rs::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+
+use crate::prelude::*;
+
+fn to_compute_err(err: ArrowError) -> PolarsError {
+    polars_err!(ComputeError: "arrow-rs error: {}", err)
+}
+
+impl DataFrame {
+    /// Convert the `DataFrame` to arrow-rs record batches, one for every chunk, without copying
+    /// the buffers. If the chunks of the columns are not aligned, the columns are rechunked
+    /// first, see [`DataFrame::to_arrow_c_stream`].
+    pub fn to_record_batches(&self) -> PolarsResult<Vec<RecordBatch>> {
+        self.export_record_batches().map(|(_, batches)| batches)
+    }
+
+    fn export_record_batches(&self) -> PolarsResult<(SchemaRef, Vec<RecordBatch>)> {
+        let stream = self.to_arrow_c_stream();
+        // Safety: both are the `ArrowArrayStream` struct of the C stream interface.
+        let stream =
+            unsafe { std::mem::transmute::<ArrowArrayStream, FFI_ArrowArrayStream>(stream) };
+        let reader = ArrowArrayStreamReader::try_new(stream).map_err(to_compute_err)?;
+        let schema = reader.schema();
+        let batches = reader
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_compute_err)?;
+        Ok((schema, batches))
+    }
+}
+
+impl TryFrom<RecordBatch> for DataFrame {
+    type Error = PolarsError;
+
+    fn try_from(batch: RecordBatch) -> PolarsResult<Self> {
+        let schema = batch.schema();
+        let reader = RecordBatchIterator::new([Ok(batch)], schema);
+        let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+        // Safety: both are the `ArrowArrayStream` struct of the C stream interface.
+        unsafe {
+            let stream = std::mem::transmute::<FFI_ArrowArrayStream, ArrowArrayStream>(stream);
+            DataFrame::from_arrow_c_stream(Box::new(stream))
+        }
+    }
+}
+
+impl TryFrom<DataFrame> for RecordBatch {
+    type Error = PolarsError;
+
+    /// The columns are rechunked to a single chunk first, which only copies the columns that
+    /// consist of multiple chunks.
+    fn try_from(mut df: DataFrame) -> PolarsResult<Self> {
+        df.as_single_chunk_par();
+        let (schema, mut batches) = df.export_record_batches()?;
+        Ok(batches
+            .pop()
+            .unwrap_or_else(|| RecordBatch::new_empty(schema)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_record_batch_roundtrip() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i32, 2, 3],
+            "b" => [Some("x"), None, Some("z")],
+        ]?;
+        let other = df.clone();
+        df.vstack_mut(&other)?;
+
+        let batches = df.to_record_batches()?;
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].num_rows(), 3);
+
+        let batch = RecordBatch::try_from(df.clone())?;
+        assert_eq!(batch.num_rows(), 6);
+        assert_eq!(batch.num_columns(), 2);
+
+        let out = DataFrame::try_from(batch)?;
+        assert!(out.frame_equal_missing(&df));
+        Ok(())
+    }
+}
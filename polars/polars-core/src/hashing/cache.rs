@@ -0,0 +1,219 @@
+use arrow::array::{Array, BinaryArray, Utf8Array};
+use once_cell::sync::Lazy;
+use polars_utils::HashSingle;
+
+use super::*;
+
+/// All cached hashes are computed with this hasher, so that the cached hashes of
+/// different arrays can be compared with each other, e.g. in a join.
+static HASH_CACHE_STATE: Lazy<RandomState> = Lazy::new(RandomState::new);
+
+/// Identifies the data of a `LargeUtf8` or `LargeBinary` array by the addresses of its buffers.
+#[derive(PartialEq, Eq)]
+struct DataId {
+    len: usize,
+    offsets: *const i64,
+    values: *const u8,
+    validity: Option<(*const u8, usize)>,
+}
+
+fn data_id(arr: &dyn Array) -> Option<DataId> {
+    let (offsets, values) = match arr.data_type() {
+        ArrowDataType::LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            (
+                arr.offsets().as_slice().as_ptr(),
+                arr.values().as_slice().as_ptr(),
+            )
+        }
+        ArrowDataType::LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            (
+                arr.offsets().as_slice().as_ptr(),
+                arr.values().as_slice().as_ptr(),
+            )
+        }
+        _ => return None,
+    };
+    let validity = arr.validity().map(|bitmap| {
+        let (bytes, offset, _) = bitmap.as_slice();
+        (bytes.as_ptr(), offset)
+    });
+    Some(DataId {
+        len: arr.len(),
+        offsets,
+        values,
+        validity,
+    })
+}
+
+/// The hashes of the values of a `Utf8` or `Binary` [`ChunkedArray`], computed once by
+/// [`Series::with_cached_hashes`] and reused by the group-bys and joins on that array.
+pub(crate) struct HashCache {
+    /// The chunks the hashes were computed for. Holding on to their buffers ensures that
+    /// changed chunks never have the same addresses, so that stale hashes are detected.
+    chunks: Vec<ArrayRef>,
+    hashes: Arc<[u64]>,
+    offset: usize,
+}
+
+impl HashCache {
+    fn is_valid_for(&self, chunks: &[ArrayRef]) -> bool {
+        self.chunks.len() == chunks.len()
+            && self.chunks.iter().zip(chunks).all(|(l, r)| {
+                let l = data_id(l.as_ref());
+                l.is_some() && l == data_id(r.as_ref())
+            })
+    }
+}
+
+impl<T: PolarsDataType> ChunkedArray<T> {
+    fn valid_hash_cache(&self) -> Option<&HashCache> {
+        self.hash_cache
+            .as_deref()
+            .filter(|cache| cache.is_valid_for(&self.chunks))
+    }
+
+    /// The cached hashes of the values, if they were computed and the data didn't change since.
+    pub(crate) fn cached_hashes(&self) -> Option<&[u64]> {
+        self.valid_hash_cache()
+            .map(|cache| &cache.hashes[cache.offset..cache.offset + self.len()])
+    }
+
+    /// Attach the cached hashes of `self` to `out`, which holds the same data as `self`
+    /// from `offset` onwards.
+    pub(crate) fn carry_hash_cache<U: PolarsDataType>(
+        &self,
+        out: &mut ChunkedArray<U>,
+        offset: usize,
+    ) {
+        if let Some(cache) = self.valid_hash_cache() {
+            debug_assert!(cache.offset + offset + out.len() <= cache.hashes.len());
+            out.hash_cache = Some(Arc::new(HashCache {
+                chunks: out.chunks.clone(),
+                hashes: cache.hashes.clone(),
+                offset: cache.offset + offset,
+            }));
+        }
+    }
+}
+
+/// The hasher to hash the values of `cas` with. If any of them has cached hashes, this
+/// is the hasher of the cache, so that [`BinaryChunked::to_bytes_hashes`] can reuse them.
+pub(crate) fn bytes_hash_builder(cas: &[&BinaryChunked]) -> RandomState {
+    if cas.iter().any(|ca| ca.cached_hashes().is_some()) {
+        HASH_CACHE_STATE.clone()
+    } else {
+        RandomState::default()
+    }
+}
+
+impl BinaryChunked {
+    /// Hash every value, or take the cached hashes if there are any. In the latter case
+    /// `hb` must be created by [`bytes_hash_builder`].
+    pub(crate) fn to_bytes_hashes<'a>(&'a self, hb: &RandomState) -> Vec<BytesHash<'a>> {
+        match self.cached_hashes() {
+            Some(hashes) => self
+                .into_iter()
+                .zip(hashes)
+                .map(|(opt_b, hash)| BytesHash::new(opt_b, *hash))
+                .collect(),
+            None => {
+                let null_h = get_null_hash_value(hb.clone());
+                self.into_iter()
+                    .map(|opt_b| {
+                        let hash = match opt_b {
+                            Some(s) => hb.hash_single(s),
+                            None => null_h,
+                        };
+                        BytesHash::new(opt_b, hash)
+                    })
+                    .collect_trusted()
+            }
+        }
+    }
+
+    /// Compute the hashes of the values once, so that group-bys and joins on this array
+    /// don't have to. The hashes are discarded as soon as the data changes.
+    pub fn cache_hashes(&mut self) {
+        if self.cached_hashes().is_some() {
+            return;
+        }
+        let hashes = self
+            .to_bytes_hashes(&HASH_CACHE_STATE)
+            .into_iter()
+            .map(|bh| bh.hash)
+            .collect::<Vec<_>>();
+        self.hash_cache = Some(Arc::new(HashCache {
+            chunks: self.chunks.clone(),
+            hashes: hashes.into(),
+            offset: 0,
+        }));
+    }
+}
+
+impl Utf8Chunked {
+    /// Compute the hashes of the values once, so that group-bys and joins on this array
+    /// don't have to. The hashes are discarded as soon as the data changes.
+    pub fn cache_hashes(&mut self) {
+        if self.cached_hashes().is_some() {
+            return;
+        }
+        // hash the bytes, as the array is hashed as binary in group-bys and joins
+        let mut ca = self.as_binary();
+        ca.cache_hashes();
+        ca.carry_hash_cache(self, 0);
+    }
+}
+
+impl Series {
+    /// Compute the hashes of the values once and attach them to the returned `Series`,
+    /// so that repeated group-bys and joins on it don't have to rehash the values.
+    ///
+    /// Only `Utf8` and `Binary` data is supported. Operations that change the data
+    /// don't keep the hashes.
+    pub fn with_cached_hashes(&self) -> PolarsResult<Series> {
+        match self.dtype() {
+            DataType::Utf8 => {
+                let mut ca = self.utf8().unwrap().clone();
+                ca.cache_hashes();
+                Ok(ca.into_series())
+            }
+            DataType::Binary => {
+                let mut ca = self.binary().unwrap().clone();
+                ca.cache_hashes();
+                Ok(ca.into_series())
+            }
+            dt => polars_bail!(opq = with_cached_hashes, dt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hash_cache_invalidation() {
+        let mut ca = Utf8Chunked::new("a", &[Some("a"), None, Some("b"), Some("a")]);
+        assert!(ca.cached_hashes().is_none());
+        ca.cache_hashes();
+        let hashes = ca.cached_hashes().unwrap().to_vec();
+        assert_eq!(hashes[0], hashes[3]);
+        assert_ne!(hashes[0], hashes[2]);
+
+        // views on the same data keep the hashes
+        assert_eq!(ca.clone().cached_hashes().unwrap(), hashes);
+        assert_eq!(ca.as_binary().cached_hashes().unwrap(), hashes);
+        assert_eq!(ca.slice(1, 2).cached_hashes().unwrap(), &hashes[1..3]);
+
+        // changed data drops them
+        let mut appended = ca.clone();
+        appended.append(&Utf8Chunked::new("a", &["c"]));
+        assert!(appended.cached_hashes().is_none());
+        let mut rechunked = appended.rechunk();
+        assert!(rechunked.cached_hashes().is_none());
+        unsafe { rechunked.chunks_mut() };
+        assert!(rechunked.hash_cache.is_none());
+    }
+}
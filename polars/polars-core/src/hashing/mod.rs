@@ -1,3 +1,4 @@
+mod cache;
 mod fx;
 mod identity;
 pub mod partition;
@@ -6,6 +7,7 @@ pub(crate) mod vector_hasher;
 use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
 
 use ahash::RandomState;
+pub(crate) use cache::*;
 pub use fx::*;
 pub use identity::*;
 pub(crate) use partition::*;
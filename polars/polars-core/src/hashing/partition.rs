@@ -136,3 +136,99 @@ pub fn this_partition(h: u64, thread_no: u64, n_partitions: u64) -> bool {
     // n % 2^i = n & (2^i - 1)
     (h & n_partitions.wrapping_sub(1)) == thread_no
 }
+
+/// Minimal number of keys before we look for heavy hitters.
+const HEAVY_HITTER_MIN_LEN: usize = 1 << 16;
+/// Number of keys that are sampled to find heavy hitters.
+const HEAVY_HITTER_SAMPLE_SIZE: usize = 1024;
+
+/// Find the keys that occur so often that the partition they fall in would get
+/// a lot more rows than the other partitions.
+///
+/// The keys are sampled at a fixed interval. A key is a heavy hitter if it is more
+/// than twice as frequent as a partition would be with perfectly spread keys, so at
+/// most `n_partitions / 2` keys are returned. They map to their index in
+/// `0..n_heavy_hitters`, the most frequent first.
+pub(crate) fn sample_heavy_hitters<T, I>(keys: &[I], n_partitions: u64) -> PlHashMap<T, usize>
+where
+    I: IntoIterator<Item = T> + Copy,
+    I::IntoIter: ExactSizeIterator,
+    T: Hash + Eq,
+{
+    let len = keys.iter().map(|k| k.into_iter().len()).sum::<usize>();
+    if n_partitions < 2 || len < HEAVY_HITTER_MIN_LEN {
+        return PlHashMap::new();
+    }
+    let step = len / HEAVY_HITTER_SAMPLE_SIZE;
+
+    let mut counts = PlHashMap::with_capacity(HEAVY_HITTER_SAMPLE_SIZE);
+    let mut n_sampled = 0usize;
+    for keys in keys {
+        for k in keys.into_iter().step_by(step) {
+            *counts.entry(k).or_insert(0usize) += 1;
+            n_sampled += 1;
+        }
+    }
+    let threshold = 2 * n_sampled / n_partitions as usize;
+    let mut heavy_hitters = counts
+        .into_iter()
+        .filter(|(_, count)| *count > threshold)
+        .collect::<Vec<_>>();
+    heavy_hitters.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+    heavy_hitters
+        .into_iter()
+        .enumerate()
+        .map(|(i, (k, _))| (k, i))
+        .collect()
+}
+
+/// The partition that collects the rows of the heavy hitters with index `row_idx`.
+///
+/// Heavy hitters are not partitioned by key but by row range, so that every thread
+/// gets an equal share of them.
+#[inline]
+pub(crate) fn heavy_hitter_partition(row_idx: IdxSize, len: usize, n_partitions: u64) -> u64 {
+    let rows_per_partition = std::cmp::max(len as u64 / n_partitions, 1);
+    std::cmp::min(row_idx as u64 / rows_per_partition, n_partitions - 1)
+}
+
+/// Number of leading hashes that are sampled to estimate the number of groups.
+const CARDINALITY_SAMPLE_SIZE: usize = 4096;
+/// Below this number of groups a single hash table is built on one thread.
+const SINGLE_THREAD_MAX_GROUPS: usize = 64;
+/// Number of groups a partition's hash table should hold to stay cache friendly.
+const GROUPS_PER_PARTITION: usize = 1 << 14;
+
+/// Choose the number of partitions of a threaded groupby by estimating the number
+/// of groups from the first hashes.
+///
+/// Every partition traverses all hashes, so with few groups more partitions only
+/// cost memory bandwidth, whereas with many groups a single hash table no longer
+/// fits in cache. The result is a power of two no larger than `n_partitions`.
+pub(crate) fn estimate_n_partitions<I>(hashes: I, len: usize, n_partitions: u64) -> u64
+where
+    I: IntoIterator<Item = u64>,
+{
+    let mut distinct = PlHashSet::with_capacity(CARDINALITY_SAMPLE_SIZE);
+    let mut n_sampled = 0usize;
+    for h in hashes.into_iter().take(CARDINALITY_SAMPLE_SIZE) {
+        distinct.insert(h);
+        n_sampled += 1;
+    }
+    if n_sampled == 0 {
+        return 1;
+    }
+    let n_distinct = distinct.len();
+    // If the keys repeat a lot in the sample, we have likely seen most of them,
+    // otherwise we extrapolate to the full length.
+    let estimated_groups = if n_distinct * 2 <= n_sampled {
+        n_distinct
+    } else {
+        (n_distinct as f64 / n_sampled as f64 * len as f64) as usize
+    };
+    if estimated_groups <= SINGLE_THREAD_MAX_GROUPS {
+        return 1;
+    }
+    let needed = (estimated_groups / GROUPS_PER_PARTITION + 1).next_power_of_two() as u64;
+    std::cmp::min(needed, n_partitions)
+}
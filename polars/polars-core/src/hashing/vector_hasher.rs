@@ -353,6 +353,11 @@ impl<'a> BytesHash<'a> {
     pub(crate) fn new(s: Option<&'a [u8]>, hash: u64) -> Self {
         Self { payload: s, hash }
     }
+
+    #[inline]
+    pub(crate) fn payload(&self) -> Option<&'a [u8]> {
+        self.payload
+    }
 }
 
 impl<'a> PartialEq for BytesHash<'a> {
@@ -17,6 +17,8 @@ pub use crate::chunked_array::builder::{
 pub use crate::chunked_array::iterator::PolarsIterator;
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::*;
+#[cfg(feature = "ndarray")]
+pub use crate::chunked_array::ndarray::IndexOrder;
 #[cfg(feature = "object")]
 pub use crate::chunked_array::object::PolarsObject;
 pub use crate::chunked_array::ops::aggregate::*;
@@ -36,9 +38,13 @@ pub use crate::frame::asof_join::*;
 pub use crate::frame::explode::MeltArgs;
 pub(crate) use crate::frame::groupby::aggregations::*;
 pub use crate::frame::groupby::{GroupsIdx, GroupsProxy, GroupsSlice, IntoGroupsProxy};
-pub use crate::frame::hash_join::JoinType;
+#[cfg(feature = "range_join")]
+pub use crate::frame::hash_join::InequalityOperator;
 pub(crate) use crate::frame::hash_join::*;
-pub use crate::frame::{DataFrame, UniqueKeepStrategy};
+#[cfg(feature = "interval_join")]
+pub use crate::frame::hash_join::{ClosedInterval, IntervalJoinOptions};
+pub use crate::frame::hash_join::{JoinAlgorithm, JoinType, JoinValidation};
+pub use crate::frame::{DataFrame, NullStrategy, UniqueKeepStrategy, UnnestOptions};
 pub use crate::hashing::{FxHash, VecHash};
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
 pub use crate::schema::*;
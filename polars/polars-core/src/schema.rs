@@ -6,12 +6,31 @@ use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
+use crate::utils::try_get_supertype;
 
 /// A map from field/column name (`String`) to the type of that field/column (`DataType`)
 #[derive(Eq, Clone, Default)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct Schema {
     inner: PlIndexMap<SmartString, DataType>,
+    /// The metadata of the fields that have any.
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    metadata: PlHashMap<SmartString, FieldMetadata>,
+}
+
+/// How [`Schema::try_merge`] resolves a field that is in both schemas with a different dtype, or
+/// with a different value for the same metadata key.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SchemaMergePolicy {
+    /// Raise an error.
+    #[default]
+    Strict,
+    /// Keep the dtype and metadata of `self`.
+    KeepLeft,
+    /// Take the dtype and metadata of `other`.
+    KeepRight,
+    /// Take the supertype of both dtypes. Conflicting metadata raises an error.
+    Supertype,
 }
 
 // Schemas will only compare equal if they have the same fields in the same order. We can't use `self.inner ==
@@ -40,6 +59,7 @@ where
         let iter = iter.into_iter();
         let mut map: PlIndexMap<_, _> =
             IndexMap::with_capacity_and_hasher(iter.size_hint().0, ahash::RandomState::default());
+        let mut metadata = PlHashMap::new();
         for fld in iter {
             let fld = fld.into();
 
@@ -58,8 +78,14 @@ where
             };
 
             map.insert(fld.name().clone(), fld.data_type().clone());
+            if !fld.metadata().is_empty() {
+                metadata.insert(fld.name, fld.metadata);
+            }
+        }
+        Self {
+            inner: map,
+            metadata,
         }
-        Self { inner: map }
     }
 }
 
@@ -77,7 +103,10 @@ impl Schema {
     pub fn with_capacity(capacity: usize) -> Self {
         let map: PlIndexMap<_, _> =
             IndexMap::with_capacity_and_hasher(capacity, ahash::RandomState::default());
-        Self { inner: map }
+        Self {
+            inner: map,
+            metadata: Default::default(),
+        }
     }
 
     /// The number of fields in the schema
@@ -99,10 +128,13 @@ impl Schema {
         // Remove `old`, get the corresponding index and dtype, and move the last item in the map to that position
         let (old_index, old_name, dtype) = self.inner.swap_remove_full(old)?;
         // Insert the same dtype under the new name at the end of the map and store that index
-        let (new_index, _) = self.inner.insert_full(new, dtype);
+        let (new_index, _) = self.inner.insert_full(new.clone(), dtype);
         // Swap the two indices to move the originally last element back to the end and to move the new element back to
         // its original position
         self.inner.swap_indices(old_index, new_index);
+        if let Some(metadata) = self.metadata.remove(old) {
+            self.metadata.insert(new, metadata);
+        }
 
         Some(old_name)
     }
@@ -133,7 +165,10 @@ impl Schema {
                     self.len()
         );
 
-        let mut new = Self::default();
+        let mut new = Self {
+            metadata: self.metadata.clone(),
+            ..Default::default()
+        };
         let mut iter = self.inner.iter().filter_map(|(fld_name, dtype)| {
             (fld_name != &name).then_some((fld_name.clone(), dtype.clone()))
         });
@@ -218,7 +253,7 @@ impl Schema {
     pub fn get_field(&self, name: &str) -> Option<Field> {
         self.inner
             .get(name)
-            .map(|dtype| Field::new(name, dtype.clone()))
+            .map(|dtype| self.make_field(name, dtype))
     }
 
     /// Look up the name in the schema and return an owned [`Field`] by cloning the data
@@ -231,7 +266,35 @@ impl Schema {
         self.inner
             .get(name)
             .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
-            .map(|dtype| Field::new(name, dtype.clone()))
+            .map(|dtype| self.make_field(name, dtype))
+    }
+
+    fn make_field(&self, name: &str, dtype: &DataType) -> Field {
+        let field = Field::new(name, dtype.clone());
+        match self.metadata.get(name) {
+            Some(metadata) => field.with_metadata(metadata.clone()),
+            None => field,
+        }
+    }
+
+    /// Get a reference to the metadata of the field named `name`, or `None` if the field has no metadata
+    pub fn metadata(&self, name: &str) -> Option<&FieldMetadata> {
+        self.metadata.get(name)
+    }
+
+    /// Change the metadata of the field named `name`
+    ///
+    /// If `name` doesn't exist in the schema, the schema is not modified and `false` is returned.
+    pub fn set_metadata(&mut self, name: &str, metadata: FieldMetadata) -> bool {
+        let Some((_, name, _)) = self.inner.get_full(name) else {
+            return false;
+        };
+        if metadata.is_empty() {
+            self.metadata.remove(name);
+        } else {
+            self.metadata.insert(name.clone(), metadata);
+        }
+        true
     }
 
     /// Get references to the name and dtype of the field at `index`
@@ -258,6 +321,7 @@ impl Schema {
     /// is replaced by the last field, which takes its position. For a slower, but order-preserving, method, use
     /// [`shift_remove`][Self::shift_remove].
     pub fn remove(&mut self, name: &str) -> Option<DataType> {
+        self.metadata.remove(name);
         self.inner.swap_remove(name)
     }
 
@@ -268,6 +332,7 @@ impl Schema {
     /// This method does a `shift_remove`, which preserves the order of the fields in the schema but **is O(n)**. For a
     /// faster, but not order-preserving, method, use [`remove`][Self::remove].
     pub fn shift_remove(&mut self, name: &str) -> Option<DataType> {
+        self.metadata.remove(name);
         self.inner.shift_remove(name)
     }
 
@@ -324,16 +389,68 @@ impl Schema {
     /// - Fields that occur in both `self` and `other` are updated with the dtype from `other`, but keep their original
     ///   index
     pub fn merge(&mut self, other: Self) {
-        self.inner.extend(other.inner)
+        self.inner.extend(other.inner);
+        self.metadata.extend(other.metadata)
+    }
+
+    /// Merge `other` into `self`, resolving conflicting fields with `policy`
+    ///
+    /// Fields that occur in `other` but not `self` are appended, in order, to the end of `self`. For fields that occur
+    /// in both, the metadata keys of `other` are added to the metadata of `self`. A field conflicts if the dtypes
+    /// differ, or if a metadata key has a different value in both schemas; see [`SchemaMergePolicy`].
+    ///
+    /// If the schemas can't be merged, `self` is not modified.
+    pub fn try_merge(&mut self, other: &Self, policy: SchemaMergePolicy) -> PolarsResult<()> {
+        use SchemaMergePolicy::*;
+
+        let mut merged = self.clone();
+        for (name, dtype) in other.iter() {
+            match merged.inner.get_mut(name) {
+                None => {
+                    merged.inner.insert(name.clone(), dtype.clone());
+                }
+                Some(merged_dtype) if *merged_dtype != *dtype => match policy {
+                    Strict => polars_bail!(
+                        SchemaMismatch: "cannot merge field '{}': dtype {} conflicts with {}",
+                        name, merged_dtype, dtype
+                    ),
+                    KeepLeft => {}
+                    KeepRight => *merged_dtype = dtype.clone(),
+                    Supertype => *merged_dtype = try_get_supertype(merged_dtype, dtype)?,
+                },
+                Some(_) => {}
+            }
+
+            let Some(other_metadata) = other.metadata.get(name) else {
+                continue;
+            };
+            let metadata = merged.metadata.entry(name.clone()).or_default();
+            for (key, value) in other_metadata {
+                match metadata.get(key) {
+                    Some(current) if current != value => match policy {
+                        Strict | Supertype => polars_bail!(
+                            SchemaMismatch: "cannot merge field '{}': metadata '{}' is '{}' and '{}'",
+                            name, key, current, value
+                        ),
+                        KeepLeft => {}
+                        KeepRight => {
+                            metadata.insert(key.clone(), value.clone());
+                        }
+                    },
+                    Some(_) => {}
+                    None => {
+                        metadata.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        *self = merged;
+        Ok(())
     }
 
     /// Convert self to `ArrowSchema` by cloning the fields
     pub fn to_arrow(&self) -> ArrowSchema {
-        let fields: Vec<_> = self
-            .inner
-            .iter()
-            .map(|(name, dtype)| ArrowField::new(name.as_str(), dtype.to_arrow(), true))
-            .collect();
+        let fields: Vec<_> = self.iter_fields().map(|field| field.to_arrow()).collect();
         ArrowSchema::from(fields)
     }
 
@@ -344,7 +461,7 @@ impl Schema {
     pub fn iter_fields(&self) -> impl Iterator<Item = Field> + ExactSizeIterator + '_ {
         self.inner
             .iter()
-            .map(|(name, dtype)| Field::new(name, dtype.clone()))
+            .map(|(name, dtype)| self.make_field(name, dtype))
     }
 
     /// Iterates over references to the dtypes in this schema
@@ -400,3 +517,58 @@ impl IndexOfSchema for ArrowSchema {
         self.fields.iter().position(|f| f.name == name)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn metadata(unit: &str) -> FieldMetadata {
+        FieldMetadata::from([("unit".to_string(), unit.to_string())])
+    }
+
+    #[test]
+    fn test_schema_try_merge() -> PolarsResult<()> {
+        let mut left = Schema::from_iter([
+            Field::new("a", DataType::Int32).with_metadata(metadata("m")),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let right = Schema::from_iter([
+            Field::new("a", DataType::Int64).with_metadata(metadata("km")),
+            Field::new("c", DataType::Boolean),
+        ]);
+
+        // a failed merge doesn't modify the schema
+        assert!(left
+            .clone()
+            .try_merge(&right, SchemaMergePolicy::Strict)
+            .is_err());
+        assert!(left
+            .clone()
+            .try_merge(&right, SchemaMergePolicy::Supertype)
+            .is_err());
+
+        let mut merged = left.clone();
+        merged.try_merge(&right, SchemaMergePolicy::KeepLeft)?;
+        assert_eq!(
+            merged.get_field("a"),
+            Some(Field::new("a", DataType::Int32))
+        );
+        assert_eq!(merged.metadata("a"), Some(&metadata("m")));
+        assert_eq!(merged.len(), 3);
+
+        let mut merged = left.clone();
+        merged.try_merge(&right, SchemaMergePolicy::KeepRight)?;
+        assert_eq!(merged.get("a"), Some(&DataType::Int64));
+        assert_eq!(merged.metadata("a"), Some(&metadata("km")));
+
+        left.set_metadata("a", metadata("km"));
+        left.try_merge(&right, SchemaMergePolicy::Supertype)?;
+        assert_eq!(left.get("a"), Some(&DataType::Int64));
+        assert_eq!(left.to_arrow().fields[0].metadata, metadata("km"));
+
+        left.rename("a", "d".into());
+        assert_eq!(left.metadata("d"), Some(&metadata("km")));
+        assert_eq!(left.metadata("a"), None);
+        Ok(())
+    }
+}
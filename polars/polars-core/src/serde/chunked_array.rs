@@ -19,7 +19,7 @@ where
     I: IntoIterator,
     <I as IntoIterator>::Item: Serialize,
 {
-    fn new(iter: I) -> Self {
+    pub(super) fn new(iter: I) -> Self {
         IterSer {
             iter: RefCell::new(Some(iter)),
         }
@@ -141,6 +141,25 @@ impl Serialize for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-array")]
+impl Serialize for ArrayChunked {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let ca = self.to_list();
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry("name", self.name())?;
+        let dtype: DeDataType = self.dtype().into();
+        state.serialize_entry("datatype", &dtype)?;
+        state.serialize_entry("values", &IterSer::new(&ca))?;
+        state.end()
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 impl Serialize for StructChunked {
     fn serialize<S>(
@@ -26,17 +26,32 @@ enum DeDataType<'a> {
     Datetime(TimeUnit, Option<TimeZone>),
     Duration(TimeUnit),
     Time,
+    /// A list of which the inner dtype is inferred from the values, as written by older
+    /// versions.
     List,
     Object(&'a str),
     Null,
     Categorical,
     Struct,
+    // new variants are added at the end, so that older data still decodes with bincode
+    TypedList(DataType),
+    #[cfg(feature = "dtype-array")]
+    Array(DataType, usize),
+    #[cfg(feature = "dtype-decimal")]
+    Decimal(Option<usize>, Option<usize>),
+    /// The categories of an enum, in their declared order.
+    #[cfg(feature = "dtype-categorical")]
+    Enum(Vec<String>),
 }
 
 impl From<&DataType> for DeDataType<'_> {
     fn from(dt: &DataType) -> Self {
         match dt {
+            DataType::Int8 => DeDataType::Int8,
+            DataType::Int16 => DeDataType::Int16,
             DataType::Int32 => DeDataType::Int32,
+            DataType::UInt8 => DeDataType::UInt8,
+            DataType::UInt16 => DeDataType::UInt16,
             DataType::UInt32 => DeDataType::UInt32,
             DataType::Int64 => DeDataType::Int64,
             DataType::UInt64 => DeDataType::UInt64,
@@ -49,13 +64,22 @@ impl From<&DataType> for DeDataType<'_> {
             DataType::Utf8 => DeDataType::Utf8,
             DataType::Boolean => DeDataType::Boolean,
             DataType::Null => DeDataType::Null,
-            DataType::List(_) => DeDataType::List,
+            DataType::List(inner) => DeDataType::TypedList(inner.as_ref().clone()),
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner, width) => DeDataType::Array(inner.as_ref().clone(), *width),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, scale) => DeDataType::Decimal(*precision, *scale),
             DataType::Binary => DeDataType::Binary,
             #[cfg(feature = "object")]
             DataType::Object(s) => DeDataType::Object(s),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => DeDataType::Struct,
             #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rev_map)) if rev_map.is_enum() => {
+                let categories = rev_map.get_enum_categories().unwrap();
+                DeDataType::Enum(categories.values_iter().map(str::to_string).collect())
+            }
+            #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => DeDataType::Categorical,
             _ => unimplemented!(),
         }
@@ -185,6 +209,145 @@ mod test {
         let out = serde_json::from_str::<DataFrame>(&df_str).unwrap();
         assert!(df.frame_equal_missing(&out));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_serde_enum_series() -> PolarsResult<()> {
+        let dtype = DataType::new_enum(["low", "mid", "high"])?;
+        let s = Series::new("a", [Some("high"), None, Some("low")]).strict_cast(&dtype)?;
+
+        let json = serde_json::to_string(&s).unwrap();
+        let out = serde_json::from_str::<Series>(&json).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(out.series_equal_missing(&s));
+
+        let bytes = bincode::serialize(&s).unwrap();
+        let out = bincode::deserialize::<Series>(&bytes).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(out.series_equal_missing(&s));
+
+        // the dtype on its own keeps the categories as well
+        let json = serde_json::to_string(&dtype).unwrap();
+        assert_eq!(serde_json::from_str::<DataType>(&json).unwrap(), dtype);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-i8",
+        feature = "dtype-i16",
+        feature = "dtype-u8",
+        feature = "dtype-u16",
+        feature = "dtype-datetime",
+        feature = "dtype-duration",
+        feature = "dtype-decimal",
+        feature = "dtype-array"
+    ))]
+    fn test_serde_df_all_dtypes() {
+        let ints = Series::new("int", &[Some(1i32), None, Some(3)]);
+        let mut columns = [
+            DataType::Int8,
+            DataType::Int16,
+            DataType::UInt8,
+            DataType::UInt16,
+            DataType::Duration(TimeUnit::Microseconds),
+        ]
+        .iter()
+        .map(|dtype| {
+            let mut s = ints.cast(dtype).unwrap();
+            s.rename(&dtype.to_string());
+            s
+        })
+        .collect::<Vec<_>>();
+
+        let datetime = Int64Chunked::new("datetime", &[Some(1i64), None, Some(3)])
+            .into_datetime(TimeUnit::Milliseconds, Some("UTC".into()));
+        columns.push(datetime.into_series());
+        let decimal: Int128Chunked = [Some(1234i128), None, Some(-5)].into_iter().collect();
+        let mut decimal = decimal.into_decimal(None, 2).unwrap().into_series();
+        decimal.rename("decimal");
+        columns.push(decimal);
+        let pair = Series::new("", &[1i32, 2]);
+        let array = Series::new("array", &[pair.clone(), pair.clone(), pair])
+            .cast(&DataType::Array(Box::new(DataType::Int32), 2))
+            .unwrap();
+        columns.push(array);
+        columns.push(Series::full_null(
+            "null_list",
+            3,
+            &DataType::List(Box::new(DataType::Utf8)),
+        ));
+        columns.push(Series::new_null("null", 3));
+        let df = DataFrame::new(columns).unwrap();
+
+        let json = serde_json::to_string(&df).unwrap();
+        let out = serde_json::from_str::<DataFrame>(&json).unwrap();
+        assert_eq!(out.schema(), df.schema());
+        assert!(df.frame_equal_missing(&out));
+
+        let bytes = bincode::serialize(&df).unwrap();
+        let out = bincode::deserialize::<DataFrame>(&bytes).unwrap();
+        assert_eq!(out.schema(), df.schema());
+        assert!(df.frame_equal_missing(&out));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-struct"
+    ))]
+    fn test_serde_any_value() {
+        let values = vec![
+            AnyValue::Null,
+            AnyValue::Int16(-3),
+            AnyValue::Date(19000),
+            AnyValue::Datetime(10, TimeUnit::Nanoseconds, &None),
+            AnyValue::StructOwned(Box::new((
+                vec![AnyValue::Int32(1), AnyValue::Utf8Owned("a".into())],
+                vec![
+                    Field::new("x", DataType::Int32),
+                    Field::new("y", DataType::Utf8),
+                ],
+            ))),
+        ];
+        let json = serde_json::to_string(&values).unwrap();
+        let out = serde_json::from_str::<Vec<AnyValue>>(&json).unwrap();
+        assert_eq!(out, values);
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+    fn test_serde_any_value_time_zone() {
+        let utc = Some("UTC".to_string());
+        let offset = Some("+01:00".to_string());
+        let values = vec![
+            AnyValue::Datetime(20, TimeUnit::Milliseconds, &utc),
+            AnyValue::Datetime(30, TimeUnit::Microseconds, &offset),
+        ];
+        let json = serde_json::to_string(&values).unwrap();
+        let out = serde_json::from_str::<Vec<AnyValue>>(&json).unwrap();
+        assert_eq!(out, values);
+
+        // only known time zones are interned
+        for tz in ["Not/A_Zone", "+001:00"] {
+            let tz = Some(tz.to_string());
+            let json =
+                serde_json::to_string(&AnyValue::Datetime(1, TimeUnit::Milliseconds, &tz)).unwrap();
+            assert!(serde_json::from_str::<AnyValue>(&json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde_untyped_list() {
+        // lists were written without their inner dtype by older versions
+        let json = r#"{"name":"list","datatype":"List","values":[{"name":"","datatype":"Int32","values":[1,2]},null]}"#;
+        let out = serde_json::from_str::<Series>(json).unwrap();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int32)));
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.null_count(), 1);
+    }
+
     /// test using the `DeserializedOwned` trait
     #[test]
     fn test_serde_df_owned_bincode() {
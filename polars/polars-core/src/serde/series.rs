@@ -2,9 +2,11 @@ use std::borrow::Cow;
 use std::fmt::Formatter;
 
 use serde::de::{MapAccess, Visitor};
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeMap;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::prelude::*;
+use crate::serde::chunked_array::IterSer;
 use crate::serde::DeDataType;
 
 impl Serialize for Series {
@@ -15,65 +17,48 @@ impl Serialize for Series {
     where
         S: Serializer,
     {
-        if let Ok(ca) = self.i32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.u32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.i64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.u64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.f32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.f64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.utf8() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.bool() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.list() {
-            ca.serialize(serializer)
-        } else {
-            match self.dtype() {
-                DataType::Binary => {
-                    let ca = self.binary().unwrap();
-                    ca.serialize(serializer)
-                }
-                #[cfg(feature = "dtype-struct")]
-                DataType::Struct(_) => {
-                    let ca = self.struct_().unwrap();
-                    ca.serialize(serializer)
-                }
-                #[cfg(feature = "dtype-date")]
-                DataType::Date => {
-                    let ca = self.date().unwrap();
-                    ca.serialize(serializer)
-                }
-                #[cfg(feature = "dtype-datetime")]
-                DataType::Datetime(_, _) => {
-                    let ca = self.datetime().unwrap();
-                    ca.serialize(serializer)
-                }
-                #[cfg(feature = "dtype-categorical")]
-                DataType::Categorical(_) => {
-                    let ca = self.categorical().unwrap();
-                    ca.serialize(serializer)
-                }
-                #[cfg(feature = "dtype-duration")]
-                DataType::Duration(_) => {
-                    let ca = self.duration().unwrap();
-                    ca.serialize(serializer)
-                }
-                #[cfg(feature = "dtype-time")]
-                DataType::Time => {
-                    let ca = self.time().unwrap();
-                    ca.serialize(serializer)
-                }
-                _ => {
-                    // cast small integers to i32
-                    self.cast(&DataType::Int32).unwrap().serialize(serializer)
-                }
+        match self.dtype() {
+            DataType::Int8 => self.i8().unwrap().serialize(serializer),
+            DataType::Int16 => self.i16().unwrap().serialize(serializer),
+            DataType::Int32 => self.i32().unwrap().serialize(serializer),
+            DataType::Int64 => self.i64().unwrap().serialize(serializer),
+            DataType::UInt8 => self.u8().unwrap().serialize(serializer),
+            DataType::UInt16 => self.u16().unwrap().serialize(serializer),
+            DataType::UInt32 => self.u32().unwrap().serialize(serializer),
+            DataType::UInt64 => self.u64().unwrap().serialize(serializer),
+            DataType::Float32 => self.f32().unwrap().serialize(serializer),
+            DataType::Float64 => self.f64().unwrap().serialize(serializer),
+            DataType::Utf8 => self.utf8().unwrap().serialize(serializer),
+            DataType::Boolean => self.bool().unwrap().serialize(serializer),
+            DataType::Binary => self.binary().unwrap().serialize(serializer),
+            DataType::List(_) => self.list().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(_, _) => self.array().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => self.struct_().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => self.date().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => self.datetime().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => self.duration().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => self.time().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_) => self.categorical().unwrap().serialize(serializer),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => self.decimal().unwrap().serialize(serializer),
+            DataType::Null => {
+                let mut state = serializer.serialize_map(Some(3))?;
+                state.serialize_entry("name", self.name())?;
+                state.serialize_entry("datatype", &DeDataType::Null)?;
+                let values = std::iter::repeat(None::<()>).take(self.len());
+                state.serialize_entry("values", &IterSer::new(values))?;
+                state.end()
             }
+            dt => Err(ser::Error::custom(format!(
+                "serialization of dtype {dt} is not supported"
+            ))),
         }
     }
 }
@@ -140,11 +125,21 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<i8>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
+                    #[cfg(feature = "dtype-i16")]
+                    DeDataType::Int16 => {
+                        let values: Vec<Option<i16>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    }
                     #[cfg(feature = "dtype-u8")]
                     DeDataType::UInt8 => {
                         let values: Vec<Option<u8>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
+                    #[cfg(feature = "dtype-u16")]
+                    DeDataType::UInt16 => {
+                        let values: Vec<Option<u16>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    }
                     DeDataType::Int32 => {
                         let values: Vec<Option<i32>> = map.next_value()?;
                         Ok(Series::new(&name, values))
@@ -205,6 +200,34 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<Series>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
+                    DeDataType::TypedList(inner) => {
+                        let values: Vec<Option<Series>> = map.next_value()?;
+                        // the inner dtype cannot be inferred if all values are null
+                        Series::new(&name, values)
+                            .cast(&DataType::List(Box::new(inner)))
+                            .map_err(de::Error::custom)
+                    }
+                    #[cfg(feature = "dtype-array")]
+                    DeDataType::Array(inner, width) => {
+                        let values: Vec<Option<Series>> = map.next_value()?;
+                        Series::new(&name, values)
+                            .cast(&DataType::List(Box::new(inner.clone())))
+                            .and_then(|s| s.cast(&DataType::Array(Box::new(inner), width)))
+                            .map_err(de::Error::custom)
+                    }
+                    #[cfg(feature = "dtype-decimal")]
+                    DeDataType::Decimal(precision, scale) => {
+                        let values: Vec<Option<i128>> = map.next_value()?;
+                        let mut ca: Int128Chunked = values.into_iter().collect();
+                        ca.rename(&name);
+                        ca.into_decimal(precision, scale.unwrap_or(0))
+                            .map(|ca| ca.into_series())
+                            .map_err(de::Error::custom)
+                    }
+                    DeDataType::Null => {
+                        let values: Vec<Option<()>> = map.next_value()?;
+                        Ok(Series::new_null(&name, values.len()))
+                    }
                     DeDataType::Binary => {
                         let values: Vec<Option<Cow<[u8]>>> = map.next_value()?;
                         Ok(Series::new(&name, values))
@@ -224,9 +247,16 @@ impl<'de> Deserialize<'de> for Series {
                             .cast(&DataType::Categorical(None))
                             .unwrap())
                     }
-                    dt => {
-                        panic!("{dt:?} dtype deserialization not yet implemented")
+                    #[cfg(feature = "dtype-categorical")]
+                    DeDataType::Enum(categories) => {
+                        let values: Vec<Option<Cow<str>>> = map.next_value()?;
+                        DataType::new_enum(categories)
+                            .and_then(|dtype| Series::new(&name, values).strict_cast(&dtype))
+                            .map_err(de::Error::custom)
                     }
+                    dt => Err(de::Error::custom(format!(
+                        "deserialization of dtype {dt:?} is not supported"
+                    ))),
                 }
             }
         }
@@ -5,7 +5,8 @@
     feature = "dtype-datetime",
     feature = "dtype-date",
     feature = "dtype-time",
-    feature = "dtype-struct"
+    feature = "dtype-struct",
+    feature = "dtype-decimal"
 ))]
 use std::ops::Deref;
 
@@ -38,6 +39,11 @@ macro_rules! impl_compare {
                 .duration()
                 .unwrap()
                 .$method(rhs.duration().unwrap().deref()),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => lhs
+                .decimal()
+                .unwrap()
+                .$method(rhs.decimal().unwrap().deref()),
             DataType::List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => lhs
@@ -89,6 +95,35 @@ where
     }
 }
 
+/// Cast both sides to the enum dtype of either side. The physical representations that are
+/// returned follow the order of the categories, so they can be compared directly.
+#[cfg(feature = "dtype-categorical")]
+fn coerce_enums(
+    lhs: &Series,
+    rhs: &Series,
+) -> PolarsResult<Option<(UInt32Chunked, UInt32Chunked)>> {
+    let dtype = match (lhs.dtype(), rhs.dtype()) {
+        (DataType::Categorical(Some(l)), DataType::Categorical(Some(r)))
+            if l.is_enum() || r.is_enum() =>
+        {
+            polars_ensure!(
+                l.same_src(r),
+                ComputeError: "cannot compare enums with different categories"
+            );
+            lhs.dtype()
+        }
+        (l, _) if l.is_enum() => l,
+        (_, r) if r.is_enum() => r,
+        _ => return Ok(None),
+    };
+    let lhs = lhs.strict_cast(dtype)?;
+    let rhs = rhs.strict_cast(dtype)?;
+    Ok(Some((
+        lhs.categorical()?.logical().clone(),
+        rhs.categorical()?.logical().clone(),
+    )))
+}
+
 fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
     #[cfg(feature = "dtype-categorical")]
@@ -224,6 +259,12 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self > rhs.
     fn gt(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = coerce_enums(self, rhs)? {
+            let mut out = lhs.gt(&rhs);
+            out.rename(self.name());
+            return Ok(out);
+        }
         let mut out = impl_compare!(self, rhs, gt);
         out.rename(self.name());
         Ok(out)
@@ -232,6 +273,12 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self >= rhs.
     fn gt_eq(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = coerce_enums(self, rhs)? {
+            let mut out = lhs.gt_eq(&rhs);
+            out.rename(self.name());
+            return Ok(out);
+        }
         let mut out = impl_compare!(self, rhs, gt_eq);
         out.rename(self.name());
         Ok(out)
@@ -240,6 +287,12 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self < rhs.
     fn lt(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = coerce_enums(self, rhs)? {
+            let mut out = lhs.lt(&rhs);
+            out.rename(self.name());
+            return Ok(out);
+        }
         let mut out = impl_compare!(self, rhs, lt);
         out.rename(self.name());
         Ok(out)
@@ -248,6 +301,12 @@ impl ChunkCompare<&Series> for Series {
     /// Create a boolean mask by checking if self <= rhs.
     fn lt_eq(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), rhs.dtype())?;
+        #[cfg(feature = "dtype-categorical")]
+        if let Some((lhs, rhs)) = coerce_enums(self, rhs)? {
+            let mut out = lhs.lt_eq(&rhs);
+            out.rename(self.name());
+            return Ok(out);
+        }
         let mut out = impl_compare!(self, rhs, lt_eq);
         out.rename(self.name());
         Ok(out)
@@ -344,18 +403,134 @@ impl ChunkCompare<&str> for Series {
     }
 
     fn gt(&self, rhs: &str) -> PolarsResult<BooleanChunked> {
+        if self.dtype().is_enum() {
+            return self.gt(&Series::new("", [rhs]));
+        }
         compare_series_str(self, rhs, |lhs, rhs| lhs.gt(rhs))
     }
 
     fn gt_eq(&self, rhs: &str) -> PolarsResult<BooleanChunked> {
+        if self.dtype().is_enum() {
+            return self.gt_eq(&Series::new("", [rhs]));
+        }
         compare_series_str(self, rhs, |lhs, rhs| lhs.gt_eq(rhs))
     }
 
     fn lt(&self, rhs: &str) -> PolarsResult<BooleanChunked> {
+        if self.dtype().is_enum() {
+            return self.lt(&Series::new("", [rhs]));
+        }
         compare_series_str(self, rhs, |lhs, rhs| lhs.lt(rhs))
     }
 
     fn lt_eq(&self, rhs: &str) -> PolarsResult<BooleanChunked> {
+        if self.dtype().is_enum() {
+            return self.lt_eq(&Series::new("", [rhs]));
+        }
         compare_series_str(self, rhs, |lhs, rhs| lhs.lt_eq(rhs))
     }
 }
+
+/// Compare the lists of `lhs` and `rhs`, which have equal lengths, with
+/// [`Series::equal_missing`]. The values of all lists of equal length are compared at once.
+fn list_equal_missing(lhs: &ListChunked, rhs: &ListChunked) -> PolarsResult<BooleanChunked> {
+    let (lhs, rhs) = (lhs.rechunk(), rhs.rechunk());
+    let l_arr = lhs.downcast_iter().next().unwrap();
+    let r_arr = rhs.downcast_iter().next().unwrap();
+    let (l_offsets, r_offsets) = (l_arr.offsets().buffer(), r_arr.offsets().buffer());
+    let is_valid = |arr: &LargeListArray, i: usize| arr.validity().map_or(true, |v| v.get_bit(i));
+
+    // the values of the rows of which both lists are valid and have the same length
+    let mut l_idx = Vec::<IdxSize>::new();
+    let mut r_idx = Vec::<IdxSize>::new();
+    for i in 0..lhs.len() {
+        let (l_start, l_end) = (l_offsets[i] as IdxSize, l_offsets[i + 1] as IdxSize);
+        let (r_start, r_end) = (r_offsets[i] as IdxSize, r_offsets[i + 1] as IdxSize);
+        if is_valid(l_arr, i) && is_valid(r_arr, i) && l_end - l_start == r_end - r_start {
+            l_idx.extend(l_start..l_end);
+            r_idx.extend(r_start..r_end);
+        }
+    }
+    let l_values = lhs.get_inner().take(&IdxCa::from_vec("", l_idx))?;
+    let r_values = rhs.get_inner().take(&IdxCa::from_vec("", r_idx))?;
+    let values_eq = l_values.equal_missing(&r_values)?.rechunk();
+    let values_eq = values_eq.downcast_iter().next().unwrap().values();
+
+    let mut offset = 0;
+    let out = (0..lhs.len())
+        .map(|i| match (is_valid(l_arr, i), is_valid(r_arr, i)) {
+            (false, false) => true,
+            (true, true) => {
+                let len = (l_offsets[i + 1] - l_offsets[i]) as usize;
+                if len != (r_offsets[i + 1] - r_offsets[i]) as usize {
+                    return false;
+                }
+                let all_eq = (offset..offset + len).all(|j| values_eq.get_bit(j));
+                offset += len;
+                all_eq
+            }
+            _ => false,
+        })
+        .collect::<Vec<_>>();
+    Ok(BooleanChunked::from_slice("", &out))
+}
+
+impl Series {
+    /// Create a boolean mask by checking for equality, where `None == None` evaluates to `true`.
+    ///
+    /// In contrast to [`ChunkCompare::equal`] the result never contains null values. Nested
+    /// types are compared recursively, so a `List` or `Struct` value is equal if all of its
+    /// (possibly missing) elements are equal.
+    pub fn equal_missing(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
+        let (lhs, rhs) = match (self.len(), rhs.len()) {
+            (1, len) if len != 1 => (self.new_from_index(0, len), rhs.clone()),
+            (len, 1) if len != 1 => (self.clone(), rhs.new_from_index(0, len)),
+            (left, right) => {
+                polars_ensure!(
+                    left == right,
+                    ShapeMismatch: "cannot compare series of lengths {} and {}", left, right
+                );
+                (self.clone(), rhs.clone())
+            }
+        };
+        let mut out = match (lhs.dtype(), rhs.dtype()) {
+            (DataType::List(_), DataType::List(_)) => {
+                list_equal_missing(lhs.list().unwrap(), rhs.list().unwrap())?
+            }
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(_), DataType::Struct(_)) => {
+                let lhs = lhs.struct_().unwrap();
+                let rhs = rhs.struct_().unwrap();
+                polars_ensure!(
+                    lhs.fields().len() == rhs.fields().len(),
+                    ComputeError: "cannot compare structs with a different number of fields"
+                );
+                let mut out = BooleanChunked::full("", true, lhs.len());
+                for (l, r) in lhs.fields().iter().zip(rhs.fields()) {
+                    out = &out & &l.equal_missing(r)?;
+                }
+                out
+            }
+            _ => {
+                let eq = lhs.equal(&rhs)?;
+                if lhs.null_count() == 0 && rhs.null_count() == 0 {
+                    eq
+                } else {
+                    let both_null = &lhs.is_null() & &rhs.is_null();
+                    &eq.fill_null_with_values(false)? | &both_null
+                }
+            }
+        };
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Create a boolean mask by checking for inequality, where `None != None` evaluates to `false`.
+    ///
+    /// See [`Series::equal_missing`].
+    pub fn not_equal_missing(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
+        let mut out = !self.equal_missing(rhs)?;
+        out.rename(self.name());
+        Ok(out)
+    }
+}
@@ -73,6 +73,11 @@ impl Series {
                 .into_series(),
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-array")]
+            Array(_, _) => {
+                ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
+                    .into_series()
+            }
             Utf8 => Utf8Chunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -136,6 +141,11 @@ impl Series {
                 let chunks = chunks.iter().map(convert_inner_types).collect();
                 Ok(ListChunked::from_chunks(name, chunks).into_series())
             }
+            #[cfg(feature = "dtype-array")]
+            ArrowDataType::FixedSizeList(_, _) => {
+                let chunks = chunks.iter().map(convert_inner_types).collect();
+                Ok(ArrayChunked::from_chunks(name, chunks).into_series())
+            }
             ArrowDataType::Boolean => Ok(BooleanChunked::from_chunks(name, chunks).into_series()),
             #[cfg(feature = "dtype-u8")]
             ArrowDataType::UInt8 => Ok(UInt8Chunked::from_chunks(name, chunks).into_series()),
@@ -222,7 +232,7 @@ impl Series {
                 panic!("activate dtype-categorical to convert dictionary arrays")
             }
             #[cfg(feature = "dtype-categorical")]
-            ArrowDataType::Dictionary(key_type, value_type, _) => {
+            ArrowDataType::Dictionary(key_type, value_type, is_ordered) => {
                 use arrow::datatypes::IntegerType;
                 // don't spuriously call this; triggers a read on mmapped data
                 let arr = if chunks.len() > 1 {
@@ -282,6 +292,13 @@ impl Series {
 
                 // Safety
                 // the invariants of an Arrow Dictionary guarantee the keys are in bounds
+                if *is_ordered {
+                    // ordered dictionaries are enums, see `RevMapping::Enum`
+                    return Ok(
+                        CategoricalChunked::from_keys_and_values_enum(name, keys, values)?
+                            .into_series(),
+                    );
+                }
                 Ok(CategoricalChunked::from_keys_and_values(name, keys, values).into_series())
             }
             #[cfg(feature = "object")]
@@ -460,6 +477,17 @@ fn convert_inner_types(arr: &ArrayRef) -> ArrayRef {
             let out = cast(&**arr, &ArrowDataType::LargeBinary).unwrap();
             convert_inner_types(&out)
         }
+        #[cfg(feature = "dtype-array")]
+        ArrowDataType::FixedSizeList(_, width) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = convert_inner_types(arr.values());
+            let dtype = FixedSizeListArray::default_datatype(values.data_type().clone(), *width);
+            Box::from(FixedSizeListArray::new(
+                dtype,
+                values,
+                arr.validity().cloned(),
+            ))
+        }
         ArrowDataType::LargeList(_) => {
             let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
             let values = convert_inner_types(arr.values());
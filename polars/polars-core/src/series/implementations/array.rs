@@ -0,0 +1,196 @@
+use std::any::Any;
+use std::borrow::Cow;
+
+use super::{private, IntoSeries, SeriesTrait};
+use crate::chunked_array::AsSinglePtr;
+use crate::prelude::*;
+use crate::series::implementations::SeriesWrap;
+use crate::series::IsSorted;
+
+impl SeriesWrap<ArrayChunked> {
+    // convert chunk ids to indices in the rechunked array
+    fn chunk_ids_to_idx<'a>(&self, by: impl Iterator<Item = Option<&'a ChunkId>>) -> IdxCa {
+        let mut offsets = Vec::with_capacity(self.0.chunks().len());
+        let mut offset = 0 as IdxSize;
+        for arr in self.0.chunks() {
+            offsets.push(offset);
+            offset += arr.len() as IdxSize;
+        }
+        by.map(|opt_id| {
+            opt_id.map(|[chunk_idx, array_idx]| offsets[*chunk_idx as usize] + *array_idx)
+        })
+        .collect()
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<ArrayChunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.ref_field().data_type()
+    }
+
+    fn _set_sorted_flag(&mut self, is_sorted: IsSorted) {
+        self.0.set_sorted_flag(is_sorted)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<ArrayChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata);
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.chunk_id()
+    }
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append(other.as_ref().as_ref())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        self.0.append(other.as_ref().as_ref())?;
+        self.0 = self.0.rechunk();
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0.filter(filter).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "chunked_ids")]
+    unsafe fn _take_chunked_unchecked(&self, by: &[ChunkId], _sorted: IsSorted) -> Series {
+        let idx = self.chunk_ids_to_idx(by.iter().map(Some));
+        self.0.take_unchecked_idx(&idx).into_series()
+    }
+
+    #[cfg(feature = "chunked_ids")]
+    unsafe fn _take_opt_chunked_unchecked(&self, by: &[Option<ChunkId>]) -> Series {
+        let idx = self.chunk_ids_to_idx(by.iter().map(|opt_id| opt_id.as_ref()));
+        self.0.take_unchecked_idx(&idx).into_series()
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take_idx(indices)?.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn TakeIterator) -> PolarsResult<Series> {
+        iter.check_bounds(self.len())?;
+        Ok(unsafe { self.take_iter_unchecked(iter) })
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        self.0.take_every(n).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn TakeIterator) -> Series {
+        let idx: NoNull<IdxCa> = iter.map(|i| i as IdxSize).collect();
+        self.0.take_unchecked_idx(&idx.into_inner()).into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take_unchecked_idx(idx).into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(&self, iter: &mut dyn TakeIteratorNulls) -> Series {
+        let idx: IdxCa = iter.map(|opt_i| opt_i.map(|i| i as IdxSize)).collect();
+        self.0.take_unchecked_idx(&idx).into_series()
+    }
+
+    #[cfg(feature = "take_opt_iter")]
+    fn take_opt_iter(&self, iter: &mut dyn TakeIteratorNulls) -> PolarsResult<Series> {
+        iter.check_bounds(self.len())?;
+        Ok(unsafe { self.take_opt_iter_unchecked(iter) })
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0.new_from_index(index, length).into_series()
+    }
+
+    fn cast(&self, data_type: &DataType) -> PolarsResult<Series> {
+        self.0.cast(data_type)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    #[cfg(feature = "private")]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_validity(&self) -> bool {
+        self.0.has_validity()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.reverse().into_series()
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.shift(periods).into_series()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    /// Get a hold to self as `Any` trait reference.
+    /// Only implemented for ObjectType
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+}
@@ -30,6 +30,16 @@ impl private::PrivateSeries for SeriesWrap<BooleanChunked> {
         self.0.explode_by_offsets(offsets)
     }
 
+    #[cfg(feature = "cum_agg")]
+    fn _cummax(&self, reverse: bool) -> Series {
+        self.0.cummax(reverse).into_series()
+    }
+
+    #[cfg(feature = "cum_agg")]
+    fn _cummin(&self, reverse: bool) -> Series {
+        self.0.cummin(reverse).into_series()
+    }
+
     fn _set_sorted_flag(&mut self, is_sorted: IsSorted) {
         self.0.set_sorted_flag(is_sorted)
     }
@@ -133,6 +143,10 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.chunk_id()
     }
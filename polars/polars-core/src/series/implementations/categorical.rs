@@ -9,9 +9,9 @@ use crate::chunked_array::ops::compare_inner::{IntoPartialOrdInner, PartialOrdIn
 use crate::chunked_array::ops::explode::ExplodeByOffsets;
 use crate::chunked_array::AsSinglePtr;
 use crate::frame::groupby::*;
-use crate::frame::hash_join::ZipOuterJoinColumn;
 #[cfg(feature = "is_in")]
 use crate::frame::hash_join::_check_categorical_src;
+use crate::frame::hash_join::ZipOuterJoinColumn;
 use crate::prelude::*;
 use crate::series::implementations::SeriesWrap;
 
@@ -168,6 +168,10 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.0.logical_mut().rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.logical_mut().set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.logical().chunk_id()
     }
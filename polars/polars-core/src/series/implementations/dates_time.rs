@@ -174,6 +174,10 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn set_metadata(&mut self, metadata: FieldMetadata) {
+                self.0.set_metadata(metadata);
+            }
+
             fn chunk_lengths(&self) -> ChunkIdIter {
                 self.0.chunk_id()
             }
@@ -182,6 +182,10 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.chunk_id()
     }
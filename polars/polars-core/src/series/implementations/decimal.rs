@@ -37,6 +37,34 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
             .into_decimal_unchecked(self.0.precision(), self.0.scale())
             .into_series())
     }
+
+    unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_min(groups)
+    }
+
+    unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_max(groups)
+    }
+
+    unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_sum(groups)
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        Ok(self.0.try_sub(rhs.decimal()?)?.into_series())
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        Ok(self.0.try_add(rhs.decimal()?)?.into_series())
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        Ok(self.0.try_mul(rhs.decimal()?)?.into_series())
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        Ok(self.0.try_div(rhs.decimal()?)?.into_series())
+    }
 }
 
 impl SeriesTrait for SeriesWrap<DecimalChunked> {
@@ -44,6 +72,10 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         self.0.rename(name)
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata)
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.chunk_id()
     }
@@ -194,6 +226,26 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         self.apply_logical(|ca| ca.shift(periods))
     }
 
+    fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+
+    fn _sum_as_series(&self) -> Series {
+        self.0.sum_as_series()
+    }
+
+    fn max_as_series(&self) -> Series {
+        Int128Chunked::from_slice_options(self.name(), &[self.0.max()])
+            .into_decimal_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn min_as_series(&self) -> Series {
+        Int128Chunked::from_slice_options(self.name(), &[self.0.min()])
+            .into_decimal_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
         Arc::new(SeriesWrap(Clone::clone(&self.0)))
     }
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-array")]
+mod array;
 mod binary;
 mod boolean;
 #[cfg(feature = "dtype-categorical")]
@@ -39,6 +41,7 @@ use crate::chunked_array::ops::compare_inner::{
 };
 use crate::chunked_array::ops::explode::ExplodeByOffsets;
 use crate::chunked_array::AsSinglePtr;
+use crate::config::{get_agg_dtype_policy, SumDtype};
 use crate::frame::groupby::*;
 use crate::frame::hash_join::ZipOuterJoinColumn;
 use crate::prelude::*;
@@ -157,7 +160,11 @@ macro_rules! impl_dyn_series {
             unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
                 use DataType::*;
                 match self.dtype() {
-                    Int8 | UInt8 | Int16 | UInt16 => self.cast(&Int64).unwrap().agg_sum(groups),
+                    Int8 | UInt8 | Int16 | UInt16
+                        if get_agg_dtype_policy().sum == SumDtype::Int64 =>
+                    {
+                        self.cast(&Int64).unwrap().agg_sum(groups)
+                    }
                     _ => self.0.agg_sum(groups),
                 }
             }
@@ -259,6 +266,10 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn set_metadata(&mut self, metadata: FieldMetadata) {
+                self.0.set_metadata(metadata);
+            }
+
             fn chunk_lengths(&self) -> ChunkIdIter {
                 self.0.chunk_id()
             }
@@ -71,6 +71,10 @@ impl SeriesTrait for NullChunked {
         self.name = Arc::from(name)
     }
 
+    fn set_metadata(&mut self, _metadata: FieldMetadata) {
+        // a null series has no field to store the metadata
+    }
+
     fn chunks(&self) -> &Vec<ArrayRef> {
         &self.chunks
     }
@@ -114,7 +118,7 @@ impl SeriesTrait for NullChunked {
     }
 
     fn take_every(&self, n: usize) -> Series {
-        NullChunked::new(self.name.clone(), self.len() / n).into_series()
+        NullChunked::new(self.name.clone(), (self.len() + n - 1) / n).into_series()
     }
 
     fn has_validity(&self) -> bool {
@@ -83,6 +83,10 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
         self.0.rename(name)
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata)
+    }
+
     fn take_every(&self, n: usize) -> Series {
         self.0.apply_fields(|s| s.take_every(n)).into_series()
     }
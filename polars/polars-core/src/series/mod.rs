@@ -28,6 +28,7 @@ use num_traits::NumCast;
 use rayon::prelude::*;
 pub use series_trait::{IsSorted, *};
 
+use crate::config::{get_agg_dtype_policy, SumDtype};
 #[cfg(feature = "rank")]
 use crate::prelude::unique::rank::rank;
 #[cfg(feature = "zip_with")]
@@ -209,6 +210,13 @@ impl Series {
         self
     }
 
+    /// Set the metadata of the field of the series, e.g. its unit or a description. The metadata
+    /// is kept when the series is selected or renamed, and written to IPC and Parquet files.
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) -> &mut Series {
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
     /// Shrink the capacity of this array to fit its length.
     pub fn shrink_to_fit(&mut self) {
         self._get_inner_mut().shrink_to_fit()
@@ -547,7 +555,8 @@ impl Series {
     /// Returns a Series with a single zeroed entry if self is an empty numeric series.
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
-    /// first cast to `Int64` to prevent overflow issues.
+    /// first cast to `Int64` to prevent overflow issues, unless the
+    /// [`AggDtypePolicy`](crate::config::AggDtypePolicy) keeps the input type.
     pub fn sum_as_series(&self) -> Series {
         use DataType::*;
         if self.is_empty()
@@ -559,7 +568,9 @@ impl Series {
                 .sum_as_series();
         }
         match self.dtype() {
-            Int8 | UInt8 | Int16 | UInt16 => self.cast(&Int64).unwrap().sum_as_series(),
+            Int8 | UInt8 | Int16 | UInt16 if get_agg_dtype_policy().sum == SumDtype::Int64 => {
+                self.cast(&Int64).unwrap().sum_as_series()
+            }
             _ => self._sum_as_series(),
         }
     }
@@ -897,8 +908,33 @@ impl Series {
         self.slice(-(len as i64), len)
     }
 
+    /// Take every `n`th value, starting at `offset`.
+    ///
+    /// The values are gathered from a zero-copy slice by striding over it,
+    /// so no index vector is created.
+    pub fn gather_every(&self, n: usize, offset: usize) -> PolarsResult<Series> {
+        polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
+        let s = if offset > 0 {
+            self.slice(offset as i64, self.len().saturating_sub(offset))
+        } else {
+            self.clone()
+        };
+        Ok(if n == 1 { s } else { s.take_every(n) })
+    }
+
+    /// Get the mean of the Series as a new Series of length 1.
+    ///
+    /// The mean of integers is a `Float64`, or a `Decimal` if the
+    /// [`AggDtypePolicy`](crate::config::AggDtypePolicy) says so.
+    /// The mean of a `Decimal` is a `Decimal` with the same scale.
     pub fn mean_as_series(&self) -> Series {
+        #[cfg(feature = "dtype-decimal")]
+        if let Some(scale) = get_agg_dtype_policy().mean_decimal_scale(self.dtype()) {
+            return self.decimal_mean_as_series(scale);
+        }
         match self.dtype() {
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => self.decimal().unwrap().mean_as_series(),
             DataType::Float32 => {
                 let val = &[self.mean().map(|m| m as f32)];
                 Series::new(self.name(), val)
@@ -958,7 +994,7 @@ impl Series {
         match self.dtype() {
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(Some(rv)) => match &**rv {
-                RevMapping::Local(arr) => size += estimated_bytes_size(arr),
+                RevMapping::Local(arr) | RevMapping::Enum(arr) => size += estimated_bytes_size(arr),
                 RevMapping::Global(map, arr, _) => {
                     size +=
                         map.capacity() * std::mem::size_of::<u32>() * 2 + estimated_bytes_size(arr);
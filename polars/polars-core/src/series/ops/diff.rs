@@ -1,13 +1,97 @@
 use crate::prelude::*;
 use crate::series::ops::NullBehavior;
 
+/// The output dtype of a `diff` on `dtype`. Unsigned integers are diffed in a signed type
+/// in which the differences don't wrap around, nested types are diffed per field and per
+/// element.
+pub fn diff_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(tu, _) => Duration(*tu),
+        #[cfg(feature = "dtype-date")]
+        Date => Duration(TimeUnit::Milliseconds),
+        #[cfg(feature = "dtype-time")]
+        Time => Duration(TimeUnit::Nanoseconds),
+        UInt8 => Int16,
+        UInt16 => Int32,
+        UInt32 | UInt64 => Int64,
+        List(inner) => List(Box::new(diff_dtype(inner))),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|fld| Field::new(fld.name(), diff_dtype(fld.data_type())))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+/// Elementwise differences of the lists, the lists that are subtracted should
+/// have the same length.
+fn diff_list(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+    let (lhs, rhs) = match null_behavior {
+        NullBehavior::Ignore => (s.clone(), s.shift(n)),
+        NullBehavior::Drop => {
+            polars_ensure!(n > 0, InvalidOperation: "only positive integer allowed if nulls are dropped in 'diff' operation");
+            let len = s.len().saturating_sub(n as usize);
+            (s.slice(n, len), s.slice(0, len))
+        }
+    };
+    let (lhs, rhs) = (lhs.list()?, rhs.list()?);
+    let out = lhs
+        .amortized_iter()
+        .zip(rhs.amortized_iter())
+        .map(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => {
+                let (a, b) = (a.as_ref(), b.as_ref());
+                polars_ensure!(
+                    a.len() == b.len(),
+                    ShapeMismatch: "cannot compute the difference of lists of length {} and {}",
+                    a.len(), b.len()
+                );
+                Ok(Some(a - b))
+            }
+            _ => Ok(None),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut out: ListChunked = out.into_iter().collect();
+    out.rename(s.name());
+    let out = out.into_series();
+    // there were no valid differences to infer the inner dtype from
+    match out.dtype() {
+        DataType::List(inner) if matches!(**inner, DataType::Null) => out.cast(s.dtype()),
+        _ => Ok(out),
+    }
+}
+
 impl Series {
     pub fn diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
         use DataType::*;
+        match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(_) => polars_bail!(opq = diff, self.dtype()),
+            #[cfg(feature = "dtype-struct")]
+            Struct(_) => {
+                return self
+                    .struct_()?
+                    .try_apply_fields(|s| s.diff(n, null_behavior))
+                    .map(|ca| ca.into_series())
+            }
+            List(inner) => {
+                // the temporal elements are subtracted into durations by `diff_list`
+                let s = if inner.is_unsigned() {
+                    self.cast(&diff_dtype(self.dtype()))?
+                } else {
+                    self.clone()
+                };
+                return diff_list(&s, n, null_behavior);
+            }
+            _ => {}
+        }
         let s = match self.dtype() {
-            UInt8 => self.cast(&Int16).unwrap(),
-            UInt16 => self.cast(&Int32).unwrap(),
-            UInt32 | UInt64 => self.cast(&Int64).unwrap(),
+            UInt8 | UInt16 | UInt32 | UInt64 => self.cast(&diff_dtype(self.dtype())).unwrap(),
             _ => self.clone(),
         };
 
@@ -22,3 +106,27 @@ impl Series {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_list() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            [
+                Series::new("", [1u32, 2]),
+                Series::new("", [4u32, 3]),
+                Series::new("", [5u32, 9]),
+            ],
+        );
+        let out = s.diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        let out = out.list()?;
+        assert!(out.get(0).is_none());
+        assert_eq!(Vec::from(out.get(1).unwrap().i64()?), &[Some(3), Some(1)]);
+        assert_eq!(Vec::from(out.get(2).unwrap().i64()?), &[Some(1), Some(6)]);
+        Ok(())
+    }
+}
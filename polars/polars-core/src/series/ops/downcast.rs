@@ -127,6 +127,12 @@ impl Series {
         unpack_chunked!(self, DataType::List(_) => ListChunked, "List")
     }
 
+    /// Unpack to ChunkedArray of dtype array
+    #[cfg(feature = "dtype-array")]
+    pub fn array(&self) -> PolarsResult<&ArrayChunked> {
+        unpack_chunked!(self, DataType::Array(_, _) => ArrayChunked, "Array")
+    }
+
     /// Unpack to ChunkedArray of dtype categorical
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
@@ -7,6 +7,18 @@ impl Series {
             DataType::List(inner_dtype) => {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             }
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner_dtype, width) => {
+                ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
+            }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rev_map)) if rev_map.is_enum() => unsafe {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    UInt32Chunked::full_null(name, size),
+                    rev_map.clone(),
+                )
+            }
+            .into_series(),
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => CategoricalChunked::full_null(name, size).into_series(),
             #[cfg(feature = "dtype-date")]
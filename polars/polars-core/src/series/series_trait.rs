@@ -184,6 +184,9 @@ pub trait SeriesTrait:
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Set the metadata of the field of the Series.
+    fn set_metadata(&mut self, metadata: FieldMetadata);
+
     fn bitand(&self, _other: &Series) -> PolarsResult<Series> {
         polars_bail!(opq = bitand, self._dtype());
     }
@@ -242,6 +242,11 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(DataType::List(Box::new(st)))
             }
+            #[cfg(feature = "dtype-array")]
+            (Array(inner_left, width_left), Array(inner_right, width_right)) if width_left == width_right => {
+                let st = get_supertype(inner_left, inner_right)?;
+                Some(DataType::Array(Box::new(st), *width_left))
+            }
             // todo! check if can be removed
             (List(inner), other) | (other, List(inner)) => {
                 let st = get_supertype(inner, other)?;
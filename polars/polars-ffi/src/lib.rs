@@ -0,0 +1,91 @@
+//! A stable C ABI to move a [`Series`] between Polars and code that is compiled separately,
+//! e.g. the expression plugins of [`plugin`] that are loaded from shared libraries.
+//!
+//! The data is exchanged through the
+//! [Arrow C data interface](https://arrow.apache.org/docs/format/CDataInterface.html), so the
+//! buffers are not copied and both sides do not need to be compiled with the same version of
+//! Polars or even of Rust.
+pub mod plugin;
+
+use arrow::ffi::{
+    export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c, ArrowArray,
+    ArrowSchema,
+};
+use polars_core::prelude::*;
+
+/// A [`Series`] that is exported with the Arrow C data interface.
+///
+/// An export owns its buffers: they are released once it is dropped, unless it is moved out
+/// with [`import_series`]. The structs are `#[repr(C)]`, so an export can be moved across a
+/// C ABI by value or by pointer. A pointer to an export is moved out with
+/// `std::mem::replace(&mut *ptr, SeriesExport::empty())`, which leaves a released export behind.
+#[repr(C)]
+pub struct SeriesExport {
+    field: ArrowSchema,
+    array: ArrowArray,
+}
+
+impl SeriesExport {
+    /// A released export, which owns no buffers.
+    pub fn empty() -> Self {
+        SeriesExport {
+            field: ArrowSchema::empty(),
+            array: ArrowArray::empty(),
+        }
+    }
+}
+
+/// Export a [`Series`] without copying its buffers. A series of multiple chunks is rechunked
+/// first.
+pub fn export_series(s: &Series) -> SeriesExport {
+    let s = s.rechunk();
+    let array = s.to_arrow(0);
+    let field = ArrowField::new(s.name(), array.data_type().clone(), true);
+    SeriesExport {
+        field: export_field_to_c(&field),
+        array: export_array_to_c(array),
+    }
+}
+
+/// Import a [`Series`] without copying its buffers.
+///
+/// # Safety
+/// The export must be created by [`export_series`] or follow the Arrow C data interface.
+pub unsafe fn import_series(e: SeriesExport) -> PolarsResult<Series> {
+    let field = import_field_from_c(&e.field)?;
+    let array = import_array_from_c(e.array, field.data_type().clone())?;
+    Series::try_from((field.name.as_str(), array))
+}
+
+/// Export the [`Field`] of a series.
+pub fn export_field(field: &Field) -> ArrowSchema {
+    export_field_to_c(&field.to_arrow())
+}
+
+/// Import the [`Field`] of a series.
+///
+/// # Safety
+/// The schema must be created by [`export_field`] or follow the Arrow C data interface.
+pub unsafe fn import_field(schema: &ArrowSchema) -> PolarsResult<Field> {
+    Ok(Field::from(&import_field_from_c(schema)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_series_export_roundtrip() -> PolarsResult<()> {
+        let mut s = Series::new("a", [Some(1i32), None, Some(3)]);
+        s.append(&Series::new("a", [4i32]))?;
+
+        let out = unsafe { import_series(export_series(&s))? };
+        assert!(out.series_equal_missing(&s));
+        assert_eq!(out.name(), "a");
+
+        let field = Field::new("b", DataType::List(Box::new(DataType::Utf8)));
+        let out = unsafe { import_field(&export_field(&field))? };
+        assert_eq!(out, field);
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+//! Expression plugins: expression kernels that are compiled into a shared library (a `cdylib`)
+//! and loaded by Polars at runtime.
+//!
+//! A plugin implements [`PluginExpr`] for every function and exports them with
+//! [`export_plugins!`]:
+//!
+//! ```rust,ignore
+//! use polars_core::prelude::*;
+//! use polars_ffi::plugin::PluginExpr;
+//!
+//! struct AddOne;
+//!
+//! impl PluginExpr for AddOne {
+//!     const NAME: &'static str = "add_one";
+//!
+//!     fn call(inputs: &[Series]) -> PolarsResult<Series> {
+//!         Ok(&inputs[0] + 1)
+//!     }
+//!
+//!     fn output_field(fields: &[Field]) -> PolarsResult<Field> {
+//!         Ok(fields[0].clone())
+//!     }
+//! }
+//!
+//! polars_ffi::export_plugins!(AddOne);
+//! ```
+//!
+//! The macro exports the [`PLUGIN_REGISTER_SYMBOL`] symbol, a function that returns the
+//! [`PluginRegistry`] with the metadata of the functions. The functions receive their inputs
+//! and return their output as [`SeriesExport`]s, so a plugin does not need to be compiled with
+//! the same version of Polars as the library that loads it, as long as the
+//! [`PLUGIN_ABI_VERSION`] is the same.
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use arrow::ffi::ArrowSchema;
+#[doc(hidden)]
+pub use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+use crate::{export_field, export_series, import_field, import_series, SeriesExport};
+
+/// The version of the plugin ABI, which is bumped on every change of the structs of this module.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// The nul-terminated symbol of the function that returns the [`PluginRegistry`] of a plugin.
+pub const PLUGIN_REGISTER_SYMBOL: &[u8] = b"_polars_plugin_register\0";
+
+/// Moves the exports of the inputs out, leaving released exports behind, and writes the
+/// export of the output. Returns `false` on failure.
+pub type CallFn = unsafe extern "C" fn(
+    inputs: *mut SeriesExport,
+    n_inputs: usize,
+    out: *mut SeriesExport,
+) -> bool;
+
+/// Borrows the schemas of the input fields and writes the schema of the output field. Returns
+/// `false` on failure.
+pub type OutputFieldFn = unsafe extern "C" fn(
+    fields: *const ArrowSchema,
+    n_fields: usize,
+    out: *mut ArrowSchema,
+) -> bool;
+
+/// Returns the nul-terminated message of the last failure on the calling thread. The message
+/// is owned by the plugin and valid until the next call on this thread.
+pub type LastErrorFn = unsafe extern "C" fn() -> *const c_char;
+
+/// An expression function of a plugin.
+///
+/// The functions are called from multiple threads at once, so they must be thread safe.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PluginFunction {
+    name: *const c_char,
+    elementwise: bool,
+    call: CallFn,
+    output_field: OutputFieldFn,
+    last_error: LastErrorFn,
+}
+
+// Safety: the name is valid as long as the plugin is loaded and the functions are thread safe.
+unsafe impl Send for PluginFunction {}
+unsafe impl Sync for PluginFunction {}
+
+/// The metadata of all the functions of a plugin.
+#[repr(C)]
+pub struct PluginRegistry {
+    abi_version: u32,
+    functions: *const PluginFunction,
+    n_functions: usize,
+}
+
+// Safety: a registry is immutable and only points to thread safe functions.
+unsafe impl Send for PluginRegistry {}
+unsafe impl Sync for PluginRegistry {}
+
+/// An expression function that is exported by a plugin.
+pub trait PluginExpr {
+    /// The name by which the function is loaded.
+    const NAME: &'static str;
+    /// Whether the function maps every row independently of the other rows. Otherwise it is
+    /// called on every group in an aggregation context.
+    const ELEMENTWISE: bool = true;
+
+    /// Compute the output of the function. The first input is the expression the function
+    /// is called on, the others are its arguments.
+    fn call(inputs: &[Series]) -> PolarsResult<Series>;
+
+    /// Determine the output field from the fields of the inputs.
+    fn output_field(fields: &[Field]) -> PolarsResult<Field>;
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+unsafe extern "C" fn last_error() -> *const c_char {
+    LAST_ERROR.with(|msg| msg.borrow().as_ptr())
+}
+
+/// Run `f` and store its error or panic message, as neither can cross the C ABI.
+fn catch_error(f: impl FnOnce() -> PolarsResult<()>) -> bool {
+    let msg = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return true,
+        Ok(Err(err)) => err.to_string(),
+        Err(payload) => match payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(|s| s.as_str()))
+        {
+            Some(msg) => format!("the plugin panicked: {msg}"),
+            None => "the plugin panicked".to_string(),
+        },
+    };
+    let msg = CString::new(msg.replace('\0', "")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = msg);
+    false
+}
+
+unsafe extern "C" fn call_plugin<P: PluginExpr>(
+    inputs: *mut SeriesExport,
+    n_inputs: usize,
+    out: *mut SeriesExport,
+) -> bool {
+    catch_error(|| {
+        let inputs = std::slice::from_raw_parts_mut(inputs, n_inputs)
+            .iter_mut()
+            .map(|e| import_series(std::mem::replace(e, SeriesExport::empty())))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let s = P::call(&inputs)?;
+        out.write(export_series(&s));
+        Ok(())
+    })
+}
+
+unsafe extern "C" fn plugin_output_field<P: PluginExpr>(
+    fields: *const ArrowSchema,
+    n_fields: usize,
+    out: *mut ArrowSchema,
+) -> bool {
+    catch_error(|| {
+        let fields = std::slice::from_raw_parts(fields, n_fields)
+            .iter()
+            .map(|schema| import_field(schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let field = P::output_field(&fields)?;
+        out.write(export_field(&field));
+        Ok(())
+    })
+}
+
+impl PluginFunction {
+    pub fn name(&self) -> &str {
+        // Safety: the name is a nul-terminated string that lives as long as the plugin
+        unsafe { CStr::from_ptr(self.name) }
+            .to_str()
+            .unwrap_or_default()
+    }
+
+    pub fn is_elementwise(&self) -> bool {
+        self.elementwise
+    }
+
+    fn error(&self) -> PolarsError {
+        // Safety: the message is a nul-terminated string that is valid until the next call
+        let msg = unsafe { CStr::from_ptr((self.last_error)()) };
+        polars_err!(
+            ComputeError: "plugin function '{}' failed: {}", self.name(), msg.to_string_lossy()
+        )
+    }
+
+    /// Call the function on the `inputs`.
+    pub fn call(&self, inputs: &[Series]) -> PolarsResult<Series> {
+        let mut inputs = inputs.iter().map(export_series).collect::<Vec<_>>();
+        let mut out = SeriesExport::empty();
+        // Safety: the exports are valid and the plugin only moves out of them
+        if unsafe { (self.call)(inputs.as_mut_ptr(), inputs.len(), &mut out) } {
+            unsafe { import_series(out) }
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Determine the output field of the function from the fields of its inputs.
+    pub fn output_field(&self, fields: &[Field]) -> PolarsResult<Field> {
+        let fields = fields.iter().map(export_field).collect::<Vec<_>>();
+        let mut out = ArrowSchema::empty();
+        // Safety: the schemas are valid and the plugin only borrows them
+        if unsafe { (self.output_field)(fields.as_ptr(), fields.len(), &mut out) } {
+            unsafe { import_field(&out) }
+        } else {
+            Err(self.error())
+        }
+    }
+}
+
+impl PluginRegistry {
+    pub fn functions(&self) -> &[PluginFunction] {
+        // Safety: a registry is only created by `Registry`, which owns the functions
+        unsafe { std::slice::from_raw_parts(self.functions, self.n_functions) }
+    }
+
+    /// Get the function `name`, if the registry has the ABI version of this crate.
+    pub fn get(&self, name: &str) -> PolarsResult<&PluginFunction> {
+        polars_ensure!(
+            self.abi_version == PLUGIN_ABI_VERSION,
+            ComputeError: "the plugin has ABI version {}, expected version {}",
+            self.abi_version, PLUGIN_ABI_VERSION
+        );
+        self.functions()
+            .iter()
+            .find(|f| f.name() == name)
+            .ok_or_else(|| polars_err!(ComputeError: "the plugin has no function '{}'", name))
+    }
+}
+
+/// Owns the [`PluginRegistry`] of a plugin and the functions it points to. This is created by
+/// [`export_plugins!`].
+#[doc(hidden)]
+pub struct Registry {
+    registry: PluginRegistry,
+    functions: Vec<PluginFunction>,
+    names: Vec<CString>,
+}
+
+impl Registry {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Registry {
+            registry: PluginRegistry {
+                abi_version: PLUGIN_ABI_VERSION,
+                functions: std::ptr::null(),
+                n_functions: 0,
+            },
+            functions: vec![],
+            names: vec![],
+        }
+    }
+
+    pub fn register<P: PluginExpr>(mut self) -> Self {
+        let name = CString::new(P::NAME).expect("the name of a plugin function contains a nul");
+        self.functions.push(PluginFunction {
+            // the buffer of the name does not move when the `CString` is moved
+            name: name.as_ptr(),
+            elementwise: P::ELEMENTWISE,
+            call: call_plugin::<P>,
+            output_field: plugin_output_field::<P>,
+            last_error,
+        });
+        self.names.push(name);
+        self.registry.functions = self.functions.as_ptr();
+        self.registry.n_functions = self.functions.len();
+        self
+    }
+
+    pub fn as_ptr(&self) -> *const PluginRegistry {
+        &self.registry
+    }
+}
+
+/// Export the [`PluginExpr`] implementations of a plugin, by defining the function that
+/// returns their [`PluginRegistry`]. This must be used once in a `cdylib`.
+#[macro_export]
+macro_rules! export_plugins {
+    ($($plugin:ty),* $(,)?) => {
+        #[no_mangle]
+        pub extern "C" fn _polars_plugin_register() -> *const $crate::plugin::PluginRegistry {
+            static REGISTRY: $crate::plugin::Lazy<$crate::plugin::Registry> =
+                $crate::plugin::Lazy::new(|| {
+                    $crate::plugin::Registry::new()$(.register::<$plugin>())*
+                });
+            REGISTRY.as_ptr()
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct AddOne;
+
+    impl PluginExpr for AddOne {
+        const NAME: &'static str = "add_one";
+
+        fn call(inputs: &[Series]) -> PolarsResult<Series> {
+            Ok(&inputs[0] + 1)
+        }
+
+        fn output_field(fields: &[Field]) -> PolarsResult<Field> {
+            Ok(fields[0].clone())
+        }
+    }
+
+    struct Fail;
+
+    impl PluginExpr for Fail {
+        const NAME: &'static str = "fail";
+        const ELEMENTWISE: bool = false;
+
+        fn call(_inputs: &[Series]) -> PolarsResult<Series> {
+            polars_bail!(ComputeError: "oops")
+        }
+
+        fn output_field(_fields: &[Field]) -> PolarsResult<Field> {
+            panic!("no field")
+        }
+    }
+
+    export_plugins!(AddOne, Fail);
+
+    #[test]
+    fn test_plugin_registry() -> PolarsResult<()> {
+        let registry = unsafe { &*_polars_plugin_register() };
+        assert_eq!(registry.functions().len(), 2);
+        assert!(registry.get("missing").is_err());
+
+        let add_one = registry.get("add_one")?;
+        assert!(add_one.is_elementwise());
+        let s = Series::new("a", [1i64, 2, 3]);
+        let out = add_one.call(&[s.clone()])?;
+        assert!(out.series_equal(&Series::new("a", [2i64, 3, 4])));
+        assert_eq!(add_one.output_field(&[s.field().into_owned()])?, *s.field());
+
+        let fail = registry.get("fail")?;
+        assert!(!fail.is_elementwise());
+        let err = fail.call(&[s.clone()]).unwrap_err();
+        assert!(err.to_string().contains("oops"));
+        let err = fail.output_field(&[s.field().into_owned()]).unwrap_err();
+        assert!(err.to_string().contains("no field"));
+        Ok(())
+    }
+}
@@ -3,14 +3,18 @@
 use std::str::FromStr;
 
 use object_store::local::LocalFileSystem;
+use object_store::path::Path;
 use object_store::ObjectStore;
 use polars_core::cloud::{CloudOptions, CloudType};
+use polars_core::error::to_compute_err;
 use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
 
 mod adaptors;
 mod glob;
+mod writer;
 pub use adaptors::*;
 pub use glob::*;
+pub use writer::*;
 
 type BuildResult = PolarsResult<(CloudLocation, Box<dyn ObjectStore>)>;
 
@@ -77,3 +81,18 @@ pub fn build(url: &str, _options: Option<&CloudOptions>) -> BuildResult {
     }?;
     Ok((cloud_location, store))
 }
+
+#[tokio::main(flavor = "current_thread")]
+/// Download the whole object at `url`, for formats that can only be read from the start,
+/// such as CSV.
+pub async fn download(url: &str, options: Option<&CloudOptions>) -> PolarsResult<Vec<u8>> {
+    let (CloudLocation { prefix, .. }, store) = build(url, options)?;
+    let bytes = store
+        .get(&Path::from(prefix))
+        .await
+        .map_err(to_compute_err)?
+        .bytes()
+        .await
+        .map_err(to_compute_err)?;
+    Ok(bytes.to_vec())
+}
@@ -0,0 +1,183 @@
+//! Write to cloud storage through a multipart upload.
+use std::io::{self, Write};
+
+use object_store::path::Path;
+use object_store::{MultipartId, ObjectStore};
+use polars_core::cloud::CloudOptions;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::{polars_ensure, PolarsResult};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::runtime::Runtime;
+
+use super::{build, CloudLocation};
+
+/// The minimal size of the parts of a multipart upload, except for the last part.
+const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// A [`Write`] that streams the written bytes to cloud storage through a multipart upload.
+///
+/// The bytes are uploaded in parts of [`CloudOptions::upload_part_size`]. After
+/// [`CloudOptions::max_upload_concurrency`] parts are handed to the object store, the writer waits
+/// until they are uploaded, so at most that many parts are held in memory. The upload is only
+/// completed by [`CloudWriter::finish`]. If the writer is dropped before that, e.g. because
+/// writing failed, the upload is aborted so that no orphaned parts are left behind.
+pub struct CloudWriter {
+    // The upload is driven by this runtime, it lives as long as the writer so that the
+    // connections of the object store stay valid between writes.
+    runtime: Runtime,
+    object_store: Box<dyn ObjectStore>,
+    path: Path,
+    multipart_id: MultipartId,
+    writer: Box<dyn AsyncWrite + Send + Unpin>,
+    part_size: usize,
+    max_concurrency: usize,
+    buf: Vec<u8>,
+    // The number of parts handed to the upload since the last flush.
+    parts_in_flight: usize,
+    finished: bool,
+}
+
+impl CloudWriter {
+    /// Start a multipart upload to `uri`.
+    pub fn new(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let (part_size, max_concurrency) = cloud_options.map_or(
+            (
+                polars_core::cloud::DEFAULT_UPLOAD_PART_SIZE,
+                polars_core::cloud::DEFAULT_MAX_UPLOAD_CONCURRENCY,
+            ),
+            |options| (options.upload_part_size(), options.max_upload_concurrency()),
+        );
+        polars_ensure!(
+            part_size >= MIN_PART_SIZE,
+            ComputeError: "the upload part size must be at least {} bytes, got {}",
+            MIN_PART_SIZE, part_size
+        );
+        polars_ensure!(
+            max_concurrency > 0,
+            ComputeError: "the maximum upload concurrency must be positive"
+        );
+
+        let (
+            CloudLocation {
+                prefix, expansion, ..
+            },
+            object_store,
+        ) = build(uri, cloud_options)?;
+        polars_ensure!(
+            expansion.is_none(),
+            ComputeError: "cannot write to a url with wildcards: {}", uri
+        );
+        let path = Path::from(prefix);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let (multipart_id, writer) = runtime
+            .block_on(object_store.put_multipart(&path))
+            .map_err(to_compute_err)?;
+
+        Ok(CloudWriter {
+            runtime,
+            object_store,
+            path,
+            multipart_id,
+            writer,
+            part_size,
+            max_concurrency,
+            buf: Vec::with_capacity(part_size),
+            parts_in_flight: 0,
+            finished: false,
+        })
+    }
+
+    /// Hand the buffered bytes to the upload as a single part.
+    fn upload_part(&mut self) -> io::Result<()> {
+        let Self {
+            runtime,
+            writer,
+            buf,
+            ..
+        } = self;
+        runtime.block_on(writer.write_all(buf))?;
+        buf.clear();
+
+        self.parts_in_flight += 1;
+        if self.parts_in_flight == self.max_concurrency {
+            // wait until the parts in flight are uploaded
+            self.runtime.block_on(self.writer.flush())?;
+            self.parts_in_flight = 0;
+        }
+        Ok(())
+    }
+
+    /// Upload the remaining bytes and complete the upload.
+    pub fn finish(mut self) -> PolarsResult<()> {
+        if !self.buf.is_empty() {
+            self.upload_part()?;
+        }
+        self.runtime.block_on(self.writer.shutdown())?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+impl Write for CloudWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = std::cmp::min(buf.len(), self.part_size - self.buf.len());
+        self.buf.extend_from_slice(&buf[..n]);
+        if self.buf.len() == self.part_size {
+            self.upload_part()?;
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // Parts must have a minimal size, so flushing doesn't upload the buffered bytes.
+        Ok(())
+    }
+}
+
+impl Drop for CloudWriter {
+    fn drop(&mut self) {
+        if !self.finished {
+            // the upload failed or was not completed, remove the uploaded parts
+            let _ = self.runtime.block_on(
+                self.object_store
+                    .abort_multipart(&self.path, &self.multipart_id),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cloud_writer() -> PolarsResult<()> {
+        let dir = tempdir::TempDir::new("cloud_writer")?;
+        let path = dir.path().join("out.bin");
+        let uri = format!("file://{}", path.display());
+        let options = CloudOptions::default().with_max_upload_concurrency(2);
+
+        let data = (0..3 * MIN_PART_SIZE + 10)
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+        let mut writer = CloudWriter::new(&uri, Some(&options))?;
+        writer.write_all(&data)?;
+        writer.finish()?;
+        assert_eq!(std::fs::read(&path)?, data);
+
+        // an upload that isn't finished leaves no file behind
+        let path = dir.path().join("aborted.bin");
+        let uri = format!("file://{}", path.display());
+        let mut writer = CloudWriter::new(&uri, Some(&options))?;
+        writer.write_all(&data)?;
+        drop(writer);
+        assert!(!path.exists());
+
+        let options = CloudOptions::default().with_upload_part_size(1024);
+        assert!(CloudWriter::new(&uri, Some(&options)).is_err());
+        Ok(())
+    }
+}
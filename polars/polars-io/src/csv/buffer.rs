@@ -10,7 +10,7 @@ use polars_time::prelude::utf8::infer::{infer_pattern_single, DatetimeInfer, Str
 use crate::csv::parser::{is_whitespace, skip_whitespace};
 use crate::csv::read_impl::RunningSize;
 use crate::csv::utils::escape_field;
-use crate::csv::CsvEncoding;
+use crate::csv::{CsvEncoding, NumberFormat};
 
 pub(crate) trait PrimitiveParser: PolarsNumericType {
     fn parse(bytes: &[u8]) -> Option<Self::Native>;
@@ -506,6 +506,7 @@ pub(crate) fn init_buffers<'a>(
     quote_char: Option<u8>,
     encoding: CsvEncoding,
     ignore_errors: bool,
+    number_format: Option<&NumberFormat>,
 ) -> PolarsResult<Vec<Buffer<'a>>> {
     // we keep track of the string columns we have seen so that we can increment the index
     let mut str_index = 0;
@@ -553,7 +554,26 @@ pub(crate) fn init_buffers<'a>(
                     ComputeError: "unsupported data type when reading CSV: {} when reading CSV", dt,
                 ),
             };
-            Ok(builder)
+            Ok(match number_format {
+                Some(format)
+                    if matches!(
+                        builder,
+                        Buffer::Int32(_)
+                            | Buffer::Int64(_)
+                            | Buffer::UInt32(_)
+                            | Buffer::UInt64(_)
+                            | Buffer::Float32(_)
+                            | Buffer::Float64(_)
+                    ) =>
+                {
+                    Buffer::Formatted {
+                        buf: Box::new(builder),
+                        format: format.clone(),
+                        scratch: vec![],
+                    }
+                }
+                _ => builder,
+            })
         })
         .collect()
 }
@@ -579,6 +599,12 @@ pub(crate) enum Buffer<'a> {
     Date(DatetimeField<Int32Type>),
     #[allow(dead_code)]
     Categorical(CategoricalField<'a>),
+    /// A numeric buffer of which the fields are cleaned with the number format before parsing
+    Formatted {
+        buf: Box<Buffer<'a>>,
+        format: NumberFormat,
+        scratch: Vec<u8>,
+    },
 }
 
 impl<'a> Buffer<'a> {
@@ -658,6 +684,7 @@ impl<'a> Buffer<'a> {
                     panic!("activate 'dtype-categorical' feature")
                 }
             }
+            Buffer::Formatted { buf, .. } => buf.into_series()?,
         };
         Ok(s)
     }
@@ -690,6 +717,7 @@ impl<'a> Buffer<'a> {
                     panic!("activate 'dtype-categorical' feature")
                 }
             }
+            Buffer::Formatted { buf, .. } => buf.add_null(valid),
         };
     }
 
@@ -718,6 +746,7 @@ impl<'a> Buffer<'a> {
                     panic!("activate 'dtype-categorical' feature")
                 }
             }
+            Buffer::Formatted { buf, .. } => buf.dtype(),
         }
     }
 
@@ -815,6 +844,39 @@ impl<'a> Buffer<'a> {
                     panic!("activate 'dtype-categorical' feature")
                 }
             }
+            Formatted {
+                buf,
+                format,
+                scratch,
+            } => {
+                let bytes = if needs_escaping && bytes.len() > 1 {
+                    &bytes[1..bytes.len() - 1]
+                } else {
+                    bytes
+                };
+                format.clean_field(bytes, scratch);
+                buf.add_number(scratch, ignore_errors, missing_is_null)
+            }
+        }
+    }
+
+    /// Add a number to a numeric buffer. Unlike [`Buffer::add`] the bytes don't have to outlive
+    /// the buffer, as they are not kept.
+    fn add_number(
+        &mut self,
+        bytes: &[u8],
+        ignore_errors: bool,
+        missing_is_null: bool,
+    ) -> PolarsResult<()> {
+        use Buffer::*;
+        match self {
+            Int32(buf) => buf.parse_bytes(bytes, ignore_errors, false, missing_is_null),
+            Int64(buf) => buf.parse_bytes(bytes, ignore_errors, false, missing_is_null),
+            UInt32(buf) => buf.parse_bytes(bytes, ignore_errors, false, missing_is_null),
+            UInt64(buf) => buf.parse_bytes(bytes, ignore_errors, false, missing_is_null),
+            Float32(buf) => buf.parse_bytes(bytes, ignore_errors, false, missing_is_null),
+            Float64(buf) => buf.parse_bytes(bytes, ignore_errors, false, missing_is_null),
+            _ => unreachable!("only numeric buffers are formatted"),
         }
     }
 }
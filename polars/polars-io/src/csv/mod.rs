@@ -62,10 +62,14 @@ use polars_core::prelude::*;
 use polars_time::prelude::*;
 #[cfg(feature = "temporal")]
 use rayon::prelude::*;
-pub use read::{CsvEncoding, CsvReader, NullValues};
+pub use read::{
+    open_file_or_url, CsvDelimiter, CsvEncoding, CsvReader, DuplicateNames, HeaderNormalization,
+    NullValues, NumberFormat,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-pub use write::CsvWriter;
+pub use write::{BatchedWriter, CsvWriter};
+pub use write_impl::SerializeOptions;
 
 use crate::csv::read_impl::CoreReader;
 use crate::csv::utils::get_reader_bytes;
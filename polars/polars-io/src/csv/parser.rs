@@ -5,6 +5,7 @@ use polars_core::prelude::*;
 use super::buffer::*;
 use crate::csv::read::NullValuesCompiled;
 use crate::csv::splitfields::SplitFields;
+use crate::csv::CsvDelimiter;
 
 /// Skip the utf-8 Byte Order Mark.
 /// credits to csv-core
@@ -30,20 +31,20 @@ pub(crate) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usi
 pub(crate) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
-        delimiter: u8,
+        delimiter: CsvDelimiter,
         eol_char: u8,
         quote_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
         for (field, _) in SplitFields::new(line, delimiter, quote_char, eol_char) {
-            if memchr2_iter(delimiter, eol_char, field).count() >= expected_fields {
+            if memchr2_iter(delimiter.first(), eol_char, field).count() >= expected_fields {
                 return false;
             }
             count += 1;
@@ -197,7 +198,7 @@ pub(crate) fn get_line_stats(
     n_lines: usize,
     eol_char: u8,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
@@ -251,6 +252,7 @@ pub(crate) struct SplitLines<'a> {
     v: &'a [u8],
     quote_char: u8,
     end_line_char: u8,
+    comment_char: Option<u8>,
 }
 
 impl<'a> SplitLines<'a> {
@@ -259,8 +261,16 @@ impl<'a> SplitLines<'a> {
             v: slice,
             quote_char,
             end_line_char,
+            comment_char: None,
         }
     }
+
+    /// A line that starts with the comment character ends at the next end of line character, the
+    /// quote characters in a comment don't start a string field.
+    pub(crate) fn with_comment_char(mut self, comment_char: Option<u8>) -> Self {
+        self.comment_char = comment_char;
+        self
+    }
 }
 
 impl<'a> Iterator for SplitLines<'a> {
@@ -271,6 +281,12 @@ impl<'a> Iterator for SplitLines<'a> {
         if self.v.is_empty() {
             return None;
         }
+        if self.comment_char.is_some() && self.v.first() == self.comment_char.as_ref() {
+            let pos = memchr::memchr(self.end_line_char, self.v)?;
+            let ret = &self.v[..pos];
+            self.v = &self.v[pos + 1..];
+            return Some(ret);
+        }
 
         // denotes if we are in a string field, started with a quote
         let mut in_field = false;
@@ -335,7 +351,7 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
 }
 
 #[inline]
-fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
+pub(crate) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
     let pos = match quote {
         Some(quote) => find_quoted(bytes, quote, eol_char),
         None => bytes.iter().position(|x| *x == eol_char),
@@ -359,7 +375,7 @@ fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
 pub(super) fn parse_lines<'a>(
     mut bytes: &'a [u8],
     offset: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
@@ -393,9 +409,9 @@ pub(super) fn parse_lines<'a>(
         // only when we have one column \n should not be skipped
         // other widths should have commas.
         bytes = if schema_len > 1 {
-            skip_whitespace_line_ending_exclude(bytes, delimiter, eol_char)
+            skip_whitespace_line_ending_exclude(bytes, delimiter.first(), eol_char)
         } else {
-            skip_whitespace_exclude(bytes, delimiter)
+            skip_whitespace_exclude(bytes, delimiter.first())
         };
         if bytes.is_empty() {
             return Ok(original_bytes_len);
@@ -405,7 +421,8 @@ pub(super) fn parse_lines<'a>(
         if let Some(c) = comment_char {
             // line is a comment -> skip
             if bytes[0] == c {
-                let bytes_rem = skip_this_line(bytes, quote_char, eol_char);
+                // quote characters in a comment don't start a string field
+                let bytes_rem = skip_this_line(bytes, None, eol_char);
                 bytes = bytes_rem;
                 continue;
             }
@@ -431,8 +448,13 @@ pub(super) fn parse_lines<'a>(
                 Some((mut field, needs_escaping)) => {
                     let field_len = field.len();
 
-                    // +1 is the split character that is consumed by the iterator.
-                    read_sol += field_len + 1;
+                    // the split characters, the end of line or the delimiter, are consumed by the
+                    // iterator.
+                    let field_end = field.as_ptr() as usize - bytes.as_ptr() as usize + field_len;
+                    read_sol = match bytes.get(field_end) {
+                        Some(&c) if c != eol_char => field_end + delimiter.len(),
+                        _ => field_end + 1,
+                    };
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -535,14 +557,14 @@ mod test {
     #[test]
     fn test_splitfields() {
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b','.into(), Some(b'"'), b'\n');
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b';'.into(), Some(b'"'), b'\n');
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
@@ -563,5 +585,26 @@ mod test {
         assert_eq!(lines2.next(), Some("1,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
+
+        // the quote in the comment is not closed
+        let input3 = "a,b\n# it's \"a comment\n1,\"#foo\n#bar\"\n2,3\n";
+        let mut lines3 =
+            SplitLines::new(input3.as_bytes(), b'"', b'\n').with_comment_char(Some(b'#'));
+        assert_eq!(lines3.next(), Some("a,b".as_bytes()));
+        assert_eq!(lines3.next(), Some("# it's \"a comment".as_bytes()));
+        assert_eq!(lines3.next(), Some("1,\"#foo\n#bar\"".as_bytes()));
+        assert_eq!(lines3.next(), Some("2,3".as_bytes()));
+        assert_eq!(lines3.next(), None);
+    }
+
+    #[test]
+    fn test_splitfields_multi_byte_delimiter() {
+        let delimiter = CsvDelimiter::try_from("||").unwrap();
+        let input = "a|b||\"c||d\"||e\nf";
+        let mut fields = SplitFields::new(input.as_bytes(), delimiter, Some(b'"'), b'\n');
+        assert_eq!(fields.next(), Some(("a|b".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("\"c||d\"".as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("e".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
     }
 }
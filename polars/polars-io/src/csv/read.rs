@@ -1,9 +1,19 @@
+#[cfg(feature = "cloud")]
+use std::io::Cursor;
+use std::path::Path;
+
+use polars_core::cloud::CloudOptions;
+use polars_core::datatypes::PlHashSet;
+
 use super::*;
+use crate::csv::parser::is_whitespace;
 use crate::csv::read_impl::{
     to_batched_owned_mmap, to_batched_owned_read, BatchedCsvReaderMmap, BatchedCsvReaderRead,
     OwnedBatchedCsvReader, OwnedBatchedCsvReaderMmap,
 };
 use crate::csv::utils::infer_file_schema;
+use crate::is_cloud_url;
+use crate::utils::percent_decode;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -25,6 +35,218 @@ pub enum NullValues {
     Named(Vec<(String, String)>),
 }
 
+/// The delimiter of the fields of a CSV file. This is a single byte, e.g. `b','`, or a sequence
+/// of at most 4 bytes, e.g. `"||"`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CsvDelimiter {
+    bytes: [u8; 4],
+    len: u8,
+}
+
+impl CsvDelimiter {
+    pub const MAX_LEN: usize = 4;
+
+    pub fn new(delimiter: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            !delimiter.is_empty() && delimiter.len() <= Self::MAX_LEN,
+            ComputeError: "a CSV delimiter must be 1 to {} bytes, got {:?}",
+            Self::MAX_LEN, String::from_utf8_lossy(delimiter)
+        );
+        let mut bytes = [0; 4];
+        bytes[..delimiter.len()].copy_from_slice(delimiter);
+        Ok(Self {
+            bytes,
+            len: delimiter.len() as u8,
+        })
+    }
+
+    /// The first byte of the delimiter.
+    #[inline]
+    pub fn first(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether the delimiter starts at `idx` of `bytes`, given that `bytes[idx]` is the first
+    /// byte of the delimiter.
+    #[inline]
+    pub(crate) fn is_at(&self, bytes: &[u8], idx: usize) -> bool {
+        self.len == 1 || bytes[idx + 1..].starts_with(&self.as_bytes()[1..])
+    }
+}
+
+impl Default for CsvDelimiter {
+    fn default() -> Self {
+        b','.into()
+    }
+}
+
+impl From<u8> for CsvDelimiter {
+    fn from(delimiter: u8) -> Self {
+        Self {
+            bytes: [delimiter, 0, 0, 0],
+            len: 1,
+        }
+    }
+}
+
+impl TryFrom<&str> for CsvDelimiter {
+    type Error = PolarsError;
+
+    fn try_from(delimiter: &str) -> PolarsResult<Self> {
+        Self::new(delimiter.as_bytes())
+    }
+}
+
+/// The formatting of the numeric fields of a CSV file, e.g. `"$1,234.50"`. The thousands
+/// separators and currency symbols are removed from a field before it is parsed as a number.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NumberFormat {
+    pub thousands_separator: Option<u8>,
+    pub currency_symbols: Vec<String>,
+}
+
+impl NumberFormat {
+    pub fn new(thousands_separator: Option<u8>, currency_symbols: Vec<String>) -> Self {
+        Self {
+            thousands_separator,
+            currency_symbols,
+        }
+    }
+
+    /// Write the field without the thousands separators, currency symbols and surrounding
+    /// whitespace to `out`.
+    pub(crate) fn clean_field(&self, bytes: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        let mut i = 0;
+        'outer: while i < bytes.len() {
+            if Some(bytes[i]) == self.thousands_separator {
+                i += 1;
+                continue;
+            }
+            for symbol in &self.currency_symbols {
+                if !symbol.is_empty() && bytes[i..].starts_with(symbol.as_bytes()) {
+                    i += symbol.len();
+                    continue 'outer;
+                }
+            }
+            out.push(bytes[i]);
+            i += 1;
+        }
+        while out.last().map_or(false, |&b| is_whitespace(b)) {
+            out.pop();
+        }
+        let start = out
+            .iter()
+            .position(|&b| !is_whitespace(b))
+            .unwrap_or(out.len());
+        out.drain(..start);
+    }
+}
+
+/// How the duplicated column names in the header of a CSV file are renamed. A name is never
+/// renamed to a name that is already in the header, the next suffix is taken instead.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DuplicateNames {
+    /// `a, a, a` becomes `a, a_duplicated_0, a_duplicated_1`.
+    #[default]
+    Duplicated,
+    /// `a, a, a` becomes `a, a_1, a_2`.
+    Numbered,
+}
+
+impl DuplicateNames {
+    /// The name of the `n`-th duplicate of `name`, counted from 0.
+    fn rename(&self, name: &str, n: usize) -> String {
+        match self {
+            DuplicateNames::Duplicated => format!("{name}_duplicated_{n}"),
+            DuplicateNames::Numbered => format!("{name}_{}", n + 1),
+        }
+    }
+
+    /// Rename the duplicated names.
+    pub(super) fn deduplicate(&self, names: Vec<String>) -> Vec<String> {
+        let mut taken = names.iter().cloned().collect::<PlHashSet<_>>();
+        let mut counts = PlHashMap::with_capacity(names.len());
+        names
+            .into_iter()
+            .map(|name| {
+                let Some(n) = counts.get_mut(&name) else {
+                    counts.insert(name.clone(), 0usize);
+                    return name;
+                };
+                loop {
+                    let new_name = self.rename(&name, *n);
+                    *n += 1;
+                    if taken.insert(new_name.clone()) {
+                        return new_name;
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// How the column names in the header of a CSV file are normalized, in the order of the fields.
+/// The names that are duplicated after the normalization are renamed as set by `duplicates`.
+/// The dtypes, null values and projected columns refer to the normalized names.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeaderNormalization {
+    /// Decode percent-encoded bytes, e.g. `unit%20price` becomes `unit price`.
+    pub percent_decode: bool,
+    /// Remove leading and trailing whitespace.
+    pub trim: bool,
+    /// Replace every run of whitespace by this string, e.g. `"_"`.
+    pub replace_whitespace: Option<String>,
+    /// Convert the names to lowercase.
+    pub lowercase: bool,
+    pub duplicates: DuplicateNames,
+}
+
+impl HeaderNormalization {
+    pub fn normalize(&self, name: &str) -> String {
+        let mut name = if self.percent_decode {
+            percent_decode(name)
+        } else {
+            name.to_string()
+        };
+        if self.trim {
+            name = name.trim().to_string();
+        }
+        if let Some(replacement) = &self.replace_whitespace {
+            let mut replaced = String::with_capacity(name.len());
+            let mut in_whitespace = false;
+            for c in name.chars() {
+                if !c.is_whitespace() {
+                    replaced.push(c);
+                } else if !in_whitespace {
+                    replaced.push_str(replacement);
+                }
+                in_whitespace = c.is_whitespace();
+            }
+            name = replaced;
+        }
+        if self.lowercase {
+            name = name.to_lowercase();
+        }
+        name
+    }
+}
+
 pub(super) enum NullValuesCompiled {
     /// A single value that's used for all columns
     AllColumnsSingle(String),
@@ -111,7 +333,7 @@ where
     projection: Option<Vec<usize>>,
     /// Optional column names to project/ select.
     columns: Option<Vec<String>>,
-    delimiter: Option<u8>,
+    delimiter: Option<CsvDelimiter>,
     has_header: bool,
     ignore_errors: bool,
     pub(crate) schema: Option<SchemaRef>,
@@ -132,6 +354,9 @@ where
     skip_rows_after_header: usize,
     try_parse_dates: bool,
     row_count: Option<RowCount>,
+    header_normalization: Option<HeaderNormalization>,
+    datetime_formats: Option<Vec<String>>,
+    number_format: Option<NumberFormat>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -202,9 +427,10 @@ where
         self
     }
 
-    /// Set the CSV file's column delimiter as a byte character
-    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = Some(delimiter);
+    /// Set the CSV file's column delimiter, a byte character or a [`CsvDelimiter`] of multiple
+    /// characters, e.g. `CsvDelimiter::try_from("||")?`.
+    pub fn with_delimiter(mut self, delimiter: impl Into<CsvDelimiter>) -> Self {
+        self.delimiter = Some(delimiter.into());
         self
     }
 
@@ -313,6 +539,30 @@ where
         self
     }
 
+    /// The formats, e.g. `"%d.%m.%Y %H:%M"`, that are tried in order when parsing dates and
+    /// datetimes, instead of inferring the pattern. A date or datetime column is parsed with the
+    /// first format that parses all of its values.
+    pub fn with_datetime_formats(mut self, formats: Option<Vec<String>>) -> Self {
+        self.datetime_formats = formats;
+        self
+    }
+
+    /// Remove the thousands separators and currency symbols of the numeric fields while parsing,
+    /// e.g. to read `"$1,234.50"` as a float. This is also taken into account in schema inference.
+    pub fn with_number_format(mut self, number_format: Option<NumberFormat>) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Normalize the column names of the header, see [`HeaderNormalization`].
+    pub fn with_header_normalization(
+        mut self,
+        header_normalization: Option<HeaderNormalization>,
+    ) -> Self {
+        self.header_normalization = header_normalization;
+        self
+    }
+
     #[cfg(feature = "private")]
     pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
         self.predicate = predicate;
@@ -367,6 +617,9 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.skip_rows_after_header,
             std::mem::take(&mut self.row_count),
             self.try_parse_dates,
+            self.header_normalization.as_ref(),
+            std::mem::take(&mut self.datetime_formats),
+            std::mem::take(&mut self.number_format),
         )
     }
 
@@ -432,7 +685,41 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
     }
 }
 
+/// Open the CSV file at `path`, which may be a cloud url. A cloud file is downloaded into
+/// memory, as a CSV file can only be parsed from its start.
+#[allow(unused_variables)]
+pub fn open_file_or_url(
+    path: &Path,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Box<dyn MmapBytesReader>> {
+    if is_cloud_url(path) {
+        #[cfg(feature = "cloud")]
+        {
+            let bytes = crate::cloud::download(&path.to_string_lossy(), cloud_options)?;
+            return Ok(Box::new(Cursor::new(bytes)));
+        }
+        #[cfg(not(feature = "cloud"))]
+        polars_bail!(
+            ComputeError:
+            "one or more of the cloud storage features ('aws', 'gcp', ...) must be enabled to read a CSV file from a cloud url"
+        );
+    }
+    let file = std::fs::File::open(resolve_homedir(path))?;
+    Ok(Box::new(file))
+}
+
 impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
+    /// Create a reader of the CSV file at `path`, which may be a cloud url, see
+    /// [`open_file_or_url`].
+    pub fn from_uri<P: Into<PathBuf>>(
+        path: P,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let path = path.into();
+        let reader = open_file_or_url(&path, cloud_options)?;
+        Ok(Self::new(reader).with_path(Some(path)))
+    }
+
     pub fn batched_mmap(
         mut self,
         schema: Option<SchemaRef>,
@@ -444,7 +731,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
-                    self.delimiter.unwrap_or(b','),
+                    self.delimiter.unwrap_or_default(),
                     self.max_records,
                     self.has_header,
                     None,
@@ -455,6 +742,9 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
+                    self.header_normalization.as_ref(),
+                    self.datetime_formats.as_deref(),
+                    self.number_format.as_ref(),
                 )?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned_mmap(self, schema))
@@ -472,7 +762,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
-                    self.delimiter.unwrap_or(b','),
+                    self.delimiter.unwrap_or_default(),
                     self.max_records,
                     self.has_header,
                     None,
@@ -483,6 +773,9 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
+                    self.header_normalization.as_ref(),
+                    self.datetime_formats.as_deref(),
+                    self.number_format.as_ref(),
                 )?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned_read(self, schema))
@@ -526,6 +819,9 @@ where
             skip_rows_after_header: 0,
             try_parse_dates: false,
             row_count: None,
+            header_normalization: None,
+            datetime_formats: None,
+            number_format: None,
         }
     }
 
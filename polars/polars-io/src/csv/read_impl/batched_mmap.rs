@@ -13,7 +13,7 @@ pub(crate) fn get_file_chunks_iterator(
     chunk_size: usize,
     bytes: &[u8],
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -49,7 +49,7 @@ struct ChunkOffsetIter<'a> {
     // not a promise, but something we want
     rows_per_batch: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 }
@@ -159,6 +159,8 @@ impl<'a> CoreReader<'a> {
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
+            datetime_formats: self.datetime_formats,
+            number_format: self.number_format,
             ignore_errors: self.ignore_errors,
             n_rows: self.n_rows,
             encoding: self.encoding,
@@ -186,10 +188,12 @@ pub struct BatchedCsvReaderMmap<'a> {
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     to_cast: Vec<Field>,
+    datetime_formats: Option<DatetimeFormats>,
+    number_format: Option<NumberFormat>,
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
@@ -246,10 +250,9 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
+                        self.number_format.as_ref(),
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false)?;
-
                     update_string_stats(&self.str_capacities, &self.str_columns, &df)?;
                     if let Some(rc) = &self.row_count {
                         df.with_row_count_mut(&rc.name, Some(rc.offset));
@@ -258,6 +261,16 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
+        // the datetime formats are resolved with the first batches, in the order of the file,
+        // and reused for all later batches
+        if let Some(formats) = &self.datetime_formats {
+            formats.resolve(&chunks, &self.to_cast);
+        }
+        POOL.install(|| {
+            chunks.par_iter_mut().try_for_each(|df| {
+                cast_columns(df, &self.to_cast, false, self.datetime_formats.as_ref())
+            })
+        })?;
         self.file_chunks.clear();
 
         if self.row_count.is_some() {
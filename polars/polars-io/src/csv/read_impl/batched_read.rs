@@ -14,7 +14,7 @@ pub(crate) fn get_offsets(
     chunk_size: usize,
     bytes: &[u8],
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -57,7 +57,7 @@ struct ChunkReader<'a> {
     // not a promise, but something we want
     rows_per_batch: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 }
@@ -67,7 +67,7 @@ impl<'a> ChunkReader<'a> {
         file: &'a File,
         rows_per_batch: usize,
         expected_fields: usize,
-        delimiter: u8,
+        delimiter: CsvDelimiter,
         quote_char: Option<u8>,
         eol_char: u8,
         page_size: u64,
@@ -192,7 +192,9 @@ impl<'a> CoreReader<'a> {
     pub fn batched_read(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReaderRead<'a>> {
         let reader_bytes = self.reader_bytes.take().unwrap();
 
-        let ReaderBytes::Mapped(bytes, mut file) = &reader_bytes else { unreachable!() };
+        let ReaderBytes::Mapped(bytes, mut file) = &reader_bytes else {
+            unreachable!()
+        };
         let (_, starting_point_offset) = self.find_starting_point(bytes, self.eol_char)?;
         if let Some(starting_point_offset) = starting_point_offset {
             file.seek(SeekFrom::Current(starting_point_offset as i64))
@@ -240,6 +242,8 @@ impl<'a> CoreReader<'a> {
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
+            datetime_formats: self.datetime_formats,
+            number_format: self.number_format,
             ignore_errors: self.ignore_errors,
             n_rows: self.n_rows,
             encoding: self.encoding,
@@ -267,10 +271,12 @@ pub struct BatchedCsvReaderRead<'a> {
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     to_cast: Vec<Field>,
+    datetime_formats: Option<DatetimeFormats>,
+    number_format: Option<NumberFormat>,
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
@@ -341,10 +347,9 @@ impl<'a> BatchedCsvReaderRead<'a> {
                         self.chunk_size,
                         stop_at_n_bytes,
                         self.starting_point_offset,
+                        self.number_format.as_ref(),
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false)?;
-
                     update_string_stats(&self.str_capacities, &self.str_columns, &df)?;
                     if let Some(rc) = &self.row_count {
                         df.with_row_count_mut(&rc.name, Some(rc.offset));
@@ -353,6 +358,16 @@ impl<'a> BatchedCsvReaderRead<'a> {
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
+        // the datetime formats are resolved with the first batches, in the order of the file,
+        // and reused for all later batches
+        if let Some(formats) = &self.datetime_formats {
+            formats.resolve(&chunks, &self.to_cast);
+        }
+        POOL.install(|| {
+            chunks.par_iter_mut().try_for_each(|df| {
+                cast_columns(df, &self.to_cast, false, self.datetime_formats.as_ref())
+            })
+        })?;
         self.file_chunks.clear();
 
         if self.row_count.is_some() {
@@ -4,7 +4,7 @@ mod batched_read;
 use std::fmt;
 use std::ops::Deref;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 pub use batched_mmap::*;
 pub use batched_read::*;
@@ -23,30 +23,113 @@ use crate::csv::buffer::*;
 use crate::csv::parser::*;
 use crate::csv::read::NullValuesCompiled;
 use crate::csv::utils::*;
-use crate::csv::{CsvEncoding, NullValues};
+use crate::csv::{CsvDelimiter, CsvEncoding, HeaderNormalization, NullValues, NumberFormat};
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
 use crate::RowCount;
 
+/// Parse a `Utf8` column as a date or datetime with `fmt`, or with the inferred pattern.
+#[cfg(feature = "temporal")]
+fn parse_temporal(s: &Series, dtype: &DataType, fmt: Option<&str>) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    match dtype {
+        DataType::Date => ca.as_date(fmt, false).map(|ca| ca.into_series()),
+        DataType::Datetime(tu, _) => ca
+            .as_datetime(fmt, *tu, false, false, false, None)
+            .map(|ca| ca.into_series()),
+        dt => s.cast(dt),
+    }
+}
+
+/// The datetime formats of a reader, and the format of every temporal column once it is
+/// resolved. A column is parsed with the same format in all chunks, so that a chunk whose values
+/// happen to match another format isn't parsed differently.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "temporal"), allow(dead_code))]
+pub(crate) struct DatetimeFormats {
+    formats: Vec<String>,
+    /// The index in `formats` of the format of a column, or `None` if none of the formats parses
+    /// all of its values and the pattern is inferred.
+    resolved: RwLock<PlHashMap<String, Option<usize>>>,
+}
+
+impl DatetimeFormats {
+    pub(crate) fn new(formats: Vec<String>) -> Self {
+        DatetimeFormats {
+            formats,
+            resolved: Default::default(),
+        }
+    }
+
+    /// Resolve the formats of the temporal columns that have no format yet. The first of `dfs`
+    /// with values for a column determines its format.
+    pub(crate) fn resolve<'b>(
+        &self,
+        dfs: impl IntoIterator<Item = &'b DataFrame>,
+        to_cast: &[Field],
+    ) {
+        #[cfg(not(feature = "temporal"))]
+        let _ = (dfs, to_cast);
+        #[cfg(feature = "temporal")]
+        for df in dfs {
+            for fld in to_cast {
+                if let Ok(s) = df.column(fld.name()) {
+                    self.format_index(s, fld.data_type());
+                }
+            }
+        }
+    }
+
+    /// The format of a column, which is resolved with its values in `s` if it has no format yet.
+    #[cfg(feature = "temporal")]
+    fn format_index(&self, s: &Series, dtype: &DataType) -> Option<usize> {
+        if !matches!(dtype, DataType::Date | DataType::Datetime(_, _))
+            || s.dtype() != &DataType::Utf8
+        {
+            return None;
+        }
+        if let Some(idx) = self.resolved.read().unwrap().get(s.name()) {
+            return *idx;
+        }
+        // a chunk without values doesn't determine the format
+        if s.null_count() == s.len() {
+            return None;
+        }
+        let idx = self.formats.iter().position(|fmt| {
+            parse_temporal(s, dtype, Some(fmt))
+                .map_or(false, |out| out.null_count() == s.null_count())
+        });
+        // another chunk may have resolved the format in the meantime, its format is kept
+        *self
+            .resolved
+            .write()
+            .unwrap()
+            .entry(s.name().to_string())
+            .or_insert(idx)
+    }
+
+    #[cfg(feature = "temporal")]
+    fn format(&self, s: &Series, dtype: &DataType) -> Option<&str> {
+        self.format_index(s, dtype)
+            .map(|idx| self.formats[idx].as_str())
+    }
+}
+
 pub(crate) fn cast_columns(
     df: &mut DataFrame,
     to_cast: &[Field],
     parallel: bool,
+    datetime_formats: Option<&DatetimeFormats>,
 ) -> PolarsResult<()> {
+    #[cfg(not(feature = "temporal"))]
+    let _ = datetime_formats;
     let cast_fn = |s: &Series, fld: &Field| match (s.dtype(), fld.data_type()) {
         #[cfg(feature = "temporal")]
-        (DataType::Utf8, DataType::Date) => s
-            .utf8()
-            .unwrap()
-            .as_date(None, false)
-            .map(|ca| ca.into_series()),
-        #[cfg(feature = "temporal")]
-        (DataType::Utf8, DataType::Datetime(tu, _)) => s
-            .utf8()
-            .unwrap()
-            .as_datetime(None, *tu, false, false, false, None)
-            .map(|ca| ca.into_series()),
+        (DataType::Utf8, dt @ (DataType::Date | DataType::Datetime(_, _))) => {
+            let fmt = datetime_formats.and_then(|formats| formats.format(s, dt));
+            parse_temporal(s, dt, fmt)
+        }
         (_, dt) => s.cast(dt),
     };
 
@@ -89,7 +172,7 @@ pub(crate) struct CoreReader<'a> {
     encoding: CsvEncoding,
     n_threads: Option<usize>,
     has_header: bool,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
@@ -101,6 +184,8 @@ pub(crate) struct CoreReader<'a> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_count: Option<RowCount>,
+    datetime_formats: Option<DatetimeFormats>,
+    number_format: Option<NumberFormat>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -169,7 +254,7 @@ impl<'a> CoreReader<'a> {
         mut skip_rows: usize,
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
-        delimiter: Option<u8>,
+        delimiter: Option<CsvDelimiter>,
         has_header: bool,
         ignore_errors: bool,
         schema: Option<SchemaRef>,
@@ -191,6 +276,9 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        header_normalization: Option<&HeaderNormalization>,
+        datetime_formats: Option<Vec<String>>,
+        number_format: Option<NumberFormat>,
     ) -> PolarsResult<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
@@ -203,8 +291,14 @@ impl<'a> CoreReader<'a> {
             );
         }
 
+        let delimiter = delimiter.unwrap_or_default();
+        polars_ensure!(
+            !delimiter.as_bytes().contains(&eol_char)
+                && quote_char.map_or(true, |quote| !delimiter.as_bytes().contains(&quote)),
+            ComputeError: "the CSV delimiter cannot contain the end of line or quote character"
+        );
+
         // check if schema should be inferred
-        let delimiter = delimiter.unwrap_or(b',');
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -233,6 +327,9 @@ impl<'a> CoreReader<'a> {
                         eol_char,
                         null_values.as_ref(),
                         try_parse_dates,
+                        header_normalization,
+                        datetime_formats.as_deref(),
+                        number_format.as_ref(),
                     )?;
                     Arc::new(inferred_schema)
                 }
@@ -245,6 +342,24 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        // the temporal columns are parsed as strings and converted with the datetime formats
+        #[cfg(feature = "temporal")]
+        let mut to_cast = to_cast;
+        #[cfg(feature = "temporal")]
+        if datetime_formats.is_some() {
+            let s = Arc::make_mut(&mut schema);
+            let temporal = s
+                .iter_fields()
+                .filter(|fld| matches!(fld.data_type(), DataType::Date | DataType::Datetime(_, _)))
+                .collect::<Vec<_>>();
+            for fld in temporal {
+                s.set_dtype(fld.name(), DataType::Utf8);
+                if !to_cast.iter().any(|other| other.name() == fld.name()) {
+                    to_cast.push(fld);
+                }
+            }
+        }
+
         // create a null value for every column
         let mut null_values = null_values.map(|nv| nv.compile(&schema)).transpose()?;
 
@@ -287,6 +402,8 @@ impl<'a> CoreReader<'a> {
             predicate,
             to_cast,
             row_count,
+            datetime_formats: datetime_formats.map(DatetimeFormats::new),
+            number_format,
         })
     }
 
@@ -298,7 +415,7 @@ impl<'a> CoreReader<'a> {
         let starting_point_offset = bytes.as_ptr() as usize;
 
         // Skip all leading white space and the occasional utf8-bom
-        bytes = skip_whitespace_exclude(skip_bom(bytes), self.delimiter);
+        bytes = skip_whitespace_exclude(skip_bom(bytes), self.delimiter.first());
         // \n\n can be a empty string row of a single column
         // in other cases we skip it.
         if self.schema.len() > 1 {
@@ -320,18 +437,15 @@ impl<'a> CoreReader<'a> {
 
         if self.skip_rows_after_header > 0 {
             for _ in 0..self.skip_rows_after_header {
-                let pos = match bytes.first() {
+                bytes = match bytes.first() {
+                    // quote characters in a comment don't start a string field
                     Some(first) if Some(*first) == self.comment_char => {
-                        next_line_position_naive(bytes, eol_char)
+                        skip_this_line(bytes, None, eol_char)
                     }
-                    // we don't pass expected fields
-                    // as we want to skip all rows
-                    // no matter the no. of fields
-                    _ => next_line_position(bytes, None, self.delimiter, self.quote_char, eol_char),
-                }
-                .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
-
-                bytes = &bytes[pos..];
+                    // a row can have new lines in quoted fields
+                    Some(_) => skip_this_line(bytes, self.quote_char, eol_char),
+                    None => polars_bail!(NoData: "not enough lines to skip"),
+                };
             }
         }
 
@@ -556,6 +670,7 @@ impl<'a> CoreReader<'a> {
                 self.quote_char,
                 self.encoding,
                 self.ignore_errors,
+                self.number_format.as_ref(),
             )?;
             let df = DataFrame::new_no_checks(
                 buffers
@@ -571,6 +686,45 @@ impl<'a> CoreReader<'a> {
         //      the inner vec has got buffers from all the columns.
         if let Some(predicate) = predicate {
             let str_capacities = self.init_string_size_stats(&str_columns, chunk_size);
+            // the chunks are filtered as soon as they are cast, so the datetime formats are
+            // resolved with the first rows of the file
+            if let (Some(formats), Some(&(offset, stop_at_nbytes))) =
+                (&self.datetime_formats, file_chunks.first())
+            {
+                let mut buffers = init_buffers(
+                    &projection,
+                    chunk_size,
+                    &self.schema,
+                    &str_capacities,
+                    self.quote_char,
+                    self.encoding,
+                    self.ignore_errors,
+                    self.number_format.as_ref(),
+                )?;
+                parse_lines(
+                    &bytes[offset..stop_at_nbytes],
+                    offset + starting_point_offset.unwrap(),
+                    self.delimiter,
+                    self.comment_char,
+                    self.quote_char,
+                    self.eol_char,
+                    self.null_values.as_ref(),
+                    self.missing_is_null,
+                    &projection,
+                    &mut buffers,
+                    self.ignore_errors,
+                    chunk_size,
+                    self.schema.len(),
+                    &self.schema,
+                )?;
+                let sample = DataFrame::new_no_checks(
+                    buffers
+                        .into_iter()
+                        .map(|buf| buf.into_series())
+                        .collect::<PolarsResult<_>>()?,
+                );
+                formats.resolve([&sample], &self.to_cast);
+            }
             let dfs = pool.install(|| {
                 file_chunks
                     .into_par_iter()
@@ -596,6 +750,7 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.ignore_errors,
+                                self.number_format.as_ref(),
                             )?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
@@ -630,7 +785,12 @@ impl<'a> CoreReader<'a> {
                                 local_df.with_row_count_mut(&rc.name, Some(rc.offset));
                             };
 
-                            cast_columns(&mut local_df, &self.to_cast, false)?;
+                            cast_columns(
+                                &mut local_df,
+                                &self.to_cast,
+                                false,
+                                self.datetime_formats.as_ref(),
+                            )?;
                             let s = predicate.evaluate(&local_df)?;
                             let mask = s.bool()?;
                             local_df = local_df.filter(mask)?;
@@ -686,6 +846,7 @@ impl<'a> CoreReader<'a> {
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
+                            self.number_format.as_ref(),
                         )?;
 
                         // update the running str bytes statistics
@@ -693,7 +854,6 @@ impl<'a> CoreReader<'a> {
                             update_string_stats(&str_capacities, &str_columns, &df)?;
                         }
 
-                        cast_columns(&mut df, &self.to_cast, false)?;
                         if let Some(rc) = &self.row_count {
                             df.with_row_count_mut(&rc.name, Some(rc.offset));
                         }
@@ -702,6 +862,16 @@ impl<'a> CoreReader<'a> {
                     })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
+            // the datetime formats are resolved with the chunks in the order of the file before
+            // any chunk is cast
+            if let Some(formats) = &self.datetime_formats {
+                formats.resolve(dfs.iter().map(|(df, _)| df), &self.to_cast);
+            }
+            pool.install(|| {
+                dfs.par_iter_mut().try_for_each(|(df, _)| {
+                    cast_columns(df, &self.to_cast, false, self.datetime_formats.as_ref())
+                })
+            })?;
             if let (Some(n_rows), Some(remaining_bytes)) = (self.n_rows, remaining_bytes) {
                 let rows_already_read: usize = dfs.iter().map(|x| x.1 as usize).sum();
                 if rows_already_read < n_rows {
@@ -716,6 +886,7 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.ignore_errors,
+                                self.number_format.as_ref(),
                             )?;
 
                             parse_lines(
@@ -743,7 +914,12 @@ impl<'a> CoreReader<'a> {
                             )
                         };
 
-                        cast_columns(&mut df, &self.to_cast, false)?;
+                        cast_columns(
+                            &mut df,
+                            &self.to_cast,
+                            false,
+                            self.datetime_formats.as_ref(),
+                        )?;
                         if let Some(rc) = &self.row_count {
                             df.with_row_count_mut(&rc.name, Some(rc.offset));
                         }
@@ -786,7 +962,10 @@ fn update_string_stats(
 ) -> PolarsResult<()> {
     // update the running str bytes statistics
     for (str_index, name) in str_columns.iter().enumerate() {
-        let ca = local_df.column(name)?.utf8()?;
+        // the temporal columns are parsed as strings, but may already be cast
+        let Ok(ca) = local_df.column(name)?.utf8() else {
+            continue;
+        };
         let str_bytes_len = ca.get_values_size();
 
         let _ = str_capacities[str_index].update(str_bytes_len);
@@ -798,7 +977,7 @@ fn update_string_stats(
 #[allow(clippy::too_many_arguments)]
 fn read_chunk(
     bytes: &[u8],
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     schema: &Schema,
     ignore_errors: bool,
     projection: &[usize],
@@ -814,6 +993,7 @@ fn read_chunk(
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
+    number_format: Option<&NumberFormat>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
     let mut buffers = init_buffers(
@@ -824,6 +1004,7 @@ fn read_chunk(
         quote_char,
         encoding,
         ignore_errors,
+        number_format,
     )?;
 
     let mut last_read = usize::MAX;
@@ -1,10 +1,14 @@
 #[cfg(not(feature = "simd"))]
 mod inner {
+    use crate::csv::CsvDelimiter;
+
     /// An adapted version of std::iter::Split.
     /// This exists solely because we cannot split the lines naively as
     pub(crate) struct SplitFields<'a> {
         v: &'a [u8],
+        // the first byte of the delimiter
         delimiter: u8,
+        full_delimiter: CsvDelimiter,
         finished: bool,
         quote_char: u8,
         quoting: bool,
@@ -14,13 +18,15 @@ mod inner {
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            delimiter: u8,
+            full_delimiter: CsvDelimiter,
             quote_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
+            let delimiter = full_delimiter.first();
             Self {
                 v: slice,
                 delimiter,
+                full_delimiter,
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
@@ -86,7 +92,11 @@ mod inner {
                         in_field = !in_field;
                     }
 
-                    if !in_field && self.eof_oel(c) {
+                    if !in_field
+                        && self.eof_oel(c)
+                        && (c == self.eol_char
+                            || self.full_delimiter.is_at(self.v, current_idx as usize))
+                    {
                         if c == self.eol_char {
                             // safety
                             // we are in bounds
@@ -106,17 +116,24 @@ mod inner {
 
                 idx as usize
             } else {
-                match self.v.iter().position(|&c| self.eof_oel(c)) {
-                    None => return self.finish(needs_escaping),
-                    Some(idx) => unsafe {
-                        // Safety:
-                        // idx was just found
-                        if *self.v.get_unchecked(idx) == self.eol_char {
-                            return self.finish_eol(needs_escaping, idx);
-                        } else {
-                            idx
-                        }
-                    },
+                let mut offset = 0;
+                loop {
+                    match self.v[offset..].iter().position(|&c| self.eof_oel(c)) {
+                        None => return self.finish(needs_escaping),
+                        Some(idx) => unsafe {
+                            let idx = offset + idx;
+                            // Safety:
+                            // idx was just found
+                            if *self.v.get_unchecked(idx) == self.eol_char {
+                                return self.finish_eol(needs_escaping, idx);
+                            } else if self.full_delimiter.is_at(self.v, idx) {
+                                break idx;
+                            } else {
+                                // only the first byte of a multi-byte delimiter
+                                offset = idx + 1;
+                            }
+                        },
+                    }
                 }
             };
 
@@ -125,7 +142,7 @@ mod inner {
                 // safety
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + self.full_delimiter.len()..);
                 ret
             }
         }
@@ -140,6 +157,8 @@ mod inner {
     use polars_utils::slice::GetSaferUnchecked;
     use polars_utils::unwrap::UnwrapUncheckedRelease;
 
+    use crate::csv::CsvDelimiter;
+
     const SIMD_SIZE: usize = 16;
     type SimdVec = u8x16;
 
@@ -157,7 +176,9 @@ mod inner {
     /// This exists solely because we cannot split the lines naively as
     pub(crate) struct SplitFields<'a> {
         pub v: &'a [u8],
+        // the first byte of the delimiter
         delimiter: u8,
+        full_delimiter: CsvDelimiter,
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
@@ -169,16 +190,18 @@ mod inner {
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            delimiter: u8,
+            full_delimiter: CsvDelimiter,
             quote_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
+            let delimiter = full_delimiter.first();
             let simd_delimiter = SimdVec::splat(delimiter);
             let simd_eol_char = SimdVec::splat(eol_char);
 
             Self {
                 v: slice,
                 delimiter,
+                full_delimiter,
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
@@ -246,7 +269,11 @@ mod inner {
                         in_field = !in_field;
                     }
 
-                    if !in_field && self.eof_oel(c) {
+                    if !in_field
+                        && self.eof_oel(c)
+                        && (c == self.eol_char
+                            || self.full_delimiter.is_at(self.v, current_idx as usize))
+                    {
                         if c == self.eol_char {
                             // safety
                             // we are in bounds
@@ -269,45 +296,50 @@ mod inner {
                 let mut total_idx = 0;
 
                 loop {
-                    let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
-
-                    if bytes.len() > SIMD_SIZE {
-                        unsafe {
-                            let lane: [u8; SIMD_SIZE] = bytes
-                                .get_unchecked(0..SIMD_SIZE)
-                                .try_into()
-                                .unwrap_unchecked_release();
-                            let simd_bytes = SimdVec::from(lane);
-                            let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
-                            let has_delimiter = simd_bytes.simd_eq(self.simd_delimiter);
-                            let has_any = has_delimiter.bitor(has_eol_char);
-                            if has_any.any() {
-                                // soundness we can transmute because we have the same alignment
-                                let has_any = std::mem::transmute::<
-                                    Mask<_, SIMD_SIZE>,
-                                    [bool; SIMD_SIZE],
-                                >(has_any);
-                                total_idx += simple_argmax(&has_any);
-                                break;
-                            } else {
-                                total_idx += SIMD_SIZE;
+                    loop {
+                        let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
+
+                        if bytes.len() > SIMD_SIZE {
+                            unsafe {
+                                let lane: [u8; SIMD_SIZE] = bytes
+                                    .get_unchecked(0..SIMD_SIZE)
+                                    .try_into()
+                                    .unwrap_unchecked_release();
+                                let simd_bytes = SimdVec::from(lane);
+                                let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
+                                let has_delimiter = simd_bytes.simd_eq(self.simd_delimiter);
+                                let has_any = has_delimiter.bitor(has_eol_char);
+                                if has_any.any() {
+                                    // soundness we can transmute because we have the same alignment
+                                    let has_any = std::mem::transmute::<
+                                        Mask<_, SIMD_SIZE>,
+                                        [bool; SIMD_SIZE],
+                                    >(has_any);
+                                    total_idx += simple_argmax(&has_any);
+                                    break;
+                                } else {
+                                    total_idx += SIMD_SIZE;
+                                }
                             }
-                        }
-                    } else {
-                        match bytes.iter().position(|&c| self.eof_oel(c)) {
-                            None => return self.finish(needs_escaping),
-                            Some(idx) => {
-                                total_idx += idx;
-                                break;
+                        } else {
+                            match bytes.iter().position(|&c| self.eof_oel(c)) {
+                                None => return self.finish(needs_escaping),
+                                Some(idx) => {
+                                    total_idx += idx;
+                                    break;
+                                }
                             }
                         }
                     }
-                }
-                unsafe {
-                    if *self.v.get_unchecked_release(total_idx) == self.eol_char {
-                        return self.finish_eol(needs_escaping, total_idx);
-                    } else {
-                        total_idx
+                    unsafe {
+                        if *self.v.get_unchecked_release(total_idx) == self.eol_char {
+                            return self.finish_eol(needs_escaping, total_idx);
+                        } else if self.full_delimiter.is_at(self.v, total_idx) {
+                            break total_idx;
+                        } else {
+                            // only the first byte of a multi-byte delimiter
+                            total_idx += 1;
+                        }
                     }
                 }
             };
@@ -317,7 +349,7 @@ mod inner {
                 // safety
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + self.full_delimiter.len()..);
                 ret
             }
         }
@@ -5,18 +5,20 @@ use std::mem::MaybeUninit;
 use once_cell::sync::Lazy;
 use polars_core::datatypes::PlHashSet;
 use polars_core::prelude::*;
+use polars_core::POOL;
 #[cfg(feature = "polars-time")]
 use polars_time::chunkedarray::utf8::infer as date_infer;
 use polars_time::chunkedarray::utf8::PatternWithOffset;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::utf8::Pattern;
+use rayon::prelude::*;
 use regex::{Regex, RegexBuilder};
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use crate::csv::parser::next_line_position_naive;
 use crate::csv::parser::{next_line_position, skip_bom, skip_line_ending, SplitLines};
 use crate::csv::splitfields::SplitFields;
-use crate::csv::CsvEncoding;
+use crate::csv::{CsvDelimiter, CsvEncoding, HeaderNormalization, NumberFormat};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::NullValues;
 
@@ -24,7 +26,7 @@ pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
@@ -102,37 +104,71 @@ static BOOLEAN_RE: Lazy<Regex> = Lazy::new(|| {
         .unwrap()
 });
 
+/// Infer the temporal data type of a record with the given formats, e.g. `"%d.%m.%Y %H:%M"`.
+#[cfg(feature = "temporal")]
+fn infer_temporal_with_formats(string: &str, formats: &[String]) -> DataType {
+    use chrono::{NaiveDate, NaiveDateTime};
+    formats
+        .iter()
+        .find_map(|fmt| {
+            if NaiveDateTime::parse_from_str(string, fmt).is_ok() {
+                Some(DataType::Datetime(TimeUnit::Microseconds, None))
+            } else if NaiveDate::parse_from_str(string, fmt).is_ok() {
+                Some(DataType::Date)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(DataType::Utf8)
+}
+
+/// Infer the temporal data type of a record, or `Utf8` if it isn't temporal.
+fn infer_temporal(string: &str, datetime_formats: Option<&[String]>) -> DataType {
+    #[cfg(feature = "temporal")]
+    if let Some(formats) = datetime_formats {
+        return infer_temporal_with_formats(string, formats);
+    }
+    #[cfg(not(feature = "temporal"))]
+    let _ = datetime_formats;
+
+    #[cfg(feature = "polars-time")]
+    {
+        match date_infer::infer_pattern_single(string) {
+            Some(pattern_with_offset) => match pattern_with_offset {
+                PatternWithOffset {
+                    pattern: Pattern::DatetimeYMD | Pattern::DatetimeDMY,
+                    offset: _,
+                } => DataType::Datetime(TimeUnit::Microseconds, None),
+                PatternWithOffset {
+                    pattern: Pattern::DateYMD | Pattern::DateDMY,
+                    offset: _,
+                } => DataType::Date,
+                PatternWithOffset {
+                    pattern: Pattern::DatetimeYMDZ,
+                    offset: _,
+                } => DataType::Utf8, // TODO: support tz-aware,
+                                     // need to keep track of offset
+            },
+            None => DataType::Utf8,
+        }
+    }
+    #[cfg(not(feature = "polars-time"))]
+    {
+        panic!("activate one of {{'dtype-date', 'dtype-datetime', dtype-time'}} features")
+    }
+}
+
 /// Infer the data type of a record
-fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
+fn infer_field_schema(
+    string: &str,
+    try_parse_dates: bool,
+    datetime_formats: Option<&[String]>,
+) -> DataType {
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // Utf8 for them
     if string.starts_with('"') {
         if try_parse_dates {
-            #[cfg(feature = "polars-time")]
-            {
-                match date_infer::infer_pattern_single(&string[1..string.len() - 1]) {
-                    Some(pattern_with_offset) => match pattern_with_offset {
-                        PatternWithOffset {
-                            pattern: Pattern::DatetimeYMD | Pattern::DatetimeDMY,
-                            offset: _,
-                        } => DataType::Datetime(TimeUnit::Microseconds, None),
-                        PatternWithOffset {
-                            pattern: Pattern::DateYMD | Pattern::DateDMY,
-                            offset: _,
-                        } => DataType::Date,
-                        PatternWithOffset {
-                            pattern: Pattern::DatetimeYMDZ,
-                            offset: _,
-                        } => DataType::Utf8, // TODO: support tz-aware,
-                                             // need to keep track of offset
-                    },
-                    None => DataType::Utf8,
-                }
-            }
-            #[cfg(not(feature = "polars-time"))]
-            {
-                panic!("activate one of {{'dtype-date', 'dtype-datetime', dtype-time'}} features")
-            }
+            infer_temporal(&string[1..string.len() - 1], datetime_formats)
         } else {
             DataType::Utf8
         }
@@ -145,31 +181,7 @@ fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
     } else if INTEGER_RE.is_match(string) {
         DataType::Int64
     } else if try_parse_dates {
-        #[cfg(feature = "polars-time")]
-        {
-            match date_infer::infer_pattern_single(string) {
-                Some(pattern_with_offset) => match pattern_with_offset {
-                    PatternWithOffset {
-                        pattern: Pattern::DatetimeYMD | Pattern::DatetimeDMY,
-                        offset: _,
-                    } => DataType::Datetime(TimeUnit::Microseconds, None),
-                    PatternWithOffset {
-                        pattern: Pattern::DateYMD | Pattern::DateDMY,
-                        offset: _,
-                    } => DataType::Date,
-                    PatternWithOffset {
-                        pattern: Pattern::DatetimeYMDZ,
-                        offset: _,
-                    } => DataType::Utf8, // TODO: support tz-aware,
-                                         // need to keep track of offset
-                },
-                None => DataType::Utf8,
-            }
-        }
-        #[cfg(not(feature = "polars-time"))]
-        {
-            panic!("activate one of {{'dtype-date', 'dtype-datetime', dtype-time'}} features")
-        }
+        infer_temporal(string, datetime_formats)
     } else {
         DataType::Utf8
     }
@@ -200,7 +212,7 @@ pub(crate) fn parse_bytes_with_encoding(
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema(
     reader_bytes: &ReaderBytes,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
@@ -213,6 +225,9 @@ pub fn infer_file_schema(
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
+    header_normalization: Option<&HeaderNormalization>,
+    datetime_formats: Option<&[String]>,
+    number_format: Option<&NumberFormat>,
 ) -> PolarsResult<(Schema, usize, usize)> {
     // keep track so that we can determine the amount of bytes read
     let start_ptr = reader_bytes.as_ptr() as usize;
@@ -223,7 +238,9 @@ pub fn infer_file_schema(
 
     let bytes = skip_line_ending(skip_bom(reader_bytes), eol_char);
     polars_ensure!(!bytes.is_empty(), NoData: "empty CSV");
-    let mut lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+    let mut lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char)
+        .with_comment_char(comment_char)
+        .skip(*skip_rows);
     // it can be that we have a single line without eol char
     let has_eol = bytes.contains(&eol_char);
 
@@ -271,24 +288,17 @@ pub fn infer_file_schema(
                         slice
                     };
                     let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
-                    Ok(s)
+                    Ok(match header_normalization {
+                        Some(normalization) => normalization.normalize(&s),
+                        None => s.into_owned(),
+                    })
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
-            let mut final_headers = Vec::with_capacity(headers.len());
-
-            let mut header_names = PlHashMap::with_capacity(headers.len());
-
-            for name in &headers {
-                let count = header_names.entry(name.as_ref()).or_insert(0usize);
-                if *count != 0 {
-                    final_headers.push(format!("{}_duplicated_{}", name, *count - 1))
-                } else {
-                    final_headers.push(name.to_string())
-                }
-                *count += 1;
-            }
-            final_headers
+            header_normalization
+                .map(|normalization| normalization.duplicates)
+                .unwrap_or_default()
+                .deduplicate(headers)
         } else {
             let mut column_names: Vec<String> = byterecord
                 .enumerate()
@@ -296,7 +306,7 @@ pub fn infer_file_schema(
                 .collect();
             // needed because SplitLines does not return the \n char, so SplitFields does not catch
             // the latest value if ending with a delimiter.
-            if header_line.ends_with(&[delimiter]) {
+            if header_line.ends_with(delimiter.as_bytes()) {
                 column_names.push(format!("column_{}", column_names.len() + 1))
             }
             column_names
@@ -321,30 +331,24 @@ pub fn infer_file_schema(
             eol_char,
             null_values,
             try_parse_dates,
+            header_normalization,
+            datetime_formats,
+            number_format,
         );
     } else {
         polars_bail!(NoData: "empty CSV");
     };
     if !has_header {
         // re-init lines so that the header is included in type inference.
-        lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+        lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char)
+            .with_comment_char(comment_char)
+            .skip(*skip_rows);
     }
 
     let header_length = headers.len();
-    // keep track of inferred field types
-    let mut column_types: Vec<PlHashSet<DataType>> =
-        vec![PlHashSet::with_capacity(4); header_length];
-    // keep track of columns with nulls
-    let mut nulls: Vec<bool> = vec![false; header_length];
-
-    let mut rows_count = 0;
     let mut fields = Vec::with_capacity(header_length);
 
-    // needed to prevent ownership going into the iterator loop
-    let records_ref = &mut lines;
-
-    let mut end_ptr = start_ptr;
-    for mut line in records_ref
+    let lines = lines
         .take(match max_read_rows {
             Some(max_read_rows) => {
                 if max_read_rows <= (usize::MAX - skip_rows_after_header) {
@@ -359,71 +363,110 @@ pub fn infer_file_schema(
             None => usize::MAX,
         })
         .skip(skip_rows_after_header)
-    {
-        rows_count += 1;
-        // keep track so that we can determine the amount of bytes read
-        end_ptr = line.as_ptr() as usize + line.len();
+        .collect::<Vec<_>>();
+    let rows_count = lines.len();
+    // keep track so that we can determine the amount of bytes read
+    let end_ptr = lines
+        .last()
+        .map_or(start_ptr, |line| line.as_ptr() as usize + line.len());
+
+    // the possible types of the fields, per column
+    let infer_lines = |lines: &[&[u8]]| -> PolarsResult<Vec<PlHashSet<DataType>>> {
+        let mut column_types: Vec<PlHashSet<DataType>> =
+            vec![PlHashSet::with_capacity(4); header_length];
+        let mut cleaned = vec![];
+        let mut infer = |i: usize, s: &str| {
+            let mut dtype = infer_field_schema(s, try_parse_dates, datetime_formats);
+            // a formatted number, e.g. "$1,234.50", is inferred as the number it holds
+            if let (DataType::Utf8, Some(number_format)) = (&dtype, number_format) {
+                number_format.clean_field(s.as_bytes(), &mut cleaned);
+                if let Ok(cleaned) = std::str::from_utf8(&cleaned) {
+                    let number = infer_field_schema(cleaned, false, None);
+                    if matches!(number, DataType::Int64 | DataType::Float64) {
+                        dtype = number;
+                    }
+                }
+            }
+            column_types[i].insert(dtype);
+        };
 
-        if let Some(c) = comment_char {
+        for &line in lines {
             // line is a comment -> skip
-            if line[0] == c {
+            if comment_char.is_some() && line.first() == comment_char.as_ref() {
                 continue;
             }
-        }
 
-        let len = line.len();
-        if len > 1 {
-            // remove carriage return
-            let trailing_byte = line[len - 1];
-            if trailing_byte == b'\r' {
-                line = &line[..len - 1];
+            let mut line = line;
+            let len = line.len();
+            if len > 1 {
+                // remove carriage return
+                let trailing_byte = line[len - 1];
+                if trailing_byte == b'\r' {
+                    line = &line[..len - 1];
+                }
             }
-        }
-
-        let mut record = SplitFields::new(line, delimiter, quote_char, eol_char);
 
-        for i in 0..header_length {
-            if let Some((slice, needs_escaping)) = record.next() {
-                if slice.is_empty() {
-                    nulls[i] = true;
-                } else {
-                    let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                        &slice[1..(slice.len() - 1)]
-                    } else {
-                        slice
-                    };
-                    let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
-                    match &null_values {
-                        None => {
-                            column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                        }
-                        Some(NullValues::AllColumns(names)) => {
-                            if !names.iter().any(|nv| nv == s.as_ref()) {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
+            let mut record = SplitFields::new(line, delimiter, quote_char, eol_char);
+
+            for i in 0..header_length {
+                if let Some((slice, needs_escaping)) = record.next() {
+                    if !slice.is_empty() {
+                        let slice_escaped = if needs_escaping && (slice.len() >= 2) {
+                            &slice[1..(slice.len() - 1)]
+                        } else {
+                            slice
+                        };
+                        let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
+                        match &null_values {
+                            None => infer(i, &s),
+                            Some(NullValues::AllColumns(names)) => {
+                                if !names.iter().any(|nv| nv == s.as_ref()) {
+                                    infer(i, &s)
+                                }
                             }
-                        }
-                        Some(NullValues::AllColumnsSingle(name)) => {
-                            if s.as_ref() != name {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
+                            Some(NullValues::AllColumnsSingle(name)) => {
+                                if s.as_ref() != name {
+                                    infer(i, &s)
+                                }
                             }
-                        }
-                        Some(NullValues::Named(names)) => {
-                            let current_name = &headers[i];
-                            let null_name = &names.iter().find(|name| &name.0 == current_name);
-
-                            if let Some(null_name) = null_name {
-                                if null_name.1 != s.as_ref() {
-                                    column_types[i].insert(infer_field_schema(&s, try_parse_dates));
+                            Some(NullValues::Named(names)) => {
+                                let current_name = &headers[i];
+                                let null_name = &names.iter().find(|name| &name.0 == current_name);
+
+                                if let Some(null_name) = null_name {
+                                    if null_name.1 != s.as_ref() {
+                                        infer(i, &s)
+                                    }
+                                } else {
+                                    infer(i, &s)
                                 }
-                            } else {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
                             }
                         }
                     }
                 }
             }
         }
-    }
+        Ok(column_types)
+    };
+
+    // a full scan of a large file is done in parallel and the possible types are merged
+    let column_types = if max_read_rows.is_none() && rows_count > 1 << 14 {
+        let chunk_size = rows_count / POOL.current_num_threads() + 1;
+        POOL.install(|| {
+            lines
+                .par_chunks(chunk_size)
+                .map(infer_lines)
+                .try_reduce_with(|mut column_types, other| {
+                    for (types, other) in column_types.iter_mut().zip(other) {
+                        types.extend(other)
+                    }
+                    Ok(column_types)
+                })
+                .unwrap()
+        })?
+    } else {
+        infer_lines(&lines)?
+    };
 
     // build schema from inference results
     for i in 0..header_length {
@@ -503,6 +546,9 @@ pub fn infer_file_schema(
             eol_char,
             null_values,
             try_parse_dates,
+            header_normalization,
+            datetime_formats,
+            number_format,
         );
     }
 
@@ -527,7 +573,7 @@ pub fn is_compressed(bytes: &[u8]) -> bool {
 fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
@@ -600,7 +646,7 @@ fn decompress_impl<R: Read>(
 pub(crate) fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
@@ -677,7 +723,11 @@ mod test {
         let s = std::fs::read_to_string(path).unwrap();
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
-        assert!((get_file_chunks(bytes, 10, 4, b',', None, b'\n').len() as i32 - 10).abs() <= 1);
-        assert!((get_file_chunks(bytes, 8, 4, b',', None, b'\n').len() as i32 - 8).abs() <= 1);
+        assert!(
+            (get_file_chunks(bytes, 10, 4, b','.into(), None, b'\n').len() as i32 - 10).abs() <= 1
+        );
+        assert!(
+            (get_file_chunks(bytes, 8, 4, b','.into(), None, b'\n').len() as i32 - 8).abs() <= 1
+        );
     }
 }
@@ -50,9 +50,10 @@ where
         self
     }
 
-    /// Set the CSV file's column delimiter as a byte character
-    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.options.delimiter = delimiter;
+    /// Set the CSV file's column delimiter, a byte character or a [`CsvDelimiter`] of multiple
+    /// characters.
+    pub fn with_delimiter(mut self, delimiter: impl Into<CsvDelimiter>) -> Self {
+        self.options.delimiter = delimiter.into();
         self
     }
 
@@ -104,4 +105,52 @@ where
         self.options.null = null_value;
         self
     }
+
+    /// Set all options to serialize the values at once.
+    pub fn with_serialize_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Write the header of `schema`, and return a writer for the batches of a [`DataFrame`] with
+    /// that schema.
+    pub fn batched(mut self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        if self.header {
+            let names = schema
+                .iter_names()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            write_impl::write_header(&mut self.buffer, &names, &self.options)?;
+        }
+        Ok(BatchedWriter { writer: self })
+    }
+}
+
+/// Write the batches of a [`DataFrame`] to csv, see [`CsvWriter::batched`].
+pub struct BatchedWriter<W: Write> {
+    writer: CsvWriter<W>,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the csv file.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let CsvWriter {
+            buffer,
+            options,
+            batch_size,
+            ..
+        } = &mut self.writer;
+        write_impl::write(buffer, df, *batch_size, options)
+    }
+
+    /// Flush the written batches.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.buffer.flush()?;
+        Ok(())
+    }
+
+    /// Get the underlying writer, e.g. to complete an upload after [`BatchedWriter::finish`].
+    pub fn into_inner(self) -> W {
+        self.writer.buffer
+    }
 }
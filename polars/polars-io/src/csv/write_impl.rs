@@ -17,6 +17,10 @@ use polars_core::series::SeriesIter;
 use polars_core::POOL;
 use polars_utils::contention_pool::LowContentionPool;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::CsvDelimiter;
 
 fn fmt_and_escape_str(f: &mut Vec<u8>, v: &str, options: &SerializeOptions) -> std::io::Result<()> {
     if v.is_empty() {
@@ -35,7 +39,14 @@ fn fmt_and_escape_str(f: &mut Vec<u8>, v: &str, options: &SerializeOptions) -> s
             };
             return write!(f, "\"{replaced}\"");
         }
-        let surround_with_quotes = memchr2(options.delimiter, b'\n', v.as_bytes()).is_some();
+        let bytes = v.as_bytes();
+        let surround_with_quotes = match options.delimiter.as_bytes() {
+            [delimiter] => memchr2(*delimiter, b'\n', bytes).is_some(),
+            delimiter => {
+                memchr(b'\n', bytes).is_some()
+                    || bytes.windows(delimiter.len()).any(|w| w == delimiter)
+            }
+        };
 
         if surround_with_quotes {
             write!(f, "\"{v}\"")
@@ -152,6 +163,7 @@ fn write_anyvalue(
 /// Options to serialize logical types to CSV
 /// The default is to format times and dates as `chrono` crate formats them.
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SerializeOptions {
     /// used for [`DataType::Date`]
     pub date_format: Option<String>,
@@ -162,7 +174,7 @@ pub struct SerializeOptions {
     /// used for [`DataType::Float64`] and [`DataType::Float32`]
     pub float_precision: Option<usize>,
     /// used as separator/delimiter
-    pub delimiter: u8,
+    pub delimiter: CsvDelimiter,
     /// quoting character
     pub quote: u8,
     /// null value representation
@@ -176,7 +188,7 @@ impl Default for SerializeOptions {
             time_format: None,
             datetime_format: None,
             float_precision: None,
-            delimiter: b',',
+            delimiter: CsvDelimiter::default(),
             quote: b'"',
             null: String::new(),
         }
@@ -217,7 +229,7 @@ pub(crate) fn write<W: Write>(
         std::str::from_utf8(&[options.quote, options.quote]).is_ok(),
         ComputeError: "quote char results in invalid utf-8",
     );
-    let delimiter = char::from(options.delimiter);
+    let delimiter = options.delimiter.as_bytes();
 
     let formats: Option<Vec<Option<&str>>> = match &options.datetime_format {
         None => Some(
@@ -302,7 +314,7 @@ pub(crate) fn write<W: Write>(
                     }
                     let current_ptr = col as *const SeriesIter;
                     if current_ptr != last_ptr {
-                        write!(&mut write_buffer, "{delimiter}").unwrap()
+                        write_buffer.extend_from_slice(delimiter)
                     }
                 }
                 if !finished {
@@ -349,11 +361,12 @@ pub(crate) fn write_header<W: Write>(
         }
         nm.clear();
     }
-    writer.write_all(
-        escaped_names
-            .join(std::str::from_utf8(&[options.delimiter]).unwrap())
-            .as_bytes(),
-    )?;
+    for (i, name) in escaped_names.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(options.delimiter.as_bytes())?;
+        }
+        writer.write_all(name.as_bytes())?;
+    }
     writer.write_all(&[b'\n'])?;
     Ok(())
 }
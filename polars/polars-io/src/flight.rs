@@ -0,0 +1,318 @@
+//! Read from an [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) server.
+use std::future::Future;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::Arc;
+
+use arrow_flight::FlightClient;
+pub use arrow_flight::FlightDescriptor;
+use arrow_rs::record_batch::RecordBatch;
+use futures::{Stream, TryStreamExt};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use tokio::runtime::Runtime;
+use tonic::transport::{Channel, Endpoint};
+
+/// Encode the selected columns in the [`FlightDescriptor`] that is sent to the server.
+///
+/// Descriptors and tickets are opaque, so how a server accepts a projection is specific to the
+/// server. Without a projection the server sends all of its columns, and the columns that are
+/// not selected are dropped from every batch when it is received.
+pub type FlightProjection =
+    Arc<dyn Fn(&FlightDescriptor, &[String]) -> FlightDescriptor + Send + Sync>;
+
+/// The number of converted batches that may be buffered before the server is read further.
+const BATCH_BUFFER: usize = 2;
+
+/// Read the record batches of a flight into a [`DataFrame`].
+///
+/// The reader asks the server at `endpoint` for the flight of a [`FlightDescriptor`] with
+/// `GetFlightInfo` and reads its endpoints with `DoGet`. The batches are converted as they
+/// arrive and the calls are cancelled as soon as `n_rows` rows are read.
+///
+/// The calls run on a runtime of their own thread, so the reader can also be used from within a
+/// tokio runtime.
+#[must_use]
+#[derive(Clone)]
+pub struct FlightReader {
+    endpoint: String,
+    descriptor: FlightDescriptor,
+    projection: Option<FlightProjection>,
+    columns: Option<Vec<String>>,
+    n_rows: Option<usize>,
+}
+
+impl FlightReader {
+    /// Create a reader for the flight of `descriptor` of the server at `endpoint`,
+    /// e.g. `"http://localhost:50051"`.
+    pub fn new(endpoint: impl Into<String>, descriptor: FlightDescriptor) -> Self {
+        FlightReader {
+            endpoint: endpoint.into(),
+            descriptor,
+            projection: None,
+            columns: None,
+            n_rows: None,
+        }
+    }
+
+    /// Encode the selected columns in the descriptor, see [`FlightProjection`].
+    pub fn with_projection(mut self, projection: Option<FlightProjection>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Only read these columns.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Stop reading after `n_rows` rows.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// The descriptor that is sent to the server, with the projection if there is one.
+    fn descriptor(&self) -> FlightDescriptor {
+        match (&self.projection, &self.columns) {
+            (Some(projection), Some(columns)) => projection(&self.descriptor, columns),
+            _ => self.descriptor.clone(),
+        }
+    }
+
+    /// Get the schema of the flight with a `GetSchema` call, without reading any batches.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        block_on(async {
+            let mut client = FlightClient::new(connect(&self.endpoint).await?);
+            let schema = client
+                .get_schema(self.descriptor.clone())
+                .await
+                .map_err(to_compute_err)?;
+            Ok(DataFrame::try_from(RecordBatch::new_empty(Arc::new(schema)))?.schema())
+        })?
+    }
+
+    /// Read the batches on a background thread, which stops reading once the batches are
+    /// dropped.
+    pub fn batched(self) -> FlightBatches {
+        spawn_batches(move |tx| async move { self.send_batches(&tx).await })
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        let dfs = self.clone().batched().collect::<PolarsResult<Vec<_>>>()?;
+        if dfs.is_empty() {
+            let df = DataFrame::from(&self.schema()?);
+            return match self.columns {
+                Some(columns) => df.select(columns),
+                None => Ok(df),
+            };
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    async fn send_batches(&self, tx: &SyncSender<PolarsResult<DataFrame>>) -> PolarsResult<()> {
+        let channel = connect(&self.endpoint).await?;
+        let info = FlightClient::new(channel.clone())
+            .get_flight_info(self.descriptor())
+            .await
+            .map_err(to_compute_err)?;
+
+        let mut n_read = 0;
+        for endpoint in info.endpoint {
+            let ticket = endpoint
+                .ticket
+                .ok_or_else(|| polars_err!(ComputeError: "a flight endpoint has no ticket"))?;
+            // the ticket is redeemed at the server that sent it, unless a location is given
+            let channel = match endpoint.location.first() {
+                Some(location) => connect(&location.uri).await?,
+                None => channel.clone(),
+            };
+            let stream = FlightClient::new(channel)
+                .do_get(ticket)
+                .await
+                .map_err(to_compute_err)?
+                .map_err(to_compute_err);
+            let more = forward_batches(
+                stream,
+                self.columns.as_deref(),
+                self.n_rows,
+                &mut n_read,
+                tx,
+            )
+            .await?;
+            if !more {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The data frames of the batches of a flight, which are read on a background thread.
+pub struct FlightBatches {
+    rx: Receiver<PolarsResult<DataFrame>>,
+}
+
+impl Iterator for FlightBatches {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the sender is dropped once all batches are sent
+        self.rx.recv().ok()
+    }
+}
+
+fn runtime() -> PolarsResult<Runtime> {
+    Ok(tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?)
+}
+
+/// Run `fut` on a runtime of its own thread, as `block_on` panics within a tokio runtime.
+fn block_on<F>(fut: F) -> PolarsResult<F::Output>
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| Ok(runtime()?.block_on(fut)))
+            .join()
+            .map_err(|_| polars_err!(ComputeError: "the flight thread panicked"))?
+    })
+}
+
+/// Run the future of `send` on a runtime of a background thread, it sends the data frames
+/// that [`FlightBatches`] receives. An error of `send` is sent as the last item.
+fn spawn_batches<F, Fut>(send: F) -> FlightBatches
+where
+    F: FnOnce(SyncSender<PolarsResult<DataFrame>>) -> Fut + Send + 'static,
+    Fut: Future<Output = PolarsResult<()>>,
+{
+    let (tx, rx) = sync_channel(BATCH_BUFFER);
+    std::thread::spawn(move || {
+        let result = runtime().and_then(|rt| rt.block_on(send(tx.clone())));
+        if let Err(e) = result {
+            let _ = tx.send(Err(e));
+        }
+    });
+    FlightBatches { rx }
+}
+
+async fn connect(endpoint: &str) -> PolarsResult<Channel> {
+    Endpoint::from_shared(endpoint.to_string())
+        .map_err(to_compute_err)?
+        .connect()
+        .await
+        .map_err(to_compute_err)
+}
+
+fn batch_to_df(batch: RecordBatch, columns: Option<&[String]>) -> PolarsResult<DataFrame> {
+    let batch = match columns {
+        Some(columns) => {
+            let schema = batch.schema();
+            let indices = columns
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(to_compute_err)?;
+            batch.project(&indices).map_err(to_compute_err)?
+        }
+        None => batch,
+    };
+    DataFrame::try_from(batch)
+}
+
+/// Send the batches of `stream` as data frames until `n_rows` rows are read. Returns whether
+/// more batches are wanted, which is not the case once `n_rows` rows are read or the receiver
+/// is dropped.
+async fn forward_batches<S>(
+    stream: S,
+    columns: Option<&[String]>,
+    n_rows: Option<usize>,
+    n_read: &mut usize,
+    tx: &SyncSender<PolarsResult<DataFrame>>,
+) -> PolarsResult<bool>
+where
+    S: Stream<Item = PolarsResult<RecordBatch>>,
+{
+    futures::pin_mut!(stream);
+    loop {
+        let remaining = n_rows.map(|n_rows| n_rows.saturating_sub(*n_read));
+        if remaining == Some(0) {
+            return Ok(false);
+        }
+        let Some(batch) = stream.try_next().await? else {
+            return Ok(true);
+        };
+        let mut df = batch_to_df(batch, columns)?;
+        if let Some(remaining) = remaining {
+            if df.height() > remaining {
+                df = df.slice(0, remaining);
+            }
+        }
+        *n_read += df.height();
+        if tx.send(Ok(df)).is_err() {
+            return Ok(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_rs::array::{ArrayRef, Int32Array};
+
+    use super::*;
+
+    #[test]
+    fn test_forward_batches() -> PolarsResult<()> {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![4, 5, 6]));
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).map_err(to_compute_err)?;
+        let batches = move || futures::stream::iter(vec![Ok(batch.clone()), Ok(batch)]);
+
+        // read from within a runtime, where a `block_on` would panic
+        let dfs = runtime()?.block_on(async {
+            assert_eq!(block_on(async { 1 })?, 1);
+            spawn_batches(move |tx| async move {
+                let columns = vec!["b".to_string()];
+                let mut n_read = 0;
+                let more =
+                    forward_batches(batches(), Some(&columns), Some(4), &mut n_read, &tx).await?;
+                assert!(!more);
+                assert_eq!(n_read, 4);
+                Ok(())
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let df = accumulate_dataframes_vertical_unchecked(dfs);
+        assert_eq!(df.get_column_names(), &["b"]);
+        assert_eq!(
+            Vec::from(df.column("b")?.i32()?),
+            &[Some(4), Some(5), Some(6), Some(4)]
+        );
+
+        // an error is the last item
+        let out = spawn_batches(|_| async { polars_bail!(ComputeError: "lost connection") })
+            .collect::<Vec<_>>();
+        assert_eq!(out.len(), 1);
+        assert!(out[0].is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_projection_descriptor() {
+        let projection: FlightProjection = Arc::new(|descriptor, columns| {
+            let mut cmd = descriptor.cmd.to_vec();
+            cmd.extend_from_slice(format!(" columns={}", columns.join(",")).as_bytes());
+            FlightDescriptor::new_cmd(cmd)
+        });
+        let reader = FlightReader::new("http://localhost:50051", FlightDescriptor::new_cmd("t"))
+            .with_projection(Some(projection))
+            .with_columns(Some(vec!["a".into(), "b".into()]));
+        assert_eq!(&reader.descriptor().cmd[..], b"t columns=a,b");
+        let reader = reader.with_columns(None);
+        assert_eq!(&reader.descriptor().cmd[..], b"t");
+    }
+}
@@ -0,0 +1,166 @@
+//! Follow a file that is appended to, like `tail -f`.
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use polars_core::prelude::*;
+
+use crate::FollowOptions;
+
+/// The maximum number of bytes that are returned at once.
+const MAX_CHUNK_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Returns the complete lines that are appended to a file, until one of the bounds or the
+/// end-of-file marker of the [`FollowOptions`] is reached.
+///
+/// Lines are split at every end-of-line character, so a quoted field cannot span multiple
+/// lines.
+pub struct FileFollower {
+    file: File,
+    offset: u64,
+    eol_char: u8,
+    options: FollowOptions,
+    n_rows: usize,
+    finished: bool,
+}
+
+impl FileFollower {
+    /// Follow the file at `path` after its first `skip_lines` lines, e.g. the header.
+    pub fn new(
+        path: &Path,
+        eol_char: u8,
+        skip_lines: usize,
+        options: FollowOptions,
+    ) -> PolarsResult<Self> {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(&file);
+        let mut offset = 0;
+        let mut line = vec![];
+        for _ in 0..skip_lines {
+            line.clear();
+            offset += reader.read_until(eol_char, &mut line)? as u64;
+            polars_ensure!(
+                line.last() == Some(&eol_char),
+                NoData: "the followed file has less than {} lines", skip_lines
+            );
+        }
+        let finished = options.max_rows == Some(0);
+        Ok(FileFollower {
+            file,
+            offset,
+            eol_char,
+            options,
+            n_rows: 0,
+            finished,
+        })
+    }
+
+    /// Wait for complete lines to be appended and return them, or `None` once the file is
+    /// followed to the end.
+    pub fn next_lines(&mut self) -> PolarsResult<Option<Vec<u8>>> {
+        while !self.finished {
+            let mut len = self.file.metadata()?.len();
+            if let Some(max_bytes) = self.options.max_bytes {
+                len = len.min(max_bytes);
+            }
+            let available = len.saturating_sub(self.offset).min(MAX_CHUNK_BYTES);
+            if available > 0 {
+                let mut buf = vec![0; available as usize];
+                self.file.seek(SeekFrom::Start(self.offset))?;
+                self.file.read_exact(&mut buf)?;
+                // an incomplete line is read again once its end-of-line character is written
+                if let Some(end) = memchr::memrchr(self.eol_char, &buf) {
+                    buf.truncate(end + 1);
+                    self.offset += buf.len() as u64;
+                    self.apply_bounds(&mut buf);
+                    if !buf.is_empty() {
+                        return Ok(Some(buf));
+                    }
+                    continue;
+                }
+                polars_ensure!(
+                    available < MAX_CHUNK_BYTES,
+                    ComputeError: "a line of the followed file is larger than {} bytes",
+                    MAX_CHUNK_BYTES
+                );
+            }
+            if self
+                .options
+                .max_bytes
+                .map_or(false, |max_bytes| len >= max_bytes)
+            {
+                self.finished = true;
+            } else {
+                std::thread::sleep(self.options.poll_interval);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Cut the lines at the end-of-file marker or the maximum number of rows.
+    fn apply_bounds(&mut self, buf: &mut Vec<u8>) {
+        let mut start = 0;
+        let mut cut = None;
+        for end in memchr::memchr_iter(self.eol_char, buf) {
+            let line = &buf[start..end];
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if self.options.eof_marker.as_deref().map(str::as_bytes) == Some(line) {
+                cut = Some(start);
+                break;
+            }
+            self.n_rows += 1;
+            start = end + 1;
+            if self.options.max_rows == Some(self.n_rows) {
+                cut = Some(start);
+                break;
+            }
+        }
+        if let Some(cut) = cut {
+            buf.truncate(cut);
+            self.finished = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_follow_until_marker() -> PolarsResult<()> {
+        let dir = tempdir::TempDir::new("follow")?;
+        let path = dir.path().join("log.csv");
+        let mut file = File::create(&path)?;
+        file.write_all(b"a,b\n1,2\n3,")?;
+
+        let options = FollowOptions {
+            eof_marker: Some("EOF".to_string()),
+            ..Default::default()
+        };
+        let mut follower = FileFollower::new(&path, b'\n', 1, options)?;
+        assert_eq!(follower.next_lines()?.as_deref(), Some(&b"1,2\n"[..]));
+
+        file.write_all(b"4\n5,6\nEOF\n7,8\n")?;
+        assert_eq!(follower.next_lines()?.as_deref(), Some(&b"3,4\n5,6\n"[..]));
+        assert_eq!(follower.next_lines()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_follow_max_rows() -> PolarsResult<()> {
+        let dir = tempdir::TempDir::new("follow")?;
+        let path = dir.path().join("log.csv");
+        std::fs::write(&path, b"1\n2\n3\n")?;
+
+        let options = FollowOptions {
+            max_rows: Some(2),
+            ..Default::default()
+        };
+        let mut follower = FileFollower::new(&path, b'\n', 0, options)?;
+        assert_eq!(follower.next_lines()?.as_deref(), Some(&b"1\n2\n"[..]));
+        assert_eq!(follower.next_lines()?, None);
+        Ok(())
+    }
+}
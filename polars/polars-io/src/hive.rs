@@ -0,0 +1,162 @@
+//! Discovery of hive partitioned datasets: datasets of which the files are stored in
+//! directories named `key=value`, e.g. `root/year=2023/month=1/0.parquet`.
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+
+use crate::utils::percent_decode;
+
+/// The directory value that hive uses for a null partition value.
+pub const HIVE_NULL_VALUE: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// A file of a hive partitioned dataset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HiveFile {
+    pub path: PathBuf,
+    /// The `(key, value)` pairs of the partition directories, from the root down.
+    pub partitions: Vec<(String, String)>,
+}
+
+impl HiveFile {
+    /// The partition values as single-row columns of the data types in `schema`.
+    pub fn partition_columns(&self, schema: &Schema) -> PolarsResult<Vec<Series>> {
+        self.partitions
+            .iter()
+            .map(|(key, value)| {
+                let dtype = schema.try_get(key)?;
+                let s = if value == HIVE_NULL_VALUE {
+                    Series::full_null(key, 1, &DataType::Utf8)
+                } else {
+                    Series::new(key, [value.as_str()])
+                };
+                s.strict_cast(dtype)
+            })
+            .collect()
+    }
+}
+
+fn split_partition(name: &str) -> Option<(String, String)> {
+    let (key, value) = name.split_once('=')?;
+    // hive escapes special characters, e.g. `/` and `=`, in the names of partition directories
+    (!key.is_empty()).then(|| (percent_decode(key), percent_decode(value)))
+}
+
+fn is_hidden(name: &str) -> bool {
+    name.starts_with('.') || name.starts_with('_')
+}
+
+fn collect_files(
+    dir: &Path,
+    extension: &str,
+    partitions: &mut Vec<(String, String)>,
+    out: &mut Vec<HiveFile>,
+) -> PolarsResult<()> {
+    let mut paths = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    // sort for a deterministic order of the rows
+    paths.sort();
+
+    for path in paths {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if path.is_dir() {
+            if let Some((key, value)) = split_partition(name) {
+                partitions.push((key, value));
+                collect_files(&path, extension, partitions, out)?;
+                partitions.pop();
+            } else if !is_hidden(name) {
+                collect_files(&path, extension, partitions, out)?;
+            }
+        } else if !is_hidden(name) && path.extension().map_or(false, |ext| ext == extension) {
+            out.push(HiveFile {
+                path,
+                partitions: partitions.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Collect the files with the given `extension` under `root`, together with the values of
+/// the `key=value` directories they are stored in.
+///
+/// The keys and values are percent-decoded. Hidden files and directories, of which the name
+/// starts with `.` or `_`, are skipped.
+/// All files must be partitioned by the same keys.
+pub fn discover_hive_files(root: &Path, extension: &str) -> PolarsResult<Vec<HiveFile>> {
+    let mut files = vec![];
+    collect_files(root, extension, &mut vec![], &mut files)?;
+
+    if let Some(first) = files.first() {
+        let keys = |file: &HiveFile| {
+            file.partitions
+                .iter()
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>()
+        };
+        let first_keys = keys(first);
+        for file in &files[1..] {
+            polars_ensure!(
+                keys(file) == first_keys,
+                ComputeError: "hive partitions of {} don't match those of {}",
+                file.path.display(), first.path.display()
+            );
+        }
+    }
+    Ok(files)
+}
+
+/// Infer the data types of the partition columns of `files`: `Int64` if all values of a
+/// column are integers, `Float64` if they are all numbers and `Utf8` otherwise.
+pub fn infer_hive_schema(files: &[HiveFile]) -> Schema {
+    let Some(first) = files.first() else {
+        return Schema::new();
+    };
+    first
+        .partitions
+        .iter()
+        .enumerate()
+        .map(|(i, (key, _))| {
+            let values = || {
+                files
+                    .iter()
+                    .map(move |file| file.partitions[i].1.as_str())
+                    .filter(|value| *value != HIVE_NULL_VALUE)
+            };
+            let has_values = values().next().is_some();
+            let dtype = if has_values && values().all(|v| v.parse::<i64>().is_ok()) {
+                DataType::Int64
+            } else if has_values && values().all(|v| v.parse::<f64>().is_ok()) {
+                DataType::Float64
+            } else {
+                DataType::Utf8
+            };
+            Field::new(key, dtype)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_partition() {
+        assert_eq!(
+            split_partition("city=New%20York%2FNY"),
+            Some(("city".to_string(), "New York/NY".to_string()))
+        );
+        assert_eq!(
+            split_partition("a%3Db=100%"),
+            Some(("a=b".to_string(), "100%".to_string()))
+        );
+        assert_eq!(
+            split_partition("k=%zz"),
+            Some(("k".to_string(), "%zz".to_string()))
+        );
+        assert_eq!(split_partition("=1"), None);
+        assert_eq!(split_partition("dir"), None);
+    }
+}
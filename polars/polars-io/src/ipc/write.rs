@@ -109,6 +109,11 @@ impl<W: Write> BatchedWriter<W> {
         self.writer.finish()?;
         Ok(())
     }
+
+    /// Get the underlying writer, e.g. to complete an upload after [`BatchedWriter::finish`].
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
 }
 
 /// Compression codec
@@ -301,4 +306,24 @@ mod test {
         let df_read = IpcReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    fn write_and_read_ipc_metadata() -> PolarsResult<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let metadata = FieldMetadata::from([("unit".to_string(), "m/s".to_string())]);
+        let mut speed = Series::new("speed", [1.0, 2.0]);
+        speed.set_metadata(metadata.clone());
+        let mut df = DataFrame::new(vec![speed, Series::new("b", [1, 2])])?;
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf).finish()?;
+        let schema = df_read.schema();
+        assert_eq!(schema.metadata("speed"), Some(&metadata));
+        assert_eq!(schema.metadata("b"), None);
+        // a projection keeps the metadata
+        let selected = df_read.select(["speed"])?;
+        assert_eq!(selected.schema().metadata("speed"), Some(&metadata));
+        Ok(())
+    }
 }
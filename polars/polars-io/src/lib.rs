@@ -10,14 +10,25 @@ mod cloud;
 pub mod csv;
 #[cfg(feature = "parquet")]
 pub mod export;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub mod follow;
+pub mod hive;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+pub mod listing_cache;
+pub mod manifest;
 #[cfg(feature = "json")]
 pub mod ndjson_core;
 #[cfg(feature = "cloud")]
+pub use crate::cloud::download as async_download;
+#[cfg(feature = "cloud")]
 pub use crate::cloud::glob as async_glob;
+#[cfg(feature = "cloud")]
+pub use crate::cloud::CloudWriter;
 
 #[cfg(any(
     feature = "csv",
@@ -29,6 +40,7 @@ pub mod mmap;
 mod options;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+pub mod part_writer;
 #[cfg(feature = "private")]
 pub mod predicates;
 #[cfg(not(feature = "private"))]
@@ -156,7 +168,14 @@ pub(crate) fn finish_reader<R: ArrowReader>(
                 .fields
                 .iter()
                 .map(|fld| {
-                    Series::try_from((fld.name.as_str(), new_empty_array(fld.data_type.clone())))
+                    let mut s = Series::try_from((
+                        fld.name.as_str(),
+                        new_empty_array(fld.data_type.clone()),
+                    ))?;
+                    if !fld.metadata.is_empty() {
+                        s.set_metadata(fld.metadata.clone());
+                    }
+                    Ok(s)
                 })
                 .collect::<PolarsResult<_>>()?;
             DataFrame::new(empty_cols)?
@@ -174,7 +193,8 @@ pub(crate) fn finish_reader<R: ArrowReader>(
 
 /// Check if the path is a cloud url.
 pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
-    p.as_ref().starts_with("s3://")
-        || p.as_ref().starts_with("file://")
-        || p.as_ref().starts_with("gcs://")
+    const SCHEMES: [&str; 8] = [
+        "s3://", "file://", "gs://", "gcs://", "gcp://", "az://", "adl://", "abfs://",
+    ];
+    SCHEMES.iter().any(|scheme| p.as_ref().starts_with(scheme))
 }
@@ -0,0 +1,125 @@
+//! A cache of the files that match a glob pattern.
+//!
+//! Listing the keys under a prefix on cloud storage takes many requests for large datasets.
+//! Queries that scan the same pattern repeatedly can reuse the listing for a while. The cache
+//! is disabled by default, as files that are added after a pattern was listed are not seen
+//! until the listing expires or is invalidated.
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+/// The maximum number of patterns of which the listing is cached.
+const MAX_CACHED_PATTERNS: usize = 128;
+
+struct ListingCache {
+    // `None` disables the cache
+    ttl: Option<Duration>,
+    entries: PlHashMap<String, (Instant, Arc<Vec<PathBuf>>)>,
+}
+
+impl ListingCache {
+    fn get(&mut self, pattern: &str) -> Option<Arc<Vec<PathBuf>>> {
+        let ttl = self.ttl?;
+        match self.entries.get(pattern) {
+            Some((listed_at, paths)) if listed_at.elapsed() < ttl => Some(paths.clone()),
+            Some(_) => {
+                self.entries.remove(pattern);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&mut self, pattern: &str, paths: Arc<Vec<PathBuf>>) {
+        if self.ttl.is_none() {
+            return;
+        }
+        if self.entries.len() >= MAX_CACHED_PATTERNS && !self.entries.contains_key(pattern) {
+            // drop the listing that is the closest to expiring
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (listed_at, _))| *listed_at)
+                .map(|(pattern, _)| pattern.clone())
+                .unwrap();
+            self.entries.remove(&oldest);
+        }
+        self.entries
+            .insert(pattern.to_string(), (Instant::now(), paths));
+    }
+}
+
+static CACHE: Lazy<Mutex<ListingCache>> = Lazy::new(|| {
+    Mutex::new(ListingCache {
+        ttl: None,
+        entries: Default::default(),
+    })
+});
+
+/// Reuse the files that match a glob pattern for `ttl` after listing them. `None` disables
+/// the cache, which is the default.
+pub fn set_listing_cache_ttl(ttl: Option<Duration>) {
+    let mut cache = CACHE.lock().unwrap();
+    cache.ttl = ttl;
+    if ttl.is_none() {
+        cache.entries.clear();
+    }
+}
+
+/// Whether listings are cached, see [`set_listing_cache_ttl`].
+pub fn listing_cache_enabled() -> bool {
+    CACHE.lock().unwrap().ttl.is_some()
+}
+
+/// Drop the cached listing of `pattern`.
+pub fn invalidate_listing_cache(pattern: &str) {
+    CACHE.lock().unwrap().entries.remove(pattern);
+}
+
+/// Drop all cached listings.
+pub fn clear_listing_cache() {
+    CACHE.lock().unwrap().entries.clear();
+}
+
+/// Get the files that match `pattern` from the cache, or list them with `list`.
+pub fn cached_listing<F>(pattern: &str, list: F) -> PolarsResult<Arc<Vec<PathBuf>>>
+where
+    F: FnOnce() -> PolarsResult<Vec<PathBuf>>,
+{
+    if let Some(paths) = CACHE.lock().unwrap().get(pattern) {
+        return Ok(paths);
+    }
+    // don't hold the lock while listing, that may take a while
+    let paths = Arc::new(list()?);
+    CACHE.lock().unwrap().insert(pattern, paths.clone());
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_listing_cache() {
+        let mut cache = ListingCache {
+            ttl: None,
+            entries: Default::default(),
+        };
+        let paths = Arc::new(vec![PathBuf::from("a.parquet")]);
+        // disabled
+        cache.insert("*.parquet", paths.clone());
+        assert!(cache.get("*.parquet").is_none());
+
+        cache.ttl = Some(Duration::from_secs(60));
+        cache.insert("*.parquet", paths.clone());
+        assert_eq!(cache.get("*.parquet"), Some(paths.clone()));
+
+        // expired
+        cache.ttl = Some(Duration::ZERO);
+        assert!(cache.get("*.parquet").is_none());
+        assert!(cache.entries.is_empty());
+    }
+}
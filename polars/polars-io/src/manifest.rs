@@ -0,0 +1,187 @@
+//! A precomputed list of the files of a dataset.
+//!
+//! Listing a large dataset on cloud storage can dominate the latency of a query. A
+//! [`FileManifest`] lists the files up front, e.g. as written by the job that produced the
+//! dataset, so that scans don't have to glob.
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+
+/// A file of a [`FileManifest`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestEntry {
+    pub path: PathBuf,
+    /// Size of the file in bytes. Files that are known to be empty are skipped.
+    pub size: Option<u64>,
+    /// Number of rows in the file. If known, files that fall outside of `n_rows`
+    /// are not scanned at all.
+    pub num_rows: Option<usize>,
+    /// The minimum and maximum value of columns of the file: a column of length 2 with the
+    /// name of the column of the file. Files that can't match a predicate according to these
+    /// statistics are pruned without reading their footers.
+    pub statistics: Vec<Series>,
+}
+
+impl ManifestEntry {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        ManifestEntry {
+            path: path.into(),
+            size: None,
+            num_rows: None,
+            statistics: vec![],
+        }
+    }
+
+    pub fn with_size(mut self, size: Option<u64>) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_num_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.num_rows = num_rows;
+        self
+    }
+
+    pub fn with_statistics(mut self, statistics: Vec<Series>) -> Self {
+        self.statistics = statistics;
+        self
+    }
+}
+
+/// The files of a dataset, in the order in which they are scanned.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileManifest {
+    entries: Vec<ManifestEntry>,
+}
+
+impl FileManifest {
+    pub fn new(entries: Vec<ManifestEntry>) -> Self {
+        FileManifest { entries }
+    }
+
+    pub fn from_paths<I, P>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        Self::new(paths.into_iter().map(ManifestEntry::new).collect())
+    }
+
+    /// Create a manifest from a [`DataFrame`] with a `path` column and optional `size` and
+    /// `num_rows` columns, e.g. read from a manifest file. Nulls in the optional columns
+    /// mean that the value is unknown.
+    ///
+    /// The statistics of a column `x` of the files are given by a pair of `x_min` and `x_max`
+    /// columns. The statistics of a file are ignored if either of them is null.
+    pub fn try_from_df(df: &DataFrame) -> PolarsResult<Self> {
+        let paths = df.column("path")?;
+        let paths = paths.utf8().map_err(|_| {
+            polars_err!(
+                ComputeError: "the 'path' column of a manifest must be of type Utf8, got {}",
+                paths.dtype()
+            )
+        })?;
+        polars_ensure!(
+            paths.null_count() == 0,
+            ComputeError: "the 'path' column of a manifest cannot contain nulls"
+        );
+        let optional_column = |name: &str| -> PolarsResult<Option<UInt64Chunked>> {
+            match df.column(name) {
+                Ok(s) => Ok(Some(s.cast(&DataType::UInt64)?.u64()?.clone())),
+                Err(_) => Ok(None),
+            }
+        };
+        let sizes = optional_column("size")?;
+        let num_rows = optional_column("num_rows")?;
+        let statistics = df
+            .get_columns()
+            .iter()
+            .filter_map(|min| {
+                let name = min.name().strip_suffix("_min")?;
+                let max = df.column(&format!("{name}_max")).ok()?;
+                Some((name, min, max))
+            })
+            .collect::<Vec<_>>();
+        let entry_statistics = |i: usize| {
+            let is_null = |s: &Series| matches!(s.get(i), Ok(AnyValue::Null));
+            statistics
+                .iter()
+                .filter(|(_, min, max)| !is_null(min) && !is_null(max))
+                .map(|(name, min, max)| {
+                    let mut s = min.slice(i as i64, 1);
+                    s.append(&max.slice(i as i64, 1))?;
+                    s.rename(name);
+                    Ok(s)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        };
+
+        let entries = paths
+            .into_no_null_iter()
+            .enumerate()
+            .map(|(i, path)| {
+                Ok(ManifestEntry::new(path)
+                    .with_size(sizes.as_ref().and_then(|ca| ca.get(i)))
+                    .with_num_rows(
+                        num_rows
+                            .as_ref()
+                            .and_then(|ca| ca.get(i))
+                            .map(|n| n as usize),
+                    )
+                    .with_statistics(entry_statistics(i)?))
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(Self::new(entries))
+    }
+
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_manifest_from_df() -> PolarsResult<()> {
+        let df = df![
+            "path" => ["a.parquet", "b.parquet"],
+            "size" => [Some(10i64), None],
+        ]?;
+        let manifest = FileManifest::try_from_df(&df)?;
+        assert_eq!(
+            manifest.entries(),
+            &[
+                ManifestEntry::new("a.parquet").with_size(Some(10)),
+                ManifestEntry::new("b.parquet"),
+            ]
+        );
+
+        let df = df![
+            "path" => ["a.parquet", "b.parquet"],
+            "a_min" => [Some(1i64), None],
+            "a_max" => [5i64, 8],
+            "b_min" => [2i64, 3],
+        ]?;
+        let manifest = FileManifest::try_from_df(&df)?;
+        let statistics = &manifest.entries()[0].statistics;
+        assert_eq!(statistics.len(), 1);
+        assert!(statistics[0].series_equal(&Series::new("a", [1i64, 5])));
+        assert!(manifest.entries()[1].statistics.is_empty());
+
+        let df = df!["path" => [1, 2]]?;
+        assert!(FileManifest::try_from_df(&df).is_err());
+        Ok(())
+    }
+}
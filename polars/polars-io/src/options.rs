@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use polars_arrow::prelude::IdxSize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,3 +10,29 @@ pub struct RowCount {
     pub name: String,
     pub offset: IdxSize,
 }
+
+/// Options to follow a file that is appended to, like `tail -f`. The file is read until one of
+/// the bounds or the end-of-file marker is reached.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FollowOptions {
+    /// Stop after this many rows.
+    pub max_rows: Option<usize>,
+    /// Stop at this byte offset of the file.
+    pub max_bytes: Option<u64>,
+    /// Stop at a line that equals this marker. The marker itself is not read.
+    pub eof_marker: Option<String>,
+    /// How long to wait before the file is checked for new lines again.
+    pub poll_interval: Duration,
+}
+
+impl Default for FollowOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: None,
+            max_bytes: None,
+            eof_marker: None,
+            poll_interval: Duration::from_millis(100),
+        }
+    }
+}
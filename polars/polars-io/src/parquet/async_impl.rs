@@ -19,15 +19,21 @@ use polars_core::prelude::*;
 use polars_core::schema::Schema;
 
 use super::cloud::{build, CloudLocation, CloudReader};
+use super::metadata_cache::{self, CacheKey, FileMetaDataRef};
 use super::mmap;
 use super::mmap::ColumnStore;
+use super::predicates::read_this_row_group;
 use super::read_impl::FetchRowGroups;
+use crate::predicates::PhysicalIoExpr;
 
 pub struct ParquetObjectStore {
     store: Arc<Mutex<Box<dyn ObjectStore>>>,
+    uri: String,
     path: ObjectPath,
     length: Option<u64>,
-    metadata: Option<FileMetaData>,
+    /// Last modification time of the object in nanoseconds since the epoch.
+    last_modified: Option<i128>,
+    metadata: Option<FileMetaDataRef>,
 }
 
 impl ParquetObjectStore {
@@ -37,20 +43,25 @@ impl ParquetObjectStore {
 
         Ok(ParquetObjectStore {
             store,
+            uri: uri.to_string(),
             path: prefix.into(),
             length: None,
+            last_modified: None,
             metadata: None,
         })
     }
 
-    /// Initialize the length property of the object, unless it has already been fetched.
+    /// Initialize the length and modification time of the object, unless they have already been
+    /// fetched.
     async fn initialize_length(&mut self) -> PolarsResult<()> {
         if self.length.is_some() {
             return Ok(());
         }
         let path = self.path.clone();
         let locked_store = self.store.lock().await;
-        self.length = Some(locked_store.head(&path).await.map_err(to_compute_err)?.size as u64);
+        let meta = locked_store.head(&path).await.map_err(to_compute_err)?;
+        self.length = Some(meta.size as u64);
+        self.last_modified = Some(meta.last_modified.timestamp_nanos() as i128);
         Ok(())
     }
 
@@ -80,11 +91,22 @@ impl ParquetObjectStore {
             .map_err(to_compute_err)
     }
 
-    /// Fetch and memoize the metadata of the parquet file.
-    pub async fn get_metadata(&mut self) -> PolarsResult<&FileMetaData> {
+    /// Fetch and memoize the metadata of the parquet file. The metadata is shared with other
+    /// readers of the same object through the [metadata cache](metadata_cache), as long as the
+    /// object doesn't change.
+    pub async fn get_metadata(&mut self) -> PolarsResult<&FileMetaDataRef> {
         self.initialize_length().await?;
         if self.metadata.is_none() {
-            self.metadata = Some(self.fetch_metadata().await?);
+            let key = CacheKey::new(self.uri.clone(), self.last_modified, self.length.unwrap());
+            let metadata = match metadata_cache::get(&key) {
+                Some(metadata) => metadata,
+                None => {
+                    let metadata = Arc::new(self.fetch_metadata().await?);
+                    metadata_cache::insert(key, metadata.clone());
+                    metadata
+                }
+            };
+            self.metadata = Some(metadata);
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -140,8 +162,10 @@ async fn download_projection<'a: 'b, 'b>(
 
 pub(crate) struct FetchRowGroupsFromObjectStore {
     reader: ParquetObjectStore,
-    row_groups_metadata: Vec<RowGroupMetaData>,
+    metadata: FileMetaDataRef,
     projection: Vec<usize>,
+    /// Row groups of which the statistics don't match this predicate are not downloaded.
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
     logging: bool,
     schema: ArrowSchema,
 }
@@ -149,10 +173,11 @@ pub(crate) struct FetchRowGroupsFromObjectStore {
 impl FetchRowGroupsFromObjectStore {
     pub fn new(
         reader: ParquetObjectStore,
-        metadata: &FileMetaData,
+        metadata: FileMetaDataRef,
         projection: &Option<Vec<usize>>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
     ) -> PolarsResult<Self> {
-        let schema = parquet2_read::schema::infer_schema(metadata)?;
+        let schema = parquet2_read::schema::infer_schema(&metadata)?;
         let logging = verbose();
 
         let projection = projection
@@ -161,8 +186,9 @@ impl FetchRowGroupsFromObjectStore {
 
         Ok(FetchRowGroupsFromObjectStore {
             reader,
-            row_groups_metadata: metadata.row_groups.to_owned(),
+            metadata,
             projection,
+            predicate,
             logging,
             schema,
         })
@@ -171,16 +197,28 @@ impl FetchRowGroupsFromObjectStore {
 
 impl FetchRowGroups for FetchRowGroupsFromObjectStore {
     fn fetch_row_groups(&mut self, row_groups: Range<usize>) -> PolarsResult<ColumnStore> {
-        // Fetch the required row groups.
-        let row_groups = &self
-            .row_groups_metadata
-            .get(row_groups.clone())
-            .map_or_else(
-                || Err(polars_err!(
-                    ComputeError: "cannot access slice {0}..{1}", row_groups.start, row_groups.end,
-                )),
-                Ok,
-            )?;
+        polars_ensure!(
+            row_groups.end <= self.metadata.row_groups.len(),
+            ComputeError: "cannot access slice {0}..{1}", row_groups.start, row_groups.end,
+        );
+        // Fetch the required row groups, skipping those that the predicate filters out
+        // entirely. The reader skips the same row groups, so they are never accessed.
+        let row_groups = row_groups
+            .filter_map(|rg| {
+                match read_this_row_group(
+                    self.predicate.as_ref(),
+                    &self.metadata,
+                    &self.schema,
+                    rg,
+                    None,
+                ) {
+                    Ok(true) => Some(Ok(self.metadata.row_groups[rg].clone())),
+                    Ok(false) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let row_groups = &row_groups;
 
         // Package in the format required by ColumnStore.
         let downloaded =
@@ -0,0 +1,443 @@
+//! Split block bloom filters as written by parquet writers.
+//!
+//! See: <https://github.com/apache/parquet-format/blob/master/BloomFilter.md>
+use arrow::io::parquet::read::{
+    ColumnChunkMetaData, ParquetTimeUnit, PhysicalType, PrimitiveConvertedType,
+    PrimitiveLogicalType,
+};
+use polars_core::prelude::*;
+use xxhash_rust::xxh64::xxh64;
+
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+const BYTES_PER_BLOCK: usize = 32;
+
+// thrift compact protocol types
+const STOP: u8 = 0;
+const I32: u8 = 5;
+const STRUCT: u8 = 12;
+
+/// Julian day of 1970-01-01, the day count of INT96 timestamps.
+#[cfg(feature = "dtype-datetime")]
+const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+#[cfg(feature = "dtype-datetime")]
+const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// The parquet type of the values of a column chunk, the values are hashed in this
+/// type's plain encoding.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ValueType {
+    Int32,
+    Int64,
+    Date,
+    /// A time of day in an INT32 for milliseconds and in an INT64 otherwise.
+    Time(TimeUnit),
+    Timestamp(TimeUnit),
+    /// Legacy nanosecond timestamps.
+    Int96,
+    Float,
+    Double,
+    ByteArray,
+    /// E.g. decimals and booleans, which are never ruled out.
+    Unknown,
+}
+
+impl ValueType {
+    fn of(column: &ColumnChunkMetaData) -> Self {
+        use PrimitiveConvertedType as C;
+        use PrimitiveLogicalType as L;
+
+        let ty = &column.descriptor().descriptor.primitive_type;
+        let unit = |unit: &ParquetTimeUnit| match unit {
+            ParquetTimeUnit::Milliseconds => TimeUnit::Milliseconds,
+            ParquetTimeUnit::Microseconds => TimeUnit::Microseconds,
+            ParquetTimeUnit::Nanoseconds => TimeUnit::Nanoseconds,
+        };
+        if matches!(ty.logical_type, Some(L::Decimal(..)))
+            || matches!(ty.converted_type, Some(C::Decimal(..)))
+        {
+            return Self::Unknown;
+        }
+        match ty.physical_type {
+            PhysicalType::Int32 => match (&ty.logical_type, &ty.converted_type) {
+                (Some(L::Date), _) | (None, Some(C::Date)) => Self::Date,
+                (Some(L::Time { unit: u, .. }), _) => match unit(u) {
+                    TimeUnit::Milliseconds => Self::Time(TimeUnit::Milliseconds),
+                    _ => Self::Unknown,
+                },
+                (None, Some(C::TimeMillis)) => Self::Time(TimeUnit::Milliseconds),
+                _ => Self::Int32,
+            },
+            PhysicalType::Int64 => match (&ty.logical_type, &ty.converted_type) {
+                (Some(L::Time { unit: u, .. }), _) => Self::Time(unit(u)),
+                (None, Some(C::TimeMicros)) => Self::Time(TimeUnit::Microseconds),
+                (Some(L::Timestamp { unit: u, .. }), _) => Self::Timestamp(unit(u)),
+                (None, Some(C::TimestampMillis)) => Self::Timestamp(TimeUnit::Milliseconds),
+                (None, Some(C::TimestampMicros)) => Self::Timestamp(TimeUnit::Microseconds),
+                _ => Self::Int64,
+            },
+            PhysicalType::Int96 => Self::Int96,
+            PhysicalType::Float => Self::Float,
+            PhysicalType::Double => Self::Double,
+            PhysicalType::ByteArray => Self::ByteArray,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// The bloom filter of a single column chunk.
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct BloomFilter {
+    bitset: Vec<u8>,
+    value_type: ValueType,
+}
+
+impl BloomFilter {
+    /// Read the bloom filter of `column` from the bytes of the whole file.
+    ///
+    /// Returns `None` if the column chunk has no bloom filter, or if it is
+    /// not one we understand.
+    pub(super) fn read(file: &[u8], column: &ColumnChunkMetaData) -> Option<Self> {
+        let offset = column.metadata().bloom_filter_offset?;
+        let bytes = file.get(usize::try_from(offset).ok()?..)?;
+        let (num_bytes, header_len) = read_header(bytes)?;
+        let bitset = bytes.get(header_len..header_len + num_bytes)?;
+        if bitset.is_empty() || bitset.len() % BYTES_PER_BLOCK != 0 {
+            return None;
+        }
+        Some(Self {
+            bitset: bitset.to_vec(),
+            value_type: ValueType::of(column),
+        })
+    }
+
+    fn contains_hash(&self, hash: u64) -> bool {
+        let num_blocks = (self.bitset.len() / BYTES_PER_BLOCK) as u64;
+        let block = (((hash >> 32) * num_blocks) >> 32) as usize;
+        let block = &self.bitset[block * BYTES_PER_BLOCK..(block + 1) * BYTES_PER_BLOCK];
+        let key = hash as u32;
+
+        SALT.iter().enumerate().all(|(i, salt)| {
+            let word = u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap());
+            let mask = 1u32 << (key.wrapping_mul(*salt) >> 27);
+            word & mask != 0
+        })
+    }
+
+    /// Returns `false` if `value` is certainly not in the column chunk.
+    ///
+    /// Values that can't be represented exactly in the parquet type of the column
+    /// are assumed to be present.
+    pub fn may_contain(&self, value: &AnyValue) -> bool {
+        // writers hash the bits of a float, but -0.0 equals 0.0 and NaN has many bit patterns
+        match value {
+            AnyValue::Float32(v) if v.is_nan() => true,
+            AnyValue::Float64(v) if v.is_nan() => true,
+            AnyValue::Float32(v) if *v == 0.0 => {
+                self.may_contain_bits(&AnyValue::Float32(0.0))
+                    || self.may_contain_bits(&AnyValue::Float32(-0.0))
+            }
+            AnyValue::Float64(v) if *v == 0.0 => {
+                self.may_contain_bits(&AnyValue::Float64(0.0))
+                    || self.may_contain_bits(&AnyValue::Float64(-0.0))
+            }
+            _ => self.may_contain_bits(value),
+        }
+    }
+
+    fn may_contain_bits(&self, value: &AnyValue) -> bool {
+        match plain_bytes(value, self.value_type) {
+            Some(bytes) => self.contains_bytes(&bytes),
+            None => true,
+        }
+    }
+
+    fn contains_bytes(&self, bytes: &[u8]) -> bool {
+        self.contains_hash(xxh64(bytes, 0))
+    }
+}
+
+fn integer(value: &AnyValue) -> Option<i128> {
+    use AnyValue::*;
+    match value {
+        Int8(v) => Some(*v as i128),
+        Int16(v) => Some(*v as i128),
+        Int32(v) => Some(*v as i128),
+        Int64(v) => Some(*v as i128),
+        UInt8(v) => Some(*v as i128),
+        UInt16(v) => Some(*v as i128),
+        UInt32(v) => Some(*v as i128),
+        UInt64(v) => Some(*v as i128),
+        _ => None,
+    }
+}
+
+#[cfg(any(feature = "dtype-time", feature = "dtype-datetime"))]
+fn nanoseconds_per(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// Convert `v` from `from` to `to`, `None` if that loses precision or overflows.
+#[cfg(any(feature = "dtype-time", feature = "dtype-datetime"))]
+fn convert_time_unit(v: i64, from: TimeUnit, to: TimeUnit) -> Option<i64> {
+    let (from, to) = (nanoseconds_per(from), nanoseconds_per(to));
+    if from >= to {
+        v.checked_mul(from / to)
+    } else if v % (to / from) == 0 {
+        Some(v / (to / from))
+    } else {
+        None
+    }
+}
+
+/// The INT96 representation of a timestamp: the nanoseconds of the day followed by the
+/// julian day.
+#[cfg(feature = "dtype-datetime")]
+fn int96_bytes(ns: i64) -> Option<Vec<u8>> {
+    let day = u32::try_from(ns.div_euclid(NANOSECONDS_PER_DAY) + JULIAN_DAY_OF_EPOCH).ok()?;
+    let mut bytes = (ns.rem_euclid(NANOSECONDS_PER_DAY) as u64)
+        .to_le_bytes()
+        .to_vec();
+    bytes.extend_from_slice(&day.to_le_bytes());
+    Some(bytes)
+}
+
+/// The plain encoding of `value` in the parquet type `ty`, which is what is hashed into
+/// the bloom filter. `None` if `value` has no exact representation in `ty`.
+fn plain_bytes(value: &AnyValue, ty: ValueType) -> Option<Vec<u8>> {
+    use AnyValue::*;
+    let bytes = match (ty, value) {
+        // unsigned integers are stored with the bits of the signed physical type
+        (ValueType::Int32, v) => {
+            let v = integer(v)?;
+            i32::try_from(v)
+                .ok()
+                .or_else(|| u32::try_from(v).ok().map(|v| v as i32))?
+                .to_le_bytes()
+                .to_vec()
+        }
+        (ValueType::Int64, v) => {
+            let v = integer(v)?;
+            i64::try_from(v)
+                .ok()
+                .or_else(|| u64::try_from(v).ok().map(|v| v as i64))?
+                .to_le_bytes()
+                .to_vec()
+        }
+        #[cfg(feature = "dtype-date")]
+        (ValueType::Date, Date(v)) => v.to_le_bytes().to_vec(),
+        #[cfg(feature = "dtype-time")]
+        (ValueType::Time(unit), Time(ns)) => {
+            let v = convert_time_unit(*ns, TimeUnit::Nanoseconds, unit)?;
+            match unit {
+                TimeUnit::Milliseconds => i32::try_from(v).ok()?.to_le_bytes().to_vec(),
+                _ => v.to_le_bytes().to_vec(),
+            }
+        }
+        #[cfg(feature = "dtype-datetime")]
+        (ValueType::Timestamp(unit), Datetime(v, tu, _)) => {
+            convert_time_unit(*v, *tu, unit)?.to_le_bytes().to_vec()
+        }
+        #[cfg(feature = "dtype-datetime")]
+        (ValueType::Int96, Datetime(v, tu, _)) => {
+            int96_bytes(convert_time_unit(*v, *tu, TimeUnit::Nanoseconds)?)?
+        }
+        (ValueType::Float, Float32(v)) => v.to_le_bytes().to_vec(),
+        (ValueType::Float, Float64(v)) if *v as f32 as f64 == *v => {
+            (*v as f32).to_le_bytes().to_vec()
+        }
+        (ValueType::Double, Float32(v)) => (*v as f64).to_le_bytes().to_vec(),
+        (ValueType::Double, Float64(v)) => v.to_le_bytes().to_vec(),
+        (ValueType::ByteArray, Utf8(v)) => v.as_bytes().to_vec(),
+        (ValueType::ByteArray, Utf8Owned(v)) => v.as_bytes().to_vec(),
+        (ValueType::ByteArray, Binary(v)) => v.to_vec(),
+        (ValueType::ByteArray, BinaryOwned(v)) => v.clone(),
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+/// Parse the thrift `BloomFilterHeader` at the start of `bytes`.
+///
+/// Returns the number of bytes of the bitset and the length of the header.
+/// Only the split block algorithm with xxhash and no compression exists,
+/// so the other fields don't have to be inspected.
+fn read_header(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut pos = 0;
+    let mut num_bytes = None;
+    let mut field_id = 0i16;
+    loop {
+        let header = *bytes.get(pos)?;
+        pos += 1;
+        let field_type = header & 0x0f;
+        if field_type == STOP {
+            break;
+        }
+        let delta = (header >> 4) as i16;
+        if delta == 0 {
+            // long form field header: the field id follows as zigzag varint
+            field_id = zigzag(read_varint(bytes, &mut pos)?) as i16;
+        } else {
+            field_id += delta;
+        }
+        match (field_id, field_type) {
+            (1, I32) => num_bytes = usize::try_from(zigzag(read_varint(bytes, &mut pos)?)).ok(),
+            (_, STRUCT) => skip_struct(bytes, &mut pos, 0)?,
+            _ => return None,
+        }
+    }
+    Some((num_bytes?, pos))
+}
+
+/// Skip a nested struct that only consists of (possibly empty) structs.
+fn skip_struct(bytes: &[u8], pos: &mut usize, depth: usize) -> Option<()> {
+    if depth > 8 {
+        return None;
+    }
+    loop {
+        let header = *bytes.get(*pos)?;
+        *pos += 1;
+        match header & 0x0f {
+            STOP => return Some(()),
+            STRUCT => {
+                if header >> 4 == 0 {
+                    read_varint(bytes, pos)?;
+                }
+                skip_struct(bytes, pos, depth + 1)?
+            }
+            _ => return None,
+        }
+    }
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut out = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*pos)?;
+        *pos += 1;
+        out |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Some(out);
+        }
+    }
+    None
+}
+
+fn zigzag(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_bloom_filter_header() {
+        // num_bytes: 32, algorithm: BLOCK, hash: XXHASH, compression: UNCOMPRESSED
+        let header = [
+            0x15, 0x40, 0x1c, 0x1c, 0x00, 0x00, 0x1c, 0x1c, 0x00, 0x00, 0x1c, 0x1c, 0x00, 0x00,
+            0x00,
+        ];
+        assert_eq!(read_header(&header), Some((32, header.len())));
+    }
+
+    /// A single block with the bits of the hash of `bytes` set.
+    fn bloom_filter_of(bytes: &[u8], value_type: ValueType) -> BloomFilter {
+        let key = xxh64(bytes, 0) as u32;
+        let mut bitset = vec![0u8; BYTES_PER_BLOCK];
+        for (i, salt) in SALT.iter().enumerate() {
+            let mut word = u32::from_le_bytes(bitset[i * 4..i * 4 + 4].try_into().unwrap());
+            word |= 1u32 << (key.wrapping_mul(*salt) >> 27);
+            bitset[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        BloomFilter { bitset, value_type }
+    }
+
+    #[test]
+    fn test_bloom_filter_contains() {
+        let bf = bloom_filter_of(&42i32.to_le_bytes(), ValueType::Int32);
+        assert!(bf.may_contain(&AnyValue::Int32(42)));
+        assert!(bf.may_contain(&AnyValue::Int64(42)));
+        assert!(!bf.may_contain(&AnyValue::Int32(43)));
+        assert!(bf.may_contain(&AnyValue::Boolean(true)));
+        // a string can't be encoded as an INT32
+        assert!(bf.may_contain(&AnyValue::Utf8("x")));
+
+        // unsigned integers are stored with the bits of the signed type
+        let bf = bloom_filter_of(&(u32::MAX as i32).to_le_bytes(), ValueType::Int32);
+        assert!(bf.may_contain(&AnyValue::UInt32(u32::MAX)));
+        assert!(!bf.may_contain(&AnyValue::UInt32(1)));
+        let bf = bloom_filter_of(&42i64.to_le_bytes(), ValueType::Int64);
+        assert!(bf.may_contain(&AnyValue::UInt32(42)));
+        assert!(!bf.may_contain(&AnyValue::UInt32(43)));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-time")]
+    fn test_bloom_filter_time() {
+        // TIME_MICROS: 00:00:01.5
+        let bf = bloom_filter_of(
+            &1_500_000i64.to_le_bytes(),
+            ValueType::Time(TimeUnit::Microseconds),
+        );
+        assert!(bf.may_contain(&AnyValue::Time(1_500_000_000)));
+        assert!(!bf.may_contain(&AnyValue::Time(1_600_000_000)));
+        // sub-microsecond times aren't in the column, but can't be encoded either
+        assert!(bf.may_contain(&AnyValue::Time(1_500_000_001)));
+
+        // TIME_MILLIS is stored in an INT32
+        let bf = bloom_filter_of(
+            &1_500i32.to_le_bytes(),
+            ValueType::Time(TimeUnit::Milliseconds),
+        );
+        assert!(bf.may_contain(&AnyValue::Time(1_500_000_000)));
+        assert!(!bf.may_contain(&AnyValue::Time(1_600_000_000)));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_bloom_filter_timestamp() {
+        // 1970-01-02 00:00:01
+        let ns = NANOSECONDS_PER_DAY + 1_000_000_000;
+        let mut int96 = 1_000_000_000u64.to_le_bytes().to_vec();
+        int96.extend_from_slice(&((JULIAN_DAY_OF_EPOCH + 1) as u32).to_le_bytes());
+        let bf = bloom_filter_of(&int96, ValueType::Int96);
+        assert!(bf.may_contain(&AnyValue::Datetime(ns, TimeUnit::Nanoseconds, &None)));
+        assert!(bf.may_contain(&AnyValue::Datetime(
+            ns / 1000,
+            TimeUnit::Microseconds,
+            &None
+        )));
+        assert!(!bf.may_contain(&AnyValue::Datetime(ns + 1, TimeUnit::Nanoseconds, &None)));
+
+        let bf = bloom_filter_of(
+            &(ns / 1_000_000).to_le_bytes(),
+            ValueType::Timestamp(TimeUnit::Milliseconds),
+        );
+        assert!(bf.may_contain(&AnyValue::Datetime(ns, TimeUnit::Nanoseconds, &None)));
+        assert!(!bf.may_contain(&AnyValue::Datetime(
+            ns + 1_000_000,
+            TimeUnit::Nanoseconds,
+            &None
+        )));
+        assert!(bf.may_contain(&AnyValue::Datetime(ns + 1, TimeUnit::Nanoseconds, &None)));
+    }
+
+    #[test]
+    fn test_bloom_filter_float_canonicalization() {
+        let bf = bloom_filter_of(&(-0.0f64).to_le_bytes(), ValueType::Double);
+        assert!(bf.may_contain(&AnyValue::Float64(0.0)));
+        assert!(bf.may_contain(&AnyValue::Float64(-0.0)));
+        assert!(!bf.may_contain(&AnyValue::Float64(1.0)));
+        // NaN payloads differ between writers
+        let bf = bloom_filter_of(&1.0f32.to_le_bytes(), ValueType::Float);
+        assert!(bf.may_contain(&AnyValue::Float32(f32::NAN)));
+        assert!(bf.may_contain(&AnyValue::Float64(1.0)));
+        assert!(!bf.may_contain(&AnyValue::Float64(2.0)));
+    }
+}
@@ -0,0 +1,188 @@
+//! An in-process cache of the metadata of parquet files.
+//!
+//! Scanning the same files repeatedly, e.g. from a dashboard, reads their footers at plan time and
+//! again when the scan is executed. The metadata is cached per file, keyed by the path together
+//! with the modification time and size of the file, so that a file that was rewritten is read again.
+//! Files that are changed in place without changing their modification time or size can be
+//! dropped from the cache with [`invalidate_metadata_cache`].
+use std::fs::File;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+
+use arrow::io::parquet::read;
+use arrow::io::parquet::write::FileMetaData;
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+/// The metadata of a parquet file, which is shared by the cache and the readers of the file.
+pub type FileMetaDataRef = Arc<FileMetaData>;
+
+/// The default number of files of which the metadata is cached.
+pub const DEFAULT_METADATA_CACHE_CAPACITY: usize = 256;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct CacheKey {
+    path: String,
+    /// Modification time in nanoseconds since the epoch.
+    modified: Option<i128>,
+    size: u64,
+}
+
+impl CacheKey {
+    pub(super) fn new(path: String, modified: Option<i128>, size: u64) -> Self {
+        CacheKey {
+            path,
+            modified,
+            size,
+        }
+    }
+
+    fn local(path: &Path, file_metadata: &std::fs::Metadata) -> Self {
+        let modified = file_metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_nanos() as i128);
+        Self::new(
+            path.to_string_lossy().into_owned(),
+            modified,
+            file_metadata.len(),
+        )
+    }
+}
+
+struct MetadataCache {
+    capacity: usize,
+    // the metadata and the tick at which it was last used
+    entries: PlHashMap<CacheKey, (FileMetaDataRef, u64)>,
+    tick: u64,
+}
+
+impl MetadataCache {
+    fn get(&mut self, key: &CacheKey) -> Option<FileMetaDataRef> {
+        self.tick += 1;
+        let tick = self.tick;
+        self.entries.get_mut(key).map(|(metadata, last_used)| {
+            *last_used = tick;
+            metadata.clone()
+        })
+    }
+
+    fn insert(&mut self, key: CacheKey, metadata: FileMetaDataRef) {
+        if self.capacity == 0 {
+            return;
+        }
+        // an older version of the file can't be read anymore
+        self.entries.retain(|k, _| k.path != key.path);
+        self.tick += 1;
+        self.entries.insert(key, (metadata, self.tick));
+        self.evict();
+    }
+
+    /// Drop the least recently used entries until the cache fits its capacity.
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone())
+                .unwrap();
+            self.entries.remove(&lru);
+        }
+    }
+}
+
+static CACHE: Lazy<Mutex<MetadataCache>> = Lazy::new(|| {
+    Mutex::new(MetadataCache {
+        capacity: DEFAULT_METADATA_CACHE_CAPACITY,
+        entries: Default::default(),
+        tick: 0,
+    })
+});
+
+/// Set the maximum number of files of which the metadata is cached. A capacity of `0` disables
+/// the cache.
+pub fn set_metadata_cache_capacity(capacity: usize) {
+    let mut cache = CACHE.lock().unwrap();
+    cache.capacity = capacity;
+    cache.evict();
+}
+
+/// Drop the cached metadata of the file at `path`, which is either a local path or a cloud url.
+pub fn invalidate_metadata_cache(path: &str) {
+    CACHE.lock().unwrap().entries.retain(|k, _| k.path != path);
+}
+
+/// Drop all cached metadata.
+pub fn clear_metadata_cache() {
+    CACHE.lock().unwrap().entries.clear();
+}
+
+pub(super) fn get(key: &CacheKey) -> Option<FileMetaDataRef> {
+    CACHE.lock().unwrap().get(key)
+}
+
+pub(super) fn insert(key: CacheKey, metadata: FileMetaDataRef) {
+    CACHE.lock().unwrap().insert(key, metadata)
+}
+
+/// Read the metadata of the local parquet file at `path`, or get it from the cache if the file
+/// didn't change since it was cached.
+pub fn read_metadata_cached(path: &Path) -> PolarsResult<FileMetaDataRef> {
+    let key = CacheKey::local(path, &std::fs::metadata(path)?);
+    if let Some(metadata) = get(&key) {
+        return Ok(metadata);
+    }
+    let metadata = Arc::new(read::read_metadata(&mut File::open(path)?)?);
+    insert(key, metadata.clone());
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+    use crate::prelude::*;
+
+    fn key(path: &str, modified: i128) -> CacheKey {
+        CacheKey::new(path.to_string(), Some(modified), 10)
+    }
+
+    #[test]
+    fn test_metadata_cache() -> PolarsResult<()> {
+        let dir = tempdir::TempDir::new("metadata_cache")?;
+        let path = dir.path().join("a.parquet");
+        let mut df = df!["a" => [1, 2, 3]]?;
+        ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+        let metadata = read_metadata_cached(&path)?;
+        assert_eq!(metadata.num_rows, 3);
+        // a hit shares the cached metadata
+        assert!(Arc::ptr_eq(&metadata, &read_metadata_cached(&path)?));
+        invalidate_metadata_cache(&path.to_string_lossy());
+        assert_eq!(read_metadata_cached(&path)?.num_rows, 3);
+
+        let metadata = Arc::new(read::read_metadata(&mut File::open(&path)?)?);
+        let mut cache = MetadataCache {
+            capacity: 2,
+            entries: Default::default(),
+            tick: 0,
+        };
+        cache.insert(key("a", 0), metadata.clone());
+        cache.insert(key("b", 0), metadata.clone());
+        assert!(cache.get(&key("a", 0)).is_some());
+        // `b` is the least recently used
+        cache.insert(key("c", 0), metadata.clone());
+        assert!(cache.get(&key("b", 0)).is_none());
+        assert!(cache.get(&key("a", 0)).is_some());
+
+        // a changed file replaces the old entry
+        cache.insert(key("a", 1), metadata);
+        assert!(cache.get(&key("a", 0)).is_none());
+        assert!(cache.get(&key("a", 1)).is_some());
+        assert_eq!(cache.entries.len(), 2);
+        Ok(())
+    }
+}
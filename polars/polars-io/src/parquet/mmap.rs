@@ -1,7 +1,8 @@
 use arrow::datatypes::Field;
+use arrow::io::parquet::read::indexes::FilteredPage;
 use arrow::io::parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    PageReader,
+    IndexedPageReader, PageReader,
 };
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
@@ -25,6 +26,17 @@ pub enum ColumnStore<'a> {
     Fetched(PlHashMap<u64, Vec<u8>>),
 }
 
+impl ColumnStore<'_> {
+    /// The bytes of the whole file, if it is available locally.
+    pub(super) fn local_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ColumnStore::Local(bytes) => Some(bytes),
+            #[cfg(feature = "async")]
+            ColumnStore::Fetched(_) => None,
+        }
+    }
+}
+
 /// For local files memory maps all columns that are part of the parquet field `field_name`.
 /// For cloud files the relevant memory regions should have been prefetched.
 pub(super) fn mmap_columns<'a>(
@@ -60,30 +72,59 @@ fn _mmap_single_column<'a>(
 
 // similar to arrow2 serializer, except this accepts a slice instead of a vec.
 // this allows us to memory map
+//
+// If `pages` are given, only those pages of every column are read. They are located in the
+// bytes of the whole file, and `num_rows` must be the number of rows they select.
 pub(super) fn to_deserializer<'a>(
     columns: Vec<(&ColumnChunkMetaData, &'a [u8])>,
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+    pages: Option<(&'a [u8], Vec<Vec<FilteredPage>>)>,
 ) -> ArrowResult<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
-    let (columns, types): (Vec<_>, Vec<_>) = columns
-        .into_iter()
-        .map(|(column_meta, chunk)| {
-            let pages = PageReader::new(
-                std::io::Cursor::new(chunk),
-                column_meta,
-                std::sync::Arc::new(|_, _| true),
-                vec![],
-                usize::MAX,
-            );
-            (
-                BasicDecompressor::new(pages, vec![]),
-                &column_meta.descriptor().descriptor.primitive_type,
-            )
-        })
-        .unzip();
+    let (columns, types): (Vec<_>, Vec<_>) = match pages {
+        Some((file, pages)) => columns
+            .into_iter()
+            .zip(pages)
+            .map(|((column_meta, _), pages)| {
+                let pages = IndexedPageReader::new(
+                    std::io::Cursor::new(file),
+                    column_meta,
+                    pages,
+                    vec![],
+                    vec![],
+                );
+                (
+                    BasicDecompressor::new(
+                        Box::new(pages) as Box<dyn Iterator<Item = _> + Send + Sync + 'a>,
+                        vec![],
+                    ),
+                    &column_meta.descriptor().descriptor.primitive_type,
+                )
+            })
+            .unzip(),
+        None => columns
+            .into_iter()
+            .map(|(column_meta, chunk)| {
+                let pages = PageReader::new(
+                    std::io::Cursor::new(chunk),
+                    column_meta,
+                    std::sync::Arc::new(|_, _| true),
+                    vec![],
+                    usize::MAX,
+                );
+                (
+                    BasicDecompressor::new(
+                        Box::new(pages) as Box<dyn Iterator<Item = _> + Send + Sync + 'a>,
+                        vec![],
+                    ),
+                    &column_meta.descriptor().descriptor.primitive_type,
+                )
+            })
+            .unzip(),
+    };
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size), num_rows)
 }
@@ -16,12 +16,18 @@
 //!
 #[cfg(feature = "cloud")]
 pub(super) mod async_impl;
+mod bloom_filter;
+pub mod metadata_cache;
 pub(super) mod mmap;
 pub mod predicates;
 mod read;
 mod read_impl;
 mod write;
 
+pub use metadata_cache::{
+    clear_metadata_cache, invalidate_metadata_cache, read_metadata_cached,
+    set_metadata_cache_capacity, FileMetaDataRef,
+};
 pub use read::*;
 pub use write::{BrotliLevel, GzipLevel, ZstdLevel, *};
 
@@ -109,4 +115,43 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.frame_equal(&expected);
     }
+
+    #[test]
+    fn test_write_parquet_column_options() -> PolarsResult<()> {
+        let values = ["polars-io", "polars-core", "polars-lazy"];
+        let mut df = df!(
+            "a" => (0..1000).map(|i| values[i % 3]).collect::<Vec<_>>(),
+            "b" => (0..1000).collect::<Vec<i32>>()
+        )?;
+        let write = |df: &mut DataFrame, dictionary: bool| -> PolarsResult<Vec<u8>> {
+            let mut buf = vec![];
+            let row_group_bytes = df.estimated_size() / 4;
+            ParquetWriter::new(&mut buf)
+                .with_compression(ParquetCompression::Uncompressed)
+                .with_column_statistics("b", true)
+                .with_column_dictionary_encoding("a", dictionary)
+                .with_row_group_size_bytes(Some(row_group_bytes))
+                .finish(df)?;
+            Ok(buf)
+        };
+        let plain = write(&mut df, false)?;
+        let dictionary = write(&mut df, true)?;
+        assert!(dictionary.len() < plain.len());
+
+        let metadata = arrow::io::parquet::read::read_metadata(&mut Cursor::new(&dictionary))?;
+        assert_eq!(metadata.row_groups.len(), 4);
+        for rg in &metadata.row_groups {
+            assert!(rg.columns()[0].statistics().is_none());
+            assert!(rg.columns()[1].statistics().is_some());
+        }
+
+        let read = ParquetReader::new(Cursor::new(dictionary)).finish()?;
+        assert!(read.frame_equal(&df));
+
+        let out = ParquetWriter::new(vec![])
+            .with_column_statistics("c", true)
+            .finish(&mut df);
+        assert!(matches!(out, Err(PolarsError::ColumnNotFound(_))));
+        Ok(())
+    }
 }
@@ -1,9 +1,16 @@
+use std::io::Cursor;
+
+use arrow::array::new_null_array;
 use arrow::compute::concatenate::concatenate;
+use arrow::io::parquet::read::indexes::{
+    has_indexes, read_columns_indexes, FieldPageStatistics, Interval,
+};
 use arrow::io::parquet::read::statistics::{deserialize, Statistics};
-use arrow::io::parquet::read::RowGroupMetaData;
+use arrow::io::parquet::read::{get_field_columns, read_pages_locations, RowGroupMetaData};
 use polars_core::prelude::*;
 
-use crate::predicates::PhysicalIoExpr;
+use super::bloom_filter::BloomFilter;
+use crate::predicates::{PhysicalIoExpr, StatsEvaluator};
 use crate::ArrowResult;
 
 /// The statistics for a column in a Parquet file
@@ -11,8 +18,10 @@ use crate::ArrowResult;
 /// - max value
 /// - min value
 /// - null_count
+///
+/// and optionally a bloom filter.
 #[cfg_attr(debug_assertions, derive(Debug))]
-pub struct ColumnStats(Statistics, Field);
+pub struct ColumnStats(Statistics, Field, Option<BloomFilter>);
 
 impl ColumnStats {
     pub fn dtype(&self) -> DataType {
@@ -84,6 +93,15 @@ impl ColumnStats {
         }
     }
 
+    /// Returns `false` if the bloom filter proves that none of the values of `s`
+    /// are in the column. Without a bloom filter this is always `true`.
+    pub fn may_contain(&self, s: &Series) -> bool {
+        match &self.2 {
+            Some(bf) => s.iter().any(|av| bf.may_contain(&av)),
+            None => true,
+        }
+    }
+
     #[cfg(feature = "dtype-binary")]
     fn use_min_max(dtype: DataType) -> bool {
         dtype.is_numeric() || matches!(dtype, DataType::Utf8) || matches!(dtype, DataType::Binary)
@@ -102,6 +120,30 @@ pub struct BatchStats {
 }
 
 impl BatchStats {
+    /// Create the statistics of a file from columns of length 2 holding the minimum and the
+    /// maximum value of the file's column of that name, e.g. as listed in a manifest.
+    pub fn from_min_max(columns: &[Series]) -> PolarsResult<Self> {
+        let mut schema = Schema::with_capacity(columns.len());
+        let mut stats = Vec::with_capacity(columns.len());
+        for s in columns {
+            polars_ensure!(
+                s.len() == 2,
+                ComputeError: "expected the minimum and maximum of column '{}', got {} values",
+                s.name(), s.len()
+            );
+            let s = s.rechunk();
+            let st = Statistics {
+                null_count: new_null_array(ArrowDataType::Int64, 1),
+                distinct_count: new_null_array(ArrowDataType::UInt64, 1),
+                min_value: s.slice(0, 1).to_arrow(0),
+                max_value: s.slice(1, 1).to_arrow(0),
+            };
+            schema.with_column(s.name().into(), s.dtype().clone());
+            stats.push(ColumnStats(st, s.field().into_owned(), None));
+        }
+        Ok(BatchStats { schema, stats })
+    }
+
     pub fn get_stats(&self, column: &str) -> polars_core::error::PolarsResult<&ColumnStats> {
         self.schema.try_index_of(column).map(|i| &self.stats[i])
     }
@@ -128,7 +170,7 @@ pub(crate) fn collect_statistics(
             Some(rg) => deserialize(fld, &md[rg..rg + 1])?,
         };
         schema.with_column((&fld.name).into(), (&fld.data_type).into());
-        stats.push(ColumnStats(st, fld.into()));
+        stats.push(ColumnStats(st, fld.into(), None));
     }
 
     Ok(if stats.is_empty() {
@@ -138,24 +180,195 @@ pub(crate) fn collect_statistics(
     })
 }
 
+/// Evaluate the predicate on the stats. A parquet file may not have statistics of
+/// all columns, in that case we must read.
+fn should_read(pred: &dyn StatsEvaluator, stats: &BatchStats) -> PolarsResult<bool> {
+    match pred.should_read(stats) {
+        Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+        should_read => should_read,
+    }
+}
+
+/// Determine if a file with the statistics `stats` should be read.
+pub fn read_this_file(predicate: &dyn PhysicalIoExpr, stats: &BatchStats) -> PolarsResult<bool> {
+    match predicate.as_stats_evaluator() {
+        Some(pred) => should_read(pred, stats),
+        None => Ok(true),
+    }
+}
+
+/// Load the bloom filters of the `live_columns` of row group `md`.
+fn add_bloom_filters(
+    stats: &mut BatchStats,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    bytes: &[u8],
+    live_columns: &[Arc<str>],
+) {
+    for (fld, column_stats) in schema.fields.iter().zip(stats.stats.iter_mut()) {
+        if !live_columns.iter().any(|name| name.as_ref() == fld.name) {
+            continue;
+        }
+        // nested types are spread over multiple column chunks
+        if let [column] = get_field_columns(md.columns(), &fld.name)[..] {
+            column_stats.2 = BloomFilter::read(bytes, column);
+        }
+    }
+}
+
+/// The rows of a row group that have to be read.
+pub(super) enum RowGroupSelection {
+    /// No row can match the predicate, the row group is skipped.
+    Skip,
+    All,
+    /// Only these rows can match the predicate, the pages that don't hold any of them are
+    /// skipped.
+    Rows(Vec<Interval>),
+}
+
+/// The rows that are in both `a` and `b`, which are sorted and don't overlap.
+fn intersect_intervals(a: &[Interval], b: &[Interval]) -> Vec<Interval> {
+    let mut out = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let a_end = a[i].start + a[i].length;
+        let b_end = b[j].start + b[j].length;
+        let start = a[i].start.max(b[j].start);
+        let end = a_end.min(b_end);
+        if start < end {
+            out.push(Interval::new(start, end - start));
+        }
+        if a_end < b_end {
+            i += 1
+        } else {
+            j += 1
+        }
+    }
+    out
+}
+
+/// Use the page index to determine the rows of a row group that should be read. For every
+/// column in `live_columns` we evaluate the predicate with that column's statistics narrowed
+/// to a single page. If that is false, no row of that page can match.
+fn select_by_page_index(
+    pred: &dyn StatsEvaluator,
+    stats: &mut BatchStats,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    bytes: &[u8],
+    live_columns: &[Arc<str>],
+) -> PolarsResult<RowGroupSelection> {
+    if !has_indexes(md) {
+        return Ok(RowGroupSelection::All);
+    }
+    let num_rows = md.num_rows();
+    let indexes = read_columns_indexes(&mut Cursor::new(bytes), md.columns(), &schema.fields)?;
+
+    let mut selected = vec![Interval::new(0, num_rows)];
+    for (i, (fld, index)) in schema.fields.iter().zip(indexes).enumerate() {
+        if !live_columns.iter().any(|name| name.as_ref() == fld.name) {
+            continue;
+        }
+        let FieldPageStatistics::Single(index) = index else {
+            continue;
+        };
+        let [column] = get_field_columns(md.columns(), &fld.name)[..] else {
+            continue;
+        };
+        let locations = read_pages_locations(&mut Cursor::new(bytes), std::slice::from_ref(column))
+            .map_err(arrow::error::Error::from)?;
+        let [locations] = &locations[..] else {
+            continue;
+        };
+
+        let mut column_selected = vec![];
+        for (page, location) in locations.iter().enumerate() {
+            let page_stats = Statistics {
+                null_count: index.null_count.clone().sliced(page, 1).boxed(),
+                distinct_count: new_null_array(ArrowDataType::UInt64, 1),
+                min_value: index.min.sliced(page, 1),
+                max_value: index.max.sliced(page, 1),
+            };
+            let rg_stats = std::mem::replace(&mut stats.stats[i].0, page_stats);
+            let page_read = should_read(pred, stats);
+            stats.stats[i].0 = rg_stats;
+            if page_read? {
+                let start = location.first_row_index as usize;
+                let end = locations
+                    .get(page + 1)
+                    .map_or(num_rows, |next| next.first_row_index as usize);
+                column_selected.push(Interval::new(start, end - start));
+            }
+        }
+        selected = intersect_intervals(&selected, &column_selected);
+    }
+
+    Ok(match &selected[..] {
+        [] => RowGroupSelection::Skip,
+        [rows] if rows.length == num_rows => RowGroupSelection::All,
+        _ => RowGroupSelection::Rows(selected),
+    })
+}
+
+/// Determine if row group `rg` should be read, using the statistics in the file metadata.
 pub(super) fn read_this_row_group(
     predicate: Option<&Arc<dyn PhysicalIoExpr>>,
     file_metadata: &arrow::io::parquet::read::FileMetaData,
     schema: &ArrowSchema,
     rg: usize,
+    bytes: Option<&[u8]>,
 ) -> PolarsResult<bool> {
+    let selection = select_row_group(predicate, file_metadata, schema, rg, bytes)?;
+    Ok(!matches!(selection, RowGroupSelection::Skip))
+}
+
+/// Determine which rows of row group `rg` should be read, using the statistics in the file
+/// metadata.
+///
+/// If the `bytes` of the file are given, the bloom filters and the page index of the
+/// columns the predicate depends on are used as well.
+pub(super) fn select_row_group(
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    file_metadata: &arrow::io::parquet::read::FileMetaData,
+    schema: &ArrowSchema,
+    rg: usize,
+    bytes: Option<&[u8]>,
+) -> PolarsResult<RowGroupSelection> {
     if let Some(pred) = &predicate {
+        let live_columns = pred.live_variables();
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(&file_metadata.row_groups, schema, Some(rg))? {
-                let should_read = pred.should_read(&stats);
-                // a parquet file may not have statistics of all columns
-                if matches!(should_read, Ok(false)) {
-                    return Ok(false);
-                } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
-                    let _ = should_read?;
+            if let Some(mut stats) =
+                collect_statistics(&file_metadata.row_groups, schema, Some(rg))?
+            {
+                let md = &file_metadata.row_groups[rg];
+                if let (Some(bytes), Some(live_columns)) = (bytes, &live_columns) {
+                    add_bloom_filters(&mut stats, md, schema, bytes, live_columns);
+                }
+                if !should_read(pred, &stats)? {
+                    return Ok(RowGroupSelection::Skip);
+                }
+                if let (Some(bytes), Some(live_columns)) = (bytes, &live_columns) {
+                    return select_by_page_index(pred, &mut stats, md, schema, bytes, live_columns);
                 }
             }
         }
     }
-    Ok(true)
+    Ok(RowGroupSelection::All)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_intersect_intervals() {
+        let a = [Interval::new(0, 10), Interval::new(20, 10)];
+        let b = [Interval::new(5, 20), Interval::new(28, 5)];
+        let out = intersect_intervals(&a, &b)
+            .into_iter()
+            .map(|i| (i.start, i.length))
+            .collect::<Vec<_>>();
+        assert_eq!(out, [(5, 5), (20, 5), (28, 2)]);
+        assert!(intersect_intervals(&a, &[Interval::new(10, 10)]).is_empty());
+    }
 }
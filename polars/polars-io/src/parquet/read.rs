@@ -2,10 +2,13 @@ use std::io::{Read, Seek};
 use std::sync::Arc;
 
 use arrow::io::parquet::read;
-use arrow::io::parquet::write::FileMetaData;
 #[cfg(feature = "cloud")]
 use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+#[cfg(feature = "cloud")]
+use polars_core::utils::accumulate_dataframes_vertical;
+#[cfg(feature = "cloud")]
+use polars_core::POOL;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -47,7 +50,7 @@ pub struct ParquetReader<R: Read + Seek> {
     parallel: ParallelStrategy,
     row_count: Option<RowCount>,
     low_memory: bool,
-    metadata: Option<FileMetaData>,
+    metadata: Option<FileMetaDataRef>,
     use_statistics: bool,
 }
 
@@ -60,7 +63,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         projection: Option<&[usize]>,
     ) -> PolarsResult<DataFrame> {
         // this path takes predicates and parallelism into account
-        let metadata = read::read_metadata(&mut self.reader)?;
+        let metadata = self.take_metadata()?;
         let schema = read::schema::infer_schema(&metadata)?;
 
         let rechunk = self.rechunk;
@@ -141,17 +144,33 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         Ok(metadata.num_rows)
     }
 
-    fn get_metadata(&mut self) -> PolarsResult<&FileMetaData> {
+    /// Use metadata that was already read, e.g. by [`read_metadata_cached`], instead of reading
+    /// it from the file.
+    ///
+    /// [`read_metadata_cached`]: crate::parquet::read_metadata_cached
+    pub fn with_metadata(mut self, metadata: FileMetaDataRef) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    fn get_metadata(&mut self) -> PolarsResult<&FileMetaDataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(read::read_metadata(&mut self.reader)?);
+            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
+
+    fn take_metadata(&mut self) -> PolarsResult<FileMetaDataRef> {
+        match self.metadata.take() {
+            Some(metadata) => Ok(metadata),
+            None => Ok(Arc::new(read::read_metadata(&mut self.reader)?)),
+        }
+    }
 }
 
 impl<R: MmapBytesReader + 'static> ParquetReader<R> {
     pub fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
-        let metadata = read::read_metadata(&mut self.reader)?;
+        let metadata = self.take_metadata()?;
 
         let row_group_fetcher = Box::new(FetchRowGroupsFromMmapReader::new(Box::new(self.reader))?);
         BatchedParquetReader::new(
@@ -162,6 +181,7 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             self.row_count,
             chunk_size,
             self.use_statistics,
+            None,
         )
     }
 }
@@ -189,7 +209,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
-        let metadata = read::read_metadata(&mut self.reader)?;
+        let metadata = self.take_metadata()?;
         let schema = read::schema::infer_schema(&metadata)?;
 
         if let Some(cols) = &self.columns {
@@ -227,6 +247,7 @@ pub struct ParquetAsyncReader {
     row_count: Option<RowCount>,
     low_memory: bool,
     use_statistics: bool,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 #[cfg(feature = "cloud")]
@@ -243,6 +264,7 @@ impl ParquetAsyncReader {
             row_count: None,
             low_memory: false,
             use_statistics: true,
+            predicate: None,
         })
     }
 
@@ -297,13 +319,21 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Filter the rows by this predicate. If statistics are used, the row groups that
+    /// can't match the predicate are not downloaded.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
     #[tokio::main(flavor = "current_thread")]
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
-        let metadata = self.reader.get_metadata().await?.to_owned();
+        let metadata = self.reader.get_metadata().await?.clone();
         let row_group_fetcher = Box::new(FetchRowGroupsFromObjectStore::new(
             self.reader,
-            &metadata,
+            metadata.clone(),
             &self.projection,
+            self.predicate.clone().filter(|_| self.use_statistics),
         )?);
         BatchedParquetReader::new(
             row_group_fetcher,
@@ -313,6 +343,29 @@ impl ParquetAsyncReader {
             self.row_count,
             chunk_size,
             self.use_statistics,
+            self.predicate,
         )
     }
+
+    /// Read the whole file. Only the projected columns of the row groups that pass the
+    /// statistics of the predicate are downloaded.
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        let rechunk = self.rechunk;
+        // only download as many row groups at once as can be decoded in parallel
+        let n_row_groups = POOL.current_num_threads();
+        let mut reader = self.batched(usize::MAX)?;
+
+        let mut dfs = vec![];
+        while let Some(batch) = reader.next_batches(n_row_groups)? {
+            dfs.extend(batch);
+        }
+        if dfs.is_empty() {
+            return Ok(reader.empty_df());
+        }
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if rechunk {
+            df.rechunk();
+        }
+        Ok(df)
+    }
 }
@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use arrow::array::new_empty_array;
 use arrow::io::parquet::read;
+use arrow::io::parquet::read::indexes::{read_filtered_pages, Interval};
 use arrow::io::parquet::read::{ArrayIter, FileMetaData, RowGroupMetaData};
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
@@ -15,13 +16,15 @@ use rayon::prelude::*;
 use super::mmap::ColumnStore;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::parquet::mmap::mmap_columns;
-use crate::parquet::predicates::read_this_row_group;
-use crate::parquet::{mmap, ParallelStrategy};
+use crate::parquet::predicates::{select_row_group, RowGroupSelection};
+use crate::parquet::{mmap, FileMetaDataRef, ParallelStrategy};
 use crate::predicates::{apply_predicate, arrow_schema_to_empty_df, PhysicalIoExpr};
 use crate::prelude::utils::get_reader_bytes;
 use crate::utils::apply_projection;
 use crate::RowCount;
 
+/// Deserialize column `column_i` of row group `md`. If `rows` are given, only the pages that
+/// hold those rows are read, and only those rows are returned.
 fn column_idx_to_series(
     column_i: usize,
     md: &RowGroupMetaData,
@@ -29,6 +32,7 @@ fn column_idx_to_series(
     schema: &ArrowSchema,
     store: &mmap::ColumnStore,
     chunk_size: usize,
+    rows: Option<&[Interval]>,
 ) -> PolarsResult<Series> {
     let mut field = schema.fields[column_i].clone();
 
@@ -44,7 +48,30 @@ fn column_idx_to_series(
     }
 
     let columns = mmap_columns(store, md.columns(), &field.name);
-    let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
+    if let (Some(rows), Some(file)) = (rows, store.local_bytes()) {
+        let mut pages = read_filtered_pages(
+            &mut std::io::Cursor::new(file),
+            md,
+            std::slice::from_ref(&schema.fields[column_i]),
+            |_| rows.to_vec(),
+        )?;
+        let num_rows = rows.iter().map(|rows| rows.length).sum();
+        let iter = mmap::to_deserializer(
+            columns,
+            field.clone(),
+            num_rows,
+            Some(chunk_size),
+            Some((file, pages.pop().unwrap())),
+        )?;
+        return array_iter_to_series(iter, &field, None);
+    }
+    let iter = mmap::to_deserializer(
+        columns,
+        field.clone(),
+        remaining_rows,
+        Some(chunk_size),
+        None,
+    )?;
 
     if remaining_rows < md.num_rows() {
         array_iter_to_series(iter, &field, Some(remaining_rows))
@@ -77,12 +104,16 @@ pub(super) fn array_iter_to_series(
             out
         }
     };
-    if chunks.is_empty() {
+    let mut s = if chunks.is_empty() {
         let arr = new_empty_array(field.data_type.clone());
-        Series::try_from((field.name.as_str(), arr))
+        Series::try_from((field.name.as_str(), arr))?
     } else {
-        Series::try_from((field.name.as_str(), chunks))
+        Series::try_from((field.name.as_str(), chunks))?
+    };
+    if !field.metadata.is_empty() {
+        s.set_metadata(field.metadata.clone());
     }
+    Ok(s)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -107,10 +138,30 @@ fn rg_to_dfs(
         let md = &file_metadata.row_groups[rg];
         let current_row_count = md.num_rows() as IdxSize;
 
-        if use_statistics && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg)? {
-            *previous_row_count += current_row_count;
-            continue;
-        }
+        let selection = if use_statistics {
+            select_row_group(
+                predicate.as_ref(),
+                file_metadata,
+                schema,
+                rg,
+                store.local_bytes(),
+            )?
+        } else {
+            RowGroupSelection::All
+        };
+        let rows = match selection {
+            RowGroupSelection::Skip => {
+                *previous_row_count += current_row_count;
+                continue;
+            }
+            // the row count and the limit refer to all rows of the row group
+            RowGroupSelection::Rows(rows)
+                if row_count.is_none() && *remaining_rows >= md.num_rows() =>
+            {
+                Some(rows)
+            }
+            _ => None,
+        };
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
         {
@@ -130,6 +181,7 @@ fn rg_to_dfs(
                             schema,
                             store,
                             chunk_size,
+                            rows.as_deref(),
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()
@@ -138,7 +190,15 @@ fn rg_to_dfs(
             projection
                 .iter()
                 .map(|column_i| {
-                    column_idx_to_series(*column_i, md, *remaining_rows, schema, store, chunk_size)
+                    column_idx_to_series(
+                        *column_i,
+                        md,
+                        *remaining_rows,
+                        schema,
+                        store,
+                        chunk_size,
+                        rows.as_deref(),
+                    )
                 })
                 .collect::<PolarsResult<Vec<_>>>()?
         };
@@ -198,12 +258,30 @@ fn rg_to_dfs_par(
     let dfs = row_groups
         .into_par_iter()
         .map(|(rg_idx, md, local_limit, row_count_start)| {
-            if local_limit == 0
-                || use_statistics
-                    && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg_idx)?
-            {
+            if local_limit == 0 {
                 return Ok(None);
             }
+            let selection = if use_statistics {
+                select_row_group(
+                    predicate.as_ref(),
+                    file_metadata,
+                    schema,
+                    rg_idx,
+                    store.local_bytes(),
+                )?
+            } else {
+                RowGroupSelection::All
+            };
+            let rows = match selection {
+                RowGroupSelection::Skip => return Ok(None),
+                // the row count and the limit refer to all rows of the row group
+                RowGroupSelection::Rows(rows)
+                    if row_count.is_none() && local_limit >= md.num_rows() =>
+                {
+                    Some(rows)
+                }
+                _ => None,
+            };
             // test we don't read the parquet file if this env var is set
             #[cfg(debug_assertions)]
             {
@@ -214,7 +292,15 @@ fn rg_to_dfs_par(
             let columns = projection
                 .iter()
                 .map(|column_i| {
-                    column_idx_to_series(*column_i, md, local_limit, schema, store, chunk_size)
+                    column_idx_to_series(
+                        *column_i,
+                        md,
+                        local_limit,
+                        schema,
+                        store,
+                        chunk_size,
+                        rows.as_deref(),
+                    )
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -238,7 +324,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut limit: usize,
     projection: Option<&[usize]>,
     schema: &ArrowSchema,
-    metadata: Option<FileMetaData>,
+    metadata: Option<FileMetaDataRef>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     mut parallel: ParallelStrategy,
     row_count: Option<RowCount>,
@@ -246,7 +332,7 @@ pub fn read_parquet<R: MmapBytesReader>(
 ) -> PolarsResult<DataFrame> {
     let file_metadata = metadata
         .map(Ok)
-        .unwrap_or_else(|| read::read_metadata(&mut reader))?;
+        .unwrap_or_else(|| read::read_metadata(&mut reader).map(Arc::new))?;
     let row_group_len = file_metadata.row_groups.len();
 
     let projection = projection
@@ -350,7 +436,7 @@ pub struct BatchedParquetReader {
     limit: usize,
     projection: Vec<usize>,
     schema: ArrowSchema,
-    metadata: FileMetaData,
+    metadata: FileMetaDataRef,
     row_count: Option<RowCount>,
     rows_read: IdxSize,
     row_group_offset: usize,
@@ -359,17 +445,20 @@ pub struct BatchedParquetReader {
     parallel: ParallelStrategy,
     chunk_size: usize,
     use_statistics: bool,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 impl BatchedParquetReader {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         row_group_fetcher: Box<dyn FetchRowGroups>,
-        metadata: FileMetaData,
+        metadata: FileMetaDataRef,
         limit: usize,
         projection: Option<Vec<usize>>,
         row_count: Option<RowCount>,
         chunk_size: usize,
         use_statistics: bool,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
     ) -> PolarsResult<Self> {
         let schema = read::schema::infer_schema(&metadata)?;
         let n_row_groups = metadata.row_groups.len();
@@ -397,12 +486,22 @@ impl BatchedParquetReader {
             parallel,
             chunk_size,
             use_statistics,
+            predicate,
         })
     }
 
+    /// An empty `DataFrame` with the projected schema of the file.
+    pub fn empty_df(&self) -> DataFrame {
+        arrow_schema_to_empty_df(&apply_projection(&self.schema, &self.projection))
+    }
+
     pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
-        // fill up fifo stack
-        if self.row_group_offset <= self.n_row_groups && self.chunks_fifo.len() < n {
+        // fill up fifo stack, unless the row limit is reached: then the remaining row
+        // groups don't have to be fetched at all
+        if self.limit > 0
+            && self.row_group_offset <= self.n_row_groups
+            && self.chunks_fifo.len() < n
+        {
             let row_group_start = self.row_group_offset;
             let row_group_end = std::cmp::min(self.row_group_offset + n, self.n_row_groups);
             let store = self
@@ -418,7 +517,7 @@ impl BatchedParquetReader {
                         &mut self.limit,
                         &self.metadata,
                         &self.schema,
-                        None,
+                        self.predicate.clone(),
                         self.row_count.clone(),
                         ParallelStrategy::Columns,
                         &self.projection,
@@ -436,7 +535,7 @@ impl BatchedParquetReader {
                         &mut self.limit,
                         &self.metadata,
                         &self.schema,
-                        None,
+                        self.predicate.clone(),
                         self.row_count.clone(),
                         &self.projection,
                         self.use_statistics,
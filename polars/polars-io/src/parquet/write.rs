@@ -2,7 +2,8 @@ use std::io::Write;
 
 use arrow::array::Array;
 use arrow::chunk::Chunk;
-use arrow::datatypes::{DataType as ArrowDataType, PhysicalType};
+use arrow::compute::cast::{cast, CastOptions};
+use arrow::datatypes::{DataType as ArrowDataType, IntegerType, PhysicalType};
 use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read::ParquetError;
 use arrow::io::parquet::write::{self, DynIter, DynStreamingIterator, Encoding, FileWriter, *};
@@ -94,8 +95,16 @@ pub struct ParquetWriter<W> {
     compression: CompressionOptions,
     /// Compute and write column statistics.
     statistics: bool,
+    /// Overrides `statistics` for the given columns.
+    column_statistics: PlHashMap<String, bool>,
+    /// Dictionary encode the `Utf8` and `Binary` columns.
+    dictionary: bool,
+    /// Overrides `dictionary` for the given columns.
+    column_dictionary: PlHashMap<String, bool>,
     /// If `None` will be all written to a single row group.
     row_group_size: Option<usize>,
+    /// If `None` the row groups are not limited by their size in bytes.
+    row_group_size_bytes: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_pagesize_limit: Option<usize>,
     /// Serialize columns in parallel
@@ -115,7 +124,11 @@ where
             writer,
             compression: CompressionOptions::Zstd(None),
             statistics: false,
+            column_statistics: Default::default(),
+            dictionary: false,
+            column_dictionary: Default::default(),
             row_group_size: None,
+            row_group_size_bytes: None,
             data_pagesize_limit: None,
             parallel: true,
         }
@@ -136,6 +149,29 @@ where
         self
     }
 
+    /// Compute and write the statistics of `column` or not, regardless of
+    /// [`with_statistics`](Self::with_statistics).
+    pub fn with_column_statistics(mut self, column: &str, statistics: bool) -> Self {
+        self.column_statistics
+            .insert(column.to_string(), statistics);
+        self
+    }
+
+    /// Dictionary encode the `Utf8` and `Binary` columns. This makes the file smaller if
+    /// they have few distinct values. `Categorical` columns are always dictionary encoded.
+    pub fn with_dictionary_encoding(mut self, dictionary: bool) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+
+    /// Dictionary encode `column` or not, regardless of
+    /// [`with_dictionary_encoding`](Self::with_dictionary_encoding).
+    pub fn with_column_dictionary_encoding(mut self, column: &str, dictionary: bool) -> Self {
+        self.column_dictionary
+            .insert(column.to_string(), dictionary);
+        self
+    }
+
     /// Set the row group size (in number of rows) during writing. This can reduce memory pressure and improve
     /// writing performance.
     pub fn with_row_group_size(mut self, size: Option<usize>) -> Self {
@@ -143,6 +179,13 @@ where
         self
     }
 
+    /// Set the target size of the row groups in bytes, as estimated from the in-memory size of
+    /// the `DataFrame`. If a row group size in rows is set as well, the smallest of both is used.
+    pub fn with_row_group_size_bytes(mut self, size: Option<usize>) -> Self {
+        self.row_group_size_bytes = size;
+        self
+    }
+
     /// Sets the maximum bytes size of a data page. If `None` will be 1024^2 bytes.
     pub fn with_data_pagesize_limit(mut self, limit: Option<usize>) -> Self {
         self.data_pagesize_limit = limit;
@@ -164,30 +207,66 @@ where
         }
     }
 
+    /// Resolve a per-column setting to a value for every column of `schema`.
+    fn per_column(
+        schema: &Schema,
+        default: bool,
+        overrides: &PlHashMap<String, bool>,
+    ) -> PolarsResult<Vec<bool>> {
+        for name in overrides.keys() {
+            polars_ensure!(schema.contains(name), ColumnNotFound: "{}", name);
+        }
+        Ok(schema
+            .iter_names()
+            .map(|name| overrides.get(name.as_str()).copied().unwrap_or(default))
+            .collect())
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let statistics = Self::per_column(schema, self.statistics, &self.column_statistics)?;
+        let dictionary = Self::per_column(schema, self.dictionary, &self.column_dictionary)?;
+
         let fields = schema.to_arrow().fields;
         let schema = ArrowSchema::from(fields);
 
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let encodings = get_encodings(&schema, &dictionary);
         let options = self.materialize_options();
-        let writer = FileWriter::try_new(self.writer, schema, options)?;
+        // the file level option only determines if statistics are written at all
+        let file_options = WriteOptions {
+            write_statistics: statistics.iter().any(|s| *s),
+            ..options
+        };
+        let writer = FileWriter::try_new(self.writer, schema, file_options)?;
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
+            statistics,
             options,
             parallel: self.parallel,
         })
     }
 
+    /// The number of rows of the row groups of `df`, if it is limited.
+    fn rows_per_row_group(&self, df: &DataFrame) -> Option<usize> {
+        let from_bytes = self.row_group_size_bytes.map(|bytes| {
+            let row_size = df.estimated_size() as f64 / df.height().max(1) as f64;
+            std::cmp::max((bytes as f64 / row_size) as usize, 1)
+        });
+        match (self.row_group_size, from_bytes) {
+            (Some(rows), Some(from_bytes)) => Some(std::cmp::min(rows, from_bytes)),
+            (rows, from_bytes) => rows.or(from_bytes),
+        }
+    }
+
     /// Write the given DataFrame in the the writer `W`. Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
         // ensures all chunks are aligned.
         df.rechunk();
 
-        if let Some(n) = self.row_group_size {
+        if let Some(n) = self.rows_per_row_group(df) {
             let n_splits = df.height() / n;
             if n_splits > 0 {
                 *df = accumulate_dataframes_vertical_unchecked(split_df(df, n_splits)?);
@@ -204,6 +283,7 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    statistics: &'a [bool],
     options: WriteOptions,
     parallel: bool,
 ) -> impl Iterator<Item = Result<RowGroupIter<'a, ArrowError>, ArrowError>> + 'a {
@@ -211,19 +291,31 @@ fn prepare_rg_iter<'a>(
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let row_group = create_serializer(
+                batch,
+                parquet_schema.fields(),
+                encodings,
+                statistics,
+                options,
+                parallel,
+            );
 
             Some(row_group)
         }
     })
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
+fn get_encodings(schema: &ArrowSchema, dictionary: &[bool]) -> Vec<Vec<Encoding>> {
     schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .zip(dictionary)
+        .map(|(f, dictionary)| match f.data_type {
+            ArrowDataType::LargeUtf8 | ArrowDataType::LargeBinary if *dictionary => {
+                vec![Encoding::RleDictionary]
+            }
+            _ => transverse(&f.data_type, encoding_map),
+        })
         .collect()
 }
 
@@ -240,6 +332,8 @@ pub struct BatchedWriter<W: Write> {
     writer: FileWriter<W>,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Vec<Encoding>>,
+    /// Whether the statistics of every column are written.
+    statistics: Vec<bool>,
     options: WriteOptions,
     parallel: bool,
 }
@@ -254,6 +348,7 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.statistics,
             self.options,
             self.parallel,
         );
@@ -268,16 +363,47 @@ impl<W: Write> BatchedWriter<W> {
         let size = self.writer.end(None)?;
         Ok(size)
     }
+
+    /// Get the underlying writer, e.g. to complete an upload after [`BatchedWriter::finish`].
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+}
+
+/// Dictionary encoded columns that aren't dictionary arrays are converted to one.
+fn to_dictionary_if_encoded(array: &ArrayRef, encoding: &[Encoding]) -> ArrayRef {
+    match array.data_type() {
+        ArrowDataType::LargeUtf8 | ArrowDataType::LargeBinary
+            if encoding == [Encoding::RleDictionary] =>
+        {
+            let dtype = ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(array.data_type().clone()),
+                false,
+            );
+            cast(array.as_ref(), &dtype, CastOptions::default()).unwrap()
+        }
+        _ => array.clone(),
+    }
 }
 
 fn create_serializer<'a>(
     batch: Chunk<Box<dyn Array>>,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    statistics: &[bool],
     options: WriteOptions,
     parallel: bool,
 ) -> Result<RowGroupIter<'a, ArrowError>, ArrowError> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), statistics): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &bool,
+    )| {
+        let options = WriteOptions {
+            write_statistics: *statistics,
+            ..options
+        };
+        let array = to_dictionary_if_encoded(array, encoding);
         let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
 
         encoded_columns
@@ -311,6 +437,7 @@ fn create_serializer<'a>(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(statistics)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -320,6 +447,7 @@ fn create_serializer<'a>(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(statistics)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
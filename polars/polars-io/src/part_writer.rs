@@ -0,0 +1,296 @@
+//! A [`Write`] that hands the written bytes over in parts.
+//!
+//! Every writer in this crate accepts any [`Write`], none of them need to
+//! seek. [`PartWriter`] can be given to them to stream the output to a sink
+//! that takes the bytes in parts, e.g. a multipart upload to an object store.
+//!
+//! With one of the `decompress` features, [`GzipPartWriter`] compresses the
+//! bytes before they are split into parts. With the `parquet` feature,
+//! [`write_parquet_parts`] writes a `DataFrame` one row group at a time, so
+//! that only a single encoded row group is held in memory.
+//!
+//! # Example
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::part_writer::PartWriter;
+//! use polars_io::SerWriter;
+//! # #[cfg(feature = "ipc")]
+//! use polars_io::ipc::IpcWriter;
+//!
+//! # #[cfg(feature = "ipc")]
+//! fn upload(df: &mut DataFrame) -> PolarsResult<()> {
+//!     let mut parts = vec![];
+//!     let mut writer = PartWriter::new(5 * 1024 * 1024, |part_number, bytes: Vec<u8>| {
+//!         // upload `bytes` as part `part_number` here
+//!         parts.push((part_number, bytes.len()));
+//!         Ok(())
+//!     });
+//!     IpcWriter::new(&mut writer).finish(df)?;
+//!     writer.finish()?;
+//!     Ok(())
+//! }
+//! ```
+use std::io::{self, Write};
+
+#[cfg(feature = "parquet")]
+use polars_core::prelude::*;
+
+#[cfg(feature = "parquet")]
+use crate::parquet::ParquetWriter;
+
+/// Collects the written bytes in parts of exactly `part_size` bytes and
+/// calls `on_part` with the number (starting at 0) and the bytes of every
+/// part. A write that is larger than `part_size` is split over multiple
+/// parts.
+///
+/// The last part may be smaller and is only handed over by
+/// [`PartWriter::finish`]. Bytes that were not handed over when the writer
+/// is dropped are lost. [`Write::flush`] doesn't create a part, as the parts
+/// of a multipart upload (except for the last) have a minimum size.
+pub struct PartWriter<F>
+where
+    F: FnMut(usize, Vec<u8>) -> io::Result<()>,
+{
+    part_size: usize,
+    buf: Vec<u8>,
+    n_parts: usize,
+    on_part: F,
+}
+
+impl<F> PartWriter<F>
+where
+    F: FnMut(usize, Vec<u8>) -> io::Result<()>,
+{
+    /// Create a new writer that hands over parts of `part_size` bytes.
+    pub fn new(part_size: usize, on_part: F) -> Self {
+        let part_size = std::cmp::max(part_size, 1);
+        PartWriter {
+            part_size,
+            buf: Vec::with_capacity(part_size),
+            n_parts: 0,
+            on_part,
+        }
+    }
+
+    fn emit(&mut self) -> io::Result<()> {
+        let part = std::mem::replace(&mut self.buf, Vec::with_capacity(self.part_size));
+        (self.on_part)(self.n_parts, part)?;
+        self.n_parts += 1;
+        Ok(())
+    }
+
+    /// Hand over the remaining bytes as the last part. Returns the total
+    /// number of parts.
+    ///
+    /// If nothing was written, a single empty part is handed over so that
+    /// the output always consists of at least one part.
+    pub fn finish(mut self) -> io::Result<usize> {
+        if !self.buf.is_empty() || self.n_parts == 0 {
+            self.emit()?;
+        }
+        Ok(self.n_parts)
+    }
+}
+
+impl<F> Write for PartWriter<F>
+where
+    F: FnMut(usize, Vec<u8>) -> io::Result<()>,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut remaining = buf;
+        while !remaining.is_empty() {
+            let n = std::cmp::min(self.part_size - self.buf.len(), remaining.len());
+            self.buf.extend_from_slice(&remaining[..n]);
+            remaining = &remaining[n..];
+            if self.buf.len() == self.part_size {
+                self.emit()?;
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The number of bytes that [`GzipPartWriter`] buffers before they are
+/// compressed.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+const COMPRESSION_BUFFER_SIZE: usize = 1 << 16;
+
+/// A [`PartWriter`] that gzip compresses the written bytes before they are
+/// split into parts.
+///
+/// The writes are buffered, so that writers that write a few bytes at a time
+/// (e.g. a csv writer with a small batch size) don't call the encoder for
+/// every write.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub struct GzipPartWriter<F>
+where
+    F: FnMut(usize, Vec<u8>) -> io::Result<()>,
+{
+    inner: io::BufWriter<flate2::write::GzEncoder<PartWriter<F>>>,
+}
+
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+impl<F> GzipPartWriter<F>
+where
+    F: FnMut(usize, Vec<u8>) -> io::Result<()>,
+{
+    /// Create a new writer that hands over compressed parts of `part_size`
+    /// bytes. `level` is the compression level, from 0 (none) to 9 (best).
+    pub fn new(part_size: usize, level: u32, on_part: F) -> Self {
+        let encoder = flate2::write::GzEncoder::new(
+            PartWriter::new(part_size, on_part),
+            flate2::Compression::new(std::cmp::min(level, 9)),
+        );
+        GzipPartWriter {
+            inner: io::BufWriter::with_capacity(COMPRESSION_BUFFER_SIZE, encoder),
+        }
+    }
+
+    /// Compress the buffered bytes, write the gzip trailer and hand over the
+    /// last part. Returns the total number of parts.
+    pub fn finish(self) -> io::Result<usize> {
+        let encoder = self.inner.into_inner().map_err(|e| e.into_error())?;
+        encoder.finish()?.finish()
+    }
+}
+
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+impl<F> Write for GzipPartWriter<F>
+where
+    F: FnMut(usize, Vec<u8>) -> io::Result<()>,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Write `df` as parquet to `writer`, `row_group_size` rows at a time.
+///
+/// [`ParquetWriter::finish`] rechunks and encodes the whole `DataFrame`
+/// before its bytes are written. Here only the rows of a single row group are
+/// made contiguous and encoded, so that at most one row group is staged in
+/// memory before it is handed over to e.g. a [`PartWriter`]. Returns the
+/// total size of the file.
+#[cfg(feature = "parquet")]
+pub fn write_parquet_parts<W: Write>(
+    writer: ParquetWriter<W>,
+    df: &DataFrame,
+    row_group_size: usize,
+) -> PolarsResult<u64> {
+    let row_group_size = std::cmp::max(row_group_size, 1);
+    let mut batched = writer.batched(&df.schema())?;
+    let mut offset = 0;
+    while offset < df.height() {
+        let mut row_group = df.slice(offset as i64, row_group_size);
+        // a single chunk is encoded as a single row group
+        row_group.as_single_chunk_par();
+        batched.write_batch(&row_group)?;
+        offset += row_group_size;
+    }
+    batched.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_writer() -> io::Result<()> {
+        let mut parts = vec![];
+        let mut writer = PartWriter::new(4, |i, part| {
+            parts.push((i, part));
+            Ok(())
+        });
+        writer.write_all(b"abc")?;
+        writer.write_all(b"defghij")?;
+        writer.flush()?;
+        writer.write_all(b"k")?;
+        assert_eq!(writer.finish()?, 3);
+        assert_eq!(
+            parts,
+            &[
+                (0, b"abcd".to_vec()),
+                (1, b"efgh".to_vec()),
+                (2, b"ijk".to_vec())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn test_part_writer_csv() -> polars_core::prelude::PolarsResult<()> {
+        use crate::prelude::*;
+
+        let mut df = create_df();
+        let mut expected = vec![];
+        CsvWriter::new(&mut expected).finish(&mut df)?;
+
+        let mut out = vec![];
+        let mut writer = PartWriter::new(8, |_, part| {
+            assert!(part.len() == 8 || out.len() + part.len() == expected.len());
+            out.extend_from_slice(&part);
+            Ok(())
+        });
+        CsvWriter::new(&mut writer)
+            .with_batch_size(1)
+            .finish(&mut df)?;
+        assert!(writer.finish()? > 1);
+        assert_eq!(out, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn test_gzip_part_writer() -> io::Result<()> {
+        use std::io::Read;
+
+        let data = b"abcdefghij".repeat(100);
+        let mut out = vec![];
+        let mut writer = GzipPartWriter::new(16, 6, |_, part| {
+            out.extend_from_slice(&part);
+            Ok(())
+        });
+        for byte in &data {
+            writer.write_all(std::slice::from_ref(byte))?;
+        }
+        assert!(writer.finish()? > 1);
+
+        let mut decoded = vec![];
+        flate2::read::GzDecoder::new(&out[..]).read_to_end(&mut decoded)?;
+        assert_eq!(decoded, data);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_write_parquet_parts() -> PolarsResult<()> {
+        use std::io::Cursor;
+
+        use crate::prelude::*;
+
+        let df = create_df();
+        let mut out = vec![];
+        let mut writer = PartWriter::new(64, |_, part| {
+            out.extend_from_slice(&part);
+            Ok(())
+        });
+        write_parquet_parts(ParquetWriter::new(&mut writer), &df, 2)?;
+        assert!(writer.finish()? > 1);
+
+        let metadata = arrow::io::parquet::read::read_metadata(&mut Cursor::new(&out))?;
+        assert_eq!(metadata.row_groups.len(), 3);
+        let read = ParquetReader::new(Cursor::new(out)).finish()?;
+        assert!(read.frame_equal(&df));
+        Ok(())
+    }
+}
@@ -5,6 +5,12 @@ pub trait PhysicalIoExpr: Send + Sync {
     /// as a predicate mask
     fn evaluate(&self, df: &DataFrame) -> PolarsResult<Series>;
 
+    /// Get the names of the columns this predicate depends on,
+    /// `None` if they are unknown.
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
+
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
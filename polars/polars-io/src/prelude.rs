@@ -3,6 +3,8 @@ use polars_core::prelude::*;
 
 #[cfg(feature = "csv")]
 pub use crate::csv::*;
+#[cfg(feature = "flight")]
+pub use crate::flight::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
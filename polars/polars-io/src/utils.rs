@@ -60,7 +60,8 @@ pub(crate) fn columns_to_projection(
 
         for column in columns.iter() {
             let Some(&i) = column_names.get(column.as_str()) else {
-                let valid_columns: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+                let valid_columns: Vec<String> =
+                    schema.fields.iter().map(|f| f.name.clone()).collect();
                 polars_bail!(
                     ColumnNotFound:
                     "unable to find {:?}; valid columns: {:?}", column, valid_columns,
@@ -107,11 +108,47 @@ pub(crate) fn update_row_counts2(dfs: &mut [DataFrame], offset: IdxSize) {
     }
 }
 
+/// Decode the `%XX` escapes in `s`, e.g. `unit%20price` becomes `unit price`. Invalid escapes
+/// are kept as they are, and `s` is returned unchanged if the decoded bytes are not valid utf8.
+pub(crate) fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(|b| b.is_ascii_hexdigit()));
+        match hex {
+            Some(hex) => {
+                // the digits are ascii, so they are valid utf8
+                let hex = std::str::from_utf8(hex).unwrap();
+                out.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).unwrap_or_else(|_| s.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
-    use super::resolve_homedir;
+    use super::{percent_decode, resolve_homedir};
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("unit%20price"), "unit price");
+        assert_eq!(percent_decode("a%2Fb%3d"), "a/b=");
+        // invalid escapes and bytes that aren't utf8
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%2"), "%zz%2");
+        assert_eq!(percent_decode("%FF"), "%FF");
+    }
 
     #[cfg(not(target_os = "windows"))]
     #[test]
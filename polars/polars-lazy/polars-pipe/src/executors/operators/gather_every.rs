@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::IdxSize;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// Take every `n`th row of `df`, starting at `offset` of all rows, where `position` is the
+/// number of rows before `df`.
+pub(crate) fn gather_every_at(
+    df: &DataFrame,
+    n: usize,
+    offset: usize,
+    position: usize,
+) -> PolarsResult<DataFrame> {
+    // the first row of this chunk that should be taken
+    let start = if position <= offset {
+        offset - position
+    } else {
+        (n - (position - offset) % n) % n
+    };
+    if start < df.height() {
+        df.gather_every(n, start)
+    } else {
+        Ok(df.clear())
+    }
+}
+
+struct GatherEveryState {
+    // the index of the chunk whose rows follow the rows taken so far
+    next_index: IdxSize,
+    // the number of rows before the chunk of `next_index`
+    position: usize,
+    // the chunks that arrived before the chunks that precede them
+    pending: BTreeMap<IdxSize, DataFrame>,
+}
+
+/// Takes every `n`th row of the chunks as soon as the rows before them are seen.
+///
+/// The chunks arrive out of order on different threads, so the splits share the position of
+/// the rows. A chunk that arrives before a chunk that precedes it is held back until that one
+/// is seen, the chunks that are still held back in the end are flushed. This requires unique
+/// chunk indices, so there may be no operators before this one that produce multiple chunks
+/// from one chunk.
+pub(crate) struct GatherEveryOperator {
+    n: usize,
+    offset: usize,
+    state: Arc<Mutex<GatherEveryState>>,
+}
+
+impl GatherEveryOperator {
+    pub(crate) fn new(n: usize, offset: usize) -> Self {
+        GatherEveryOperator {
+            n,
+            offset,
+            state: Arc::new(Mutex::new(GatherEveryState {
+                next_index: 0,
+                position: 0,
+                pending: BTreeMap::new(),
+            })),
+        }
+    }
+
+    fn gather(&self, state: &mut GatherEveryState, df: &DataFrame) -> PolarsResult<DataFrame> {
+        let out = gather_every_at(df, self.n, self.offset, state.position)?;
+        state.position += df.height();
+        Ok(out)
+    }
+}
+
+impl Operator for GatherEveryOperator {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        debug_assert!(chunk.chunk_index >= state.next_index);
+        if chunk.chunk_index != state.next_index {
+            state.pending.insert(chunk.chunk_index, chunk.data.clone());
+            return Ok(OperatorResult::NeedsNewData);
+        }
+
+        // take this chunk and the chunks that were waiting for it
+        let mut dfs = vec![self.gather(state, &chunk.data)?];
+        state.next_index += 1;
+        while let Some(df) = state.pending.remove(&state.next_index) {
+            dfs.push(self.gather(state, &df)?);
+            state.next_index += 1;
+        }
+        Ok(OperatorResult::Finished(
+            chunk.with_data(accumulate_dataframes_vertical_unchecked(dfs)),
+        ))
+    }
+
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<Option<DataChunk>> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        let pending = std::mem::take(&mut state.pending);
+        let Some(chunk_index) = pending.keys().next().copied() else {
+            return Ok(None);
+        };
+        let dfs = pending
+            .values()
+            .map(|df| self.gather(state, df))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Some(DataChunk::new(
+            chunk_index,
+            accumulate_dataframes_vertical_unchecked(dfs),
+        )))
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(GatherEveryOperator {
+            n: self.n,
+            offset: self.offset,
+            state: self.state.clone(),
+        })
+    }
+
+    fn fmt(&self) -> &str {
+        "gather_every"
+    }
+}
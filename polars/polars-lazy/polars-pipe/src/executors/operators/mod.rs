@@ -1,11 +1,13 @@
 mod filter;
 mod function;
+mod gather_every;
 mod placeholder;
 mod projection;
 mod reproject;
 
 pub(crate) use filter::*;
 pub(crate) use function::*;
+pub(crate) use gather_every::*;
 pub(crate) use placeholder::PlaceHolder;
 pub(crate) use projection::*;
 pub(crate) use reproject::*;
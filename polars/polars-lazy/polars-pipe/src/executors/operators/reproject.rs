@@ -63,6 +63,16 @@ impl Operator for ReProjectOperator {
         })
     }
 
+    fn flush(&mut self, context: &PExecutionContext) -> PolarsResult<Option<DataChunk>> {
+        match self.operator.flush(context)? {
+            Some(mut chunk) => {
+                reproject_chunk(&mut chunk, &mut self.positions, self.schema.as_ref())?;
+                Ok(Some(chunk))
+            }
+            None => Ok(None),
+        }
+    }
+
     fn split(&self, thread_no: usize) -> Box<dyn Operator> {
         let operator = self.operator.split(thread_no);
         Box::new(Self {
@@ -1,24 +1,34 @@
 use std::any::Any;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+use std::io::Write;
 use std::path::Path;
 use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+#[cfg(feature = "csv")]
+use polars_io::csv::CsvWriter;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+use polars_io::is_cloud_url;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetWriter;
 #[cfg(feature = "ipc")]
 use polars_io::prelude::IpcWriter;
-#[cfg(feature = "ipc")]
+#[cfg(feature = "cloud")]
+use polars_io::CloudWriter;
+#[cfg(any(feature = "ipc", feature = "csv"))]
 use polars_io::SerWriter;
 use polars_plan::prelude::*;
 
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 trait SinkWriter {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()>;
-    fn _finish(&mut self) -> PolarsResult<()>;
+    fn _finish(self: Box<Self>) -> PolarsResult<()>;
 }
 
 #[cfg(feature = "parquet")]
@@ -27,7 +37,7 @@ impl SinkWriter for polars_io::parquet::BatchedWriter<std::fs::File> {
         self.write_batch(df)
     }
 
-    fn _finish(&mut self) -> PolarsResult<()> {
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
         self.finish()?;
         Ok(())
     }
@@ -39,12 +49,83 @@ impl SinkWriter for polars_io::ipc::BatchedWriter<std::fs::File> {
         self.write_batch(df)
     }
 
-    fn _finish(&mut self) -> PolarsResult<()> {
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
         self.finish()?;
         Ok(())
     }
 }
 
+#[cfg(feature = "csv")]
+impl SinkWriter for polars_io::csv::BatchedWriter<std::fs::File> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
+        self.finish()
+    }
+}
+
+#[cfg(all(feature = "parquet", feature = "cloud"))]
+impl SinkWriter for polars_io::parquet::BatchedWriter<CloudWriter> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
+        self.finish()?;
+        // complete the upload
+        self.into_inner().finish()
+    }
+}
+
+#[cfg(all(feature = "ipc", feature = "cloud"))]
+impl SinkWriter for polars_io::ipc::BatchedWriter<CloudWriter> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
+        self.finish()?;
+        // complete the upload
+        self.into_inner().finish()
+    }
+}
+
+#[cfg(all(feature = "csv", feature = "cloud"))]
+impl SinkWriter for polars_io::csv::BatchedWriter<CloudWriter> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
+        self.finish()?;
+        // complete the upload
+        self.into_inner().finish()
+    }
+}
+
+/// Bail if cloud urls can't be written to.
+#[cfg(all(
+    any(feature = "parquet", feature = "ipc", feature = "csv"),
+    not(feature = "cloud")
+))]
+fn cloud_writer(_: &Path, _: Option<&CloudOptions>) -> PolarsResult<std::fs::File> {
+    polars_bail!(
+        ComputeError:
+        "feature 'async' (or more likely one of the cloud provider features) is required to write to cloud storage"
+    )
+}
+
+/// Start a multipart upload to the cloud url `path`.
+#[cfg(all(
+    any(feature = "parquet", feature = "ipc", feature = "csv"),
+    feature = "cloud"
+))]
+fn cloud_writer(path: &Path, cloud_options: Option<&CloudOptions>) -> PolarsResult<CloudWriter> {
+    CloudWriter::new(&path.to_string_lossy(), cloud_options)
+}
+
 #[cfg(feature = "parquet")]
 pub struct ParquetSink {}
 #[cfg(feature = "parquet")]
@@ -53,10 +134,25 @@ impl ParquetSink {
     pub fn new(
         path: &Path,
         options: ParquetWriteOptions,
+        cloud_options: Option<&CloudOptions>,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
+        let writer = if is_cloud_url(path) {
+            let writer = cloud_writer(path, cloud_options)?;
+            Box::new(Self::batched(writer, options, schema)?) as Box<dyn SinkWriter + Send>
+        } else {
+            let file = std::fs::File::create(path)?;
+            Box::new(Self::batched(file, options, schema)?) as Box<dyn SinkWriter + Send>
+        };
+        Ok(FilesSink::new(writer, options.maintain_order))
+    }
+
+    fn batched<W: Write>(
+        writer: W,
+        options: ParquetWriteOptions,
+        schema: &Schema,
+    ) -> PolarsResult<polars_io::parquet::BatchedWriter<W>> {
+        ParquetWriter::new(writer)
             .with_compression(options.compression)
             .with_data_pagesize_limit(options.data_pagesize_limit)
             .with_statistics(options.statistics)
@@ -64,25 +160,7 @@ impl ParquetSink {
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
-
-        let morsels_per_sink = morsels_per_sink();
-        let backpressure = morsels_per_sink * 2;
-        let (sender, receiver) = bounded(backpressure);
-
-        let io_thread_handle = Arc::new(Some(init_writer_thread(
-            receiver,
-            writer,
-            options.maintain_order,
-            morsels_per_sink,
-        )));
-
-        Ok(FilesSink {
-            sender,
-            io_thread_handle,
-        })
+            .batched(schema)
     }
 }
 
@@ -91,42 +169,79 @@ pub struct IpcSink {}
 #[cfg(feature = "ipc")]
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+    pub fn new(
+        path: &Path,
+        options: IpcWriterOptions,
+        cloud_options: Option<&CloudOptions>,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        let writer = if is_cloud_url(path) {
+            let writer = cloud_writer(path, cloud_options)?;
+            Box::new(Self::batched(writer, options, schema)?) as Box<dyn SinkWriter + Send>
+        } else {
+            let file = std::fs::File::create(path)?;
+            Box::new(Self::batched(file, options, schema)?) as Box<dyn SinkWriter + Send>
+        };
+        Ok(FilesSink::new(writer, options.maintain_order))
+    }
 
-        let morsels_per_sink = morsels_per_sink();
-        let backpressure = morsels_per_sink * 2;
-        let (sender, receiver) = bounded(backpressure);
+    fn batched<W: Write>(
+        writer: W,
+        options: IpcWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<polars_io::ipc::BatchedWriter<W>> {
+        IpcWriter::new(writer)
+            .with_compression(options.compression)
+            .batched(schema)
+    }
+}
 
-        let io_thread_handle = Arc::new(Some(init_writer_thread(
-            receiver,
-            writer,
-            options.maintain_order,
-            morsels_per_sink,
-        )));
+#[cfg(feature = "csv")]
+pub struct CsvSink {}
+#[cfg(feature = "csv")]
+impl CsvSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        options: CsvWriterOptions,
+        cloud_options: Option<&CloudOptions>,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        let maintain_order = options.maintain_order;
+        let writer = if is_cloud_url(path) {
+            let writer = cloud_writer(path, cloud_options)?;
+            Box::new(Self::batched(writer, options, schema)?) as Box<dyn SinkWriter + Send>
+        } else {
+            let file = std::fs::File::create(path)?;
+            Box::new(Self::batched(file, options, schema)?) as Box<dyn SinkWriter + Send>
+        };
+        Ok(FilesSink::new(writer, maintain_order))
+    }
 
-        Ok(FilesSink {
-            sender,
-            io_thread_handle,
-        })
+    fn batched<W: Write>(
+        writer: W,
+        options: CsvWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<polars_io::csv::BatchedWriter<W>> {
+        CsvWriter::new(writer)
+            .has_header(options.include_header)
+            .with_batch_size(options.batch_size)
+            .with_serialize_options(options.serialize_options)
+            .batched(schema)
     }
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 fn init_writer_thread(
     receiver: Receiver<Option<DataChunk>>,
-    mut writer: Box<dyn SinkWriter + Send + Sync>,
+    mut writer: Box<dyn SinkWriter + Send>,
     maintain_order: bool,
     // this is used to determine when a batch of chunks should be written to disk
     // all chunks per push should be collected to determine in which order they should
     // be written
     morsels_per_sink: usize,
-) -> JoinHandle<()> {
+) -> JoinHandle<PolarsResult<()>> {
+    // if writing fails the writer is dropped, which aborts an upload to cloud storage
     std::thread::spawn(move || {
         // keep chunks around until all chunks per sink are written
         // then we write them all at once.
@@ -148,34 +263,56 @@ fn init_writer_thread(
                 }
 
                 for chunk in chunks.iter() {
-                    writer._write_batch(&chunk.data).unwrap()
+                    writer._write_batch(&chunk.data)?
                 }
                 // all chunks are written remove them
                 chunks.clear();
 
                 if last_write {
-                    writer._finish().unwrap();
-                    return;
+                    return writer._finish();
                 }
             }
         }
+        Ok(())
     })
 }
 
 // Ensure the data is return in the order it was streamed
 #[derive(Clone)]
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 pub struct FilesSink {
     sender: Sender<Option<DataChunk>>,
-    io_thread_handle: Arc<Option<JoinHandle<()>>>,
+    io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+impl FilesSink {
+    fn new(writer: Box<dyn SinkWriter + Send>, maintain_order: bool) -> Self {
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order,
+            morsels_per_sink,
+        )));
+
+        FilesSink {
+            sender,
+            io_thread_handle,
+        }
+    }
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 impl Sink for FilesSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
         // don't add empty dataframes
+        // if the io thread stopped because writing failed, the error is returned by `finalize`
         if chunk.data.height() > 0 {
-            self.sender.send(Some(chunk)).unwrap();
+            let _ = self.sender.send(Some(chunk));
         };
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -189,7 +326,7 @@ impl Sink for FilesSink {
     }
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         // `None` indicates that we can flush all remaining chunks.
-        self.sender.send(None).unwrap();
+        let _ = self.sender.send(None);
 
         // wait until all files written
         // some unwrap/mut kung-fu to get a hold of `self`
@@ -198,7 +335,7 @@ impl Sink for FilesSink {
             .take()
             .unwrap()
             .join()
-            .unwrap();
+            .unwrap()?;
 
         // return a dummy dataframe;
         Ok(FinalizedSink::Finished(Default::default()))
@@ -0,0 +1,73 @@
+use std::any::Any;
+
+use polars_core::error::PolarsResult;
+
+use crate::executors::operators::gather_every_at;
+use crate::operators::{
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult,
+};
+
+// The chunks come in random order, so the position of their rows is only
+// known once all chunks have been seen. This is used if the chunk indices
+// are not unique, otherwise the `GatherEveryOperator` takes the rows while
+// the chunks stream through.
+#[derive(Clone)]
+pub struct GatherEverySink {
+    n: usize,
+    offset: usize,
+    chunks: Vec<DataChunk>,
+}
+
+impl GatherEverySink {
+    pub fn new(n: usize, offset: usize) -> Self {
+        GatherEverySink {
+            n,
+            offset,
+            chunks: vec![],
+        }
+    }
+
+    fn sort(&mut self) {
+        // stable, chunks produced from the same input chunk share the index
+        self.chunks.sort_by_key(|chunk| chunk.chunk_index);
+    }
+}
+
+impl Sink for GatherEverySink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 || self.chunks.is_empty() {
+            self.chunks.push(chunk);
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_ref::<GatherEverySink>().unwrap();
+        self.chunks.extend_from_slice(&other.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(self.n, self.offset))
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.sort();
+        let mut position = 0;
+        let chunks = std::mem::take(&mut self.chunks)
+            .into_iter()
+            .map(|chunk| {
+                let data = gather_every_at(&chunk.data, self.n, self.offset, position)?;
+                position += chunk.data.height();
+                Ok(chunk.with_data(data))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(FinalizedSink::Finished(chunks_to_df_unchecked(chunks)))
+    }
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn fmt(&self) -> &str {
+        "gather_every_sink"
+    }
+}
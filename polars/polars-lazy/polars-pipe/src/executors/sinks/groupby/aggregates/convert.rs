@@ -1,6 +1,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use polars_core::config::{get_agg_dtype_policy, MeanDtype, SumDtype};
 use polars_core::datatypes::Field;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{DataType, SchemaRef, Series, IDX_DTYPE};
@@ -72,6 +73,13 @@ pub fn can_convert_to_hash_agg(
         }
         match expr_arena.get(node) {
             AExpr::Count => true,
+            // the hash aggregations only produce the default output types
+            AExpr::Agg(AAggExpr::Sum(_)) if get_agg_dtype_policy().sum != SumDtype::Int64 => false,
+            AExpr::Agg(AAggExpr::Mean(_))
+                if !matches!(get_agg_dtype_policy().mean, MeanDtype::Float64) =>
+            {
+                false
+            }
             ae @ AExpr::Agg(agg_fn) => {
                 matches!(
                     agg_fn,
@@ -79,7 +87,7 @@ pub fn can_convert_to_hash_agg(
                         | AAggExpr::First(_)
                         | AAggExpr::Last(_)
                         | AAggExpr::Mean(_)
-                        | AAggExpr::Count(_)
+                        | AAggExpr::Count(_, _)
                 ) || (matches!(
                     agg_fn,
                     AAggExpr::Max {
@@ -117,7 +125,7 @@ where
         AExpr::Alias(input, _) => convert_to_hash_agg(*input, expr_arena, schema, to_physical),
         AExpr::Count => (
             Arc::new(Count {}),
-            AggregateFunction::Count(CountAgg::new()),
+            AggregateFunction::Count(CountAgg::new(true)),
         ),
         AExpr::Agg(agg) => match agg {
             AAggExpr::Min { input, .. } => {
@@ -230,9 +238,12 @@ where
                     AggregateFunction::Last(LastAgg::new(dtype.to_physical())),
                 )
             }
-            AAggExpr::Count(input) => {
+            AAggExpr::Count(input, include_nulls) => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
-                (phys_expr, AggregateFunction::Count(CountAgg::new()))
+                (
+                    phys_expr,
+                    AggregateFunction::Count(CountAgg::new(*include_nulls)),
+                )
             }
             agg => panic!("{agg:?} not yet implemented."),
         },
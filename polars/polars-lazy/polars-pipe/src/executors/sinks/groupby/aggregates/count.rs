@@ -9,11 +9,15 @@ use crate::operators::IdxSize;
 
 pub(crate) struct CountAgg {
     count: IdxSize,
+    include_nulls: bool,
 }
 
 impl CountAgg {
-    pub(crate) fn new() -> Self {
-        CountAgg { count: 0 }
+    pub(crate) fn new(include_nulls: bool) -> Self {
+        CountAgg {
+            count: 0,
+            include_nulls,
+        }
     }
     fn incr(&mut self) {
         self.count += 1;
@@ -25,17 +29,28 @@ impl AggregateFn for CountAgg {
         false
     }
 
-    fn pre_agg(&mut self, _chunk_idx: IdxSize, _item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
-        self.incr();
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        if self.include_nulls {
+            self.incr();
+        } else {
+            let item = unsafe { item.next().unwrap_unchecked_release() };
+            if !matches!(item, AnyValue::Null) {
+                self.incr();
+            }
+        }
     }
     fn pre_agg_ordered(
         &mut self,
         _chunk_idx: IdxSize,
-        _offset: IdxSize,
+        offset: IdxSize,
         length: IdxSize,
-        _values: &Series,
+        values: &Series,
     ) {
-        self.count += length
+        self.count += if self.include_nulls {
+            length
+        } else {
+            length - values.slice(offset as i64, length as usize).null_count() as IdxSize
+        }
     }
 
     fn dtype(&self) -> DataType {
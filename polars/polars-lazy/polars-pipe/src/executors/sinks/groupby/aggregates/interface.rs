@@ -109,7 +109,7 @@ impl AggregateFunction {
             SumI64(_) => SumI64(SumAgg::new()),
             MeanF32(_) => MeanF32(MeanAgg::new()),
             MeanF64(_) => MeanF64(MeanAgg::new()),
-            Count(_) => Count(CountAgg::new()),
+            Count(agg) => Count(CountAgg::new(agg.include_nulls)),
             Null(a) => Null(a.clone()),
             MinMaxF32(inner) => MinMaxF32(inner.split()),
             MinMaxF64(inner) => MinMaxF64(inner.split()),
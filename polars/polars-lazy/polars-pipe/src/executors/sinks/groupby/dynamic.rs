@@ -0,0 +1,263 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::frame::row::AnyValueBuffer;
+use polars_core::prelude::*;
+use polars_time::prelude::{groupby_windows_aligned, Window, NO_TIMEZONE};
+use polars_time::DynamicGroupOptions;
+
+use super::aggregates::{AggregateFn, AggregateFunction};
+use super::physical_agg_to_logical;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// The time unit of the timestamps of an index column, see `groupby_dynamic`.
+fn time_unit(index_dtype: &DataType) -> TimeUnit {
+    match index_dtype {
+        DataType::Datetime(tu, _) => *tu,
+        DataType::Date => TimeUnit::Milliseconds,
+        _ => TimeUnit::Nanoseconds,
+    }
+}
+
+fn to_timestamps(index: &Series) -> PolarsResult<Int64Chunked> {
+    polars_ensure!(
+        index.null_count() == 0,
+        ComputeError: "null values in dynamic groupby not supported, fill nulls."
+    );
+    let index = match index.dtype() {
+        DataType::Date => index.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+        _ => index.clone(),
+    };
+    let ts = index.to_physical_repr().cast(&DataType::Int64)?.rechunk();
+    Ok(ts.i64()?.clone())
+}
+
+fn timestamps_to_series(
+    name: &str,
+    ts: Vec<i64>,
+    tu: TimeUnit,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let ca = Int64Chunked::new_vec(name, ts);
+    match dtype {
+        DataType::Int32 | DataType::Int64 => ca.into_series().cast(dtype),
+        _ => ca.into_datetime(tu, None).into_series().cast(dtype),
+    }
+}
+
+// The windows of a chunk.
+struct ChunkWindows {
+    chunk_index: IdxSize,
+    // the window of the first value, the windows of the first chunk start there
+    start: i64,
+    lower: Vec<i64>,
+    upper: Vec<i64>,
+    // the first value of every window
+    first: Vec<i64>,
+    // the aggregation functions of the windows, `n_aggs` per window
+    aggregators: Vec<AggregateFunction>,
+}
+
+/// A dynamic groupby without keys of which the input is sorted by the index column. The rows
+/// are assigned to their windows and aggregated per chunk. A window that spans chunks has
+/// partial aggregates in each of them, which are combined when the sink is finalized. Only the
+/// partial aggregates of the windows are kept, not the rows.
+pub struct DynamicGroupbySink {
+    options: DynamicGroupOptions,
+    index_dtype: DataType,
+    aggregation_columns: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    agg_fns: Arc<[AggregateFunction]>,
+    output_schema: SchemaRef,
+    slice: Option<(i64, usize)>,
+    chunks: Vec<ChunkWindows>,
+}
+
+impl DynamicGroupbySink {
+    pub(crate) fn new(
+        options: DynamicGroupOptions,
+        index_dtype: DataType,
+        aggregation_columns: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        agg_fns: Arc<[AggregateFunction]>,
+        output_schema: SchemaRef,
+        slice: Option<(i64, usize)>,
+    ) -> Self {
+        Self {
+            options,
+            index_dtype,
+            aggregation_columns,
+            agg_fns,
+            output_schema,
+            slice,
+            chunks: vec![],
+        }
+    }
+
+    fn window(&self) -> Window {
+        Window::new(self.options.every, self.options.period, self.options.offset)
+    }
+}
+
+impl Sink for DynamicGroupbySink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if chunk.is_empty() {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let state = context.execution_state.as_any();
+        let tu = time_unit(&self.index_dtype);
+        let ts = to_timestamps(chunk.data.column(&self.options.index_column)?)?;
+        let ts = ts.cont_slice()?;
+        let aggregation_series = self
+            .aggregation_columns
+            .iter()
+            .map(|e| Ok(e.evaluate(&chunk, state)?.to_physical_repr().rechunk()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let window = self.window();
+        let (groups, lower, upper) =
+            groupby_windows_aligned(window, ts, self.options.closed_window, tu)?;
+        let start = match tu {
+            TimeUnit::Nanoseconds => window.truncate_ns(ts[0], NO_TIMEZONE)?,
+            TimeUnit::Microseconds => window.truncate_us(ts[0], NO_TIMEZONE)?,
+            TimeUnit::Milliseconds => window.truncate_ms(ts[0], NO_TIMEZONE)?,
+        };
+        let mut aggregators = Vec::with_capacity(groups.len() * self.agg_fns.len());
+        for [offset, length] in &groups {
+            for (agg_fn, s) in self.agg_fns.iter().zip(&aggregation_series) {
+                let mut agg_fn = agg_fn.split();
+                agg_fn.pre_agg_ordered(chunk.chunk_index, *offset, *length, s);
+                aggregators.push(agg_fn);
+            }
+        }
+        let first = groups
+            .iter()
+            .map(|[offset, _]| ts[*offset as usize])
+            .collect();
+
+        self.chunks.push(ChunkWindows {
+            chunk_index: chunk.chunk_index,
+            start,
+            lower,
+            upper,
+            first,
+            aggregators,
+        });
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<DynamicGroupbySink>().unwrap();
+        self.chunks.append(&mut other.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(
+            self.options.clone(),
+            self.index_dtype.clone(),
+            self.aggregation_columns.clone(),
+            self.agg_fns.clone(),
+            self.output_schema.clone(),
+            self.slice,
+        ))
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        let n_aggs = self.agg_fns.len();
+        // like in the in-memory engine, the windows start at the window of the first value
+        let start = chunks.first().map_or(i64::MIN, |chunk| chunk.start);
+
+        let mut lower: Vec<i64> = vec![];
+        let mut upper = vec![];
+        let mut first = vec![];
+        let mut aggregators: Vec<AggregateFunction> = vec![];
+        for chunk in chunks {
+            let mut chunk_aggregators = chunk.aggregators.into_iter();
+            let windows = chunk.lower.iter().zip(&chunk.upper).zip(&chunk.first);
+            for ((l, u), f) in windows {
+                let window_aggregators = chunk_aggregators.by_ref().take(n_aggs);
+                if *l < start {
+                    window_aggregators.for_each(drop);
+                    continue;
+                }
+                // a window that is still open is combined with its partial aggregates
+                match lower.binary_search(l) {
+                    Ok(i) => {
+                        for (agg, other) in aggregators[i * n_aggs..(i + 1) * n_aggs]
+                            .iter_mut()
+                            .zip(window_aggregators)
+                        {
+                            agg.combine(other.as_any())
+                        }
+                    }
+                    Err(i) => {
+                        lower.insert(i, *l);
+                        upper.insert(i, *u);
+                        first.insert(i, *f);
+                        aggregators.splice(i * n_aggs..i * n_aggs, window_aggregators);
+                    }
+                }
+            }
+        }
+
+        let n_groups = lower.len();
+        let tu = time_unit(&self.index_dtype);
+        let index = if self.options.truncate {
+            lower.clone()
+        } else {
+            first
+        };
+        let mut cols = vec![];
+        if self.options.include_boundaries {
+            let bounds_dtype = match self.index_dtype {
+                DataType::Int32 | DataType::Int64 => DataType::Int64,
+                _ => DataType::Datetime(tu, None),
+            };
+            cols.push(timestamps_to_series(
+                "_lower_boundary",
+                lower,
+                tu,
+                &bounds_dtype,
+            )?);
+            cols.push(timestamps_to_series(
+                "_upper_boundary",
+                upper,
+                tu,
+                &bounds_dtype,
+            )?);
+        }
+        cols.push(timestamps_to_series(
+            &self.options.index_column,
+            index,
+            tu,
+            &self.index_dtype,
+        )?);
+
+        let mut buffers = self
+            .agg_fns
+            .iter()
+            .map(|agg_fn| AnyValueBuffer::new(&agg_fn.dtype(), n_groups))
+            .collect::<Vec<_>>();
+        for group in 0..n_groups {
+            for (i, buffer) in buffers.iter_mut().enumerate() {
+                buffer.add(aggregators[group * n_aggs + i].finalize());
+            }
+        }
+        cols.extend(buffers.into_iter().map(|buffer| buffer.into_series()));
+        physical_agg_to_logical(&mut cols, &self.output_schema);
+        let mut df = DataFrame::new_no_checks(cols);
+        if let Some((offset, len)) = self.slice {
+            df = df.slice(offset, len);
+        }
+        Ok(FinalizedSink::Finished(df))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "dynamic_groupby"
+    }
+}
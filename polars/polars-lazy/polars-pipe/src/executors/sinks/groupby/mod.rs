@@ -1,16 +1,22 @@
 pub(crate) mod aggregates;
+#[cfg(feature = "dynamic_groupby")]
+mod dynamic;
 mod generic;
 mod ooc;
 mod ooc_state;
 mod primitive;
+mod sorted;
 mod string;
 mod utils;
 
+#[cfg(feature = "dynamic_groupby")]
+pub(crate) use dynamic::*;
 pub(crate) use generic::GenericGroupby2;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::using_string_cache;
 pub(crate) use primitive::*;
+pub(crate) use sorted::*;
 pub(crate) use string::*;
 
 const MEMORY_FRACTION_THRESHOLD: f64 = 0.3;
@@ -0,0 +1,185 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::frame::row::AnyValueBuffer;
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, NoNull};
+
+use super::aggregates::{AggregateFn, AggregateFunction};
+use super::physical_agg_to_logical;
+use crate::executors::sinks::utils::key_runs;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+fn key_row(keys: &DataFrame, idx: usize) -> Option<Vec<AnyValue<'static>>> {
+    keys.get(idx).map(|row| {
+        row.into_iter()
+            .map(|av| av.into_static().unwrap())
+            .collect()
+    })
+}
+
+// The groups of a chunk.
+struct ChunkGroups {
+    chunk_index: IdxSize,
+    // the keys of the first row of every group
+    keys: DataFrame,
+    // the aggregation functions of the groups, `n_aggs` per group
+    aggregators: Vec<AggregateFunction>,
+}
+
+/// A groupby of which the input is sorted by the keys, so that the rows of a group are
+/// contiguous. No hash table is needed: the groups are aggregated per chunk and the partial
+/// aggregates of a group that spans chunks are combined when the sink is finalized. The groups
+/// are in the order in which they occur.
+pub struct SortedGroupbySink {
+    keys: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    aggregation_columns: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    agg_fns: Arc<[AggregateFunction]>,
+    output_schema: SchemaRef,
+    slice: Option<(i64, usize)>,
+    chunks: Vec<ChunkGroups>,
+}
+
+impl SortedGroupbySink {
+    pub(crate) fn new(
+        keys: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        aggregation_columns: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        agg_fns: Arc<[AggregateFunction]>,
+        output_schema: SchemaRef,
+        slice: Option<(i64, usize)>,
+    ) -> Self {
+        Self {
+            keys,
+            aggregation_columns,
+            agg_fns,
+            output_schema,
+            slice,
+            chunks: vec![],
+        }
+    }
+}
+
+impl Sink for SortedGroupbySink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if chunk.is_empty() {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let state = context.execution_state.as_any();
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&chunk, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let aggregation_series = self
+            .aggregation_columns
+            .iter()
+            .map(|e| Ok(e.evaluate(&chunk, state)?.to_physical_repr().rechunk()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let runs = key_runs(&keys)?;
+        let mut aggregators = Vec::with_capacity(runs.len() * self.agg_fns.len());
+        for [offset, length] in &runs {
+            for (agg_fn, s) in self.agg_fns.iter().zip(&aggregation_series) {
+                let mut agg_fn = agg_fn.split();
+                agg_fn.pre_agg_ordered(chunk.chunk_index, *offset, *length, s);
+                aggregators.push(agg_fn);
+            }
+        }
+        let first_rows: NoNull<IdxCa> = runs.iter().map(|[offset, _]| *offset).collect();
+        let first_rows = first_rows.into_inner();
+        let keys = DataFrame::new_no_checks(keys).take(&first_rows)?;
+
+        self.chunks.push(ChunkGroups {
+            chunk_index: chunk.chunk_index,
+            keys,
+            aggregators,
+        });
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<SortedGroupbySink>().unwrap();
+        self.chunks.append(&mut other.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(
+            self.keys.clone(),
+            self.aggregation_columns.clone(),
+            self.agg_fns.clone(),
+            self.output_schema.clone(),
+            self.slice,
+        ))
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        let n_aggs = self.agg_fns.len();
+
+        // a group that continues in the next chunk is combined with its first group there
+        let mut keys = Vec::with_capacity(chunks.len());
+        let mut aggregators: Vec<AggregateFunction> = vec![];
+        let mut n_groups = 0;
+        let mut last_key = None;
+        for chunk in chunks {
+            let height = chunk.keys.height();
+            let mut chunk_aggregators = chunk.aggregators.into_iter();
+            let mut chunk_keys = chunk.keys;
+            if last_key.is_some() && key_row(&chunk_keys, 0) == last_key {
+                let start = aggregators.len() - n_aggs;
+                for (agg, other) in aggregators[start..]
+                    .iter_mut()
+                    .zip(chunk_aggregators.by_ref().take(n_aggs))
+                {
+                    agg.combine(other.as_any())
+                }
+                last_key = key_row(&chunk_keys, height - 1);
+                chunk_keys = chunk_keys.slice(1, height - 1);
+            } else {
+                last_key = key_row(&chunk_keys, height - 1);
+            }
+            n_groups += chunk_keys.height();
+            aggregators.extend(chunk_aggregators);
+            keys.push(chunk_keys);
+        }
+
+        let keys: Vec<Series> = if keys.is_empty() {
+            self.output_schema
+                .iter_fields()
+                .take(self.keys.len())
+                .map(|field| Series::new_empty(field.name(), field.data_type()))
+                .collect()
+        } else {
+            accumulate_dataframes_vertical_unchecked(keys).into()
+        };
+        let mut buffers = self
+            .agg_fns
+            .iter()
+            .map(|agg_fn| AnyValueBuffer::new(&agg_fn.dtype(), n_groups))
+            .collect::<Vec<_>>();
+        for group in 0..n_groups {
+            for (i, buffer) in buffers.iter_mut().enumerate() {
+                buffer.add(aggregators[group * n_aggs + i].finalize());
+            }
+        }
+
+        let mut cols = keys;
+        cols.extend(buffers.into_iter().map(|buffer| buffer.into_series()));
+        physical_agg_to_logical(&mut cols, &self.output_schema);
+        let mut df = DataFrame::new_no_checks(cols);
+        if let Some((offset, len)) = self.slice {
+            df = df.slice(offset, len);
+        }
+        Ok(FinalizedSink::Finished(df))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "sorted_groupby"
+    }
+}
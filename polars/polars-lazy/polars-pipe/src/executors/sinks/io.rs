@@ -19,6 +19,7 @@ type Payload = (Option<IdxCa>, DfIter);
 /// A helper that can be used to spill to disk
 pub(crate) struct IOThread {
     sender: Sender<Payload>,
+    pub(in crate::executors::sinks) schema: SchemaRef,
     _lockfile: Arc<LockFile>,
     pub(in crate::executors::sinks) dir: PathBuf,
     pub(in crate::executors::sinks) sent: Arc<AtomicUsize>,
@@ -32,11 +33,23 @@ fn get_lockfile_path(dir: &Path) -> PathBuf {
     lockfile_path
 }
 
+/// The directory that out-of-core operations spill to.
+///
+/// This is `~/.polars/{operation_name}` unless another location is configured with
+/// [`polars_core::config::set_temp_dir`] or the `POLARS_TEMP_DIR` environment variable,
+/// e.g. a disk that is large enough to hold the spilled data.
+fn spill_dir(operation_name: &'static str) -> PathBuf {
+    let mut dir = polars_core::config::get_temp_dir()
+        .unwrap_or_else(|| resolve_homedir(Path::new("~/.polars")));
+    dir.push(operation_name);
+    dir
+}
+
 /// Starts a new thread that will clean up operations of directories that don't
 /// have a lockfile (opened with 'w' permissions).
 fn gc_thread(operation_name: &'static str) {
     let _ = std::thread::spawn(move || {
-        let dir = resolve_homedir(Path::new(&format!("~/.polars/{operation_name}/")));
+        let dir = spill_dir(operation_name);
 
         // if the directory does not exist, there is nothing to clean
         let rd = match std::fs::read_dir(&dir) {
@@ -75,7 +88,7 @@ impl IOThread {
     pub(in crate::executors::sinks) fn try_new(
         // Schema of the file that will be dumped to disk
         schema: SchemaRef,
-        // Will be used as subdirectory name in `~/.polars/`
+        // Will be used as subdirectory name in the spill directory
         operation_name: &'static str,
     ) -> PolarsResult<Self> {
         let uuid = SystemTime::now()
@@ -83,7 +96,8 @@ impl IOThread {
             .unwrap()
             .as_nanos();
 
-        let dir = resolve_homedir(Path::new(&format!("~/.polars/{operation_name}/{uuid}")));
+        let mut dir = spill_dir(operation_name);
+        dir.push(format!("{uuid}"));
         std::fs::create_dir_all(&dir)?;
 
         // make sure we create lockfile before we GC
@@ -104,6 +118,7 @@ impl IOThread {
         let dir2 = dir.clone();
         let total2 = total.clone();
         let lockfile2 = lockfile.clone();
+        let schema2 = schema.clone();
         std::thread::spawn(move || {
             // this moves the lockfile in the thread
             // we keep one in the thread and one in the `IoThread` struct
@@ -127,7 +142,7 @@ impl IOThread {
 
                         let file = std::fs::File::create(path).unwrap();
                         let writer = IpcWriter::new(file);
-                        let mut writer = writer.batched(&schema).unwrap();
+                        let mut writer = writer.batched(&schema2).unwrap();
                         writer.write_batch(&df).unwrap();
                         writer.finish().unwrap();
                         count += 1;
@@ -138,7 +153,7 @@ impl IOThread {
 
                     let file = std::fs::File::create(path).unwrap();
                     let writer = IpcWriter::new(file);
-                    let mut writer = writer.batched(&schema).unwrap();
+                    let mut writer = writer.batched(&schema2).unwrap();
 
                     for df in iter {
                         writer.write_batch(&df).unwrap();
@@ -153,6 +168,7 @@ impl IOThread {
 
         Ok(Self {
             sender,
+            schema,
             dir,
             sent,
             total,
@@ -209,6 +225,39 @@ pub(in crate::executors::sinks) fn block_thread_until_io_thread_done(io_thread:
     }
 }
 
+pub(in crate::executors::sinks) fn read_df(path: &Path) -> PolarsResult<DataFrame> {
+    let file = std::fs::File::open(path)?;
+    IpcReader::new(file).set_rechunk(false).finish()
+}
+
+/// Split `df` by the partition of every row. Returns the parts and the partitions they
+/// belong to, which can be given to [`IOThread::dump_iter`].
+pub(in crate::executors::sinks) fn partition_df(
+    df: DataFrame,
+    partitions: &IdxCa,
+) -> PolarsResult<(DfIter, IdxCa)> {
+    let groups = partitions.group_tuples(false, false)?;
+    let partitions = unsafe { partitions.clone().into_series().agg_first(&groups) };
+    let partitions = partitions.idx().unwrap().clone();
+
+    let out = match groups {
+        GroupsProxy::Idx(idx) => {
+            let iter = idx.into_iter().map(move |(_, group)| {
+                // groups are in bounds
+                unsafe { df._take_unchecked_slice(&group, false) }
+            });
+            Box::new(iter) as DfIter
+        }
+        GroupsProxy::Slice { groups, .. } => {
+            let iter = groups
+                .into_iter()
+                .map(move |[first, len]| df.slice(first as i64, len as usize));
+            Box::new(iter) as DfIter
+        }
+    };
+    Ok((out, partitions))
+}
+
 struct LockFile {
     path: PathBuf,
 }
@@ -1,9 +1,10 @@
 use std::any::Any;
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use hashbrown::hash_map::RawEntryMut;
 use polars_arrow::trusted_len::PushUnchecked;
+use polars_core::config::verbose;
 use polars_core::error::PolarsResult;
 use polars_core::export::ahash::RandomState;
 use polars_core::frame::hash_join::ChunkId;
@@ -13,11 +14,15 @@ use polars_utils::hash_to_partition;
 use polars_utils::slice::GetSaferUnchecked;
 use polars_utils::unwrap::UnwrapUncheckedRelease;
 
+use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
 use crate::executors::sinks::joins::inner_left::GenericJoinProbe;
+use crate::executors::sinks::joins::ooc::{spill_partitioned, OocJoinProbe};
+use crate::executors::sinks::memory::MemTracker;
 use crate::executors::sinks::utils::{hash_series, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::{morsels_per_sink, FORCE_OOC_JOIN};
 
 pub(super) type ChunkIdx = IdxSize;
 pub(super) type DfIdx = IdxSize;
@@ -74,6 +79,15 @@ pub struct GenericBuild {
     join_type: JoinType,
     // the join order is swapped to ensure we hash the smaller table
     swapped: bool,
+    // spill to disk if the build side doesn't fit in memory
+    low_memory: bool,
+    // Stores available memory in the system at the start of this sink.
+    // and stores the memory used by this this sink.
+    mem_track: MemTracker,
+    // build in-memory or out-of-core
+    ooc: bool,
+    // when ooc, the build side is partitioned and written to disk by an IO thread
+    io_thread: Arc<RwLock<Option<IOThread>>>,
 }
 
 impl GenericBuild {
@@ -83,10 +97,16 @@ impl GenericBuild {
         swapped: bool,
         join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
         join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        low_memory: bool,
     ) -> Self {
         let hb: RandomState = Default::default();
         let partitions = _set_partition_size();
         let hash_tables = load_vec(partitions, || PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE));
+        // for testing purposes
+        let ooc = std::env::var(FORCE_OOC_JOIN).is_ok();
+        if ooc && verbose() {
+            eprintln!("OOC join forced");
+        }
         GenericBuild {
             chunks: vec![],
             join_type,
@@ -99,6 +119,10 @@ impl GenericBuild {
             materialized_join_cols: vec![],
             hash_tables,
             hashes: vec![],
+            low_memory,
+            mem_track: MemTracker::new(morsels_per_sink()),
+            ooc,
+            io_thread: Default::default(),
         }
     }
 }
@@ -145,6 +169,53 @@ impl GenericBuild {
         self.join_columns_left.len()
     }
 
+    /// Stop building the hash tables, the build side will be spilled to disk.
+    fn init_ooc(&mut self) {
+        if verbose() {
+            eprintln!("OOC join started");
+        }
+        self.ooc = true;
+        self.materialized_join_cols.clear();
+        let partitions = self.hash_tables.len();
+        self.hash_tables = load_vec(partitions, || PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE));
+    }
+
+    /// Spill the rows of `chunk` to the partitions of their join keys.
+    fn spill(&self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<()> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        {
+            let mut iot = self.io_thread.write().unwrap();
+            if iot.is_none() {
+                *iot = Some(IOThread::try_new(Arc::new(chunk.data.schema()), "join")?)
+            }
+        }
+        let iot = self.io_thread.read().unwrap();
+        spill_partitioned(
+            iot.as_ref().unwrap(),
+            &self.join_columns_left,
+            context,
+            chunk,
+            &self.hb,
+        )
+    }
+
+    /// Spill the chunks that are still in memory.
+    fn spill_chunks(&mut self, context: &PExecutionContext) -> PolarsResult<()> {
+        for chunk in std::mem::take(&mut self.chunks) {
+            if chunk.is_empty() {
+                // keep a single empty chunk, needed if nothing is spilled at all
+                if self.chunks.is_empty() {
+                    self.chunks.push(chunk)
+                }
+            } else {
+                self.spill(context, chunk)?
+            }
+        }
+        Ok(())
+    }
+
     fn set_join_series(
         &mut self,
         context: &PExecutionContext,
@@ -185,6 +256,25 @@ impl GenericBuild {
 
 impl Sink for GenericBuild {
     fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if self.ooc {
+            self.chunks.push(chunk);
+            self.spill_chunks(context)?;
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        if self.low_memory {
+            let chunk_bytes = chunk.data.estimated_size();
+            let used = self.mem_track.fetch_add(chunk_bytes);
+            let free = self.mem_track.get_available();
+
+            // keep room for the hash tables and the output of the join
+            if used * 3 > free {
+                self.init_ooc();
+                self.chunks.push(chunk);
+                self.spill_chunks(context)?;
+                return Ok(SinkResult::CanHaveMoreInput);
+            }
+        }
+
         // we do some juggling here so that we don't
         // end up with empty chunks
         // But we always want one empty chunk if all is empty as we need
@@ -245,6 +335,17 @@ impl Sink for GenericBuild {
     }
 
     fn combine(&mut self, other: &mut dyn Sink) {
+        {
+            let other = other.as_any().downcast_mut::<Self>().unwrap();
+            if self.ooc || other.ooc {
+                // the chunks that are still in memory are spilled when finalizing
+                if !self.ooc {
+                    self.init_ooc();
+                }
+                self.chunks.append(&mut other.chunks);
+                return;
+            }
+        }
         if self.is_empty() {
             let other = other.as_any().downcast_mut::<Self>().unwrap();
             if !other.is_empty() {
@@ -318,12 +419,33 @@ impl Sink for GenericBuild {
             self.swapped,
             self.join_columns_left.clone(),
             self.join_columns_right.clone(),
+            self.low_memory,
         );
         new.hb = self.hb.clone();
+        new.mem_track = self.mem_track.clone();
+        new.ooc = self.ooc;
+        new.io_thread = self.io_thread.clone();
         Box::new(new)
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.ooc {
+            self.spill_chunks(context)?;
+            // if nothing was spilled, all chunks were empty and we join in memory
+            if let Some(io_thread) = self.io_thread.write().unwrap().take() {
+                block_thread_until_io_thread_done(&io_thread);
+                let probe_operator = OocJoinProbe::new(
+                    io_thread,
+                    self.hb.clone(),
+                    self.join_columns_left.clone(),
+                    self.join_columns_right.clone(),
+                    self.suffix.clone(),
+                    self.join_type.clone(),
+                    self.swapped,
+                );
+                return Ok(FinalizedSink::Operator(Box::new(probe_operator)));
+            }
+        }
         match self.join_type {
             JoinType::Inner | JoinType::Left => {
                 let chunks_len = self.chunks.len();
@@ -2,6 +2,7 @@
 mod cross;
 mod generic_build;
 mod inner_left;
+mod ooc;
 
 #[cfg(feature = "cross_join")]
 pub(crate) use cross::*;
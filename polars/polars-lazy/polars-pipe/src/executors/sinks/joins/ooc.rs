@@ -0,0 +1,216 @@
+//! Out-of-core (grace) hash join.
+//!
+//! If the build side of a low memory join doesn't fit in memory, it is partitioned by the hash
+//! of the join keys and spilled to disk. The probe side is partitioned and spilled in the same
+//! way. Once all of the probe side was seen, the partitions are joined pair by pair, so that only
+//! a single partition of both sides is in memory per thread.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::prelude::*;
+use polars_utils::hash_to_partition;
+
+use crate::executors::sinks::io::{
+    block_thread_until_io_thread_done, partition_df, read_df, IOThread,
+};
+use crate::executors::sinks::utils::hash_series;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+use crate::pipeline::PARTITION_SIZE;
+
+/// Determine the partition of every row of `chunk` by the hash of the join keys.
+pub(super) fn partition_by_keys(
+    join_columns: &[Arc<dyn PhysicalPipedExpr>],
+    context: &PExecutionContext,
+    chunk: &DataChunk,
+    hb: &RandomState,
+) -> PolarsResult<IdxCa> {
+    let join_series = join_columns
+        .iter()
+        .map(|phys_e| {
+            let s = phys_e.evaluate(chunk, context.execution_state.as_any())?;
+            Ok(s.to_physical_repr().rechunk())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut hashes = Vec::with_capacity(chunk.data.height());
+    hash_series(&join_series, &mut hashes, hb);
+    Ok(hashes
+        .into_iter()
+        .map(|h| hash_to_partition(h, PARTITION_SIZE) as IdxSize)
+        .collect())
+}
+
+/// Spill the rows of `chunk` to the partitions of their join keys.
+pub(super) fn spill_partitioned(
+    io_thread: &IOThread,
+    join_columns: &[Arc<dyn PhysicalPipedExpr>],
+    context: &PExecutionContext,
+    chunk: DataChunk,
+    hb: &RandomState,
+) -> PolarsResult<()> {
+    let partitions = partition_by_keys(join_columns, context, &chunk, hb)?;
+    let (iter, partitions) = partition_df(chunk.data, &partitions)?;
+    io_thread.dump_iter(Some(partitions), iter);
+    Ok(())
+}
+
+/// Read all files that were spilled to `partition`, if any.
+fn read_partition(io_thread: &IOThread, partition: usize) -> PolarsResult<Option<DataFrame>> {
+    let dir = io_thread.dir.join(format!("{partition}"));
+    if !dir.exists() {
+        return Ok(None);
+    }
+    let dfs = std::fs::read_dir(dir)?
+        .map(|entry| read_df(&entry?.path()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(Some(accumulate_dataframes_vertical_unchecked(dfs)))
+}
+
+#[derive(Clone)]
+pub struct OocJoinProbe {
+    // the spilled build side
+    build_io_thread: Arc<IOThread>,
+    // the spilled probe side, created once the schema of the probe side is known
+    probe_io_thread: Arc<Mutex<Option<Arc<IOThread>>>>,
+    // the next partition that is joined by one of the threads
+    next_partition: Arc<AtomicUsize>,
+    hb: RandomState,
+    join_columns_build: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    join_columns_probe: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    suffix: Arc<str>,
+    how: JoinType,
+    // the join order is swapped, the probe side is the left table of the output
+    swapped: bool,
+}
+
+impl OocJoinProbe {
+    pub(super) fn new(
+        build_io_thread: IOThread,
+        hb: RandomState,
+        join_columns_build: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        join_columns_probe: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        suffix: Arc<str>,
+        how: JoinType,
+        swapped: bool,
+    ) -> Self {
+        OocJoinProbe {
+            build_io_thread: Arc::new(build_io_thread),
+            probe_io_thread: Default::default(),
+            next_partition: Default::default(),
+            hb,
+            join_columns_build,
+            join_columns_probe,
+            suffix,
+            how,
+            swapped,
+        }
+    }
+
+    fn join_partition(
+        &self,
+        context: &PExecutionContext,
+        build: DataFrame,
+        probe: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        let evaluate = |df: &DataFrame, exprs: &[Arc<dyn PhysicalPipedExpr>]| {
+            let chunk = DataChunk {
+                data: df.clone(),
+                chunk_index: 0,
+            };
+            exprs
+                .iter()
+                .map(|phys_e| phys_e.evaluate(&chunk, context.execution_state.as_any()))
+                .collect::<PolarsResult<Vec<_>>>()
+        };
+        let build_keys = evaluate(&build, &self.join_columns_build)?;
+        let probe_keys = evaluate(&probe, &self.join_columns_probe)?;
+
+        let (left, right, left_keys, right_keys) = if self.swapped {
+            (probe, build, probe_keys, build_keys)
+        } else {
+            (build, probe, build_keys, probe_keys)
+        };
+        left._join_impl(
+            &right,
+            left_keys,
+            right_keys,
+            self.how.clone(),
+            JoinAlgorithm::Hash,
+            JoinValidation::ManyToMany,
+            Some(self.suffix.to_string()),
+            None,
+            false,
+            false,
+        )
+    }
+}
+
+impl Operator for OocJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        if chunk.is_empty() {
+            // nothing to spill, but the output must have the schema of the join
+            let build = DataFrame::from(self.build_io_thread.schema.as_ref());
+            let out = self.join_partition(context, build, chunk.data.clone())?;
+            return Ok(OperatorResult::Finished(chunk.with_data(out)));
+        }
+        let probe_io_thread = {
+            let mut lock = self.probe_io_thread.lock().unwrap();
+            if lock.is_none() {
+                let schema = Arc::new(chunk.data.schema());
+                *lock = Some(Arc::new(IOThread::try_new(schema, "join")?));
+            }
+            lock.as_ref().unwrap().clone()
+        };
+        spill_partitioned(
+            &probe_io_thread,
+            &self.join_columns_probe,
+            context,
+            chunk.clone(),
+            &self.hb,
+        )?;
+        // the output is produced when the operator is flushed
+        Ok(OperatorResult::NeedsNewData)
+    }
+
+    fn flush(&mut self, context: &PExecutionContext) -> PolarsResult<Option<DataChunk>> {
+        let Some(probe_io_thread) = self.probe_io_thread.lock().unwrap().clone() else {
+            // the probe side was empty
+            return Ok(None);
+        };
+        block_thread_until_io_thread_done(&probe_io_thread);
+
+        // the threads take the partitions one by one, until all are joined
+        loop {
+            let partition = self.next_partition.fetch_add(1, Ordering::Relaxed);
+            if partition >= PARTITION_SIZE {
+                return Ok(None);
+            }
+            let Some(probe) = read_partition(&probe_io_thread, partition)? else {
+                continue;
+            };
+            // an empty build partition still gives the output its schema
+            let build = read_partition(&self.build_io_thread, partition)?
+                .unwrap_or_else(|| DataFrame::from(self.build_io_thread.schema.as_ref()));
+            let out = self.join_partition(context, build, probe)?;
+            return Ok(Some(DataChunk {
+                data: out,
+                chunk_index: partition as IdxSize,
+            }));
+        }
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "ooc_join_probe"
+    }
+}
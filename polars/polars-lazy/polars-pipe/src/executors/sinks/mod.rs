@@ -1,5 +1,6 @@
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 mod file_sink;
+mod gather_every;
 pub(crate) mod groupby;
 mod io;
 mod joins;
@@ -9,14 +10,17 @@ mod reproject;
 mod slice;
 mod sort;
 mod utils;
+mod window;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 pub(crate) use file_sink::*;
+pub(crate) use gather_every::*;
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
 pub(crate) use reproject::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
+pub(crate) use window::*;
 
 // We must strike a balance between cache coherence and resizing costs.
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
@@ -1,22 +1,15 @@
-use std::path::Path;
-
 use polars_core::prelude::*;
 use polars_core::utils::_split_offsets;
 use polars_core::POOL;
-use polars_io::ipc::IpcReader;
-use polars_io::SerReader;
 use polars_ops::prelude::*;
 use rayon::prelude::*;
 
-use crate::executors::sinks::io::{block_thread_until_io_thread_done, DfIter, IOThread};
+use crate::executors::sinks::io::{
+    block_thread_until_io_thread_done, partition_df, read_df, IOThread,
+};
 use crate::executors::sinks::sort::source::SortSource;
 use crate::operators::FinalizedSink;
 
-pub(super) fn read_df(path: &Path) -> PolarsResult<DataFrame> {
-    let file = std::fs::File::open(path)?;
-    IpcReader::new(file).set_rechunk(false).finish()
-}
-
 pub(super) fn sort_ooc(
     io_thread: &IOThread,
     partitions: Series,
@@ -85,26 +78,3 @@ fn det_partitions(s: &Series, partitions: &Series, descending: bool) -> IdxCa {
 
     search_sorted(partitions, &s, SearchSortedSide::Any, descending).unwrap()
 }
-
-fn partition_df(df: DataFrame, partitions: &IdxCa) -> PolarsResult<(DfIter, IdxCa)> {
-    let groups = partitions.group_tuples(false, false)?;
-    let partitions = unsafe { partitions.clone().into_series().agg_first(&groups) };
-    let partitions = partitions.idx().unwrap().clone();
-
-    let out = match groups {
-        GroupsProxy::Idx(idx) => {
-            let iter = idx.into_iter().map(move |(_, group)| {
-                // groups are in bounds
-                unsafe { df._take_unchecked_slice(&group, false) }
-            });
-            Box::new(iter) as DfIter
-        }
-        GroupsProxy::Slice { groups, .. } => {
-            let iter = groups
-                .into_iter()
-                .map(move |[first, len]| df.slice(first as i64, len as usize));
-            Box::new(iter) as DfIter
-        }
-    };
-    Ok((out, partitions))
-}
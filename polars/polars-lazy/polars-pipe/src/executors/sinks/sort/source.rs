@@ -5,7 +5,7 @@ use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
 use polars_core::POOL;
 use rayon::prelude::*;
 
-use crate::executors::sinks::sort::ooc::read_df;
+use crate::executors::sinks::io::read_df;
 use crate::executors::sinks::sort::sink::sort_accumulated;
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
 
@@ -18,3 +18,32 @@ pub(super) fn load_vec<T, F: Fn() -> T>(partitions: usize, item: F) -> Vec<T> {
     }
     buf
 }
+
+/// The `[offset, len]` of the runs of rows with equal keys, where `None == None`. The keys of a
+/// sorted input are contiguous, so these are its groups.
+pub(super) fn key_runs(keys: &[Series]) -> PolarsResult<Vec<[IdxSize; 2]>> {
+    let len = keys[0].len();
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    // `true` if row `i + 1` starts a new run
+    let mut boundaries = BooleanChunked::full("", false, len - 1);
+    for s in keys {
+        let differs = s
+            .slice(1, len - 1)
+            .not_equal_missing(&s.slice(0, len - 1))?;
+        boundaries = &boundaries | &differs;
+    }
+
+    let mut runs = Vec::new();
+    let mut start = 0 as IdxSize;
+    for (i, new_run) in boundaries.into_no_null_iter().enumerate() {
+        if new_run {
+            let end = i as IdxSize + 1;
+            runs.push([start, end - start]);
+            start = end;
+        }
+    }
+    runs.push([start, len as IdxSize - start]);
+    Ok(runs)
+}
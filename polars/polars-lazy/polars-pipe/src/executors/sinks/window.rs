@@ -0,0 +1,168 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
+use polars_core::POOL;
+
+use super::utils::key_runs;
+use crate::operators::{
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext,
+    Sink, SinkResult, Source, SourceResult,
+};
+
+/// Evaluates a projection or `with_columns` that has window functions (`over`).
+///
+/// This is a pipeline breaker. A partition of a window can span any number of chunks, and the
+/// chunks arrive out of order on different threads, so all chunks are buffered until the input
+/// is finished. The windows are not evaluated while sinking, because the threads share the
+/// cache of the window groups.
+///
+/// If the input is sorted by the partition keys, e.g. by a streaming sort, the partitions are
+/// contiguous and the source of this sink evaluates the windows per batch of complete
+/// partitions, so only one batch is evaluated at a time. Otherwise the windows are evaluated on
+/// all data at once.
+pub struct WindowSink {
+    chunks: Vec<DataChunk>,
+    // the projection or hstack that evaluates the window functions
+    operator: Box<dyn Operator>,
+    partition_by: Arc<[Arc<str>]>,
+    sorted: bool,
+}
+
+impl WindowSink {
+    pub(crate) fn new(
+        operator: Box<dyn Operator>,
+        partition_by: Arc<[Arc<str>]>,
+        sorted: bool,
+    ) -> Self {
+        WindowSink {
+            chunks: vec![],
+            operator,
+            partition_by,
+            sorted,
+        }
+    }
+}
+
+impl Sink for WindowSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 || self.chunks.is_empty() {
+            self.chunks.push(chunk);
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<WindowSink>().unwrap();
+        self.chunks.append(&mut other.chunks);
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(WindowSink::new(
+            self.operator.split(thread_no),
+            self.partition_by.clone(),
+            self.sorted,
+        ))
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        Ok(FinalizedSink::Source(Box::new(WindowSource {
+            chunks: chunks.into_iter(),
+            operator: self.operator.split(0),
+            partition_by: self.partition_by.clone(),
+            sorted: self.sorted,
+            pending: None,
+            chunk_index: 0,
+        })))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "window_sink"
+    }
+}
+
+struct WindowSource {
+    chunks: std::vec::IntoIter<DataChunk>,
+    operator: Box<dyn Operator>,
+    partition_by: Arc<[Arc<str>]>,
+    sorted: bool,
+    // the rows of the last partition seen, which may continue in the next chunk
+    pending: Option<DataFrame>,
+    chunk_index: IdxSize,
+}
+
+impl WindowSource {
+    fn evaluate(&mut self, context: &PExecutionContext, df: DataFrame) -> PolarsResult<DataFrame> {
+        // the window functions cache their groups, which differ per batch
+        context.execution_state.clear_window_cache();
+        let chunk = DataChunk::new(self.chunk_index, df);
+        match self.operator.execute(context, &chunk)? {
+            OperatorResult::Finished(chunk) => Ok(chunk.data),
+            _ => unreachable!(),
+        }
+    }
+
+    fn finish_batch(&mut self, dfs: Vec<DataFrame>) -> Vec<DataChunk> {
+        let chunk_index = self.chunk_index;
+        self.chunk_index += dfs.len() as IdxSize;
+        dfs.into_iter()
+            .enumerate()
+            .map(|(i, df)| DataChunk::new(chunk_index + i as IdxSize, df))
+            .collect()
+    }
+}
+
+impl Source for WindowSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        if !self.sorted {
+            if self.chunks.as_slice().is_empty() {
+                return Ok(SourceResult::Finished);
+            }
+            let df = chunks_to_df_unchecked(std::mem::take(&mut self.chunks).collect());
+            let mut out = self.evaluate(context, df)?;
+            let dfs = split_df(&mut out, POOL.current_num_threads())?;
+            return Ok(SourceResult::GotMoreData(self.finish_batch(dfs)));
+        }
+
+        // collect chunks until there is a complete partition
+        loop {
+            let Some(chunk) = self.chunks.next() else {
+                return match self.pending.take() {
+                    Some(df) => {
+                        let out = self.evaluate(context, df)?;
+                        Ok(SourceResult::GotMoreData(self.finish_batch(vec![out])))
+                    }
+                    None => Ok(SourceResult::Finished),
+                };
+            };
+            let df = match self.pending.take() {
+                Some(pending) => accumulate_dataframes_vertical_unchecked([pending, chunk.data]),
+                None => chunk.data,
+            };
+            let keys = df.select_series(self.partition_by.as_ref())?;
+            let runs = key_runs(&keys)?;
+            // the last partition may continue in the next chunk
+            match runs.last() {
+                Some([offset, _]) if *offset > 0 => {
+                    let offset = *offset as i64;
+                    self.pending = Some(df.slice(offset, df.height() - offset as usize));
+                    let out = self.evaluate(context, df.slice(0, offset as usize))?;
+                    return Ok(SourceResult::GotMoreData(self.finish_batch(vec![out])));
+                }
+                _ => self.pending = Some(df),
+            }
+        }
+    }
+
+    fn fmt(&self) -> &str {
+        "window_source"
+    }
+}
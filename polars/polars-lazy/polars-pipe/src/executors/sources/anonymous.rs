@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::SchemaRef;
+use polars_plan::prelude::{AnonymousScan, AnonymousScanBatches, AnonymousScanOptions};
+use polars_utils::IdxSize;
+
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// A source that streams the batches of an [`AnonymousScan`] that allows streaming.
+pub(crate) struct AnonymousSource {
+    batches: AnonymousScanBatches,
+    output_schema: Option<SchemaRef>,
+    chunk_index: IdxSize,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        options: AnonymousScanOptions,
+    ) -> PolarsResult<Self> {
+        let output_schema = options.output_schema.clone();
+        Ok(AnonymousSource {
+            batches: function.scan_batches(options)?,
+            output_schema,
+            chunk_index: 0,
+        })
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let mut data = match self.batches.next() {
+            Some(data) => data?,
+            None => return Ok(SourceResult::Finished),
+        };
+        // select the projected columns, in case the scan returned all columns or reordered them
+        if let Some(schema) = &self.output_schema {
+            if !data
+                .get_column_names()
+                .into_iter()
+                .eq(schema.iter_names().map(|name| name.as_str()))
+            {
+                data = data.select(schema.iter_names())?;
+            }
+        }
+        let chunk = DataChunk {
+            chunk_index: self.chunk_index,
+            data,
+        };
+        self.chunk_index += 1;
+        Ok(SourceResult::GotMoreData(vec![chunk]))
+    }
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
@@ -1,10 +1,14 @@
-use std::fs::File;
+use std::io::Cursor;
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
 use polars_core::export::arrow::Either;
 use polars_core::POOL;
 use polars_io::csv::read_impl::{BatchedCsvReaderMmap, BatchedCsvReaderRead};
 use polars_io::csv::{CsvEncoding, CsvReader};
+use polars_io::follow::FileFollower;
+use polars_io::mmap::MmapBytesReader;
+use polars_io::RowCount;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::CsvParserOptions;
 
@@ -15,7 +19,7 @@ pub(crate) struct CsvSource {
     #[allow(dead_code)]
     // this exist because we need to keep ownership
     schema: SchemaRef,
-    reader: *mut CsvReader<'static, File>,
+    reader: *mut CsvReader<'static, Box<dyn MmapBytesReader>>,
     batched_reader: Either<*mut BatchedCsvReaderMmap<'static>, *mut BatchedCsvReaderRead<'static>>,
     n_threads: usize,
     chunk_index: IdxSize,
@@ -26,6 +30,7 @@ impl CsvSource {
         path: PathBuf,
         schema: SchemaRef,
         options: CsvParserOptions,
+        cloud_options: Option<CloudOptions>,
         verbose: bool,
     ) -> PolarsResult<Self> {
         let mut with_columns = options.with_columns;
@@ -53,8 +58,7 @@ impl CsvSource {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
         }
 
-        let reader = CsvReader::from_path(&path)
-            .unwrap()
+        let reader = CsvReader::from_uri(&path, cloud_options.as_ref())?
             .has_header(options.has_header)
             .with_schema(schema.clone())
             .with_delimiter(options.delimiter)
@@ -72,10 +76,12 @@ impl CsvSource {
             .with_rechunk(options.rechunk)
             .with_chunk_size(chunk_size)
             .with_row_count(options.row_count)
-            .with_try_parse_dates(options.try_parse_dates);
+            .with_try_parse_dates(options.try_parse_dates)
+            .with_datetime_formats(options.datetime_formats)
+            .with_number_format(options.number_format);
 
         let reader = Box::new(reader);
-        let reader = Box::leak(reader) as *mut CsvReader<'static, File>;
+        let reader = Box::leak(reader) as *mut CsvReader<'static, Box<dyn MmapBytesReader>>;
 
         let batched_reader = if options.low_memory {
             let batched_reader = unsafe { Box::new((*reader).batched_borrowed_read()?) };
@@ -151,3 +157,81 @@ impl Source for CsvSource {
         "csv"
     }
 }
+
+/// A source that follows a CSV file that is appended to, see [`polars_io::FollowOptions`].
+pub(crate) struct CsvFollowSource {
+    follower: FileFollower,
+    schema: SchemaRef,
+    options: CsvParserOptions,
+    n_read: IdxSize,
+    chunk_index: IdxSize,
+}
+
+impl CsvFollowSource {
+    pub(crate) fn new(
+        path: PathBuf,
+        schema: SchemaRef,
+        mut options: CsvParserOptions,
+    ) -> PolarsResult<Self> {
+        let mut follow = options.follow.take().unwrap();
+        follow.max_rows = match (follow.max_rows, options.n_rows) {
+            (Some(max_rows), Some(n_rows)) => Some(max_rows.min(n_rows)),
+            (max_rows, n_rows) => max_rows.or(n_rows),
+        };
+        let skip_lines = options.skip_rows + usize::from(options.has_header);
+        let follower = FileFollower::new(&path, options.eol_char, skip_lines, follow)?;
+        Ok(CsvFollowSource {
+            follower,
+            schema,
+            options,
+            n_read: 0,
+            chunk_index: 0,
+        })
+    }
+}
+
+impl Source for CsvFollowSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let lines = match self.follower.next_lines()? {
+            Some(lines) => lines,
+            None => return Ok(SourceResult::Finished),
+        };
+        let with_columns = self
+            .options
+            .with_columns
+            .as_ref()
+            .filter(|columns| !columns.is_empty())
+            .map(|columns| columns.as_ref().clone());
+        let row_count = self.options.row_count.as_ref().map(|rc| RowCount {
+            name: rc.name.clone(),
+            offset: rc.offset + self.n_read,
+        });
+        let data = CsvReader::new(Cursor::new(lines))
+            .has_header(false)
+            .with_schema(self.schema.clone())
+            .with_delimiter(self.options.delimiter)
+            .with_ignore_errors(self.options.ignore_errors)
+            .with_columns(with_columns)
+            .with_null_values(self.options.null_values.clone())
+            .with_comment_char(self.options.comment_char)
+            .with_quote_char(self.options.quote_char)
+            .with_end_of_line_char(self.options.eol_char)
+            .with_encoding(self.options.encoding)
+            .with_row_count(row_count)
+            .with_try_parse_dates(self.options.try_parse_dates)
+            .with_datetime_formats(self.options.datetime_formats.clone())
+            .with_number_format(self.options.number_format.clone())
+            .finish()?;
+        self.n_read += data.height() as IdxSize;
+
+        let chunk = DataChunk {
+            chunk_index: self.chunk_index,
+            data,
+        };
+        self.chunk_index += 1;
+        Ok(SourceResult::GotMoreData(vec![chunk]))
+    }
+    fn fmt(&self) -> &str {
+        "csv-follow"
+    }
+}
@@ -1,3 +1,4 @@
+mod anonymous;
 #[cfg(feature = "csv")]
 mod csv;
 mod frame;
@@ -7,8 +8,9 @@ mod parquet;
 mod reproject;
 mod union;
 
+pub(crate) use anonymous::*;
 #[cfg(feature = "csv")]
-pub(crate) use csv::CsvSource;
+pub(crate) use csv::{CsvFollowSource, CsvSource};
 pub(crate) use frame::*;
 pub(crate) use ipc_one_shot::*;
 #[cfg(feature = "parquet")]
@@ -62,9 +62,10 @@ impl ParquetSource {
                     .batched(chunk_size)?
             }
         } else {
-            let file = std::fs::File::open(path).unwrap();
+            let file = std::fs::File::open(&path).unwrap();
 
             ParquetReader::new(file)
+                .with_metadata(polars_io::parquet::read_metadata_cached(&path)?)
                 .with_n_rows(options.n_rows)
                 .with_row_count(options.row_count)
                 .with_projection(projection)
@@ -2,6 +2,10 @@ use std::any::Any;
 
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
+
+    /// Clear the groups that window functions cache, so that they can be evaluated on another
+    /// `DataFrame`.
+    fn clear_window_cache(&self);
 }
 
 pub struct PExecutionContext {
@@ -14,6 +14,13 @@ pub trait Operator: Send + Sync {
         chunk: &DataChunk,
     ) -> PolarsResult<OperatorResult>;
 
+    /// Called repeatedly after all chunks were pushed through the operator, until it returns
+    /// `None`. Operators that hold back their output, e.g. a join that spilled to disk, hand
+    /// it over here.
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<Option<DataChunk>> {
+        Ok(None)
+    }
+
     fn split(&self, thread_no: usize) -> Box<dyn Operator>;
 
     fn fmt(&self) -> &str;
@@ -5,7 +5,7 @@ use polars_core::with_match_physical_integer_polars_type;
 use polars_plan::prelude::*;
 
 use crate::executors::sinks::groupby::aggregates::convert_to_hash_agg;
-use crate::executors::sinks::groupby::GenericGroupby2;
+use crate::executors::sinks::groupby::{GenericGroupby2, SortedGroupbySink};
 use crate::executors::sinks::*;
 use crate::executors::{operators, sources};
 use crate::expressions::PhysicalPipedExpr;
@@ -27,6 +27,92 @@ where
         .collect()
 }
 
+/// The partition keys of the window functions (`over`) in `exprs`, if they can be evaluated by
+/// the streaming window sink: all windows are partitioned by the same columns and map the
+/// results of the groups back to the rows.
+pub fn window_partition_by(exprs: &[Node], expr_arena: &Arena<AExpr>) -> Option<Vec<Arc<str>>> {
+    let mut keys: Option<Vec<Arc<str>>> = None;
+    for node in exprs {
+        for (_, ae) in expr_arena.iter(*node) {
+            if let AExpr::Window {
+                partition_by,
+                options,
+                ..
+            } = ae
+            {
+                if options.explode || options.mapping != WindowMapping::GroupsToRows {
+                    return None;
+                }
+                let names = partition_by
+                    .iter()
+                    .map(|node| match expr_arena.get(*node) {
+                        AExpr::Column(name) => Some(name.clone()),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                match &keys {
+                    Some(keys) if *keys != names => return None,
+                    _ => keys = Some(names),
+                }
+            }
+        }
+    }
+    keys
+}
+
+/// Whether the output of `node` is sorted by the columns `keys` (in any order), so that the rows
+/// with equal keys are contiguous.
+pub fn is_sorted_by(
+    node: Node,
+    keys: &[Arc<str>],
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        Sort { by_column, .. } => {
+            let by = by_column
+                .iter()
+                .take(keys.len())
+                .map(|node| match expr_arena.get(*node) {
+                    AExpr::Column(name) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            match by {
+                Some(by) => by.len() == keys.len() && keys.iter().all(|key| by.contains(key)),
+                None => false,
+            }
+        }
+        // a filter keeps the order
+        Selection { input, .. } => is_sorted_by(*input, keys, lp_arena, expr_arena),
+        _ => false,
+    }
+}
+
+/// Whether the output of `node` is sorted in ascending order by the column `key`.
+pub fn is_sorted_ascending_by(
+    node: Node,
+    key: &str,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        Sort {
+            by_column, args, ..
+        } => {
+            matches!(
+                by_column.first().map(|node| expr_arena.get(*node)),
+                Some(AExpr::Column(name)) if name.as_ref() == key
+            ) && !args.descending.first().copied().unwrap_or(false)
+        }
+        // a filter keeps the order
+        Selection { input, .. } => is_sorted_ascending_by(*input, key, lp_arena, expr_arena),
+        _ => false,
+    }
+}
+
 fn get_source<F>(
     source: ALogicalPlan,
     operator_objects: &mut Vec<Box<dyn Operator>>,
@@ -62,6 +148,23 @@ where
             }
             Ok(Box::new(sources::DataFrameSource::from_df(df)) as Box<dyn Source>)
         }
+        AnonymousScan {
+            function,
+            predicate,
+            output_schema,
+            options,
+            ..
+        } => {
+            // the predicate is applied to the batches, as the scan may not support it
+            if let (true, Some(predicate)) = (push_predicate, predicate) {
+                let predicate = to_physical(predicate, expr_arena, output_schema.as_ref())?;
+                let op = operators::FilterOperator { predicate };
+                let op = Box::new(op) as Box<dyn Operator>;
+                operator_objects.push(op)
+            }
+            let src = sources::AnonymousSource::new(function, options)?;
+            Ok(Box::new(src) as Box<dyn Source>)
+        }
         #[cfg(feature = "csv")]
         CsvScan {
             path,
@@ -69,7 +172,7 @@ where
             options,
             predicate,
             output_schema,
-            ..
+            cloud_options,
         } => {
             // add predicate to operators
             if let (true, Some(predicate)) = (push_predicate, predicate) {
@@ -78,7 +181,12 @@ where
                 let op = Box::new(op) as Box<dyn Operator>;
                 operator_objects.push(op)
             }
-            let src = sources::CsvSource::new(path, file_info.schema, options, verbose)?;
+            if options.follow.is_some() {
+                let src = sources::CsvFollowSource::new(path, file_info.schema, options)?;
+                return Ok(Box::new(src) as Box<dyn Source>);
+            }
+            let src =
+                sources::CsvSource::new(path, file_info.schema, options, cloud_options, verbose)?;
             Ok(Box::new(src) as Box<dyn Source>)
         }
         #[cfg(feature = "parquet")]
@@ -122,20 +230,32 @@ where
 {
     use ALogicalPlan::*;
     let out = match lp_arena.get(node) {
-        #[cfg(any(feature = "parquet", feature = "ipc"))]
+        #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
         FileSink { input, payload } => {
             let path = payload.path.as_ref().as_path();
             let input_schema = lp_arena.get(*input).schema(lp_arena);
             match &payload.file_type {
                 #[cfg(feature = "parquet")]
-                FileType::Parquet(options) => {
-                    Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                        as Box<dyn Sink>
-                }
+                FileType::Parquet(options) => Box::new(ParquetSink::new(
+                    path,
+                    *options,
+                    payload.cloud_options.as_ref(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn Sink>,
                 #[cfg(feature = "ipc")]
-                FileType::Ipc(options) => {
-                    Box::new(IpcSink::new(path, *options, input_schema.as_ref())?) as Box<dyn Sink>
-                }
+                FileType::Ipc(options) => Box::new(IpcSink::new(
+                    path,
+                    *options,
+                    payload.cloud_options.as_ref(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn Sink>,
+                #[cfg(feature = "csv")]
+                FileType::Csv(options) => Box::new(CsvSink::new(
+                    path,
+                    options.clone(),
+                    payload.cloud_options.as_ref(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn Sink>,
             }
         }
         Join {
@@ -184,6 +304,7 @@ where
                         swapped,
                         join_columns_left,
                         join_columns_right,
+                        options.low_memory,
                     ))
                 }
                 _ => unimplemented!(),
@@ -193,6 +314,10 @@ where
             let slice = SliceSink::new(*offset as u64, *len as usize);
             Box::new(slice) as Box<dyn Sink>
         }
+        MapFunction {
+            function: FunctionNode::GatherEvery { n, offset },
+            ..
+        } => Box::new(GatherEverySink::new(*n, *offset)) as Box<dyn Sink>,
         Sort {
             input,
             by_column,
@@ -302,12 +427,21 @@ where
 
             Box::new(ReProjectSink::new(input_schema, groupby_sink))
         }
+        // the window functions of a projection that aren't elementwise
+        lp @ (Projection { input, .. } | HStack { input, .. }) => {
+            let input = *input;
+            let partition_by = window_partition_by(&lp.get_exprs(), expr_arena).unwrap();
+            let sorted = is_sorted_by(input, &partition_by, lp_arena, expr_arena);
+            let operator = get_operator(node, lp_arena, expr_arena, to_physical)?;
+            Box::new(WindowSink::new(operator, Arc::from(partition_by), sorted)) as Box<dyn Sink>
+        }
         Aggregate {
             input,
             keys,
             aggs,
             schema: output_schema,
             options,
+            maintain_order,
             ..
         } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena).as_ref().clone();
@@ -329,7 +463,30 @@ where
             }
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
+            // the planner only streams a dynamic groupby without keys of a sorted index column
+            #[cfg(feature = "dynamic_groupby")]
+            if let Some(dynamic) = &options.dynamic {
+                let index_dtype = input_schema.try_get(&dynamic.index_column)?.clone();
+                return Ok(Box::new(groupby::DynamicGroupbySink::new(
+                    dynamic.clone(),
+                    index_dtype,
+                    aggregation_columns,
+                    Arc::from(agg_fns),
+                    output_schema.clone(),
+                    options.slice,
+                )));
+            }
+
+            if *maintain_order {
+                // the planner only streams an order maintaining groupby of sorted keys
+                Box::new(SortedGroupbySink::new(
+                    key_columns,
+                    aggregation_columns,
+                    Arc::from(agg_fns),
+                    output_schema.clone(),
+                    options.slice,
+                ))
+            } else if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
                 Box::new(GenericGroupby2::new(
                     key_columns,
                     aggregation_columns,
@@ -426,6 +583,13 @@ where
             };
             Box::new(op) as Box<dyn Operator>
         }
+        MapFunction {
+            function: FunctionNode::GatherEvery { n, offset },
+            ..
+        } => {
+            let op = operators::GatherEveryOperator::new(*n, *offset);
+            Box::new(op) as Box<dyn Operator>
+        }
         MapFunction { function, .. } => {
             let op = operators::FunctionOperator::new(function.clone());
             Box::new(op) as Box<dyn Operator>
@@ -467,6 +631,14 @@ where
                 true,
                 verbose,
             )?,
+            lp @ AnonymousScan { .. } => get_source(
+                lp.clone(),
+                &mut operator_objects,
+                expr_arena,
+                &to_physical,
+                true,
+                verbose,
+            )?,
             #[cfg(feature = "csv")]
             lp @ CsvScan { .. } => get_source(
                 lp.clone(),
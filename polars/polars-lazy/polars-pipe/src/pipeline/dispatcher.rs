@@ -150,6 +150,41 @@ impl PipeLine {
         out
     }
 
+    /// Flush the operators in order. The output of an operator is pushed through the
+    /// operators that follow it, before those are flushed themselves.
+    fn flush_operators(
+        &mut self,
+        sink: &mut [Box<dyn Sink>],
+        ec: &PExecutionContext,
+        operator_start: usize,
+        operator_end: usize,
+    ) -> PolarsResult<()> {
+        let mut operators = std::mem::take(&mut self.operators);
+        let out = POOL.install(|| {
+            sink.par_iter_mut()
+                .zip(operators.par_iter_mut())
+                .try_for_each(|(sink, operator_pipe)| {
+                    let operator_pipe = &mut operator_pipe[operator_start..operator_end];
+                    for i in 0..operator_pipe.len() {
+                        let (op, remaining) = operator_pipe[i..].split_first_mut().unwrap();
+                        while let Some(chunk) = op.flush(ec)? {
+                            let sink_result = if remaining.is_empty() {
+                                sink.sink(ec, chunk)?
+                            } else {
+                                self.push_operators(chunk, ec, remaining, sink)?
+                            };
+                            if let SinkResult::Finished = sink_result {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Ok(())
+                })
+        });
+        self.operators = operators;
+        out
+    }
+
     fn push_operators(
         &self,
         chunk: DataChunk,
@@ -228,6 +263,8 @@ impl PipeLine {
                 }
             }
 
+            self.flush_operators(&mut sink, ec, operator_start, operator_end)?;
+
             let mut reduced_sink = POOL
                 .install(|| {
                     sink.into_par_iter().reduce_with(|mut a, mut b| {
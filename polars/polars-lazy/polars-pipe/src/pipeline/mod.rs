@@ -2,7 +2,10 @@ mod config;
 mod convert;
 mod dispatcher;
 
-pub use convert::{create_pipeline, get_dummy_operator, get_operator, get_sink, swap_join_order};
+pub use convert::{
+    create_pipeline, get_dummy_operator, get_operator, get_sink, is_sorted_ascending_by,
+    is_sorted_by, swap_join_order, window_partition_by,
+};
 pub use dispatcher::PipeLine;
 use polars_core::prelude::*;
 use polars_core::POOL;
@@ -20,6 +23,7 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 // env vars
 pub(crate) static FORCE_OOC_GROUPBY: &str = "POLARS_FORCE_OOC_GROUPBY";
 pub(crate) static FORCE_OOC_SORT: &str = "POLARS_FORCE_OOC_SORT";
+pub(crate) static FORCE_OOC_JOIN: &str = "POLARS_FORCE_OOC_JOIN";
 
 /// ideal chunk size we strive to have
 /// scale the chunk size depending on the number of
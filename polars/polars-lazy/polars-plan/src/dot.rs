@@ -23,11 +23,11 @@ impl Expr {
         id: usize,
     ) -> std::fmt::Result {
         if id == 0 {
-            writeln!(acc_str, "graph expr {{")
+            writeln!(acc_str, "digraph expr {{")
         } else {
             writeln!(
                 acc_str,
-                "\"{}\" -- \"{}\"",
+                "\"{}\" -> \"{}\"",
                 prev_node.replace('"', r#"\""#),
                 current_node.replace('"', r#"\""#)
             )
@@ -73,7 +73,7 @@ pub struct DotNode<'a> {
 impl LogicalPlan {
     fn write_single_node(&self, acc_str: &mut String, node: DotNode) -> std::fmt::Result {
         let fmt_node = node.fmt.replace('"', r#"\""#);
-        writeln!(acc_str, "graph  polars_query {{\n\"[{fmt_node}]\"")?;
+        writeln!(acc_str, "digraph polars_query {{\n\"[{fmt_node}]\"")?;
         Ok(())
     }
 
@@ -85,7 +85,7 @@ impl LogicalPlan {
         id_map: &mut PlHashMap<String, String>,
     ) -> std::fmt::Result {
         if current_node.id == 0 && current_node.branch == 0 {
-            writeln!(acc_str, "graph  polars_query {{")
+            writeln!(acc_str, "digraph polars_query {{")
         } else {
             let fmt_prev_node = prev_node.fmt.replace('"', r#"\""#);
             let fmt_current_node = current_node.fmt.replace('"', r#"\""#);
@@ -101,7 +101,7 @@ impl LogicalPlan {
                 (current_node.branch, current_node.id)
             );
 
-            writeln!(acc_str, "{} -- {}", &id_prev_node, &id_current_node)?;
+            writeln!(acc_str, "{} -> {}", &id_prev_node, &id_current_node)?;
 
             id_map.insert(id_current_node, fmt_current_node);
             id_map.insert(id_prev_node, fmt_prev_node);
@@ -140,6 +140,7 @@ impl LogicalPlan {
                 Path::new(""),
                 options.with_columns.as_deref().map(|cols| cols.as_slice()),
                 file_info.schema.len(),
+                Some(file_info.row_estimation),
                 &options.predicate,
                 branch,
                 id,
@@ -198,6 +199,7 @@ impl LogicalPlan {
                 Path::new(""),
                 options.with_columns.as_ref().map(|s| s.as_slice()),
                 options.schema.len(),
+                None,
                 &options.predicate,
                 branch,
                 id,
@@ -316,6 +318,7 @@ impl LogicalPlan {
                 input.dot(acc_str, (branch, id + 1), current_node, id_map)
             }
             DataFrameScan {
+                df,
                 schema,
                 projection,
                 selection,
@@ -328,7 +331,8 @@ impl LogicalPlan {
                 }
 
                 let pred = fmt_predicate(selection.as_ref());
-                let fmt = format!("TABLE\nπ {n_columns}/{total_columns};\nσ {pred};");
+                let rows = fmt_rows((Some(df.height()), df.height()));
+                let fmt = format!("TABLE\nπ {n_columns}/{total_columns};\nσ {pred};\nrows: {rows}");
                 let current_node = DotNode {
                     branch,
                     id,
@@ -354,6 +358,7 @@ impl LogicalPlan {
                 path.as_ref(),
                 options.with_columns.as_deref().map(|cols| cols.as_slice()),
                 file_info.schema.len(),
+                Some(file_info.row_estimation),
                 predicate,
                 branch,
                 id,
@@ -373,6 +378,7 @@ impl LogicalPlan {
                 path.as_ref(),
                 options.with_columns.as_deref().map(|cols| cols.as_slice()),
                 file_info.schema.len(),
+                Some(file_info.row_estimation),
                 predicate,
                 branch,
                 id,
@@ -392,6 +398,7 @@ impl LogicalPlan {
                 path.as_ref(),
                 options.with_columns.as_deref().map(|cols| cols.as_slice()),
                 file_info.schema.len(),
+                Some(file_info.row_estimation),
                 predicate,
                 branch,
                 id,
@@ -471,6 +478,7 @@ impl LogicalPlan {
         path: &Path,
         with_columns: Option<&[String]>,
         total_columns: usize,
+        row_estimation: Option<(Option<usize>, usize)>,
         predicate: &Option<P>,
         branch: usize,
         id: usize,
@@ -482,12 +490,14 @@ impl LogicalPlan {
         }
 
         let pred = fmt_predicate(predicate.as_ref());
+        let rows = row_estimation.map_or_else(|| "?".to_string(), fmt_rows);
         let fmt = format!(
-            "{name} SCAN {};\nπ {}/{};\nσ {}",
+            "{name} SCAN {};\nπ {}/{};\nσ {};\nrows: {}",
             path.to_string_lossy(),
             n_columns_fmt,
             total_columns,
             pred,
+            rows,
         );
         let current_node = DotNode {
             branch,
@@ -502,6 +512,15 @@ impl LogicalPlan {
     }
 }
 
+/// Format the (known, estimated) number of rows of a scan. Estimates are prefixed with `~`.
+fn fmt_rows(row_estimation: (Option<usize>, usize)) -> String {
+    match row_estimation {
+        (Some(known), _) => known.to_string(),
+        (None, usize::MAX) => "?".to_string(),
+        (None, estimated) => format!("~{estimated}"),
+    }
+}
+
 fn fmt_predicate<P: Display>(predicate: Option<&P>) -> String {
     if let Some(predicate) = predicate {
         let n = 25;
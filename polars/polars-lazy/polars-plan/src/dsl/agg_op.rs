@@ -0,0 +1,79 @@
+use polars_arrow::prelude::QuantileInterpolOptions;
+
+use super::*;
+
+/// An aggregation that is applied to every column of a selector, see
+/// [`AggOp::apply_all`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AggOp {
+    Count,
+    NullCount,
+    NUnique,
+    Min,
+    Max,
+    Sum,
+    Mean,
+    Median,
+    /// The standard deviation with the given delta degrees of freedom.
+    Std(u8),
+    /// The variance with the given delta degrees of freedom.
+    Var(u8),
+    /// The quantile with nearest interpolation, e.g. `Quantile(0.95)` for the 95th percentile.
+    Quantile(f64),
+    First,
+    Last,
+}
+
+impl AggOp {
+    /// The suffix of the names of the aggregated columns, e.g. `mean` or `quantile_0.95`.
+    pub fn name(&self) -> String {
+        use AggOp::*;
+        match self {
+            Count => "count".into(),
+            NullCount => "null_count".into(),
+            NUnique => "n_unique".into(),
+            Min => "min".into(),
+            Max => "max".into(),
+            Sum => "sum".into(),
+            Mean => "mean".into(),
+            Median => "median".into(),
+            Std(_) => "std".into(),
+            Var(_) => "var".into(),
+            Quantile(q) => format!("quantile_{q}"),
+            First => "first".into(),
+            Last => "last".into(),
+        }
+    }
+
+    /// Apply the aggregation to `expr`.
+    pub fn apply(&self, expr: Expr) -> Expr {
+        use AggOp::*;
+        match *self {
+            Count => expr.count(),
+            NullCount => expr.null_count(),
+            NUnique => expr.n_unique(),
+            Min => expr.min(),
+            Max => expr.max(),
+            Sum => expr.sum(),
+            Mean => expr.mean(),
+            Median => expr.median(),
+            Std(ddof) => expr.std(ddof),
+            Var(ddof) => expr.var(ddof),
+            Quantile(q) => expr.quantile(lit(q), QuantileInterpolOptions::Nearest),
+            First => expr.first(),
+            Last => expr.last(),
+        }
+    }
+
+    /// Apply every aggregation of `ops` to every column of the `selector`, e.g. `col("*")` or
+    /// [`dtype_cols`]. The aggregated columns are named `{column}_{op}`, see [`AggOp::name`],
+    /// and ordered by aggregation and then by column.
+    pub fn apply_all(selector: Expr, ops: &[AggOp]) -> Vec<Expr> {
+        ops.iter()
+            .map(|op| {
+                op.apply(selector.clone())
+                    .suffix(&format!("_{}", op.name()))
+            })
+            .collect()
+    }
+}
@@ -49,6 +49,20 @@ impl Expr {
         binary_expr(self, Operator::FloorDivide, rhs)
     }
 
+    /// Add `rhs` to `self`, where `null_strategy` decides if a null operand counts as zero or
+    /// makes the result null.
+    pub fn add_with(self, rhs: Self, null_strategy: NullStrategy) -> Self {
+        let (lhs, rhs) = fill_null_operands(self, rhs, null_strategy);
+        lhs + rhs
+    }
+
+    /// Subtract `rhs` from `self`, where `null_strategy` decides if a null operand counts as
+    /// zero or makes the result null.
+    pub fn sub_with(self, rhs: Self, null_strategy: NullStrategy) -> Self {
+        let (lhs, rhs) = fill_null_operands(self, rhs, null_strategy);
+        lhs - rhs
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow<E: Into<Expr>>(self, exponent: E) -> Self {
         Expr::Function {
@@ -139,3 +153,10 @@ impl Expr {
         self.map_private(FunctionExpr::Sign)
     }
 }
+
+fn fill_null_operands(lhs: Expr, rhs: Expr, null_strategy: NullStrategy) -> (Expr, Expr) {
+    match null_strategy {
+        NullStrategy::Ignore => (lhs.fill_null(lit(0)), rhs.fill_null(lit(0))),
+        NullStrategy::Propagate => (lhs, rhs),
+    }
+}
@@ -236,6 +236,33 @@ impl DateLikeNameSpace {
             )))
     }
 
+    /// Roll back to the first day of the month. The time of day of datetimes is preserved.
+    pub fn month_start(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::MonthStart))
+    }
+
+    /// Roll forward to the last day of the month. The time of day of datetimes is preserved.
+    pub fn month_end(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::MonthEnd))
+    }
+
+    /// Roll back to the first day of the quarter. The time of day of datetimes is preserved.
+    pub fn quarter_start(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::QuarterStart))
+    }
+
+    /// Add `n` calendar months. The [`AddMonthsStrategy`] determines what happens if the day of
+    /// the month doesn't exist in the target month, e.g. for Jan 31 + 1 month.
+    pub fn add_months(self, n: i64, strategy: AddMonthsStrategy) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::AddMonths(
+                n, strategy,
+            )))
+    }
+
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
     #[cfg(feature = "date_offset")]
@@ -256,6 +283,30 @@ impl DateLikeNameSpace {
             )))
     }
 
+    /// Interpret the wall time of every row in the time zone of that row, given by `time_zones`
+    /// (IANA names such as "Europe/Amsterdam"), and return the instants in UTC. `ambiguous`
+    /// determines the result of wall times that occur twice or not at all because of daylight
+    /// saving time.
+    #[cfg(feature = "timezones")]
+    pub fn replace_time_zone_by_column(self, time_zones: Expr, ambiguous: Ambiguous) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::ReplaceTimeZoneByColumn(ambiguous)),
+            &[time_zones],
+            false,
+        )
+    }
+
+    /// The wall time of every tz-aware datetime in the time zone of that row, given by
+    /// `time_zones` (IANA names such as "Europe/Amsterdam"). The result is tz-naive.
+    #[cfg(feature = "timezones")]
+    pub fn convert_time_zone_by_column(self, time_zones: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::ConvertTimeZoneByColumn),
+            &[time_zones],
+            false,
+        )
+    }
+
     pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
         self.0.map_many_private(
             FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu)),
@@ -261,13 +261,29 @@ pub enum AggExpr {
         input: Box<Expr>,
         propagate_nans: bool,
     },
+    /// The value of `input` at the minimum of `by`.
+    MinBy {
+        input: Box<Expr>,
+        by: Box<Expr>,
+    },
+    /// The value of `input` at the maximum of `by`.
+    MaxBy {
+        input: Box<Expr>,
+        by: Box<Expr>,
+    },
     Median(Box<Expr>),
     NUnique(Box<Expr>),
+    ApproxNUnique(Box<Expr>),
     First(Box<Expr>),
     Last(Box<Expr>),
+    /// The first non-null value; the `bool` indicates if its position is returned instead.
+    FirstNonNull(Box<Expr>, bool),
+    /// The last non-null value; the `bool` indicates if its position is returned instead.
+    LastNonNull(Box<Expr>, bool),
     Mean(Box<Expr>),
     Implode(Box<Expr>),
-    Count(Box<Expr>),
+    /// Count the values; the `bool` indicates if nulls are included.
+    Count(Box<Expr>, bool),
     Quantile {
         expr: Box<Expr>,
         quantile: Box<Expr>,
@@ -285,13 +301,18 @@ impl AsRef<Expr> for AggExpr {
         match self {
             Min { input, .. } => input,
             Max { input, .. } => input,
+            MinBy { input, .. } => input,
+            MaxBy { input, .. } => input,
             Median(e) => e,
             NUnique(e) => e,
+            ApproxNUnique(e) => e,
             First(e) => e,
             Last(e) => e,
+            FirstNonNull(e, _) => e,
+            LastNonNull(e, _) => e,
             Mean(e) => e,
             Implode(e) => e,
-            Count(e) => e,
+            Count(e, _) => e,
             Quantile { expr, .. } => expr,
             Sum(e) => e,
             AggGroups(e) => e,
@@ -379,22 +400,24 @@ pub enum Expr {
     Count,
     /// Take the nth column in the `DataFrame`
     Nth(i64),
-    // skipped fields must be last otherwise serde fails in pickle
-    #[cfg_attr(feature = "serde", serde(skip))]
-    RenameAlias {
-        function: SpecialEq<Arc<dyn RenameAliasFn>>,
-        expr: Box<Expr>,
-    },
-    #[cfg_attr(feature = "serde", serde(skip))]
+    /// Serialized by the name of the function, if it is registered with `register_udf`.
     AnonymousFunction {
         /// function arguments
         input: Vec<Expr>,
         /// function to apply
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialize::udf_function"))]
         function: SpecialEq<Arc<dyn SeriesUdf>>,
         /// output dtype of the function
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialize::udf_output_type"))]
         output_type: GetOutput,
         options: FunctionOptions,
     },
+    // skipped fields must be last otherwise serde fails in pickle
+    #[cfg_attr(feature = "serde", serde(skip))]
+    RenameAlias {
+        function: SpecialEq<Arc<dyn RenameAliasFn>>,
+        expr: Box<Expr>,
+    },
 }
 
 // TODO! derive. This is only a temporary fix
@@ -447,7 +470,9 @@ impl Expr {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Operator {
     Eq,
+    EqValidity,
     NotEq,
+    NotEqValidity,
     Lt,
     LtEq,
     Gt,
@@ -469,7 +494,9 @@ impl Display for Operator {
         use Operator::*;
         let tkn = match self {
             Eq => "==",
+            EqValidity => "==v",
             NotEq => "!=",
+            NotEqValidity => "!=v",
             Lt => "<",
             LtEq => "<=",
             Gt => ">",
@@ -494,7 +521,9 @@ impl Operator {
         matches!(
             self,
             Self::Eq
+                | Self::EqValidity
                 | Self::NotEq
+                | Self::NotEqValidity
                 | Self::Lt
                 | Self::LtEq
                 | Self::Gt
@@ -25,6 +25,7 @@ pub enum BooleanFunction {
     IsDuplicated,
     #[cfg(feature = "is_in")]
     IsIn,
+    AllNotNull,
 }
 
 impl BooleanFunction {
@@ -54,6 +55,7 @@ impl Display for BooleanFunction {
             IsDuplicated => "is_duplicated",
             #[cfg(feature = "is_in")]
             IsIn => "is_in",
+            AllNotNull => "all_not_null",
         };
         write!(f, "{s}")
     }
@@ -80,6 +82,7 @@ impl From<BooleanFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             IsDuplicated => map!(is_duplicated),
             #[cfg(feature = "is_in")]
             IsIn => wrap!(is_in),
+            AllNotNull => wrap!(all_not_null),
         }
     }
 }
@@ -157,3 +160,25 @@ fn is_in(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     let other = &s[1];
     left.is_in(other).map(|ca| Some(ca.into_series()))
 }
+
+/// A single mask that is `true` where none of the inputs is null.
+fn all_not_null(s: &mut [Series]) -> PolarsResult<Option<Series>> {
+    let Some((first, rest)) = s.split_first() else {
+        // e.g. a selector that matches no columns, so no value is null
+        return Ok(Some(
+            BooleanChunked::full("all_not_null", true, 1).into_series(),
+        ));
+    };
+    let mut mask = first.is_not_null();
+    for s in rest {
+        polars_ensure!(
+            s.len() == mask.len(),
+            ShapeMismatch: "cannot combine the null masks of series of length {} and {}",
+            mask.len(), s.len()
+        );
+        if s.null_count() > 0 {
+            mask = &mask & &s.is_not_null();
+        }
+    }
+    Ok(Some(mask.into_series()))
+}
@@ -1,32 +1,59 @@
 use super::*;
 
+fn is_physical_numeric(dt: &DataType) -> bool {
+    dt.is_numeric() && !dt.is_logical()
+}
+
+/// The row numbers of `s`, nulls included.
 pub(super) fn cumcount(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    if reverse {
-        let ca: NoNull<UInt32Chunked> = (0u32..s.len() as u32).rev().collect();
-        let mut ca = ca.into_inner();
-        ca.rename(s.name());
-        Ok(ca.into_series())
+    let len = s.len() as IdxSize;
+    let ca: NoNull<IdxCa> = if reverse {
+        (0..len).rev().collect()
     } else {
-        let ca: NoNull<UInt32Chunked> = (0u32..s.len() as u32).collect();
-        let mut ca = ca.into_inner();
-        ca.rename(s.name());
-        Ok(ca.into_series())
-    }
+        (0..len).collect()
+    };
+    let mut ca = ca.into_inner();
+    ca.rename(s.name());
+    Ok(ca.into_series())
 }
 
 pub(super) fn cumsum(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    let dt = s.dtype();
+    polars_ensure!(
+        is_physical_numeric(dt) || matches!(dt, DataType::Boolean | DataType::Duration(_)),
+        opq = cumsum,
+        dt
+    );
     Ok(s.cumsum(reverse))
 }
 
 pub(super) fn cumprod(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    let dt = s.dtype();
+    polars_ensure!(
+        is_physical_numeric(dt) || matches!(dt, DataType::Boolean),
+        opq = cumprod,
+        dt
+    );
     Ok(s.cumprod(reverse))
 }
 
 pub(super) fn cummin(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    let dt = s.dtype();
+    polars_ensure!(
+        is_physical_numeric(dt) || dt.is_temporal() || matches!(dt, DataType::Boolean),
+        opq = cummin,
+        dt
+    );
     Ok(s.cummin(reverse))
 }
 
 pub(super) fn cummax(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    let dt = s.dtype();
+    polars_ensure!(
+        is_physical_numeric(dt) || dt.is_temporal() || matches!(dt, DataType::Boolean),
+        opq = cummax,
+        dt
+    );
     Ok(s.cummax(reverse))
 }
 
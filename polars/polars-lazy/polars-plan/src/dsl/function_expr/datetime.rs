@@ -32,10 +32,18 @@ pub enum TemporalFunction {
     TimeStamp(TimeUnit),
     Truncate(String, String),
     Round(String, String),
+    MonthStart,
+    MonthEnd,
+    QuarterStart,
+    AddMonths(i64, AddMonthsStrategy),
     #[cfg(feature = "timezones")]
     CastTimezone(Option<TimeZone>, Option<bool>),
     #[cfg(feature = "timezones")]
     TzLocalize(TimeZone),
+    #[cfg(feature = "timezones")]
+    ReplaceTimeZoneByColumn(Ambiguous),
+    #[cfg(feature = "timezones")]
+    ConvertTimeZoneByColumn,
     DateRange {
         name: String,
         every: Duration,
@@ -70,10 +78,18 @@ impl Display for TemporalFunction {
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
             Truncate(..) => "truncate",
             Round(..) => "round",
+            MonthStart => "month_start",
+            MonthEnd => "month_end",
+            QuarterStart => "quarter_start",
+            AddMonths(..) => "add_months",
             #[cfg(feature = "timezones")]
             CastTimezone(_, _) => "replace_timezone",
             #[cfg(feature = "timezones")]
             TzLocalize(_) => "tz_localize",
+            #[cfg(feature = "timezones")]
+            ReplaceTimeZoneByColumn(_) => "replace_time_zone_by_column",
+            #[cfg(feature = "timezones")]
+            ConvertTimeZoneByColumn => "convert_time_zone_by_column",
             DateRange { .. } => return write!(f, "date_range"),
             Combine(_) => "combine",
         };
@@ -241,6 +257,38 @@ pub(super) fn round(s: &Series, every: &str, offset: &str) -> PolarsResult<Serie
     })
 }
 
+pub(super) fn month_start(s: &Series) -> PolarsResult<Series> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, _) => s.datetime().unwrap().month_start()?.into_series(),
+        DataType::Date => s.date().unwrap().month_start()?.into_series(),
+        dt => polars_bail!(opq = month_start, got = dt, expected = "date/datetime"),
+    })
+}
+
+pub(super) fn month_end(s: &Series) -> PolarsResult<Series> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, _) => s.datetime().unwrap().month_end()?.into_series(),
+        DataType::Date => s.date().unwrap().month_end()?.into_series(),
+        dt => polars_bail!(opq = month_end, got = dt, expected = "date/datetime"),
+    })
+}
+
+pub(super) fn quarter_start(s: &Series) -> PolarsResult<Series> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, _) => s.datetime().unwrap().quarter_start()?.into_series(),
+        DataType::Date => s.date().unwrap().quarter_start()?.into_series(),
+        dt => polars_bail!(opq = quarter_start, got = dt, expected = "date/datetime"),
+    })
+}
+
+pub(super) fn add_months(s: &Series, n: i64, strategy: AddMonthsStrategy) -> PolarsResult<Series> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, _) => s.datetime().unwrap().add_months(n, strategy)?.into_series(),
+        DataType::Date => s.date().unwrap().add_months(n, strategy)?.into_series(),
+        dt => polars_bail!(opq = add_months, got = dt, expected = "date/datetime"),
+    })
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn replace_timezone(
     s: &Series,
@@ -252,6 +300,23 @@ pub(super) fn replace_timezone(
         .map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "timezones")]
+pub(super) fn replace_time_zone_by_column(
+    s: &[Series],
+    ambiguous: Ambiguous,
+) -> PolarsResult<Series> {
+    let ca = s[0].datetime()?;
+    let time_zones = s[1].utf8()?;
+    replace_time_zone_per_row(ca, time_zones, ambiguous).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "timezones")]
+pub(super) fn convert_time_zone_by_column(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].datetime()?;
+    let time_zones = s[1].utf8()?;
+    convert_time_zone_per_row(ca, time_zones).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "timezones")]
 #[deprecated(note = "use replace_time_zone")]
 pub(super) fn tz_localize(s: &Series, tz: &str) -> PolarsResult<Series> {
@@ -8,6 +8,10 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(s.reverse())
 }
 
+pub(super) fn gather_every(s: &Series, n: usize, offset: usize) -> PolarsResult<Series> {
+    s.gather_every(n, offset)
+}
+
 #[cfg(feature = "approx_unique")]
 pub(super) fn approx_unique(s: &Series) -> PolarsResult<Series> {
     polars_ops::prelude::approx_unique(s)
@@ -1,4 +1,7 @@
+use polars_arrow::kernels::distance;
 use polars_arrow::utils::CustomIterTools;
+#[cfg(feature = "dtype-array")]
+use polars_ops::chunked_array::array::*;
 use polars_ops::chunked_array::list::*;
 
 use super::*;
@@ -16,6 +19,20 @@ pub enum ListFunction {
     #[cfg(feature = "list_count")]
     CountMatch,
     Sum,
+    Dot,
+    CosineSimilarity,
+    L2Distance,
+    #[cfg(feature = "dtype-array")]
+    TopKNearest {
+        k: usize,
+        metric: DistanceMetric,
+    },
+    #[cfg(feature = "list_contains_multi")]
+    ContainsAny,
+    #[cfg(feature = "list_contains_multi")]
+    ContainsAll,
+    #[cfg(feature = "list_sets")]
+    SetOperation(SetOperation),
 }
 
 impl Display for ListFunction {
@@ -33,6 +50,17 @@ impl Display for ListFunction {
             #[cfg(feature = "list_count")]
             CountMatch => "count",
             Sum => "sum",
+            Dot => "dot",
+            CosineSimilarity => "cosine_similarity",
+            L2Distance => "l2_distance",
+            #[cfg(feature = "dtype-array")]
+            TopKNearest { .. } => "top_k_nearest",
+            #[cfg(feature = "list_contains_multi")]
+            ContainsAny => "contains_any",
+            #[cfg(feature = "list_contains_multi")]
+            ContainsAll => "contains_all",
+            #[cfg(feature = "list_sets")]
+            SetOperation(op) => return write!(f, "set_{op}"),
         };
         write!(f, "{name}")
     }
@@ -163,6 +191,16 @@ pub(super) fn concat(s: &mut [Series]) -> PolarsResult<Option<Series>> {
 }
 
 pub(super) fn get(s: &mut [Series]) -> PolarsResult<Option<Series>> {
+    #[cfg(feature = "dtype-array")]
+    if let Ok(ca) = s[0].array() {
+        let index = &s[1];
+        polars_ensure!(
+            index.len() == 1,
+            ComputeError: "`arr.get` on an array column expects a single index, got {}", index.len()
+        );
+        let index = index.get(0).unwrap().try_extract::<i64>()?;
+        return ca.array_get(index).map(Some);
+    }
     let ca = s[0].list()?;
     let index = s[1].cast(&DataType::Int64)?;
     let index = index.i64().unwrap();
@@ -238,6 +276,122 @@ pub(super) fn count_match(args: &[Series]) -> PolarsResult<Series> {
     list_count_match(ca, element.get(0).unwrap())
 }
 
+#[cfg(feature = "list_contains_multi")]
+pub(super) fn contains_any(args: &[Series]) -> PolarsResult<Series> {
+    let ca = args[0].list()?;
+    list_contains_any(ca, &args[1])
+}
+
+#[cfg(feature = "list_contains_multi")]
+pub(super) fn contains_all(args: &[Series]) -> PolarsResult<Series> {
+    let ca = args[0].list()?;
+    list_contains_all(ca, &args[1])
+}
+
+#[cfg(feature = "list_sets")]
+pub(super) fn set_operation(args: &[Series], op: SetOperation) -> PolarsResult<Series> {
+    let a = args[0].list()?;
+    let b = args[1].list()?;
+    list_set_operation(a, b, op).map(|ca| ca.into_series())
+}
+
 pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-array")]
+    if let Ok(ca) = s.array() {
+        return ca.array_sum();
+    }
     Ok(s.list()?.lst_sum())
 }
+
+/// Apply `f` to the values of every sublist of `s[0]` and the sublist at the same position
+/// in `s[1]`, which may also be a single sublist. The values are cast to `Float64` and null
+/// values are replaced by zeros.
+fn pairwise_f64(
+    s: &[Series],
+    what: &str,
+    f: impl Fn(&[f64], &[f64]) -> f64,
+) -> PolarsResult<Series> {
+    let dtype = DataType::List(Box::new(DataType::Float64));
+    let lhs = s[0].cast(&dtype)?;
+    let rhs = s[1].cast(&dtype)?;
+    let (lhs, rhs) = (lhs.list()?, rhs.list()?);
+    let rhs = if rhs.len() == 1 && lhs.len() != 1 {
+        rhs.new_from_index(0, lhs.len())
+    } else {
+        polars_ensure!(
+            lhs.len() == rhs.len(),
+            ShapeMismatch: "cannot compute {} of series of length {} and {}",
+            what, lhs.len(), rhs.len()
+        );
+        rhs.clone()
+    };
+
+    let mut out = lhs
+        .amortized_iter()
+        .zip(rhs.amortized_iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => {
+                let (l, r) = (l.as_ref(), r.as_ref());
+                polars_ensure!(
+                    l.len() == r.len(),
+                    ShapeMismatch: "cannot compute {} of lists of length {} and {}",
+                    what, l.len(), r.len()
+                );
+                let l = l.f64()?.fill_null_with_values(0.0)?.rechunk();
+                let r = r.f64()?.fill_null_with_values(0.0)?.rechunk();
+                Ok(Some(f(l.cont_slice()?, r.cont_slice()?)))
+            }
+            _ => Ok(None),
+        })
+        .collect::<PolarsResult<Float64Chunked>>()?;
+    out.rename(lhs.name());
+    Ok(out.into_series())
+}
+
+pub(super) fn dot(s: &[Series]) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-array")]
+    if let Ok(ca) = s[0].array() {
+        return ca.array_dot(&s[1]);
+    }
+    pairwise_f64(s, "dot product", distance::dot)
+}
+
+pub(super) fn cosine_similarity(s: &[Series]) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-array")]
+    if let Ok(ca) = s[0].array() {
+        return ca.array_cosine_similarity(&s[1]);
+    }
+    pairwise_f64(s, "cosine similarity", distance::cosine_similarity)
+}
+
+pub(super) fn l2_distance(s: &[Series]) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-array")]
+    if let Ok(ca) = s[0].array() {
+        return ca.array_l2_distance(&s[1]);
+    }
+    pairwise_f64(s, "l2 distance", |a, b| {
+        distance::squared_l2_distance(a, b).sqrt()
+    })
+}
+
+#[cfg(feature = "dtype-array")]
+pub(super) fn top_k_nearest(
+    s: &[Series],
+    k: usize,
+    metric: DistanceMetric,
+) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let query = s[1].cast(&DataType::List(Box::new(DataType::Float64)))?;
+    polars_ensure!(
+        query.len() == 1,
+        ComputeError: "expected a single query array, got {}", query.len()
+    );
+    let query = query
+        .list()?
+        .get(0)
+        .ok_or_else(|| polars_err!(ComputeError: "the query array is null"))?;
+    let query = query.f64()?.fill_null_with_values(0.0)?.rechunk();
+    Ok(ca
+        .array_top_k_nearest(query.cont_slice()?, k, metric)?
+        .into_series())
+}
@@ -10,6 +10,19 @@ pub(super) fn entropy(s: &Series, base: f64, normalize: bool) -> PolarsResult<Se
     }
 }
 
+pub(super) fn gini_impurity(s: &Series) -> PolarsResult<Series> {
+    Ok(Series::new(s.name(), [s.gini_impurity()]))
+}
+
+pub(super) fn weight_of_evidence(s: &[Series]) -> PolarsResult<Series> {
+    Ok(s[0].weight_of_evidence(&s[1])?.into_series())
+}
+
+pub(super) fn information_value(s: &[Series]) -> PolarsResult<Series> {
+    let out = s[0].information_value(&s[1])?;
+    Ok(Series::new(s[0].name(), [out]))
+}
+
 pub(super) fn log(s: &Series, base: f64) -> PolarsResult<Series> {
     Ok(s.log(base))
 }
@@ -18,6 +18,8 @@ mod list;
 #[cfg(feature = "log")]
 mod log;
 mod nan;
+#[cfg(feature = "plugins")]
+mod plugin;
 mod pow;
 #[cfg(all(feature = "rolling_window", feature = "moment"))]
 mod rolling;
@@ -56,10 +58,12 @@ pub use self::boolean::BooleanFunction;
 pub(crate) use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub(super) use self::datetime::TemporalFunction;
+#[cfg(feature = "plugins")]
+pub use self::plugin::register_plugin_function;
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
-pub(super) use self::struct_::StructFunction;
+pub(crate) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
 use super::*;
@@ -131,6 +135,10 @@ pub enum FunctionExpr {
         reverse: bool,
     },
     Reverse,
+    GatherEvery {
+        n: usize,
+        offset: usize,
+    },
     Boolean(BooleanFunction),
     #[cfg(feature = "approx_unique")]
     ApproxUnique,
@@ -150,6 +158,12 @@ pub enum FunctionExpr {
         normalize: bool,
     },
     #[cfg(feature = "log")]
+    GiniImpurity,
+    #[cfg(feature = "log")]
+    WeightOfEvidence,
+    #[cfg(feature = "log")]
+    InformationValue,
+    #[cfg(feature = "log")]
     Log {
         base: f64,
     },
@@ -168,6 +182,12 @@ pub enum FunctionExpr {
     Ceil,
     UpperBound,
     LowerBound,
+    /// A function of an expression plugin, see [`register_plugin_function`].
+    #[cfg(feature = "plugins")]
+    FfiPlugin {
+        lib: Arc<str>,
+        symbol: Arc<str>,
+    },
 }
 
 impl Display for FunctionExpr {
@@ -220,6 +240,7 @@ impl Display for FunctionExpr {
             Cummin { .. } => "cummin",
             Cummax { .. } => "cummax",
             Reverse => "reverse",
+            GatherEvery { .. } => "gather_every",
             Boolean(func) => return write!(f, "{func}"),
             #[cfg(feature = "approx_unique")]
             ApproxUnique => "approx_unique",
@@ -236,6 +257,12 @@ impl Display for FunctionExpr {
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
+            GiniImpurity => "gini_impurity",
+            #[cfg(feature = "log")]
+            WeightOfEvidence => "weight_of_evidence",
+            #[cfg(feature = "log")]
+            InformationValue => "information_value",
+            #[cfg(feature = "log")]
             Log { .. } => "log",
             #[cfg(feature = "log")]
             Log1p => "log1p",
@@ -256,6 +283,8 @@ impl Display for FunctionExpr {
             Ceil => "ceil",
             UpperBound => "upper_bound",
             LowerBound => "lower_bound",
+            #[cfg(feature = "plugins")]
+            FfiPlugin { symbol, .. } => return write!(f, "{symbol}"),
         };
         write!(f, "{s}")
     }
@@ -409,6 +438,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     #[cfg(feature = "list_count")]
                     CountMatch => map_as_slice!(list::count_match),
                     Sum => map!(list::sum),
+                    Dot => map_as_slice!(list::dot),
+                    CosineSimilarity => map_as_slice!(list::cosine_similarity),
+                    L2Distance => map_as_slice!(list::l2_distance),
+                    #[cfg(feature = "dtype-array")]
+                    TopKNearest { k, metric } => map_as_slice!(list::top_k_nearest, k, metric),
+                    #[cfg(feature = "list_contains_multi")]
+                    ContainsAny => map_as_slice!(list::contains_any),
+                    #[cfg(feature = "list_contains_multi")]
+                    ContainsAll => map_as_slice!(list::contains_all),
+                    #[cfg(feature = "list_sets")]
+                    SetOperation(op) => map_as_slice!(list::set_operation, op),
                 }
             }
             #[cfg(feature = "dtype-struct")]
@@ -417,6 +457,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 match sf {
                     FieldByIndex(index) => map!(struct_::get_by_index, index),
                     FieldByName(name) => map!(struct_::get_by_name, name.clone()),
+                    Unnest => map!(struct_::unnest),
+                    WithFields => map_as_slice!(struct_::with_fields),
+                    PrefixFields(prefix) => map!(struct_::prefix_fields, prefix.clone()),
                 }
             }
             #[cfg(feature = "top_k")]
@@ -430,6 +473,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Cummin { reverse } => map!(cum::cummin, reverse),
             Cummax { reverse } => map!(cum::cummax, reverse),
             Reverse => map!(dispatch::reverse),
+            GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             Boolean(func) => func.into(),
             #[cfg(feature = "approx_unique")]
             ApproxUnique => map!(dispatch::approx_unique),
@@ -450,6 +494,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
             #[cfg(feature = "log")]
+            GiniImpurity => map!(log::gini_impurity),
+            #[cfg(feature = "log")]
+            WeightOfEvidence => map_as_slice!(log::weight_of_evidence),
+            #[cfg(feature = "log")]
+            InformationValue => map_as_slice!(log::information_value),
+            #[cfg(feature = "log")]
             Log { base } => map!(log::log, base),
             #[cfg(feature = "log")]
             Log1p => map!(log::log1p),
@@ -464,6 +514,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Ceil => map!(round::ceil),
             UpperBound => map!(bounds::upper_bound),
             LowerBound => map!(bounds::lower_bound),
+            #[cfg(feature = "plugins")]
+            FfiPlugin { lib, symbol } => {
+                map_as_slice!(plugin::call_plugin, &lib, &symbol)
+            }
         }
     }
 }
@@ -515,6 +569,40 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             RStrip(matches) => map!(strings::rstrip, matches.as_deref()),
             #[cfg(feature = "string_from_radix")]
             FromRadix(radix, strict) => map!(strings::from_radix, radix, strict),
+            #[cfg(feature = "string_anonymize")]
+            Mask {
+                keep_prefix,
+                keep_suffix,
+                mask_char,
+            } => map!(strings::mask, keep_prefix, keep_suffix, mask_char),
+            #[cfg(feature = "string_anonymize")]
+            HashSha256(salt) => map!(strings::hash_sha256, &salt),
+            #[cfg(feature = "string_anonymize")]
+            Pseudonymize(seed) => map!(strings::pseudonymize, seed),
+            #[cfg(feature = "string_to_number")]
+            ToFloat {
+                thousands_separator,
+                currency_symbols,
+                strict,
+            } => map!(
+                strings::to_float,
+                thousands_separator.as_deref(),
+                &currency_symbols,
+                strict
+            ),
+            #[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+            ToDecimal {
+                scale,
+                thousands_separator,
+                currency_symbols,
+                strict,
+            } => map!(
+                strings::to_decimal,
+                scale,
+                thousands_separator.as_deref(),
+                &currency_symbols,
+                strict
+            ),
         }
     }
 }
@@ -563,12 +651,22 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             TimeStamp(tu) => map!(datetime::timestamp, tu),
             Truncate(every, offset) => map!(datetime::truncate, &every, &offset),
             Round(every, offset) => map!(datetime::round, &every, &offset),
+            MonthStart => map!(datetime::month_start),
+            MonthEnd => map!(datetime::month_end),
+            QuarterStart => map!(datetime::quarter_start),
+            AddMonths(n, strategy) => map!(datetime::add_months, n, strategy),
             #[cfg(feature = "timezones")]
             CastTimezone(tz, use_earliest) => {
                 map!(datetime::replace_timezone, tz.as_deref(), use_earliest)
             }
             #[cfg(feature = "timezones")]
             TzLocalize(tz) => map!(datetime::tz_localize, &tz),
+            #[cfg(feature = "timezones")]
+            ReplaceTimeZoneByColumn(ambiguous) => {
+                map_as_slice!(datetime::replace_time_zone_by_column, ambiguous)
+            }
+            #[cfg(feature = "timezones")]
+            ConvertTimeZoneByColumn => map_as_slice!(datetime::convert_time_zone_by_column),
             Combine(tu) => map_as_slice!(temporal::combine, tu),
             DateRange {
                 name,
@@ -0,0 +1,81 @@
+//! Expressions of which the kernel is loaded at runtime from the shared library of a plugin,
+//! see [`polars_ffi::plugin`].
+use std::sync::RwLock;
+
+use libloading::{Library, Symbol};
+use once_cell::sync::Lazy;
+use polars_ffi::plugin::{PluginFunction, PluginRegistry, PLUGIN_REGISTER_SYMBOL};
+
+use super::*;
+
+/// The registries of the loaded plugins by the path of their library. The libraries are never
+/// unloaded, as expressions may still refer to their functions.
+static PLUGINS: Lazy<RwLock<PlHashMap<String, &'static PluginRegistry>>> =
+    Lazy::new(Default::default);
+
+/// # Safety
+/// Loading a library runs its initialization routines and the register function is trusted to
+/// return a valid registry.
+unsafe fn load_plugin(lib: &str) -> PolarsResult<&'static PluginRegistry> {
+    let library = Library::new(lib)
+        .map_err(|err| polars_err!(ComputeError: "failed to load plugin '{}': {}", lib, err))?;
+    let register: Symbol<unsafe extern "C" fn() -> *const PluginRegistry> = library
+        .get(PLUGIN_REGISTER_SYMBOL)
+        .map_err(|err| polars_err!(ComputeError: "'{}' is not a polars plugin: {}", lib, err))?;
+    let registry = &*register();
+    std::mem::forget(library);
+    Ok(registry)
+}
+
+fn get_function(lib: &str, symbol: &str) -> PolarsResult<PluginFunction> {
+    let loaded = PLUGINS.read().unwrap().get(lib).copied();
+    let registry = match loaded {
+        Some(registry) => registry,
+        None => {
+            let mut plugins = PLUGINS.write().unwrap();
+            match plugins.get(lib) {
+                Some(registry) => *registry,
+                None => {
+                    let registry = unsafe { load_plugin(lib)? };
+                    plugins.insert(lib.to_string(), registry);
+                    registry
+                }
+            }
+        }
+    };
+    registry.get(symbol).copied()
+}
+
+pub(super) fn call_plugin(s: &[Series], lib: &str, symbol: &str) -> PolarsResult<Series> {
+    get_function(lib, symbol)?.call(s)
+}
+
+pub(super) fn plugin_field(fields: &[Field], lib: &str, symbol: &str) -> PolarsResult<Field> {
+    get_function(lib, symbol)?.output_field(fields)
+}
+
+/// Call the function `symbol` of the plugin with the shared library at the path `lib` on
+/// `args`. The first argument is the expression the function is called on.
+///
+/// The library is loaded at once, so that a missing function is reported here instead of when
+/// the query runs. Loading a library runs its initialization routines, so only load trusted
+/// plugins.
+pub fn register_plugin_function(lib: &str, symbol: &str, args: Vec<Expr>) -> PolarsResult<Expr> {
+    let function = get_function(lib, symbol)?;
+    let collect_groups = if function.is_elementwise() {
+        ApplyOptions::ApplyFlat
+    } else {
+        ApplyOptions::ApplyGroups
+    };
+    Ok(Expr::Function {
+        input: args,
+        function: FunctionExpr::FfiPlugin {
+            lib: lib.into(),
+            symbol: symbol.into(),
+        },
+        options: FunctionOptions {
+            collect_groups,
+            ..Default::default()
+        },
+    })
+}
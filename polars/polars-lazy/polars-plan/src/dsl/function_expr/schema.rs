@@ -49,12 +49,21 @@ impl FunctionExpr {
                     },
                     Truncate(..) => mapper.with_same_dtype().unwrap().dtype,
                     Round(..) => mapper.with_same_dtype().unwrap().dtype,
+                    MonthStart | MonthEnd | QuarterStart | AddMonths(..) => {
+                        mapper.with_same_dtype().unwrap().dtype
+                    }
                     #[cfg(feature = "timezones")]
                     CastTimezone(tz, _use_earliest) => {
                         return mapper.map_datetime_dtype_timezone(tz.as_ref())
                     }
                     #[cfg(feature = "timezones")]
                     TzLocalize(tz) => return mapper.map_datetime_dtype_timezone(Some(tz)),
+                    #[cfg(feature = "timezones")]
+                    ReplaceTimeZoneByColumn(_) => {
+                        return mapper.map_datetime_dtype_timezone(Some(&"UTC".to_string()))
+                    }
+                    #[cfg(feature = "timezones")]
+                    ConvertTimeZoneByColumn => return mapper.map_datetime_dtype_timezone(None),
                     DateRange { .. } => return mapper.map_to_supertype(),
                     Combine(tu) => match mapper.with_same_dtype().unwrap().dtype {
                         DataType::Datetime(_, tz) => DataType::Datetime(*tu, tz),
@@ -104,6 +113,21 @@ impl FunctionExpr {
                         }
                         Ok(first)
                     }
+                    Dot | CosineSimilarity | L2Distance => mapper.with_dtype(DataType::Float64),
+                    #[cfg(feature = "dtype-array")]
+                    TopKNearest { .. } => mapper.with_dtype(IDX_DTYPE),
+                    #[cfg(feature = "list_contains_multi")]
+                    ContainsAny | ContainsAll => mapper.with_dtype(DataType::Boolean),
+                    #[cfg(feature = "list_sets")]
+                    SetOperation(_) => mapper.map_to_list_supertype().map(|mut field| {
+                        #[cfg(feature = "dtype-categorical")]
+                        if let DataType::List(inner) = &field.dtype {
+                            if matches!(**inner, DataType::Categorical(_)) {
+                                field.coerce(DataType::List(Box::new(DataType::Utf8)));
+                            }
+                        }
+                        field
+                    }),
                 }
             }
             #[cfg(feature = "dtype-struct")]
@@ -130,11 +154,42 @@ impl FunctionExpr {
                             polars_bail!(StructFieldNotFound: "{}", name.as_ref());
                         }
                     }
+                    Unnest => mapper.with_same_dtype(),
+                    WithFields => {
+                        if let DataType::Struct(flds) = &fields[0].dtype {
+                            let mut flds = flds.clone();
+                            for new in &fields[1..] {
+                                match flds.iter_mut().find(|fld| fld.name() == new.name()) {
+                                    Some(fld) => *fld = new.clone(),
+                                    None => flds.push(new.clone()),
+                                }
+                            }
+                            Ok(Field::new(fields[0].name(), DataType::Struct(flds)))
+                        } else {
+                            polars_bail!(
+                                SchemaMismatch: "expected a struct in `struct.with_fields`, got `{}`",
+                                fields[0].dtype
+                            );
+                        }
+                    }
+                    PrefixFields(prefix) => mapper.map_dtype(|dt| match dt {
+                        DataType::Struct(flds) => DataType::Struct(
+                            flds.iter()
+                                .map(|fld| {
+                                    Field::new(
+                                        &format!("{}_{}", prefix, fld.name()),
+                                        fld.data_type().clone(),
+                                    )
+                                })
+                                .collect(),
+                        ),
+                        dt => dt.clone(),
+                    }),
                 }
             }
             #[cfg(feature = "top_k")]
             TopK { .. } => mapper.with_same_dtype(),
-            Shift(..) | Reverse => mapper.with_same_dtype(),
+            Shift(..) | Reverse | GatherEvery { .. } => mapper.with_same_dtype(),
             Boolean(func) => func.get_field(mapper),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
@@ -146,18 +201,7 @@ impl FunctionExpr {
             #[cfg(feature = "approx_unique")]
             ApproxUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "diff")]
-            Diff(_, _) => mapper.map_dtype(|dt| match dt {
-                #[cfg(feature = "dtype-datetime")]
-                DataType::Datetime(tu, _) => DataType::Duration(*tu),
-                #[cfg(feature = "dtype-date")]
-                DataType::Date => DataType::Duration(TimeUnit::Milliseconds),
-                #[cfg(feature = "dtype-time")]
-                DataType::Time => DataType::Duration(TimeUnit::Nanoseconds),
-                DataType::UInt64 | DataType::UInt32 => DataType::Int64,
-                DataType::UInt16 => DataType::Int32,
-                DataType::UInt8 => DataType::Int16,
-                dt => dt.clone(),
-            }),
+            Diff(_, _) => mapper.map_dtype(polars_core::series::ops::diff::diff_dtype),
             #[cfg(feature = "interpolate")]
             Interpolate(_) => mapper.with_same_dtype(),
             ShrinkType => {
@@ -193,10 +237,16 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "log")]
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
+            #[cfg(feature = "log")]
+            GiniImpurity | WeightOfEvidence | InformationValue => {
+                mapper.with_dtype(DataType::Float64)
+            }
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Round { .. } | Floor | Ceil => mapper.with_same_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
+            #[cfg(feature = "plugins")]
+            FfiPlugin { lib, symbol } => plugin::plugin_field(fields, lib, symbol),
         }
     }
 }
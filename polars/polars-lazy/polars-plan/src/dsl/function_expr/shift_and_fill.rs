@@ -15,11 +15,7 @@ where
     ca.shift_and_fill(periods, fill_value)
 }
 
-#[cfg(any(
-    feature = "object",
-    feature = "dtype-struct",
-    feature = "dtype-categorical"
-))]
+#[cfg(any(feature = "object", feature = "dtype-struct"))]
 fn shift_and_fill_with_mask(s: &Series, periods: i64, fill_value: &Series) -> PolarsResult<Series> {
     use polars_core::export::arrow::array::BooleanArray;
     use polars_core::export::arrow::bitmap::MutableBitmap;
@@ -75,10 +71,10 @@ pub(super) fn shift_and_fill(args: &mut [Series], periods: i64) -> PolarsResult<
                 .into_series()
                 .cast(logical)
         }
-        List(_) => {
+        List(inner) => {
             let ca = s.list().unwrap();
             let fill_value = match fill_value {
-                AnyValue::List(v) => Some(v),
+                AnyValue::List(v) => Some(v.cast(inner)?),
                 AnyValue::Null => None,
                 _ => unimplemented!(),
             };
@@ -89,9 +85,33 @@ pub(super) fn shift_and_fill(args: &mut [Series], periods: i64) -> PolarsResult<
         #[cfg(feature = "object")]
         Object(_) => shift_and_fill_with_mask(s, periods, fill_value_s),
         #[cfg(feature = "dtype-struct")]
-        Struct(_) => shift_and_fill_with_mask(s, periods, fill_value_s),
+        Struct(_) => shift_and_fill_with_mask(s, periods, &fill_value_s.cast(logical)?),
+        // shift the keys, so that the rev-map is kept
         #[cfg(feature = "dtype-categorical")]
-        Categorical(_) => shift_and_fill_with_mask(s, periods, fill_value_s),
+        Categorical(_) => {
+            let ca = s.categorical().unwrap();
+            let rev_map = ca.get_rev_map();
+            let fill_value = fill_value_s.cast(&Utf8)?;
+            let fill_key = match fill_value.utf8()?.get(0) {
+                Some(v) => Some(
+                    rev_map
+                        .find(v)
+                        .filter(|key| rev_map.get_optional(*key).is_some())
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "fill value '{}' is not one of the categories", v
+                            )
+                        })?,
+                ),
+                None => None,
+            };
+            let keys = ca.logical().shift_and_fill(periods, fill_key);
+            // safety: the keys are from the same rev-map
+            let out = unsafe {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(keys, rev_map.clone())
+            };
+            Ok(out.into_series())
+        }
         dt if dt.is_numeric() || dt.is_logical() => {
             macro_rules! dispatch {
                 ($ca:expr, $periods:expr, $fill_value:expr) => {{
@@ -62,6 +62,29 @@ pub enum StringFunction {
     LStrip(Option<String>),
     #[cfg(feature = "string_from_radix")]
     FromRadix(u32, bool),
+    #[cfg(feature = "string_anonymize")]
+    Mask {
+        keep_prefix: usize,
+        keep_suffix: usize,
+        mask_char: char,
+    },
+    #[cfg(feature = "string_anonymize")]
+    HashSha256(String),
+    #[cfg(feature = "string_anonymize")]
+    Pseudonymize(u64),
+    #[cfg(feature = "string_to_number")]
+    ToFloat {
+        thousands_separator: Option<String>,
+        currency_symbols: Vec<String>,
+        strict: bool,
+    },
+    #[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+    ToDecimal {
+        scale: usize,
+        thousands_separator: Option<String>,
+        currency_symbols: Vec<String>,
+        strict: bool,
+    },
 }
 
 impl StringFunction {
@@ -87,6 +110,12 @@ impl StringFunction {
             }
             #[cfg(feature = "string_from_radix")]
             FromRadix { .. } => mapper.with_dtype(DataType::Int32),
+            #[cfg(feature = "string_anonymize")]
+            Mask { .. } | HashSha256(_) | Pseudonymize(_) => mapper.with_dtype(DataType::Utf8),
+            #[cfg(feature = "string_to_number")]
+            ToFloat { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+            ToDecimal { scale, .. } => mapper.with_dtype(DataType::Decimal(None, Some(*scale))),
         }
     }
 }
@@ -123,6 +152,16 @@ impl Display for StringFunction {
             StringFunction::RStrip(_) => "rstrip",
             #[cfg(feature = "string_from_radix")]
             StringFunction::FromRadix { .. } => "from_radix",
+            #[cfg(feature = "string_anonymize")]
+            StringFunction::Mask { .. } => "mask",
+            #[cfg(feature = "string_anonymize")]
+            StringFunction::HashSha256(_) => "hash_sha256",
+            #[cfg(feature = "string_anonymize")]
+            StringFunction::Pseudonymize(_) => "pseudonymize",
+            #[cfg(feature = "string_to_number")]
+            StringFunction::ToFloat { .. } => "to_float",
+            #[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+            StringFunction::ToDecimal { .. } => "to_decimal",
         };
 
         write!(f, "str.{s}")
@@ -262,6 +301,54 @@ pub(super) fn rjust(s: &Series, width: usize, fillchar: char) -> PolarsResult<Se
     Ok(ca.rjust(width, fillchar).into_series())
 }
 
+#[cfg(feature = "string_anonymize")]
+pub(super) fn mask(
+    s: &Series,
+    keep_prefix: usize,
+    keep_suffix: usize,
+    mask_char: char,
+) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.mask(keep_prefix, keep_suffix, mask_char).into_series())
+}
+
+#[cfg(feature = "string_anonymize")]
+pub(super) fn hash_sha256(s: &Series, salt: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.hash_sha256(salt).into_series())
+}
+
+#[cfg(feature = "string_anonymize")]
+pub(super) fn pseudonymize(s: &Series, seed: u64) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.pseudonymize(seed).into_series())
+}
+
+#[cfg(feature = "string_to_number")]
+pub(super) fn to_float(
+    s: &Series,
+    thousands_separator: Option<&str>,
+    currency_symbols: &[String],
+    strict: bool,
+) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    ca.to_float(thousands_separator, currency_symbols, strict)
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+pub(super) fn to_decimal(
+    s: &Series,
+    scale: usize,
+    thousands_separator: Option<&str>,
+    currency_symbols: &[String],
+    strict: bool,
+) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    ca.to_decimal(scale, thousands_separator, currency_symbols, strict)
+        .map(|ca| ca.into_series())
+}
+
 pub(super) fn strip(s: &Series, matches: Option<&str>) -> PolarsResult<Series> {
     let ca = s.utf8()?;
     if let Some(matches) = matches {
@@ -7,6 +7,9 @@ use super::*;
 pub enum StructFunction {
     FieldByIndex(i64),
     FieldByName(Arc<str>),
+    Unnest,
+    WithFields,
+    PrefixFields(Arc<str>),
 }
 
 impl Display for StructFunction {
@@ -15,6 +18,9 @@ impl Display for StructFunction {
         match self {
             StructFunction::FieldByIndex(_) => write!(f, "struct.field_by_name"),
             StructFunction::FieldByName(_) => write!(f, "struct.field_by_index"),
+            StructFunction::Unnest => write!(f, "struct.unnest"),
+            StructFunction::WithFields => write!(f, "struct.with_fields"),
+            StructFunction::PrefixFields(_) => write!(f, "struct.prefix_fields"),
         }
     }
 }
@@ -31,3 +37,48 @@ pub(super) fn get_by_name(s: &Series, name: Arc<str>) -> PolarsResult<Series> {
     let ca = s.struct_()?;
     ca.field_by_name(name.as_ref())
 }
+
+/// Add `s[1..]` as fields of the struct `s[0]`. A field that is already in the struct is
+/// replaced in place, a field of length 1 is broadcast.
+pub(super) fn with_fields(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].struct_()?;
+    let len = ca.len();
+    let mut fields = ca.fields().to_vec();
+    for field in &s[1..] {
+        let field = match field.len() {
+            1 if len != 1 => field.new_from_index(0, len),
+            _ => field.clone(),
+        };
+        polars_ensure!(
+            field.len() == len,
+            ShapeMismatch: "cannot add field '{}' of length {} to a struct of length {}",
+            field.name(), field.len(), len
+        );
+        match fields.iter_mut().find(|fld| fld.name() == field.name()) {
+            Some(fld) => *fld = field,
+            None => fields.push(field),
+        }
+    }
+    StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+}
+
+/// The unnesting itself is done by the query that owns the expression,
+/// here we only check that there is something to unnest.
+pub(super) fn unnest(s: &Series) -> PolarsResult<Series> {
+    s.struct_()?;
+    Ok(s.clone())
+}
+
+pub(super) fn prefix_fields(s: &Series, prefix: Arc<str>) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    let fields = ca
+        .fields()
+        .iter()
+        .map(|fld| {
+            let mut fld = fld.clone();
+            fld.rename(&format!("{}_{}", prefix, fld.name()));
+            fld
+        })
+        .collect::<Vec<_>>();
+    StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+}
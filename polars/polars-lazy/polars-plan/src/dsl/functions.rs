@@ -1148,6 +1148,28 @@ pub fn sum_exprs<E: AsRef<[Expr]>>(exprs: E) -> Expr {
     fold_exprs(init, func, exprs).alias("sum")
 }
 
+/// Create a new column with the the sum of the values in each row, where `null_strategy`
+/// decides if a null value counts as zero or makes the sum of its row null.
+///
+/// The name of the resulting column will be `"sum"`; use [`alias`](Expr::alias) to choose a different name.
+pub fn sum_exprs_with<E: AsRef<[Expr]>>(exprs: E, null_strategy: NullStrategy) -> Expr {
+    let mut exprs = exprs.as_ref().to_vec();
+    let func = move |s1, s2| {
+        let df = DataFrame::new_no_checks(vec![s1, s2]);
+        df.hsum(null_strategy)
+    };
+    let init = match exprs.pop() {
+        Some(e) => e,
+        // use u32 as that is not cast to float as eagerly
+        _ => lit(0u32),
+    };
+    if exprs.is_empty() && null_strategy == NullStrategy::Ignore {
+        // there is nothing to fold, so the nulls are filled here
+        return init.fill_null(lit(0)).alias("sum");
+    }
+    fold_exprs(init, func, exprs).alias("sum")
+}
+
 /// Create a new column with the the maximum value per row.
 ///
 /// The name of the resulting column will be `"max"`; use [`alias`](Expr::alias) to choose a different name.
@@ -1196,6 +1218,23 @@ pub fn all_exprs<E: AsRef<[Expr]>>(exprs: E) -> Expr {
     fold_exprs(lit(true), func, exprs)
 }
 
+/// Create a new column that is `true` in each row where none of `exprs` is null.
+///
+/// Unlike folding [`is_not_null`] with [`all_exprs`], the mask is computed by a single
+/// expression. The name of the resulting column is arbitrary; use [`alias`](Expr::alias) to
+/// choose a different name.
+pub fn all_not_null<E: AsRef<[Expr]>>(exprs: E) -> Expr {
+    Expr::Function {
+        input: exprs.as_ref().to_vec(),
+        function: FunctionExpr::Boolean(BooleanFunction::AllNotNull),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyFlat,
+            input_wildcard_expansion: true,
+            ..Default::default()
+        },
+    }
+}
+
 /// Negates a boolean column.
 pub fn not(expr: Expr) -> Expr {
     expr.not()
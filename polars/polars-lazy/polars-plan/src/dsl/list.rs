@@ -69,12 +69,60 @@ impl ListNameSpace {
     pub fn mean(self) -> Expr {
         self.0
             .map(
-                |s| Ok(Some(s.list()?.lst_mean().into_series())),
+                |s| {
+                    #[cfg(feature = "dtype-array")]
+                    if let Ok(ca) = s.array() {
+                        return ca.array_mean().map(Some);
+                    }
+                    Ok(Some(s.list()?.lst_mean().into_series()))
+                },
                 GetOutput::from_type(DataType::Float64),
             )
             .with_fmt("arr.mean")
     }
 
+    /// Compute the dot product of every sublist with the sublist at the same
+    /// position in `other`, and return a `Series` of dtype `Float64`.
+    pub fn dot<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::Dot),
+            &[other.into()],
+            false,
+        )
+    }
+
+    /// Compute the cosine similarity of every sublist with the sublist at the
+    /// same position in `other`, and return a `Series` of dtype `Float64`.
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::CosineSimilarity),
+            &[other.into()],
+            false,
+        )
+    }
+
+    /// Compute the euclidean distance of every sublist to the sublist at the
+    /// same position in `other`, and return a `Series` of dtype `Float64`.
+    pub fn l2_distance<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::L2Distance),
+            &[other.into()],
+            false,
+        )
+    }
+
+    /// Get the indices of the `k` arrays that are nearest to the single array `query` by the
+    /// `metric`, nearest first. This is a brute-force scan over all arrays.
+    #[cfg(feature = "dtype-array")]
+    pub fn top_k_nearest<E: Into<Expr>>(self, query: E, k: usize, metric: DistanceMetric) -> Expr {
+        self.0.apply_many_private(
+            FunctionExpr::ListExpr(ListFunction::TopKNearest { k, metric }),
+            &[query.into()],
+            false,
+            false,
+        )
+    }
+
     /// Sort every sublist.
     pub fn sort(self, options: SortOptions) -> Expr {
         self.0
@@ -309,4 +357,61 @@ impl ListNameSpace {
             },
         }
     }
+
+    /// Check if the lists contain at least one of the given values.
+    #[cfg(feature = "list_contains_multi")]
+    pub fn contains_any<E: Into<Expr>>(self, other: E) -> Expr {
+        self.contains_multi(other.into(), ListFunction::ContainsAny)
+    }
+
+    /// Check if the lists contain all of the given values.
+    #[cfg(feature = "list_contains_multi")]
+    pub fn contains_all<E: Into<Expr>>(self, other: E) -> Expr {
+        self.contains_multi(other.into(), ListFunction::ContainsAll)
+    }
+
+    /// Compute the union of the lists and the lists of `other`, which may also be a single list.
+    /// The values keep the order in which they are first seen.
+    #[cfg(feature = "list_sets")]
+    pub fn set_union<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Union)
+    }
+
+    /// Compute the values that the lists share with the lists of `other`.
+    #[cfg(feature = "list_sets")]
+    pub fn set_intersection<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Intersection)
+    }
+
+    /// Compute the values of the lists that are not in the lists of `other`.
+    #[cfg(feature = "list_sets")]
+    pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Difference)
+    }
+
+    #[cfg(feature = "list_sets")]
+    fn set_operation(self, other: Expr, op: SetOperation) -> Expr {
+        Expr::Function {
+            input: vec![self.0, other],
+            function: FunctionExpr::ListExpr(ListFunction::SetOperation(op)),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[cfg(feature = "list_contains_multi")]
+    fn contains_multi(self, other: Expr, function: ListFunction) -> Expr {
+        Expr::Function {
+            input: vec![self.0, other],
+            function: FunctionExpr::ListExpr(function),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                input_wildcard_expansion: true,
+                auto_explode: true,
+                ..Default::default()
+            },
+        }
+    }
 }
@@ -1,5 +1,6 @@
 #![allow(ambiguous_glob_reexports)]
 //! Domain specific language for the Lazy API.
+mod agg_op;
 #[cfg(feature = "dtype-categorical")]
 pub mod cat;
 #[cfg(feature = "dtype-categorical")]
@@ -26,6 +27,7 @@ mod struct_;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+pub use agg_op::AggOp;
 pub use expr::*;
 pub use function_expr::*;
 pub use functions::*;
@@ -246,6 +248,16 @@ impl Expr {
         binary_expr(self, Operator::NotEq, other.into())
     }
 
+    /// Compare `Expr` with other `Expr` on equality where `None == None`.
+    pub fn eq_missing<E: Into<Expr>>(self, other: E) -> Expr {
+        binary_expr(self, Operator::EqValidity, other.into())
+    }
+
+    /// Compare `Expr` with other `Expr` on non-equality where `None == None`.
+    pub fn neq_missing<E: Into<Expr>>(self, other: E) -> Expr {
+        binary_expr(self, Operator::NotEqValidity, other.into())
+    }
+
     /// Check if `Expr` < `Expr`
     pub fn lt<E: Into<Expr>>(self, other: E) -> Expr {
         binary_expr(self, Operator::Lt, other.into())
@@ -335,6 +347,30 @@ impl Expr {
         .into()
     }
 
+    /// Reduce groups to the value at the minimum of `by`, the first one on ties.
+    ///
+    /// This is the same as `self.take(by.arg_min())`, but computed in a single pass
+    /// per group.
+    pub fn min_by<E: Into<Expr>>(self, by: E) -> Self {
+        AggExpr::MinBy {
+            input: Box::new(self),
+            by: Box::new(by.into()),
+        }
+        .into()
+    }
+
+    /// Reduce groups to the value at the maximum of `by`, the first one on ties.
+    ///
+    /// This is the same as `self.take(by.arg_max())`, but computed in a single pass
+    /// per group.
+    pub fn max_by<E: Into<Expr>>(self, by: E) -> Self {
+        AggExpr::MaxBy {
+            input: Box::new(self),
+            by: Box::new(by.into()),
+        }
+        .into()
+    }
+
     /// Reduce groups to the mean value.
     pub fn mean(self) -> Self {
         AggExpr::Mean(Box::new(self)).into()
@@ -350,11 +386,52 @@ impl Expr {
         AggExpr::Sum(Box::new(self)).into()
     }
 
+    /// Reduce groups to the sum of all the values, where `null_strategy` decides if a null
+    /// value is skipped or makes the sum of its group null.
+    pub fn sum_with(self, null_strategy: NullStrategy) -> Self {
+        self.agg_with(null_strategy, Expr::sum)
+    }
+
+    /// Reduce groups to the mean value, where `null_strategy` decides if a null value is
+    /// skipped or makes the mean of its group null.
+    pub fn mean_with(self, null_strategy: NullStrategy) -> Self {
+        self.agg_with(null_strategy, Expr::mean)
+    }
+
+    /// Reduce groups to the minimal value, where `null_strategy` decides if a null value is
+    /// skipped or makes the minimum of its group null.
+    pub fn min_with(self, null_strategy: NullStrategy) -> Self {
+        self.agg_with(null_strategy, Expr::min)
+    }
+
+    /// Reduce groups to the maximum value, where `null_strategy` decides if a null value is
+    /// skipped or makes the maximum of its group null.
+    pub fn max_with(self, null_strategy: NullStrategy) -> Self {
+        self.agg_with(null_strategy, Expr::max)
+    }
+
+    fn agg_with(self, null_strategy: NullStrategy, agg: fn(Expr) -> Expr) -> Self {
+        match null_strategy {
+            NullStrategy::Ignore => agg(self),
+            // the aggregation is the truthy branch, so that it keeps the name of `self`
+            NullStrategy::Propagate => when(self.clone().null_count().eq(lit(0)))
+                .then(agg(self))
+                .otherwise(Null {}.lit()),
+        }
+    }
+
     /// Get the number of unique values in the groups.
     pub fn n_unique(self) -> Self {
         AggExpr::NUnique(Box::new(self)).into()
     }
 
+    /// Estimate the number of unique values in the groups with a HyperLogLog sketch.
+    ///
+    /// This uses constant memory per group, where [`Expr::n_unique`] keeps all unique values.
+    pub fn approx_n_unique(self) -> Self {
+        AggExpr::ApproxNUnique(Box::new(self)).into()
+    }
+
     /// Get the first value in the group.
     pub fn first(self) -> Self {
         AggExpr::First(Box::new(self)).into()
@@ -634,6 +711,31 @@ impl Expr {
         self.apply_private(FunctionExpr::Reverse)
     }
 
+    /// Take every `n`th value, starting at `offset`.
+    pub fn gather_every(self, n: usize, offset: usize) -> Self {
+        self.apply_private(FunctionExpr::GatherEvery { n, offset })
+    }
+
+    /// Get the first non-null value, per group in an aggregation or window.
+    pub fn first_non_null(self) -> Self {
+        AggExpr::FirstNonNull(Box::new(self), false).into()
+    }
+
+    /// Get the last non-null value, per group in an aggregation or window.
+    pub fn last_non_null(self) -> Self {
+        AggExpr::LastNonNull(Box::new(self), false).into()
+    }
+
+    /// Get the index of the first non-null value, per group in an aggregation or window.
+    pub fn arg_first_non_null(self) -> Self {
+        AggExpr::FirstNonNull(Box::new(self), true).into()
+    }
+
+    /// Get the index of the last non-null value, per group in an aggregation or window.
+    pub fn arg_last_non_null(self) -> Self {
+        AggExpr::LastNonNull(Box::new(self), true).into()
+    }
+
     /// Apply a function/closure once the logical plan get executed.
     ///
     /// This function is very similar to [`Expr::apply`], but differs in how it handles aggregations.
@@ -891,6 +993,9 @@ impl Expr {
     }
 
     /// Cumulatively count values from 0 to len.
+    ///
+    /// This numbers the rows, so null values are counted as well. Use
+    /// `self.is_not_null().cumsum(reverse)` to count the non-null values.
     pub fn cumcount(self, reverse: bool) -> Self {
         self.apply_private(FunctionExpr::Cumcount { reverse })
     }
@@ -1068,7 +1173,102 @@ impl Expr {
             function: Box::new(self),
             partition_by,
             order_by: None,
-            options: WindowOptions { explode: false },
+            options: WindowOptions {
+                explode: false,
+                frame: None,
+                mapping: Default::default(),
+            },
+        }
+    }
+
+    /// Apply the window function [`over`](Expr::over) the groups and determine with `mapping`
+    /// how the results of the groups are returned:
+    ///
+    /// * [`WindowMapping::GroupsToRows`] - map the results back to the rows (same as `over`).
+    /// * [`WindowMapping::Explode`] - explode the results of the groups, the output is ordered
+    ///   by group instead of by row and its length may differ from the input.
+    /// * [`WindowMapping::Join`] - give every row the results of its group as a `List`.
+    pub fn over_with_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        partition_by: E,
+        mapping: WindowMapping,
+    ) -> Self {
+        match self.over(partition_by) {
+            Expr::Window {
+                function,
+                partition_by,
+                order_by,
+                mut options,
+            } => {
+                options.mapping = mapping;
+                Expr::Window {
+                    function,
+                    partition_by,
+                    order_by,
+                    options,
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Evaluate the window function over a frame of rows around every row, instead of over
+    /// the whole partition. `start` and `end` are inclusive row offsets relative to the
+    /// current row, e.g. `rows_between(-3, 0)` spans the 3 preceding rows and the current row.
+    /// Use `i64::MIN` and `i64::MAX` for unbounded frames.
+    ///
+    /// The rows are in the order of the `DataFrame`. If this is not a window expression
+    /// (see [`Expr::over`]), the whole column is a single partition.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_plan::prelude::*;
+    /// // moving sum over the current and the 3 preceding rows of every group
+    /// col("x").sum().over([col("g")]).rows_between(-3, 0);
+    /// ```
+    pub fn rows_between(self, start: i64, end: i64) -> Self {
+        self.with_window_frame(None, WindowFrame::Rows { start, end })
+    }
+
+    /// Evaluate the window function over a frame of rows around every row, instead of over
+    /// the whole partition. The frame holds the rows of the partition of which the value of
+    /// `order_by` lies within `[value + start, value + end]`, where `value` is the value of
+    /// the current row. Use `i64::MIN` and `i64::MAX` for unbounded frames.
+    ///
+    /// The ordering column must be an integer or temporal column without nulls; the offsets are
+    /// in units of its physical representation.
+    pub fn range_between<E: Into<Expr>>(self, order_by: E, start: i64, end: i64) -> Self {
+        self.with_window_frame(Some(order_by.into()), WindowFrame::Range { start, end })
+    }
+
+    fn with_window_frame(self, order_by: Option<Expr>, frame: WindowFrame) -> Self {
+        let order_by = order_by.map(Box::new);
+        match self {
+            Expr::Window {
+                function,
+                partition_by,
+                order_by: previous_order_by,
+                mut options,
+            } => {
+                options.frame = Some(frame);
+                Expr::Window {
+                    function,
+                    partition_by,
+                    order_by: order_by.or(previous_order_by),
+                    options,
+                }
+            }
+            function => Expr::Window {
+                function: Box::new(function),
+                partition_by: vec![],
+                order_by,
+                options: WindowOptions {
+                    explode: false,
+                    frame: Some(frame),
+                    mapping: Default::default(),
+                },
+            },
         }
     }
 
@@ -1104,11 +1304,18 @@ impl Expr {
             .then(self)
             .otherwise(fill_value.into())
     }
-    /// Count the values of the Series
+    /// Count the non-null values of the Series
     /// or
-    /// Get counts of the group by operation.
+    /// Get the non-null counts of the group by operation.
     pub fn count(self) -> Self {
-        AggExpr::Count(Box::new(self)).into()
+        AggExpr::Count(Box::new(self), false).into()
+    }
+
+    /// Get the number of values of the Series, including nulls
+    /// or
+    /// Get the group lengths of the group by operation.
+    pub fn len(self) -> Self {
+        AggExpr::Count(Box::new(self), true).into()
     }
 
     /// Standard deviation of the values of the Series
@@ -1173,6 +1380,29 @@ impl Expr {
         }
     }
 
+    /// Check if the values are between `lower` and `upper` (both inclusive).
+    ///
+    /// The bounds may be literals or expressions, in which case they are evaluated per row.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_between<E: Into<Expr>>(self, lower: E, upper: E) -> Self {
+        self.clone().gt_eq(lower).and(self.lt_eq(upper))
+    }
+
+    /// Check if the values are in any of the `ranges` of `(lower, upper)` bounds (both
+    /// inclusive). See [`Expr::is_between`].
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_in_ranges<E, I>(self, ranges: I) -> Self
+    where
+        E: Into<Expr>,
+        I: IntoIterator<Item = (E, E)>,
+    {
+        ranges
+            .into_iter()
+            .map(|(lower, upper)| self.clone().is_between(lower, upper))
+            .reduce(|acc, in_range| acc.or(in_range))
+            .unwrap_or_else(|| lit(false))
+    }
+
     /// Check if the values of the left expression are in the lists of the right expr.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_in")]
@@ -1861,6 +2091,41 @@ impl Expr {
                 options
             })
     }
+
+    #[cfg(feature = "log")]
+    /// Compute the gini impurity `1 - sum(pk^2)`, where `pk` are the relative
+    /// frequencies of the distinct values.
+    pub fn gini_impurity(self) -> Self {
+        self.apply_private(FunctionExpr::GiniImpurity)
+            .with_function_options(|mut options| {
+                options.auto_explode = true;
+                options
+            })
+    }
+
+    #[cfg(feature = "log")]
+    /// Compute the weight of evidence of every bin (distinct value) of this
+    /// expression with respect to a binary `target`.
+    pub fn weight_of_evidence<E: Into<Expr>>(self, target: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightOfEvidence,
+            &[target.into()],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "log")]
+    /// Compute the information value of the bins (distinct values) of this
+    /// expression with respect to a binary `target`.
+    pub fn information_value<E: Into<Expr>>(self, target: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::InformationValue,
+            &[target.into()],
+            true,
+            false,
+        )
+    }
     /// Get the null count of the column/group
     pub fn null_count(self) -> Expr {
         self.apply_private(FunctionExpr::NullCount)
@@ -1907,6 +2172,10 @@ impl Expr {
     pub fn arr(self) -> list::ListNameSpace {
         list::ListNameSpace(self)
     }
+    /// The operations on `List` columns, the same namespace as [`Expr::arr`].
+    pub fn list(self) -> list::ListNameSpace {
+        list::ListNameSpace(self)
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn cat(self) -> cat::CategoricalNameSpace {
         cat::CategoricalNameSpace(self)
@@ -2017,6 +2286,11 @@ pub fn count() -> Expr {
     Expr::Count
 }
 
+/// Number of rows in the `DataFrame` or group. This is an alias for [`count`].
+pub fn len() -> Expr {
+    Expr::Count
+}
+
 /// First column in DataFrame
 pub fn first() -> Expr {
     Expr::Nth(0)
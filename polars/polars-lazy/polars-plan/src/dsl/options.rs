@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use polars_core::datatypes::DataType;
-use polars_core::prelude::{JoinType, TimeUnit};
+use polars_core::prelude::{JoinAlgorithm, JoinType, JoinValidation, TimeUnit};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,6 +45,12 @@ pub struct JoinOptions {
     pub allow_parallel: bool,
     pub force_parallel: bool,
     pub how: JoinType,
+    /// Hint for the algorithm used to find the matching keys.
+    pub algorithm: JoinAlgorithm,
+    /// Uniqueness of the keys that is checked before joining.
+    pub validate: JoinValidation,
+    /// Spill to disk if the tables don't fit in memory. Only used by the streaming engine.
+    pub low_memory: bool,
     pub suffix: Cow<'static, str>,
     pub slice: Option<(i64, usize)>,
     /// Proxy of the number of rows in both sides of the joins
@@ -59,6 +65,9 @@ impl Default for JoinOptions {
             allow_parallel: true,
             force_parallel: false,
             how: JoinType::Left,
+            algorithm: JoinAlgorithm::Auto,
+            validate: JoinValidation::ManyToMany,
+            low_memory: false,
             suffix: "_right".into(),
             slice: None,
             rows_left: (None, usize::MAX),
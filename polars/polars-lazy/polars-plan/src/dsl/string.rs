@@ -89,6 +89,77 @@ impl StringNameSpace {
             .map_private(StringFunction::RJust { width, fillchar }.into())
     }
 
+    /// Replace all but the first `keep_prefix` and the last `keep_suffix` characters
+    /// with `mask_char`. Values that are too short to keep anything are masked entirely.
+    #[cfg(feature = "string_anonymize")]
+    pub fn mask(self, keep_prefix: usize, keep_suffix: usize, mask_char: char) -> Expr {
+        self.0.map_private(
+            StringFunction::Mask {
+                keep_prefix,
+                keep_suffix,
+                mask_char,
+            }
+            .into(),
+        )
+    }
+
+    /// Hex encoded SHA-256 digest of the string values prefixed with `salt`.
+    #[cfg(feature = "string_anonymize")]
+    pub fn hash_sha256(self, salt: &str) -> Expr {
+        self.0
+            .map_private(StringFunction::HashSha256(salt.to_string()).into())
+    }
+
+    /// Replace every string value with a token derived from the value and `seed`.
+    /// Equal values always map to the same token for a given `seed`, so joins and
+    /// group-bys on the pseudonymized column keep working.
+    #[cfg(feature = "string_anonymize")]
+    pub fn pseudonymize(self, seed: u64) -> Expr {
+        self.0
+            .map_private(StringFunction::Pseudonymize(seed).into())
+    }
+
+    /// Parse formatted numbers, e.g. `"$1,234.50"`, as floats. The `thousands_separator` and
+    /// the `currency_symbols` are removed before parsing. Values that cannot be parsed are null,
+    /// or raise an error if `strict`.
+    #[cfg(feature = "string_to_number")]
+    pub fn to_float(
+        self,
+        thousands_separator: Option<&str>,
+        currency_symbols: &[&str],
+        strict: bool,
+    ) -> Expr {
+        self.0.map_private(
+            StringFunction::ToFloat {
+                thousands_separator: thousands_separator.map(|sep| sep.to_string()),
+                currency_symbols: currency_symbols.iter().map(|s| s.to_string()).collect(),
+                strict,
+            }
+            .into(),
+        )
+    }
+
+    /// Parse formatted numbers, e.g. `"$1,234.50"`, as decimals with the given `scale`. The
+    /// digits beyond the scale are truncated. See [`StringNameSpace::to_float`].
+    #[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+    pub fn to_decimal(
+        self,
+        scale: usize,
+        thousands_separator: Option<&str>,
+        currency_symbols: &[&str],
+        strict: bool,
+    ) -> Expr {
+        self.0.map_private(
+            StringFunction::ToDecimal {
+                scale,
+                thousands_separator: thousands_separator.map(|sep| sep.to_string()),
+                currency_symbols: currency_symbols.iter().map(|s| s.to_string()).collect(),
+                strict,
+            }
+            .into(),
+        )
+    }
+
     /// Extract each successive non-overlapping match in an individual string as an array
     pub fn extract_all(self, pat: Expr) -> Expr {
         self.0
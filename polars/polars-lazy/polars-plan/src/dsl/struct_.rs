@@ -17,6 +17,10 @@ impl StructNameSpace {
     }
 
     /// Retrieve one of the fields of this [`StructChunked`] as a new Series.
+    ///
+    /// With a name of `"*"` every field is retrieved and with a regex that starts with `^` and
+    /// ends with `$` every field that matches it. Those expand to one expression per field,
+    /// like `col("*")` does for the columns of a frame.
     pub fn field_by_name(self, name: &str) -> Expr {
         self.0
             .map_private(FunctionExpr::StructExpr(StructFunction::FieldByName(
@@ -28,6 +32,43 @@ impl StructNameSpace {
             })
     }
 
+    /// Expand the fields of this struct into separate columns.
+    ///
+    /// This allows a single expression to produce multiple outputs in a groupby
+    /// aggregation, so that work shared by the outputs is only done once per group.
+    /// The fields become columns named `{output_name}_{field}`. Outside of
+    /// `LazyGroupBy::agg` the struct is returned as is.
+    pub fn unnest(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::Unnest))
+    }
+
+    /// Add the outputs of `fields` as fields of the struct, a field of the same name is
+    /// replaced. The fields are named by their output names and evaluated in the context of
+    /// the struct column, so e.g. `col("s").struct_().field_by_name("a") * lit(2)` overwrites
+    /// field `a` with its double.
+    pub fn with_fields<E: AsRef<[Expr]>>(self, fields: E) -> Expr {
+        let mut input = vec![self.0];
+        input.extend_from_slice(fields.as_ref());
+        Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::WithFields),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                input_wildcard_expansion: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Prefix the names of the fields with `{prefix}_`.
+    pub(crate) fn prefix_fields(self, prefix: &str) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::PrefixFields(
+                Arc::from(prefix),
+            )))
+    }
+
     /// Rename the fields of the [`StructChunked`].
     pub fn rename_fields(self, names: Vec<String>) -> Expr {
         let names = Arc::new(names);
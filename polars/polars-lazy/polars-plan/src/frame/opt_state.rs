@@ -10,6 +10,8 @@ pub struct OptState {
     #[cfg(feature = "cse")]
     pub common_subplan_elimination: bool,
     pub streaming: bool,
+    pub aggregate_pushdown: bool,
+    pub join_reorder: bool,
 }
 
 impl Default for OptState {
@@ -25,6 +27,8 @@ impl Default for OptState {
             #[cfg(feature = "cse")]
             common_subplan_elimination: true,
             streaming: false,
+            aggregate_pushdown: true,
+            join_reorder: true,
         }
     }
 }
@@ -7,4 +7,6 @@ pub mod frame;
 pub mod global;
 pub mod logical_plan;
 pub mod prelude;
+#[cfg(feature = "serde")]
+pub mod serialize;
 pub mod utils;
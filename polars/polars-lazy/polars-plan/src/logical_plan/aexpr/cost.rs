@@ -0,0 +1,117 @@
+use super::*;
+#[cfg(feature = "strings")]
+use crate::dsl::function_expr::StringFunction;
+
+/// Split a predicate into the expressions that are combined with `&`.
+///
+/// `a & (b & c)` results in `[a, b, c]`.
+pub fn split_conjunctions(node: Node, expr_arena: &Arena<AExpr>) -> Vec<Node> {
+    let mut out = vec![];
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        match expr_arena.get(node) {
+            AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            } => {
+                // Pushed in reverse so that the original order is kept.
+                stack.push(*right);
+                stack.push(*left);
+            }
+            _ => out.push(node),
+        }
+    }
+    out
+}
+
+/// Estimate the per-row cost of evaluating an expression.
+///
+/// The numbers only have a meaning relative to each other: a column or a numeric
+/// comparison is cheap, string matching is more expensive and regex matching is
+/// the most expensive.
+pub fn aexpr_cost(node: Node, expr_arena: &Arena<AExpr>) -> usize {
+    let own_cost = match expr_arena.get(node) {
+        AExpr::Literal(_) => 0,
+        AExpr::Cast { .. } | AExpr::Ternary { .. } => 2,
+        AExpr::Function { function, .. } => match function {
+            #[cfg(feature = "is_in")]
+            FunctionExpr::Boolean(BooleanFunction::IsIn) => 5,
+            #[cfg(all(feature = "strings", feature = "regex"))]
+            FunctionExpr::StringExpr(StringFunction::Contains { literal, .. }) => {
+                if *literal {
+                    10
+                } else {
+                    50
+                }
+            }
+            #[cfg(feature = "strings")]
+            FunctionExpr::StringExpr(StringFunction::StartsWith | StringFunction::EndsWith) => 5,
+            _ => 10,
+        },
+        AExpr::AnonymousFunction { .. } => 100,
+        _ => 1,
+    };
+    let mut inputs = vec![];
+    expr_arena.get(node).nodes(&mut inputs);
+    own_cost
+        + inputs
+            .into_iter()
+            .map(|input| aexpr_cost(input, expr_arena))
+            .sum::<usize>()
+}
+
+/// Check whether an expression only combines values of the same row.
+///
+/// The outcome of such an expression on a row doesn't change if other rows are
+/// filtered out first, so the predicates of a filter can be applied one by one.
+pub fn aexpr_is_row_local(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    match expr_arena.get(node) {
+        AExpr::Column(_) => true,
+        AExpr::Literal(lv) => !matches!(lv, LiteralValue::Series(_) | LiteralValue::Range { .. }),
+        AExpr::Alias(input, _) | AExpr::Cast { expr: input, .. } => {
+            aexpr_is_row_local(*input, expr_arena)
+        }
+        AExpr::BinaryExpr { left, right, .. } => {
+            aexpr_is_row_local(*left, expr_arena) && aexpr_is_row_local(*right, expr_arena)
+        }
+        AExpr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => [predicate, truthy, falsy]
+            .iter()
+            .all(|node| aexpr_is_row_local(**node, expr_arena)),
+        AExpr::Function {
+            input, function, ..
+        } => match function {
+            // The values to check against may be any (literal) series.
+            #[cfg(feature = "is_in")]
+            FunctionExpr::Boolean(BooleanFunction::IsIn) => {
+                aexpr_is_row_local(input[0], expr_arena)
+                    && matches!(expr_arena.get(input[1]), AExpr::Literal(_))
+            }
+            FunctionExpr::Boolean(
+                BooleanFunction::IsNot
+                | BooleanFunction::IsNull
+                | BooleanFunction::IsNotNull
+                | BooleanFunction::IsFinite
+                | BooleanFunction::IsInfinite
+                | BooleanFunction::IsNan
+                | BooleanFunction::IsNotNan,
+            ) => aexpr_is_row_local(input[0], expr_arena),
+            #[cfg(all(feature = "strings", feature = "regex"))]
+            FunctionExpr::StringExpr(StringFunction::Contains { .. }) => input
+                .iter()
+                .all(|node| aexpr_is_row_local(*node, expr_arena)),
+            #[cfg(feature = "strings")]
+            FunctionExpr::StringExpr(StringFunction::StartsWith | StringFunction::EndsWith) => {
+                input
+                    .iter()
+                    .all(|node| aexpr_is_row_local(*node, expr_arena))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
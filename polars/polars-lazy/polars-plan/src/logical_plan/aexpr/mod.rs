@@ -1,3 +1,4 @@
+mod cost;
 mod schema;
 
 use std::sync::Arc;
@@ -7,6 +8,7 @@ use polars_core::prelude::*;
 use polars_core::utils::{get_time_units, try_get_supertype};
 use polars_utils::arena::{Arena, Node};
 
+pub use self::cost::*;
 use crate::dsl::function_expr::FunctionExpr;
 use crate::logical_plan::Context;
 use crate::prelude::aexpr::NodeInputs::Single;
@@ -23,10 +25,21 @@ pub enum AAggExpr {
         input: Node,
         propagate_nans: bool,
     },
+    MinBy {
+        input: Node,
+        by: Node,
+    },
+    MaxBy {
+        input: Node,
+        by: Node,
+    },
     Median(Node),
     NUnique(Node),
+    ApproxNUnique(Node),
     First(Node),
     Last(Node),
+    FirstNonNull(Node, bool),
+    LastNonNull(Node, bool),
     Mean(Node),
     Implode(Node),
     Quantile {
@@ -35,7 +48,7 @@ pub enum AAggExpr {
         interpol: QuantileInterpolOptions,
     },
     Sum(Node),
-    Count(Node),
+    Count(Node, bool),
     Std(Node, u8),
     Var(Node, u8),
     AggGroups(Node),
@@ -230,15 +243,18 @@ impl AAggExpr {
         match self {
             Min { input, .. } => Single(*input),
             Max { input, .. } => Single(*input),
+            MinBy { input, by } | MaxBy { input, by } => NodeInputs::Many(vec![*input, *by]),
             Median(input) => Single(*input),
             NUnique(input) => Single(*input),
+            ApproxNUnique(input) => Single(*input),
             First(input) => Single(*input),
             Last(input) => Single(*input),
+            FirstNonNull(input, _) | LastNonNull(input, _) => Single(*input),
             Mean(input) => Single(*input),
             Implode(input) => Single(*input),
             Quantile { expr, .. } => Single(*expr),
             Sum(input) => Single(*input),
-            Count(input) => Single(*input),
+            Count(input, _) => Single(*input),
             Std(input, _) => Single(*input),
             Var(input, _) => Single(*input),
             AggGroups(input) => Single(*input),
@@ -1,3 +1,5 @@
+use polars_core::config::get_agg_dtype_policy;
+
 use super::*;
 
 fn float_type(field: &mut Field) {
@@ -18,9 +20,15 @@ impl AExpr {
         use DataType::*;
         match self {
             Count => Ok(Field::new(COUNT, IDX_DTYPE)),
-            Window { function, .. } => {
+            Window {
+                function, options, ..
+            } => {
                 let e = arena.get(*function);
-                e.to_field(schema, ctxt, arena)
+                let mut field = e.to_field(schema, ctxt, arena)?;
+                if options.mapping == WindowMapping::Join && !matches!(field.dtype, List(_)) {
+                    field.coerce(List(Box::new(field.dtype.clone())));
+                }
+                Ok(field)
             }
             Explode(expr) => {
                 let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
@@ -60,7 +68,9 @@ impl AExpr {
                     Operator::Lt
                     | Operator::Gt
                     | Operator::Eq
+                    | Operator::EqValidity
                     | Operator::NotEq
+                    | Operator::NotEqValidity
                     | Operator::And
                     | Operator::LtEq
                     | Operator::GtEq
@@ -87,22 +97,19 @@ impl AExpr {
                 match agg {
                     Max { input: expr, .. }
                     | Min { input: expr, .. }
+                    | MinBy { input: expr, .. }
+                    | MaxBy { input: expr, .. }
                     | First(expr)
-                    | Last(expr) => {
+                    | Last(expr)
+                    | FirstNonNull(expr, false)
+                    | LastNonNull(expr, false) => {
                         // default context because `col()` would return a list in aggregation context
                         arena.get(*expr).to_field(schema, Context::Default, arena)
                     }
                     Sum(expr) => {
                         let mut field =
                             arena.get(*expr).to_field(schema, Context::Default, arena)?;
-                        let dt = match field.data_type() {
-                            Boolean => Some(IDX_DTYPE),
-                            UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
-                            _ => None,
-                        };
-                        if let Some(dt) = dt {
-                            field.coerce(dt);
-                        }
+                        field.coerce(get_agg_dtype_policy().sum_dtype(field.data_type()));
                         Ok(field)
                     }
                     Median(expr) => {
@@ -114,7 +121,7 @@ impl AExpr {
                     Mean(expr) => {
                         let mut field =
                             arena.get(*expr).to_field(schema, Context::Default, arena)?;
-                        float_type(&mut field);
+                        field.coerce(get_agg_dtype_policy().mean_dtype(field.data_type()));
                         Ok(field)
                     }
                     Implode(expr) => {
@@ -142,7 +149,13 @@ impl AExpr {
                         field.coerce(DataType::UInt32);
                         Ok(field)
                     }
-                    Count(expr) => {
+                    ApproxNUnique(expr) | FirstNonNull(expr, true) | LastNonNull(expr, true) => {
+                        let mut field =
+                            arena.get(*expr).to_field(schema, Context::Default, arena)?;
+                        field.coerce(IDX_DTYPE);
+                        Ok(field)
+                    }
+                    Count(expr, _) => {
                         let mut field =
                             arena.get(*expr).to_field(schema, Context::Default, arena)?;
                         field.coerce(IDX_DTYPE);
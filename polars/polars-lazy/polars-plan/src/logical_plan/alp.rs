@@ -51,6 +51,7 @@ pub enum ALogicalPlan {
         output_schema: Option<SchemaRef>,
         options: CsvParserOptions,
         predicate: Option<Node>,
+        cloud_options: Option<CloudOptions>,
     },
     #[cfg(feature = "ipc")]
     IpcScan {
@@ -409,7 +410,7 @@ impl ALogicalPlan {
                 output_schema,
                 predicate,
                 options,
-                ..
+                cloud_options,
             } => {
                 let mut new_predicate = None;
                 if predicate.is_some() {
@@ -421,6 +422,7 @@ impl ALogicalPlan {
                     output_schema: output_schema.clone(),
                     options: options.clone(),
                     predicate: new_predicate,
+                    cloud_options: cloud_options.clone(),
                 }
             }
             DataFrameScan {
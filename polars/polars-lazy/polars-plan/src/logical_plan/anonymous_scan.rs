@@ -5,11 +5,26 @@ use polars_core::prelude::*;
 
 pub use super::options::AnonymousScanOptions;
 
+/// The batches of an [`AnonymousScan`], in order.
+pub type AnonymousScanBatches = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a dataframe from the supplied function & scan options.
     fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame>;
 
+    /// Creates the batches of the scan, so that the streaming engine can process them as they
+    /// arrive instead of waiting for the whole dataframe. Only called if
+    /// [`AnonymousScan::allows_streaming`] is `true`.
+    fn scan_batches(&self, _scan_opts: AnonymousScanOptions) -> PolarsResult<AnonymousScanBatches> {
+        polars_bail!(ComputeError: "this anonymous scan cannot be streamed");
+    }
+    /// specify if the scan provides its batches with [`AnonymousScan::scan_batches`]
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
     /// function to supply the schema.
     /// Allows for an optional infer schema argument for data sources with dynamic schemas
     fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
@@ -21,6 +36,14 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_predicate_pushdown(&self) -> bool {
         false
     }
+    /// specify if the scan only returns the rows that match the pushed down predicate.
+    /// If `false`, the predicate is a hint, e.g. to skip partitions or to filter by the part
+    /// of the predicate the source supports, and the rows the scan returns are filtered again.
+    ///
+    /// Defaults to `true`
+    fn predicate_pushdown_is_exact(&self) -> bool {
+        true
+    }
     /// specify if the scan provider should allow projection pushdowns
     ///
     /// Defaults to `false`
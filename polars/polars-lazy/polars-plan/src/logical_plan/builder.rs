@@ -17,10 +17,16 @@ use polars_io::RowCount;
 #[cfg(feature = "csv")]
 use polars_io::{
     csv::utils::{get_reader_bytes, infer_file_schema, is_compressed},
+    csv::CsvDelimiter,
     csv::CsvEncoding,
+    csv::HeaderNormalization,
     csv::NullValues,
+    csv::NumberFormat,
+    FollowOptions,
 };
 
+#[cfg(feature = "dtype-struct")]
+use crate::dsl::function_expr::StructFunction;
 use crate::logical_plan::functions::FunctionNode;
 use crate::logical_plan::projection::{is_regex_projection, rewrite_projections};
 use crate::logical_plan::schema::{det_join_schema, FileInfo};
@@ -36,6 +42,49 @@ pub(crate) fn prepare_projection(
     Ok((exprs, schema))
 }
 
+/// Strip the `struct.unnest` markers from the aggregations and return the output names of
+/// the aggregations that should be unnested after the groupby.
+#[cfg(feature = "dtype-struct")]
+fn split_struct_unnest(aggs: Vec<Expr>) -> PolarsResult<(Vec<Expr>, Vec<Arc<str>>)> {
+    fn strip(e: Expr) -> Expr {
+        match e {
+            Expr::Alias(input, name) => Expr::Alias(Box::new(strip(*input)), name),
+            Expr::Function {
+                mut input,
+                function: FunctionExpr::StructExpr(StructFunction::Unnest),
+                ..
+            } => input.pop().unwrap(),
+            e => e,
+        }
+    }
+    fn is_marked(e: &Expr) -> bool {
+        match e {
+            Expr::Alias(input, _) => is_marked(input),
+            Expr::Function {
+                function: FunctionExpr::StructExpr(StructFunction::Unnest),
+                ..
+            } => true,
+            _ => false,
+        }
+    }
+
+    let mut unnest = vec![];
+    let aggs = aggs
+        .into_iter()
+        .map(|e| {
+            if is_marked(&e) {
+                let name = expr_output_name(&e)?;
+                let e = strip(e).struct_().prefix_fields(&name).alias(&name);
+                unnest.push(name);
+                Ok(e)
+            } else {
+                Ok(e)
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok((aggs, unnest))
+}
+
 pub struct LogicalPlanBuilder(LogicalPlan);
 
 impl From<LogicalPlan> for LogicalPlanBuilder {
@@ -91,6 +140,8 @@ impl LogicalPlanBuilder {
         let file_info = FileInfo {
             schema: schema.clone(),
             row_estimation: (n_rows, n_rows.unwrap_or(usize::MAX)),
+            hive_parts: None,
+            statistics: None,
         };
         Ok(LogicalPlan::AnonymousScan {
             function,
@@ -121,11 +172,16 @@ impl LogicalPlanBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        hive_parts: Option<Arc<HivePartitions>>,
+        file_info: Option<FileInfo>,
     ) -> PolarsResult<Self> {
         use polars_io::{is_cloud_url, SerReader as _};
 
         let path = path.into();
-        let file_info: PolarsResult<FileInfo> = if is_cloud_url(&path) {
+        // a known file info, e.g. of another file of the same dataset, saves reading the footer
+        let file_info: PolarsResult<FileInfo> = if let Some(file_info) = file_info {
+            Ok(file_info)
+        } else if is_cloud_url(&path) {
             #[cfg(not(feature = "async"))]
             panic!(
                 "One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled."
@@ -139,19 +195,37 @@ impl LogicalPlanBuilder {
                 Ok(FileInfo {
                     schema: Arc::new(schema),
                     row_estimation: (Some(num_rows), num_rows),
+                    hive_parts: None,
+                    statistics: None,
                 })
             }
         } else {
             let file = std::fs::File::open(&path)?;
-            let mut reader = ParquetReader::new(file);
+            let mut reader = ParquetReader::new(file)
+                .with_metadata(polars_io::parquet::read_metadata_cached(&path)?);
             let schema = Arc::new(reader.schema()?);
             let num_rows = reader.num_rows()?;
             Ok(FileInfo {
                 schema,
                 row_estimation: (Some(num_rows), num_rows),
+                hive_parts: None,
+                statistics: None,
             })
         };
-        let file_info = file_info?;
+        let mut file_info = file_info?;
+        if let Some(hive_parts) = hive_parts {
+            // the partition columns follow the columns of the file
+            let mut schema = (*file_info.schema).clone();
+            for (name, dtype) in hive_parts.schema().iter() {
+                polars_ensure!(
+                    !schema.contains(name),
+                    Duplicate: "column '{}' of {} is also a hive partition", name, path.display()
+                );
+                schema.with_column(name.clone(), dtype.clone());
+            }
+            file_info.schema = Arc::new(schema);
+            file_info.hive_parts = Some(hive_parts);
+        }
 
         Ok(LogicalPlan::ParquetScan {
             path,
@@ -189,6 +263,8 @@ impl LogicalPlanBuilder {
         let file_info = FileInfo {
             schema,
             row_estimation: (None, num_rows),
+            hive_parts: None,
+            statistics: None,
         };
         Ok(LogicalPlan::IpcScan {
             path,
@@ -203,7 +279,7 @@ impl LogicalPlanBuilder {
     #[cfg(feature = "csv")]
     pub fn scan_csv<P: Into<std::path::PathBuf>>(
         path: P,
-        delimiter: u8,
+        delimiter: CsvDelimiter,
         has_header: bool,
         ignore_errors: bool,
         mut skip_rows: usize,
@@ -222,9 +298,14 @@ impl LogicalPlanBuilder {
         encoding: CsvEncoding,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        header_normalization: Option<HeaderNormalization>,
+        datetime_formats: Option<Vec<String>>,
+        number_format: Option<NumberFormat>,
+        follow: Option<FollowOptions>,
+        cloud_options: Option<CloudOptions>,
     ) -> PolarsResult<Self> {
         let path = path.into();
-        let mut file = std::fs::File::open(&path)?;
+        let mut file = polars_io::csv::open_file_or_url(&path, cloud_options.as_ref())?;
         let mut magic_nr = [0u8; 2];
         file.read_exact(&mut magic_nr)
             .map_err(|_| polars_err!(NoData: "empty csv"))?;
@@ -250,6 +331,9 @@ impl LogicalPlanBuilder {
             eol_char,
             null_values.as_ref(),
             try_parse_dates,
+            header_normalization.as_ref(),
+            datetime_formats.as_deref(),
+            number_format.as_ref(),
         )?;
 
         let schema = schema.unwrap_or_else(|| Arc::new(inferred_schema));
@@ -260,6 +344,8 @@ impl LogicalPlanBuilder {
         let file_info = FileInfo {
             schema,
             row_estimation: (None, estimated_n_rows),
+            hive_parts: None,
+            statistics: None,
         };
         Ok(LogicalPlan::CsvScan {
             path,
@@ -281,9 +367,13 @@ impl LogicalPlanBuilder {
                 encoding,
                 row_count,
                 try_parse_dates,
+                datetime_formats,
+                number_format,
+                follow,
                 file_counter: Default::default(),
             },
             predicate: None,
+            cloud_options,
         }
         .into())
     }
@@ -496,6 +586,8 @@ impl LogicalPlanBuilder {
             &self.0,
             into
         );
+        #[cfg(feature = "dtype-struct")]
+        let (aggs, unnest) = try_delayed!(split_struct_unnest(aggs), &self.0, into);
 
         let mut schema = try_delayed!(
             utils::expressions_to_schema(&keys, current_schema, Context::Default),
@@ -555,7 +647,7 @@ impl LogicalPlanBuilder {
         #[cfg(not(feature = "dynamic_groupby"))]
         let options = GroupbyOptions { slice: None };
 
-        LogicalPlan::Aggregate {
+        let lp = LogicalPlan::Aggregate {
             input: Box::new(self.0),
             keys: Arc::new(keys),
             aggs,
@@ -563,8 +655,19 @@ impl LogicalPlanBuilder {
             apply,
             maintain_order,
             options,
+        };
+        #[cfg(feature = "dtype-struct")]
+        if !unnest.is_empty() {
+            return LogicalPlan::MapFunction {
+                input: Box::new(lp),
+                function: FunctionNode::Unnest {
+                    columns: unnest.into(),
+                    options: Default::default(),
+                },
+            }
+            .into();
         }
-        .into()
+        lp.into()
     }
 
     pub fn build(self) -> LogicalPlan {
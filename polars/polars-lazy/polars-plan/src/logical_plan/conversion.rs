@@ -68,13 +68,30 @@ pub fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                     input: to_aexpr(*input, arena),
                     propagate_nans,
                 },
+                AggExpr::MinBy { input, by } => AAggExpr::MinBy {
+                    input: to_aexpr(*input, arena),
+                    by: to_aexpr(*by, arena),
+                },
+                AggExpr::MaxBy { input, by } => AAggExpr::MaxBy {
+                    input: to_aexpr(*input, arena),
+                    by: to_aexpr(*by, arena),
+                },
                 AggExpr::Median(expr) => AAggExpr::Median(to_aexpr(*expr, arena)),
                 AggExpr::NUnique(expr) => AAggExpr::NUnique(to_aexpr(*expr, arena)),
+                AggExpr::ApproxNUnique(expr) => AAggExpr::ApproxNUnique(to_aexpr(*expr, arena)),
                 AggExpr::First(expr) => AAggExpr::First(to_aexpr(*expr, arena)),
                 AggExpr::Last(expr) => AAggExpr::Last(to_aexpr(*expr, arena)),
+                AggExpr::FirstNonNull(expr, arg) => {
+                    AAggExpr::FirstNonNull(to_aexpr(*expr, arena), arg)
+                }
+                AggExpr::LastNonNull(expr, arg) => {
+                    AAggExpr::LastNonNull(to_aexpr(*expr, arena), arg)
+                }
                 AggExpr::Mean(expr) => AAggExpr::Mean(to_aexpr(*expr, arena)),
                 AggExpr::Implode(expr) => AAggExpr::Implode(to_aexpr(*expr, arena)),
-                AggExpr::Count(expr) => AAggExpr::Count(to_aexpr(*expr, arena)),
+                AggExpr::Count(expr, include_nulls) => {
+                    AAggExpr::Count(to_aexpr(*expr, arena), include_nulls)
+                }
                 AggExpr::Quantile {
                     expr,
                     quantile,
@@ -208,12 +225,14 @@ pub fn to_alp(
             file_info,
             options,
             predicate,
+            cloud_options,
         } => ALogicalPlan::CsvScan {
             path,
             file_info,
             output_schema: None,
             options,
             predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
+            cloud_options,
         },
         #[cfg(feature = "ipc")]
         LogicalPlan::IpcScan {
@@ -502,6 +521,16 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 }
                 .into()
             }
+            AAggExpr::MinBy { input, by } => AggExpr::MinBy {
+                input: Box::new(node_to_expr(input, expr_arena)),
+                by: Box::new(node_to_expr(by, expr_arena)),
+            }
+            .into(),
+            AAggExpr::MaxBy { input, by } => AggExpr::MaxBy {
+                input: Box::new(node_to_expr(input, expr_arena)),
+                by: Box::new(node_to_expr(by, expr_arena)),
+            }
+            .into(),
 
             AAggExpr::Median(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
@@ -511,6 +540,10 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::NUnique(Box::new(exp)).into()
             }
+            AAggExpr::ApproxNUnique(expr) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::ApproxNUnique(Box::new(exp)).into()
+            }
             AAggExpr::First(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::First(Box::new(exp)).into()
@@ -519,6 +552,14 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Last(Box::new(exp)).into()
             }
+            AAggExpr::FirstNonNull(expr, arg) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::FirstNonNull(Box::new(exp), arg).into()
+            }
+            AAggExpr::LastNonNull(expr, arg) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::LastNonNull(Box::new(exp), arg).into()
+            }
             AAggExpr::Mean(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Mean(Box::new(exp)).into()
@@ -557,9 +598,9 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::AggGroups(Box::new(exp)).into()
             }
-            AAggExpr::Count(expr) => {
+            AAggExpr::Count(expr, include_nulls) => {
                 let exp = node_to_expr(expr, expr_arena);
-                AggExpr::Count(Box::new(exp)).into()
+                AggExpr::Count(Box::new(exp), include_nulls).into()
             }
         },
         AExpr::Ternary {
@@ -691,11 +732,13 @@ impl ALogicalPlan {
                 output_schema: _,
                 options,
                 predicate,
+                cloud_options,
             } => LogicalPlan::CsvScan {
                 path,
                 file_info,
                 options,
                 predicate: predicate.map(|n| node_to_expr(n, expr_arena)),
+                cloud_options,
             },
             #[cfg(feature = "ipc")]
             ALogicalPlan::IpcScan {
@@ -280,8 +280,12 @@ impl Debug for Expr {
             Window {
                 function,
                 partition_by,
+                options,
                 ..
-            } => write!(f, "{function:?}.over({partition_by:?})"),
+            } => match options.frame {
+                Some(frame) => write!(f, "{function:?}.over({partition_by:?}).{frame}"),
+                None => write!(f, "{function:?}.over({partition_by:?})"),
+            },
             Nth(i) => write!(f, "nth({i})"),
             Count => write!(f, "count()"),
             Explode(expr) => write!(f, "{expr:?}.explode()"),
@@ -339,15 +343,23 @@ impl Debug for Expr {
                             write!(f, "{input:?}.max()")
                         }
                     }
+                    MinBy { input, by } => write!(f, "{input:?}.min_by({by:?})"),
+                    MaxBy { input, by } => write!(f, "{input:?}.max_by({by:?})"),
                     Median(expr) => write!(f, "{expr:?}.median()"),
                     Mean(expr) => write!(f, "{expr:?}.mean()"),
                     First(expr) => write!(f, "{expr:?}.first()"),
                     Last(expr) => write!(f, "{expr:?}.last()"),
+                    FirstNonNull(expr, false) => write!(f, "{expr:?}.first_non_null()"),
+                    FirstNonNull(expr, true) => write!(f, "{expr:?}.arg_first_non_null()"),
+                    LastNonNull(expr, false) => write!(f, "{expr:?}.last_non_null()"),
+                    LastNonNull(expr, true) => write!(f, "{expr:?}.arg_last_non_null()"),
                     Implode(expr) => write!(f, "{expr:?}.list()"),
                     NUnique(expr) => write!(f, "{expr:?}.n_unique()"),
+                    ApproxNUnique(expr) => write!(f, "{expr:?}.approx_n_unique()"),
                     Sum(expr) => write!(f, "{expr:?}.sum()"),
                     AggGroups(expr) => write!(f, "{expr:?}.groups()"),
-                    Count(expr) => write!(f, "{expr:?}.count()"),
+                    Count(expr, false) => write!(f, "{expr:?}.count()"),
+                    Count(expr, true) => write!(f, "{expr:?}.len()"),
                     Var(expr, _) => write!(f, "{expr:?}.var()"),
                     Std(expr, _) => write!(f, "{expr:?}.var()"),
                     Quantile { expr, .. } => write!(f, "{expr:?}.quantile()"),
@@ -408,7 +420,9 @@ impl Debug for Operator {
         use Operator::*;
         let s = match self {
             Eq => "==",
+            EqValidity => "==v",
             NotEq => "!=",
+            NotEqValidity => "!=v",
             Lt => "<",
             LtEq => "<=",
             Gt => ">",
@@ -42,6 +42,7 @@ pub enum FunctionNode {
     },
     Unnest {
         columns: Arc<[Arc<str>]>,
+        options: UnnestOptions,
     },
     FastProjection {
         columns: Arc<[Arc<str>]>,
@@ -76,6 +77,11 @@ pub enum FunctionNode {
         args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
+    /// Take every `n`th row, starting at row `offset`.
+    GatherEvery {
+        n: usize,
+        offset: usize,
+    },
 }
 
 impl PartialEq for FunctionNode {
@@ -100,6 +106,16 @@ impl PartialEq for FunctionNode {
             (Drop { names: l }, Drop { names: r }) => l == r,
             (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
             (Melt { args: l, .. }, Melt { args: r, .. }) => l == r,
+            (
+                GatherEvery {
+                    n: n_l,
+                    offset: offset_l,
+                },
+                GatherEvery {
+                    n: n_r,
+                    offset: offset_r,
+                },
+            ) => n_l == n_r && offset_l == offset_r,
             _ => false,
         }
     }
@@ -110,7 +126,9 @@ impl FunctionNode {
     pub fn is_streamable(&self) -> bool {
         use FunctionNode::*;
         match self {
-            Rechunk | Pipeline { .. } => false,
+            // needs the position of the rows, so the streaming engine
+            // converts it to an operator of its own or a sink
+            Rechunk | Pipeline { .. } | GatherEvery { .. } => false,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => false,
             DropNulls { .. }
@@ -159,23 +177,19 @@ impl FunctionNode {
                     .collect::<PolarsResult<Schema>>()?;
                 Ok(Cow::Owned(Arc::new(schema)))
             }
-            DropNulls { .. } => Ok(Cow::Borrowed(input_schema)),
-            Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns: _columns } => {
+            DropNulls { .. } | Rechunk | GatherEvery { .. } => Ok(Cow::Borrowed(input_schema)),
+            Unnest {
+                columns: _columns,
+                options: _options,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
                     let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
                     for (name, dtype) in input_schema.iter() {
                         if _columns.iter().any(|item| item.as_ref() == name.as_str()) {
-                            if let DataType::Struct(flds) = dtype {
-                                for fld in flds {
-                                    new_schema
-                                        .with_column(fld.name().clone(), fld.data_type().clone());
-                                }
-                            } else {
-                                polars_bail!(
-                                    SchemaMismatch: "expected struct dtype, got: `{}`", dtype
-                                );
+                            let field = Field::new(name, dtype.clone());
+                            for fld in _options.unnest_field(&field)? {
+                                new_schema.with_column(fld.name().clone(), fld.data_type().clone());
                             }
                         } else {
                             new_schema.with_column(name.clone(), dtype.clone());
@@ -212,6 +226,8 @@ impl FunctionNode {
             | Drop { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
+            // a filter changes the positions of the rows
+            GatherEvery { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -227,7 +243,8 @@ impl FunctionNode {
             | Rename { .. }
             | Explode { .. }
             | Melt { .. }
-            | Drop { .. } => true,
+            | Drop { .. }
+            | GatherEvery { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             Pipeline { .. } => unimplemented!(),
@@ -237,7 +254,7 @@ impl FunctionNode {
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<[Arc<str>]> {
         use FunctionNode::*;
         match self {
-            Unnest { columns } => Cow::Borrowed(columns.as_ref()),
+            Unnest { columns, .. } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
@@ -257,10 +274,13 @@ impl FunctionNode {
             }
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column } => merge_sorted(&df, column.as_ref()),
-            Unnest { columns: _columns } => {
+            Unnest {
+                columns: _columns,
+                options: _options,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    df.unnest(_columns.as_ref())
+                    df.unnest_with_options(_columns.as_ref(), _options)
                 }
                 #[cfg(not(feature = "dtype-struct"))]
                 {
@@ -287,6 +307,7 @@ impl FunctionNode {
                 let args = (**args).clone();
                 df.melt2(args)
             }
+            GatherEvery { n, offset } => df.gather_every(*n, *offset),
         }
     }
 }
@@ -313,7 +334,7 @@ impl Display for FunctionNode {
                 fmt_column_delimited(f, subset, "[", "]")
             }
             Rechunk => write!(f, "RECHUNK"),
-            Unnest { columns } => {
+            Unnest { columns, .. } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")
@@ -334,6 +355,7 @@ impl Display for FunctionNode {
             Drop { .. } => write!(f, "DROP"),
             Explode { .. } => write!(f, "EXPLODE"),
             Melt { .. } => write!(f, "MELT"),
+            GatherEvery { n, offset } => write!(f, "GATHER_EVERY n: {n}, offset: {offset}"),
         }
     }
 }
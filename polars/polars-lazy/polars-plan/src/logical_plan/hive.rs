@@ -0,0 +1,50 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The partition values of a file in a hive partitioned dataset, which are exposed as
+/// columns of the scan of that file.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HivePartitions {
+    /// A single-row column for every partition key.
+    columns: Vec<Series>,
+}
+
+impl HivePartitions {
+    pub fn new(columns: Vec<Series>) -> Self {
+        Self { columns }
+    }
+
+    pub fn schema(&self) -> Schema {
+        self.columns
+            .iter()
+            .map(|s| s.field().into_owned())
+            .collect()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.columns.iter().any(|s| s.name() == name)
+    }
+
+    /// A single-row `DataFrame` of the partition values.
+    pub fn to_df(&self) -> DataFrame {
+        DataFrame::new_no_checks(self.columns.clone())
+    }
+
+    /// Append the partition columns to `df`, repeated to its height. If `with_columns` is
+    /// given, only the partition columns in it are added.
+    pub fn materialize(
+        &self,
+        df: &mut DataFrame,
+        with_columns: Option<&[String]>,
+    ) -> PolarsResult<()> {
+        let height = df.height();
+        for s in &self.columns {
+            if with_columns.map_or(true, |names| names.iter().any(|name| name == s.name())) {
+                df.with_column(s.new_from_index(0, height))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -33,13 +33,20 @@ macro_rules! push_expr {
                 match agg_e {
                     Max { input, .. } => $push(input),
                     Min { input, .. } => $push(input),
+                    MinBy { input, by } | MaxBy { input, by } => {
+                        $push(by);
+                        // latest, so that it is popped first
+                        $push(input);
+                    }
                     Mean(e) => $push(e),
                     Median(e) => $push(e),
                     NUnique(e) => $push(e),
+                    ApproxNUnique(e) => $push(e),
                     First(e) => $push(e),
                     Last(e) => $push(e),
+                    FirstNonNull(e, _) | LastNonNull(e, _) => $push(e),
                     Implode(e) => $push(e),
-                    Count(e) => $push(e),
+                    Count(e, _) => $push(e),
                     Quantile { expr, .. } => $push(expr),
                     Sum(e) => $push(e),
                     AggGroups(e) => $push(e),
@@ -129,6 +136,18 @@ impl<'a> ExprMut<'a> {
     }
 }
 
+impl Expr {
+    /// The direct inputs of this expression.
+    pub fn inputs<'a>(&'a self) -> Vec<&'a Expr> {
+        let mut inputs = Vec::with_capacity(2);
+        let mut push = |e: &'a Expr| inputs.push(e);
+        push_expr!(self, push, iter);
+        // the inputs are pushed in reverse order
+        inputs.reverse();
+        inputs
+    }
+}
+
 pub struct ExprIter<'a> {
     stack: Vec<&'a Expr>,
 }
@@ -195,13 +214,20 @@ impl AExpr {
                 match agg_e {
                     Max { input, .. } => push(input),
                     Min { input, .. } => push(input),
+                    MinBy { input, by } | MaxBy { input, by } => {
+                        push(by);
+                        // latest, so that it is popped first
+                        push(input);
+                    }
                     Mean(e) => push(e),
                     Median(e) => push(e),
                     NUnique(e) => push(e),
+                    ApproxNUnique(e) => push(e),
                     First(e) => push(e),
                     Last(e) => push(e),
+                    FirstNonNull(e, _) | LastNonNull(e, _) => push(e),
                     Implode(e) => push(e),
-                    Count(e) => push(e),
+                    Count(e, _) => push(e),
                     Quantile { expr, .. } => push(expr),
                     Sum(e) => push(e),
                     AggGroups(e) => push(e),
@@ -0,0 +1,193 @@
+//! Column level lineage: the columns of the scans an output column is derived from, and the
+//! expressions that compute it.
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+use crate::utils::aexpr_to_leaf_names_iter;
+
+/// A column of a scan that an output column is derived from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceColumn {
+    /// The path of the scanned file, or the kind of scan for in-memory and anonymous scans.
+    pub source: String,
+    pub column: String,
+}
+
+/// The lineage of an output column of a query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnLineage {
+    pub column: String,
+    pub sources: Vec<SourceColumn>,
+    /// The expressions that compute the column, from the output towards the sources. Columns
+    /// that are only selected or renamed are not included.
+    pub expressions: Vec<String>,
+}
+
+impl ColumnLineage {
+    fn add_source(&mut self, source: String, column: &str) {
+        let source = SourceColumn {
+            source,
+            column: column.to_string(),
+        };
+        if !self.sources.contains(&source) {
+            self.sources.push(source)
+        }
+    }
+
+    fn add_expression(&mut self, expr: String) {
+        if !self.expressions.contains(&expr) {
+            self.expressions.push(expr)
+        }
+    }
+}
+
+/// Get the lineage of the output column `name` of the plan at `root`.
+pub fn column_lineage(
+    root: Node,
+    name: &str,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<ColumnLineage> {
+    let schema = lp_arena.get(root).schema(lp_arena);
+    polars_ensure!(schema.contains(name), ColumnNotFound: "{}", name);
+    let mut lineage = ColumnLineage {
+        column: name.to_string(),
+        ..Default::default()
+    };
+    trace_column(root, name, lp_arena, expr_arena, &mut lineage)?;
+    Ok(lineage)
+}
+
+/// Trace the columns `expr` uses in the output of `input`.
+fn trace_expr(
+    expr: Node,
+    input: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    lineage: &mut ColumnLineage,
+) -> PolarsResult<()> {
+    let is_column = match expr_arena.get(expr) {
+        AExpr::Column(_) => true,
+        AExpr::Alias(input, _) => matches!(expr_arena.get(*input), AExpr::Column(_)),
+        _ => false,
+    };
+    if !is_column {
+        lineage.add_expression(format!("{:?}", node_to_expr(expr, expr_arena)));
+    }
+    for name in aexpr_to_leaf_names_iter(expr, expr_arena) {
+        trace_column(input, &name, lp_arena, expr_arena, lineage)?;
+    }
+    Ok(())
+}
+
+/// Trace the column `name` in the output of `node` back to the scans.
+fn trace_column(
+    node: Node,
+    name: &str,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    lineage: &mut ColumnLineage,
+) -> PolarsResult<()> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        #[cfg(feature = "csv")]
+        CsvScan { path, .. } => lineage.add_source(path.display().to_string(), name),
+        #[cfg(feature = "ipc")]
+        IpcScan { path, .. } => lineage.add_source(path.display().to_string(), name),
+        #[cfg(feature = "parquet")]
+        ParquetScan { path, .. } => lineage.add_source(path.display().to_string(), name),
+        #[cfg(feature = "python")]
+        PythonScan { .. } => lineage.add_source("python scan".to_string(), name),
+        AnonymousScan { .. } => lineage.add_source("anonymous scan".to_string(), name),
+        DataFrameScan { .. } => lineage.add_source("DataFrame".to_string(), name),
+        Projection {
+            input,
+            expr,
+            schema,
+        }
+        | LocalProjection {
+            input,
+            expr,
+            schema,
+        } => {
+            let expr = expr[schema.try_index_of(name)?];
+            trace_expr(expr, *input, lp_arena, expr_arena, lineage)?;
+        }
+        Aggregate {
+            input,
+            keys,
+            aggs,
+            schema,
+            ..
+        } => {
+            // the output has the keys first and then the aggregations
+            let idx = schema.try_index_of(name)?;
+            match keys.iter().chain(aggs).nth(idx) {
+                Some(expr) => trace_expr(*expr, *input, lp_arena, expr_arena, lineage)?,
+                // the index column of a dynamic group by
+                None => trace_column(*input, name, lp_arena, expr_arena, lineage)?,
+            }
+        }
+        HStack { input, exprs, .. } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            for expr in exprs {
+                let field =
+                    expr_arena
+                        .get(*expr)
+                        .to_field(&input_schema, Context::Default, expr_arena)?;
+                if field.name.as_str() == name {
+                    return trace_expr(*expr, *input, lp_arena, expr_arena, lineage);
+                }
+            }
+            trace_column(*input, name, lp_arena, expr_arena, lineage)?;
+        }
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            if lp_arena.get(*input_left).schema(lp_arena).contains(name) {
+                trace_column(*input_left, name, lp_arena, expr_arena, lineage)?;
+            } else {
+                let right_schema = lp_arena.get(*input_right).schema(lp_arena);
+                let name = match name.strip_suffix(options.suffix.as_ref()) {
+                    Some(stripped) if !right_schema.contains(name) => stripped,
+                    _ => name,
+                };
+                trace_column(*input_right, name, lp_arena, expr_arena, lineage)?;
+            }
+        }
+        MapFunction { input, function } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            match function {
+                FunctionNode::Rename { existing, new, .. } => {
+                    let name = match new.iter().position(|new| new.as_str() == name) {
+                        Some(idx) => existing[idx].as_str(),
+                        None => name,
+                    };
+                    trace_column(*input, name, lp_arena, expr_arena, lineage)?;
+                }
+                _ if input_schema.contains(name) => {
+                    trace_column(*input, name, lp_arena, expr_arena, lineage)?;
+                }
+                // a column created by the function, e.g. by a melt or a udf, may depend on any
+                // of the input columns
+                _ => {
+                    for name in input_schema.iter_names() {
+                        trace_column(*input, name, lp_arena, expr_arena, lineage)?;
+                    }
+                }
+            }
+        }
+        // the inputs that have the column, e.g. the tables of a union
+        lp => {
+            for input in lp.get_inputs() {
+                if lp_arena.get(input).schema(lp_arena).contains(name) {
+                    trace_column(input, name, lp_arena, expr_arena, lineage)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -22,7 +22,9 @@ pub(crate) mod conversion;
 pub(crate) mod debug;
 mod format;
 mod functions;
+mod hive;
 pub(crate) mod iterator;
+mod lineage;
 mod lit;
 pub(crate) mod optimizer;
 pub(crate) mod options;
@@ -38,7 +40,9 @@ pub use apply::*;
 pub use builder::*;
 pub use conversion::*;
 pub use functions::*;
+pub use hive::*;
 pub use iterator::*;
+pub use lineage::*;
 pub use lit::*;
 pub use optimizer::*;
 pub use schema::*;
@@ -161,6 +165,7 @@ pub enum LogicalPlan {
         options: CsvParserOptions,
         /// Filters at the scan level
         predicate: Option<Expr>,
+        cloud_options: Option<CloudOptions>,
     },
     #[cfg(feature = "parquet")]
     /// Scan a Parquet file
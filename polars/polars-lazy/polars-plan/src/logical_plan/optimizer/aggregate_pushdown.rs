@@ -0,0 +1,203 @@
+use super::*;
+use crate::utils::aexpr_to_leaf_names_iter;
+
+/// Runs (partial) aggregations before a join if the aggregation happens on the join keys.
+///
+/// If all group keys come from the left side of an inner or left join and include all
+/// the left join keys, every group of the output holds the rows of a single group of
+/// the left input, repeated once for every match on the right. So the left input can
+/// be aggregated first and the join only has to deal with one row per group:
+///
+/// - `sum` and `count` are summed after the join, so that the repetitions are accounted for.
+/// - `min` and `max` don't change by repeating the rows.
+/// - `mean`, `n_unique` and `approx_n_unique` don't change either and take the `first` after
+///   the join.
+///
+/// Other aggregations (e.g. `std`, `quantile`, `first`) depend on the repetitions or on the
+/// order of the rows after the join, so they block this optimization.
+pub(super) struct AggregatePushdown {}
+
+/// How a partial aggregate is combined after the join.
+enum Combine {
+    Sum,
+    Min(bool),
+    Max(bool),
+    First,
+}
+
+impl AggregatePushdown {
+    /// Split an aggregation in the partial aggregation and how to combine it.
+    fn split_agg(
+        agg: &AExpr,
+        left_schema: &Schema,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<(AExpr, Combine)> {
+        let (input, combine) = match agg {
+            AExpr::Count => return Some((AExpr::Count, Combine::Sum)),
+            AExpr::Agg(agg) => match agg {
+                AAggExpr::Sum(input) | AAggExpr::Count(input, _) => (*input, Combine::Sum),
+                AAggExpr::Min {
+                    input,
+                    propagate_nans,
+                } => (*input, Combine::Min(*propagate_nans)),
+                AAggExpr::Max {
+                    input,
+                    propagate_nans,
+                } => (*input, Combine::Max(*propagate_nans)),
+                AAggExpr::Mean(input)
+                | AAggExpr::NUnique(input)
+                | AAggExpr::ApproxNUnique(input) => (*input, Combine::First),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let from_left = aexpr_is_row_local(input, expr_arena)
+            && aexpr_to_leaf_names_iter(input, expr_arena)
+                .all(|name| left_schema.contains(name.as_ref()));
+        from_left.then(|| (agg.clone(), combine))
+    }
+}
+
+impl OptimizationRule for AggregatePushdown {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        let ALogicalPlan::Aggregate {
+            input,
+            keys,
+            aggs,
+            schema,
+            apply: None,
+            maintain_order: false,
+            options,
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        #[cfg(feature = "dynamic_groupby")]
+        if options.dynamic.is_some() || options.rolling.is_some() {
+            return None;
+        }
+        let ALogicalPlan::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options: join_options,
+            ..
+        } = lp_arena.get(*input)
+        else {
+            return None;
+        };
+        if !matches!(join_options.how, JoinType::Inner | JoinType::Left)
+            || join_options.slice.is_some()
+            // Already aggregated.
+            || matches!(lp_arena.get(*input_left), ALogicalPlan::Aggregate { .. })
+        {
+            return None;
+        }
+
+        let column_name = |node: &Node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        };
+        let key_names = keys.iter().map(column_name).collect::<Option<Vec<_>>>()?;
+        let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+        if !key_names
+            .iter()
+            .all(|name| left_schema.contains(name.as_ref()))
+        {
+            return None;
+        }
+        for node in left_on {
+            if !key_names.contains(&column_name(node)?) {
+                return None;
+            }
+        }
+
+        let right_schema = lp_arena.get(*input_right).schema(lp_arena);
+        let mut partial_aggs = Vec::with_capacity(aggs.len());
+        let mut combines = Vec::with_capacity(aggs.len());
+        for (i, agg) in aggs.iter().enumerate() {
+            let agg = match expr_arena.get(*agg) {
+                AExpr::Alias(agg, _) => *agg,
+                _ => *agg,
+            };
+            let (partial, combine) =
+                Self::split_agg(expr_arena.get(agg), &left_schema, expr_arena)?;
+            let tmp_name: Arc<str> = Arc::from(format!("__POLARS_PARTIAL_AGG_{i}"));
+            if right_schema.contains(&tmp_name) {
+                return None;
+            }
+            partial_aggs.push((partial, tmp_name.clone()));
+            combines.push((combine, tmp_name));
+        }
+
+        let input_left = *input_left;
+        let input_right = *input_right;
+        let left_on = left_on.clone();
+        let right_on = right_on.clone();
+        let join_options = join_options.clone();
+        let keys = keys.clone();
+        let schema = schema.clone();
+        let options = options.clone();
+
+        let partial_keys = key_names
+            .iter()
+            .map(|name| expr_arena.add(AExpr::Column(name.clone())))
+            .collect();
+        let partial_aggs = partial_aggs
+            .into_iter()
+            .map(|(partial, tmp_name)| {
+                let partial = expr_arena.add(partial);
+                expr_arena.add(AExpr::Alias(partial, tmp_name))
+            })
+            .collect();
+        let final_aggs = combines
+            .into_iter()
+            .zip(schema.iter_names().skip(keys.len()))
+            .map(|((combine, tmp_name), name)| {
+                let input = expr_arena.add(AExpr::Column(tmp_name));
+                let agg = match combine {
+                    Combine::Sum => AAggExpr::Sum(input),
+                    Combine::Min(propagate_nans) => AAggExpr::Min {
+                        input,
+                        propagate_nans,
+                    },
+                    Combine::Max(propagate_nans) => AAggExpr::Max {
+                        input,
+                        propagate_nans,
+                    },
+                    Combine::First => AAggExpr::First(input),
+                };
+                let agg = expr_arena.add(AExpr::Agg(agg));
+                expr_arena.add(AExpr::Alias(agg, Arc::from(name.as_str())))
+            })
+            .collect();
+
+        let join = ALogicalPlanBuilder::new(input_left, expr_arena, lp_arena)
+            .groupby(
+                partial_keys,
+                partial_aggs,
+                None,
+                false,
+                GroupbyOptions::default(),
+            )
+            .join(input_right, left_on, right_on, join_options)
+            .build();
+        let join = lp_arena.add(join);
+
+        Some(ALogicalPlan::Aggregate {
+            input: join,
+            keys,
+            aggs: final_aggs,
+            schema,
+            apply: None,
+            maintain_order: false,
+            options,
+        })
+    }
+}
@@ -23,6 +23,26 @@ impl OptimizationRule for ReplaceDropNulls {
         use ALogicalPlan::*;
         match lp {
             Selection { input, predicate } => {
+                // `all_not_null` of columns only is a drop_nulls on those columns
+                if let AExpr::Function {
+                    input: columns,
+                    function: FunctionExpr::Boolean(BooleanFunction::AllNotNull),
+                    ..
+                } = expr_arena.get(*predicate)
+                {
+                    if columns
+                        .iter()
+                        .all(|e| matches!(expr_arena.get(*e), AExpr::Column(_)))
+                    {
+                        let subset = Arc::from(aexpr_to_leaf_names(*predicate, expr_arena));
+                        return Some(ALogicalPlan::MapFunction {
+                            input: *input,
+                            function: FunctionNode::DropNulls { subset },
+                        });
+                    }
+                    return None;
+                }
+
                 // We want to make sure we find this pattern
                 // A != null AND B != null AND C != null .. etc.
                 // the outer expression always is a binary and operation and the inner
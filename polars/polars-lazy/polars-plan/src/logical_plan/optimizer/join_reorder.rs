@@ -0,0 +1,230 @@
+use super::*;
+
+/// Reorders chains of inner and left joins, so that the smallest tables are joined first.
+///
+/// In `a.join(b).join(c).join(d)` every join probes the result of the previous join. If all
+/// join keys are columns of `a`, the joins can be done in any order. Then the tables are
+/// joined in the order of their estimated number of rows, which comes from the scans (the
+/// metadata of parquet and ipc files, the rows sampled from csv files) and the filters on
+/// top of them. On star schema queries this keeps the intermediate results small.
+///
+/// Chains of which the tables share column names other than the join keys are not reordered,
+/// as the suffixes of the output columns depend on the join order. The columns of the
+/// reordered joins are projected in their original order.
+pub(super) struct JoinReorder {}
+
+/// A join of the chain, of which `input_right` is the table that is joined.
+struct Link {
+    input_right: Node,
+    left_on: Vec<Node>,
+    right_on: Vec<Node>,
+    options: JoinOptions,
+    rows: usize,
+}
+
+/// The estimated number of rows produced by `node`.
+fn estimate_rows(node: Node, lp_arena: &Arena<ALogicalPlan>) -> usize {
+    use ALogicalPlan::*;
+    // every filter is assumed to keep 90% of the rows
+    let filtered = |rows: usize, predicate: Option<Node>| match predicate {
+        Some(_) => (rows as f64 * 0.9) as usize,
+        None => rows,
+    };
+    match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => filtered(df.height(), *selection),
+        #[cfg(feature = "csv")]
+        CsvScan {
+            file_info,
+            predicate,
+            ..
+        } => filtered(file_info.row_estimation.1, *predicate),
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            file_info,
+            predicate,
+            ..
+        } => filtered(file_info.row_estimation.1, *predicate),
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            file_info,
+            predicate,
+            ..
+        } => filtered(file_info.row_estimation.1, *predicate),
+        AnonymousScan {
+            options, predicate, ..
+        } => filtered(options.n_rows.unwrap_or(usize::MAX), *predicate),
+        #[cfg(feature = "python")]
+        PythonScan { .. } => usize::MAX,
+        Selection { input, predicate } => {
+            filtered(estimate_rows(*input, lp_arena), Some(*predicate))
+        }
+        Slice { input, len, .. } => std::cmp::min(*len as usize, estimate_rows(*input, lp_arena)),
+        Union { inputs, .. } => inputs.iter().fold(0usize, |acc, input| {
+            acc.saturating_add(estimate_rows(*input, lp_arena))
+        }),
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let left = estimate_rows(*input_left, lp_arena);
+            let right = estimate_rows(*input_right, lp_arena);
+            match options.how {
+                JoinType::Cross | JoinType::Outer => left.saturating_mul(right),
+                JoinType::Left => left,
+                _ => std::cmp::max(left, right),
+            }
+        }
+        lp => match lp.get_inputs().first() {
+            Some(input) => estimate_rows(*input, lp_arena),
+            None => usize::MAX,
+        },
+    }
+}
+
+impl JoinReorder {
+    /// Collect the chain of inner and left joins that ends at `node`. Returns the first table
+    /// of the chain and the joins in their current order.
+    fn collect_chain(
+        node: Node,
+        lp_arena: &Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> (Node, Vec<Link>) {
+        let mut links = vec![];
+        let mut current = node;
+        while let ALogicalPlan::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = lp_arena.get(current)
+        {
+            let is_column = |node: &Node| matches!(expr_arena.get(*node), AExpr::Column(_));
+            if !matches!(options.how, JoinType::Inner | JoinType::Left)
+                || options.slice.is_some()
+                // the uniqueness of the keys of the left table depends on the join order
+                || !matches!(options.validate, JoinValidation::ManyToMany)
+                || !left_on.iter().all(is_column)
+                || !right_on.iter().all(is_column)
+            {
+                break;
+            }
+            links.push(Link {
+                input_right: *input_right,
+                left_on: left_on.clone(),
+                right_on: right_on.clone(),
+                options: options.clone(),
+                rows: estimate_rows(*input_right, lp_arena),
+            });
+            current = *input_left;
+        }
+        links.reverse();
+        (current, links)
+    }
+
+    /// The names of the key columns, which are all plain columns.
+    fn key_names<'a>(keys: &[Node], expr_arena: &'a Arena<AExpr>) -> Vec<&'a str> {
+        keys.iter()
+            .map(|node| match expr_arena.get(*node) {
+                AExpr::Column(name) => name.as_ref(),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    /// Check that the joins of the chain can be done in any order: the left keys are columns
+    /// of the first table, and no column name is shared, so that no suffixes are added.
+    fn is_reorderable(
+        first: Node,
+        links: &[Link],
+        lp_arena: &Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> bool {
+        let first_schema = lp_arena.get(first).schema(lp_arena);
+        let mut names: PlHashSet<String> =
+            first_schema.iter_names().map(|s| s.to_string()).collect();
+        for link in links {
+            let left_keys = Self::key_names(&link.left_on, expr_arena);
+            if !left_keys.iter().all(|name| first_schema.contains(name)) {
+                return false;
+            }
+            let right_keys = Self::key_names(&link.right_on, expr_arena);
+            let right_schema = lp_arena.get(link.input_right).schema(lp_arena);
+            for name in right_schema.iter_names() {
+                if !right_keys.contains(&name.as_str()) && !names.insert(name.to_string()) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn join(
+        input_left: Node,
+        link: Link,
+        lp_arena: &Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> ALogicalPlan {
+        let right_keys = Self::key_names(&link.right_on, expr_arena);
+        let mut schema = (**lp_arena.get(input_left).schema(lp_arena)).clone();
+        let right_schema = lp_arena.get(link.input_right).schema(lp_arena);
+        for (name, dtype) in right_schema.iter() {
+            if !right_keys.contains(&name.as_str()) {
+                schema.with_column(name.clone(), dtype.clone());
+            }
+        }
+        ALogicalPlan::Join {
+            input_left,
+            input_right: link.input_right,
+            schema: Arc::new(schema),
+            left_on: link.left_on,
+            right_on: link.right_on,
+            options: link.options,
+        }
+    }
+}
+
+impl OptimizationRule for JoinReorder {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        let (first, mut links) = Self::collect_chain(node, lp_arena, expr_arena);
+        if links.len() < 2
+            || links.windows(2).all(|w| w[0].rows <= w[1].rows)
+            || !Self::is_reorderable(first, &links, lp_arena, expr_arena)
+        {
+            return None;
+        }
+        // stable, so that tables of the same size keep their order
+        links.sort_by_key(|link| link.rows);
+
+        let last = links.pop().unwrap();
+        let mut input_left = first;
+        for link in links {
+            let lp = Self::join(input_left, link, lp_arena, expr_arena);
+            input_left = lp_arena.add(lp);
+        }
+        let join = Self::join(input_left, last, lp_arena, expr_arena);
+
+        // restore the order of the columns
+        let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+        if join.schema(lp_arena).iter_names().eq(schema.iter_names()) {
+            return Some(join);
+        }
+        let expr = schema
+            .iter_names()
+            .map(|name| expr_arena.add(AExpr::Column(Arc::from(name.as_str()))))
+            .collect();
+        Some(ALogicalPlan::Projection {
+            input: lp_arena.add(join),
+            expr,
+            schema,
+        })
+    }
+}
@@ -3,6 +3,7 @@ use polars_core::prelude::*;
 
 use crate::prelude::*;
 
+mod aggregate_pushdown;
 mod cache_states;
 #[cfg(feature = "cse")]
 mod cse;
@@ -12,6 +13,7 @@ mod fast_projection;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv", feature = "cse"))]
 pub(crate) mod file_caching;
 mod flatten_union;
+mod join_reorder;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -19,18 +21,22 @@ mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
 mod type_coercion;
+mod user_passes;
 
+use aggregate_pushdown::AggregatePushdown;
 use delay_rechunk::DelayRechunk;
 use drop_nulls::ReplaceDropNulls;
 use fast_projection::FastProjectionAndCollapse;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use file_caching::{find_column_union_and_fingerprints, FileCacher};
+use join_reorder::JoinReorder;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
 pub use type_coercion::TypeCoercionRule;
+pub use user_passes::{register_optimization_pass, unregister_optimization_pass, OptimizationPass};
 
 use self::flatten_union::FlattenUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptState};
@@ -60,6 +66,8 @@ pub fn optimize(
     let simplify_expr = opt_state.simplify_expr;
     let slice_pushdown = opt_state.slice_pushdown;
     let streaming = opt_state.streaming;
+    let aggregate_pushdown = opt_state.aggregate_pushdown;
+    let join_reorder = opt_state.join_reorder;
     #[cfg(feature = "cse")]
     let cse = opt_state.common_subplan_elimination;
 
@@ -86,6 +94,12 @@ pub fn optimize(
     #[cfg(not(feature = "cse"))]
     let cse_changed = false;
 
+    // before the pushdowns, so that these work on the joins in their new order
+    if join_reorder {
+        let join_reorder = &mut [Box::new(JoinReorder {}) as Box<dyn OptimizationRule>];
+        lp_top = opt.optimize_loop(join_reorder, expr_arena, lp_arena, lp_top)?;
+    }
+
     // we do simplification
     if simplify_expr {
         rules.push(Box::new(SimplifyExprRule {}));
@@ -129,6 +143,9 @@ pub fn optimize(
     if type_coercion {
         rules.push(Box::new(TypeCoercionRule {}))
     }
+    if aggregate_pushdown {
+        rules.push(Box::new(AggregatePushdown {}));
+    }
     // this optimization removes branches, so we must do it when type coercion
     // is completed
     if simplify_expr {
@@ -171,6 +188,8 @@ pub fn optimize(
 
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
 
+    lp_top = user_passes::run_user_passes(lp_top, lp_arena, expr_arena)?;
+
     // during debug we check if the optimizations have not modified the final schema
     #[cfg(debug_assertions)]
     {
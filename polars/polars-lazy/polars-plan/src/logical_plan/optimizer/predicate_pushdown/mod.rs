@@ -12,6 +12,28 @@ use crate::logical_plan::{optimizer, Context};
 use crate::prelude::optimizer::predicate_pushdown::rename::process_rename;
 use crate::utils::{aexprs_to_schema, check_input_node, has_aexpr};
 
+/// Whether `predicate` only refers to join keys that have the same name in
+/// both tables. Such a predicate can also filter the right table of a semi
+/// or anti join, as it only drops keys that no remaining left row has.
+#[cfg(feature = "semi_anti_join")]
+fn is_shared_key_predicate(
+    predicate: Node,
+    left_on: &[Node],
+    right_on: &[Node],
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let shared_keys = left_on
+        .iter()
+        .zip(right_on)
+        .filter_map(|(l, r)| match (expr_arena.get(*l), expr_arena.get(*r)) {
+            (AExpr::Column(l), AExpr::Column(r)) if l == r => Some(l.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    !shared_keys.is_empty()
+        && aexpr_to_leaf_names_iter(predicate, expr_arena).all(|name| shared_keys.contains(&name))
+}
+
 #[derive(Default)]
 pub struct PredicatePushDown {}
 
@@ -290,6 +312,7 @@ impl PredicatePushDown {
                 output_schema,
                 options,
                 predicate,
+                cloud_options,
             } => {
                 let local_predicates = partition_by_full_context(&mut acc_predicates, expr_arena);
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
@@ -301,6 +324,7 @@ impl PredicatePushDown {
                         output_schema,
                         options,
                         predicate: None,
+                        cloud_options,
                     };
                     let input = lp_arena.add(lp);
                     Selection {
@@ -314,6 +338,7 @@ impl PredicatePushDown {
                         output_schema,
                         options,
                         predicate,
+                        cloud_options,
                     }
                 };
 
@@ -446,6 +471,17 @@ impl PredicatePushDown {
                     #[allow(clippy::suspicious_else_formatting)]
                     if !predicate_is_pushdown_boundary(predicate, expr_arena) {
                         if check_input_node(predicate, &schema_left, expr_arena) {
+                            #[cfg(feature = "semi_anti_join")]
+                            if matches!(options.how, JoinType::Semi | JoinType::Anti)
+                                && is_shared_key_predicate(predicate, &left_on, &right_on, expr_arena) {
+                                // copy the predicate, the nodes of both tables may be rewritten
+                                let right_predicate = to_aexpr(node_to_expr(predicate, expr_arena), expr_arena);
+                                insert_and_combine_predicate(
+                                    &mut pushdown_right,
+                                    right_predicate,
+                                    expr_arena,
+                                );
+                            }
                             insert_and_combine_predicate(
                                 &mut pushdown_left,
                                 predicate,
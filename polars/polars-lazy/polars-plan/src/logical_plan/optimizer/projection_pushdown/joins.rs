@@ -1,6 +1,8 @@
 #![allow(clippy::too_many_arguments)]
 use std::collections::BTreeSet;
 
+use smartstring::alias::String as SmartString;
+
 use super::*;
 
 fn add_keys_to_accumulated_state(
@@ -28,7 +30,30 @@ fn add_keys_to_accumulated_state(
     }
 }
 
-#[cfg(feature = "asof_join")]
+/// Whether the key columns of the right table remain after the join.
+#[cfg(any(
+    feature = "asof_join",
+    feature = "range_join",
+    feature = "interval_join"
+))]
+fn keeps_right_keys(how: &JoinType) -> bool {
+    match how {
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
+        #[cfg(feature = "range_join")]
+        JoinType::Range(_) => true,
+        #[cfg(feature = "interval_join")]
+        JoinType::Interval(_) => true,
+        _ => false,
+    }
+}
+
+/// Also used for range and interval joins, where the key columns of both tables remain as well.
+#[cfg(any(
+    feature = "asof_join",
+    feature = "range_join",
+    feature = "interval_join"
+))]
 pub(super) fn process_asof_join(
     proj_pd: &mut ProjectionPushDown,
     input_left: Node,
@@ -51,8 +76,6 @@ pub(super) fn process_asof_join(
     let mut names_right = PlHashSet::with_capacity(n);
     let mut local_projection = Vec::with_capacity(n);
 
-    let JoinType::AsOf(asof_options) = &options.how else {unreachable!()};
-
     // if there are no projections we don't have to do anything (all columns are projected)
     // otherwise we build local projections to sort out proper column names due to the
     // join operation
@@ -65,8 +88,23 @@ pub(super) fn process_asof_join(
         let schema_left = lp_arena.get(input_left).schema(lp_arena);
         let schema_right = lp_arena.get(input_right).schema(lp_arena);
 
-        // make sure that the asof join 'by' columns are projected
-        if let (Some(left_by), Some(right_by)) = (&asof_options.left_by, &asof_options.right_by) {
+        // make sure that the 'by' columns of asof and interval joins are projected
+        let by: Option<(&[SmartString], &[SmartString])> = match &options.how {
+            #[cfg(feature = "asof_join")]
+            JoinType::AsOf(AsOfOptions {
+                left_by: Some(left_by),
+                right_by: Some(right_by),
+                ..
+            }) => Some((left_by, right_by)),
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(IntervalJoinOptions {
+                left_by: Some(left_by),
+                right_by: Some(right_by),
+                ..
+            }) => Some((left_by, right_by)),
+            _ => None,
+        };
+        if let Some((left_by, right_by)) = by {
             for name in left_by {
                 let add = _projected_names.contains(name.as_str());
 
@@ -108,7 +146,10 @@ pub(super) fn process_asof_join(
                 true,
             )
             .unwrap();
-            already_added_local_to_local_projected.insert(local_name);
+            // a range join can use a left key in multiple predicates
+            if !already_added_local_to_local_projected.insert(local_name) {
+                local_projection.pop();
+            }
         }
         // this differs from normal joins, as in `asof_joins`
         // both columns remain. So `add_local=true` also for the right table
@@ -203,8 +244,12 @@ pub(super) fn process_join(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<ALogicalPlan> {
-    #[cfg(feature = "asof_join")]
-    if matches!(options.how, JoinType::AsOf(_)) {
+    #[cfg(any(
+        feature = "asof_join",
+        feature = "range_join",
+        feature = "interval_join"
+    ))]
+    if keeps_right_keys(&options.how) {
         return process_asof_join(
             proj_pd,
             input_left,
@@ -500,6 +500,7 @@ impl ProjectionPushDown {
                 file_info,
                 mut options,
                 predicate,
+                cloud_options,
                 ..
             } => {
                 options.with_columns = get_scan_columns(&mut acc_projections, expr_arena);
@@ -521,6 +522,7 @@ impl ProjectionPushDown {
                     output_schema,
                     options,
                     predicate,
+                    cloud_options,
                 };
                 Ok(lp)
             }
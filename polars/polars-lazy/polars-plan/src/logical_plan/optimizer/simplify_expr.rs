@@ -1,8 +1,12 @@
 use polars_utils::arena::Arena;
 
-#[cfg(all(feature = "strings", feature = "concat_str"))]
+#[cfg(any(
+    all(feature = "strings", feature = "concat_str"),
+    all(feature = "strings", feature = "regex")
+))]
 use crate::dsl::function_expr::StringFunction;
 use crate::logical_plan::optimizer::stack_opt::OptimizationRule;
+use crate::logical_plan::optimizer::type_coercion::get_schema;
 use crate::logical_plan::*;
 use crate::prelude::function_expr::FunctionExpr;
 
@@ -535,7 +539,11 @@ impl OptimizationRule for SimplifyExprRule {
                     Operator::Lt => eval_binary_bool_type!(left_aexpr, <, right_aexpr),
                     Operator::Gt => eval_binary_bool_type!(left_aexpr, >, right_aexpr),
                     Operator::Eq => eval_binary_bool_type!(left_aexpr, ==, right_aexpr),
+                    Operator::EqValidity => eval_binary_bool_type!(left_aexpr, ==, right_aexpr),
                     Operator::NotEq => eval_binary_bool_type!(left_aexpr, !=, right_aexpr),
+                    Operator::NotEqValidity => {
+                        eval_binary_bool_type!(left_aexpr, !=, right_aexpr)
+                    }
                     Operator::GtEq => eval_binary_bool_type!(left_aexpr, >=, right_aexpr),
                     Operator::LtEq => eval_binary_bool_type!(left_aexpr, <=, right_aexpr),
                     Operator::And => eval_bitwise(left_aexpr, right_aexpr, |l, r| l & r),
@@ -551,10 +559,16 @@ impl OptimizationRule for SimplifyExprRule {
                 let right_is_null = matches!(right_aexpr, AExpr::Literal(LiteralValue::Null));
                 use Operator::*;
                 match (left_is_null, op, right_is_null) {
+                    // null ==v null -> true
+                    (true, EqValidity, true) => Some(AExpr::Literal(LiteralValue::Boolean(true))),
+                    // null !=v null -> false
+                    (true, NotEqValidity, true) => {
+                        Some(AExpr::Literal(LiteralValue::Boolean(false)))
+                    }
                     // all null operation null -> null
                     (true, _, true) => Some(AExpr::Literal(LiteralValue::Null)),
                     // null == column -> column.is_null()
-                    (true, Eq, false) => Some(AExpr::Function {
+                    (true, Eq | EqValidity, false) => Some(AExpr::Function {
                         input: vec![*right],
                         function: BooleanFunction::IsNull.into(),
                         options: FunctionOptions {
@@ -563,7 +577,7 @@ impl OptimizationRule for SimplifyExprRule {
                         },
                     }),
                     // column == null -> column.is_null()
-                    (false, Eq, true) => Some(AExpr::Function {
+                    (false, Eq | EqValidity, true) => Some(AExpr::Function {
                         input: vec![*left],
                         function: BooleanFunction::IsNull.into(),
                         options: FunctionOptions {
@@ -572,7 +586,7 @@ impl OptimizationRule for SimplifyExprRule {
                         },
                     }),
                     // null != column -> column.is_not_null()
-                    (true, NotEq, false) => Some(AExpr::Function {
+                    (true, NotEq | NotEqValidity, false) => Some(AExpr::Function {
                         input: vec![*right],
                         function: BooleanFunction::IsNotNull.into(),
                         options: FunctionOptions {
@@ -581,7 +595,7 @@ impl OptimizationRule for SimplifyExprRule {
                         },
                     }),
                     // column != null -> column.is_not_null()
-                    (false, NotEq, true) => Some(AExpr::Function {
+                    (false, NotEq | NotEqValidity, true) => Some(AExpr::Function {
                         input: vec![*left],
                         function: BooleanFunction::IsNotNull.into(),
                         options: FunctionOptions {
@@ -623,12 +637,97 @@ impl OptimizationRule for SimplifyExprRule {
                 }
             }
             AExpr::Cast {
-                expr, data_type, ..
+                expr,
+                data_type,
+                strict,
             } => {
                 let input = expr_arena.get(*expr);
-                // faster casts (we only do strict casts)
-                inline_cast(input, data_type)
+                match input {
+                    // x.cast(dtype).cast(dtype) => x.cast(dtype)
+                    AExpr::Cast {
+                        data_type: inner_type,
+                        ..
+                    } if inner_type == data_type => Some(input.clone()),
+                    // x.cast(wider).cast(dtype) => x.cast(dtype)
+                    // the inner cast cannot fail or change any value, but a non-numeric `dtype`
+                    // may still depend on the type it is cast from, e.g. `1` vs `1.0` as utf8
+                    AExpr::Cast {
+                        expr: inner_expr,
+                        data_type: inner_type,
+                        ..
+                    } if data_type.is_numeric() => {
+                        let input_schema = get_schema(_lp_arena, _lp_node);
+                        expr_arena
+                            .get(*inner_expr)
+                            .get_type(&input_schema, Context::Default, expr_arena)
+                            .ok()
+                            .filter(|input_type| is_lossless_cast(input_type, inner_type))
+                            .map(|_| AExpr::Cast {
+                                expr: *inner_expr,
+                                data_type: data_type.clone(),
+                                strict: *strict,
+                            })
+                    }
+                    // faster casts (we only do strict casts)
+                    _ => inline_cast(input, data_type),
+                }
             }
+            // x.is_in([value]) => x.eq_missing(value)
+            #[cfg(feature = "is_in")]
+            AExpr::Function {
+                input,
+                function: FunctionExpr::Boolean(BooleanFunction::IsIn),
+                ..
+            } => match expr_arena.get(input[1]) {
+                AExpr::Literal(LiteralValue::Series(s))
+                    if s.len() == 1 && s.dtype().is_primitive() =>
+                {
+                    let lv = s.get(0).ok().and_then(|av| LiteralValue::try_from(av).ok());
+                    lv.map(|lv| {
+                        let left = input[0];
+                        let right = expr_arena.add(AExpr::Literal(lv));
+                        AExpr::BinaryExpr {
+                            left,
+                            op: Operator::EqValidity,
+                            right,
+                        }
+                    })
+                }
+                _ => None,
+            },
+            // a regex without meta characters is matched as a literal substring
+            #[cfg(all(feature = "strings", feature = "regex"))]
+            AExpr::Function {
+                input,
+                function:
+                    FunctionExpr::StringExpr(StringFunction::Contains {
+                        literal: false,
+                        strict,
+                    }),
+                options,
+            } => match expr_arena.get(input[1]) {
+                AExpr::Literal(LiteralValue::Utf8(pat)) if regex::escape(pat) == *pat => {
+                    Some(AExpr::Function {
+                        input: input.clone(),
+                        function: FunctionExpr::StringExpr(StringFunction::Contains {
+                            literal: true,
+                            strict: *strict,
+                        }),
+                        options: *options,
+                    })
+                }
+                _ => None,
+            },
+            // col(name).len() => count().alias(name)
+            // the length is known without materializing the column
+            AExpr::Agg(AAggExpr::Count(input, true)) => match expr_arena.get(*input) {
+                AExpr::Column(name) => {
+                    let name = name.clone();
+                    let count = expr_arena.add(AExpr::Count);
+                    Some(AExpr::Alias(count, name))
+                }
+                _ => None,
+            },
             // flatten nested concat_str calls
             #[cfg(all(feature = "strings", feature = "concat_str"))]
             AExpr::Function {
@@ -665,6 +764,46 @@ impl OptimizationRule for SimplifyExprRule {
     }
 }
 
+/// Bit width and signedness of the integer types.
+fn integer_repr(dtype: &DataType) -> Option<(u32, bool)> {
+    use DataType::*;
+    match dtype {
+        UInt8 => Some((8, false)),
+        UInt16 => Some((16, false)),
+        UInt32 => Some((32, false)),
+        UInt64 => Some((64, false)),
+        Int8 => Some((8, true)),
+        Int16 => Some((16, true)),
+        Int32 => Some((32, true)),
+        Int64 => Some((64, true)),
+        _ => None,
+    }
+}
+
+/// Whether every value of `from` can be represented exactly in `to`.
+fn is_lossless_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    if from == to {
+        return true;
+    }
+    match (integer_repr(from), to) {
+        (Some((from_bits, from_signed)), _) => match integer_repr(to) {
+            Some((to_bits, to_signed)) => match (from_signed, to_signed) {
+                (true, false) => false,
+                (false, true) => to_bits > from_bits,
+                _ => to_bits >= from_bits,
+            },
+            None => match to {
+                Float32 => from_bits <= 16,
+                Float64 => from_bits <= 32,
+                _ => false,
+            },
+        },
+        (None, Float64) => matches!(from, Float32),
+        _ => false,
+    }
+}
+
 fn inline_cast(input: &AExpr, dtype: &DataType) -> Option<AExpr> {
     match (input, dtype) {
         #[cfg(feature = "dtype-duration")]
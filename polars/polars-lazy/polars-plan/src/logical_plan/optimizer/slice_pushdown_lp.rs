@@ -190,6 +190,7 @@ impl SlicePushDown {
                 output_schema,
                 mut options,
                 predicate,
+                cloud_options,
             }, Some(state)) if state.offset >= 0 && predicate.is_none() => {
                 options.skip_rows += state.offset as usize;
                 options.n_rows = Some(state.len as usize);
@@ -200,6 +201,7 @@ impl SlicePushDown {
                     output_schema,
                     options,
                     predicate,
+                    cloud_options,
                 };
                 Ok(lp)
             }
@@ -324,6 +326,7 @@ impl SlicePushDown {
             | m @ (Sort {..}, _)
             | m @ (MapFunction {function: FunctionNode::Explode {..}, ..}, _)
             | m @ (MapFunction {function: FunctionNode::Melt {..}, ..}, _)
+            | m @ (MapFunction {function: FunctionNode::GatherEvery {..}, ..}, _)
             | m @ (Cache {..}, _)
             | m @ (Distinct {..}, _)
             | m @ (HStack {..},_)
@@ -250,7 +250,7 @@ fn get_input(lp_arena: &Arena<ALogicalPlan>, lp_node: Node) -> [Option<Node>; 2]
     inputs
 }
 
-fn get_schema(lp_arena: &Arena<ALogicalPlan>, lp_node: Node) -> Cow<'_, SchemaRef> {
+pub(super) fn get_schema(lp_arena: &Arena<ALogicalPlan>, lp_node: Node) -> Cow<'_, SchemaRef> {
     match get_input(lp_arena, lp_node) {
         [Some(input), _] => lp_arena.get(input).schema(lp_arena),
         // files don't have an input, so we must take their schema
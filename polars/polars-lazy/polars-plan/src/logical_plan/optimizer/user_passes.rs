@@ -0,0 +1,75 @@
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use super::*;
+
+/// A custom optimization pass over the whole plan, see [`register_optimization_pass`].
+///
+/// A pass gets the root of the plan and returns the root of the optimized plan. Passes that
+/// rewrite single nodes can run an [`OptimizationRule`] with the [`StackOptimizer`].
+pub trait OptimizationPass: Send + Sync {
+    fn optimize(
+        &self,
+        root: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Node>;
+}
+
+impl<F> OptimizationPass for F
+where
+    F: Fn(Node, &mut Arena<ALogicalPlan>, &mut Arena<AExpr>) -> PolarsResult<Node> + Send + Sync,
+{
+    fn optimize(
+        &self,
+        root: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Node> {
+        self(root, lp_arena, expr_arena)
+    }
+}
+
+type Passes = Vec<(String, Arc<dyn OptimizationPass>)>;
+
+static PASSES: Lazy<RwLock<Passes>> = Lazy::new(Default::default);
+
+/// Register a custom optimization pass under `name`. The passes run in the order in which they
+/// are registered, after the built-in optimizations of every query. A pass that is already
+/// registered under `name` is replaced.
+///
+/// A pass must not change the names of the output columns of the plan.
+pub fn register_optimization_pass(name: &str, pass: Arc<dyn OptimizationPass>) {
+    let mut passes = PASSES.write().unwrap();
+    match passes.iter_mut().find(|(n, _)| n == name) {
+        Some((_, registered)) => *registered = pass,
+        None => passes.push((name.to_string(), pass)),
+    }
+}
+
+/// Remove the optimization pass registered under `name`. Returns `false` if there is none.
+pub fn unregister_optimization_pass(name: &str) -> bool {
+    let mut passes = PASSES.write().unwrap();
+    let len = passes.len();
+    passes.retain(|(n, _)| n != name);
+    passes.len() != len
+}
+
+pub(super) fn run_user_passes(
+    mut root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
+    // don't hold the lock while optimizing, a pass may be slow
+    let passes = PASSES
+        .read()
+        .unwrap()
+        .iter()
+        .map(|(_, pass)| pass.clone())
+        .collect::<Vec<_>>();
+    for pass in passes {
+        root = pass.optimize(root, lp_arena, expr_arena)?;
+    }
+    Ok(root)
+}
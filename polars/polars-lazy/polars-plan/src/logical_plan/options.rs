@@ -1,12 +1,16 @@
+use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv::{CsvDelimiter, CsvEncoding, NullValues, NumberFormat, SerializeOptions};
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcCompression;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetCompression;
+#[cfg(feature = "csv")]
+use polars_io::FollowOptions;
 use polars_io::RowCount;
 #[cfg(feature = "dynamic_groupby")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
@@ -21,7 +25,7 @@ pub type FileCount = u32;
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvParserOptions {
-    pub delimiter: u8,
+    pub delimiter: CsvDelimiter,
     pub comment_char: Option<u8>,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
@@ -37,6 +41,9 @@ pub struct CsvParserOptions {
     pub encoding: CsvEncoding,
     pub row_count: Option<RowCount>,
     pub try_parse_dates: bool,
+    pub datetime_formats: Option<Vec<String>>,
+    pub number_format: Option<NumberFormat>,
+    pub follow: Option<FollowOptions>,
     pub file_counter: FileCount,
 }
 
@@ -81,6 +88,31 @@ pub struct IpcWriterOptions {
     pub maintain_order: bool,
 }
 
+#[cfg(feature = "csv")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CsvWriterOptions {
+    /// write the names of the columns as the first line
+    pub include_header: bool,
+    /// the number of rows that are serialized at once
+    pub batch_size: usize,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+    pub serialize_options: SerializeOptions,
+}
+
+#[cfg(feature = "csv")]
+impl Default for CsvWriterOptions {
+    fn default() -> Self {
+        CsvWriterOptions {
+            include_header: true,
+            batch_size: 1024,
+            maintain_order: false,
+            serialize_options: SerializeOptions::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
@@ -179,6 +211,44 @@ pub struct WindowOptions {
     /// Explode the aggregated list and just do a hstack instead of a join
     /// this requires the groups to be sorted to make any sense
     pub explode: bool,
+    /// Evaluate the function over a frame around every row instead of over the whole partition.
+    pub frame: Option<WindowFrame>,
+    /// How the results of the groups are mapped back to the rows.
+    pub mapping: WindowMapping,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WindowMapping {
+    /// Map the results of the groups back to the rows they were derived from.
+    #[default]
+    GroupsToRows,
+    /// Explode the results of the groups; the output is ordered by group and its
+    /// length may differ from the input.
+    Explode,
+    /// Give every row the results of its group as a `List`.
+    Join,
+}
+
+/// The rows of a partition a window function is evaluated on, relative to the current row.
+/// Both bounds are inclusive; `i64::MIN` and `i64::MAX` are unbounded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WindowFrame {
+    /// Offsets in number of rows.
+    Rows { start: i64, end: i64 },
+    /// Offsets in units of the (integer or temporal) ordering column.
+    Range { start: i64, end: i64 },
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (kind, start, end) = match self {
+            WindowFrame::Rows { start, end } => ("rows", start, end),
+            WindowFrame::Range { start, end } => ("range", start, end),
+        };
+        write!(f, "{kind}_between({start}, {end})")
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -215,7 +285,10 @@ pub struct FunctionOptions {
     /// sum(x) -> {4}
     pub auto_explode: bool,
     // used for formatting, (only for anonymous functions)
-    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialize::deserialize_fmt_str")
+    )]
     pub fmt_str: &'static str,
 
     // if the expression and its inputs should be cast to supertypes
@@ -303,9 +376,11 @@ pub struct AnonymousScanOptions {
 pub struct FileSinkOptions {
     pub path: Arc<PathBuf>,
     pub file_type: FileType,
+    /// Options to upload to cloud storage if `path` is a cloud url.
+    pub cloud_options: Option<CloudOptions>,
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub enum FileType {
@@ -313,7 +388,9 @@ pub enum FileType {
     Parquet(ParquetWriteOptions),
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
+    #[cfg(feature = "csv")]
+    Csv(CsvWriterOptions),
 }
 
-#[cfg(not(any(feature = "parquet", feature = "ipc")))]
+#[cfg(not(any(feature = "parquet", feature = "ipc", feature = "csv")))]
 pub type FileType = ();
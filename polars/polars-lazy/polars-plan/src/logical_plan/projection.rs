@@ -4,6 +4,8 @@ use polars_core::utils::get_supertype;
 
 use super::*;
 use crate::prelude::function_expr::FunctionExpr;
+#[cfg(feature = "dtype-struct")]
+use crate::prelude::function_expr::StructFunction;
 
 /// This replace the wildcard Expr with a Column Expr. It also removes the Exclude Expr from the
 /// expression chain.
@@ -151,6 +153,75 @@ fn replace_regex(expr: &Expr, result: &mut Vec<Expr>, schema: &Schema) -> Polars
     Ok(())
 }
 
+#[cfg(feature = "dtype-struct")]
+fn is_struct_field_pattern(name: &str) -> bool {
+    name == "*" || is_regex_projection(name)
+}
+
+#[cfg(feature = "dtype-struct")]
+/// Expand `struct_().field_by_name("*")` or a regex in `field_by_name` into one expression
+/// per field of the struct that is selected by it. The expressions are written to `result`.
+fn expand_struct_fields(expr: Expr, result: &mut Vec<Expr>, schema: &Schema) -> PolarsResult<()> {
+    let found = expr.into_iter().find_map(|e| match e {
+        Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByName(name)),
+            ..
+        } if is_struct_field_pattern(name) => Some((input[0].clone(), name.clone())),
+        _ => None,
+    });
+    let (struct_expr, pattern) = match found {
+        Some(found) => found,
+        None => {
+            result.push(expr);
+            return Ok(());
+        }
+    };
+
+    let fields = match struct_expr.to_field(schema, Context::Default)?.dtype {
+        DataType::Struct(fields) => fields,
+        dt => {
+            polars_bail!(SchemaMismatch: "expected a struct to select fields '{}' from, got `{}`", pattern, dt)
+        }
+    };
+    #[cfg(feature = "regex")]
+    let re = match pattern.as_ref() {
+        "*" => None,
+        _ => Some(regex::Regex::new(&pattern).map_err(
+            |_| polars_err!(ComputeError: "invalid regular expression in struct field: {}", pattern),
+        )?),
+    };
+    #[cfg(not(feature = "regex"))]
+    polars_ensure!(
+        pattern.as_ref() == "*",
+        ComputeError: "selecting struct fields by a regex needs the 'regex' feature"
+    );
+
+    for fld in fields {
+        #[cfg(feature = "regex")]
+        if let Some(re) = &re {
+            if !re.is_match(fld.name()) {
+                continue;
+            }
+        }
+        let mut new_expr = expr.clone();
+        new_expr.mutate().apply(|e| {
+            if let Expr::Function {
+                function: FunctionExpr::StructExpr(StructFunction::FieldByName(name)),
+                ..
+            } = e
+            {
+                if *name == pattern {
+                    *name = Arc::from(fld.name().as_str());
+                }
+            }
+            true
+        });
+        result.push(new_expr)
+    }
+    Ok(())
+}
+
 /// replace `columns(["A", "B"])..` with `col("A")..`, `col("B")..`
 fn expand_columns(expr: &Expr, result: &mut Vec<Expr>, names: &[String]) -> PolarsResult<()> {
     let mut is_valid = true;
@@ -347,6 +418,8 @@ pub(crate) fn rewrite_projections(
         let mut has_nth = false;
         let mut has_wildcard = false;
         let mut replace_fill_null_type = false;
+        #[cfg(feature = "dtype-struct")]
+        let mut has_struct_field_pattern = false;
 
         // do a single pass and collect all flags at once.
         // supertypes/modification that can be done in place are also don e in that pass
@@ -359,6 +432,11 @@ pub(crate) fn rewrite_projections(
                     function: FunctionExpr::FillNull { .. },
                     ..
                 } => replace_fill_null_type = true,
+                #[cfg(feature = "dtype-struct")]
+                Expr::Function {
+                    function: FunctionExpr::StructExpr(StructFunction::FieldByName(name)),
+                    ..
+                } if is_struct_field_pattern(name) => has_struct_field_pattern = true,
                 _ => {}
             }
         }
@@ -406,6 +484,15 @@ pub(crate) fn rewrite_projections(
             }
         }
 
+        // the struct fields are selected after the columns, as the fields of every expanded
+        // struct column may differ
+        #[cfg(feature = "dtype-struct")]
+        if has_struct_field_pattern {
+            for e in result.drain(result_offset..).collect::<Vec<_>>() {
+                expand_struct_fields(e, &mut result, schema)?;
+            }
+        }
+
         // this is done after all expansion (wildcard, column, dtypes)
         // have been done. This will ensure the conversion to aexpr does
         // not panic because of an unexpected wildcard etc.
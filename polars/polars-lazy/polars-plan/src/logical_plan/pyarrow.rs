@@ -6,7 +6,8 @@ use crate::prelude::*;
 pub(super) fn predicate_to_pa(predicate: Node, expr_arena: &Arena<AExpr>) -> Option<String> {
     match expr_arena.get(predicate) {
         AExpr::BinaryExpr { left, right, op } => {
-            if op.is_comparison() {
+            // pyarrow has no null-aware equality operators
+            if op.is_comparison() && !matches!(op, Operator::EqValidity | Operator::NotEqValidity) {
                 let left = predicate_to_pa(*left, expr_arena)?;
                 let right = predicate_to_pa(*right, expr_arena)?;
                 Some(format!("({left} {op} {right})"))
@@ -54,6 +54,11 @@ pub struct FileInfo {
     // - known size
     // - estimated size
     pub row_estimation: (Option<usize>, usize),
+    /// The partition values of the file, if it is part of a hive partitioned dataset.
+    pub hive_parts: Option<Arc<HivePartitions>>,
+    /// The minimum and maximum values of columns of the file, e.g. from a manifest, with
+    /// which the file can be pruned without reading it.
+    pub statistics: Option<Arc<Vec<Series>>>,
 }
 
 #[cfg(feature = "streaming")]
@@ -278,6 +283,20 @@ pub(crate) fn det_join_schema(
                 }
             }
 
+            // range and interval joins are not equi-joins either, but there
+            // the key columns of the right table remain
+            #[cfg(feature = "range_join")]
+            let right_on = if let JoinType::Range(_) = &options.how {
+                &[]
+            } else {
+                right_on
+            };
+            #[cfg(feature = "interval_join")]
+            let right_on = if let JoinType::Interval(_) = &options.how {
+                &[]
+            } else {
+                right_on
+            };
             let mut right_names: PlHashSet<_> = PlHashSet::with_capacity(right_on.len());
             for e in right_on {
                 let field = e.to_field_amortized(schema_right, Context::Default, &mut arena)?;
@@ -285,6 +304,17 @@ pub(crate) fn det_join_schema(
             }
 
             for (name, dtype) in schema_right.iter() {
+                // the `by` columns of the right table of an interval join are dropped
+                #[cfg(feature = "interval_join")]
+                if let JoinType::Interval(IntervalJoinOptions {
+                    right_by: Some(right_by),
+                    ..
+                }) = &options.how
+                {
+                    if right_by.contains(name) {
+                        continue;
+                    }
+                }
                 if !right_names.contains(name.as_str()) {
                     if names.contains(name.as_str()) {
                         #[cfg(feature = "asof_join")]
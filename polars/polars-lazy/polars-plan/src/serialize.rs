@@ -0,0 +1,318 @@
+//! Serialization of logical plans and expressions, e.g. to ship a plan from a coordinator to
+//! worker processes.
+//!
+//! Plans are serialized as JSON or as a compact binary format, with the version of the format,
+//! so that a worker rejects plans it doesn't understand instead of misreading them.
+//!
+//! Functions can't be serialized. Anonymous functions (e.g. `Expr::map`) that are registered
+//! with [`register_udf`] and created with [`registered_udf`] are serialized as a reference to
+//! their name, which is resolved in the registry of the process that deserializes the plan.
+//! Serializing other functions, e.g. `LazyFrame::map` or anonymous scans, is an error.
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::*;
+
+/// The version of the serialization format. It is incremented on every change of the format.
+pub const PLAN_FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct VersionedRef<'a, T> {
+    version: u32,
+    value: &'a T,
+}
+
+#[derive(Deserialize)]
+struct Versioned<T> {
+    // checked before the value is deserialized
+    #[serde(rename = "version")]
+    _version: u32,
+    value: T,
+}
+
+fn check_version(version: u32) -> PolarsResult<()> {
+    polars_ensure!(
+        version == PLAN_FORMAT_VERSION,
+        ComputeError: "cannot deserialize a plan of format version {}, the supported version is {}",
+        version, PLAN_FORMAT_VERSION
+    );
+    Ok(())
+}
+
+fn to_json<T: Serialize>(value: &T) -> PolarsResult<String> {
+    let versioned = VersionedRef {
+        version: PLAN_FORMAT_VERSION,
+        value,
+    };
+    serde_json::to_string(&versioned).map_err(|e| polars_err!(ComputeError: "{}", e))
+}
+
+fn from_json<T: DeserializeOwned>(json: &str) -> PolarsResult<T> {
+    // check the version before the value, which may not be readable in another version
+    #[derive(Deserialize)]
+    struct Version {
+        version: u32,
+    }
+    let version: Version =
+        serde_json::from_str(json).map_err(|e| polars_err!(ComputeError: "{}", e))?;
+    check_version(version.version)?;
+    let versioned: Versioned<T> =
+        serde_json::from_str(json).map_err(|e| polars_err!(ComputeError: "{}", e))?;
+    Ok(versioned.value)
+}
+
+fn to_bytes<T: Serialize>(value: &T) -> PolarsResult<Vec<u8>> {
+    let versioned = VersionedRef {
+        version: PLAN_FORMAT_VERSION,
+        value,
+    };
+    bincode::serialize(&versioned).map_err(|e| polars_err!(ComputeError: "{}", e))
+}
+
+fn from_bytes<T: DeserializeOwned>(bytes: &[u8]) -> PolarsResult<T> {
+    // the version is the first field, so it can be read without the value
+    let version: u32 =
+        bincode::deserialize(bytes).map_err(|e| polars_err!(ComputeError: "{}", e))?;
+    check_version(version)?;
+    let versioned: Versioned<T> =
+        bincode::deserialize(bytes).map_err(|e| polars_err!(ComputeError: "{}", e))?;
+    Ok(versioned.value)
+}
+
+impl LogicalPlan {
+    /// Serialize the plan to JSON.
+    pub fn to_json(&self) -> PolarsResult<String> {
+        to_json(self)
+    }
+
+    /// Deserialize a plan serialized with [`LogicalPlan::to_json`].
+    pub fn from_json(json: &str) -> PolarsResult<Self> {
+        from_json(json)
+    }
+
+    /// Serialize the plan to a compact binary format.
+    pub fn to_bytes(&self) -> PolarsResult<Vec<u8>> {
+        to_bytes(self)
+    }
+
+    /// Deserialize a plan serialized with [`LogicalPlan::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        from_bytes(bytes)
+    }
+}
+
+impl Expr {
+    /// Serialize the expression to JSON.
+    pub fn to_json(&self) -> PolarsResult<String> {
+        to_json(self)
+    }
+
+    /// Deserialize an expression serialized with [`Expr::to_json`].
+    pub fn from_json(json: &str) -> PolarsResult<Self> {
+        from_json(json)
+    }
+
+    /// Serialize the expression to a compact binary format.
+    pub fn to_bytes(&self) -> PolarsResult<Vec<u8>> {
+        to_bytes(self)
+    }
+
+    /// Deserialize an expression serialized with [`Expr::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        from_bytes(bytes)
+    }
+}
+
+struct RegisteredUdf {
+    name: &'static str,
+    function: Arc<dyn SeriesUdf>,
+    output_type: GetOutput,
+}
+
+static UDF_REGISTRY: Lazy<RwLock<Vec<RegisteredUdf>>> = Lazy::new(Default::default);
+
+/// Register an anonymous function under `name`, so that the expressions created with
+/// [`registered_udf`] can be serialized. The processes that deserialize them must register
+/// the same function under the same name. A function that is already registered under `name`
+/// is replaced.
+pub fn register_udf(name: &str, function: Arc<dyn SeriesUdf>, output_type: GetOutput) {
+    let mut registry = UDF_REGISTRY.write().unwrap();
+    registry.retain(|udf| udf.name != name);
+    registry.push(RegisteredUdf {
+        name: intern(name),
+        function,
+        output_type,
+    })
+}
+
+/// Apply the function registered under `name` to `input`, see [`register_udf`].
+pub fn registered_udf(name: &str, input: Vec<Expr>) -> PolarsResult<Expr> {
+    let registry = UDF_REGISTRY.read().unwrap();
+    let udf = registry
+        .iter()
+        .find(|udf| udf.name == name)
+        .ok_or_else(|| polars_err!(ComputeError: "no udf is registered under '{}'", name))?;
+    Ok(Expr::AnonymousFunction {
+        input,
+        function: SpecialEq::new(udf.function.clone()),
+        output_type: udf.output_type.clone(),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyFlat,
+            fmt_str: udf.name,
+            ..Default::default()
+        },
+    })
+}
+
+/// The names of functions are `&'static str`. They are leaked once per distinct name.
+fn intern(name: &str) -> &'static str {
+    static INTERNED: Lazy<RwLock<PlHashSet<&'static str>>> = Lazy::new(Default::default);
+    if let Some(name) = INTERNED.read().unwrap().get(name) {
+        return *name;
+    }
+    let mut interned = INTERNED.write().unwrap();
+    match interned.get(name) {
+        Some(name) => *name,
+        None => {
+            let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+            interned.insert(name);
+            name
+        }
+    }
+}
+
+pub(crate) fn deserialize_fmt_str<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<&'static str, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    Ok(intern(&name))
+}
+
+/// Find the name of a registered udf of which `is_match` matches the function or output type.
+fn registered_name<S: Serializer>(
+    is_match: impl Fn(&RegisteredUdf) -> bool,
+) -> Result<&'static str, S::Error> {
+    UDF_REGISTRY
+        .read()
+        .unwrap()
+        .iter()
+        .find(|udf| is_match(udf))
+        .map(|udf| udf.name)
+        .ok_or_else(|| {
+            serde::ser::Error::custom(
+                "cannot serialize an anonymous function that is not registered with 'register_udf'",
+            )
+        })
+}
+
+fn registered<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RegisteredUdfRef, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    UDF_REGISTRY
+        .read()
+        .unwrap()
+        .iter()
+        .find(|udf| udf.name == name)
+        .map(|udf| RegisteredUdfRef {
+            function: udf.function.clone(),
+            output_type: udf.output_type.clone(),
+        })
+        .ok_or_else(|| serde::de::Error::custom(format!("no udf is registered under '{name}'")))
+}
+
+struct RegisteredUdfRef {
+    function: Arc<dyn SeriesUdf>,
+    output_type: GetOutput,
+}
+
+/// (De)serialize the function of an anonymous function by the name it is registered under.
+pub(crate) mod udf_function {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        function: &SpecialEq<Arc<dyn SeriesUdf>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let name = registered_name::<S>(|udf| {
+            Arc::as_ptr(&udf.function) as *const u8 == Arc::as_ptr(&**function) as *const u8
+        })?;
+        serializer.serialize_str(name)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SpecialEq<Arc<dyn SeriesUdf>>, D::Error> {
+        registered(deserializer).map(|udf| SpecialEq::new(udf.function))
+    }
+}
+
+/// (De)serialize the output type of an anonymous function by the name of the function it is
+/// registered with.
+pub(crate) mod udf_output_type {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        output_type: &GetOutput,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let name = registered_name::<S>(|udf| {
+            Arc::as_ptr(&*udf.output_type) as *const u8 == Arc::as_ptr(&**output_type) as *const u8
+        })?;
+        serializer.serialize_str(name)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GetOutput, D::Error> {
+        registered(deserializer).map(|udf| udf.output_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serialize_expr() -> PolarsResult<()> {
+        let expr = (col("a") + lit(1)).sum().alias("b");
+        assert_eq!(Expr::from_json(&expr.to_json()?)?, expr);
+        assert_eq!(Expr::from_bytes(&expr.to_bytes()?)?, expr);
+
+        // a newer version
+        let json = expr.to_json()?.replacen(
+            &format!("\"version\":{PLAN_FORMAT_VERSION}"),
+            &format!("\"version\":{}", PLAN_FORMAT_VERSION + 1),
+            1,
+        );
+        assert!(Expr::from_json(&json).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_serialize_udf() -> PolarsResult<()> {
+        let unregistered = col("a").map(|s| Ok(Some(s)), GetOutput::same_type());
+        assert!(unregistered.to_json().is_err());
+
+        register_udf(
+            "identity",
+            Arc::new(|s: &mut [Series]| Ok(Some(std::mem::take(&mut s[0])))),
+            GetOutput::same_type(),
+        );
+        let expr = registered_udf("identity", vec![col("a")])?;
+        let out = Expr::from_bytes(&expr.to_bytes()?)?;
+        assert_eq!(format!("{out:?}"), format!("{expr:?}"));
+        match out {
+            Expr::AnonymousFunction { function, .. } => {
+                let s = Series::new("a", [1, 2]);
+                let out = function.call_udf(&mut [s.clone()])?.unwrap();
+                assert!(out.series_equal(&s));
+            }
+            _ => unreachable!(),
+        }
+        assert!(registered_udf("unknown", vec![col("a")]).is_err());
+        Ok(())
+    }
+}
@@ -7,7 +7,10 @@ use polars_plan::prelude::*;
 use crate::prelude::*;
 
 impl LazyFrame {
-    /// Get a dot language representation of the LogicalPlan.
+    /// Get a dot language representation of the LogicalPlan, which can be rendered with graphviz.
+    ///
+    /// The nodes show the projections and predicates, and the scans show their (estimated)
+    /// number of rows. If `optimized` is set, the plan is optimized first.
     pub fn to_dot(&self, optimized: bool) -> PolarsResult<String> {
         let mut s = String::with_capacity(512);
 
@@ -35,8 +38,8 @@ impl LazyFrame {
         // maps graphviz id to label
         // we use this to create this graph
         // first we create nodes including ids to make sure they are unique
-        // A [id] -- B [id]
-        // B [id] -- C [id]
+        // A [id] -> B [id]
+        // B [id] -> C [id]
         //
         // then later we hide the [id] by adding this to the graph
         // A [id] [label="A"]
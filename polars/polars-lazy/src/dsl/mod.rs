@@ -36,6 +36,7 @@ pub mod functions;
 mod into;
 #[cfg(feature = "list_eval")]
 mod list;
+mod standalone;
 
 #[cfg(any(feature = "cumulative_eval", feature = "list_eval"))]
 pub use eval::*;
@@ -46,3 +47,4 @@ use into::IntoExpr;
 pub use list::*;
 pub use polars_plan::dsl::*;
 pub use polars_plan::logical_plan::UdfSchema;
+pub use standalone::*;
@@ -0,0 +1,115 @@
+use polars_core::prelude::*;
+
+use super::into::IntoExpr;
+use crate::prelude::*;
+
+/// Options of [`ExprStandaloneExtension::evaluate_standalone_with_options`].
+#[derive(Clone, Debug)]
+pub struct StandaloneOptions {
+    /// Check that the data type of the output is the data type the query resolves for the
+    /// expression, e.g. the output type of a udf.
+    pub check_schema: bool,
+    /// Evaluate the expression in the group context of these input columns, as in
+    /// `groupby_stable(group_by).agg([expr])`. The output has one value per group, in the order
+    /// in which the groups first occur.
+    pub group_by: Option<Vec<String>>,
+}
+
+impl Default for StandaloneOptions {
+    fn default() -> Self {
+        Self {
+            check_schema: true,
+            group_by: None,
+        }
+    }
+}
+
+impl StandaloneOptions {
+    pub fn with_check_schema(mut self, check_schema: bool) -> Self {
+        self.check_schema = check_schema;
+        self
+    }
+
+    pub fn with_group_by<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, group_by: I) -> Self {
+        self.group_by = Some(
+            group_by
+                .into_iter()
+                .map(|name| name.as_ref().to_string())
+                .collect(),
+        );
+        self
+    }
+}
+
+pub trait ExprStandaloneExtension: IntoExpr + Sized {
+    /// Evaluate the expression on `inputs`, which are the columns it refers to by name.
+    ///
+    /// This is meant for unit tests of custom expressions, so that they don't have to build a
+    /// [`DataFrame`] and a query. The expression must produce a single column.
+    fn evaluate_standalone(self, inputs: &[Series]) -> PolarsResult<Series> {
+        self.evaluate_standalone_with_options(inputs, &StandaloneOptions::default())
+    }
+
+    /// Evaluate the expression on `inputs`, see [`StandaloneOptions`].
+    fn evaluate_standalone_with_options(
+        self,
+        inputs: &[Series],
+        options: &StandaloneOptions,
+    ) -> PolarsResult<Series> {
+        let expr = self.into_expr();
+        let lf = DataFrame::new(inputs.to_vec())?.lazy();
+        let (lf, n_keys) = match &options.group_by {
+            Some(by) => {
+                let keys = by.iter().map(|name| col(name)).collect::<Vec<_>>();
+                (lf.groupby_stable(keys).agg([expr]), by.len())
+            }
+            None => (lf.select([expr]), 0),
+        };
+        let schema = lf.schema()?;
+        polars_ensure!(
+            schema.len() == n_keys + 1,
+            ComputeError: "'evaluate_standalone' expects an expression that produces a single column"
+        );
+
+        let out = lf.collect()?;
+        let s = out.get_columns()[n_keys].clone();
+        if options.check_schema {
+            let (name, dtype) = schema.get_at_index(n_keys).unwrap();
+            polars_ensure!(
+                s.dtype() == dtype,
+                SchemaMismatch: "the output '{}' has data type {}, but its schema has data type {}",
+                name, s.dtype(), dtype
+            );
+        }
+        Ok(s)
+    }
+}
+
+impl ExprStandaloneExtension for Expr {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_standalone() -> PolarsResult<()> {
+        let a = Series::new("a", [1i64, 2, 3]);
+        let g = Series::new("g", ["x", "y", "x"]);
+
+        let out = (col("a") * lit(2)).evaluate_standalone(&[a.clone()])?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(2), Some(4), Some(6)]);
+
+        let options = StandaloneOptions::default().with_group_by(["g"]);
+        let out = col("a")
+            .sum()
+            .evaluate_standalone_with_options(&[a.clone(), g], &options)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(4), Some(2)]);
+
+        // the udf claims to return a Float64
+        let udf = col("a").map(|s| Ok(Some(s)), GetOutput::from_type(DataType::Float64));
+        assert!(udf.clone().evaluate_standalone(&[a.clone()]).is_err());
+        let options = StandaloneOptions::default().with_check_schema(false);
+        assert!(udf.evaluate_standalone_with_options(&[a], &options).is_ok());
+        Ok(())
+    }
+}
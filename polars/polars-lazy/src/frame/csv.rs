@@ -1,9 +1,12 @@
 use std::path::{Path, PathBuf};
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
 use polars_io::csv::utils::{get_reader_bytes, infer_file_schema};
-use polars_io::csv::{CsvEncoding, NullValues};
-use polars_io::RowCount;
+use polars_io::csv::{
+    open_file_or_url, CsvDelimiter, CsvEncoding, HeaderNormalization, NullValues, NumberFormat,
+};
+use polars_io::{FollowOptions, RowCount};
 
 use crate::frame::LazyFileListReader;
 use crate::prelude::*;
@@ -12,7 +15,7 @@ use crate::prelude::*;
 #[cfg(feature = "csv")]
 pub struct LazyCsvReader<'a> {
     path: PathBuf,
-    delimiter: u8,
+    delimiter: CsvDelimiter,
     has_header: bool,
     ignore_errors: bool,
     skip_rows: usize,
@@ -32,6 +35,11 @@ pub struct LazyCsvReader<'a> {
     encoding: CsvEncoding,
     row_count: Option<RowCount>,
     try_parse_dates: bool,
+    header_normalization: Option<HeaderNormalization>,
+    datetime_formats: Option<Vec<String>>,
+    number_format: Option<NumberFormat>,
+    follow: Option<FollowOptions>,
+    cloud_options: Option<CloudOptions>,
 }
 
 #[cfg(feature = "csv")]
@@ -39,7 +47,7 @@ impl<'a> LazyCsvReader<'a> {
     pub fn new(path: impl AsRef<Path>) -> Self {
         LazyCsvReader {
             path: path.as_ref().to_owned(),
-            delimiter: b',',
+            delimiter: CsvDelimiter::default(),
             has_header: true,
             ignore_errors: false,
             skip_rows: 0,
@@ -59,6 +67,11 @@ impl<'a> LazyCsvReader<'a> {
             encoding: CsvEncoding::Utf8,
             row_count: None,
             try_parse_dates: false,
+            header_normalization: None,
+            datetime_formats: None,
+            number_format: None,
+            follow: None,
+            cloud_options: None,
         }
     }
 
@@ -129,10 +142,11 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
-    /// Set the CSV file's column delimiter as a byte character
+    /// Set the CSV file's column delimiter, a byte character or a [`CsvDelimiter`] of multiple
+    /// characters.
     #[must_use]
-    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = delimiter;
+    pub fn with_delimiter(mut self, delimiter: impl Into<CsvDelimiter>) -> Self {
+        self.delimiter = delimiter.into();
         self
     }
 
@@ -198,6 +212,50 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// The formats, e.g. `"%d.%m.%Y %H:%M"`, that are tried in order when parsing dates and
+    /// datetimes, instead of inferring the pattern.
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn with_datetime_formats(mut self, formats: Option<Vec<String>>) -> Self {
+        self.datetime_formats = formats;
+        self
+    }
+
+    /// Remove the thousands separators and currency symbols of the numeric fields while parsing,
+    /// see [`NumberFormat`].
+    #[must_use]
+    pub fn with_number_format(mut self, number_format: Option<NumberFormat>) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Keep reading the rows that are appended to the file, like `tail -f`, until one of the
+    /// bounds or the end-of-file marker of the [`FollowOptions`] is reached. This requires the
+    /// streaming engine.
+    #[must_use]
+    pub fn with_follow(mut self, follow: Option<FollowOptions>) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Set the [`CloudOptions`] to read a CSV file from a cloud url. The file is downloaded
+    /// into memory before it is parsed.
+    #[must_use]
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
+
+    /// Normalize the column names of the header, see [`HeaderNormalization`].
+    #[must_use]
+    pub fn with_header_normalization(
+        mut self,
+        header_normalization: Option<HeaderNormalization>,
+    ) -> Self {
+        self.header_normalization = header_normalization;
+        self
+    }
+
     /// Modify a schema before we run the lazy scanning.
     ///
     /// Important! Run this function latest in the builder!
@@ -210,9 +268,9 @@ impl<'a> LazyCsvReader<'a> {
                 Some(globresult) => globresult?,
                 None => polars_bail!(ComputeError: "globbing pattern did not match any files"),
             };
-            std::fs::File::open(&path)
+            open_file_or_url(&path, self.cloud_options.as_ref())
         } else {
-            std::fs::File::open(&self.path)
+            open_file_or_url(&self.path, self.cloud_options.as_ref())
         }?;
         let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
         let mut skip_rows = self.skip_rows;
@@ -231,6 +289,9 @@ impl<'a> LazyCsvReader<'a> {
             self.eol_char,
             None,
             self.try_parse_dates,
+            self.header_normalization.as_ref(),
+            self.datetime_formats.as_deref(),
+            self.number_format.as_ref(),
         )?;
         let mut schema = f(schema)?;
 
@@ -268,6 +329,11 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.encoding,
             self.row_count,
             self.try_parse_dates,
+            self.header_normalization,
+            self.datetime_formats,
+            self.number_format,
+            self.follow,
+            self.cloud_options,
         )?
         .build()
         .into();
@@ -288,6 +354,10 @@ impl LazyFileListReader for LazyCsvReader<'_> {
         self.rechunk
     }
 
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
+
     /// Rechunk the memory to contiguous chunks when parsing is done.
     #[must_use]
     fn with_rechunk(mut self, toggle: bool) -> Self {
@@ -0,0 +1,119 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A node of the expression tree, with the window it is evaluated in.
+struct ExplainNode {
+    expr: Expr,
+    depth: u32,
+    // the expression of which the value is taken, i.e. `expr` in its window
+    eval: Expr,
+}
+
+/// Collect the nodes of `expr` depth first. The inputs of a window function are evaluated in
+/// the same window, so that their values are those of the group of the row.
+fn collect_nodes(expr: &Expr, depth: u32, window: Option<&Expr>, nodes: &mut Vec<ExplainNode>) {
+    let eval = match window {
+        Some(Expr::Window {
+            partition_by,
+            order_by,
+            options,
+            ..
+        }) => Expr::Window {
+            function: Box::new(expr.clone()),
+            partition_by: partition_by.clone(),
+            order_by: order_by.clone(),
+            options: *options,
+        },
+        _ => expr.clone(),
+    };
+    nodes.push(ExplainNode {
+        expr: expr.clone(),
+        depth,
+        eval,
+    });
+
+    if let Expr::Window {
+        function,
+        partition_by,
+        order_by,
+        ..
+    } = expr
+    {
+        collect_nodes(function, depth + 1, Some(expr), nodes);
+        for e in partition_by.iter().chain(order_by.as_deref()) {
+            collect_nodes(e, depth + 1, window, nodes);
+        }
+    } else {
+        for input in expr.inputs() {
+            collect_nodes(input, depth + 1, window, nodes);
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Explain how the value at `row` of the column computed by `expr` comes about.
+    ///
+    /// Every node of the expression tree is evaluated on this `LazyFrame` and the result is a
+    /// `DataFrame` with one row per node, depth first:
+    ///
+    /// * `depth` - the depth of the node in the tree, the root is at depth 0.
+    /// * `expr` - the expression of the node.
+    /// * `dtype` - the data type of the result of the node.
+    /// * `len` - the length of the result of the node.
+    /// * `value` - the value of the node at `row`. Results of length 1 are broadcast, results of
+    ///   which the length differs from the output (e.g. a filter) have no value.
+    ///
+    /// The inputs of a window function are evaluated in the same window. As every node is a
+    /// separate query, this is meant for debugging small frames, e.g. to find out where a null
+    /// in a `when/then/otherwise` chain comes from.
+    pub fn explain_value(self, expr: Expr, row: usize) -> PolarsResult<DataFrame> {
+        let mut nodes = vec![];
+        collect_nodes(&expr, 0, None, &mut nodes);
+
+        let mut depths = Vec::with_capacity(nodes.len());
+        let mut exprs = Vec::with_capacity(nodes.len());
+        let mut dtypes = Vec::with_capacity(nodes.len());
+        let mut lens = Vec::with_capacity(nodes.len());
+        let mut values = Vec::with_capacity(nodes.len());
+        let mut height = None;
+        for node in nodes {
+            let out = self.clone().select([node.eval]).collect()?;
+            if out.width() != 1 {
+                // e.g. the wildcard of a multiple column input
+                polars_ensure!(
+                    height.is_some(),
+                    ComputeError: "'explain_value' expects an expression that produces a single column, got {:?}",
+                    node.expr
+                );
+                continue;
+            }
+            let s = &out.get_columns()[0];
+            // the length of the output is the length of the root
+            let height = *height.get_or_insert(s.len());
+            polars_ensure!(
+                row < height,
+                ComputeError: "row {} is out of bounds for an output of length {}", row, height
+            );
+
+            let value = match s.len() {
+                1 => Some(format!("{}", s.get(0)?)),
+                len if len == height => Some(format!("{}", s.get(row)?)),
+                _ => None,
+            };
+            depths.push(node.depth);
+            exprs.push(format!("{:?}", node.expr));
+            dtypes.push(format!("{}", s.dtype()));
+            lens.push(s.len() as IdxSize);
+            values.push(value);
+        }
+
+        DataFrame::new(vec![
+            Series::new("depth", depths),
+            Series::new("expr", exprs),
+            Series::new("dtype", dtypes),
+            Series::new("len", lens),
+            Series::new("value", values),
+        ])
+    }
+}
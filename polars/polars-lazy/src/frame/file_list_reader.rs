@@ -3,6 +3,8 @@ use std::path::{Path, PathBuf};
 use polars_core::cloud::CloudOptions;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_io::listing_cache::{cached_listing, listing_cache_enabled};
+use polars_io::manifest::{FileManifest, ManifestEntry};
 use polars_io::{is_cloud_url, RowCount};
 
 use crate::prelude::*;
@@ -12,20 +14,57 @@ pub type GlobIterator = Box<dyn Iterator<Item = PolarsResult<PathBuf>>>;
 // cloud_options is used only with async feature
 #[allow(unused_variables)]
 fn polars_glob(pattern: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<GlobIterator> {
-    if is_cloud_url(pattern) {
-        #[cfg(feature = "async")]
-        {
-            let paths = polars_io::async_glob(pattern, cloud_options)?;
-            Ok(Box::new(paths.into_iter().map(|a| Ok(PathBuf::from(&a)))))
-        }
-        #[cfg(not(feature = "async"))]
-        panic!("Feature `async` must be enabled to use globbing patterns with cloud urls.")
-    } else {
+    let local_glob = || -> PolarsResult<GlobIterator> {
         let paths = glob::glob(pattern)
             .map_err(|_| polars_err!(ComputeError: "invalid glob pattern given"))?;
-        let paths = paths.map(|v| v.map_err(to_compute_err));
-        Ok(Box::new(paths))
+        Ok(Box::new(paths.map(|v| v.map_err(to_compute_err))))
+    };
+    let is_cloud = is_cloud_url(pattern);
+    if !is_cloud && !listing_cache_enabled() {
+        // the files are yielded while the directories are traversed
+        return local_glob();
     }
+    let paths = cached_listing(pattern, || {
+        if is_cloud {
+            #[cfg(feature = "async")]
+            {
+                let paths = polars_io::async_glob(pattern, cloud_options)?;
+                Ok(paths.into_iter().map(PathBuf::from).collect())
+            }
+            #[cfg(not(feature = "async"))]
+            panic!("Feature `async` must be enabled to use globbing patterns with cloud urls.")
+        } else {
+            local_glob()?.collect()
+        }
+    })?;
+    Ok(Box::new(
+        (0..paths.len()).map(move |i| Ok(paths[i].clone())),
+    ))
+}
+
+/// The files of `manifest` that have to be scanned to read the first `n_rows` rows.
+pub(super) fn manifest_entries(
+    manifest: &FileManifest,
+    n_rows: Option<usize>,
+) -> PolarsResult<Vec<&ManifestEntry>> {
+    let mut entries = Vec::with_capacity(manifest.len());
+    // files of which the number of rows is unknown count as empty, so this is a lower bound
+    let mut rows_seen = 0;
+    for entry in manifest.entries() {
+        if matches!(n_rows, Some(n_rows) if rows_seen >= n_rows) {
+            break;
+        }
+        if entry.size == Some(0) || entry.num_rows == Some(0) {
+            continue;
+        }
+        rows_seen += entry.num_rows.unwrap_or(0);
+        entries.push(entry);
+    }
+    polars_ensure!(
+        !entries.is_empty(),
+        ComputeError: "the manifest contains no files to scan"
+    );
+    Ok(entries)
 }
 
 /// Reads [LazyFrame] from a filesystem or a cloud storage.
@@ -35,7 +74,17 @@ fn polars_glob(pattern: &str, cloud_options: Option<&CloudOptions>) -> PolarsRes
 pub trait LazyFileListReader: Clone {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
-        if let Some(paths) = self.glob()? {
+        let paths = match self.manifest() {
+            Some(manifest) => {
+                let paths = manifest_entries(manifest, self.n_rows())?
+                    .into_iter()
+                    .map(|entry| Ok(entry.path.clone()))
+                    .collect::<Vec<_>>();
+                Some(Box::new(paths.into_iter()) as GlobIterator)
+            }
+            None => self.glob()?,
+        };
+        if let Some(paths) = paths {
             let lfs = paths
                 .map(|r| {
                     let path = r?;
@@ -112,6 +161,12 @@ pub trait LazyFileListReader: Clone {
         None
     }
 
+    /// Precomputed list of the files to scan. If set, the files are not listed, and
+    /// [LazyFileListReader::path] is ignored.
+    fn manifest(&self) -> Option<&FileManifest> {
+        None
+    }
+
     /// Get list of files referenced by this reader.
     ///
     /// Returns [None] if path is not a glob pattern.
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_io::flight::{FlightDescriptor, FlightProjection};
+
+use super::{LazyFrame, ScanArgsAnonymous};
+
+/// The lazy source of [`LazyFrame::scan_flight`].
+#[derive(Clone)]
+pub(crate) struct LazyFlightReader {
+    pub(crate) endpoint: String,
+    pub(crate) descriptor: FlightDescriptor,
+    pub(crate) projection: Option<FlightProjection>,
+}
+
+impl LazyFrame {
+    /// Lazily read the flight of `descriptor` from an Arrow Flight server at `endpoint`,
+    /// e.g. `"http://localhost:50051"`. The schema is received with a `GetSchema` call when the
+    /// scan is created.
+    ///
+    /// The selected columns are encoded in the descriptor by `projection`; without it the
+    /// columns that are not selected are dropped from every batch before it is converted. The
+    /// calls are cancelled once enough rows are read for a slice or a `limit`, and the streaming
+    /// engine processes the batches as they arrive.
+    pub fn scan_flight(
+        endpoint: &str,
+        descriptor: FlightDescriptor,
+        projection: Option<FlightProjection>,
+    ) -> PolarsResult<Self> {
+        let reader = LazyFlightReader {
+            endpoint: endpoint.to_string(),
+            descriptor,
+            projection,
+        };
+        let args = ScanArgsAnonymous {
+            name: "FLIGHT SCAN",
+            ..ScanArgsAnonymous::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(reader), args)
+    }
+}
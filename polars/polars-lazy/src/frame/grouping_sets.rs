@@ -0,0 +1,265 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Name of the output column that indicates the aggregation level of a row.
+pub const GROUPING_ID: &str = "grouping_id";
+
+/// An aggregation split in partial aggregations over the finest grouping set and a final
+/// aggregation that combines the partial results of a coarser set.
+struct RollupAgg {
+    partial: Vec<Expr>,
+    combine: Expr,
+}
+
+/// Split `agg` in partial aggregations, which are stored in columns with prefix `prefix`,
+/// and the aggregation that combines them. Returns `None` if the result of `agg` can't be
+/// computed from the results of finer groups, e.g. for a median.
+fn rollup_agg(agg: &Expr, prefix: &str) -> Option<RollupAgg> {
+    let partial_name = |i: usize| format!("{prefix}_{i}");
+    let partial = |expr: Expr| vec![expr.alias(&partial_name(0))];
+    let partial_col = || col(&partial_name(0));
+    let (partial, combine) = match agg {
+        Expr::Alias(expr, _) => return rollup_agg(expr, prefix),
+        Expr::Count => (partial(count()), partial_col().sum()),
+        Expr::Agg(agg) => match agg {
+            AggExpr::Sum(_) => (partial(agg.clone().into()), partial_col().sum()),
+            AggExpr::Count(..) => (partial(agg.clone().into()), partial_col().sum()),
+            AggExpr::Min { propagate_nans, .. } => {
+                let combine = if *propagate_nans {
+                    partial_col().nan_min()
+                } else {
+                    partial_col().min()
+                };
+                (partial(agg.clone().into()), combine)
+            }
+            AggExpr::Max { propagate_nans, .. } => {
+                let combine = if *propagate_nans {
+                    partial_col().nan_max()
+                } else {
+                    partial_col().max()
+                };
+                (partial(agg.clone().into()), combine)
+            }
+            // the groups are in order of first occurrence, so the first finer group of a
+            // coarser group holds its first row
+            AggExpr::First(_) => (partial(agg.clone().into()), partial_col().first()),
+            AggExpr::Mean(expr) => {
+                let sum = col(&partial_name(0)).sum();
+                let n = col(&partial_name(1)).sum();
+                let partial = vec![
+                    (**expr).clone().sum().alias(&partial_name(0)),
+                    (**expr).clone().count().alias(&partial_name(1)),
+                ];
+                let mean = sum.cast(DataType::Float64) / n.clone().cast(DataType::Float64);
+                let combine = when(n.gt(lit(0 as IdxSize)))
+                    .then(mean)
+                    .otherwise(NULL.lit());
+                (partial, combine)
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(RollupAgg { partial, combine })
+}
+
+impl LazyFrame {
+    /// Group by multiple subsets of `keys` and aggregate every subset with `aggs`.
+    ///
+    /// This is the equivalent of SQL's `GROUPING SETS`. Every set in `sets` holds the
+    /// indices of the `keys` it groups by; an empty set aggregates the whole frame.
+    /// The results of all sets are concatenated vertically. Keys that are not part of a
+    /// set are `null` and the [`GROUPING_ID`] column (`UInt32`) marks the aggregation level:
+    /// bit `n_keys - 1 - i` is set if key `i` was not grouped on, like `GROUPING_ID` in SQL.
+    ///
+    /// If every aggregation can be computed from the aggregations of finer groups, e.g. a
+    /// sum, min, max, count or mean, the input is only aggregated by the union of all sets,
+    /// and the other sets are rolled up from that result. Otherwise the input is cached, so
+    /// that it is only computed once for all sets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> PolarsResult<LazyFrame> {
+    ///     // (region, product), (region), ()
+    ///     lf.groupby_sets(
+    ///         [col("region"), col("product")],
+    ///         &[vec![0, 1], vec![0], vec![]],
+    ///         [col("sales").sum()],
+    ///     )
+    /// }
+    /// ```
+    pub fn groupby_sets<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        keys: E,
+        sets: &[Vec<usize>],
+        aggs: A,
+    ) -> PolarsResult<LazyFrame> {
+        let keys = keys.as_ref();
+        let aggs = aggs.as_ref();
+        polars_ensure!(!sets.is_empty(), ComputeError: "'groupby_sets' needs at least one grouping set");
+        let n_keys = keys.len();
+        polars_ensure!(
+            n_keys <= 32,
+            ComputeError: "'groupby_sets' supports at most 32 keys, got {}", n_keys
+        );
+
+        let key_schema = self.clone().select(keys).schema()?;
+        polars_ensure!(
+            key_schema.len() == n_keys,
+            ComputeError: "every key in 'groupby_sets' should produce exactly one column"
+        );
+        let key_names = key_schema.iter_names().cloned().collect::<Vec<_>>();
+
+        let mut finest = vec![false; n_keys];
+        for &idx in sets.iter().flatten() {
+            polars_ensure!(
+                idx < n_keys,
+                ComputeError: "grouping set index {} is out of bounds for {} keys", idx, n_keys
+            );
+            finest[idx] = true;
+        }
+        let finest_keys = keys
+            .iter()
+            .zip(&finest)
+            .filter(|(_, grouped)| **grouped)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        let rollup_aggs = if finest_keys.is_empty() {
+            None
+        } else {
+            aggs.iter()
+                .enumerate()
+                .map(|(i, agg)| rollup_agg(agg, &format!("__POLARS_GROUPING_SETS_{i}")))
+                .collect::<Option<Vec<_>>>()
+        };
+
+        // Either the input, or the aggregation of the finest set of which the keys are named
+        // as the output columns.
+        let (input, set_aggs) = match rollup_aggs {
+            Some(rollup_aggs) => {
+                // the names and data types of the aggregations
+                let agg_schema = self
+                    .clone()
+                    .groupby(finest_keys.clone())
+                    .agg(aggs)
+                    .schema()?;
+                let agg_fields = agg_schema.iter_fields().skip(finest_keys.len());
+                let partial = rollup_aggs
+                    .iter()
+                    .flat_map(|agg| agg.partial.iter().cloned())
+                    .collect::<Vec<_>>();
+                let finest_names = key_names
+                    .iter()
+                    .zip(&finest)
+                    .filter(|(_, grouped)| **grouped)
+                    .map(|(name, _)| name.as_str());
+                let finest_keys = finest_keys
+                    .into_iter()
+                    .zip(finest_names)
+                    .map(|(key, name)| key.alias(name))
+                    .collect::<Vec<_>>();
+                let combine = rollup_aggs
+                    .into_iter()
+                    .zip(agg_fields)
+                    .map(|(agg, field)| {
+                        agg.combine
+                            .cast(field.data_type().clone())
+                            .alias(field.name())
+                    })
+                    .collect::<Vec<_>>();
+                let input = self.groupby_stable(finest_keys).agg(partial).cache();
+                let keys = key_names.iter().map(|name| col(name)).collect::<Vec<_>>();
+                (input, Some((keys, combine)))
+            }
+            None => (self.cache(), None),
+        };
+        let (keys, aggs) = match &set_aggs {
+            Some((keys, combine)) => (keys.as_slice(), combine.as_slice()),
+            None => (keys, aggs),
+        };
+
+        let parts = sets
+            .iter()
+            .map(|set| {
+                let mut grouped = vec![false; n_keys];
+                for &idx in set {
+                    grouped[idx] = true;
+                }
+                let grouping_id = grouped
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, grouped)| !**grouped)
+                    .fold(0u32, |acc, (i, _)| acc | 1 << (n_keys - 1 - i));
+
+                let lf = if set.is_empty() {
+                    input.clone().select(aggs)
+                } else {
+                    let set_keys = set.iter().map(|&idx| keys[idx].clone()).collect::<Vec<_>>();
+                    input.clone().groupby_stable(set_keys).agg(aggs)
+                };
+
+                // Fill the keys that are not part of this set with nulls and fix the column order.
+                let mut exprs = Vec::with_capacity(n_keys + 2);
+                for ((name, dtype), grouped) in key_schema.iter().zip(&grouped) {
+                    if *grouped {
+                        exprs.push(col(name));
+                    } else {
+                        exprs.push(NULL.lit().cast(dtype.clone()).alias(name));
+                    }
+                }
+                exprs.push(lit(grouping_id).alias(GROUPING_ID));
+                exprs.push(col("*").exclude(&key_names));
+                Ok(lf.select(exprs))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        concat(parts, false, true)
+    }
+
+    /// Group by every prefix of `keys`, from all keys down to the grand total.
+    ///
+    /// This is the equivalent of SQL's `ROLLUP`. See [`LazyFrame::groupby_sets`] for
+    /// the layout of the output.
+    pub fn groupby_rollup<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        keys: E,
+        aggs: A,
+    ) -> PolarsResult<LazyFrame> {
+        let n_keys = keys.as_ref().len();
+        let sets = (0..=n_keys)
+            .rev()
+            .map(|len| (0..len).collect())
+            .collect::<Vec<_>>();
+        self.groupby_sets(keys, &sets, aggs)
+    }
+
+    /// Group by every combination of `keys`, including the grand total.
+    ///
+    /// This is the equivalent of SQL's `CUBE`. See [`LazyFrame::groupby_sets`] for
+    /// the layout of the output.
+    pub fn groupby_cube<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        keys: E,
+        aggs: A,
+    ) -> PolarsResult<LazyFrame> {
+        let n_keys = keys.as_ref().len();
+        polars_ensure!(
+            n_keys <= 16,
+            ComputeError: "'groupby_cube' supports at most 16 keys, got {}", n_keys
+        );
+        // Ordered by grouping id, so that the most detailed level comes first.
+        let sets = (0u32..1 << n_keys)
+            .map(|grouping_id| {
+                (0..n_keys)
+                    .filter(|i| grouping_id & (1 << (n_keys - 1 - i)) == 0)
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        self.groupby_sets(keys, &sets, aggs)
+    }
+}
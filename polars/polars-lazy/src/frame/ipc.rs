@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
+use polars_io::manifest::FileManifest;
 use polars_io::RowCount;
 
 use crate::prelude::*;
@@ -30,11 +31,16 @@ impl Default for ScanArgsIpc {
 struct LazyIpcReader {
     args: ScanArgsIpc,
     path: PathBuf,
+    manifest: Option<Arc<FileManifest>>,
 }
 
 impl LazyIpcReader {
     fn new(path: PathBuf, args: ScanArgsIpc) -> Self {
-        Self { args, path }
+        Self {
+            args,
+            path,
+            manifest: None,
+        }
     }
 }
 
@@ -87,6 +93,10 @@ impl LazyFileListReader for LazyIpcReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn manifest(&self) -> Option<&FileManifest> {
+        self.manifest.as_deref()
+    }
 }
 
 impl LazyFrame {
@@ -94,4 +104,12 @@ impl LazyFrame {
     pub fn scan_ipc(path: impl AsRef<Path>, args: ScanArgsIpc) -> PolarsResult<Self> {
         LazyIpcReader::new(path.as_ref().to_owned(), args).finish()
     }
+
+    /// Create a LazyFrame from an ipc scan of the files listed in `manifest`, instead of
+    /// listing the files of a glob pattern.
+    pub fn scan_ipc_manifest(manifest: FileManifest, args: ScanArgsIpc) -> PolarsResult<Self> {
+        let mut reader = LazyIpcReader::new(PathBuf::new(), args);
+        reader.manifest = Some(Arc::new(manifest));
+        reader.finish()
+    }
 }
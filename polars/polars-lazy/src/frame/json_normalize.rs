@@ -0,0 +1,116 @@
+use polars_core::prelude::*;
+use smartstring::alias::String as SmartString;
+
+use crate::prelude::*;
+
+/// Arguments for [`LazyFrame::json_normalize`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonNormalizeOptions {
+    /// Columns that are kept next to the normalized records. All other
+    /// columns are kept if `None`.
+    pub meta: Option<Vec<SmartString>>,
+    /// Separator between the name of a parent and the name of its field.
+    pub separator: SmartString,
+    /// The number of nesting levels that are flattened; `usize::MAX` flattens all levels.
+    pub max_depth: usize,
+}
+
+impl Default for JsonNormalizeOptions {
+    fn default() -> Self {
+        Self {
+            meta: None,
+            separator: ".".into(),
+            max_depth: usize::MAX,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Flatten the nested records in `columns` into a long format.
+    ///
+    /// Every `List[Struct]` column is exploded and unnested and every `Struct` column is
+    /// unnested, level by level, until `max_depth` levels are flattened. The new columns are
+    /// named `{parent}{separator}{field}`. Sibling `List[Struct]` columns are exploded one
+    /// after the other, so their records are combined as a cartesian product.
+    ///
+    /// This only builds a query, so projection pushdown prunes the fields that are not used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> PolarsResult<LazyFrame> {
+    ///     let options = JsonNormalizeOptions {
+    ///         meta: Some(vec!["id".into()]),
+    ///         ..Default::default()
+    ///     };
+    ///     // id, orders.item, orders.price.amount, orders.price.currency
+    ///     lf.json_normalize(["orders"], options)
+    /// }
+    /// ```
+    pub fn json_normalize<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        columns: I,
+        options: JsonNormalizeOptions,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.schema()?;
+        let mut pending = columns
+            .into_iter()
+            .map(|name| {
+                let name = name.as_ref();
+                let dtype = schema
+                    .get(name)
+                    .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))?;
+                Ok((SmartString::from(name), dtype.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut lf = match &options.meta {
+            Some(meta) => {
+                let exprs = meta
+                    .iter()
+                    .map(|name| name.as_str())
+                    .chain(pending.iter().map(|(name, _)| name.as_str()))
+                    .map(col)
+                    .collect::<Vec<_>>();
+                self.select(exprs)
+            }
+            None => self,
+        };
+
+        let unnest_options = UnnestOptions {
+            separator: Some(options.separator.clone()),
+            ..Default::default()
+        };
+        for _ in 0..options.max_depth {
+            let mut unnest = vec![];
+            let mut next = vec![];
+            for (name, dtype) in pending {
+                let fields = match dtype {
+                    DataType::List(inner) => match *inner {
+                        DataType::Struct(fields) => {
+                            lf = lf.explode([col(&name)]);
+                            fields
+                        }
+                        _ => continue,
+                    },
+                    DataType::Struct(fields) => fields,
+                    _ => continue,
+                };
+                next.extend(fields.into_iter().map(|fld| {
+                    let field_name = format!("{}{}{}", name, options.separator, fld.name());
+                    (SmartString::from(field_name), fld.data_type().clone())
+                }));
+                unnest.push(name);
+            }
+            if unnest.is_empty() {
+                break;
+            }
+            lf = lf.unnest_with_options(unnest, unnest_options.clone());
+            pending = next;
+        }
+        Ok(lf)
+    }
+}
@@ -1,6 +1,8 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
 #[cfg(feature = "csv")]
 mod csv;
+#[cfg(feature = "flight")]
+mod flight;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "json")]
@@ -11,12 +13,17 @@ mod parquet;
 mod python;
 
 mod anonymous_scan;
+mod explain_value;
 mod file_list_reader;
+mod grouping_sets;
+#[cfg(feature = "dtype-struct")]
+mod json_normalize;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod validate;
 
 use std::borrow::Cow;
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -24,13 +31,20 @@ pub use anonymous_scan::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 pub use file_list_reader::*;
+#[cfg(feature = "flight")]
+pub(crate) use flight::LazyFlightReader;
+pub use grouping_sets::GROUPING_ID;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "dtype-struct")]
+pub use json_normalize::JsonNormalizeOptions;
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_arrow::prelude::QuantileInterpolOptions;
+#[cfg(feature = "async")]
+use polars_core::cloud::CloudOptions;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
@@ -127,6 +141,8 @@ impl LazyFrame {
             #[cfg(feature = "cse")]
             common_subplan_elimination: false,
             streaming: false,
+            aggregate_pushdown: false,
+            join_reorder: false,
         })
     }
 
@@ -167,6 +183,18 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle aggregating before a join when the aggregation is on the join keys
+    pub fn with_aggregate_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.aggregate_pushdown = toggle;
+        self
+    }
+
+    /// Toggle joining the smallest tables of a chain of joins first
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
     /// Allow (partial) streaming engine
     pub fn with_streaming(mut self, toggle: bool) -> Self {
         self.opt_state.streaming = toggle;
@@ -192,6 +220,16 @@ impl LazyFrame {
         Ok(logical_plan.describe())
     }
 
+    /// Get the lineage of the output column `name`: the columns of the scans (files or in-memory
+    /// frames) it is derived from, and the expressions that compute it. The optimized plan is
+    /// walked, so the lineage reflects the query as it is executed.
+    pub fn column_lineage(&self, name: &str) -> PolarsResult<ColumnLineage> {
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(64);
+        let lp_top = self.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        column_lineage(lp_top, name, &lp_arena, &expr_arena)
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// # Example
@@ -552,7 +590,10 @@ impl LazyFrame {
     /// containing the materialized DataFrame and a DataFrame that contains profiling information
     /// of each node that is executed.
     ///
-    /// The units of the timings are microseconds.
+    /// Every node has a row with its `start` and `end`, in microseconds since the start of the
+    /// query, the number of `rows` it produced and the `peak_memory` of the process in bytes
+    /// while it ran. The peak memory is sampled, so short peaks may be missed, and is null if the
+    /// memory of the process can't be determined.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
         state.time_nodes();
@@ -565,43 +606,119 @@ impl LazyFrame {
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "parquet")]
-    pub fn sink_parquet(mut self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = LogicalPlan::FileSink {
-            input: Box::new(self.logical_plan),
-            payload: FileSinkOptions {
+    pub fn sink_parquet(self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
                 path: Arc::new(path),
                 file_type: FileType::Parquet(options),
+                cloud_options: None,
             },
-        };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
-        polars_ensure!(
-            is_streaming,
-            ComputeError: "cannot run the whole query in a streaming order; \
-            use `collect().write_parquet()` instead"
-        );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+            "collect().write_parquet()",
+        )
+    }
+
+    /// Stream a query result into a parquet file on cloud storage. The file is uploaded in parts
+    /// while the query runs, see [`CloudOptions::with_upload_part_size`]. This methods will return
+    /// an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(all(feature = "parquet", feature = "async"))]
+    pub fn sink_parquet_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(PathBuf::from(uri)),
+                file_type: FileType::Parquet(options),
+                cloud_options,
+            },
+            "collect().write_parquet()",
+        )
     }
 
     /// Stream a query result into an ipc/arrow file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "ipc")]
-    pub fn sink_ipc(mut self, path: PathBuf, options: IpcWriterOptions) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = LogicalPlan::FileSink {
-            input: Box::new(self.logical_plan),
-            payload: FileSinkOptions {
+    pub fn sink_ipc(self, path: PathBuf, options: IpcWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
                 path: Arc::new(path),
                 file_type: FileType::Ipc(options),
+                cloud_options: None,
             },
+            "collect().write_ipc()",
+        )
+    }
+
+    /// Stream a query result into an ipc/arrow file on cloud storage. The file is uploaded in
+    /// parts while the query runs, see [`CloudOptions::with_upload_part_size`]. This methods will
+    /// return an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(all(feature = "ipc", feature = "async"))]
+    pub fn sink_ipc_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(PathBuf::from(uri)),
+                file_type: FileType::Ipc(options),
+                cloud_options,
+            },
+            "collect().write_ipc()",
+        )
+    }
+
+    /// Stream a query result into a csv file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "csv")]
+    pub fn sink_csv(self, path: PathBuf, options: CsvWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::Csv(options),
+                cloud_options: None,
+            },
+            "collect().write_csv()",
+        )
+    }
+
+    /// Stream a query result into a csv file on cloud storage. The file is uploaded in parts
+    /// while the query runs, see [`CloudOptions::with_upload_part_size`]. This methods will return
+    /// an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(all(feature = "csv", feature = "async"))]
+    pub fn sink_csv_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: CsvWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(PathBuf::from(uri)),
+                file_type: FileType::Csv(options),
+                cloud_options,
+            },
+            "collect().write_csv()",
+        )
+    }
+
+    #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+    fn sink(mut self, payload: FileSinkOptions, msg_alternative: &str) -> PolarsResult<()> {
+        self.opt_state.streaming = true;
+        self.logical_plan = LogicalPlan::FileSink {
+            input: Box::new(self.logical_plan),
+            payload,
         };
         let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
         polars_ensure!(
             is_streaming,
             ComputeError: "cannot run the whole query in a streaming order; \
-            use `collect().write_ipc()` instead"
+            use `{}` instead", msg_alternative
         );
         let _ = physical_plan.execute(&mut state)?;
         Ok(())
@@ -661,6 +778,25 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Aggregate every column of the `selector` with every aggregation of `ops`, e.g. the mean,
+    /// the standard deviation and the 95th percentile of all numeric columns. The aggregated
+    /// columns are named `{column}_{op}`, see [`AggOp::apply_all`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///         .agg_all(dtype_cols([DataType::Float64]), &[AggOp::Mean, AggOp::Quantile(0.95)])
+    /// }
+    /// ```
+    pub fn agg_all(self, selector: Expr, ops: &[AggOp]) -> Self {
+        self.select(AggOp::apply_all(selector, ops))
+    }
+
     /// A projection that doesn't get optimized and may drop projections if they are not in
     /// schema after optimization
     fn select_local(self, exprs: Vec<Expr>) -> Self {
@@ -855,12 +991,177 @@ impl LazyFrame {
         self.join(other, [left_on.into()], [right_on.into()], JoinType::Inner)
     }
 
+    /// Keep the rows that have a matching key in `other`, without adding the columns of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .semi_join(other, col("foo"), col("bar"))
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    pub fn semi_join<E: Into<Expr>>(self, other: LazyFrame, left_on: E, right_on: E) -> LazyFrame {
+        self.join(other, [left_on.into()], [right_on.into()], JoinType::Semi)
+    }
+
+    /// Keep the rows that don't have a matching key in `other`, without adding the columns of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .anti_join(other, col("foo"), col("bar"))
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    pub fn anti_join<E: Into<Expr>>(self, other: LazyFrame, left_on: E, right_on: E) -> LazyFrame {
+        self.join(other, [left_on.into()], [right_on.into()], JoinType::Anti)
+    }
+
     /// Creates the cartesian product from both frames, preserves the order of the left keys.
     #[cfg(feature = "cross_join")]
     pub fn cross_join(self, other: LazyFrame) -> LazyFrame {
         self.join(other, vec![], vec![], JoinType::Cross)
     }
 
+    /// Join with `other` on inequality predicates between a column of this frame and
+    /// a column of `other`, e.g. `col("start").lt_eq(col("ts"))`. Predicates can be
+    /// combined with `and`. Only the pairs of rows that satisfy all predicates are kept,
+    /// and the columns of both frames remain.
+    ///
+    /// The right keys are sorted and searched, so the pairs of rows don't have to be
+    /// enumerated as in a cross join followed by a filter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn events_in_intervals(events: LazyFrame, intervals: LazyFrame) -> PolarsResult<LazyFrame> {
+    ///     events.join_where(
+    ///         intervals,
+    ///         [col("start").lt_eq(col("ts")), col("ts").lt(col("end"))],
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "range_join")]
+    pub fn join_where<E: AsRef<[Expr]>>(
+        self,
+        other: LazyFrame,
+        predicates: E,
+    ) -> PolarsResult<LazyFrame> {
+        fn flatten_and(e: &Expr, out: &mut Vec<Expr>) {
+            match e {
+                Expr::BinaryExpr {
+                    left,
+                    op: Operator::And,
+                    right,
+                } => {
+                    flatten_and(left, out);
+                    flatten_and(right, out);
+                }
+                e => out.push(e.clone()),
+            }
+        }
+        let mut flattened = vec![];
+        for e in predicates.as_ref() {
+            flatten_and(e, &mut flattened);
+        }
+
+        let schema_left = self.schema()?;
+        let schema_right = other.schema()?;
+        let mut left_on = Vec::with_capacity(flattened.len());
+        let mut right_on = Vec::with_capacity(flattened.len());
+        let mut ops = Vec::with_capacity(flattened.len());
+        for e in &flattened {
+            let invalid = || {
+                polars_err!(
+                    InvalidOperation: "`join_where` expects predicates of the form \
+                    `col(a) <op> col(b)` with an inequality operator, got: {:?}", e
+                )
+            };
+            let Expr::BinaryExpr { left, op, right } = e else {
+                return Err(invalid());
+            };
+            let op = match op {
+                Operator::Lt => InequalityOperator::Lt,
+                Operator::LtEq => InequalityOperator::LtEq,
+                Operator::Gt => InequalityOperator::Gt,
+                Operator::GtEq => InequalityOperator::GtEq,
+                _ => return Err(invalid()),
+            };
+            let (Expr::Column(a), Expr::Column(b)) = (left.as_ref(), right.as_ref()) else {
+                return Err(invalid());
+            };
+            if schema_left.contains(a) && schema_right.contains(b) {
+                left_on.push(Expr::Column(a.clone()));
+                right_on.push(Expr::Column(b.clone()));
+                ops.push(op);
+            } else if schema_right.contains(a) && schema_left.contains(b) {
+                left_on.push(Expr::Column(b.clone()));
+                right_on.push(Expr::Column(a.clone()));
+                ops.push(op.flip());
+            } else {
+                polars_bail!(
+                    ColumnNotFound: "the predicate {:?} should compare a column of both frames", e
+                );
+            }
+        }
+        polars_ensure!(
+            !ops.is_empty(),
+            InvalidOperation: "`join_where` needs at least one predicate"
+        );
+        Ok(self.join(other, left_on, right_on, JoinType::Range(ops)))
+    }
+
+    /// Join the intervals `start`, `end` of this frame to the `point`s of `other` that
+    /// fall within them. By default an interval is `(start, end]`, see
+    /// [`IntervalJoinOptions`] for the other bounds and for joining only within the groups
+    /// of `by` columns.
+    ///
+    /// The points are sorted once, so the points within an interval are found with a
+    /// binary search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn events_per_session(sessions: LazyFrame, events: LazyFrame) -> LazyFrame {
+    ///     let options = IntervalJoinOptions {
+    ///         left_by: Some(vec!["user".into()]),
+    ///         right_by: Some(vec!["user".into()]),
+    ///         ..Default::default()
+    ///     };
+    ///     sessions.join_interval(events, col("start"), col("end"), col("ts"), options)
+    /// }
+    /// ```
+    #[cfg(feature = "interval_join")]
+    pub fn join_interval(
+        self,
+        other: LazyFrame,
+        start: Expr,
+        end: Expr,
+        point: Expr,
+        options: IntervalJoinOptions,
+    ) -> LazyFrame {
+        self.join(
+            other,
+            vec![start, end],
+            vec![point],
+            JoinType::Interval(options),
+        )
+    }
+
     /// Generic join function that can join on multiple columns.
     ///
     /// # Example
@@ -943,6 +1244,26 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Add external contexts to this `LazyFrame`.
+    ///
+    /// Expressions that run on the result can reference columns of the `contexts` by name,
+    /// without joining them. Columns of `self` take precedence over columns of the contexts.
+    /// In a `select` or `with_columns` the context columns are used as is, so this is meant
+    /// for aggregations and lookups against (small) reference frames. In a `groupby`
+    /// aggregation the context columns are aligned with the rows of `self` by index: a
+    /// context column of length 1 is broadcasted and any other length must match the
+    /// height of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn is_known(df: LazyFrame, reference: LazyFrame) -> LazyFrame {
+    ///     df.with_context([reference])
+    ///         .select([col("id").is_in(col("known_ids")).alias("is_known")])
+    /// }
+    /// ```
     pub fn with_context<C: AsRef<[LazyFrame]>>(self, contexts: C) -> LazyFrame {
         let contexts = contexts
             .as_ref()
@@ -1042,19 +1363,13 @@ impl LazyFrame {
 
     /// Drop null rows.
     ///
-    /// Equal to `LazyFrame::filter(col("*").is_not_null())`
+    /// Equal to `LazyFrame::filter(all_not_null([col("*")]))`. The `subset` may contain
+    /// selectors like `col("^a.*$")` or `dtype_col(..)`, a row is dropped if any of the selected
+    /// columns is null.
     pub fn drop_nulls(self, subset: Option<Vec<Expr>>) -> LazyFrame {
         match subset {
-            None => self.filter(all_exprs([col("*").is_not_null()])),
-            Some(subset) => {
-                let predicate = all_exprs(
-                    subset
-                        .into_iter()
-                        .map(|e| e.is_not_null())
-                        .collect::<Vec<_>>(),
-                );
-                self.filter(predicate)
-            }
+            None => self.filter(all_not_null([col("*")])),
+            Some(subset) => self.filter(all_not_null(subset)),
         }
     }
 
@@ -1065,6 +1380,11 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Take every `n`th row, starting at row `offset`.
+    pub fn gather_every(self, n: usize, offset: usize) -> LazyFrame {
+        self.map_private(FunctionNode::GatherEvery { n, offset })
+    }
+
     /// Get the first row.
     pub fn first(self) -> LazyFrame {
         self.slice(0, 1)
@@ -1206,8 +1526,21 @@ impl LazyFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest<I: IntoIterator<Item = S>, S: AsRef<str>>(self, cols: I) -> Self {
+        self.unnest_with_options(cols, UnnestOptions::default())
+    }
+
+    /// Unnest the given `Struct` columns with [`UnnestOptions`]. This allows to only insert a
+    /// selection of the fields, to prefix the new columns with the name of the struct column
+    /// (e.g. `{column}.{field}`) and to flatten nested structs recursively.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        cols: I,
+        options: UnnestOptions,
+    ) -> Self {
         self.map_private(FunctionNode::Unnest {
             columns: cols.into_iter().map(|s| Arc::from(s.as_ref())).collect(),
+            options,
         })
     }
 
@@ -1281,6 +1614,13 @@ impl LazyGroupBy {
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Aggregate every column of the `selector` in every group with every aggregation of `ops`.
+    /// The aggregated columns are named `{column}_{op}`, see [`AggOp::apply_all`]. The group
+    /// keys are not aggregated.
+    pub fn agg_all(self, selector: Expr, ops: &[AggOp]) -> LazyFrame {
+        self.agg(AggOp::apply_all(selector, ops))
+    }
+
     /// Return first n rows of each group
     pub fn head(self, n: Option<usize>) -> LazyFrame {
         let keys = self
@@ -1338,11 +1678,14 @@ impl LazyGroupBy {
 pub struct JoinBuilder {
     lf: LazyFrame,
     how: JoinType,
+    algorithm: JoinAlgorithm,
+    validate: JoinValidation,
     other: Option<LazyFrame>,
     left_on: Vec<Expr>,
     right_on: Vec<Expr>,
     allow_parallel: bool,
     force_parallel: bool,
+    low_memory: bool,
     suffix: Option<String>,
 }
 impl JoinBuilder {
@@ -1351,10 +1694,13 @@ impl JoinBuilder {
             lf,
             other: None,
             how: JoinType::Inner,
+            algorithm: JoinAlgorithm::Auto,
+            validate: JoinValidation::ManyToMany,
             left_on: vec![],
             right_on: vec![],
             allow_parallel: true,
             force_parallel: false,
+            low_memory: false,
             suffix: None,
         }
     }
@@ -1371,6 +1717,20 @@ impl JoinBuilder {
         self
     }
 
+    /// Select the algorithm used to find the matching keys of an inner or
+    /// left join. By default a sort-merge join is used if the keys are sorted.
+    pub fn algorithm(mut self, algorithm: JoinAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Check that the join keys are unique in the tables required by `validate`.
+    /// The join fails if they are not. Only supported for inner, left and outer joins.
+    pub fn validate(mut self, validate: JoinValidation) -> Self {
+        self.validate = validate;
+        self
+    }
+
     /// The columns you want to join both tables on.
     pub fn on<E: AsRef<[Expr]>>(mut self, on: E) -> Self {
         let on = on.as_ref().to_vec();
@@ -1402,6 +1762,15 @@ impl JoinBuilder {
         self
     }
 
+    /// Partition the tables by the join keys and spill the partitions to disk if the table
+    /// that is hashed doesn't fit in memory. The partitions are then joined one by one.
+    /// Only supported for inner and left joins in the streaming engine, and the order of
+    /// the rows is not maintained once the join spills.
+    pub fn low_memory(mut self, toggle: bool) -> Self {
+        self.low_memory = toggle;
+        self
+    }
+
     /// Suffix to add duplicate column names in join.
     /// Defaults to `"_right"`.
     pub fn suffix<S: AsRef<str>>(mut self, suffix: S) -> Self {
@@ -1433,6 +1802,9 @@ impl JoinBuilder {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
                     how: self.how,
+                    algorithm: self.algorithm,
+                    validate: self.validate,
+                    low_memory: self.low_memory,
                     suffix,
                     ..Default::default()
                 },
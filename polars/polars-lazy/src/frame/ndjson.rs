@@ -1,9 +1,10 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
-use polars_io::RowCount;
+use polars_io::{FollowOptions, RowCount};
 
 use super::{LazyFileListReader, LazyFrame, ScanArgsAnonymous};
+use crate::prelude::AnonymousScan;
 
 #[derive(Clone)]
 pub struct LazyJsonLineReader {
@@ -15,6 +16,7 @@ pub struct LazyJsonLineReader {
     pub(crate) row_count: Option<RowCount>,
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
+    pub(crate) follow: Option<FollowOptions>,
 }
 
 impl LazyJsonLineReader {
@@ -29,6 +31,7 @@ impl LazyJsonLineReader {
             row_count: None,
             infer_schema_length: Some(100),
             n_rows: None,
+            follow: None,
         }
     }
     /// Add a `row_count` column.
@@ -44,6 +47,13 @@ impl LazyJsonLineReader {
         self.n_rows = num_rows;
         self
     }
+    /// Keep reading the lines that are appended to the file, like `tail -f`, until one of the
+    /// bounds or the end-of-file marker of the [`FollowOptions`] is reached.
+    #[must_use]
+    pub fn with_follow(mut self, follow: Option<FollowOptions>) -> Self {
+        self.follow = follow;
+        self
+    }
     /// Set the number of rows to use when inferring the json schema.
     /// the default is 100 rows.
     /// Setting to `None` will do a full table scan, very slow.
@@ -75,12 +85,25 @@ impl LazyJsonLineReader {
 
 impl LazyFileListReader for LazyJsonLineReader {
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let (schema, row_count) = match (&self.follow, &self.row_count) {
+            // a followed file is streamed, so the scan counts the rows as they are read instead
+            // of a row count on the scanned frame, which would break the pipeline
+            (Some(_), Some(rc)) => {
+                let mut schema = match &self.schema {
+                    Some(schema) => schema.clone(),
+                    None => AnonymousScan::schema(&self, self.infer_schema_length)?,
+                };
+                schema.insert_at_index(0, rc.name.as_str().into(), IDX_DTYPE)?;
+                (Some(schema), None)
+            }
+            _ => (self.schema.clone(), self.row_count.clone()),
+        };
         let options = ScanArgsAnonymous {
             name: "JSON SCAN",
             infer_schema_length: self.infer_schema_length,
             n_rows: self.n_rows,
-            row_count: self.row_count.clone(),
-            schema: self.schema.clone(),
+            row_count,
+            schema,
             ..ScanArgsAnonymous::default()
         };
 
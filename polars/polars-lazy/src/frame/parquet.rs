@@ -2,9 +2,12 @@ use std::path::{Path, PathBuf};
 
 use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
-use polars_io::parquet::ParallelStrategy;
-use polars_io::RowCount;
+use polars_io::hive::{discover_hive_files, infer_hive_schema};
+use polars_io::manifest::FileManifest;
+use polars_io::parquet::{read_metadata_cached, ParallelStrategy, ParquetReader};
+use polars_io::{RowCount, SerReader};
 
+use super::file_list_reader::manifest_entries;
 use crate::prelude::*;
 
 #[derive(Clone)]
@@ -38,11 +41,127 @@ impl Default for ScanArgsParquet {
 struct LazyParquetReader {
     args: ScanArgsParquet,
     path: PathBuf,
+    hive_parts: Option<Arc<HivePartitions>>,
+    manifest: Option<Arc<FileManifest>>,
+    /// The file info of all files, so that their footers aren't read to plan the scan.
+    file_info: Option<FileInfo>,
 }
 
 impl LazyParquetReader {
     fn new(path: PathBuf, args: ScanArgsParquet) -> Self {
-        Self { args, path }
+        Self {
+            args,
+            path,
+            hive_parts: None,
+            manifest: None,
+            file_info: None,
+        }
+    }
+
+    /// Scan a hive partitioned dataset: a directory of which the files are stored in
+    /// `key=value` directories. The partitions are exposed as columns.
+    ///
+    /// The files are assumed to have the schema of the first file, so only the footer of that
+    /// file is read to plan the scan. The other footers are only read if the partitions of
+    /// their files aren't pruned.
+    fn finish_hive(mut self) -> PolarsResult<LazyFrame> {
+        let files = discover_hive_files(&self.path, "parquet")?;
+        polars_ensure!(
+            !files.is_empty(),
+            ComputeError: "no parquet files found in {}", self.path.display()
+        );
+        let schema = infer_hive_schema(&files);
+
+        let first = &files[0].path;
+        let mut reader = ParquetReader::new(std::fs::File::open(first)?)
+            .with_metadata(read_metadata_cached(first)?);
+        self.file_info = Some(FileInfo {
+            schema: Arc::new(reader.schema()?),
+            // only an estimate for the other files
+            row_estimation: (None, reader.num_rows()?),
+            hive_parts: None,
+            statistics: None,
+        });
+
+        let lfs = files
+            .iter()
+            .map(|file| {
+                let columns = file.partition_columns(&schema)?;
+                let mut reader = self
+                    .clone()
+                    .with_path(file.path.clone())
+                    .with_rechunk(false);
+                reader.args.n_rows = None;
+                reader.args.row_count = None;
+                reader.hive_parts =
+                    (!columns.is_empty()).then(|| Arc::new(HivePartitions::new(columns)));
+                reader.finish_no_glob().map_err(|e| {
+                    polars_err!(
+                        ComputeError: "error while reading {}: {}", file.path.display(), e
+                    )
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut lf = self.concat_impl(lfs)?;
+        if let Some(n_rows) = self.n_rows() {
+            lf = lf.slice(0, n_rows as IdxSize)
+        };
+        if let Some(rc) = self.row_count() {
+            lf = lf.with_row_count(&rc.name, Some(rc.offset))
+        };
+        Ok(lf)
+    }
+
+    /// Scan the files of a manifest. Only the footer of the first file is read to plan the
+    /// scan; the other files are assumed to have its schema. Their number of rows and
+    /// statistics are taken from the manifest, so a file of which the statistics show that
+    /// it can't match the predicate is never opened.
+    fn finish_manifest(self) -> PolarsResult<LazyFrame> {
+        let manifest = self.manifest.clone().unwrap();
+        let entries = manifest_entries(&manifest, self.n_rows())?;
+
+        let file_reader = |path: &Path, file_info: Option<FileInfo>| {
+            let mut reader = self
+                .clone()
+                .with_path(path.to_path_buf())
+                .with_rechunk(false);
+            reader.args.n_rows = None;
+            reader.args.row_count = None;
+            reader.file_info = file_info;
+            reader.finish_no_glob().map_err(
+                |e| polars_err!(ComputeError: "error while reading {}: {}", path.display(), e),
+            )
+        };
+        let first = file_reader(&entries[0].path, None)?;
+        let (schema, estimated_rows) = match &first.logical_plan {
+            LogicalPlan::ParquetScan { file_info, .. } => {
+                (file_info.schema.clone(), file_info.row_estimation.1)
+            }
+            _ => unreachable!(),
+        };
+
+        let mut lfs = Vec::with_capacity(entries.len());
+        lfs.push(first);
+        for entry in &entries[1..] {
+            let file_info = FileInfo {
+                schema: schema.clone(),
+                row_estimation: (entry.num_rows, entry.num_rows.unwrap_or(estimated_rows)),
+                hive_parts: None,
+                statistics: (!entry.statistics.is_empty())
+                    .then(|| Arc::new(entry.statistics.clone())),
+            };
+            lfs.push(file_reader(&entry.path, Some(file_info))?);
+        }
+
+        let mut lf = self.concat_impl(lfs)?;
+        if let Some(n_rows) = self.n_rows() {
+            lf = lf.slice(0, n_rows as IdxSize)
+        };
+        if let Some(rc) = self.row_count() {
+            lf = lf.with_row_count(&rc.name, Some(rc.offset))
+        };
+        Ok(lf)
     }
 }
 
@@ -60,6 +179,8 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.hive_parts,
+            self.file_info,
         )?
         .build()
         .into();
@@ -95,6 +216,10 @@ impl LazyFileListReader for LazyParquetReader {
         self.args.cloud_options.as_ref()
     }
 
+    fn manifest(&self) -> Option<&FileManifest> {
+        self.manifest.as_deref()
+    }
+
     fn n_rows(&self) -> Option<usize> {
         self.args.n_rows
     }
@@ -129,7 +254,27 @@ impl LazyFrame {
     }
 
     /// Create a LazyFrame directly from a parquet scan.
+    ///
+    /// If `path` is a directory, it is scanned as a hive partitioned dataset: the values of
+    /// the `key=value` directories the files are stored in are added as columns, and
+    /// predicates on those columns skip the files of the partitions they filter out.
     pub fn scan_parquet(path: impl AsRef<Path>, args: ScanArgsParquet) -> PolarsResult<Self> {
-        LazyParquetReader::new(path.as_ref().to_owned(), args).finish()
+        let reader = LazyParquetReader::new(path.as_ref().to_owned(), args);
+        if reader.path.is_dir() {
+            reader.finish_hive()
+        } else {
+            reader.finish()
+        }
+    }
+
+    /// Create a LazyFrame from a parquet scan of the files listed in `manifest`, instead of
+    /// listing the files of a directory or glob pattern.
+    pub fn scan_parquet_manifest(
+        manifest: FileManifest,
+        args: ScanArgsParquet,
+    ) -> PolarsResult<Self> {
+        let mut reader = LazyParquetReader::new(PathBuf::new(), args);
+        reader.manifest = Some(Arc::new(manifest));
+        reader.finish_manifest()
     }
 }
@@ -0,0 +1,143 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+const ROW_IDX: &str = "__POLARS_VALIDATE_ROW_IDX";
+
+impl LazyFrame {
+    /// Check a set of named boolean `constraints` against this `LazyFrame`.
+    ///
+    /// A row violates a constraint if the constraint evaluates to `false` for that row;
+    /// `null` outcomes are not counted as violations. All constraints are evaluated in a
+    /// single query and the result is a summary `DataFrame` with one row per constraint:
+    ///
+    /// * `constraint` - the output name of the constraint expression.
+    /// * `violations` - the number of rows violating the constraint.
+    /// * `example_rows` - the row indices of at most `n_examples` offending rows.
+    /// * `examples` - the values of the columns the constraint depends on in those rows,
+    ///   formatted as `column=value, ...`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> PolarsResult<DataFrame> {
+    ///     lf.validate(
+    ///         [
+    ///             col("age").is_between(lit(0), lit(130)).alias("age_in_range"),
+    ///             col("code")
+    ///                 .is_in_ranges([(lit(100), lit(199)), (lit(300), lit(399))])
+    ///                 .alias("code_valid"),
+    ///             col("name").is_not_null().alias("name_present"),
+    ///         ],
+    ///         5,
+    ///     )
+    /// }
+    /// ```
+    pub fn validate<E: AsRef<[Expr]>>(
+        self,
+        constraints: E,
+        n_examples: usize,
+    ) -> PolarsResult<DataFrame> {
+        let constraints = constraints.as_ref();
+        let schema = self.clone().select(constraints).schema()?;
+        polars_ensure!(
+            schema.len() == constraints.len(),
+            ComputeError: "every constraint in 'validate' should produce exactly one column"
+        );
+        let names = schema.iter_names().cloned().collect::<Vec<_>>();
+        // the columns of which the values of the offending rows are reported
+        let columns = constraints
+            .iter()
+            .map(|constraint| {
+                let mut columns: Vec<Arc<str>> = vec![];
+                for e in constraint {
+                    if let Expr::Column(name) = e {
+                        if !columns.contains(name) {
+                            columns.push(name.clone())
+                        }
+                    }
+                }
+                columns
+            })
+            .collect::<Vec<_>>();
+
+        // Materialize every mask once and aggregate both the count and the examples
+        // from it, so the constraints are evaluated in a single pass over the data.
+        let mut masks = Vec::with_capacity(constraints.len());
+        let mut aggs = Vec::with_capacity(constraints.len() * 2);
+        for (i, constraint) in constraints.iter().enumerate() {
+            let mask_name = format!("__POLARS_VALIDATE_MASK_{i}");
+            masks.push(
+                constraint
+                    .clone()
+                    .not()
+                    .fill_null(lit(false))
+                    .alias(&mask_name),
+            );
+            aggs.push(
+                col(&mask_name)
+                    .sum()
+                    .cast(IDX_DTYPE)
+                    .alias(&format!("violations_{i}")),
+            );
+            let example = |name: &str| {
+                col(name)
+                    .filter(col(&mask_name))
+                    .head(Some(n_examples))
+                    .implode()
+            };
+            aggs.push(example(ROW_IDX).alias(&format!("example_rows_{i}")));
+            for (j, name) in columns[i].iter().enumerate() {
+                aggs.push(example(name).alias(&format!("examples_{i}_{j}")));
+            }
+        }
+        let out = self
+            .with_row_count(ROW_IDX, None)
+            .with_columns(masks)
+            .select(aggs)
+            .collect()?;
+
+        let mut violations = Series::new_empty("violations", &IDX_DTYPE);
+        let mut example_rows =
+            Series::new_empty("example_rows", &DataType::List(Box::new(IDX_DTYPE)));
+        let mut examples = Vec::with_capacity(names.len());
+        for (i, columns) in columns.iter().enumerate() {
+            violations.append(out.column(&format!("violations_{i}"))?)?;
+            let rows = out.column(&format!("example_rows_{i}"))?;
+            example_rows.append(rows)?;
+
+            let n_rows = rows.list()?.get(0).map_or(0, |rows| rows.len());
+            let values = (0..columns.len())
+                .map(|j| {
+                    let values = out.column(&format!("examples_{i}_{j}"))?.list()?.get(0);
+                    Ok(values.unwrap_or_else(|| Series::new_empty("", &DataType::Null)))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let formatted = (0..n_rows)
+                .map(|row| {
+                    let fields = columns
+                        .iter()
+                        .zip(&values)
+                        .map(|(name, values)| Ok(format!("{name}={}", values.get(row)?)))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    Ok(fields.join(", "))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            examples.push(Some(Series::new("", formatted)));
+        }
+        let constraint =
+            Utf8Chunked::from_iter_values("constraint", names.iter().map(|name| name.as_str()))
+                .into_series();
+        let mut examples: ListChunked = examples.into_iter().collect();
+        examples.rename("examples");
+        DataFrame::new(vec![
+            constraint,
+            violations,
+            example_rows,
+            examples.into_series(),
+        ])
+    }
+}
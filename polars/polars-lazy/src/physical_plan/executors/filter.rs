@@ -1,13 +1,25 @@
 use super::*;
 
 pub struct FilterExec {
-    pub(crate) predicate: Arc<dyn PhysicalExpr>,
+    /// The conjunctions of the predicate, in the order in which they are applied.
+    pub(crate) predicates: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) input: Box<dyn Executor>,
 }
 
 impl FilterExec {
     pub fn new(predicate: Arc<dyn PhysicalExpr>, input: Box<dyn Executor>) -> Self {
-        Self { predicate, input }
+        Self::new_conjunctions(vec![predicate], input)
+    }
+
+    /// Filter on predicates that are combined with `&`.
+    ///
+    /// Every predicate is only evaluated on the rows that passed the previous ones,
+    /// so the predicates should only depend on the values of their own row.
+    pub fn new_conjunctions(
+        predicates: Vec<Arc<dyn PhysicalExpr>>,
+        input: Box<dyn Executor>,
+    ) -> Self {
+        Self { predicates, input }
     }
 }
 
@@ -19,29 +31,36 @@ impl Executor for FilterExec {
                 println!("run FilterExec")
             }
         }
-        let df = self.input.execute(state)?;
-        let s = self.predicate.evaluate(&df, state)?;
-        let mask = s.bool().map_err(|_| {
-            polars_err!(
-                ComputeError: "filter predicate must be of type `Boolean`, got `{}`", s.dtype()
-            )
-        })?;
+        let mut df = self.input.execute(state)?;
+        for predicate in &self.predicates {
+            // Nothing left to filter, so the remaining predicates can be skipped.
+            if df.height() == 0 && self.predicates.len() > 1 {
+                break;
+            }
+            let s = predicate.evaluate(&df, state)?;
+            let mask = s.bool().map_err(|_| {
+                polars_err!(
+                    ComputeError: "filter predicate must be of type `Boolean`, got `{}`", s.dtype()
+                )
+            })?;
 
-        let profile_name = if state.has_node_timer() {
-            Cow::Owned(format!(".filter({})", &self.predicate.as_ref()))
-        } else {
-            Cow::Borrowed("")
-        };
+            let profile_name = if state.has_node_timer() {
+                Cow::Owned(format!(".filter({})", &predicate.as_ref()))
+            } else {
+                Cow::Borrowed("")
+            };
 
-        state.record(
-            || {
-                let df = df.filter(mask)?;
-                if state.verbose() {
-                    eprintln!("dataframe filtered");
-                }
-                Ok(df)
-            },
-            profile_name,
-        )
+            df = state.record(
+                || {
+                    let df = df.filter(mask)?;
+                    if state.verbose() {
+                        eprintln!("dataframe filtered");
+                    }
+                    Ok(df)
+                },
+                profile_name,
+            )?;
+        }
+        Ok(df)
     }
 }
@@ -11,9 +11,11 @@ pub struct GroupByExec {
     maintain_order: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
+    first_last_aggs: Option<Vec<FirstLastAgg>>,
 }
 
 impl GroupByExec {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         input: Box<dyn Executor>,
         keys: Vec<Arc<dyn PhysicalExpr>>,
@@ -22,6 +24,7 @@ impl GroupByExec {
         maintain_order: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
+        first_last_aggs: Option<Vec<FirstLastAgg>>,
     ) -> Self {
         Self {
             input,
@@ -31,10 +34,88 @@ impl GroupByExec {
             maintain_order,
             input_schema,
             slice,
+            first_last_aggs,
         }
     }
 }
 
+/// A `first` or a `last` aggregation of a column.
+#[derive(Clone)]
+pub(crate) struct FirstLastAgg {
+    column: Arc<str>,
+    name: Arc<str>,
+    last: bool,
+}
+
+/// The aggregations if all of them are a `first` or a `last` of a column.
+///
+/// Those only need the first and the last index of every group, so the groupby
+/// doesn't have to collect the indices of all rows in a group.
+pub(crate) fn first_last_aggs(aggs: &[Expr]) -> Option<Vec<FirstLastAgg>> {
+    if aggs.is_empty() {
+        return None;
+    }
+    aggs.iter()
+        .map(|agg| {
+            let (agg, name) = match agg {
+                Expr::Alias(agg, name) => (agg.as_ref(), Some(name)),
+                agg => (agg, None),
+            };
+            let (input, last) = match agg {
+                Expr::Agg(AggExpr::First(input)) => (input, false),
+                Expr::Agg(AggExpr::Last(input)) => (input, true),
+                _ => return None,
+            };
+            match input.as_ref() {
+                Expr::Column(column) => Some(FirstLastAgg {
+                    column: column.clone(),
+                    name: name.unwrap_or(column).clone(),
+                    last,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Take the keys and the aggregated columns at the first and the last row of every group.
+fn groupby_first_last(
+    df: &DataFrame,
+    keys: &[Series],
+    aggs: &[FirstLastAgg],
+    maintain_order: bool,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let (mut first, mut last) = df._groupby_first_last_with_series(keys, maintain_order)?;
+    if let Some((offset, len)) = slice {
+        first = first.slice(offset, len);
+        last = last.slice(offset, len);
+    }
+    let (keys, aggs) = POOL.install(|| {
+        rayon::join(
+            || {
+                keys.par_iter()
+                    .map(|s| s.take(&first))
+                    .collect::<PolarsResult<Vec<_>>>()
+            },
+            || {
+                aggs.par_iter()
+                    .map(|agg| {
+                        let idx = if agg.last { &last } else { &first };
+                        let mut s = df.column(&agg.column)?.take(idx)?;
+                        s.rename(&agg.name);
+                        Ok(s)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            },
+        )
+    });
+    let mut columns = keys?;
+    columns.extend(aggs?);
+    DataFrame::new(columns)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(super) fn groupby_helper(
     mut df: DataFrame,
     keys: Vec<Series>,
@@ -43,8 +124,15 @@ pub(super) fn groupby_helper(
     state: &mut ExecutionState,
     maintain_order: bool,
     slice: Option<(i64, usize)>,
+    first_last_aggs: Option<&[FirstLastAgg]>,
 ) -> PolarsResult<DataFrame> {
     df.as_single_chunk_par();
+    if let (Some(first_last_aggs), None) = (first_last_aggs, &apply) {
+        // broadcasted keys are grouped by the default groupby
+        if keys.iter().all(|s| s.len() == df.height()) {
+            return groupby_first_last(&df, &keys, first_last_aggs, maintain_order, slice);
+        }
+    }
     let gb = df.groupby_with_series(keys, true, maintain_order)?;
 
     if let Some(f) = apply {
@@ -102,6 +190,7 @@ impl GroupByExec {
             state,
             self.maintain_order,
             self.slice,
+            self.first_last_aggs.as_deref(),
         )
     }
 }
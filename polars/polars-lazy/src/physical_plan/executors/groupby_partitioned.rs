@@ -287,6 +287,7 @@ impl PartitionGroupByExec {
                     state,
                     self.maintain_order,
                     self.slice,
+                    first_last_aggs(&self.aggs).as_deref(),
                 );
             }
 
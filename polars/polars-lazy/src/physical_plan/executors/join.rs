@@ -4,6 +4,8 @@ pub struct JoinExec {
     input_left: Option<Box<dyn Executor>>,
     input_right: Option<Box<dyn Executor>>,
     how: JoinType,
+    algorithm: JoinAlgorithm,
+    validate: JoinValidation,
     left_on: Vec<Arc<dyn PhysicalExpr>>,
     right_on: Vec<Arc<dyn PhysicalExpr>>,
     parallel: bool,
@@ -17,6 +19,8 @@ impl JoinExec {
         input_left: Box<dyn Executor>,
         input_right: Box<dyn Executor>,
         how: JoinType,
+        algorithm: JoinAlgorithm,
+        validate: JoinValidation,
         left_on: Vec<Arc<dyn PhysicalExpr>>,
         right_on: Vec<Arc<dyn PhysicalExpr>>,
         parallel: bool,
@@ -27,6 +31,8 @@ impl JoinExec {
             input_left: Some(input_left),
             input_right: Some(input_right),
             how,
+            algorithm,
+            validate,
             left_on,
             right_on,
             parallel,
@@ -153,6 +159,8 @@ impl Executor for JoinExec {
                 left_on_series,
                 right_on_series,
                 self.how.clone(),
+                self.algorithm,
+                self.validate,
                 Some(self.suffix.clone().into_owned()),
                 self.slice,
                 true,
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
 use polars_io::predicates::PhysicalIoExpr;
 
 use super::*;
@@ -9,10 +10,16 @@ pub struct CsvExec {
     pub schema: SchemaRef,
     pub options: CsvParserOptions,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub cloud_options: Option<CloudOptions>,
 }
 
 impl CsvExec {
     fn read(&mut self) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            self.options.follow.is_none(),
+            ComputeError: "following a CSV file requires the streaming engine, \
+            collect with streaming enabled"
+        );
         let mut with_columns = mem::take(&mut self.options.with_columns);
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
@@ -29,8 +36,7 @@ impl CsvExec {
             .clone()
             .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
-        CsvReader::from_path(&self.path)
-            .unwrap()
+        CsvReader::from_uri(&self.path, self.cloud_options.as_ref())?
             .has_header(self.options.has_header)
             .with_dtypes(Some(self.schema.clone()))
             .with_delimiter(self.options.delimiter)
@@ -49,6 +55,8 @@ impl CsvExec {
             .with_rechunk(self.options.rechunk)
             .with_row_count(std::mem::take(&mut self.options.row_count))
             .with_try_parse_dates(self.options.try_parse_dates)
+            .with_datetime_formats(self.options.datetime_formats.clone())
+            .with_number_format(self.options.number_format.clone())
             .finish()
     }
 }
@@ -0,0 +1,48 @@
+use polars_io::flight::FlightReader;
+
+use super::*;
+use crate::frame::LazyFlightReader;
+use crate::prelude::{AnonymousScan, AnonymousScanBatches, AnonymousScanOptions};
+
+impl LazyFlightReader {
+    fn reader(&self, scan_opts: AnonymousScanOptions) -> FlightReader {
+        FlightReader::new(self.endpoint.as_str(), self.descriptor.clone())
+            .with_projection(self.projection.clone())
+            .with_columns(
+                scan_opts
+                    .with_columns
+                    .map(|columns| columns.as_ref().clone()),
+            )
+            .with_n_rows(scan_opts.n_rows)
+    }
+}
+
+impl AnonymousScan for LazyFlightReader {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        self.reader(scan_opts).finish()
+    }
+
+    fn scan_batches(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<AnonymousScanBatches> {
+        Ok(Box::new(self.reader(scan_opts).batched()))
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
+        FlightReader::new(self.endpoint.as_str(), self.descriptor.clone()).schema()
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "csv")]
 mod csv;
+#[cfg(feature = "flight")]
+mod flight;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "json")]
@@ -34,14 +36,11 @@ type Predicate = Option<Arc<dyn PhysicalIoExpr>>;
 
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn prepare_scan_args(
-    path: &std::path::Path,
     predicate: &Option<Arc<dyn PhysicalExpr>>,
     with_columns: &mut Option<Arc<Vec<String>>>,
     schema: &mut SchemaRef,
     n_rows: Option<usize>,
-) -> (std::fs::File, Projection, StopNRows, Predicate) {
-    let file = std::fs::File::open(path).unwrap();
-
+) -> (Projection, StopNRows, Predicate) {
     let with_columns = mem::take(with_columns);
     let schema = mem::take(schema);
 
@@ -57,7 +56,7 @@ fn prepare_scan_args(
         .clone()
         .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
-    (file, projection, n_rows, predicate)
+    (projection, n_rows, predicate)
 }
 
 /// Producer of an in memory DataFrame
@@ -99,27 +98,58 @@ pub(crate) struct AnonymousScanExec {
     pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
 }
 
+impl AnonymousScanExec {
+    /// Select the projected columns, in case the scan returned all columns or reordered them.
+    fn project(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        match &self.options.output_schema {
+            Some(schema)
+                if !df
+                    .get_column_names()
+                    .into_iter()
+                    .eq(schema.iter_names().map(|name| name.as_str())) =>
+            {
+                df.select(schema.iter_names())
+            }
+            _ => Ok(df),
+        }
+    }
+}
+
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         state.record(
-            || match (self.function.allows_predicate_pushdown(), &self.predicate) {
-                (true, Some(predicate)) => {
-                    self.options.predicate = predicate.as_expression().cloned();
-                    self.function.scan(self.options.clone())
-                }
-                (false, Some(predicate)) => {
-                    let mut df = self.function.scan(self.options.clone())?;
-                    let s = predicate.evaluate(&df, state)?;
-                    let mask = s.bool().map_err(
-                        |_| polars_err!(ComputeError: "filter predicate was not of type boolean"),
-                    )?;
-                    df = df.filter(mask)?;
-
-                    Ok(df)
-                }
-                _ => self.function.scan(self.options.clone()),
+            || {
+                let df = match (self.function.allows_predicate_pushdown(), &self.predicate) {
+                    (true, Some(predicate)) => {
+                        self.options.predicate = predicate.as_expression().cloned();
+                        let df = self.function.scan(self.options.clone())?;
+                        if self.function.predicate_pushdown_is_exact() {
+                            df
+                        } else {
+                            filter_scanned(df, predicate.as_ref(), state)?
+                        }
+                    }
+                    (false, Some(predicate)) => {
+                        let df = self.function.scan(self.options.clone())?;
+                        filter_scanned(df, predicate.as_ref(), state)?
+                    }
+                    _ => self.function.scan(self.options.clone())?,
+                };
+                self.project(df)
             },
             "anonymous_scan".into(),
         )
     }
 }
+
+fn filter_scanned(
+    df: DataFrame,
+    predicate: &dyn PhysicalExpr,
+    state: &ExecutionState,
+) -> PolarsResult<DataFrame> {
+    let s = predicate.evaluate(&df, state)?;
+    let mask = s
+        .bool()
+        .map_err(|_| polars_err!(ComputeError: "filter predicate was not of type boolean"))?;
+    df.filter(mask)
+}
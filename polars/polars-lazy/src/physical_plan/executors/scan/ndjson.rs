@@ -1,13 +1,87 @@
+use std::io::Cursor;
+
 use polars_core::error::to_compute_err;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::follow::FileFollower;
 
 use super::*;
-use crate::prelude::{AnonymousScan, AnonymousScanOptions, LazyJsonLineReader};
+use crate::prelude::{
+    AnonymousScan, AnonymousScanBatches, AnonymousScanOptions, LazyJsonLineReader,
+};
+
+impl LazyJsonLineReader {
+    /// Read the lines that are appended to the followed file in batches. The rows are counted
+    /// as they are read, the row count is part of the schema of the scan.
+    fn follow_batches(
+        &self,
+        scan_opts: AnonymousScanOptions,
+    ) -> PolarsResult<AnonymousScanBatches> {
+        let mut schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+        let mut follow = self.follow.clone().unwrap();
+        follow.max_rows = match (follow.max_rows, scan_opts.n_rows) {
+            (Some(max_rows), Some(n_rows)) => Some(max_rows.min(n_rows)),
+            (max_rows, n_rows) => max_rows.or(n_rows),
+        };
+        let row_count = match &self.row_count {
+            Some(rc) if schema.contains(&rc.name) => {
+                let mut read_schema = schema.as_ref().clone();
+                read_schema.remove(&rc.name);
+                schema = Arc::new(read_schema);
+                Some(rc.clone())
+            }
+            _ => None,
+        };
+
+        let mut follower = FileFollower::new(&self.path, b'\n', 0, follow)?;
+        let batch_size = self.batch_size;
+        let low_memory = self.low_memory;
+        let mut n_read: IdxSize = 0;
+        let mut read_lines = move || -> PolarsResult<Option<DataFrame>> {
+            let Some(lines) = follower.next_lines()? else {
+                return Ok(None);
+            };
+            let mut df = JsonLineReader::new(Cursor::new(lines))
+                .with_schema(&schema)
+                .with_chunk_size(batch_size)
+                .low_memory(low_memory)
+                .finish()?;
+            if let Some(rc) = &row_count {
+                df = df.with_row_count(&rc.name, Some(rc.offset + n_read))?;
+            }
+            n_read += df.height() as IdxSize;
+            Ok(Some(df))
+        };
+        Ok(Box::new(std::iter::from_fn(move || {
+            read_lines().transpose()
+        })))
+    }
+}
 
 impl AnonymousScan for LazyJsonLineReader {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
     fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        if self.follow.is_some() {
+            let empty = DataFrame::from(
+                scan_opts
+                    .output_schema
+                    .as_ref()
+                    .unwrap_or(&scan_opts.schema)
+                    .as_ref(),
+            );
+            let dfs = self
+                .follow_batches(scan_opts)?
+                .collect::<PolarsResult<Vec<_>>>()?;
+            if dfs.is_empty() {
+                return Ok(empty);
+            }
+            let mut df = accumulate_dataframes_vertical(dfs)?;
+            if self.rechunk {
+                df.as_single_chunk_par();
+            }
+            return Ok(df);
+        }
         let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
         JsonLineReader::from_path(&self.path)?
             .with_schema(&schema)
@@ -32,4 +106,13 @@ impl AnonymousScan for LazyJsonLineReader {
     fn allows_projection_pushdown(&self) -> bool {
         true
     }
+
+    fn scan_batches(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<AnonymousScanBatches> {
+        self.follow_batches(scan_opts)
+    }
+
+    /// A followed file is streamed as its lines are appended.
+    fn allows_streaming(&self) -> bool {
+        self.follow.is_some()
+    }
 }
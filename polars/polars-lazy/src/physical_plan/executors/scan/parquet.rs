@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use polars_core::cloud::CloudOptions;
+use polars_io::is_cloud_url;
 
 use super::*;
 
@@ -8,38 +9,120 @@ use super::*;
 pub struct ParquetExec {
     path: PathBuf,
     schema: SchemaRef,
+    hive_parts: Option<Arc<HivePartitions>>,
+    /// Evaluated on the columns of the file while reading.
     predicate: Option<Arc<dyn PhysicalExpr>>,
+    /// Evaluated after the partition columns are added.
+    mixed_predicate: Option<Arc<dyn PhysicalExpr>>,
     options: ParquetOptions,
     cloud_options: Option<CloudOptions>,
 }
 
 impl ParquetExec {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         path: PathBuf,
         schema: SchemaRef,
+        hive_parts: Option<Arc<HivePartitions>>,
         predicate: Option<Arc<dyn PhysicalExpr>>,
+        mixed_predicate: Option<Arc<dyn PhysicalExpr>>,
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         ParquetExec {
             path,
             schema,
+            hive_parts,
             predicate,
+            mixed_predicate,
             options,
             cloud_options,
         }
     }
 
     fn read(&mut self) -> PolarsResult<DataFrame> {
-        let (file, projection, n_rows, predicate) = prepare_scan_args(
-            &self.path,
+        match self.hive_parts.clone() {
+            Some(hive_parts) => self.read_hive_partition(&hive_parts),
+            None => self.read_file(),
+        }
+    }
+
+    /// Read a file of a hive partitioned dataset and add the partition columns. Partitions that
+    /// are filtered out by the predicate are already pruned by the planner.
+    fn read_hive_partition(&mut self, hive_parts: &HivePartitions) -> PolarsResult<DataFrame> {
+        let with_columns = self.options.with_columns.clone();
+        let row_count_name = self.options.row_count.as_ref().map(|rc| rc.name.clone());
+
+        // only the columns of the file are read from it; the file needs at least one
+        // column to know the number of rows
+        let mut dummy_column = None;
+        if let Some(columns) = &with_columns {
+            let mut file_columns = columns
+                .iter()
+                .filter(|name| !hive_parts.contains(name))
+                .cloned()
+                .collect::<Vec<_>>();
+            if file_columns.is_empty() {
+                if let Some((name, _)) = self
+                    .schema
+                    .iter()
+                    .find(|(name, _)| !hive_parts.contains(name))
+                {
+                    file_columns.push(name.to_string());
+                    dummy_column = Some(name.to_string());
+                }
+            }
+            self.options.with_columns = Some(Arc::new(file_columns));
+        }
+
+        let mut df = self.read_file()?;
+        if let Some(name) = dummy_column {
+            df = df.drop(&name)?;
+        }
+        hive_parts.materialize(&mut df, with_columns.as_deref().map(|v| v.as_slice()))?;
+        if let Some(columns) = &with_columns {
+            df = df.select(row_count_name.iter().chain(columns.iter()))?;
+        }
+
+        if let Some(predicate) = &self.mixed_predicate {
+            let mask = predicate.evaluate(&df, &Default::default())?;
+            df = df.filter(mask.bool()?)?;
+        }
+        Ok(df)
+    }
+
+    fn read_file(&mut self) -> PolarsResult<DataFrame> {
+        let (projection, n_rows, predicate) = prepare_scan_args(
             &self.predicate,
             &mut self.options.with_columns,
             &mut self.schema,
             self.options.n_rows,
         );
 
+        if is_cloud_url(&self.path) {
+            #[cfg(not(feature = "async"))]
+            polars_bail!(
+                ComputeError:
+                "one or more of the cloud storage features ('aws', 'gcp', ...) must be enabled"
+            );
+
+            #[cfg(feature = "async")]
+            {
+                let uri = self.path.to_string_lossy();
+                return ParquetAsyncReader::from_uri(&uri, self.cloud_options.as_ref())?
+                    .with_n_rows(n_rows)
+                    .with_row_count(mem::take(&mut self.options.row_count))
+                    .with_projection(projection)
+                    .with_predicate(predicate)
+                    .set_rechunk(self.options.rechunk)
+                    .use_statistics(self.options.use_statistics)
+                    .finish();
+            }
+        }
+
+        let file = std::fs::File::open(&self.path)?;
         ParquetReader::new(file)
+            .with_metadata(polars_io::parquet::read_metadata_cached(&self.path)?)
             .with_n_rows(n_rows)
             .read_parallel(self.options.parallel)
             .with_row_count(mem::take(&mut self.options.row_count))
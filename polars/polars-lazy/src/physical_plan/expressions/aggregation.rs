@@ -96,7 +96,21 @@ impl PhysicalExpr for AggregationExpr {
                     let agg_s = ac.flat_naive().into_owned().agg_sum(ac.groups());
                     rename_series(agg_s, &keep_name)
                 }
-                GroupByMethod::Count => {
+                GroupByMethod::Count {
+                    include_nulls: false,
+                } => {
+                    check_null_prop!();
+                    let agg_s = ac
+                        .flat_naive()
+                        .into_owned()
+                        .agg_valid_count(ac.groups())
+                        // empty groups have no valid values
+                        .fill_null(FillNullStrategy::Zero)?;
+                    rename_series(agg_s, &keep_name)
+                }
+                GroupByMethod::Count {
+                    include_nulls: true,
+                } => {
                     // a few fast paths that prevent materializing new groups
                     match ac.update_groups {
                         UpdateGroups::WithSeriesLen => {
@@ -166,11 +180,35 @@ impl PhysicalExpr for AggregationExpr {
                     agg_s.rename(&keep_name);
                     agg_s
                 }
+                GroupByMethod::FirstNonNull { arg } => {
+                    check_null_prop!();
+                    let agg_s = ac
+                        .flat_naive()
+                        .into_owned()
+                        .agg_non_null(ac.groups(), false, arg);
+                    rename_series(agg_s, &keep_name)
+                }
+                GroupByMethod::LastNonNull { arg } => {
+                    check_null_prop!();
+                    let agg_s = ac
+                        .flat_naive()
+                        .into_owned()
+                        .agg_non_null(ac.groups(), true, arg);
+                    rename_series(agg_s, &keep_name)
+                }
                 GroupByMethod::NUnique => {
                     check_null_prop!();
                     let agg_s = ac.flat_naive().into_owned().agg_n_unique(ac.groups());
                     rename_series(agg_s, &keep_name)
                 }
+                GroupByMethod::ApproxNUnique => {
+                    check_null_prop!();
+                    let agg_s = ac
+                        .flat_naive()
+                        .into_owned()
+                        .agg_approx_n_unique(ac.groups())?;
+                    rename_series(agg_s, &keep_name)
+                }
                 GroupByMethod::Implode => {
                     if state.unset_finalize_window_as_list() {
                         let agg = ac.aggregated();
@@ -346,10 +384,21 @@ impl PartitionedAggregation for AggregationExpr {
                     agg.rename(series.name());
                     Ok(agg)
                 }
-                GroupByMethod::Count => {
-                    let mut ca = groups.group_count();
-                    ca.rename(series.name());
-                    Ok(ca.into_series())
+                GroupByMethod::ApproxNUnique => {
+                    // the sketches are merged in `finalize`
+                    let agg = series.agg_hll_sketches(groups)?;
+                    Ok(rename_series(agg, series.name()))
+                }
+                GroupByMethod::Count { include_nulls } => {
+                    let mut agg = if include_nulls {
+                        groups.group_count().into_series()
+                    } else {
+                        series
+                            .agg_valid_count(groups)
+                            .fill_null(FillNullStrategy::Zero)?
+                    };
+                    agg.rename(series.name());
+                    Ok(agg)
                 }
                 _ => {
                     unimplemented!()
@@ -365,7 +414,7 @@ impl PartitionedAggregation for AggregationExpr {
         _state: &ExecutionState,
     ) -> PolarsResult<Series> {
         match self.agg_type {
-            GroupByMethod::Count | GroupByMethod::Sum => {
+            GroupByMethod::Count { .. } | GroupByMethod::Sum => {
                 let mut agg = unsafe { partitioned.agg_sum(groups) };
                 agg.rename(partitioned.name());
                 Ok(agg)
@@ -475,6 +524,7 @@ impl PartitionedAggregation for AggregationExpr {
                 agg.rename(partitioned.name());
                 Ok(agg)
             }
+            GroupByMethod::ApproxNUnique => unsafe { partitioned.agg_merge_hll_sketches(groups) },
             _ => unimplemented!(),
         }
     }
@@ -551,3 +601,121 @@ impl PhysicalExpr for AggQuantileExpr {
         true
     }
 }
+
+/// The value of `input` at the minimum or maximum of `by`.
+pub struct AggMinMaxByExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) by: Arc<dyn PhysicalExpr>,
+    pub(crate) max: bool,
+    expr: Expr,
+}
+
+impl AggMinMaxByExpr {
+    pub fn new(
+        input: Arc<dyn PhysicalExpr>,
+        by: Arc<dyn PhysicalExpr>,
+        max: bool,
+        expr: Expr,
+    ) -> Self {
+        Self {
+            input,
+            by,
+            max,
+            expr,
+        }
+    }
+
+    /// # Safety
+    /// The groups must be in bounds of `by`.
+    unsafe fn arg_min_max(&self, by: &Series, groups: &GroupsProxy) -> PolarsResult<IdxCa> {
+        if self.max {
+            by.agg_arg_max(groups)
+        } else {
+            by.agg_arg_min(groups)
+        }
+    }
+
+    fn check_lengths(&self, input: &Series, by: &Series) -> PolarsResult<()> {
+        polars_ensure!(
+            input.len() == by.len(), expr = self.expr, ComputeError:
+            "the `by` argument must result in the same length as the input"
+        );
+        Ok(())
+    }
+
+    /// The value of `input` at the minimum or maximum of `by`, as a single value.
+    fn min_max_by(&self, input: &Series, by: &Series) -> PolarsResult<Series> {
+        self.check_lengths(input, by)?;
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, by.len() as IdxSize]],
+            rolling: false,
+        };
+        // safety: the group spans `by`
+        let idx = unsafe { self.arg_min_max(by, &groups)? };
+        input.take(&idx)
+    }
+}
+
+impl PhysicalExpr for AggMinMaxByExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        let by = self.by.evaluate(df, state)?;
+        self.min_max_by(&input, &by)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let mut ac_by = self.by.evaluate_on_groups(df, groups, state)?;
+        // don't change names by aggregations as is done in polars-core
+        let keep_name = ac.series().name().to_string();
+
+        let mut agg = if matches!(ac.update_groups, UpdateGroups::No)
+            && matches!(ac_by.update_groups, UpdateGroups::No)
+        {
+            // fast path: a single pass over the groups of `by`, which index the input as well
+            let input = ac.flat_naive().into_owned();
+            let by = ac_by.flat_naive().into_owned();
+            self.check_lengths(&input, &by)?;
+            // safety: the groups are in bounds
+            let idx = unsafe { self.arg_min_max(&by, ac_by.groups())? };
+            input.take(&idx)?
+        } else {
+            // the groups of the input and `by` may differ, so they are matched per group
+            let input = ac.aggregated();
+            let input = input.list()?;
+            let by = ac_by.aggregated();
+            let by = by.list()?;
+            let dtype = input.inner_dtype();
+
+            let mut out = Series::new_empty(&keep_name, &dtype);
+            for (opt_s, opt_by) in input.amortized_iter().zip(by.amortized_iter()) {
+                let value = match (opt_s, opt_by) {
+                    (Some(s), Some(by)) => self.min_max_by(s.as_ref(), by.as_ref())?,
+                    _ => Series::full_null(&keep_name, 1, &dtype),
+                };
+                out.append(&value)?;
+            }
+            out
+        };
+        agg.rename(&keep_name);
+        Ok(AggregationContext::new(agg, Cow::Borrowed(groups), true))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.input.to_field(input_schema)
+    }
+
+    fn is_valid_aggregation(&self) -> bool {
+        true
+    }
+}
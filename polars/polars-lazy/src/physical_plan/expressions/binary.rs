@@ -38,9 +38,11 @@ fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResu
         Operator::Lt => ChunkCompare::<&Series>::lt(&left, &right).map(|ca| ca.into_series()),
         Operator::LtEq => ChunkCompare::<&Series>::lt_eq(&left, &right).map(|ca| ca.into_series()),
         Operator::Eq => ChunkCompare::<&Series>::equal(&left, &right).map(|ca| ca.into_series()),
+        Operator::EqValidity => left.equal_missing(&right).map(|ca| ca.into_series()),
         Operator::NotEq => {
             ChunkCompare::<&Series>::not_equal(&left, &right).map(|ca| ca.into_series())
         }
+        Operator::NotEqValidity => left.not_equal_missing(&right).map(|ca| ca.into_series()),
         Operator::Plus => Ok(left + right),
         Operator::Minus => Ok(left - right),
         Operator::Multiply => Ok(left * right),
@@ -62,9 +64,11 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
         Operator::Lt => ChunkCompare::<&Series>::lt(left, right).map(|ca| ca.into_series()),
         Operator::LtEq => ChunkCompare::<&Series>::lt_eq(left, right).map(|ca| ca.into_series()),
         Operator::Eq => ChunkCompare::<&Series>::equal(left, right).map(|ca| ca.into_series()),
+        Operator::EqValidity => left.equal_missing(right).map(|ca| ca.into_series()),
         Operator::NotEq => {
             ChunkCompare::<&Series>::not_equal(left, right).map(|ca| ca.into_series())
         }
+        Operator::NotEqValidity => left.not_equal_missing(right).map(|ca| ca.into_series()),
         Operator::Plus => Ok(left + right),
         Operator::Minus => Ok(left - right),
         Operator::Multiply => Ok(left * right),
@@ -406,26 +410,32 @@ mod stats {
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
                     let l = stats.get_stats(fld_l.name())?;
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
                     match l.to_min_max() {
-                        None => Ok(true),
-                        Some(min_max_s) => {
+                        Some(min_max_s)
+                            if !apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op) =>
+                        {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            Ok(false)
                         }
+                        // the bloom filter can only rule out equality
+                        _ => Ok(self.op != Eq || l.may_contain(&lit_s)),
                     }
                 }
                 (true, false) => {
                     let r = stats.get_stats(fld_r.name())?;
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
                     match r.to_min_max() {
-                        None => Ok(true),
-                        Some(min_max_s) => {
+                        Some(min_max_s)
+                            if !apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op) =>
+                        {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            Ok(false)
                         }
+                        // the bloom filter can only rule out equality
+                        _ => Ok(self.op != Eq || r.may_contain(&lit_s)),
                     }
                 }
                 // default: read the file
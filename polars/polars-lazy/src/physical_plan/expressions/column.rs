@@ -160,6 +160,17 @@ impl PhysicalExpr for ColumnExpr {
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
         let s = self.evaluate(df, state)?;
+        // columns of external contexts are aligned with the rows of `df` by index;
+        // a unit column is broadcasted
+        let s = match s.len() {
+            len if len == df.height() => s,
+            1 => s.new_from_index(0, df.height()),
+            len => polars_bail!(
+                ComputeError:
+                "column {:?} of length {} cannot be aligned with the {} rows of the frame",
+                self.name, len, df.height()
+            ),
+        };
         Ok(AggregationContext::new(s, Cow::Borrowed(groups), false))
     }
 
@@ -556,6 +556,10 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         self.expr.evaluate(df, &Default::default())
     }
 
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        self.expr.as_expression().map(expr_to_leaf_column_names)
+    }
+
     #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
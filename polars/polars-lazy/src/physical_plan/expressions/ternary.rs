@@ -34,10 +34,13 @@ impl TernaryExpr {
 fn expand_lengths(truthy: &mut Series, falsy: &mut Series, mask: &mut BooleanChunked) {
     let len = std::cmp::max(std::cmp::max(truthy.len(), falsy.len()), mask.len());
     if len > 1 {
-        if falsy.len() == 1 {
+        // `zip_with` broadcasts scalar branches of primitive dtypes, as long as
+        // the mask has the full length.
+        let broadcast = mask.len() == len && truthy.dtype().is_primitive();
+        if falsy.len() == 1 && !broadcast {
             *falsy = falsy.new_from_index(0, len);
         }
-        if truthy.len() == 1 {
+        if truthy.len() == 1 && !broadcast {
             *truthy = truthy.new_from_index(0, len);
         }
         if mask.len() == 1 {
@@ -46,6 +49,21 @@ fn expand_lengths(truthy: &mut Series, falsy: &mut Series, mask: &mut BooleanChu
     }
 }
 
+/// A scalar mask selects one of the branches as a whole.
+fn select_branch(truthy: Series, falsy: Series, mask: &BooleanChunked) -> Series {
+    let len = std::cmp::max(truthy.len(), falsy.len());
+    let name = truthy.name().to_string();
+    let mut out = match mask.get(0) {
+        Some(true) => truthy,
+        _ => falsy,
+    };
+    if out.len() != len {
+        out = out.new_from_index(0, len);
+    }
+    out.rename(&name);
+    out
+}
+
 fn finish_as_iters<'a>(
     mut ac_truthy: AggregationContext<'a>,
     mut ac_falsy: AggregationContext<'a>,
@@ -112,6 +130,9 @@ impl PhysicalExpr for TernaryExpr {
         if mask.is_empty() {
             return Ok(Series::new_empty(truthy.name(), truthy.dtype()));
         }
+        if mask.len() == 1 && truthy.dtype() == falsy.dtype() {
+            return Ok(select_branch(truthy, falsy, &mask));
+        }
 
         expand_lengths(&mut truthy, &mut falsy, &mut mask);
 
@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use polars_arrow::export::arrow::array::PrimitiveArray;
 use polars_core::export::arrow::bitmap::Bitmap;
-use polars_core::frame::groupby::{GroupBy, GroupsProxy};
+use polars_core::frame::groupby::{GroupBy, GroupsIdx, GroupsProxy};
 use polars_core::frame::hash_join::{
     default_join_ids, private_left_join_multiple_keys, JoinOptIds,
 };
@@ -24,6 +24,8 @@ pub struct WindowExpr {
     /// the root column that the Function will be applied on.
     /// This will be used to create a smaller DataFrame to prevent taking unneeded columns by index
     pub(crate) group_by: Vec<Arc<dyn PhysicalExpr>>,
+    /// Orders the rows within a partition when evaluating over a window frame.
+    pub(crate) order_by: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) apply_columns: Vec<Arc<str>>,
     pub(crate) out_name: Option<Arc<str>>,
     /// A function Expr. i.e. Mean, Median, Max, etc.
@@ -44,6 +46,8 @@ enum MapStrategy {
     ExplodeLater,
     // Use an arg_sort to map the values back
     Map,
+    // Give every row the aggregated list of its group
+    GroupsToList,
     Nothing,
 }
 
@@ -197,6 +201,140 @@ impl WindowExpr {
         self.map_list_agg_by_arg_sort(out_column, flattened, ac, gb, state, cache_key)
     }
 
+    /// Evaluate the function on a group per row, holding the rows of the frame around that
+    /// row. The rows are gathered partition by partition in their order, so that every frame
+    /// is a slice of them, and the output is mapped back to the order of the rows.
+    fn evaluate_frame(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        frame: WindowFrame,
+    ) -> PolarsResult<Series> {
+        let height = df.height();
+        let groupby_columns = self
+            .group_by
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let partitions = if groupby_columns.is_empty() {
+            let all = (0..height as IdxSize).collect::<Vec<_>>();
+            GroupsIdx::new(vec![0], vec![all], true)
+        } else {
+            df.groupby_with_series(groupby_columns, true, false)?
+                .take_groups()
+                .into_idx()
+        };
+
+        let order_by = match &self.order_by {
+            Some(e) => Some(e.evaluate(df, state)?),
+            None => None,
+        };
+        // For range frames we need the values of the ordering column.
+        let range_values = match (frame, &order_by) {
+            (WindowFrame::Range { .. }, Some(s)) => {
+                polars_ensure!(
+                    s.dtype().to_physical().is_integer(),
+                    InvalidOperation: "'range_between' needs an integer or temporal ordering column, got {}", s.dtype()
+                );
+                polars_ensure!(
+                    s.null_count() == 0,
+                    InvalidOperation: "the ordering column of 'range_between' may not contain nulls"
+                );
+                let s = s.to_physical_repr().cast(&DataType::Int64)?.rechunk();
+                Some(s.i64()?.cont_slice()?.to_vec())
+            }
+            (WindowFrame::Range { .. }, None) => {
+                polars_bail!(ComputeError: "'range_between' needs an ordering column")
+            }
+            _ => None,
+        };
+        // Position of every row in the ordering column, so that we can sort the partitions.
+        let ranks = order_by.map(|s| {
+            let mut ranks = vec![0 as IdxSize; height];
+            let sorted_idx = s.arg_sort(SortOptions {
+                nulls_last: true,
+                ..Default::default()
+            });
+            for (rank, idx) in sorted_idx.into_no_null_iter().enumerate() {
+                ranks[idx as usize] = rank as IdxSize;
+            }
+            ranks
+        });
+
+        let (start, end) = match frame {
+            WindowFrame::Rows { start, end } | WindowFrame::Range { start, end } => (start, end),
+        };
+        polars_ensure!(
+            start <= end,
+            ComputeError: "the start of a window frame ({}) may not be larger than its end ({})", start, end
+        );
+
+        // the rows in the order of the partitions and the frames as slices of those
+        let mut sorted_rows = Vec::with_capacity(height);
+        let mut frames = Vec::with_capacity(height);
+        for mut partition in partitions.all().iter().cloned() {
+            if let Some(ranks) = &ranks {
+                partition.sort_unstable_by_key(|&idx| ranks[idx as usize]);
+            }
+            let len = partition.len() as i64;
+            let offset = sorted_rows.len();
+            for (pos, &row) in partition.iter().enumerate() {
+                let (lower, upper) = match &range_values {
+                    Some(values) => {
+                        let value = values[row as usize];
+                        let lower_value = value.saturating_add(start);
+                        let upper_value = value.saturating_add(end);
+                        let lower =
+                            partition.partition_point(|&idx| values[idx as usize] < lower_value);
+                        let upper =
+                            partition.partition_point(|&idx| values[idx as usize] <= upper_value);
+                        (lower, upper)
+                    }
+                    None => {
+                        let pos = pos as i64;
+                        let lower = pos.saturating_add(start).clamp(0, len);
+                        let upper = pos.saturating_add(end).saturating_add(1).clamp(0, len);
+                        (lower as usize, upper as usize)
+                    }
+                };
+                let frame_len = upper.saturating_sub(lower);
+                frames.push([(offset + lower) as IdxSize, frame_len as IdxSize]);
+            }
+            sorted_rows.extend_from_slice(&partition);
+        }
+        // the frames overlap, so the aggregations may use rolling kernels
+        let groups = GroupsProxy::Slice {
+            groups: frames,
+            rolling: true,
+        };
+
+        let in_order = sorted_rows
+            .iter()
+            .enumerate()
+            .all(|(i, &row)| row as usize == i);
+        let mut out = if in_order {
+            self.phys_function
+                .evaluate_on_groups(df, &groups, state)?
+                .aggregated()
+        } else {
+            // safety: the rows are in bounds
+            let sorted_df = unsafe { df._take_unchecked_slice(&sorted_rows, true) };
+            let out = self
+                .phys_function
+                .evaluate_on_groups(&sorted_df, &groups, state)?
+                .aggregated();
+            let mut positions = vec![0 as IdxSize; height];
+            for (i, &row) in sorted_rows.iter().enumerate() {
+                positions[row as usize] = i as IdxSize;
+            }
+            out.take(&IdxCa::from_vec("", positions))?
+        };
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_ref());
+        }
+        Ok(out)
+    }
+
     fn run_aggregation<'a>(
         &self,
         df: &DataFrame,
@@ -264,6 +402,7 @@ impl WindowExpr {
     fn is_aggregation(&self) -> bool {
         // col()
         // or col().agg()
+        // or col().null_count()
         let mut agg_col = false;
         for e in &self.expr {
             if let Expr::Window { function, .. } = e {
@@ -273,6 +412,9 @@ impl WindowExpr {
                         Expr::Agg(_) => {
                             agg_col = true;
                         }
+                        e if is_reducing_function(e) => {
+                            agg_col = true;
+                        }
                         Expr::Alias(_, _) => {}
                         _ => break,
                     }
@@ -301,13 +443,8 @@ impl WindowExpr {
                         Expr::Agg(_) => {
                             agg_col = true;
                         }
-                        Expr::Function { options, .. }
-                        | Expr::AnonymousFunction { options, .. } => {
-                            if options.auto_explode
-                                && matches!(options.collect_groups, ApplyOptions::ApplyGroups)
-                            {
-                                agg_col = true;
-                            }
+                        e if is_reducing_function(e) => {
+                            agg_col = true;
                         }
                         _ => {}
                     }
@@ -407,6 +544,10 @@ impl PhysicalExpr for WindowExpr {
         //          This can be used to reverse, sort, shuffle etc. the values in a group
 
         // 4. select the final column and return
+        if let Some(frame) = self.options.frame {
+            return self.evaluate_frame(df, state, frame);
+        }
+
         let groupby_columns = self
             .group_by
             .iter()
@@ -433,7 +574,8 @@ impl PhysicalExpr for WindowExpr {
         }
 
         // if we flatten this column we need to make sure the groups are sorted.
-        let mut sort_groups = self.options.explode ||
+        let mut sort_groups = self.options.explode
+            || matches!(self.options.mapping, WindowMapping::Explode) ||
             // if not
             //      `col().over()`
             // and not
@@ -451,6 +593,13 @@ impl PhysicalExpr for WindowExpr {
         }
 
         let create_groups = || {
+            // sorted numeric keys already take a fast path in the groupby
+            if sorted_keys
+                && (groupby_columns.len() > 1
+                    || !groupby_columns[0].dtype().to_physical().is_numeric())
+            {
+                return groups_from_sorted_keys(&groupby_columns);
+            }
             let gb = df.groupby_with_series(groupby_columns.clone(), true, sort_groups)?;
             let out: PolarsResult<GroupsProxy> = Ok(gb.take_groups());
             out
@@ -498,7 +647,14 @@ impl PhysicalExpr for WindowExpr {
         let mut ac = self.run_aggregation(df, state, &gb)?;
 
         use MapStrategy::*;
-        match self.determine_map_strategy(ac.agg_state(), sorted_keys, explicit_list_agg, &gb)? {
+        let map_strategy = match self.options.mapping {
+            WindowMapping::GroupsToRows => {
+                self.determine_map_strategy(ac.agg_state(), sorted_keys, explicit_list_agg, &gb)?
+            }
+            WindowMapping::Explode => Explode,
+            WindowMapping::Join => GroupsToList,
+        };
+        match map_strategy {
             Nothing => {
                 let mut out = ac.flat_naive().into_owned();
                 cache_gb(gb, state, &cache_key);
@@ -508,7 +664,12 @@ impl PhysicalExpr for WindowExpr {
                 Ok(out)
             }
             Explode => {
-                let mut out = ac.aggregated().explode()?;
+                let out = ac.aggregated();
+                let mut out = match out.dtype() {
+                    DataType::List(_) => out.explode()?,
+                    // reducing aggregations have a single value per group
+                    _ => out,
+                };
                 cache_gb(gb, state, &cache_key);
                 if let Some(name) = &self.out_name {
                     out.rename(name.as_ref());
@@ -523,6 +684,19 @@ impl PhysicalExpr for WindowExpr {
                 }
                 Ok(out)
             }
+            GroupsToList => {
+                let out_column = ac.aggregated();
+                let out_column = match out_column.dtype() {
+                    DataType::List(_) => out_column,
+                    _ => out_column.as_list().into_series(),
+                };
+                let mut out = map_by_group_idx(&out_column, gb.get_groups(), df.height());
+                cache_gb(gb, state, &cache_key);
+                if let Some(name) = &self.out_name {
+                    out.rename(name.as_ref());
+                }
+                Ok(out)
+            }
             Map => {
                 // TODO!
                 // investigate if sorted arrays can be return directly
@@ -560,6 +734,16 @@ impl PhysicalExpr for WindowExpr {
                         cache_gb(gb, state, &cache_key);
                         Ok(out)
                     }
+                    // the groups are contiguous slices, so mapping by group index is
+                    // cheaper than joining on the keys
+                    (_, _) if sorted_keys => {
+                        let mut out = map_by_group_idx(&out_column, gb.get_groups(), df.height());
+                        cache_gb(gb, state, &cache_key);
+                        if let Some(name) = &self.out_name {
+                            out.rename(name.as_ref());
+                        }
+                        Ok(out)
+                    }
                     (_, _) => {
                         let keys = gb.keys();
                         cache_gb(gb, state, &cache_key);
@@ -568,7 +752,11 @@ impl PhysicalExpr for WindowExpr {
                             if groupby_columns.len() == 1 {
                                 // group key from right column
                                 let right = &keys[0];
-                                groupby_columns[0].hash_join_left(right).1
+                                // a join that isn't validated can't fail
+                                groupby_columns[0]
+                                    .hash_join_left(right, JoinValidation::ManyToMany)
+                                    .unwrap()
+                                    .1
                             } else {
                                 let df_right = DataFrame::new_no_checks(keys);
                                 let df_left = DataFrame::new_no_checks(groupby_columns);
@@ -655,6 +843,62 @@ fn materialize_column(join_opt_ids: &JoinOptIds, out_column: &Series) -> Series
     }
 }
 
+/// Create the groups of keys that are all sorted by detecting the runs of equal keys.
+fn groups_from_sorted_keys(keys: &[Series]) -> PolarsResult<GroupsProxy> {
+    let len = keys[0].len();
+    if len == 0 {
+        return Ok(GroupsProxy::Slice {
+            groups: vec![],
+            rolling: false,
+        });
+    }
+    // `true` if row `i + 1` starts a new group
+    let mut boundaries = BooleanChunked::full("", false, len - 1);
+    for s in keys {
+        let differs = s
+            .slice(1, len - 1)
+            .not_equal_missing(&s.slice(0, len - 1))?;
+        boundaries = &boundaries | &differs;
+    }
+
+    let mut groups = Vec::new();
+    let mut start = 0 as IdxSize;
+    for (i, new_group) in boundaries.into_no_null_iter().enumerate() {
+        if new_group {
+            let end = i as IdxSize + 1;
+            groups.push([start, end - start]);
+            start = end;
+        }
+    }
+    groups.push([start, len as IdxSize - start]);
+    Ok(GroupsProxy::Slice {
+        groups,
+        rolling: false,
+    })
+}
+
+/// Give every row the aggregated value of its group by taking the values by group index.
+fn map_by_group_idx(out_column: &Series, groups: &GroupsProxy, len: usize) -> Series {
+    let mut group_idx = vec![0 as IdxSize; len];
+    match groups {
+        GroupsProxy::Idx(groups) => {
+            for (i, g) in groups.all().iter().enumerate() {
+                for &idx in g {
+                    group_idx[idx as usize] = i as IdxSize;
+                }
+            }
+        }
+        GroupsProxy::Slice { groups, .. } => {
+            for (i, &[first, g_len]) in groups.iter().enumerate() {
+                group_idx[first as usize..(first + g_len) as usize].fill(i as IdxSize);
+            }
+        }
+    }
+    let group_idx = IdxCa::from_vec("", group_idx);
+    // Safety: every group has an aggregated value.
+    unsafe { out_column.take_unchecked(&group_idx).unwrap() }
+}
+
 fn cache_gb(gb: GroupBy, state: &ExecutionState, cache_key: &str) {
     if state.cache_window() {
         let groups = gb.take_groups();
@@ -663,6 +907,17 @@ fn cache_gb(gb: GroupBy, state: &ExecutionState, cache_key: &str) {
     }
 }
 
+/// Functions that reduce every group to a single value, like `null_count` and `entropy`.
+/// In a window they are mapped back to the rows like an aggregation.
+fn is_reducing_function(e: &Expr) -> bool {
+    match e {
+        Expr::Function { options, .. } | Expr::AnonymousFunction { options, .. } => {
+            options.auto_explode && matches!(options.collect_groups, ApplyOptions::ApplyGroups)
+        }
+        _ => false,
+    }
+}
+
 /// Simple reducing aggregation can be set by the groups
 fn set_by_groups(
     s: &Series,
@@ -1,63 +1,130 @@
-use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
 
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
+use polars_utils::sys::MEMINFO;
 
 type StartInstant = Instant;
 type EndInstant = Instant;
 
-type Nodes = Vec<String>;
-type Ticks = Vec<(StartInstant, EndInstant)>;
+/// The interval at which the memory of the process is sampled while profiling.
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_millis(2);
+
+struct NodeRecord {
+    name: String,
+    start: StartInstant,
+    end: EndInstant,
+    rows: Option<usize>,
+    peak_memory: u64,
+}
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
-    data: Arc<Mutex<(Nodes, Ticks)>>,
+    data: Arc<Mutex<Vec<NodeRecord>>>,
+    // the resident memory of the process, sampled by a background thread
+    memory_samples: Arc<Mutex<Vec<(Instant, u64)>>>,
 }
 
 impl NodeTimer {
     pub(super) fn new() -> Self {
+        let memory_samples = Arc::new(Mutex::new(Vec::with_capacity(1024)));
+        // the sampler stops once the timer is dropped
+        let samples: Weak<Mutex<Vec<(Instant, u64)>>> = Arc::downgrade(&memory_samples);
+        std::thread::spawn(move || {
+            while let Some(samples) = samples.upgrade() {
+                let memory = MEMINFO.process_memory();
+                samples.lock().unwrap().push((Instant::now(), memory));
+                drop(samples);
+                std::thread::sleep(MEMORY_SAMPLE_INTERVAL);
+            }
+        });
         Self {
             query_start: Instant::now(),
-            data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            data: Arc::new(Mutex::new(Vec::with_capacity(16))),
+            memory_samples,
         }
     }
 
-    pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
-        let mut data = self.data.lock().unwrap();
-        let nodes = &mut data.0;
-        nodes.push(name);
-        let ticks = &mut data.1;
-        ticks.push((start, end))
+    pub(super) fn sample_memory(&self) -> u64 {
+        MEMINFO.process_memory()
     }
 
-    pub(super) fn finish(self) -> PolarsResult<DataFrame> {
-        let mut data = self.data.lock().unwrap();
-        let mut nodes = std::mem::take(&mut data.0);
-        nodes.push("optimization".to_string());
+    /// Store the timings of a node. `memory` is the largest memory sampled at the start and end
+    /// of the node, it is combined with the background samples when the timer is finished.
+    pub(super) fn store(
+        &self,
+        start: StartInstant,
+        end: EndInstant,
+        name: String,
+        rows: Option<usize>,
+        memory: u64,
+    ) {
+        self.data.lock().unwrap().push(NodeRecord {
+            name,
+            start,
+            end,
+            rows,
+            peak_memory: memory,
+        })
+    }
 
-        let mut ticks = std::mem::take(&mut data.1);
+    pub(super) fn finish(self) -> PolarsResult<DataFrame> {
+        let mut records = std::mem::take(&mut *self.data.lock().unwrap());
         // first value is end of optimization
-        polars_ensure!(!ticks.is_empty(), ComputeError: "no data to time");
-        let start = ticks[0].0;
-        ticks.push((self.query_start, start));
-        let nodes_s = Series::new("node", nodes);
-        let start: NoNull<UInt64Chunked> = ticks
+        polars_ensure!(!records.is_empty(), ComputeError: "no data to time");
+        let start = records[0].start;
+        records.push(NodeRecord {
+            name: "optimization".to_string(),
+            start: self.query_start,
+            end: start,
+            rows: None,
+            peak_memory: 0,
+        });
+
+        let samples = self.memory_samples.lock().unwrap();
+        for record in records.iter_mut() {
+            let sampled = samples
+                .iter()
+                .filter(|(t, _)| *t >= record.start && *t <= record.end)
+                .map(|(_, memory)| *memory);
+            record.peak_memory = sampled.fold(record.peak_memory, std::cmp::max);
+        }
+
+        let nodes_s = Series::new(
+            "node",
+            records.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+        );
+        let start: NoNull<UInt64Chunked> = records
             .iter()
-            .map(|(start, _)| (start.duration_since(self.query_start)).as_micros() as u64)
+            .map(|r| (r.start.duration_since(self.query_start)).as_micros() as u64)
             .collect();
         let mut start = start.into_inner();
         start.rename("start");
 
-        let end: NoNull<UInt64Chunked> = ticks
+        let end: NoNull<UInt64Chunked> = records
             .iter()
-            .map(|(_, end)| (end.duration_since(self.query_start)).as_micros() as u64)
+            .map(|r| (r.end.duration_since(self.query_start)).as_micros() as u64)
             .collect();
         let mut end = end.into_inner();
         end.rename("end");
 
-        DataFrame::new_no_checks(vec![nodes_s, start.into_series(), end.into_series()])
-            .sort(vec!["start"], vec![false])
+        let mut rows: UInt64Chunked = records.iter().map(|r| r.rows.map(|n| n as u64)).collect();
+        rows.rename("rows");
+        let mut peak_memory: UInt64Chunked = records
+            .iter()
+            .map(|r| (r.peak_memory > 0).then_some(r.peak_memory))
+            .collect();
+        peak_memory.rename("peak_memory");
+
+        DataFrame::new_no_checks(vec![
+            nodes_s,
+            start.into_series(),
+            end.into_series(),
+            rows.into_series(),
+            peak_memory.into_series(),
+        ])
+        .sort(vec!["start"], vec![false])
     }
 }
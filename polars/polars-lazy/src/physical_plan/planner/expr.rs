@@ -31,12 +31,14 @@ pub(crate) fn create_physical_expr(
         Window {
             mut function,
             partition_by,
-            order_by: _,
+            order_by,
             options,
         } => {
-            // TODO! Order by
             let group_by =
                 create_physical_expressions(&partition_by, Context::Default, expr_arena, schema)?;
+            let order_by = order_by
+                .map(|node| create_physical_expr(node, Context::Default, expr_arena, schema))
+                .transpose()?;
             let phys_function =
                 create_physical_expr(function, Context::Aggregation, expr_arena, schema)?;
             let mut out_name = None;
@@ -68,6 +70,7 @@ pub(crate) fn create_physical_expr(
 
             Ok(Arc::new(WindowExpr {
                 group_by,
+                order_by,
                 apply_columns,
                 out_name,
                 function,
@@ -337,6 +340,17 @@ pub(crate) fn create_physical_expr(
                         }
                     }
                 }
+                AAggExpr::MinBy { input, by } | AAggExpr::MaxBy { input, by } => {
+                    let max = matches!(agg, AAggExpr::MaxBy { .. });
+                    let input = create_physical_expr(input, ctxt, expr_arena, schema)?;
+                    let by = create_physical_expr(by, ctxt, expr_arena, schema)?;
+                    Ok(Arc::new(AggMinMaxByExpr::new(
+                        input,
+                        by,
+                        max,
+                        node_to_expr(expression, expr_arena),
+                    )))
+                }
                 AAggExpr::Median(expr) => {
                     let input = create_physical_expr(expr, ctxt, expr_arena, schema)?;
                     match ctxt {
@@ -400,6 +414,38 @@ pub(crate) fn create_physical_expr(
                         }
                     }
                 }
+                AAggExpr::FirstNonNull(expr, arg) | AAggExpr::LastNonNull(expr, arg) => {
+                    let last = matches!(agg, AAggExpr::LastNonNull(..));
+                    let input = create_physical_expr(expr, ctxt, expr_arena, schema)?;
+                    match ctxt {
+                        Context::Aggregation => {
+                            let method = if last {
+                                GroupByMethod::LastNonNull { arg }
+                            } else {
+                                GroupByMethod::FirstNonNull { arg }
+                            };
+                            Ok(Arc::new(AggregationExpr::new(input, method)))
+                        }
+                        Context::Default => {
+                            let function = SpecialEq::new(Arc::new(move |s: &mut [Series]| {
+                                let s = std::mem::take(&mut s[0]);
+                                // the whole series is a single group
+                                let groups = GroupsProxy::Slice {
+                                    groups: vec![[0, s.len() as IdxSize]],
+                                    rolling: false,
+                                };
+                                Ok(Some(unsafe { s.agg_non_null(&groups, last, arg) }))
+                            })
+                                as Arc<dyn SeriesUdf>);
+                            Ok(Arc::new(ApplyExpr::new_minimal(
+                                vec![input],
+                                function,
+                                node_to_expr(expression, expr_arena),
+                                ApplyOptions::ApplyFlat,
+                            )))
+                        }
+                    }
+                }
                 AAggExpr::Implode(expr) => {
                     let input = create_physical_expr(expr, ctxt, expr_arena, schema)?;
                     match ctxt {
@@ -449,6 +495,33 @@ pub(crate) fn create_physical_expr(
                         }
                     }
                 }
+                AAggExpr::ApproxNUnique(expr) => {
+                    let input = create_physical_expr(expr, ctxt, expr_arena, schema)?;
+                    match ctxt {
+                        Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                            input,
+                            GroupByMethod::ApproxNUnique,
+                        ))),
+                        Context::Default => {
+                            let function = SpecialEq::new(Arc::new(move |s: &mut [Series]| {
+                                let s = std::mem::take(&mut s[0]);
+                                s.approx_n_unique().map(|count| {
+                                    Some(
+                                        IdxCa::from_slice(s.name(), &[count as IdxSize])
+                                            .into_series(),
+                                    )
+                                })
+                            })
+                                as Arc<dyn SeriesUdf>);
+                            Ok(Arc::new(ApplyExpr::new_minimal(
+                                vec![input],
+                                function,
+                                node_to_expr(expression, expr_arena),
+                                ApplyOptions::ApplyFlat,
+                            )))
+                        }
+                    }
+                }
                 AAggExpr::Quantile {
                     expr,
                     quantile,
@@ -489,19 +562,23 @@ pub(crate) fn create_physical_expr(
                         GroupByMethod::Groups,
                     )))
                 }
-                AAggExpr::Count(expr) => {
+                AAggExpr::Count(expr, include_nulls) => {
                     let input = create_physical_expr(expr, ctxt, expr_arena, schema)?;
                     match ctxt {
-                        Context::Aggregation => {
-                            Ok(Arc::new(AggregationExpr::new(input, GroupByMethod::Count)))
-                        }
+                        Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                            input,
+                            GroupByMethod::Count { include_nulls },
+                        ))),
                         Context::Default => {
                             let function = SpecialEq::new(Arc::new(move |s: &mut [Series]| {
                                 let s = std::mem::take(&mut s[0]);
-                                let count = s.len();
+                                let count = if include_nulls {
+                                    s.len()
+                                } else {
+                                    s.len() - s.null_count()
+                                };
                                 Ok(Some(
-                                    UInt32Chunked::from_slice(s.name(), &[count as u32])
-                                        .into_series(),
+                                    IdxCa::from_slice(s.name(), &[count as IdxSize]).into_series(),
                                 ))
                             })
                                 as Arc<dyn SeriesUdf>);
@@ -1,9 +1,51 @@
+use polars_core::config::{get_agg_dtype_policy, MeanDtype};
 use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::predicates::{read_this_file, BatchStats};
 
 use super::super::executors::{self, Executor};
 use super::*;
+#[cfg(feature = "parquet")]
+use crate::physical_plan::expressions::PhysicalIoHelper;
 use crate::utils::*;
 
+/// Split the predicate of a scan of a hive partition into the conjunctions that only
+/// refer to partition columns, those that only refer to columns of the file and those
+/// that refer to both.
+#[cfg(feature = "parquet")]
+fn split_hive_predicate(
+    predicate: Node,
+    hive_parts: &HivePartitions,
+    expr_arena: &mut Arena<AExpr>,
+) -> [Option<Node>; 3] {
+    let mut parts: [Vec<Node>; 3] = Default::default();
+    for node in split_conjunctions(predicate, expr_arena) {
+        let (n_hive, n_file) =
+            aexpr_to_leaf_names_iter(node, expr_arena).fold((0, 0), |(n_hive, n_file), name| {
+                if hive_parts.contains(&name) {
+                    (n_hive + 1, n_file)
+                } else {
+                    (n_hive, n_file + 1)
+                }
+            });
+        let idx = match (n_hive, n_file) {
+            (0, _) => 1,
+            (_, 0) => 0,
+            _ => 2,
+        };
+        parts[idx].push(node);
+    }
+    parts.map(|nodes| {
+        nodes.into_iter().reduce(|left, right| {
+            expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            })
+        })
+    })
+}
+
 fn partitionable_gb(
     keys: &[Node],
     aggs: &[Node],
@@ -76,6 +118,8 @@ fn partitionable_gb(
                         Agg(AAggExpr::Mean(_)) => {
                             // only numeric means for now.
                             // logical types seem to break because of casts to float.
+                            // decimal means are not combined from partitions.
+                            matches!(get_agg_dtype_policy().mean, MeanDtype::Float64) &&
                             matches!(expr_arena.get(*agg).get_type(_input_schema, Context::Default, expr_arena).map(|dt| {
                                         dt.is_numeric()}), Ok(true))
                         },
@@ -88,7 +132,8 @@ fn partitionable_gb(
                                                 | AAggExpr::Sum(_)
                                                 | AAggExpr::Last(_)
                                                 | AAggExpr::First(_)
-                                                | AAggExpr::Count(_)
+                                                | AAggExpr::Count(_, _)
+                                                | AAggExpr::ApproxNUnique(_)
                                         )
                         },
                         Function {input, options, ..} => {
@@ -165,8 +210,38 @@ pub fn create_physical_plan(
         }
         Selection { input, predicate } => {
             let input = create_physical_plan(input, lp_arena, expr_arena)?;
-            let predicate = create_physical_expr(predicate, Context::Default, expr_arena, None)?;
-            Ok(Box::new(executors::FilterExec::new(predicate, input)))
+            let mut conjunctions = split_conjunctions(predicate, expr_arena);
+            // If the conjunctions only look at their own row, they can be applied one after
+            // the other, starting with the cheapest, so that the expensive predicates
+            // (e.g. regex matching) only run on the rows that are left.
+            if conjunctions.len() > 1
+                && conjunctions
+                    .iter()
+                    .all(|node| aexpr_is_row_local(*node, expr_arena))
+            {
+                // Equality checks are usually the most selective, so they go first on a tie.
+                conjunctions.sort_by_cached_key(|node| {
+                    let is_eq = matches!(
+                        expr_arena.get(*node),
+                        AExpr::BinaryExpr {
+                            op: Operator::Eq,
+                            ..
+                        }
+                    );
+                    (aexpr_cost(*node, expr_arena), !is_eq)
+                });
+                let predicates = conjunctions
+                    .into_iter()
+                    .map(|node| create_physical_expr(node, Context::Default, expr_arena, None))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(Box::new(executors::FilterExec::new_conjunctions(
+                    predicates, input,
+                )))
+            } else {
+                let predicate =
+                    create_physical_expr(predicate, Context::Default, expr_arena, None)?;
+                Ok(Box::new(executors::FilterExec::new(predicate, input)))
+            }
         }
         #[cfg(feature = "csv")]
         CsvScan {
@@ -175,6 +250,7 @@ pub fn create_physical_plan(
             output_schema,
             options,
             predicate,
+            cloud_options,
         } => {
             let predicate = predicate
                 .map(|pred| {
@@ -186,6 +262,7 @@ pub fn create_physical_plan(
                 schema: file_info.schema,
                 options,
                 predicate,
+                cloud_options,
             }))
         }
         #[cfg(feature = "ipc")]
@@ -218,16 +295,68 @@ pub fn create_physical_plan(
             options,
             cloud_options,
         } => {
-            let predicate = predicate
-                .map(|pred| {
-                    create_physical_expr(pred, Context::Default, expr_arena, output_schema.as_ref())
-                })
-                .map_or(Ok(None), |v| v.map(Some))?;
+            let empty_scan = || -> PolarsResult<Box<dyn Executor>> {
+                let schema = match &options.with_columns {
+                    Some(columns) => columns
+                        .iter()
+                        .map(|name| file_info.schema.try_get_field(name))
+                        .collect::<PolarsResult<Schema>>()?,
+                    None => (*file_info.schema).clone(),
+                };
+                Ok(Box::new(executors::DataFrameExec {
+                    df: Arc::new(DataFrame::from(&schema)),
+                    selection: None,
+                    projection: None,
+                }))
+            };
+            // a file of which the statistics show that no row can match is pruned here,
+            // before its footer is read
+            if let (Some(pred), Some(statistics), true) = (
+                predicate,
+                file_info.statistics.as_deref(),
+                options.use_statistics,
+            ) {
+                let expr = create_physical_expr(
+                    pred,
+                    Context::Default,
+                    expr_arena,
+                    output_schema.as_ref(),
+                )?;
+                let stats = BatchStats::from_min_max(statistics)?;
+                if !read_this_file(&PhysicalIoHelper { expr }, &stats)? {
+                    return empty_scan();
+                }
+            }
+            let [hive_predicate, predicate, mixed_predicate] =
+                match (predicate, file_info.hive_parts.as_deref()) {
+                    (Some(predicate), Some(hive_parts)) => {
+                        split_hive_predicate(predicate, hive_parts, expr_arena)
+                    }
+                    (predicate, _) => [None, predicate, None],
+                };
+            let to_physical = |pred: Option<Node>, schema: Option<&SchemaRef>| {
+                pred.map(|pred| create_physical_expr(pred, Context::Default, expr_arena, schema))
+                    .transpose()
+            };
+            // a partition that is filtered out is pruned here, before its file is opened
+            if let (Some(hive_predicate), Some(hive_parts)) = (
+                to_physical(hive_predicate, None)?,
+                file_info.hive_parts.as_deref(),
+            ) {
+                let mask = hive_predicate.evaluate(&hive_parts.to_df(), &Default::default())?;
+                if mask.bool()?.get(0) != Some(true) {
+                    return empty_scan();
+                }
+            }
+            let predicate = to_physical(predicate, output_schema.as_ref())?;
+            let mixed_predicate = to_physical(mixed_predicate, output_schema.as_ref())?;
 
             Ok(Box::new(executors::ParquetExec::new(
                 path,
                 file_info.schema,
+                file_info.hive_parts,
                 predicate,
+                mixed_predicate,
                 options,
                 cloud_options,
             )))
@@ -425,6 +554,12 @@ pub fn create_physical_plan(
                 )))
             } else {
                 let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let first_last_aggs = executors::first_last_aggs(
+                    &aggs
+                        .iter()
+                        .map(|node| node_to_expr(*node, expr_arena))
+                        .collect::<Vec<_>>(),
+                );
                 Ok(Box::new(executors::GroupByExec::new(
                     input,
                     phys_keys,
@@ -433,6 +568,7 @@ pub fn create_physical_plan(
                     maintain_order,
                     input_schema,
                     options.slice,
+                    first_last_aggs,
                 )))
             }
         }
@@ -469,6 +605,8 @@ pub fn create_physical_plan(
                 input_left,
                 input_right,
                 options.how,
+                options.algorithm,
+                options.validate,
                 left_on,
                 right_on,
                 parallel,
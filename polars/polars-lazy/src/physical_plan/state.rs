@@ -93,15 +93,22 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
-    pub(super) fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+    pub(super) fn record<F: FnOnce() -> PolarsResult<DataFrame>>(
+        &self,
+        func: F,
+        name: Cow<'static, str>,
+    ) -> PolarsResult<DataFrame> {
         match &self.node_timer {
             None => func(),
             Some(timer) => {
+                let memory_start = timer.sample_memory();
                 let start = std::time::Instant::now();
                 let out = func();
                 let end = std::time::Instant::now();
+                let memory = std::cmp::max(memory_start, timer.sample_memory());
 
-                timer.store(start, end, name.as_ref().to_string());
+                let rows = out.as_ref().ok().map(|df| df.height());
+                timer.store(start, end, name.as_ref().to_string(), rows, memory);
                 out
             }
         }
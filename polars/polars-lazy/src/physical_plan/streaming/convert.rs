@@ -10,7 +10,8 @@ use polars_core::schema::Schema;
 use polars_pipe::expressions::PhysicalPipedExpr;
 use polars_pipe::operators::chunks::DataChunk;
 use polars_pipe::pipeline::{
-    create_pipeline, get_dummy_operator, get_operator, swap_join_order, PipeLine,
+    create_pipeline, get_dummy_operator, get_operator, is_sorted_by, swap_join_order,
+    window_partition_by, PipeLine,
 };
 use polars_pipe::SExecutionContext;
 use polars_plan::prelude::*;
@@ -100,6 +101,44 @@ fn all_streamable(exprs: &[Node], expr_arena: &Arena<AExpr>, context: Context) -
         .all(|node| is_streamable(*node, expr_arena, context))
 }
 
+/// Check if the streaming window sink can evaluate the expressions: the window functions are
+/// partitioned by the same columns and the other expressions are streamable.
+fn is_streamable_window(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
+    window_partition_by(exprs, expr_arena).is_some()
+        && exprs.iter().all(|node| {
+            let node = match expr_arena.get(*node) {
+                AExpr::Alias(input, _) => *input,
+                _ => *node,
+            };
+            matches!(expr_arena.get(node), AExpr::Window { .. })
+                || is_streamable(node, expr_arena, Context::Default)
+        })
+}
+
+/// Check if the chunks that reach `node` have unique indices: they come from a single scan
+/// through operators that produce one chunk per chunk.
+fn has_unique_chunk_indices(mut node: Node, lp_arena: &Arena<ALogicalPlan>) -> bool {
+    use ALogicalPlan::*;
+    loop {
+        match lp_arena.get(node) {
+            Selection { input, .. } | Projection { input, .. } | HStack { input, .. } => {
+                node = *input
+            }
+            MapFunction { input, function }
+                if function.is_streamable() && !function.expands_rows() =>
+            {
+                node = *input
+            }
+            #[cfg(feature = "csv")]
+            CsvScan { .. } => return true,
+            #[cfg(feature = "parquet")]
+            ParquetScan { .. } => return true,
+            DataFrameScan { .. } | AnonymousScan { .. } => return true,
+            _ => return false,
+        }
+    }
+}
+
 /// check if all expressions are a simple column projection
 fn all_column(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
     exprs
@@ -107,8 +146,12 @@ fn all_column(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
         .all(|node| matches!(expr_arena.get(*node), AExpr::Column(_)))
 }
 
-fn streamable_join(join_type: &JoinType) -> bool {
-    match join_type {
+fn streamable_join(options: &JoinOptions) -> bool {
+    // the streaming joins don't validate the keys
+    if options.validate != JoinValidation::ManyToMany {
+        return false;
+    }
+    match &options.how {
         #[cfg(feature = "cross_join")]
         JoinType::Cross => true,
         JoinType::Inner | JoinType::Left => true,
@@ -215,6 +258,27 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push((!IS_SINK, !IS_RHS_JOIN, root));
                 stack.push((*input, state, current_idx))
             }
+            // window functions are evaluated by a sink that buffers all of its input, so they
+            // break the pipeline
+            HStack { input, exprs, .. }
+            | Projection {
+                input, expr: exprs, ..
+            } if is_streamable_window(exprs, expr_arena) => {
+                state.streamable = true;
+                state.operators_sinks.push((IS_SINK, !IS_RHS_JOIN, root));
+                stack.push((*input, state, current_idx))
+            }
+            // the operator needs the chunks of the input in order, otherwise the rows are
+            // taken in a sink once all chunks are seen
+            MapFunction {
+                input,
+                function: FunctionNode::GatherEvery { .. },
+            } => {
+                state.streamable = true;
+                let is_sink = !has_unique_chunk_indices(*input, lp_arena);
+                state.operators_sinks.push((is_sink, !IS_RHS_JOIN, root));
+                stack.push((*input, state, current_idx))
+            }
             // Rechunks are ignored
             MapFunction {
                 input,
@@ -247,8 +311,9 @@ pub(crate) fn insert_streaming_nodes(
                     pipeline_trees[current_idx].push(state)
                 }
             }
+            // the streaming engine doesn't add the columns of hive partitions
             #[cfg(feature = "parquet")]
-            ParquetScan { .. } => {
+            ParquetScan { file_info, .. } if file_info.hive_parts.is_none() => {
                 if state.streamable {
                     state.sources.push(root);
                     pipeline_trees[current_idx].push(state)
@@ -260,12 +325,18 @@ pub(crate) fn insert_streaming_nodes(
                     pipeline_trees[current_idx].push(state)
                 }
             }
+            AnonymousScan { function, .. } if function.allows_streaming() => {
+                if state.streamable {
+                    state.sources.push(root);
+                    pipeline_trees[current_idx].push(state)
+                }
+            }
             Join {
                 input_left,
                 input_right,
                 options,
                 ..
-            } if streamable_join(&options.how) => {
+            } if streamable_join(options) => {
                 let input_left = *input_left;
                 let input_right = *input_right;
                 state.streamable = true;
@@ -308,14 +379,16 @@ pub(crate) fn insert_streaming_nodes(
             Union { inputs, .. } => {
                 if state.streamable
                     && inputs.iter().all(|node| match lp_arena.get(*node) {
-                        ParquetScan { .. } => true,
+                        ParquetScan { file_info, .. } => file_info.hive_parts.is_none(),
                         CsvScan { .. } => true,
                         MapFunction {
                             input,
                             function: FunctionNode::Rechunk,
-                        } => {
-                            matches!(lp_arena.get(*input), ParquetScan { .. } | CsvScan { .. })
-                        }
+                        } => match lp_arena.get(*input) {
+                            ParquetScan { file_info, .. } => file_info.hive_parts.is_none(),
+                            CsvScan { .. } => true,
+                            _ => false,
+                        },
                         _ => false,
                     })
                 {
@@ -334,8 +407,9 @@ pub(crate) fn insert_streaming_nodes(
             #[allow(unused_variables)]
             Aggregate {
                 input,
+                keys,
                 aggs,
-                maintain_order: false,
+                maintain_order,
                 apply: None,
                 schema,
                 options,
@@ -357,14 +431,52 @@ pub(crate) fn insert_streaming_nodes(
                     }
                 }
                 let input_schema = lp_arena.get(*input).schema(lp_arena);
-                #[allow(unused_mut)]
                 let mut can_stream = true;
+                #[allow(unused_mut)]
+                let mut is_dynamic = false;
 
                 #[cfg(feature = "dynamic_groupby")]
                 {
-                    if options.rolling.is_some() || options.dynamic.is_some() {
+                    if options.rolling.is_some() {
                         can_stream = false
                     }
+                    // the windows of a dynamic groupby are assigned per chunk if they don't
+                    // depend on the first value of the index column and the input is sorted by it
+                    if let Some(dynamic) = &options.dynamic {
+                        is_dynamic = true;
+                        can_stream &= keys.is_empty()
+                            && dynamic.is_aligned()
+                            && matches!(
+                                input_schema.get(&dynamic.index_column),
+                                Some(
+                                    DataType::Date
+                                        | DataType::Datetime(_, None)
+                                        | DataType::Int32
+                                        | DataType::Int64
+                                )
+                            )
+                            && polars_pipe::pipeline::is_sorted_ascending_by(
+                                *input,
+                                &dynamic.index_column,
+                                lp_arena,
+                                expr_arena,
+                            );
+                    }
+                }
+
+                // the order of the groups is only maintained if the keys are sorted
+                if *maintain_order && !is_dynamic {
+                    let names = keys
+                        .iter()
+                        .map(|node| match expr_arena.get(*node) {
+                            AExpr::Column(name) => Some(name.clone()),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>();
+                    can_stream &= match names {
+                        Some(names) => is_sorted_by(*input, &names, lp_arena, expr_arena),
+                        None => false,
+                    };
                 }
 
                 if can_stream
@@ -520,6 +632,10 @@ impl SExecutionContext for ExecutionState {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn clear_window_cache(&self) {
+        self.clear_expr_cache()
+    }
 }
 
 fn get_pipeline_node(
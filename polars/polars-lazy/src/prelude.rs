@@ -1,12 +1,21 @@
 pub(crate) use polars_ops::prelude::*;
 pub use polars_plan::logical_plan::{
-    AnonymousScan, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan, Null, NULL,
+    register_optimization_pass, unregister_optimization_pass, AnonymousScan, AnonymousScanBatches,
+    AnonymousScanOptions, ColumnLineage, Literal, LiteralValue, LogicalPlan, Null,
+    OptimizationPass, SourceColumn, NULL,
 };
+#[cfg(feature = "csv")]
+pub use polars_plan::prelude::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 pub use polars_plan::prelude::IpcWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_plan::prelude::ParquetWriteOptions;
+pub use polars_plan::prelude::WindowMapping;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(feature = "temporal")]
+pub use polars_time::AddMonthsStrategy;
+#[cfg(feature = "timezones")]
+pub use polars_time::Ambiguous;
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
 #[cfg(feature = "dynamic_groupby")]
@@ -564,3 +564,104 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_min_by_max_by() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("fruits")])
+        .agg([
+            col("A").min_by(col("B")).alias("min_by"),
+            col("A").max_by(col("B")).alias("max_by"),
+            col("A").take(col("B").arg_max()).alias("take_arg_max"),
+            col("cars").max_by(col("A").eq(lit(2))),
+        ])
+        .collect()?;
+
+    assert_eq!(Vec::from(out.column("min_by")?.i32()?), &[Some(5), Some(4)]);
+    assert_eq!(Vec::from(out.column("max_by")?.i32()?), &[Some(1), Some(3)]);
+    assert!(out
+        .column("max_by")?
+        .series_equal(out.column("take_arg_max")?));
+    assert_eq!(
+        Vec::from(out.column("cars")?.utf8()?),
+        &[Some("audi"), Some("beetle")]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("cars").min_by(col("B")), col("A").max_by(col("B"))])
+        .collect()?;
+    assert_eq!(out.column("cars")?.utf8()?.get(0), Some("beetle"));
+    assert_eq!(out.column("A")?.i32()?.get(0), Some(1));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn test_agg_information_measures() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "a", "b", "b"],
+        "x" => ["u", "u", "v", "v", "u", "u"],
+    ]?;
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("x").entropy(std::f64::consts::E, true).alias("entropy"),
+            col("x").gini_impurity().alias("gini"),
+        ])
+        .collect()?;
+    let entropy = out.column("entropy")?.f64()?;
+    assert!((entropy.get(0).unwrap() - 2.0f64.ln()).abs() < 1e-12);
+    assert_eq!(entropy.get(1), Some(0.0));
+    let gini = out.column("gini")?.f64()?;
+    assert_eq!(Vec::from(gini), &[Some(0.5), Some(0.0)]);
+
+    // booleans are probabilities, class labels are only normalized if asked to
+    let out = df![
+        "b" => [true, true],
+        "x" => ["u", "u"],
+    ]?
+    .lazy()
+    .select([
+        col("b").entropy(std::f64::consts::E, true),
+        col("x").entropy(std::f64::consts::E, false),
+    ])
+    .collect()?;
+    assert!((out.column("b")?.f64()?.get(0).unwrap() - 2.0f64.ln()).abs() < 1e-12);
+    assert!((out.column("x")?.f64()?.get(0).unwrap() + 2.0 * 2.0f64.ln()).abs() < 1e-12);
+
+    let df = df![
+        "x" => ["u", "u", "u", "v", "v", "v", "v", "w"],
+        "y" => [Some(1), Some(1), Some(0), Some(1), Some(0), Some(0), None, Some(1)],
+    ]?;
+    let woe = df
+        .clone()
+        .lazy()
+        .select([col("x").weight_of_evidence(col("y"))])
+        .collect()?;
+    let woe = woe.column("x")?.f64()?;
+    // "w" only has events, so its weight of evidence is unbounded
+    let (woe_u, woe_v) = ((0.5f64 / (1.0 / 3.0)).ln(), (0.25f64 / (2.0 / 3.0)).ln());
+    assert_eq!(woe.get(7), None);
+    for (value, expected) in woe
+        .into_iter()
+        .take(7)
+        .zip([woe_u, woe_u, woe_u, woe_v, woe_v, woe_v, woe_v])
+    {
+        assert!((value.unwrap() - expected).abs() < 1e-12);
+    }
+
+    let iv = df
+        .lazy()
+        .select([col("x").information_value(col("y"))])
+        .collect()?;
+    let iv = iv.column("x")?.f64()?.get(0).unwrap();
+    let expected = (0.5 - 1.0 / 3.0) * woe_u + (0.25 - 2.0 / 3.0) * woe_v;
+    assert!((iv - expected).abs() < 1e-12);
+    Ok(())
+}
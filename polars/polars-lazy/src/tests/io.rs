@@ -1,3 +1,6 @@
+use std::sync::Mutex;
+
+use polars_io::manifest::{FileManifest, ManifestEntry};
 use polars_io::RowCount;
 
 use super::*;
@@ -167,6 +170,277 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "async")]
+fn test_parquet_object_store() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+    let path = std::fs::canonicalize(FOODS_PARQUET)?;
+    let uri = format!("file://{}", path.display());
+    let scan = || LazyFrame::scan_parquet(&uri, Default::default());
+
+    let expected = scan_foods_parquet(true)
+        .filter(col("calories").gt(lit(100i32)))
+        .select([col("category"), col("sugars_g")])
+        .collect()?;
+    let out = scan()?
+        .filter(col("calories").gt(lit(100i32)))
+        .select([col("category"), col("sugars_g")])
+        .collect()?;
+    assert!(out.frame_equal(&expected));
+
+    // the row groups are pruned by their statistics before they are downloaded
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = scan()?.filter(col("calories").gt(lit(1000i32))).collect();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(out?.shape(), (0, 4));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn test_csv_object_store() -> PolarsResult<()> {
+    let path = std::fs::canonicalize(FOODS_CSV)?;
+    let uri = format!("file://{}", path.display());
+    let q = |lf: LazyFrame| {
+        lf.filter(col("calories").gt(lit(100i32)))
+            .select([col("category"), col("sugars_g")])
+    };
+
+    let expected = q(scan_foods_csv()).collect()?;
+    let out = q(LazyCsvReader::new(&uri).finish()?).collect()?;
+    assert!(out.frame_equal(&expected));
+    let out = q(LazyCsvReader::new(&uri).finish()?)
+        .with_streaming(true)
+        .collect()?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_page_index_pruning() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_page_index.parquet");
+    // a single row group whose pages don't cover the gap between 5000 and 10000
+    let a = (0i64..5000).chain(10_000..15_000).collect::<Vec<_>>();
+    let b = a.iter().map(|v| v * 2).collect::<Vec<_>>();
+    let mut df = df!["a" => a, "b" => b]?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_data_pagesize_limit(Some(1024))
+        .finish(&mut df)?;
+    let scan = || LazyFrame::scan_parquet(&path, ScanArgsParquet::default());
+
+    let out = scan()?.filter(col("a").eq(lit(100i64))).collect()?;
+    assert_eq!(out.shape(), (1, 2));
+
+    // only the pages that hold the selected rows are read, the columns stay aligned
+    let out = scan()?
+        .filter(
+            col("a")
+                .gt_eq(lit(12_000i64))
+                .and(col("a").lt(lit(12_010i64))),
+        )
+        .collect()?;
+    let expected = df![
+        "a" => (12_000i64..12_010).collect::<Vec<_>>(),
+        "b" => (24_000i64..24_020).step_by(2).collect::<Vec<_>>()
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    // the row group statistics don't exclude the value, the page index does
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = scan()?.filter(col("a").eq(lit(7500i64))).collect();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(out?.shape(), (0, 2));
+    Ok(())
+}
+
+// single column files without statistics, only the bloom filter can prune them
+#[cfg(all(feature = "dtype-time", feature = "dtype-datetime"))]
+const TIME_MICROS_PARQUET: &[u8] = &[
+    0x50, 0x41, 0x52, 0x31, 0x15, 0x00, 0x15, 0x30, 0x15, 0x30, 0x2c, 0x15, 0x06, 0x15, 0x00, 0x15,
+    0x06, 0x15, 0x06, 0x00, 0x00, 0x40, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0xe3, 0x16,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb0, 0xeb, 0x0e, 0x0a, 0x00, 0x00, 0x00, 0x15, 0x40, 0x1c,
+    0x1c, 0x00, 0x00, 0x1c, 0x1c, 0x00, 0x00, 0x1c, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x05,
+    0x10, 0x00, 0x00, 0xc0, 0x00, 0x40, 0x04, 0x01, 0x00, 0x02, 0x40, 0x10, 0x00, 0x00, 0x03, 0x80,
+    0x01, 0x00, 0x40, 0x00, 0x00, 0x48, 0x00, 0x08, 0x04, 0x01, 0x00, 0x08, 0x15, 0x02, 0x19, 0x2c,
+    0x48, 0x06, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x15, 0x02, 0x00, 0x15, 0x04, 0x25, 0x00, 0x18,
+    0x04, 0x74, 0x69, 0x6d, 0x65, 0x25, 0x10, 0x4c, 0x7c, 0x12, 0x1c, 0x2c, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x16, 0x06, 0x19, 0x1c, 0x19, 0x1c, 0x26, 0x08, 0x1c, 0x15, 0x04, 0x19, 0x15, 0x00, 0x19,
+    0x18, 0x04, 0x74, 0x69, 0x6d, 0x65, 0x15, 0x00, 0x16, 0x06, 0x16, 0x52, 0x16, 0x52, 0x26, 0x08,
+    0x56, 0x5a, 0x15, 0x5e, 0x00, 0x00, 0x16, 0x52, 0x16, 0x06, 0x00, 0x28, 0x14, 0x62, 0x6c, 0x6f,
+    0x6f, 0x6d, 0x20, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x20, 0x66, 0x69, 0x78, 0x74, 0x75, 0x72,
+    0x65, 0x00, 0x66, 0x00, 0x00, 0x00, 0x50, 0x41, 0x52, 0x31,
+];
+
+#[cfg(all(feature = "dtype-time", feature = "dtype-datetime"))]
+const INT96_PARQUET: &[u8] = &[
+    0x50, 0x41, 0x52, 0x31, 0x15, 0x00, 0x15, 0x48, 0x15, 0x48, 0x2c, 0x15, 0x06, 0x15, 0x00, 0x15,
+    0x06, 0x15, 0x06, 0x00, 0x00, 0x00, 0xca, 0x9a, 0x3b, 0x00, 0x00, 0x00, 0x00, 0x8d, 0x3d, 0x25,
+    0x00, 0x00, 0x80, 0xa7, 0x48, 0x4a, 0x27, 0x00, 0x00, 0x59, 0x68, 0x25, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xc1, 0x89, 0x25, 0x00, 0x15, 0x40, 0x1c, 0x1c, 0x00, 0x00, 0x1c,
+    0x1c, 0x00, 0x00, 0x1c, 0x1c, 0x00, 0x00, 0x00, 0x40, 0x80, 0x40, 0x00, 0x40, 0x00, 0x10, 0x01,
+    0x02, 0x00, 0x00, 0x05, 0x28, 0x08, 0x00, 0x00, 0x20, 0x40, 0x02, 0x00, 0x60, 0x00, 0x00, 0x08,
+    0x20, 0x01, 0x04, 0x00, 0x24, 0x08, 0x00, 0x00, 0x15, 0x02, 0x19, 0x2c, 0x48, 0x06, 0x73, 0x63,
+    0x68, 0x65, 0x6d, 0x61, 0x15, 0x02, 0x00, 0x15, 0x06, 0x25, 0x00, 0x18, 0x02, 0x74, 0x73, 0x00,
+    0x16, 0x06, 0x19, 0x1c, 0x19, 0x1c, 0x26, 0x08, 0x1c, 0x15, 0x06, 0x19, 0x15, 0x00, 0x19, 0x18,
+    0x02, 0x74, 0x73, 0x15, 0x00, 0x16, 0x06, 0x16, 0x6a, 0x16, 0x6a, 0x26, 0x08, 0x56, 0x72, 0x15,
+    0x5e, 0x00, 0x00, 0x16, 0x6a, 0x16, 0x06, 0x00, 0x28, 0x14, 0x62, 0x6c, 0x6f, 0x6f, 0x6d, 0x20,
+    0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x20, 0x66, 0x69, 0x78, 0x74, 0x75, 0x72, 0x65, 0x00, 0x57,
+    0x00, 0x00, 0x00, 0x50, 0x41, 0x52, 0x31,
+];
+
+#[test]
+#[cfg(all(feature = "dtype-time", feature = "dtype-datetime"))]
+fn test_parquet_bloom_filter_time_int96() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let check = |name: &str, bytes: &[u8], column: &str, present: Expr, absent: Expr| {
+        let path = std::env::temp_dir().join(format!(
+            "polars_test_bloom_{}_{}.parquet",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, bytes)?;
+        let scan = || LazyFrame::scan_parquet(&path, ScanArgsParquet::default());
+        let out = scan()?.filter(col(column).eq(present)).collect()?;
+        assert_eq!(out.shape(), (1, 1));
+        let out = scan()?.filter(col(column).eq(absent)).collect()?;
+        assert_eq!(out.shape(), (0, 1));
+        std::fs::remove_file(&path)?;
+        PolarsResult::Ok(())
+    };
+
+    // TIME_MICROS, the literal is in nanoseconds
+    check(
+        "time_micros",
+        TIME_MICROS_PARQUET,
+        "time",
+        Expr::Literal(LiteralValue::Time(1_500_000_000)),
+        Expr::Literal(LiteralValue::Time(2_000_000_000)),
+    )?;
+    // INT96 timestamps, 1970-01-02 00:00:01
+    check(
+        "int96",
+        INT96_PARQUET,
+        "ts",
+        Expr::Literal(LiteralValue::DateTime(
+            86_401_000_000_000,
+            TimeUnit::Nanoseconds,
+            None,
+        )),
+        Expr::Literal(LiteralValue::DateTime(
+            86_402_000_000_000,
+            TimeUnit::Nanoseconds,
+            None,
+        )),
+    )
+}
+
+#[test]
+fn test_parquet_hive_partitions() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let root = std::env::temp_dir().join("polars_test_hive_partitions");
+    let _ = std::fs::remove_dir_all(&root);
+    for (year, values) in [(2022, vec![1i64, 2]), (2023, vec![3])] {
+        let dir = root.join(format!("year={year}"));
+        std::fs::create_dir_all(&dir)?;
+        let mut df = df!["a" => values]?;
+        ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut df)?;
+    }
+    // not part of the dataset
+    std::fs::write(root.join("_SUCCESS"), "")?;
+
+    let scan = || LazyFrame::scan_parquet(&root, ScanArgsParquet::default());
+
+    let df = scan()?.collect()?;
+    let expected = df![
+        "a" => [1i64, 2, 3],
+        "year" => [2022i64, 2022, 2023]
+    ]?;
+    assert!(df.frame_equal(&expected));
+
+    // predicates on the partition columns, the file columns and both
+    let out = scan()?.filter(col("year").eq(lit(2023))).collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(3));
+    assert_eq!(out.height(), 1);
+    let out = scan()?
+        .filter(col("year").eq(lit(2022)).and(col("a").gt(lit(1))))
+        .select([col("year")])
+        .collect()?;
+    assert!(out.frame_equal(&df!["year" => [2022i64]]?));
+    let out = scan()?
+        .filter((col("a") + col("year")).eq(lit(2024)))
+        .collect()?;
+    assert!(out.frame_equal(&df!["a" => [2i64], "year" => [2022i64]]?));
+
+    // pruned partitions are not read
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = scan()?.filter(col("year").lt(lit(2000))).collect();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(out?.shape(), (0, 2));
+    std::fs::remove_dir_all(&root)?;
+
+    // percent-encoded partition values are decoded
+    let dir = root.join("name=a%2Fb%3Dc");
+    std::fs::create_dir_all(&dir)?;
+    let mut df = df!["a" => [1i64]]?;
+    ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut df)?;
+    let out = scan()?.filter(col("name").eq(lit("a/b=c"))).collect()?;
+    assert!(out.frame_equal(&df!["a" => [1i64], "name" => ["a/b=c"]]?));
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn test_parquet_manifest() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_manifest");
+    std::fs::create_dir_all(&dir)?;
+    let mut entries = vec![];
+    for (i, values) in [vec![1i64, 2], vec![], vec![3]].into_iter().enumerate() {
+        let path = dir.join(format!("{i}.parquet"));
+        let mut df = df!["a" => values]?;
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        entries.push(ManifestEntry::new(path).with_num_rows(Some(df.height())));
+    }
+    let manifest = FileManifest::new(entries);
+
+    let df = LazyFrame::scan_parquet_manifest(manifest.clone(), Default::default())?.collect()?;
+    assert!(df.frame_equal(&df!["a" => [1i64, 2, 3]]?));
+
+    // the last file is not needed for the first two rows
+    let args = ScanArgsParquet {
+        n_rows: Some(2),
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet_manifest(manifest, args)?;
+    assert!(!lf.describe_plan().contains("2.parquet"));
+    assert!(lf.collect()?.frame_equal(&df!["a" => [1i64, 2]]?));
+
+    // files that are known to be empty are skipped
+    let empty = FileManifest::new(vec![
+        ManifestEntry::new(dir.join("1.parquet")).with_size(Some(0))
+    ]);
+    assert!(LazyFrame::scan_parquet_manifest(empty, Default::default()).is_err());
+
+    // the footer of a file that is pruned by its statistics is never read, so it doesn't
+    // need to exist
+    let pruned = FileManifest::new(vec![
+        ManifestEntry::new(dir.join("0.parquet")),
+        ManifestEntry::new(dir.join("missing.parquet"))
+            .with_num_rows(Some(2))
+            .with_statistics(vec![Series::new("a", [10i64, 20])]),
+    ]);
+    let df = LazyFrame::scan_parquet_manifest(pruned, Default::default())?
+        .filter(col("a").lt(lit(5i64)))
+        .collect()?;
+    assert!(df.frame_equal(&df!["a" => [1i64, 2]]?));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_ipc_globbing() -> PolarsResult<()> {
@@ -426,6 +700,51 @@ fn scan_anonymous_fn() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_anonymous_pushdown() -> PolarsResult<()> {
+    // a source that only uses the pushdowns as hints and returns the full table
+    struct Source {
+        options: Mutex<Option<AnonymousScanOptions>>,
+    }
+    impl AnonymousScan for Source {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+            *self.options.lock().unwrap() = Some(scan_opts);
+            Ok(fruits_cars())
+        }
+        fn allows_predicate_pushdown(&self) -> bool {
+            true
+        }
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+        fn predicate_pushdown_is_exact(&self) -> bool {
+            false
+        }
+    }
+
+    let source = Arc::new(Source {
+        options: Mutex::new(None),
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema()),
+        ..ScanArgsAnonymous::default()
+    };
+    let df = LazyFrame::anonymous_scan(source.clone(), args)?
+        .filter(col("A").gt(lit(2)))
+        .select([col("B")])
+        .collect()?;
+    assert_eq!(df.shape(), (3, 1));
+    assert_eq!(df.get_column_names(), &["B"]);
+
+    let options = source.options.lock().unwrap().take().unwrap();
+    assert!(options.predicate.is_some());
+    assert_eq!(options.with_columns.unwrap().len(), 2);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -159,3 +159,57 @@ fn test_lazy_logical_plan_join() {
         let _df = lf.collect().unwrap();
     }
 }
+
+#[test]
+fn test_column_lineage() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 2, 3],
+    ]?
+    .lazy();
+    let right = df![
+        "a" => [1, 2, 3],
+        "c" => [4, 5, 6],
+    ]?
+    .lazy();
+    let q = left
+        .join(right, [col("a")], [col("a")], JoinType::Inner)
+        .with_column((col("b") + col("c")).alias("d"))
+        .groupby([col("a")])
+        .agg([col("d").sum()])
+        .rename(["d"], ["total"]);
+
+    let lineage = q.column_lineage("total")?;
+    let columns = lineage
+        .sources
+        .iter()
+        .map(|source| (source.source.as_str(), source.column.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(columns, &[("DataFrame", "b"), ("DataFrame", "c")]);
+    assert_eq!(lineage.expressions.len(), 2);
+    assert!(lineage.expressions[0].contains("sum"));
+
+    let lineage = q.column_lineage("a")?;
+    assert_eq!(lineage.sources.len(), 1);
+    assert!(lineage.expressions.is_empty());
+
+    assert!(q.column_lineage("b").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_to_dot() -> PolarsResult<()> {
+    let left = df!["a" => [1, 2, 3], "b" => [1, 2, 3]]?.lazy();
+    let right = df!["a" => [1, 2], "c" => [3, 4]]?.lazy();
+    let lf = left
+        .filter(col("b").gt(lit(1)))
+        .join(right, [col("a")], [col("a")], JoinType::Inner);
+
+    let dot = lf.to_dot(true)?;
+    assert!(dot.starts_with("digraph polars_query {"));
+    assert!(dot.contains(" -> "));
+    assert!(dot.contains("JOIN INNER"));
+    assert!(dot.contains("rows: 3"));
+    assert!(dot.contains("rows: 2"));
+    Ok(())
+}
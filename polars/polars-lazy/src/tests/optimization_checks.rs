@@ -551,3 +551,269 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+/// The expression that the optimizer makes of `expr` when it is selected from `df`.
+fn optimized_expr(df: &DataFrame, expr: Expr) -> PolarsResult<Expr> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = df
+        .clone()
+        .lazy()
+        .select([expr])
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    let node = lp_arena.get(root).get_exprs().pop().unwrap();
+    Ok(node_to_expr(node, &expr_arena))
+}
+
+#[test]
+fn test_simplify_expr_rewrites() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "s" => ["foo", "bar", "ham"]
+    ]?;
+    let check = |expr: Expr, expected: Expr| -> PolarsResult<()> {
+        assert_eq!(optimized_expr(&df, expr)?, expected);
+        Ok(())
+    };
+
+    check(lit(2) + lit(3), lit(5))?;
+    check(
+        col("a").cast(DataType::Int64).cast(DataType::Float64),
+        col("a").cast(DataType::Float64),
+    )?;
+    check(
+        col("a").cast(DataType::Int64).cast(DataType::Int64),
+        col("a").cast(DataType::Int64),
+    )?;
+    // the outer type is formatted differently from the inner type
+    check(
+        col("a").cast(DataType::Float64).cast(DataType::Utf8),
+        col("a").cast(DataType::Float64).cast(DataType::Utf8),
+    )?;
+    // the inner cast may change values, so it must be kept
+    check(
+        col("a").cast(DataType::Int8).cast(DataType::Int64),
+        col("a").cast(DataType::Int8).cast(DataType::Int64),
+    )?;
+    #[cfg(feature = "is_in")]
+    check(
+        col("a").is_in(lit(Series::new("", &[2]))),
+        col("a").eq_missing(lit(2)),
+    )?;
+    #[cfg(all(feature = "strings", feature = "regex"))]
+    {
+        check(
+            col("s").str().contains(lit("oo"), false),
+            col("s").str().contains_literal(lit("oo")),
+        )?;
+        check(
+            col("s").str().contains(lit("o+"), false),
+            col("s").str().contains(lit("o+"), false),
+        )?;
+    }
+    Ok(())
+}
+
+fn aggregate_below_join(q: LazyFrame) -> bool {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    (&lp_arena).iter(lp).any(|(_, lp)| match lp {
+        ALogicalPlan::Join { input_left, .. } => {
+            matches!(lp_arena.get(*input_left), ALogicalPlan::Aggregate { .. })
+        }
+        _ => false,
+    })
+}
+
+#[test]
+fn test_aggregate_pushdown_join() -> PolarsResult<()> {
+    let left = df![
+        "k" => [1, 1, 2, 2, 2, 3],
+        "v" => [Some(1.0), Some(2.0), Some(3.0), None, Some(3.0), Some(6.0)],
+    ]?
+    .lazy();
+    let right = df![
+        "k" => [1, 1, 2, 4],
+        "w" => ["a", "b", "c", "d"],
+    ]?
+    .lazy();
+
+    for how in [JoinType::Inner, JoinType::Left] {
+        let q = left
+            .clone()
+            .join(right.clone(), [col("k")], [col("k")], how)
+            .groupby([col("k")])
+            .agg([
+                col("v").sum().alias("sum"),
+                col("v").count().alias("v_count"),
+                count(),
+                col("v").min().alias("min"),
+                (col("v") * lit(2.0)).max().alias("max"),
+                col("v").mean().alias("mean"),
+                col("v").n_unique().alias("n_unique"),
+            ])
+            .sort("k", Default::default());
+        assert!(aggregate_below_join(q.clone()));
+
+        let out = q.clone().collect()?;
+        let expected = q.with_aggregate_pushdown(false).collect()?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+
+    // Depends on the number of matches on the right.
+    let q = left
+        .clone()
+        .join(right.clone(), [col("k")], [col("k")], JoinType::Inner)
+        .groupby([col("k")])
+        .agg([col("v").std(1)]);
+    assert!(!aggregate_below_join(q));
+
+    // The groups span multiple join keys.
+    let q = left
+        .join(right, [col("k")], [col("k")], JoinType::Inner)
+        .groupby([col("w")])
+        .agg([col("v").sum()]);
+    assert!(!aggregate_below_join(q));
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder() -> PolarsResult<()> {
+    let fact = df![
+        "a" => [1, 2, 3, 1, 2, 3],
+        "b" => [1, 2, 1, 2, 1, 2],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?
+    .lazy();
+    let dim_a = df![
+        "a" => [1, 2, 3, 4],
+        "x" => ["a", "b", "c", "d"],
+    ]?
+    .lazy();
+    let dim_b = df![
+        "b" => [1, 2],
+        "y" => ["one", "two"],
+    ]?
+    .lazy();
+
+    let q = fact
+        .join(dim_a, [col("a")], [col("a")], JoinType::Inner)
+        .join(dim_b, [col("b")], [col("b")], JoinType::Left);
+
+    // the smallest table is joined first
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let first_joined = (&lp_arena).iter(lp).find_map(|(_, lp)| match lp {
+        ALogicalPlan::Join {
+            input_left,
+            input_right,
+            ..
+        } if !matches!(lp_arena.get(*input_left), ALogicalPlan::Join { .. }) => {
+            Some(lp_arena.get(*input_right).schema(&lp_arena).into_owned())
+        }
+        _ => None,
+    });
+    assert!(first_joined.unwrap().contains("y"));
+
+    let out = q.clone().sort("v", Default::default()).collect()?;
+    let expected = q
+        .with_join_reorder(false)
+        .sort("v", Default::default())
+        .collect()?;
+    assert!(out.frame_equal(&expected));
+    assert_eq!(out.get_column_names(), &["a", "b", "v", "x", "y"]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_semi_anti_join_predicate_pushdown() -> PolarsResult<()> {
+    let left = df![
+        "k" => [1, 2, 3, 4, 5],
+        "v" => ["a", "b", "c", "d", "e"],
+    ]?
+    .lazy();
+    let right = df![
+        "k" => [2, 4, 5, 6],
+    ]?
+    .lazy();
+
+    for (how, expected) in [(JoinType::Semi, [4, 5]), (JoinType::Anti, [1, 3])] {
+        let k_gt = if matches!(how, JoinType::Semi) { 3 } else { 0 };
+        let q = left
+            .clone()
+            .join(right.clone(), [col("k")], [col("k")], how)
+            .filter(col("k").gt(lit(k_gt)))
+            .filter(col("k").neq(lit(2)))
+            .filter(col("k").lt(lit(6)));
+
+        // the predicates on the key are applied to both tables
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        let n_filtered_scans = (&lp_arena)
+            .iter(lp)
+            .filter(|(_, lp)| {
+                matches!(
+                    lp,
+                    ALogicalPlan::DataFrameScan {
+                        selection: Some(_),
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(n_filtered_scans, 2);
+
+        let out = q.collect()?;
+        assert_eq!(
+            Vec::from(out.column("k")?.i32()?),
+            expected.map(Some).to_vec()
+        );
+    }
+
+    let left = left.collect()?;
+    let right = right.collect()?;
+    let out = left.semi_join(&right, ["k"], ["k"])?;
+    assert_eq!(out.get_column_names(), &["k", "v"]);
+    assert_eq!(out.height(), 3);
+    let out = left.anti_join(&right, ["k"], ["k"])?;
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_user_optimization_pass() -> PolarsResult<()> {
+    // rewrites `slice(0, 12345)` at the root of the plan to `slice(0, 1)`
+    let pass = |root: Node,
+                lp_arena: &mut Arena<ALogicalPlan>,
+                _: &mut Arena<AExpr>|
+     -> PolarsResult<Node> {
+        if let ALogicalPlan::Slice {
+            input,
+            offset: 0,
+            len: 12345,
+        } = lp_arena.get(root)
+        {
+            let input = *input;
+            lp_arena.replace(
+                root,
+                ALogicalPlan::Slice {
+                    input,
+                    offset: 0,
+                    len: 1,
+                },
+            );
+        }
+        Ok(root)
+    };
+    register_optimization_pass("test_user_optimization_pass", Arc::new(pass));
+
+    let out = fruits_cars()
+        .lazy()
+        .with_slice_pushdown(false)
+        .slice(0, 12345)
+        .collect();
+    assert!(unregister_optimization_pass("test_user_optimization_pass"));
+    assert_eq!(out?.height(), 1);
+    assert!(!unregister_optimization_pass("test_user_optimization_pass"));
+    Ok(())
+}
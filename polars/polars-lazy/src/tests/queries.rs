@@ -35,6 +35,52 @@ fn test_lazy_exec() {
     assert!(check.all())
 }
 
+#[test]
+fn test_lazy_profile() -> PolarsResult<()> {
+    let (out, profile) = get_df()
+        .lazy()
+        .filter(col("sepal.width").gt(lit(3.4)))
+        .sort("sepal.width", Default::default())
+        .profile()?;
+
+    assert_eq!(
+        profile.get_column_names(),
+        &["node", "start", "end", "rows", "peak_memory"]
+    );
+    let rows = profile.column("rows")?.u64()?;
+    // the optimization row has no rows
+    assert_eq!(rows.null_count(), 1);
+    assert!(rows.into_iter().flatten().any(|n| n == out.height() as u64));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_explain_value() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "x" => [Some(1), None, Some(3)],
+    ]?;
+    let expr = when(col("x").sum().over([col("g")]).gt(lit(2)))
+        .then(col("x"))
+        .otherwise(lit(NULL));
+
+    let out = df.lazy().explain_value(expr, 2)?;
+    let depth = out.column("depth")?.u32()?;
+    assert_eq!(depth.get(0), Some(0));
+    let expr = out.column("expr")?.utf8()?;
+    let value = out.column("value")?.utf8()?;
+    let sum_value = expr
+        .into_iter()
+        .zip(value)
+        .find(|(expr, _)| expr.unwrap().contains(".sum()") && !expr.unwrap().contains("over"))
+        .unwrap()
+        .1;
+    // the sum of the group of the row, not of the column
+    assert_eq!(sum_value, Some("3"));
+    assert_eq!(value.get(0), Some("3"));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_alias() {
     let df = get_df();
@@ -83,6 +129,73 @@ fn test_lazy_drop_nulls() {
     assert!(new.frame_equal(&out));
 }
 
+#[test]
+fn test_lazy_drop_nulls_subset_exprs() -> PolarsResult<()> {
+    let df = df! {
+        "foo" => &[Some(1), None, Some(3), Some(4)],
+        "bar" => &[Some(1), Some(2), None, Some(4)],
+        "ham" => &[None, Some("a"), Some("b"), Some("c")]
+    }?;
+
+    // a selector drops the rows where any of its columns is null
+    let out = df
+        .clone()
+        .lazy()
+        .drop_nulls(Some(vec![dtype_col(&DataType::Int32)]))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("foo")?.i32()?), &[Some(1), Some(4)]);
+
+    // a selector that matches no columns drops no rows
+    for subset in [
+        dtype_col(&DataType::Float64),
+        dtype_col(&DataType::Float64) * lit(2),
+    ] {
+        let out = df.clone().lazy().drop_nulls(Some(vec![subset])).collect()?;
+        assert_eq!(out.height(), 4);
+    }
+
+    let out = df
+        .lazy()
+        .drop_nulls(Some(vec![col("foo") * lit(2), col("ham")]))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("foo")?.i32()?), &[Some(3), Some(4)]);
+    Ok(())
+}
+
+#[test]
+fn test_first_last_non_null() -> PolarsResult<()> {
+    let df = df! {
+        "g" => &["a", "a", "a", "b", "b"],
+        "v" => &[None, Some(2), Some(3), None, None]
+    }?;
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("v").first_non_null().alias("first"),
+            col("v").last_non_null().alias("last"),
+            col("v").arg_first_non_null().alias("arg_first"),
+            col("v").arg_last_non_null().alias("arg_last"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("first")?.i32()?), &[Some(2), None]);
+    assert_eq!(Vec::from(out.column("last")?.i32()?), &[Some(3), None]);
+    assert_eq!(Vec::from(out.column("arg_first")?.idx()?), &[Some(1), None]);
+    assert_eq!(Vec::from(out.column("arg_last")?.idx()?), &[Some(2), None]);
+
+    let out = df
+        .lazy()
+        .select([
+            col("v").first_non_null().alias("first"),
+            col("v").arg_last_non_null().alias("arg_last"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("first")?.i32()?), &[Some(2)]);
+    assert_eq!(Vec::from(out.column("arg_last")?.idx()?), &[Some(2)]);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_udf() {
     let df = get_df();
@@ -174,6 +287,61 @@ fn test_shift_and_fill() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_shift_and_fill_categorical() -> PolarsResult<()> {
+    let out = df![
+        "a" => ["x", "y", "z"]
+    ]?
+    .lazy()
+    .select([col("a")
+        .cast(DataType::Categorical(None))
+        .shift_and_fill(1, lit("z"))])
+    .collect()?;
+
+    let out = out.column("a")?;
+    assert!(matches!(out.dtype(), DataType::Categorical(_)));
+    let out = out.cast(&DataType::Utf8)?;
+    assert_eq!(Vec::from(out.utf8()?), &[Some("z"), Some("x"), Some("y")]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_cast_chain_to_enum() -> PolarsResult<()> {
+    let enum_a = DataType::new_enum(["x", "y", "z"])?;
+    let enum_b = DataType::new_enum(["z", "y"])?;
+    assert_ne!(enum_a, enum_b);
+    assert_ne!(enum_a, DataType::Categorical(None));
+
+    let out = df![
+        "a" => ["x", "y", "z"]
+    ]?
+    .lazy()
+    .select([
+        col("a")
+            .cast(DataType::Categorical(None))
+            .cast(enum_a.clone())
+            .alias("enum_a"),
+        col("a")
+            .cast(enum_a.clone())
+            .cast(enum_b.clone())
+            .alias("enum_b"),
+    ])
+    .collect()?;
+
+    // the outer casts must not be optimized away
+    assert_eq!(out.column("enum_a")?.dtype(), &enum_a);
+    let enum_b_out = out.column("enum_b")?;
+    assert_eq!(enum_b_out.dtype(), &enum_b);
+    // z sorts before y in `enum_b`, x is not a category of it
+    assert_eq!(
+        Vec::from(&enum_b_out.gt("z")?),
+        &[None, Some(true), Some(false)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_ternary_and_predicates() {
     let df = get_df();
@@ -1014,6 +1182,79 @@ fn test_groupby_cumsum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_window_cum_reverse() -> PolarsResult<()> {
+    let df = df![
+        "groups" => [1, 2, 2, 1, 2],
+        "vals" => [Some(1), Some(5), None, Some(3), Some(2)],
+        "flags" => [false, false, true, true, false]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("vals").cummax(true).over([col("groups")]).alias("max"),
+            col("vals").cummin(false).over([col("groups")]).alias("min"),
+            col("vals")
+                .cumcount(true)
+                .over([col("groups")])
+                .alias("count"),
+            col("flags")
+                .cummax(false)
+                .over([col("groups")])
+                .alias("flags"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("max")?.i32()?),
+        &[Some(3), Some(5), None, Some(3), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("min")?.i32()?),
+        &[Some(1), Some(5), None, Some(1), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[Some(1), Some(2), Some(1), Some(0), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("flags")?.bool()?),
+        &[Some(false), Some(false), Some(true), Some(true), Some(true)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cumcount_counts_nulls() -> PolarsResult<()> {
+    let df = df![
+        "vals" => [Some(1), None, None, Some(4)]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("vals").cumcount(false).alias("count"),
+            col("vals").cumcount(true).alias("count_reverse"),
+            col("vals").is_not_null().cumsum(false).alias("non_null"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[Some(0), Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("count_reverse")?.idx()?),
+        &[Some(3), Some(2), Some(1), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("non_null")?.u32()?),
+        &[Some(1), Some(1), Some(1), Some(2)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_arg_sort_multiple() -> PolarsResult<()> {
     let df = df![
@@ -1159,6 +1400,206 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "range_join")]
+#[test]
+fn test_join_where() -> PolarsResult<()> {
+    let events = df![
+        "id" => [1, 2, 3, 4],
+        "ts" => [1, 5, 10, 3]
+    ]?;
+    let intervals = df![
+        "id" => ["a", "b", "c"],
+        "start" => [0, 4, 2],
+        "end" => [4, 8, 6]
+    ]?;
+
+    let out = events
+        .lazy()
+        .join_where(
+            intervals.lazy(),
+            [col("start").lt_eq(col("ts")).and(col("ts").lt(col("end")))],
+        )?
+        .select([col("id"), col("id_right")])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("id")?.i32()?),
+        &[Some(1), Some(2), Some(2), Some(4), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("id_right")?.utf8()?),
+        &[Some("a"), Some("b"), Some("c"), Some("a"), Some("c")]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "interval_join")]
+#[test]
+fn test_join_interval() -> PolarsResult<()> {
+    let sessions = df![
+        "user" => ["a", "a", "b"],
+        "start" => [0, 4, 0],
+        "end" => [4, 8, 10]
+    ]?;
+    let events = df![
+        "user" => ["a", "b", "a", "a"],
+        "ts" => [4, 5, 0, 6]
+    ]?;
+
+    let options = IntervalJoinOptions {
+        left_by: Some(vec!["user".into()]),
+        right_by: Some(vec!["user".into()]),
+        ..Default::default()
+    };
+    let out = sessions
+        .lazy()
+        .join_interval(events.lazy(), col("start"), col("end"), col("ts"), options)
+        .select([col("user"), col("ts")])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("user")?.utf8()?),
+        &[Some("a"), Some("a"), Some("b")]
+    );
+    assert_eq!(
+        Vec::from(out.column("ts")?.i32()?),
+        &[Some(4), Some(6), Some(5)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_join_algorithm() -> PolarsResult<()> {
+    let left = df![
+        "a" => [3, 1, 2, 1],
+        "b" => ["x", "y", "z", "w"]
+    ]?;
+    let right = df![
+        "a" => [1, 3, 1],
+        "c" => [10, 30, 11]
+    ]?;
+
+    let join = |how: JoinType, algorithm: JoinAlgorithm| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .on([col("a")])
+            .how(how)
+            .algorithm(algorithm)
+            .finish()
+            .sort_by_exprs([col("b"), col("c")], [false, false], true)
+            .collect()
+    };
+    for how in [JoinType::Inner, JoinType::Left] {
+        let expected = join(how.clone(), JoinAlgorithm::Hash)?;
+        assert!(join(how.clone(), JoinAlgorithm::SortMerge)?.frame_equal_missing(&expected));
+        assert!(join(how, JoinAlgorithm::Auto)?.frame_equal_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_join_validation() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 1, 2]
+    ]?;
+    let right = df![
+        "a" => [1, 1, 2],
+        "c" => [10, 11, 20]
+    ]?;
+
+    let join = |on: &str, validate: JoinValidation| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col(on)])
+            .right_on([col("a")])
+            .how(JoinType::Left)
+            .validate(validate)
+            .finish()
+            .collect()
+    };
+    assert_eq!(join("a", JoinValidation::OneToMany)?.height(), 4);
+    assert!(join("a", JoinValidation::ManyToOne).is_err());
+    assert!(join("b", JoinValidation::OneToMany).is_err());
+    assert!(join("b", JoinValidation::OneToOne).is_err());
+    assert_eq!(join("b", JoinValidation::ManyToMany)?.height(), 5);
+
+    // rows with a null key are not duplicates
+    let left_nulls = df!["a" => [Some(1), None, None]]?;
+    let out = left_nulls
+        .lazy()
+        .join_builder()
+        .with(right.clone().lazy())
+        .on([col("a")])
+        .how(JoinType::Left)
+        .validate(JoinValidation::OneToMany)
+        .finish()
+        .collect()?;
+    assert_eq!(out.height(), 4);
+
+    // the combinations of multiple keys are validated
+    let multiple = |how: JoinType| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col("a"), col("b")])
+            .right_on([col("a"), col("c")])
+            .how(how)
+            .validate(JoinValidation::OneToOne)
+            .finish()
+            .collect()
+    };
+    assert_eq!(multiple(JoinType::Left)?.height(), 3);
+    assert_eq!(multiple(JoinType::Inner)?.height(), 0);
+    assert_eq!(multiple(JoinType::Outer)?.height(), 6);
+
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let semi = left
+            .lazy()
+            .join_builder()
+            .with(right.lazy())
+            .on([col("a")])
+            .how(JoinType::Semi)
+            .validate(JoinValidation::OneToOne)
+            .finish()
+            .collect();
+        assert!(semi.is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_gather_every() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5, 6, 7]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").gather_every(2, 1)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(2), Some(4), Some(6)]
+    );
+
+    // the filter should not be pushed down below the gather
+    let out = df
+        .lazy()
+        .gather_every(2, 0)
+        .filter(col("a").gt(lit(3)))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(5), Some(7)]);
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1898,3 +2339,93 @@ fn test_partitioned_gb_ternary() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_agg_all() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "x" => [1.0, 3.0, 5.0],
+        "y" => [2i32, 4, 6],
+        "s" => ["p", "q", "r"],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg_all(
+            dtype_cols([DataType::Float64, DataType::Int32]),
+            &[AggOp::Mean, AggOp::Max],
+        )
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["g", "x_mean", "y_mean", "x_max", "y_max"]
+    );
+    assert_eq!(
+        Vec::from(out.column("x_mean")?.f64()?),
+        &[Some(2.0), Some(5.0)]
+    );
+    assert_eq!(Vec::from(out.column("y_max")?.i32()?), &[Some(4), Some(6)]);
+
+    let out = df
+        .lazy()
+        .agg_all(
+            col("*").exclude(["g", "s"]),
+            &[AggOp::Sum, AggOp::Quantile(0.5)],
+        )
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["x_sum", "y_sum", "x_quantile_0.5", "y_quantile_0.5"]
+    );
+    assert_eq!(out.column("y_sum")?.i32()?.get(0), Some(12));
+    Ok(())
+}
+
+#[test]
+fn test_null_strategy() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "x" => [Some(1i64), None, Some(3)],
+        "y" => [Some(10i64), Some(20), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("x")
+                .add_with(col("y"), NullStrategy::Ignore)
+                .alias("ignore"),
+            col("x")
+                .add_with(col("y"), NullStrategy::Propagate)
+                .alias("propagate"),
+            sum_exprs_with([col("x"), col("y")], NullStrategy::Ignore),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("ignore")?.i64()?),
+        &[Some(11), Some(20), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("propagate")?.i64()?),
+        &[Some(11), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("sum")?.i64()?),
+        &[Some(11), Some(20), Some(3)]
+    );
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("x").sum_with(NullStrategy::Propagate),
+            col("y").sum_with(NullStrategy::Ignore),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("x")?.i64()?), &[None, Some(3)]);
+    assert_eq!(Vec::from(out.column("y")?.i64()?), &[Some(30), Some(0)]);
+    Ok(())
+}
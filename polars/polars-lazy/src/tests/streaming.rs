@@ -229,6 +229,19 @@ fn test_streaming_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_gather_every() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .filter(col("calories").gt(lit(50)))
+        .gather_every(3, 1);
+    assert_streaming_with_default(q);
+
+    // the chunks of the inputs of a union share their indices, so the rows are taken in a sink
+    let q = concat([get_csv_file(), get_csv_file()], false, false)?.gather_every(4, 6);
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_partial() -> PolarsResult<()> {
     let lf_left = df![
@@ -330,3 +343,169 @@ fn test_streaming_double_left_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "ipc"))]
+fn test_streaming_sink() -> PolarsResult<()> {
+    let q = get_parquet_file()
+        .filter(col("calories").gt(lit(50)))
+        .select([col("category"), col("calories") * lit(2)]);
+    let expected = q.clone().collect()?;
+
+    let path = std::env::temp_dir().join(format!(
+        "polars_test_streaming_sink_{}.parquet",
+        std::process::id()
+    ));
+    let options = ParquetWriteOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    q.clone().sink_parquet(path.clone(), options)?;
+    let out = LazyFrame::scan_parquet(&path, Default::default())?.collect()?;
+    assert!(out.frame_equal(&expected));
+    std::fs::remove_file(&path)?;
+
+    let path = std::env::temp_dir().join(format!(
+        "polars_test_streaming_sink_{}.ipc",
+        std::process::id()
+    ));
+    let options = IpcWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    q.sink_ipc(path.clone(), options)?;
+    let out = LazyFrame::scan_ipc(&path, Default::default())?.collect()?;
+    assert!(out.frame_equal(&expected));
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "csv"))]
+fn test_streaming_sink_csv() -> PolarsResult<()> {
+    let q = get_parquet_file()
+        .filter(col("calories").gt(lit(50)))
+        .select([col("category"), col("calories") * lit(2)]);
+    let expected = q.clone().collect()?;
+
+    let path = std::env::temp_dir().join(format!(
+        "polars_test_streaming_sink_{}.csv",
+        std::process::id()
+    ));
+    let options = CsvWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    q.clone().sink_csv(path.clone(), options)?;
+    let out = LazyCsvReader::new(&path).finish()?.collect()?;
+    assert!(out.frame_equal(&expected));
+
+    // the header is written even if there are no rows
+    let options = CsvWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    q.slice(0, 0).sink_csv(path.clone(), options)?;
+    assert_eq!(std::fs::read_to_string(&path)?, "category,calories\n");
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_streaming_follow_ndjson() -> PolarsResult<()> {
+    let path =
+        std::env::temp_dir().join(format!("polars_test_follow_{}.ndjson", std::process::id()));
+    std::fs::write(&path, "{\"a\":1}\n{\"a\":2}\n{\"a\":3}\nEOF\n{\"a\":4}\n")?;
+
+    let follow = polars_io::FollowOptions {
+        eof_marker: Some("EOF".to_string()),
+        ..Default::default()
+    };
+    let q = LazyJsonLineReader::new(path.to_string_lossy().into_owned())
+        .with_schema(Schema::from_iter([Field::new("a", DataType::Int64)]))
+        .with_row_count(Some(RowCount {
+            name: "row_nr".to_string(),
+            offset: 10,
+        }))
+        .with_follow(Some(follow))
+        .finish()?;
+    let expected = df![
+        "row_nr" => [10 as IdxSize, 11, 12],
+        "a" => [1i64, 2, 3]
+    ]?;
+    let out = q.clone().with_streaming(true).collect()?;
+    assert!(out.frame_equal(&expected));
+    let out = q.with_streaming(false).collect()?;
+    assert!(out.frame_equal(&expected));
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_streaming_window() -> PolarsResult<()> {
+    let q = get_parquet_file();
+
+    // the partitions are contiguous after the sort
+    let sorted = q
+        .clone()
+        .sort("category", Default::default())
+        .with_columns([
+            col("calories").sum().over([col("category")]).alias("sum"),
+            col("fats_g").cumsum(false).over([col("category")]),
+        ]);
+    assert_streaming_with_default(sorted);
+
+    let unsorted = q.select([
+        col("category"),
+        col("calories"),
+        col("calories").mean().over([col("category")]).alias("mean"),
+    ]);
+    assert_streaming_with_default(unsorted);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sorted_groupby() -> PolarsResult<()> {
+    let q = get_csv_glob();
+
+    let q = q
+        .sort_by_exprs([col("category"), col("calories")], [true, false], false)
+        .groupby_stable([col("calories"), col("category")])
+        .agg([
+            col("sugars_g").sum(),
+            col("fats_g").first(),
+            col("fats_g").last().alias("fats_g_last"),
+            count(),
+        ]);
+
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_groupby")]
+fn test_streaming_groupby_dynamic() -> PolarsResult<()> {
+    let q = get_csv_glob();
+
+    let q = q.sort("calories", Default::default()).groupby_dynamic(
+        [],
+        DynamicGroupOptions {
+            index_column: "calories".into(),
+            every: Duration::parse("20i"),
+            period: Duration::parse("50i"),
+            offset: Duration::parse("0i"),
+            include_boundaries: true,
+            ..Default::default()
+        },
+    );
+    let q = q.agg([
+        col("sugars_g").sum(),
+        col("fats_g").first(),
+        col("fats_g").last().alias("fats_g_last"),
+        count(),
+    ]);
+
+    assert_streaming_with_default(q);
+    Ok(())
+}
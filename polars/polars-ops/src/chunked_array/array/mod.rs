@@ -0,0 +1,17 @@
+use polars_core::prelude::*;
+
+mod namespace;
+mod top_k_nearest;
+
+pub use namespace::*;
+pub use top_k_nearest::DistanceMetric;
+
+pub trait AsArray {
+    fn as_array(&self) -> &ArrayChunked;
+}
+
+impl AsArray for ArrayChunked {
+    fn as_array(&self) -> &ArrayChunked {
+        self
+    }
+}
@@ -0,0 +1,187 @@
+use polars_arrow::kernels::distance;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::top_k_nearest::top_k_nearest;
+use super::*;
+
+/// The validity of the elements and the inner values of `ca`.
+fn rows_and_values(
+    ca: &ArrayChunked,
+    inner_dtype: &DataType,
+) -> PolarsResult<(BooleanChunked, Series)> {
+    let ca = ca.rechunk();
+    let values = ca.get_inner().cast(inner_dtype)?.rechunk();
+    Ok((ca.is_not_null(), values))
+}
+
+fn sum_rows<T>(ca: &ArrayChunked) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+{
+    let width = ca.width();
+    let (valid, values) = rows_and_values(ca, &T::get_dtype())?;
+    let values = values.unpack::<T>()?;
+    Ok(valid
+        .into_no_null_iter()
+        .enumerate()
+        .map(|(i, valid)| {
+            valid.then(|| {
+                (i * width..(i + 1) * width)
+                    .filter_map(|j| values.get(j))
+                    .sum()
+            })
+        })
+        .collect())
+}
+
+/// Apply `f` to the values of every element of `ca` and the element at the
+/// same position in `other`, which may also be a single element. The values
+/// are cast to `Float64` and null values are replaced by zeros, so `f` gets
+/// contiguous slices of the width of the arrays.
+fn pairwise_f64(
+    ca: &ArrayChunked,
+    other: &Series,
+    what: &str,
+    f: impl Fn(&[f64], &[f64]) -> f64,
+) -> PolarsResult<Series> {
+    let other = other.array()?;
+    polars_ensure!(
+        ca.width() == other.width(),
+        ShapeMismatch: "cannot compute {} of arrays of width {} and {}",
+        what, ca.width(), other.width()
+    );
+    let other = if other.len() == 1 && ca.len() != 1 {
+        other.new_from_index(0, ca.len())
+    } else {
+        polars_ensure!(
+            ca.len() == other.len(),
+            ShapeMismatch: "cannot compute {} of series of length {} and {}",
+            what, ca.len(), other.len()
+        );
+        other.clone()
+    };
+
+    let width = ca.width();
+    let (valid_l, values_l) = rows_and_values(ca, &DataType::Float64)?;
+    let (valid_r, values_r) = rows_and_values(&other, &DataType::Float64)?;
+    let values_l = values_l.f64()?.fill_null_with_values(0.0)?;
+    let values_r = values_r.f64()?.fill_null_with_values(0.0)?;
+    let (values_l, values_r) = (values_l.cont_slice()?, values_r.cont_slice()?);
+    let mut out: Float64Chunked = valid_l
+        .into_no_null_iter()
+        .zip(valid_r.into_no_null_iter())
+        .enumerate()
+        .map(|(i, (valid_l, valid_r))| {
+            let rows = i * width..(i + 1) * width;
+            (valid_l && valid_r).then(|| f(&values_l[rows.clone()], &values_r[rows]))
+        })
+        .collect();
+    out.rename(ca.name());
+    Ok(out.into_series())
+}
+
+pub trait ArrayNameSpace: AsArray {
+    /// Compute the sum of the values in every element. Small integers are
+    /// summed as `Int64`.
+    fn array_sum(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        let dtype = match ca.inner_dtype() {
+            DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+                DataType::Int64
+            }
+            dt if dt.is_numeric() && dt.is_primitive() => dt,
+            dt => polars_bail!(opq = array_sum, dt),
+        };
+        let mut out = with_match_physical_numeric_polars_type!(dtype, |$T| {
+            sum_rows::<$T>(ca)?.into_series()
+        });
+        out.rename(ca.name());
+        Ok(out)
+    }
+
+    /// Compute the mean of the values in every element.
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        polars_ensure!(
+            ca.inner_dtype().is_numeric(),
+            opq = array_mean,
+            ca.inner_dtype()
+        );
+        let width = ca.width();
+        let (valid, values) = rows_and_values(ca, &DataType::Float64)?;
+        let values = values.f64()?;
+        let mut out: Float64Chunked = valid
+            .into_no_null_iter()
+            .enumerate()
+            .map(|(i, valid)| {
+                if !valid {
+                    return None;
+                }
+                let (sum, count) = (i * width..(i + 1) * width)
+                    .filter_map(|j| values.get(j))
+                    .fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+                (count > 0).then(|| sum / count as f64)
+            })
+            .collect();
+        out.rename(ca.name());
+        Ok(out.into_series())
+    }
+
+    /// Get the value at position `idx` of every element. Negative indexing
+    /// is supported, out of bounds indices produce nulls.
+    fn array_get(&self, idx: i64) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        let width = ca.width() as i64;
+        let idx = if idx < 0 { width + idx } else { idx };
+        let ca = ca.rechunk();
+        let in_bounds = (0..width).contains(&idx);
+
+        let take_by: IdxCa = ca
+            .is_not_null()
+            .into_no_null_iter()
+            .enumerate()
+            .map(|(i, valid)| (valid && in_bounds).then(|| (i as i64 * width + idx) as IdxSize))
+            .collect();
+        let mut out = unsafe { ca.get_inner().take_unchecked(&take_by)? };
+        out.rename(ca.name());
+        Ok(out)
+    }
+
+    /// Compute the dot product of every element with the element at the same
+    /// position in `other`. Null values don't contribute to the product.
+    fn array_dot(&self, other: &Series) -> PolarsResult<Series> {
+        pairwise_f64(self.as_array(), other, "dot product", distance::dot)
+    }
+
+    /// Compute the cosine similarity of every element with the element at the
+    /// same position in `other`. Null values are treated as zeros.
+    fn array_cosine_similarity(&self, other: &Series) -> PolarsResult<Series> {
+        pairwise_f64(
+            self.as_array(),
+            other,
+            "cosine similarity",
+            distance::cosine_similarity,
+        )
+    }
+
+    /// Compute the euclidean distance of every element to the element at the
+    /// same position in `other`. Null values are treated as zeros.
+    fn array_l2_distance(&self, other: &Series) -> PolarsResult<Series> {
+        pairwise_f64(self.as_array(), other, "l2 distance", |a, b| {
+            distance::squared_l2_distance(a, b).sqrt()
+        })
+    }
+
+    /// Get the indices of the `k` elements that are nearest to `query` by the `metric`,
+    /// nearest first. Null elements are skipped and null values are treated as zeros.
+    fn array_top_k_nearest(
+        &self,
+        query: &[f64],
+        k: usize,
+        metric: DistanceMetric,
+    ) -> PolarsResult<IdxCa> {
+        top_k_nearest(self.as_array(), query, k, metric)
+    }
+}
+
+impl ArrayNameSpace for ArrayChunked {}
@@ -0,0 +1,192 @@
+//! Brute-force nearest neighbor search over the elements of an array column.
+//!
+//! The rows are scanned in parallel in blocks of [`ROWS_PER_TASK`], which are cast to `f64` one
+//! at a time and keep the `k` nearest rows in a bounded heap. The heaps of the blocks are merged
+//! at the end.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use arrow::array::{Array, FixedSizeListArray};
+use polars_arrow::kernels::distance;
+use polars_core::export::rayon::prelude::*;
+use polars_core::POOL;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// The number of rows that are scanned by a single task.
+const ROWS_PER_TASK: usize = 1 << 14;
+
+/// How the nearness of two arrays is measured.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DistanceMetric {
+    /// The highest cosine similarity is nearest.
+    Cosine,
+    /// The smallest euclidean distance is nearest.
+    L2,
+    /// The highest dot product is nearest.
+    Dot,
+}
+
+impl DistanceMetric {
+    /// A score of which the highest is nearest.
+    fn score(self, a: &[f64], b: &[f64]) -> f64 {
+        match self {
+            DistanceMetric::Cosine => distance::cosine_similarity(a, b),
+            DistanceMetric::L2 => -distance::squared_l2_distance(a, b),
+            DistanceMetric::Dot => distance::dot(a, b),
+        }
+    }
+}
+
+/// A scored row. The worse row is the greater, so that a `BinaryHeap` pops the worst row
+/// first. Of rows with the same score the later row is worse.
+struct Candidate {
+    score: f64,
+    idx: IdxSize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .total_cmp(&self.score)
+            .then(self.idx.cmp(&other.idx))
+    }
+}
+
+/// Push `candidate` if it is one of the `k` best rows seen so far.
+fn push_bounded(heap: &mut BinaryHeap<Candidate>, k: usize, candidate: Candidate) {
+    if heap.len() < k {
+        heap.push(candidate)
+    } else if let Some(mut worst) = heap.peek_mut() {
+        if candidate < *worst {
+            *worst = candidate
+        }
+    }
+}
+
+fn scan_rows(
+    arr: &FixedSizeListArray,
+    offset: usize,
+    rows: std::ops::Range<usize>,
+    query: &[f64],
+    k: usize,
+    metric: DistanceMetric,
+) -> PolarsResult<BinaryHeap<Candidate>> {
+    let width = query.len();
+    let values = arr.values().sliced(rows.start * width, rows.len() * width);
+    let values = Series::try_from(("", values))?.cast(&DataType::Float64)?;
+    let values = values.f64()?.fill_null_with_values(0.0)?.rechunk();
+    let values = values.cont_slice()?;
+
+    let mut heap = BinaryHeap::with_capacity(k.min(rows.len()) + 1);
+    for (i, row) in rows.zip(values.chunks_exact(width)) {
+        if arr.is_null(i) {
+            continue;
+        }
+        let score = metric.score(row, query);
+        if !score.is_nan() {
+            let idx = (offset + i) as IdxSize;
+            push_bounded(&mut heap, k, Candidate { score, idx });
+        }
+    }
+    Ok(heap)
+}
+
+pub(super) fn top_k_nearest(
+    ca: &ArrayChunked,
+    query: &[f64],
+    k: usize,
+    metric: DistanceMetric,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        ca.inner_dtype().is_numeric(),
+        opq = top_k_nearest,
+        ca.inner_dtype()
+    );
+    polars_ensure!(
+        query.len() == ca.width(),
+        ShapeMismatch: "cannot compare a query of length {} with arrays of width {}",
+        query.len(), ca.width()
+    );
+    polars_ensure!(
+        ca.width() > 0,
+        InvalidOperation: "cannot search the nearest arrays of width 0"
+    );
+
+    let mut tasks = vec![];
+    let mut offset = 0;
+    for arr in ca.downcast_iter() {
+        for start in (0..arr.len()).step_by(ROWS_PER_TASK) {
+            tasks.push((arr, offset, start..(start + ROWS_PER_TASK).min(arr.len())));
+        }
+        offset += arr.len();
+    }
+    let heaps = POOL.install(|| {
+        tasks
+            .into_par_iter()
+            .map(|(arr, offset, rows)| scan_rows(arr, offset, rows, query, k, metric))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let mut nearest = BinaryHeap::with_capacity(k.min(ca.len()) + 1);
+    for candidate in heaps.into_iter().flatten() {
+        push_bounded(&mut nearest, k, candidate);
+    }
+    let idx = nearest
+        .into_sorted_vec()
+        .into_iter()
+        .map(|candidate| candidate.idx)
+        .collect();
+    Ok(IdxCa::from_vec(ca.name(), idx))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::ArrayNameSpace;
+
+    #[test]
+    fn test_top_k_nearest() -> PolarsResult<()> {
+        let dtype = DataType::Array(Box::new(DataType::Float32), 2);
+        let to_array = |values: &[Option<f32>]| -> PolarsResult<Series> {
+            Series::new("emb", values).reshape(&[-1, 2])?.cast(&dtype)
+        };
+        let mut s = to_array(&[Some(1.0), Some(0.0), Some(0.0), Some(1.0)])?;
+        s.append(&to_array(&[Some(1.0), Some(1.0), Some(3.0), None])?)?;
+        s.append(&Series::full_null("emb", 1, &dtype))?;
+        let ca = s.array()?;
+        assert_eq!(ca.chunks().len(), 3);
+
+        let query = [2.0, 0.0];
+        let out = ca.array_top_k_nearest(&query, 3, DistanceMetric::L2)?;
+        assert_eq!(Vec::from(&out), &[Some(0), Some(3), Some(2)]);
+        // the rows [1.0, 0.0] and [3.0, 0.0] point in the same direction
+        let out = ca.array_top_k_nearest(&query, 2, DistanceMetric::Cosine)?;
+        assert_eq!(Vec::from(&out), &[Some(0), Some(3)]);
+        let out = ca.array_top_k_nearest(&query, 10, DistanceMetric::Dot)?;
+        assert_eq!(Vec::from(&out), &[Some(3), Some(0), Some(2), Some(1)]);
+
+        assert!(ca
+            .array_top_k_nearest(&[1.0], 1, DistanceMetric::Dot)
+            .is_err());
+        Ok(())
+    }
+}
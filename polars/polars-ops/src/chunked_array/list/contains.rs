@@ -0,0 +1,123 @@
+use std::hash::Hash;
+
+use arrow::array::{Array, BooleanArray};
+use arrow::bitmap::Bitmap;
+use polars_core::utils::_to_physical_and_bit_repr;
+
+use super::*;
+
+/// Map every inner value to the index of the (distinct) needle it equals.
+/// Null needles never match.
+fn needle_ids<K, I, J>(values: I, needles: J) -> (Vec<Option<IdxSize>>, usize)
+where
+    K: Hash + Eq,
+    I: Iterator<Item = Option<K>>,
+    J: Iterator<Item = Option<K>>,
+{
+    let mut map = PlHashMap::new();
+    for needle in needles.flatten() {
+        let id = map.len() as IdxSize;
+        map.entry(needle).or_insert(id);
+    }
+    let ids = values
+        .map(|opt_v| opt_v.and_then(|v| map.get(&v).copied()))
+        .collect();
+    (ids, map.len())
+}
+
+fn inner_needle_ids(
+    values: &Series,
+    needles: &Series,
+) -> PolarsResult<(Vec<Option<IdxSize>>, usize)> {
+    let needles = match needles.dtype() {
+        DataType::List(_) => needles.explode()?,
+        _ => needles.clone(),
+    };
+    // Categoricals may have different rev-maps, so we compare them on their string values.
+    let values = match values.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => values.cast(&DataType::Utf8)?,
+        _ => values.clone(),
+    };
+    let needles = needles.cast(values.dtype())?;
+
+    let mut physical = _to_physical_and_bit_repr(&[values, needles]);
+    let needles = physical.pop().unwrap();
+    let values = physical.pop().unwrap();
+
+    macro_rules! numeric {
+        ($method:ident) => {
+            needle_ids(
+                values.$method().unwrap().into_iter(),
+                needles.$method().unwrap().into_iter(),
+            )
+        };
+    }
+
+    use DataType::*;
+    let out = match values.dtype() {
+        Boolean => numeric!(bool),
+        Utf8 => numeric!(utf8),
+        Binary => numeric!(binary),
+        UInt32 => numeric!(u32),
+        UInt64 => numeric!(u64),
+        #[cfg(feature = "dtype-u8")]
+        UInt8 => numeric!(u8),
+        #[cfg(feature = "dtype-u16")]
+        UInt16 => numeric!(u16),
+        #[cfg(feature = "dtype-i8")]
+        Int8 => numeric!(i8),
+        #[cfg(feature = "dtype-i16")]
+        Int16 => numeric!(i16),
+        dt => polars_bail!(opq = contains_any, dt),
+    };
+    Ok(out)
+}
+
+fn list_contains_multi(ca: &ListChunked, needles: &Series, all: bool) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let (ids, n_needles) = inner_needle_ids(&ca.get_inner(), needles)?;
+    let arr = ca.downcast_iter().next().unwrap();
+
+    // Stamped with the row index + 1, so that we don't have to reset it for every row.
+    let mut seen = vec![0usize; if all { n_needles } else { 0 }];
+    let values: Bitmap = arr
+        .offsets()
+        .as_slice()
+        .windows(2)
+        .enumerate()
+        .map(|(row, w)| {
+            let ids = &ids[w[0] as usize..w[1] as usize];
+            if all {
+                let stamp = row + 1;
+                let mut n_found = 0;
+                for id in ids.iter().flatten() {
+                    let mark = &mut seen[*id as usize];
+                    if *mark != stamp {
+                        *mark = stamp;
+                        n_found += 1;
+                    }
+                }
+                n_found == n_needles
+            } else {
+                ids.iter().any(|id| id.is_some())
+            }
+        })
+        .collect();
+
+    let out = BooleanArray::new(ArrowDataType::Boolean, values, arr.validity().cloned());
+    let out = unsafe { BooleanChunked::from_chunks(ca.name(), vec![Box::new(out)]) };
+    Ok(out.into_series())
+}
+
+/// For every list, check whether it contains at least one of the `needles`.
+/// Null lists stay null and null needles are ignored.
+pub fn list_contains_any(ca: &ListChunked, needles: &Series) -> PolarsResult<Series> {
+    list_contains_multi(ca, needles, false)
+}
+
+/// For every list, check whether it contains every one of the `needles`.
+/// Null lists stay null and null needles are ignored.
+pub fn list_contains_all(ca: &ListChunked, needles: &Series) -> PolarsResult<Series> {
+    list_contains_multi(ca, needles, true)
+}
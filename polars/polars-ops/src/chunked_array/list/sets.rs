@@ -0,0 +1,223 @@
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+use arrow::array::{Array, ListArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::Offsets;
+use polars_core::utils::{_to_physical_and_bit_repr, try_get_supertype};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl Display for SetOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SetOperation::Union => "union",
+            SetOperation::Intersection => "intersection",
+            SetOperation::Difference => "difference",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Number the distinct values of `a` and `b` together. Null is a value as well.
+fn value_ids<K, I, J>(a: I, b: J) -> (Vec<IdxSize>, Vec<IdxSize>, usize)
+where
+    K: Hash + Eq,
+    I: Iterator<Item = Option<K>>,
+    J: Iterator<Item = Option<K>>,
+{
+    let mut map = PlHashMap::new();
+    let mut id = |v: Option<K>| {
+        let next = map.len() as IdxSize;
+        *map.entry(v).or_insert(next)
+    };
+    let a = a.map(&mut id).collect();
+    let b = b.map(&mut id).collect();
+    (a, b, map.len())
+}
+
+fn inner_value_ids(a: &Series, b: &Series) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>, usize)> {
+    let mut physical = _to_physical_and_bit_repr(&[a.clone(), b.clone()]);
+    let b = physical.pop().unwrap();
+    let a = physical.pop().unwrap();
+
+    macro_rules! ids {
+        ($method:ident) => {
+            value_ids(
+                a.$method().unwrap().into_iter(),
+                b.$method().unwrap().into_iter(),
+            )
+        };
+    }
+
+    use DataType::*;
+    let out = match a.dtype() {
+        Boolean => ids!(bool),
+        Utf8 => ids!(utf8),
+        Binary => ids!(binary),
+        UInt32 => ids!(u32),
+        UInt64 => ids!(u64),
+        #[cfg(feature = "dtype-u8")]
+        UInt8 => ids!(u8),
+        #[cfg(feature = "dtype-u16")]
+        UInt16 => ids!(u16),
+        #[cfg(feature = "dtype-i8")]
+        Int8 => ids!(i8),
+        #[cfg(feature = "dtype-i16")]
+        Int16 => ids!(i16),
+        dt => polars_bail!(opq = list_set_operation, dt),
+    };
+    Ok(out)
+}
+
+/// Compute the set `op` of every list of `a` with the list at the same position in `b`,
+/// either of which may also be a single list. The values of the output lists are distinct
+/// and ordered by their first occurrence in `a`, and then in `b`. A null list gives a null
+/// list and categorical values are compared, and returned, as strings.
+pub fn list_set_operation(
+    a: &ListChunked,
+    b: &ListChunked,
+    op: SetOperation,
+) -> PolarsResult<ListChunked> {
+    let broadcast_a = a.len() == 1 && b.len() != 1;
+    let broadcast_b = b.len() == 1 && a.len() != 1;
+    polars_ensure!(
+        broadcast_a || broadcast_b || a.len() == b.len(),
+        ShapeMismatch: "cannot compute the set {} of lists of length {} and {}",
+        op, a.len(), b.len()
+    );
+    let len = if broadcast_a { b.len() } else { a.len() };
+
+    let inner_dtype = match try_get_supertype(&a.inner_dtype(), &b.inner_dtype())? {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => DataType::Utf8,
+        dt => dt,
+    };
+    let dtype = DataType::List(Box::new(inner_dtype.clone()));
+    let (a, b) = (a.cast(&dtype)?, b.cast(&dtype)?);
+    let (a, b) = (a.list()?.rechunk(), b.list()?.rechunk());
+    let (a_values, b_values) = (a.get_inner(), b.get_inner());
+    let (a_ids, b_ids, n_ids) = inner_value_ids(&a_values, &b_values)?;
+    let a_arr = a.downcast_iter().next().unwrap();
+    let b_arr = b.downcast_iter().next().unwrap();
+    let (a_offsets, b_offsets) = (a_arr.offsets().as_slice(), b_arr.offsets().as_slice());
+
+    // Stamped with the row index + 1, so that we don't have to reset them for every row.
+    let mut in_b = vec![0usize; n_ids];
+    let mut taken = vec![0usize; n_ids];
+    // indices into the values of `a` followed by the values of `b`
+    let mut take = Vec::<IdxSize>::new();
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(len);
+    for row in 0..len {
+        let i = if broadcast_a { 0 } else { row };
+        let j = if broadcast_b { 0 } else { row };
+        let valid = a_arr.is_valid(i) && b_arr.is_valid(j);
+        validity.push(valid);
+        if valid {
+            let stamp = row + 1;
+            let a_rows = a_offsets[i] as usize..a_offsets[i + 1] as usize;
+            let b_rows = b_offsets[j] as usize..b_offsets[j + 1] as usize;
+            match op {
+                SetOperation::Union => {
+                    let a_idx = a_rows.map(|k| (a_ids[k], k));
+                    let b_idx = b_rows.map(|k| (b_ids[k], a_values.len() + k));
+                    for (id, k) in a_idx.chain(b_idx) {
+                        if taken[id as usize] != stamp {
+                            taken[id as usize] = stamp;
+                            take.push(k as IdxSize);
+                        }
+                    }
+                }
+                SetOperation::Intersection | SetOperation::Difference => {
+                    for id in &b_ids[b_rows] {
+                        in_b[*id as usize] = stamp;
+                    }
+                    let keep_in_b = op == SetOperation::Intersection;
+                    for k in a_rows {
+                        let id = a_ids[k] as usize;
+                        if (in_b[id] == stamp) == keep_in_b && taken[id] != stamp {
+                            taken[id] = stamp;
+                            take.push(k as IdxSize);
+                        }
+                    }
+                }
+            }
+        }
+        offsets.push(take.len() as i64);
+    }
+
+    let mut values = a_values;
+    values.append(&b_values)?;
+    // Safety: the indices are in bounds of the values.
+    let values = unsafe { values.take_unchecked(&IdxCa::from_vec("", take))? }.rechunk();
+    let values = values.array_ref(0).clone();
+    let data_type = ListArray::<i64>::default_datatype(values.data_type().clone());
+    // Safety: the offsets are monotonically increasing and in bounds of the values.
+    let arr = unsafe {
+        ListArray::new(
+            data_type,
+            Offsets::new_unchecked(offsets).into(),
+            values,
+            Some(validity.into()),
+        )
+    };
+    let mut out = unsafe { ListChunked::from_chunks(a.name(), vec![Box::new(arr)]) };
+    out.to_logical(inner_dtype);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_lists(rows: &[&[Option<i32>]]) -> ListChunked {
+        let rows = rows
+            .iter()
+            .map(|row| Series::new("", *row))
+            .collect::<Vec<_>>();
+        Series::new("a", rows).list().unwrap().clone()
+    }
+
+    #[test]
+    fn test_list_set_operation() -> PolarsResult<()> {
+        let a = to_lists(&[&[Some(1), Some(2), Some(2), None], &[Some(3)], &[]]);
+        let b = to_lists(&[&[Some(2), Some(4)], &[Some(3), Some(5)], &[Some(1)]]);
+
+        let get = |ca: &ListChunked| -> Vec<Vec<Option<i32>>> {
+            ca.into_iter()
+                .map(|s| Vec::from(s.unwrap().i32().unwrap()))
+                .collect()
+        };
+        let out = list_set_operation(&a, &b, SetOperation::Union)?;
+        assert_eq!(
+            get(&out),
+            &[
+                vec![Some(1), Some(2), None, Some(4)],
+                vec![Some(3), Some(5)],
+                vec![Some(1)]
+            ]
+        );
+        let out = list_set_operation(&a, &b, SetOperation::Intersection)?;
+        assert_eq!(get(&out), &[vec![Some(2)], vec![Some(3)], vec![]]);
+        let out = list_set_operation(&a, &b, SetOperation::Difference)?;
+        assert_eq!(get(&out), &[vec![Some(1), None], vec![], vec![]]);
+
+        // a single list is compared with every list
+        let single = to_lists(&[&[Some(3), Some(1)]]);
+        let out = list_set_operation(&a, &single, SetOperation::Intersection)?;
+        assert_eq!(get(&out), &[vec![Some(1)], vec![Some(3)], vec![]]);
+        Ok(())
+    }
+}
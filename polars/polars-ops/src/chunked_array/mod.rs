@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-array")]
+pub mod array;
 mod binary;
 #[cfg(feature = "interpolate")]
 mod interpolate;
@@ -9,6 +11,8 @@ mod strings;
 #[cfg(feature = "top_k")]
 mod top_k;
 
+#[cfg(feature = "dtype-array")]
+pub use array::*;
 pub use binary::*;
 #[cfg(feature = "interpolate")]
 pub use interpolate::*;
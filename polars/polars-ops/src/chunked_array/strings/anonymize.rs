@@ -0,0 +1,73 @@
+use std::fmt::Write;
+
+use polars_core::prelude::Utf8Chunked;
+use sha2::{Digest, Sha256};
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+pub(super) fn mask<'a>(
+    ca: &'a Utf8Chunked,
+    keep_prefix: usize,
+    keep_suffix: usize,
+    mask_char: char,
+) -> Utf8Chunked {
+    // amortize allocation
+    let mut buf = String::new();
+    let f = |s: &'a str| {
+        let n_chars = s.chars().count();
+        buf.clear();
+        // values that are too short to hide anything are masked completely
+        // so that we never leak a full value
+        if n_chars <= keep_prefix + keep_suffix {
+            for _ in 0..n_chars {
+                buf.push(mask_char)
+            }
+        } else {
+            for (i, c) in s.chars().enumerate() {
+                if i < keep_prefix || i >= n_chars - keep_suffix {
+                    buf.push(c)
+                } else {
+                    buf.push(mask_char)
+                }
+            }
+        }
+        // extend lifetime
+        // lifetime is bound to 'a
+        let slice = buf.as_str();
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
+pub(super) fn hash_sha256<'a>(ca: &'a Utf8Chunked, salt: &str) -> Utf8Chunked {
+    // amortize allocation
+    let mut buf = String::with_capacity(64);
+    let mut salted = Sha256::new();
+    salted.update(salt.as_bytes());
+    let f = |s: &'a str| {
+        let mut hasher = salted.clone();
+        hasher.update(s.as_bytes());
+        buf.clear();
+        for byte in hasher.finalize() {
+            write!(buf, "{byte:02x}").unwrap();
+        }
+        // extend lifetime
+        // lifetime is bound to 'a
+        let slice = buf.as_str();
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
+pub(super) fn pseudonymize<'a>(ca: &'a Utf8Chunked, seed: u64) -> Utf8Chunked {
+    // amortize allocation
+    let mut buf = String::with_capacity(16);
+    let f = |s: &'a str| {
+        buf.clear();
+        write!(buf, "{:016x}", xxh3_64_with_seed(s.as_bytes(), seed)).unwrap();
+        // extend lifetime
+        // lifetime is bound to 'a
+        let slice = buf.as_str();
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "string_anonymize")]
+mod anonymize;
 #[cfg(feature = "strings")]
 mod case;
 #[cfg(feature = "extract_jsonpath")]
@@ -8,6 +10,8 @@ mod justify;
 mod namespace;
 #[cfg(feature = "strings")]
 mod replace;
+#[cfg(feature = "string_to_number")]
+mod to_number;
 
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
@@ -132,6 +132,57 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         justify::rjust(ca, width, fillchar)
     }
 
+    /// Replace all but the first `keep_prefix` and the last `keep_suffix` characters
+    /// with `mask_char`. Values that are too short to keep anything are masked entirely.
+    #[cfg(feature = "string_anonymize")]
+    fn mask(&self, keep_prefix: usize, keep_suffix: usize, mask_char: char) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        anonymize::mask(ca, keep_prefix, keep_suffix, mask_char)
+    }
+
+    /// Hex encoded SHA-256 digest of the string values prefixed with `salt`.
+    #[cfg(feature = "string_anonymize")]
+    fn hash_sha256(&self, salt: &str) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        anonymize::hash_sha256(ca, salt)
+    }
+
+    /// Replace every string value with a 16 character token derived from the value and `seed`.
+    /// Equal values always map to the same token for a given `seed`.
+    #[cfg(feature = "string_anonymize")]
+    fn pseudonymize(&self, seed: u64) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        anonymize::pseudonymize(ca, seed)
+    }
+
+    /// Parse formatted numbers, e.g. `"$1,234.50"`, as floats. The `thousands_separator` and the
+    /// `currency_symbols` are removed before parsing. Values that cannot be parsed are null, or
+    /// raise an error if `strict`.
+    #[cfg(feature = "string_to_number")]
+    fn to_float(
+        &self,
+        thousands_separator: Option<&str>,
+        currency_symbols: &[String],
+        strict: bool,
+    ) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_utf8();
+        to_number::to_float(ca, thousands_separator, currency_symbols, strict)
+    }
+
+    /// Parse formatted numbers, e.g. `"$1,234.50"`, as decimals with the given `scale`. The digits
+    /// beyond the scale are truncated. See [`Utf8NameSpaceImpl::to_float`].
+    #[cfg(all(feature = "string_to_number", feature = "dtype-decimal"))]
+    fn to_decimal(
+        &self,
+        scale: usize,
+        thousands_separator: Option<&str>,
+        currency_symbols: &[String],
+        strict: bool,
+    ) -> PolarsResult<DecimalChunked> {
+        let ca = self.as_utf8();
+        to_number::to_decimal(ca, scale, thousands_separator, currency_symbols, strict)
+    }
+
     /// Check if strings contain a regex pattern.
     fn contains(&self, pat: &str, strict: bool) -> PolarsResult<BooleanChunked> {
         let ca = self.as_utf8();
@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+
+use polars_arrow::utils::CustomIterTools;
+use polars_core::prelude::*;
+
+/// Remove the thousands separators, currency symbols and surrounding whitespace of a number,
+/// e.g. `"$ 1,234.50"` -> `"1234.50"`.
+fn clean_number<'a>(
+    s: &'a str,
+    thousands_separator: Option<&str>,
+    currency_symbols: &[String],
+) -> Cow<'a, str> {
+    let mut out = Cow::Borrowed(s.trim());
+    let patterns = thousands_separator
+        .into_iter()
+        .chain(currency_symbols.iter().map(|symbol| symbol.as_str()))
+        .filter(|pat| !pat.is_empty());
+    for pat in patterns {
+        if out.contains(pat) {
+            out = Cow::Owned(out.replace(pat, ""));
+        }
+    }
+    match out {
+        Cow::Owned(s) => Cow::Owned(s.trim().to_string()),
+        borrowed => borrowed,
+    }
+}
+
+/// Parse a decimal number to an integer scaled by `10^scale`. The digits beyond the scale
+/// are truncated.
+#[cfg(feature = "dtype-decimal")]
+fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let fraction = &fraction[..fraction.len().min(scale)];
+
+    let mut value = 0i128;
+    for b in integer.bytes().chain(fraction.bytes()) {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value.checked_mul(10)?.checked_add((b - b'0') as i128)?;
+    }
+    value = value.checked_mul(10i128.checked_pow((scale - fraction.len()) as u32)?)?;
+    Some(if negative { -value } else { value })
+}
+
+/// Raise an error if a value that is not null could not be parsed.
+fn check_strict(ca: &Utf8Chunked, out_is_null: BooleanChunked, dtype: &str) -> PolarsResult<()> {
+    let failure_mask = !ca.is_null() & out_is_null;
+    let all_failures = ca.filter(&failure_mask)?;
+    if all_failures.is_empty() {
+        return Ok(());
+    }
+    let some_failures = all_failures.unique()?.slice(0, 10).sort(false);
+    polars_bail!(
+        ComputeError:
+        "strict conversion to {} failed for {} value(s): {} (consider non-strict parsing)",
+        dtype,
+        all_failures.len(),
+        some_failures.into_series().fmt_list(),
+    )
+}
+
+pub(super) fn to_float(
+    ca: &Utf8Chunked,
+    thousands_separator: Option<&str>,
+    currency_symbols: &[String],
+    strict: bool,
+) -> PolarsResult<Float64Chunked> {
+    let mut out: Float64Chunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            opt_s.and_then(|s| {
+                clean_number(s, thousands_separator, currency_symbols)
+                    .parse::<f64>()
+                    .ok()
+            })
+        })
+        .collect_trusted();
+    if strict && ca.null_count() != out.null_count() {
+        check_strict(ca, out.is_null(), "float")?;
+    }
+    out.rename(ca.name());
+    Ok(out)
+}
+
+#[cfg(feature = "dtype-decimal")]
+pub(super) fn to_decimal(
+    ca: &Utf8Chunked,
+    scale: usize,
+    thousands_separator: Option<&str>,
+    currency_symbols: &[String],
+    strict: bool,
+) -> PolarsResult<DecimalChunked> {
+    let mut out: Int128Chunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            opt_s.and_then(|s| {
+                parse_decimal(
+                    &clean_number(s, thousands_separator, currency_symbols),
+                    scale,
+                )
+            })
+        })
+        .collect_trusted();
+    if strict && ca.null_count() != out.null_count() {
+        check_strict(ca, out.is_null(), "decimal")?;
+    }
+    out.rename(ca.name());
+    out.into_decimal(None, scale)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clean_number() {
+        let symbols = ["$".to_string(), "EUR".to_string()];
+        assert_eq!(clean_number(" $1,234.50 ", Some(","), &symbols), "1234.50");
+        assert_eq!(clean_number("-1.234 EUR", Some("."), &symbols), "-1234");
+        assert_eq!(clean_number("12", Some(","), &symbols), "12");
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("1234.5", 2), Some(123450));
+        assert_eq!(parse_decimal("-0.129", 2), Some(-12));
+        assert_eq!(parse_decimal(".5", 1), Some(5));
+        assert_eq!(parse_decimal("12", 0), Some(12));
+        assert_eq!(parse_decimal("1e3", 0), None);
+        assert_eq!(parse_decimal("-", 2), None);
+    }
+}
@@ -0,0 +1,143 @@
+use polars_core::prelude::*;
+
+use super::*;
+
+const LEFT_INDICATOR: &str = "__POLARS_CMP_LEFT";
+const RIGHT_INDICATOR: &str = "__POLARS_CMP_RIGHT";
+const RIGHT_SUFFIX: &str = "__POLARS_CMP_RIGHT_VALUE";
+
+/// The result of [`DataFrameOps::compare`].
+#[derive(Clone, Debug)]
+pub struct DataFrameComparison {
+    /// Rows of which the key only occurs in the `other` `DataFrame`.
+    pub added: DataFrame,
+    /// Rows of which the key only occurs in the `self` `DataFrame`.
+    pub removed: DataFrame,
+    /// One row per key with at least one changed cell: the key columns and a
+    /// `"<column>_left"` and `"<column>_right"` column per compared column, in the
+    /// dtypes of the input. Cells that didn't change are null on both sides.
+    pub changed: DataFrame,
+}
+
+impl DataFrameComparison {
+    /// Returns `true` if both `DataFrame`s were equal on the compared columns.
+    pub fn is_empty(&self) -> bool {
+        self.added.height() == 0 && self.removed.height() == 0 && self.changed.height() == 0
+    }
+}
+
+/// Null aware inequality; two nulls are considered equal.
+fn cells_changed(left: &Series, right: &Series) -> PolarsResult<BooleanChunked> {
+    let ne = left.not_equal(right)?.fill_null_with_values(false)?;
+    let null_differs = &left.is_null() ^ &right.is_null();
+    Ok(&ne | &null_differs)
+}
+
+pub(super) fn compare(
+    left: &DataFrame,
+    right: &DataFrame,
+    on: &[String],
+) -> PolarsResult<DataFrameComparison> {
+    polars_ensure!(!on.is_empty(), ComputeError: "'compare' needs at least one key column");
+    for name in on {
+        let (l, r) = (left.column(name)?, right.column(name)?);
+        polars_ensure!(
+            l.dtype() == r.dtype(),
+            SchemaMismatch: "key column '{}' has dtype {} on the left and {} on the right",
+            name, l.dtype(), r.dtype()
+        );
+    }
+    for (df, side) in [(left, "left"), (right, "right")] {
+        polars_ensure!(
+            !df.select(on)?.is_duplicated()?.any(),
+            Duplicate: "the keys of 'compare' must be unique, the {} side has duplicates", side
+        );
+    }
+    let is_key = |name: &str| on.iter().any(|key| key == name);
+
+    let left_values = left
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !is_key(name))
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    let right_values = right
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !is_key(name))
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+
+    // Rename the value columns of the right frame so that the join never has to
+    // apply a suffix and we always know where to find them.
+    let mut right_renamed = right.clone();
+    for name in &right_values {
+        right_renamed.rename(name, &format!("{name}{RIGHT_SUFFIX}"))?;
+    }
+    let mut left_marked = left.clone();
+    left_marked.with_column(BooleanChunked::full(LEFT_INDICATOR, true, left.height()))?;
+    right_renamed.with_column(BooleanChunked::full(RIGHT_INDICATOR, true, right.height()))?;
+
+    let joined = left_marked.join(&right_renamed, on, on, JoinType::Outer, None)?;
+    let in_left = joined.column(LEFT_INDICATOR)?.is_not_null();
+    let in_right = joined.column(RIGHT_INDICATOR)?.is_not_null();
+
+    let removed = joined
+        .filter(&(&in_left & &!&in_right))?
+        .select(on.iter().chain(left_values.iter()))?;
+
+    let mut added = joined.filter(&(&!&in_left & &in_right))?.select(
+        on.iter().cloned().chain(
+            right_values
+                .iter()
+                .map(|name| format!("{name}{RIGHT_SUFFIX}")),
+        ),
+    )?;
+    for name in &right_values {
+        added.rename(&format!("{name}{RIGHT_SUFFIX}"), name)?;
+    }
+
+    let in_both = &in_left & &in_right;
+    let compared = left_values
+        .iter()
+        .filter(|name| right_values.contains(name))
+        .collect::<Vec<_>>();
+    let masks = compared
+        .iter()
+        .map(|name| {
+            let l = joined.column(name)?;
+            let r = joined.column(&format!("{name}{RIGHT_SUFFIX}"))?;
+            Ok(&in_both & &cells_changed(l, r)?)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let any_changed = masks.iter().fold(
+        BooleanChunked::full("", false, joined.height()),
+        |acc, mask| &acc | mask,
+    );
+
+    let mut columns = joined.select_series(on)?;
+    for s in columns.iter_mut() {
+        *s = s.filter(&any_changed)?;
+    }
+    for (name, mask) in compared.iter().zip(&masks) {
+        let mask = mask.filter(&any_changed)?;
+        let sides = [
+            (name.to_string(), "left"),
+            (format!("{name}{RIGHT_SUFFIX}"), "right"),
+        ];
+        for (column, side) in sides {
+            let s = joined.column(&column)?.filter(&any_changed)?;
+            let null = Series::full_null("", s.len(), s.dtype());
+            let mut s = s.zip_with(&mask, &null)?;
+            s.rename(&format!("{name}_{side}"));
+            columns.push(s);
+        }
+    }
+    let changed = DataFrame::new_no_checks(columns);
+
+    Ok(DataFrameComparison {
+        added,
+        removed,
+        changed,
+    })
+}
@@ -0,0 +1,238 @@
+//! Inner joins of the intervals of the left table to the points of the right
+//! table that fall within them.
+//!
+//! The points are sorted by their `by` group and their value once, so that the
+//! points within an interval are a contiguous range that is found with two
+//! binary searches.
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+/// `false` for NaN, which does not fall within any interval.
+#[inline]
+fn is_comparable<T: PartialOrd>(v: &T) -> bool {
+    v.partial_cmp(v).is_some()
+}
+
+/// Find the pairs of rows where the point falls within the interval. The
+/// output is ordered by the left rows, and the matches of a left row by the
+/// points.
+fn interval_join_ids<T>(
+    start: &ChunkedArray<T>,
+    end: &ChunkedArray<T>,
+    points: &ChunkedArray<T>,
+    closed: ClosedInterval,
+    groups: Option<&(Vec<IdxSize>, Vec<IdxSize>)>,
+) -> (Vec<IdxSize>, Vec<IdxSize>)
+where
+    T: PolarsNumericType,
+{
+    let left_group = |i: usize| groups.map_or(0, |(left, _)| left[i]);
+    let right_group = |i: usize| groups.map_or(0, |(_, right)| right[i]);
+
+    let mut sorted = points
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, v)| {
+            let v = v?;
+            is_comparable(&v).then_some((right_group(i), v, i as IdxSize))
+        })
+        .collect::<Vec<_>>();
+    // NaN's are filtered out, so the points are totally ordered
+    sorted.sort_unstable_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then(a.1.partial_cmp(&b.1).unwrap())
+            .then(a.2.cmp(&b.2))
+    });
+
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    for (i, (s, e)) in start.into_iter().zip(end).enumerate() {
+        let (Some(s), Some(e)) = (s, e) else {
+            continue;
+        };
+        if !is_comparable(&s) || !is_comparable(&e) {
+            continue;
+        }
+        let g = left_group(i);
+        let lo = match closed {
+            ClosedInterval::Left | ClosedInterval::Both => {
+                sorted.partition_point(|r| (r.0, r.1) < (g, s))
+            }
+            ClosedInterval::Right | ClosedInterval::None => {
+                sorted.partition_point(|r| (r.0, r.1) <= (g, s))
+            }
+        };
+        let hi = match closed {
+            ClosedInterval::Right | ClosedInterval::Both => {
+                sorted.partition_point(|r| (r.0, r.1) <= (g, e))
+            }
+            ClosedInterval::Left | ClosedInterval::None => {
+                sorted.partition_point(|r| (r.0, r.1) < (g, e))
+            }
+        };
+        if lo < hi {
+            left_idx.extend(std::iter::repeat(i as IdxSize).take(hi - lo));
+            right_idx.extend(sorted[lo..hi].iter().map(|r| r.2));
+        }
+    }
+    (left_idx, right_idx)
+}
+
+/// Number the groups of the `by` columns of both tables, so that the rows of
+/// both tables that are equal on them get the same group id.
+fn group_ids(
+    left_by: &[Series],
+    right_by: &[Series],
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+    polars_ensure!(
+        !left_by.is_empty() && left_by.len() == right_by.len(),
+        ComputeError: "an interval join needs the same number of `by` columns on both sides"
+    );
+    let keys = left_by
+        .iter()
+        .zip(right_by)
+        .map(|(l, r)| {
+            let dtype = try_get_supertype(l.dtype(), r.dtype())?;
+            let mut s = l.cast(&dtype)?;
+            s.append(&r.cast(&dtype)?)?;
+            Ok(s)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let n_left = left_by[0].len();
+    let groups = DataFrame::new_no_checks(vec![])
+        .groupby_with_series(keys, true, false)?
+        .take_groups()
+        .into_idx();
+
+    let mut ids = vec![0 as IdxSize; n_left + right_by[0].len()];
+    for (group, (_, idx)) in groups.into_iter().enumerate() {
+        for i in idx {
+            ids[i as usize] = group as IdxSize;
+        }
+    }
+    let right = ids.split_off(n_left);
+    Ok((ids, right))
+}
+
+pub(super) fn interval_join(
+    left_df: &DataFrame,
+    other: &DataFrame,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &IntervalJoinOptions,
+    suffix: Option<String>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        selected_left.len() == 2 && selected_right.len() == 1,
+        ComputeError: "an interval join needs the start and the end of the intervals as left keys \
+        and the points as right key"
+    );
+    let dtype = try_get_supertype(selected_left[0].dtype(), selected_left[1].dtype())?;
+    let dtype = try_get_supertype(&dtype, selected_right[0].dtype())?;
+    polars_ensure!(
+        (dtype.is_numeric() && !dtype.is_logical()) || dtype.is_temporal(),
+        InvalidOperation: "interval joins are not supported on keys of dtype {}", dtype
+    );
+    let to_physical = |s: &Series| -> PolarsResult<Series> {
+        Ok(s.cast(&dtype)?.to_physical_repr().into_owned())
+    };
+    let start = to_physical(&selected_left[0])?;
+    let end = to_physical(&selected_left[1])?;
+    let points = to_physical(&selected_right[0])?;
+
+    let groups = match (&options.left_by, &options.right_by) {
+        (Some(left_by), Some(right_by)) => Some(group_ids(
+            &left_df.select_series(left_by.clone())?,
+            &other.select_series(right_by.clone())?,
+        )?),
+        (None, None) => None,
+        _ => polars_bail!(
+            ComputeError: "an interval join needs `by` columns on both sides or on neither"
+        ),
+    };
+
+    let (left_idx, right_idx) = with_match_physical_numeric_polars_type!(start.dtype(), |$T| {
+        let start = start.unpack::<$T>()?;
+        let end = end.unpack::<$T>()?;
+        let points = points.unpack::<$T>()?;
+        interval_join_ids(start, end, points, options.closed, groups.as_ref())
+    });
+    let (mut left_idx, mut right_idx) = (&*left_idx, &*right_idx);
+    if let Some((offset, len)) = slice {
+        left_idx = slice_slice(left_idx, offset, len);
+        right_idx = slice_slice(right_idx, offset, len);
+    }
+
+    // the `by` columns of the right table equal those of the left table
+    let other = match &options.right_by {
+        Some(right_by) => Cow::Owned(other.drop_many(right_by)),
+        None => Cow::Borrowed(other),
+    };
+    let (df_left, df_right) = POOL.join(
+        // safety: join indices are known to be in bounds
+        || unsafe { left_df._take_unchecked_slice(left_idx, true) },
+        || unsafe { other._take_unchecked_slice(right_idx, true) },
+    );
+    _finish_join(df_left, df_right, suffix.as_deref())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval_join() -> PolarsResult<()> {
+        let intervals = df![
+            "id" => ["a", "a", "b"],
+            "start" => [0, 4, 0],
+            "end" => [4, 8, 10]
+        ]?;
+        let events = df![
+            "id" => ["a", "b", "a", "a"],
+            "ts" => [4, 5, 0, 6]
+        ]?;
+
+        // start < ts <= end
+        let out = intervals.join(
+            &events,
+            vec!["start", "end"],
+            vec!["ts"],
+            JoinType::Interval(Default::default()),
+            None,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("start")?.i32()?),
+            &[Some(0), Some(4), Some(4), Some(0), Some(0), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("ts")?.i32()?),
+            &[Some(4), Some(5), Some(6), Some(4), Some(5), Some(6)]
+        );
+
+        let options = IntervalJoinOptions {
+            closed: ClosedInterval::Both,
+            left_by: Some(vec!["id".into()]),
+            right_by: Some(vec!["id".into()]),
+        };
+        let out = intervals.join(
+            &events,
+            vec!["start", "end"],
+            vec!["ts"],
+            JoinType::Interval(options),
+            None,
+        )?;
+        assert_eq!(out.get_column_names(), &["id", "start", "end", "ts"]);
+        assert_eq!(
+            Vec::from(out.column("start")?.i32()?),
+            &[Some(0), Some(0), Some(4), Some(4), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("ts")?.i32()?),
+            &[Some(0), Some(4), Some(4), Some(6), Some(5)]
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
+#[cfg(feature = "interval_join")]
+mod interval_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
-#[cfg(feature = "chunked_ids")]
+#[cfg(feature = "range_join")]
+mod range_join;
+#[cfg(any(feature = "chunked_ids", feature = "interval_join"))]
 use std::borrow::Cow;
 
 #[cfg(feature = "merge_sorted")]
@@ -88,6 +92,64 @@ pub trait DataFrameJoinOps: IntoDf {
             selected_left,
             selected_right,
             how,
+            JoinAlgorithm::Auto,
+            JoinValidation::ManyToMany,
+            suffix,
+            None,
+            true,
+            false,
+        )
+    }
+
+    /// Join like [`DataFrameJoinOps::join`], but first check that the join keys are unique
+    /// in the tables required by `validate`. Returns an error if they are not, instead of
+    /// silently multiplying the matching rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// let orders = df!("customer" => [1, 1, 2],
+    ///                  "amount" => [10, 20, 30])?;
+    /// let customers = df!("customer" => [1, 2, 2],
+    ///                     "name" => ["a", "b", "c"])?;
+    ///
+    /// // every order should belong to a single customer
+    /// let out = orders.join_with_validation(
+    ///     &customers,
+    ///     ["customer"],
+    ///     ["customer"],
+    ///     JoinType::Left,
+    ///     JoinValidation::ManyToOne,
+    ///     None,
+    /// );
+    /// assert!(out.is_err());
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    fn join_with_validation<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        how: JoinType,
+        validate: JoinValidation,
+        suffix: Option<String>,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let df_left = self.to_df();
+        let selected_left = df_left.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        self._join_impl(
+            other,
+            selected_left,
+            selected_right,
+            how,
+            JoinAlgorithm::Auto,
+            validate,
             suffix,
             None,
             true,
@@ -103,17 +165,49 @@ pub trait DataFrameJoinOps: IntoDf {
         selected_left: Vec<Series>,
         selected_right: Vec<Series>,
         how: JoinType,
+        algorithm: JoinAlgorithm,
+        validate: JoinValidation,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         _check_rechunk: bool,
         _verbose: bool,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
+        polars_ensure!(
+            validate == JoinValidation::ManyToMany
+                || matches!(how, JoinType::Inner | JoinType::Left | JoinType::Outer),
+            InvalidOperation: "{} validation is not supported for {} joins", validate, how
+        );
 
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = how {
             return left_df.cross_join(other, suffix.as_deref(), slice);
         }
+        // the keys of a range join only need a common supertype
+        #[cfg(feature = "range_join")]
+        if let JoinType::Range(ops) = &how {
+            return range_join::range_join(
+                left_df,
+                other,
+                &selected_left,
+                &selected_right,
+                ops,
+                suffix,
+                slice,
+            );
+        }
+        #[cfg(feature = "interval_join")]
+        if let JoinType::Interval(options) = &how {
+            return interval_join::interval_join(
+                left_df,
+                other,
+                &selected_left,
+                &selected_right,
+                options,
+                suffix,
+                slice,
+            );
+        }
 
         #[cfg(feature = "chunked_ids")]
         {
@@ -148,6 +242,8 @@ pub trait DataFrameJoinOps: IntoDf {
                     selected_left,
                     selected_right,
                     how,
+                    algorithm,
+                    validate,
                     suffix,
                     slice,
                     false,
@@ -178,14 +274,14 @@ pub trait DataFrameJoinOps: IntoDf {
             let s_left = left_df.column(selected_left[0].name())?;
             let s_right = other.column(selected_right[0].name())?;
             return match how {
-                JoinType::Inner => {
-                    left_df._inner_join_from_series(other, s_left, s_right, suffix, slice, _verbose)
-                }
-                JoinType::Left => {
-                    left_df._left_join_from_series(other, s_left, s_right, suffix, slice, _verbose)
-                }
+                JoinType::Inner => left_df._inner_join_from_series(
+                    other, s_left, s_right, suffix, slice, _verbose, algorithm, validate,
+                ),
+                JoinType::Left => left_df._left_join_from_series(
+                    other, s_left, s_right, suffix, slice, _verbose, algorithm, validate,
+                ),
                 JoinType::Outer => {
-                    left_df._outer_join_from_series(other, s_left, s_right, suffix, slice)
+                    left_df._outer_join_from_series(other, s_left, s_right, suffix, slice, validate)
                 }
                 #[cfg(feature = "semi_anti_join")]
                 JoinType::Anti => left_df._semi_anti_join_from_series(s_left, s_right, slice, true),
@@ -227,6 +323,14 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 }
+                #[cfg(feature = "range_join")]
+                JoinType::Range(_) => {
+                    unreachable!()
+                }
+                #[cfg(feature = "interval_join")]
+                JoinType::Interval(_) => {
+                    unreachable!()
+                }
             };
         }
 
@@ -252,7 +356,7 @@ pub trait DataFrameJoinOps: IntoDf {
                 let right = DataFrame::new_no_checks(selected_right_physical);
                 let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
                 let (join_idx_left, join_idx_right) =
-                    _inner_join_multiple_keys(&mut left, &mut right, swap);
+                    _inner_join_multiple_keys(&mut left, &mut right, swap, validate)?;
                 let mut join_idx_left = &*join_idx_left;
                 let mut join_idx_right = &*join_idx_right;
 
@@ -275,7 +379,7 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Left => {
                 let mut left = DataFrame::new_no_checks(selected_left_physical);
                 let mut right = DataFrame::new_no_checks(selected_right_physical);
-                let ids = _left_join_multiple_keys(&mut left, &mut right, None, None);
+                let ids = _left_join_multiple_keys(&mut left, &mut right, None, None, validate)?;
 
                 left_df._finish_left_join(
                     ids,
@@ -289,7 +393,8 @@ pub trait DataFrameJoinOps: IntoDf {
                 let right = DataFrame::new_no_checks(selected_right_physical);
 
                 let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
-                let opt_join_tuples = _outer_join_multiple_keys(&mut left, &mut right, swap);
+                let opt_join_tuples =
+                    _outer_join_multiple_keys(&mut left, &mut right, swap, validate)?;
 
                 let mut opt_join_tuples = &*opt_join_tuples;
 
@@ -346,6 +451,14 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             }
+            #[cfg(feature = "range_join")]
+            JoinType::Range(_) => {
+                unreachable!()
+            }
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(_) => {
+                unreachable!()
+            }
         }
     }
 
@@ -435,6 +548,46 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinType::Outer, None)
     }
+
+    /// Keep the rows of this DataFrame that have a matching key in `other`.
+    /// The columns of `other` are not added.
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn filter_by_keys(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.semi_join(right, ["join_column_left"], ["join_column_right"])
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    fn semi_join<I, S>(&self, other: &DataFrame, left_on: I, right_on: I) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join(other, left_on, right_on, JoinType::Semi, None)
+    }
+
+    /// Keep the rows of this DataFrame that don't have a matching key in `other`.
+    /// The columns of `other` are not added.
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn filter_by_missing_keys(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.anti_join(right, ["join_column_left"], ["join_column_right"])
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    fn anti_join<I, S>(&self, other: &DataFrame, left_on: I, right_on: I) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join(other, left_on, right_on, JoinType::Anti, None)
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
@@ -443,6 +596,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         self.to_df().height()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn _inner_join_from_series(
         &self,
         other: &DataFrame,
@@ -451,12 +605,14 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         verbose: bool,
+        algorithm: JoinAlgorithm,
+        validate: JoinValidation,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
         let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose);
+            _sort_or_hash_inner(s_left, s_right, verbose, algorithm, validate)?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -0,0 +1,376 @@
+//! Inner joins on inequality predicates between the keys of both tables.
+//!
+//! The keys of every predicate are replaced by their dense ranks in the
+//! combined order of both tables, so that keys of any numeric dtype compare as
+//! integers. A single predicate is joined with a binary search in the sorted
+//! right keys. Two or more predicates are joined with an IEJoin on the first
+//! two, which only finds the pairs that satisfy both, and the pairs found that
+//! way are filtered by the remaining predicates.
+use polars_core::frame::hash_join::InequalityOperator::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+/// The dense rank of every key, or `None` if it does not satisfy any inequality.
+type Ranks = Vec<Option<IdxSize>>;
+
+#[inline]
+fn satisfies<T: PartialOrd>(l: T, r: T, op: InequalityOperator) -> bool {
+    match op {
+        Lt => l < r,
+        LtEq => l <= r,
+        Gt => l > r,
+        GtEq => l >= r,
+    }
+}
+
+/// `false` for NaN, which does not satisfy any inequality.
+#[inline]
+fn is_comparable<T: PartialOrd>(v: &T) -> bool {
+    v.partial_cmp(v).is_some()
+}
+
+/// Rank the keys of both tables in their combined order, equal keys get the
+/// same rank. Nulls and NaN's get no rank.
+fn dense_ranks<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> (Ranks, Ranks)
+where
+    T: PolarsNumericType,
+{
+    let n_left = left.len();
+    let mut sorted = left
+        .into_iter()
+        .chain(right)
+        .enumerate()
+        .filter_map(|(i, v)| {
+            let v = v?;
+            is_comparable(&v).then_some((v, i))
+        })
+        .collect::<Vec<_>>();
+    // NaN's are filtered out, so the values are totally ordered
+    sorted.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut ranks = vec![None; n_left + right.len()];
+    let mut rank = 0;
+    for (j, (v, i)) in sorted.iter().enumerate() {
+        if j > 0 && sorted[j - 1].0 < *v {
+            rank += 1;
+        }
+        ranks[*i] = Some(rank);
+    }
+    let right = ranks.split_off(n_left);
+    (ranks, right)
+}
+
+/// The range of the `sorted` right keys that satisfy `op` for the left key `v`.
+#[inline]
+fn matching_range<T: Ord>(sorted: &[(T, IdxSize)], v: T, op: InequalityOperator) -> (usize, usize) {
+    match op {
+        Lt => (sorted.partition_point(|r| r.0 <= v), sorted.len()),
+        LtEq => (sorted.partition_point(|r| r.0 < v), sorted.len()),
+        Gt => (0, sorted.partition_point(|r| r.0 < v)),
+        GtEq => (0, sorted.partition_point(|r| r.0 <= v)),
+    }
+}
+
+/// Find the pairs of rows that satisfy `op`. The output is ordered by the
+/// left rows, and the matches of a left row by the right rows.
+fn range_join_ids_sorted(
+    left: &[Option<IdxSize>],
+    right: &[Option<IdxSize>],
+    op: InequalityOperator,
+) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    let mut sorted = right
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| Some(((*r)?, i as IdxSize)))
+        .collect::<Vec<_>>();
+    sorted.sort_unstable();
+
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    let mut matches = vec![];
+    for (i, v) in left.iter().enumerate() {
+        let Some(v) = *v else {
+            continue;
+        };
+        let (lo, hi) = matching_range(&sorted, v, op);
+        matches.clear();
+        matches.extend(sorted[lo..hi].iter().map(|r| r.1));
+        matches.sort_unstable();
+        left_idx.extend(std::iter::repeat(i as IdxSize).take(matches.len()));
+        right_idx.extend_from_slice(&matches);
+    }
+    (left_idx, right_idx)
+}
+
+/// Call `f` with the positions of the set bits of `bits` in `lo..hi`.
+#[inline]
+fn for_each_set_bit(bits: &[u64], lo: usize, hi: usize, mut f: impl FnMut(usize)) {
+    let mut p = lo;
+    while p < hi {
+        let word = bits[p / 64] >> (p % 64);
+        if word == 0 {
+            // skip to the next word
+            p = (p / 64 + 1) * 64;
+            continue;
+        }
+        p += word.trailing_zeros() as usize;
+        if p < hi {
+            f(p);
+        }
+        p += 1;
+    }
+}
+
+/// Find the pairs of rows that satisfy `op_x` on the `x` keys and `op_y` on the
+/// `y` keys, without enumerating the pairs that only satisfy one of them. The
+/// output is ordered by the left rows, and the matches of a left row by the
+/// right rows.
+///
+/// The rows of both tables are swept in the order of their `x` keys, so that
+/// the right rows that satisfy `op_x` for a left row are visited before it. The
+/// visited right rows are marked in a bitmap in the order of their `y` keys, in
+/// which the ones that satisfy `op_y` for a left row are a contiguous range.
+fn iejoin_ids(
+    left_x: &[Option<IdxSize>],
+    left_y: &[Option<IdxSize>],
+    right_x: &[Option<IdxSize>],
+    right_y: &[Option<IdxSize>],
+    op_x: InequalityOperator,
+    op_y: InequalityOperator,
+) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    // the right rows that have both keys, in the order of their y keys
+    let mut by_y = right_x
+        .iter()
+        .zip(right_y)
+        .enumerate()
+        .filter_map(|(i, (x, y))| {
+            x.as_ref()?;
+            Some(((*y)?, i as IdxSize))
+        })
+        .collect::<Vec<_>>();
+    by_y.sort_unstable();
+
+    // the rows of both tables as (x, is_left, row or position in `by_y`)
+    let mut sweep = left_x
+        .iter()
+        .zip(left_y)
+        .enumerate()
+        .filter_map(|(i, (x, y))| {
+            y.as_ref()?;
+            Some(((*x)?, true, i as IdxSize))
+        })
+        .collect::<Vec<_>>();
+    sweep.extend(
+        by_y.iter()
+            .enumerate()
+            .map(|(p, r)| (right_x[r.1 as usize].unwrap(), false, p as IdxSize)),
+    );
+    let descending = matches!(op_x, Lt | LtEq);
+    let rights_first = matches!(op_x, LtEq | GtEq);
+    sweep.sort_unstable_by(|a, b| {
+        let by_x = if descending {
+            b.0.cmp(&a.0)
+        } else {
+            a.0.cmp(&b.0)
+        };
+        // on equal keys the right rows are visited first if they satisfy `op_x`
+        by_x.then_with(|| {
+            if rights_first {
+                a.1.cmp(&b.1)
+            } else {
+                b.1.cmp(&a.1)
+            }
+        })
+    });
+
+    let mut visited = vec![0u64; (by_y.len() + 63) / 64];
+    let mut pairs = vec![];
+    for (_, is_left, i) in sweep {
+        let i = i as usize;
+        if !is_left {
+            visited[i / 64] |= 1 << (i % 64);
+            continue;
+        }
+        let (lo, hi) = matching_range(&by_y, left_y[i].unwrap(), op_y);
+        for_each_set_bit(&visited, lo, hi, |p| pairs.push((i as IdxSize, by_y[p].1)));
+    }
+    pairs.sort_unstable();
+    pairs.into_iter().unzip()
+}
+
+/// Only keep the pairs of rows that also satisfy `op`.
+fn filter_ids(
+    left: &[Option<IdxSize>],
+    right: &[Option<IdxSize>],
+    op: InequalityOperator,
+    left_idx: &mut Vec<IdxSize>,
+    right_idx: &mut Vec<IdxSize>,
+) {
+    let mut keep = 0;
+    for j in 0..left_idx.len() {
+        let (l, r) = (left_idx[j], right_idx[j]);
+        if let (Some(lv), Some(rv)) = (left[l as usize], right[r as usize]) {
+            if satisfies(lv, rv, op) {
+                left_idx[keep] = l;
+                right_idx[keep] = r;
+                keep += 1;
+            }
+        }
+    }
+    left_idx.truncate(keep);
+    right_idx.truncate(keep);
+}
+
+fn range_join_ids(
+    selected_left: &[Series],
+    selected_right: &[Series],
+    ops: &[InequalityOperator],
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+    polars_ensure!(
+        !ops.is_empty() && ops.len() == selected_left.len() && ops.len() == selected_right.len(),
+        ComputeError: "a range join needs exactly one operator per pair of join keys"
+    );
+    let ranks = selected_left
+        .iter()
+        .zip(selected_right)
+        .map(|(l, r)| {
+            let dtype = try_get_supertype(l.dtype(), r.dtype())?;
+            polars_ensure!(
+                (dtype.is_numeric() && !dtype.is_logical()) || dtype.is_temporal(),
+                InvalidOperation: "range joins are not supported on keys of dtype {}", dtype
+            );
+            let l = l.cast(&dtype)?.to_physical_repr().into_owned();
+            let r = r.cast(&dtype)?.to_physical_repr().into_owned();
+            with_match_physical_numeric_polars_type!(l.dtype(), |$T| {
+                Ok(dense_ranks(l.unpack::<$T>()?, r.unpack::<$T>()?))
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let (mut left_idx, mut right_idx) = match ranks.as_slice() {
+        [(l, r)] => range_join_ids_sorted(l, r, ops[0]),
+        [(left_x, right_x), (left_y, right_y), ..] => {
+            iejoin_ids(left_x, left_y, right_x, right_y, ops[0], ops[1])
+        }
+        [] => unreachable!(),
+    };
+    for ((l, r), op) in ranks.iter().zip(ops).skip(2) {
+        filter_ids(l, r, *op, &mut left_idx, &mut right_idx);
+    }
+    Ok((left_idx, right_idx))
+}
+
+pub(super) fn range_join(
+    left_df: &DataFrame,
+    other: &DataFrame,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    ops: &[InequalityOperator],
+    suffix: Option<String>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let (left_idx, right_idx) = range_join_ids(selected_left, selected_right, ops)?;
+    let (mut left_idx, mut right_idx) = (&*left_idx, &*right_idx);
+    if let Some((offset, len)) = slice {
+        left_idx = slice_slice(left_idx, offset, len);
+        right_idx = slice_slice(right_idx, offset, len);
+    }
+
+    let (df_left, df_right) = POOL.join(
+        // safety: join indices are known to be in bounds
+        || unsafe { left_df._take_unchecked_slice(left_idx, true) },
+        || unsafe { other._take_unchecked_slice(right_idx, true) },
+    );
+    _finish_join(df_left, df_right, suffix.as_deref())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range_join() -> PolarsResult<()> {
+        let events = df![
+            "ts" => [1, 5, 10, 3]
+        ]?;
+        let intervals = df![
+            "start" => [0, 4, 2],
+            "end" => [4, 8, 6]
+        ]?;
+        // start <= ts < end
+        let out = events.join(
+            &intervals,
+            ["ts", "ts"],
+            ["start", "end"],
+            JoinType::Range(vec![GtEq, Lt]),
+            None,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("ts")?.i32()?),
+            &[Some(1), Some(5), Some(5), Some(3), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("start")?.i32()?),
+            &[Some(0), Some(4), Some(2), Some(0), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_join_ids_all_operators() -> PolarsResult<()> {
+        let la = [Some(1), Some(3), None, Some(3), Some(0), Some(2)];
+        let lb = [
+            Some(2.0),
+            Some(f64::NAN),
+            Some(1.0),
+            Some(1.0),
+            Some(5.0),
+            Some(0.5),
+        ];
+        let ra = [Some(3), Some(1), Some(2), Some(0), None, Some(2)];
+        let rb = [
+            Some(1.0),
+            Some(2.0),
+            Some(0.5),
+            Some(5.0),
+            Some(1.0),
+            Some(1.0),
+        ];
+        // the keys are cast to their supertype
+        let left = [Series::new("a", la), Series::new("b", lb)];
+        let right = [
+            Series::new("a", ra.map(|v| v.map(i64::from))),
+            Series::new("b", rb.map(|v| v.map(|v| v as f32))),
+        ];
+
+        let all = [Lt, LtEq, Gt, GtEq];
+        for op_a in all {
+            let mut expected = (vec![], vec![]);
+            for (i, j) in (0..la.len()).flat_map(|i| (0..ra.len()).map(move |j| (i, j))) {
+                if let (Some(a), Some(c)) = (la[i], ra[j]) {
+                    if satisfies(a, c, op_a) {
+                        expected.0.push(i as IdxSize);
+                        expected.1.push(j as IdxSize);
+                    }
+                }
+            }
+            assert_eq!(range_join_ids(&left[..1], &right[..1], &[op_a])?, expected);
+
+            for op_b in all {
+                let mut expected = (vec![], vec![]);
+                for (i, j) in (0..la.len()).flat_map(|i| (0..ra.len()).map(move |j| (i, j))) {
+                    if let (Some(a), Some(b), Some(c), Some(d)) = (la[i], lb[i], ra[j], rb[j]) {
+                        if satisfies(a, c, op_a) && satisfies(b, d, op_b) {
+                            expected.0.push(i as IdxSize);
+                            expected.1.push(j as IdxSize);
+                        }
+                    }
+                }
+                assert_eq!(range_join_ids(&left, &right, &[op_a, op_b])?, expected);
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
+#[cfg(feature = "compare")]
+mod compare;
 mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "set_operations")]
+mod set_operations;
 
+#[cfg(feature = "compare")]
+pub use compare::DataFrameComparison;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
@@ -109,4 +115,50 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Compare this `DataFrame` with `other` on the key columns `on`.
+    ///
+    /// Both `DataFrame`s are aligned with a single outer join on the keys, which must be
+    /// unique in each of them. The result contains the rows that were added (only in
+    /// `other`), the rows that were removed (only in `self`) and the rows of which a
+    /// value differs between the two, see [`DataFrameComparison::changed`]. Nulls
+    /// compare equal to nulls.
+    ///
+    /// Only value columns present in both `DataFrame`s are compared cell-wise.
+    #[cfg(feature = "compare")]
+    fn compare<I, S>(&self, other: &DataFrame, on: I) -> PolarsResult<DataFrameComparison>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let on = on
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        compare::compare(self.to_df(), other, &on)
+    }
+
+    /// Return the distinct rows of this `DataFrame` that don't occur in `other`,
+    /// like SQL's `EXCEPT`. Rows are compared on all columns and nulls compare equal.
+    ///
+    /// `other` must have the same columns with the same dtypes, but may order them differently.
+    #[cfg(feature = "set_operations")]
+    fn set_difference(&self, other: &DataFrame) -> PolarsResult<DataFrame> {
+        set_operations::set_difference(self.to_df(), other)
+    }
+
+    /// Return the distinct rows of this `DataFrame` that also occur in `other`,
+    /// like SQL's `INTERSECT`. See [`DataFrameOps::set_difference`] for the requirements.
+    #[cfg(feature = "set_operations")]
+    fn set_intersection(&self, other: &DataFrame) -> PolarsResult<DataFrame> {
+        set_operations::set_intersection(self.to_df(), other)
+    }
+
+    /// Return the rows of this `DataFrame` followed by the rows of `other`. If `distinct`
+    /// is `true` duplicate rows are removed, like SQL's `UNION`, otherwise all rows are kept,
+    /// like `UNION ALL`. See [`DataFrameOps::set_difference`] for the requirements.
+    #[cfg(feature = "set_operations")]
+    fn set_union(&self, other: &DataFrame, distinct: bool) -> PolarsResult<DataFrame> {
+        set_operations::set_union(self.to_df(), other, distinct)
+    }
 }
@@ -4,6 +4,7 @@ use std::borrow::Cow;
 
 use polars_core::export::rayon::prelude::*;
 use polars_core::frame::groupby::expr::PhysicalAggExpr;
+use polars_core::frame::groupby::{GroupsIndicator, IdxItem};
 use polars_core::prelude::*;
 use polars_core::utils::_split_offsets;
 use polars_core::{downcast_as_macro_arg_physical, POOL};
@@ -23,6 +24,45 @@ pub enum PivotAgg {
     Expr(Arc<dyn PhysicalAggExpr + Send + Sync>),
 }
 
+impl PivotAgg {
+    /// The name that prefixes the columns of this aggregation in a [`pivot_table`] with
+    /// multiple aggregations. An expression is named by its root name, or `expr` if it has
+    /// none, and its position in the aggregations.
+    pub fn name(&self) -> &'static str {
+        use PivotAgg::*;
+        match self {
+            First => "first",
+            Sum => "sum",
+            Min => "min",
+            Max => "max",
+            Mean => "mean",
+            Median => "median",
+            Count => "count",
+            Last => "last",
+            Expr(_) => "expr",
+        }
+    }
+}
+
+/// The names of `aggs`, see [`PivotAgg::name`].
+fn agg_names(aggs: &[PivotAgg]) -> PolarsResult<Vec<String>> {
+    aggs.iter()
+        .enumerate()
+        .map(|(i, agg)| match agg {
+            PivotAgg::Expr(expr) => {
+                let root_name = expr.root_name()?;
+                let root_name = if root_name.is_empty() {
+                    agg.name()
+                } else {
+                    root_name
+                };
+                Ok(format!("{root_name}_{i}"))
+            }
+            _ => Ok(agg.name().to_string()),
+        })
+        .collect()
+}
+
 fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
     // restore logical type
     match (logical_type, s.dtype()) {
@@ -110,7 +150,8 @@ where
         &values,
         &index,
         &columns,
-        agg_fn,
+        agg_fn.as_slice(),
+        None,
         sort_columns,
         false,
         separator,
@@ -157,13 +198,186 @@ where
         &values,
         &index,
         &columns,
-        agg_fn,
+        agg_fn.as_slice(),
+        None,
+        sort_columns,
+        true,
+        separator,
+    )
+}
+
+/// Do a pivot operation that computes every aggregation of `aggs` per cell.
+///
+/// With multiple aggregations the generated columns are prefixed with [`PivotAgg::name`].
+/// If `margins` is set, a column and a row of that name are added with the aggregates over
+/// every pivoted column and every index row, and the grand total in their intersection. The
+/// margin row is labeled with `margins` in string index columns and null in the others.
+/// The margins of `sum`, `count`, `min`, `max` and `first` combine the aggregates of the cells,
+/// the other aggregations merge the groups of the cells, so the data is not grouped again.
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_table<I0, S0, I1, S1, I2, S2>(
+    pivot_df: &DataFrame,
+    values: I0,
+    index: I1,
+    columns: I2,
+    sort_columns: bool,
+    aggs: &[PivotAgg],
+    margins: Option<&str>,
+    separator: Option<&str>,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    S0: AsRef<str>,
+    I1: IntoIterator<Item = S1>,
+    S1: AsRef<str>,
+    I2: IntoIterator<Item = S2>,
+    S2: AsRef<str>,
+{
+    let values = values
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let index = index
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let columns = columns
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    polars_ensure!(!aggs.is_empty(), ComputeError: "pivot table needs at least one aggregation");
+    let names = agg_names(aggs)?;
+    for (i, name) in names.iter().enumerate() {
+        polars_ensure!(
+            !names[..i].contains(name),
+            Duplicate: "pivot aggregation '{}' is given more than once", name
+        );
+    }
+
+    pivot_impl(
+        pivot_df,
+        &values,
+        &index,
+        &columns,
+        aggs,
+        margins,
         sort_columns,
         true,
         separator,
     )
 }
 
+/// Group the cells that share a location, the cells of a group are in their order.
+fn location_groups(locations: &[IdxSize], n_locations: usize) -> Vec<IdxItem> {
+    let mut all = vec![vec![]; n_locations];
+    for (cell, loc) in locations.iter().enumerate() {
+        all[*loc as usize].push(cell as IdxSize);
+    }
+    all.into_iter()
+        .map(|idx| (idx.first().copied().unwrap_or(0), idx))
+        .collect()
+}
+
+/// Merge the `groups` of the cells of every group of `cell_groups` into a group of rows. The
+/// indices of a merged group are sorted, so order dependent aggregations see the rows in their
+/// order.
+fn merge_groups(groups: &GroupsProxy, cell_groups: &GroupsProxy) -> GroupsProxy {
+    let merged = cell_groups
+        .iter()
+        .map(|cells| {
+            let GroupsIndicator::Idx((_, cells)) = cells else {
+                unreachable!()
+            };
+            let mut idx = vec![];
+            for cell in cells.iter() {
+                match groups.get(*cell as usize) {
+                    GroupsIndicator::Idx((_, g)) => idx.extend_from_slice(g),
+                    GroupsIndicator::Slice([first, len]) => idx.extend(first..first + len),
+                }
+            }
+            idx.sort_unstable();
+            (idx.first().copied().unwrap_or(0), idx)
+        })
+        .collect::<Vec<_>>();
+    GroupsProxy::Idx(merged.into())
+}
+
+/// Whether the margins of `agg_fn` can not be combined from the aggregates of the cells.
+fn needs_merged_groups(agg_fn: &PivotAgg) -> bool {
+    use PivotAgg::*;
+    !matches!(agg_fn, Sum | Count | Min | Max | First)
+}
+
+/// Aggregate the margins from the aggregates of the cells in `cell_agg`, or from the rows of
+/// the merged groups for the aggregations that can not be combined.
+fn aggregate_margins(
+    value_col: &Series,
+    value_col_name: &str,
+    agg_fn: &PivotAgg,
+    cell_agg: &Series,
+    cell_groups: &GroupsProxy,
+    merged_groups: Option<&GroupsProxy>,
+) -> PolarsResult<Series> {
+    use PivotAgg::*;
+    let out = unsafe {
+        match agg_fn {
+            Sum | Count => cell_agg.agg_sum(cell_groups),
+            Min => cell_agg.agg_min(cell_groups),
+            Max => cell_agg.agg_max(cell_groups),
+            // the groups of a stable groupby are ordered by their first row
+            First => cell_agg.agg_first(cell_groups),
+            _ => {
+                return aggregate(
+                    value_col,
+                    value_col_name,
+                    Some(agg_fn),
+                    merged_groups.unwrap(),
+                )
+            }
+        }
+    };
+    Ok(out)
+}
+
+fn aggregate(
+    value_col: &Series,
+    value_col_name: &str,
+    agg_fn: Option<&PivotAgg>,
+    groups: &GroupsProxy,
+) -> PolarsResult<Series> {
+    use PivotAgg::*;
+    let out = unsafe {
+        match agg_fn {
+            None => match value_col.len() > groups.len() {
+                true => {
+                    polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function")
+                }
+                false => value_col.agg_first(groups),
+            },
+            Some(agg_fn) => match agg_fn {
+                Sum => value_col.agg_sum(groups),
+                Min => value_col.agg_min(groups),
+                Max => value_col.agg_max(groups),
+                Last => value_col.agg_last(groups),
+                First => value_col.agg_first(groups),
+                Mean => value_col.agg_mean(groups),
+                Median => value_col.agg_median(groups),
+                Count => groups.group_count().into_series(),
+                Expr(ref expr) => {
+                    let name = expr.root_name()?;
+                    let mut value_col = value_col.clone();
+                    value_col.rename(name);
+                    let tmp_df = DataFrame::new_no_checks(vec![value_col]);
+                    let mut aggregated = expr.evaluate(&tmp_df, groups)?;
+                    aggregated.rename(value_col_name);
+                    aggregated
+                }
+            },
+        }
+    };
+    Ok(out)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn pivot_impl(
     pivot_df: &DataFrame,
@@ -174,8 +388,10 @@ fn pivot_impl(
     // these columns will be used for a nested groupby
     // the rows of this nested groupby will be pivoted as header column values
     columns: &[String],
-    // aggregation function
-    agg_fn: Option<PivotAgg>,
+    // aggregation functions, if empty the groups must have a single value
+    aggs: &[PivotAgg],
+    // name of the margin row and column, if any
+    margins: Option<&str>,
     sort_columns: bool,
     stable: bool,
     // used as separator/delimiter in generated column names.
@@ -183,6 +399,17 @@ fn pivot_impl(
 ) -> PolarsResult<DataFrame> {
     let sep = separator.unwrap_or("_");
     polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
+    polars_ensure!(
+        margins.is_none() || !aggs.is_empty(),
+        ComputeError: "pivot margins need an aggregation function"
+    );
+    let names = agg_names(aggs)?;
+    let any_merged = aggs.iter().any(needs_merged_groups);
+    let aggs = if aggs.is_empty() {
+        vec![None]
+    } else {
+        aggs.iter().map(Some).collect()
+    };
 
     let mut final_cols = vec![];
 
@@ -203,94 +430,135 @@ fn pivot_impl(
                 || positioning::compute_col_idx(pivot_df, column_column_name, &groups),
                 || positioning::compute_row_idx(pivot_df, index, &groups, count),
             );
-            let (col_locations, column_agg) = col?;
-            let (row_locations, n_rows, mut row_index) = row?;
+            let (mut col_locations, column_agg) = col?;
+            let (mut row_locations, mut n_rows, mut row_index) = row?;
+
+            let mut headers = column_agg
+                .unique_stable()?
+                .cast(&DataType::Utf8)?
+                .utf8()
+                .unwrap()
+                .clone();
+            let mut n_cols = headers.len();
+
+            // The margins are aggregated over the cells that share an index row, that share a
+            // pivoted column and over all cells. Those are placed in an extra row and column
+            // behind the cells.
+            let margin_groups = match margins {
+                Some(margins) => {
+                    let mut cell_groups = location_groups(&row_locations, n_rows);
+                    cell_groups.extend(location_groups(&col_locations, n_cols));
+                    cell_groups.extend(location_groups(&vec![0; groups.len()], 1));
+                    let cell_groups = GroupsProxy::Idx(cell_groups.into());
+                    let merged_groups = any_merged.then(|| merge_groups(&groups, &cell_groups));
+                    row_locations.extend(
+                        (0..n_rows as IdxSize)
+                            .chain(std::iter::repeat(n_rows as IdxSize).take(n_cols + 1)),
+                    );
+                    col_locations.extend(
+                        std::iter::repeat(n_cols as IdxSize)
+                            .take(n_rows)
+                            .chain(0..n_cols as IdxSize + 1),
+                    );
+
+                    if let Some(row_index) = row_index.as_mut() {
+                        for s in row_index.iter_mut() {
+                            let label = match s.dtype() {
+                                DataType::Utf8 => AnyValue::Utf8(margins),
+                                _ => AnyValue::Null,
+                            };
+                            *s = s.extend_constant(label, 1)?;
+                        }
+                    }
+                    headers.append(&Utf8Chunked::from_slice("", &[margins]));
+                    n_rows += 1;
+                    n_cols += 1;
+                    Some((cell_groups, merged_groups))
+                }
+                None => None,
+            };
 
             for value_col_name in values {
                 let value_col = pivot_df.column(value_col_name)?;
 
-                use PivotAgg::*;
-                let value_agg = unsafe {
-                    match &agg_fn {
-                        None => match value_col.len() > groups.len() {
-                            true => polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function"),
-                            false => value_col.agg_first(&groups),
-                        }
-                        Some(agg_fn) => match agg_fn {
-                            Sum => value_col.agg_sum(&groups),
-                            Min => value_col.agg_min(&groups),
-                            Max => value_col.agg_max(&groups),
-                            Last => value_col.agg_last(&groups),
-                            First => value_col.agg_first(&groups),
-                            Mean => value_col.agg_mean(&groups),
-                            Median => value_col.agg_median(&groups),
-                            Count => groups.group_count().into_series(),
-                            Expr(ref expr) => {
-                                let name = expr.root_name()?;
-                                let mut value_col = value_col.clone();
-                                value_col.rename(name);
-                                let tmp_df = DataFrame::new_no_checks(vec![value_col]);
-                                let mut aggregated = expr.evaluate(&tmp_df, &groups)?;
-                                aggregated.rename(value_col_name);
-                                aggregated
-                            }
-                        },
+                for (i, agg_fn) in aggs.iter().enumerate() {
+                    let mut value_agg = aggregate(value_col, value_col_name, *agg_fn, &groups)?;
+                    if let Some((cell_groups, merged_groups)) = &margin_groups {
+                        let margin_agg = aggregate_margins(
+                            value_col,
+                            value_col_name,
+                            agg_fn.unwrap(),
+                            &value_agg,
+                            cell_groups,
+                            merged_groups.as_ref(),
+                        )?;
+                        value_agg.append(&margin_agg)?;
                     }
-                };
 
-                let headers = column_agg.unique_stable()?.cast(&DataType::Utf8)?;
-                let mut headers = headers.utf8().unwrap().clone();
-                if values.len() > 1 {
-                    headers = headers.apply(|v| Cow::from(format!("{value_col_name}{sep}{column_column_name}{sep}{v}")))
-                }
+                    let mut headers = headers.clone();
+                    if aggs.len() > 1 {
+                        let agg_name = &names[i];
+                        headers = headers.apply(|v| {
+                            Cow::from(format!(
+                                "{agg_name}{sep}{value_col_name}{sep}{column_column_name}{sep}{v}"
+                            ))
+                        })
+                    } else if values.len() > 1 {
+                        headers = headers.apply(|v| {
+                            Cow::from(format!("{value_col_name}{sep}{column_column_name}{sep}{v}"))
+                        })
+                    }
+
+                    let value_agg_phys = value_agg.to_physical_repr();
+                    let logical_type = value_agg.dtype();
+
+                    debug_assert_eq!(row_locations.len(), col_locations.len());
+                    debug_assert_eq!(value_agg_phys.len(), row_locations.len());
+
+                    let mut cols = if value_agg_phys.dtype().is_numeric() {
+                        macro_rules! dispatch {
+                            ($ca:expr) => {{
+                                positioning::position_aggregates_numeric(
+                                    n_rows,
+                                    n_cols,
+                                    &row_locations,
+                                    &col_locations,
+                                    $ca,
+                                    logical_type,
+                                    &headers,
+                                )
+                            }};
+                        }
+                        downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
+                    } else {
+                        positioning::position_aggregates(
+                            n_rows,
+                            n_cols,
+                            &row_locations,
+                            &col_locations,
+                            &value_agg_phys,
+                            logical_type,
+                            &headers,
+                        )
+                    };
 
-                let n_cols = headers.len();
-                let value_agg_phys = value_agg.to_physical_repr();
-                let logical_type = value_agg.dtype();
-
-                debug_assert_eq!(row_locations.len(), col_locations.len());
-                debug_assert_eq!(value_agg_phys.len(), row_locations.len());
-
-                let mut cols = if value_agg_phys.dtype().is_numeric() {
-                    macro_rules! dispatch {
-                        ($ca:expr) => {{
-                            positioning::position_aggregates_numeric(
-                                n_rows,
-                                n_cols,
-                                &row_locations,
-                                &col_locations,
-                                $ca,
-                                logical_type,
-                                &headers,
-                            )
-                        }};
+                    if sort_columns {
+                        // the margin column stays last
+                        let n_sorted = cols.len() - margins.is_some() as usize;
+                        cols[..n_sorted]
+                            .sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
                     }
-                    downcast_as_macro_arg_physical!(value_agg_phys, dispatch)
-                } else {
-                    positioning::position_aggregates(
-                        n_rows,
-                        n_cols,
-                        &row_locations,
-                        &col_locations,
-                        &value_agg_phys,
-                        logical_type,
-                        &headers,
-                    )
-                };
 
-                if sort_columns {
-                    cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
+                    let cols = if count == 0 {
+                        let mut final_cols = row_index.take().unwrap();
+                        final_cols.extend(cols);
+                        final_cols
+                    } else {
+                        cols
+                    };
+                    count += 1;
+                    final_cols.extend_from_slice(&cols);
                 }
-
-                let cols = if count == 0 {
-                    let mut final_cols = row_index.take().unwrap();
-                    final_cols.extend(cols);
-                    final_cols
-                } else {
-                    cols
-                };
-                count += 1;
-                final_cols.extend_from_slice(&cols);
             }
         }
         Ok(())
@@ -0,0 +1,94 @@
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_core::utils::concat_df_unchecked;
+
+/// Select the columns of `other` in the order of `df` and check that the schemas match.
+fn align(df: &DataFrame, other: &DataFrame) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        df.width() > 0,
+        ComputeError: "set operations need at least one column"
+    );
+    polars_ensure!(
+        df.width() == other.width(),
+        ShapeMismatch: "set operations need DataFrames of the same width, got {} and {}",
+        df.width(), other.width()
+    );
+    let other = other.select(df.get_column_names())?;
+    for (l, r) in df.get_columns().iter().zip(other.get_columns()) {
+        polars_ensure!(
+            l.dtype() == r.dtype(),
+            SchemaMismatch: "column '{}' has dtype {} on the left and {} on the right",
+            l.name(), l.dtype(), r.dtype()
+        );
+    }
+    Ok(other)
+}
+
+/// Encode every row as a single binary value, so that rows can be hashed and compared at once.
+fn encode_rows(df: &DataFrame) -> PolarsResult<BinaryChunked> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| match s.dtype() {
+            // The physical values depend on the rev-map, so we compare the strings.
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_) => s.cast(&DataType::Utf8),
+            _ => Ok(s.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    _get_rows_encoded_ca("", &columns, &vec![false; columns.len()], false)
+}
+
+/// Take the distinct rows of `df` for which `keep` returns `true`, in order of first occurrence.
+fn take_distinct_rows<F>(
+    df: &DataFrame,
+    rows: &BinaryChunked,
+    mut keep: F,
+) -> PolarsResult<DataFrame>
+where
+    F: FnMut(&[u8]) -> bool,
+{
+    let mut seen = PlHashSet::with_capacity(rows.len());
+    let idx = rows
+        .into_no_null_iter()
+        .enumerate()
+        .filter(|(_, row)| keep(row) && seen.insert(*row))
+        .map(|(i, _)| i as IdxSize)
+        .collect::<Vec<_>>();
+    df.take(&IdxCa::from_vec("", idx))
+}
+
+fn set_membership(
+    df: &DataFrame,
+    other: &DataFrame,
+    keep_members: bool,
+) -> PolarsResult<DataFrame> {
+    let other = align(df, other)?;
+    let rows = encode_rows(df)?;
+    let other_rows = encode_rows(&other)?;
+    let members = other_rows.into_no_null_iter().collect::<PlHashSet<_>>();
+    take_distinct_rows(df, &rows, |row| members.contains(row) == keep_members)
+}
+
+pub(super) fn set_difference(df: &DataFrame, other: &DataFrame) -> PolarsResult<DataFrame> {
+    set_membership(df, other, false)
+}
+
+pub(super) fn set_intersection(df: &DataFrame, other: &DataFrame) -> PolarsResult<DataFrame> {
+    set_membership(df, other, true)
+}
+
+pub(super) fn set_union(
+    df: &DataFrame,
+    other: &DataFrame,
+    distinct: bool,
+) -> PolarsResult<DataFrame> {
+    let other = align(df, other)?;
+    let out = concat_df_unchecked([df, &other]);
+    if distinct {
+        let rows = encode_rows(&out)?;
+        take_distinct_rows(&out, &rows, |_| true)
+    } else {
+        Ok(out)
+    }
+}
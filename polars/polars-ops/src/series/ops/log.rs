@@ -1,3 +1,4 @@
+use polars_core::frame::groupby::GroupsIndicator;
 use polars_core::prelude::*;
 
 use crate::series::ops::SeriesSealed;
@@ -68,8 +69,30 @@ pub trait LogSeries: SeriesSealed {
 
     /// Compute the entropy as `-sum(pk * log(pk)`.
     /// where `pk` are discrete probabilities.
+    ///
+    /// Non-numeric data (strings, categoricals and binary) is treated as class
+    /// labels, `pk` then are the frequencies of the classes. Like other data,
+    /// they are only divided by their sum if `normalize` is set.
     fn entropy(&self, base: f64, normalize: bool) -> Option<f64> {
-        let s = self.as_series().to_physical_repr();
+        let s = self.as_series();
+        if is_class_label(s.dtype()) {
+            let counts = class_counts(s).ok()?;
+            let n = counts.iter().sum::<f64>();
+            if n == 0.0 {
+                return None;
+            }
+            return Some(
+                -counts
+                    .iter()
+                    .map(|c| {
+                        let p = if normalize { c / n } else { *c };
+                        p * p.log(base)
+                    })
+                    .sum::<f64>(),
+            );
+        }
+
+        let s = s.to_physical_repr();
         match s.dtype() {
             DataType::Float32 | DataType::Float64 => {
                 let pk = s.as_ref();
@@ -95,6 +118,139 @@ pub trait LogSeries: SeriesSealed {
                 .and_then(|s| s.entropy(base, normalize)),
         }
     }
+
+    /// Compute the gini impurity `1 - sum(pk^2)`, where `pk` are the relative
+    /// frequencies of the distinct values. Nulls are ignored.
+    fn gini_impurity(&self) -> Option<f64> {
+        let counts = class_counts(self.as_series()).ok()?;
+        let n = counts.iter().sum::<f64>();
+        if n == 0.0 {
+            return None;
+        }
+        Some(1.0 - counts.iter().map(|c| (c / n).powi(2)).sum::<f64>())
+    }
+
+    /// Compute the weight of evidence `ln(%events / %non-events)` of every
+    /// distinct value (bin) of this Series with respect to a binary `target`.
+    ///
+    /// Every element gets the weight of evidence of its bin. Rows with a null
+    /// `target` don't contribute to the distributions. The weight of evidence
+    /// of a bin without events or without non-events is unbounded, it is null.
+    fn weight_of_evidence(&self, target: &Series) -> PolarsResult<Float64Chunked> {
+        let s = self.as_series();
+        let (groups, bins) = bin_distributions(s, target)?;
+
+        let mut out = vec![None; s.len()];
+        for (group, bin) in groups.iter().zip(bins) {
+            let woe = bin.map(|(events, non_events)| (events / non_events).ln());
+            match group {
+                GroupsIndicator::Idx((_, idx)) => {
+                    for &i in idx.iter() {
+                        out[i as usize] = woe;
+                    }
+                }
+                GroupsIndicator::Slice([offset, len]) => {
+                    out[offset as usize..(offset + len) as usize].fill(woe);
+                }
+            }
+        }
+        Ok(Float64Chunked::from_iter_options(s.name(), out.into_iter()))
+    }
+
+    /// Compute the information value `sum((%events - %non-events) * woe)` of
+    /// this Series with respect to a binary `target`.
+    ///
+    /// Bins without events or without non-events are ignored, as their weight
+    /// of evidence is unbounded. Returns `None` if no bin contains both events
+    /// and non-events.
+    fn information_value(&self, target: &Series) -> PolarsResult<Option<f64>> {
+        let (_, bins) = bin_distributions(self.as_series(), target)?;
+        if bins.iter().all(|bin| bin.is_none()) {
+            return Ok(None);
+        }
+        Ok(Some(
+            bins.into_iter()
+                .flatten()
+                .map(|(events, non_events)| (events - non_events) * (events / non_events).ln())
+                .sum(),
+        ))
+    }
+}
+
+fn is_class_label(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Utf8 | DataType::Binary => true,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => true,
+        _ => false,
+    }
+}
+
+/// The number of occurrences of every distinct non-null value.
+fn class_counts(s: &Series) -> PolarsResult<Vec<f64>> {
+    let s = s.drop_nulls();
+    let groups = s.group_tuples(false, false)?;
+    Ok(groups
+        .group_lengths(s.name())
+        .into_no_null_iter()
+        .map(|len| len as f64)
+        .collect())
+}
+
+/// Group `s` into bins and compute, per bin, the fraction of all events and
+/// the fraction of all non-events of `target` that fall in that bin.
+///
+/// The fractions are `None` for bins without events or without non-events, of
+/// which the weight of evidence would be infinite.
+#[allow(clippy::type_complexity)]
+fn bin_distributions(
+    s: &Series,
+    target: &Series,
+) -> PolarsResult<(GroupsProxy, Vec<Option<(f64, f64)>>)> {
+    polars_ensure!(
+        s.len() == target.len(),
+        ShapeMismatch: "target should have the same length as the series: {} != {}",
+        target.len(), s.len()
+    );
+    let target = target.cast(&DataType::Boolean)?;
+    let target = target.bool()?;
+    let groups = s.group_tuples(true, false)?;
+
+    let count = |idx: &mut dyn Iterator<Item = usize>| {
+        idx.fold((0usize, 0usize), |(events, non_events), i| {
+            match target.get(i) {
+                Some(true) => (events + 1, non_events),
+                Some(false) => (events, non_events + 1),
+                None => (events, non_events),
+            }
+        })
+    };
+    let counts = groups
+        .iter()
+        .map(|group| match group {
+            GroupsIndicator::Idx((_, idx)) => count(&mut idx.iter().map(|&i| i as usize)),
+            GroupsIndicator::Slice([offset, len]) => {
+                count(&mut (offset as usize..(offset + len) as usize))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let total_events = counts.iter().map(|c| c.0).sum::<usize>() as f64;
+    let total_non_events = counts.iter().map(|c| c.1).sum::<usize>() as f64;
+    let bins = counts
+        .into_iter()
+        .map(|(events, non_events)| {
+            if events == 0 || non_events == 0 {
+                None
+            } else {
+                Some((
+                    events as f64 / total_events,
+                    non_events as f64 / total_non_events,
+                ))
+            }
+        })
+        .collect();
+    Ok((groups, bins))
 }
 
 impl LogSeries for Series {}
@@ -7,23 +7,34 @@ use polars_lazy::prelude::*;
 use polars_plan::prelude::*;
 use polars_plan::utils::expressions_to_schema;
 use sqlparser::ast::{
-    Expr as SqlExpr, FunctionArg, JoinOperator, ObjectName, OrderByExpr, Query, Select, SelectItem,
-    SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Value as SQLValue,
+    Expr as SqlExpr, FunctionArg, FunctionArgExpr, JoinOperator, ObjectName, OrderByExpr, Query,
+    Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+    Value as SQLValue,
 };
 use sqlparser::dialect::GenericDialect;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::Token;
 
 use crate::sql_expr::{parse_sql_expr, process_join_constraint};
 use crate::table_functions::PolarsTableFunctions;
+use crate::udf::UserDefinedFunction;
 
 thread_local! {pub(crate) static TABLES: RefCell<Vec<String>> = RefCell::new(vec![])}
 
+const HAVING_COLUMN: &str = "__POLARS_HAVING";
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Default, Clone)]
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) tables: Vec<String>,
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    /// The tables (or their aliases) in the FROM clause of the query that is translated, with
+    /// their columns that were renamed by joins.
+    scope: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    /// The registered functions by their lowercase names.
+    functions: PlHashMap<String, UserDefinedFunction>,
 }
 
 impl SQLContext {
@@ -33,13 +44,56 @@ impl SQLContext {
             table_map: PlHashMap::new(),
             tables: vec![],
             cte_map: RefCell::new(PlHashMap::new()),
+            scope: RefCell::new(PlHashMap::new()),
+            functions: PlHashMap::new(),
         }
     }
 
     /// Register a DataFrame as a table in the SQLContext.
     pub fn register(&mut self, name: &str, lf: LazyFrame) {
-        self.table_map.insert(name.to_owned(), lf);
-        self.tables.push(name.to_owned());
+        if self.table_map.insert(name.to_owned(), lf).is_none() {
+            self.tables.push(name.to_owned());
+        }
+    }
+
+    /// Register a Rust function that can be called from SQL by `name`. Function names are case
+    /// insensitive and a registered function takes precedence over a built-in function with the
+    /// same name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// use polars_sql::{SQLContext, UserDefinedFunction};
+    ///
+    /// # fn main() -> PolarsResult<()> {
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register_function(
+    ///     "add_one",
+    ///     UserDefinedFunction::scalar(|s| Ok(Some(&s[0] + 1)), GetOutput::same_type()),
+    /// );
+    /// ctx.register_function(
+    ///     "numbers",
+    ///     UserDefinedFunction::table(|args| {
+    ///         let n = args[0].extract::<i32>().unwrap_or(0);
+    ///         Ok(df!("n" => (0..n).collect::<Vec<_>>())?.lazy())
+    ///     }),
+    /// );
+    /// let df = ctx
+    ///     .execute("SELECT add_one(n) AS m FROM numbers(3)")?
+    ///     .collect()?;
+    /// assert_eq!(df.column("m")?.i32()?.into_no_null_iter().collect::<Vec<_>>(), &[1, 2, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_function(&mut self, name: &str, function: UserDefinedFunction) {
+        self.functions.insert(name.to_lowercase(), function);
+    }
+
+    /// The registered function `name`.
+    pub(crate) fn get_function(&self, name: &str) -> Option<&UserDefinedFunction> {
+        self.functions.get(&name.to_lowercase())
     }
 
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
@@ -53,14 +107,45 @@ impl SQLContext {
             self.cte_map.borrow().get(name).cloned()
         }
     }
+
+    /// The column `column` of the table (or alias) `table` in the FROM clause.
+    pub(crate) fn resolve_column(&self, table: &str, column: &str) -> PolarsResult<Expr> {
+        let scope = self.scope.borrow();
+        let renamed = scope.get(table).ok_or_else(
+            || polars_err!(ComputeError: "no table or alias '{}' in the FROM clause", table),
+        )?;
+        Ok(col(renamed
+            .get(column)
+            .map_or(column, |name| name.as_str())))
+    }
+
+    /// Execute a subquery of an expression. Subqueries are executed when the query is
+    /// translated, so they can't refer to the columns of the outer query.
+    pub(crate) fn execute_subquery(&self, query: &Query) -> PolarsResult<DataFrame> {
+        let mut ctx = self.clone();
+        ctx.execute_query(query)?.collect()
+    }
 }
 
 impl SQLContext {
     /// Execute a sql query and return the result as a LazyFrame.
+    ///
+    /// Besides queries, this supports `CREATE TABLE name AS SELECT ...`, which registers the
+    /// result in the context, and `COPY (SELECT ...) TO 'path'` or `COPY name TO 'path'`, which
+    /// writes the result to a parquet or ipc file with the streaming engine. The file format is
+    /// taken from a `(FORMAT parquet)` option or else from the extension of the path.
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let ast = Parser::parse_sql(&GenericDialect::default(), query).map_err(to_compute_err)?;
-        polars_ensure!(ast.len() == 1, ComputeError: "One and only one statement at a time please");
-        let res = self.execute_statement(ast.get(0).unwrap());
+        let dialect = GenericDialect::default();
+        let mut parser = Parser::new(&dialect)
+            .try_with_sql(query)
+            .map_err(to_compute_err)?;
+        let res = if parser.parse_keyword(Keyword::COPY) {
+            self.execute_copy(&mut parser)
+        } else {
+            let ast = parser.parse_statements().map_err(to_compute_err)?;
+            polars_ensure!(ast.len() == 1, ComputeError: "One and only one statement at a time please");
+            self.execute_statement(ast.get(0).unwrap())
+        };
         // every execution should clear the cte map
         self.cte_map.borrow_mut().clear();
         res
@@ -79,6 +164,14 @@ impl SQLContext {
     }
 
     pub(crate) fn execute_query(&mut self, query: &Query) -> PolarsResult<LazyFrame> {
+        // a (sub)query has its own FROM clause
+        let outer_scope = self.scope.take();
+        let out = self.execute_query_in_scope(query);
+        self.scope.replace(outer_scope);
+        out
+    }
+
+    fn execute_query_in_scope(&mut self, query: &Query) -> PolarsResult<LazyFrame> {
         self.register_ctes(query)?;
         let mut lf = match &query.body.as_ref() {
             SetExpr::Select(select_stmt) => self.execute_select(select_stmt)?,
@@ -126,39 +219,75 @@ impl SQLContext {
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (tbl_name, mut lf) = self.get_table(&tbl_expr.relation)?;
-        if !tbl_expr.joins.is_empty() {
-            for tbl in &tbl_expr.joins {
-                let (join_tbl_name, join_tbl) = self.get_table(&tbl.relation)?;
-                match &tbl.join_operator {
-                    JoinOperator::Inner(constraint) => {
-                        let (left_on, right_on) =
-                            process_join_constraint(constraint, &tbl_name, &join_tbl_name)?;
-                        lf = lf.inner_join(join_tbl, left_on, right_on)
-                    }
-                    JoinOperator::LeftOuter(constraint) => {
-                        let (left_on, right_on) =
-                            process_join_constraint(constraint, &tbl_name, &join_tbl_name)?;
-                        lf = lf.left_join(join_tbl, left_on, right_on)
-                    }
-                    JoinOperator::FullOuter(constraint) => {
-                        let (left_on, right_on) =
-                            process_join_constraint(constraint, &tbl_name, &join_tbl_name)?;
-                        lf = lf.outer_join(join_tbl, left_on, right_on)
-                    }
-                    JoinOperator::CrossJoin => lf = lf.cross_join(join_tbl),
-                    join_type => {
-                        polars_bail!(
-                            ComputeError:
-                            "join type '{:?}' not yet supported by polars-sql", join_type
-                        );
-                    }
+        self.scope.borrow_mut().insert(tbl_name, PlHashMap::new());
+        for tbl in &tbl_expr.joins {
+            let (join_tbl_name, join_tbl) = self.get_table(&tbl.relation)?;
+            let (how, constraint) = match &tbl.join_operator {
+                JoinOperator::Inner(constraint) => (JoinType::Inner, Some(constraint)),
+                JoinOperator::LeftOuter(constraint) => (JoinType::Left, Some(constraint)),
+                JoinOperator::FullOuter(constraint) => (JoinType::Outer, Some(constraint)),
+                JoinOperator::CrossJoin => (JoinType::Cross, None),
+                join_type => {
+                    polars_bail!(
+                        ComputeError:
+                        "join type '{:?}' not yet supported by polars-sql", join_type
+                    );
                 }
-            }
-        };
+            };
+            let (left_on, right_on) = match constraint {
+                Some(constraint) => process_join_constraint(constraint, &join_tbl_name, self)?,
+                None => (vec![], vec![]),
+            };
+            self.register_joined_table(&lf, &join_tbl, join_tbl_name, &left_on, &right_on)?;
+            lf = match how {
+                JoinType::Cross => lf.cross_join(join_tbl),
+                how => lf.join(join_tbl, left_on, right_on, how),
+            };
+        }
 
         Ok(lf)
     }
 
+    /// Add the right table of a join to the scope. Its join keys are merged into the keys of the
+    /// left table, and its other columns that also exist on the left get the suffix "_right".
+    fn register_joined_table(
+        &self,
+        left: &LazyFrame,
+        right: &LazyFrame,
+        name: String,
+        left_on: &[Expr],
+        right_on: &[Expr],
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.scope.borrow().contains_key(&name),
+            ComputeError: "table '{}' occurs more than once in the FROM clause, use an alias", name
+        );
+        let left_schema = left.schema()?;
+        let right_schema = right.schema()?;
+        let keys: PlHashMap<&str, &str> = left_on
+            .iter()
+            .zip(right_on)
+            .filter_map(|(left, right)| match (left, right) {
+                (Expr::Column(left), Expr::Column(right)) => Some((right.as_ref(), left.as_ref())),
+                _ => None,
+            })
+            .collect();
+        let renamed = right_schema
+            .iter_names()
+            .filter_map(|column| {
+                if let Some(key) = keys.get(column.as_str()) {
+                    Some((column.to_string(), key.to_string()))
+                } else if left_schema.contains(column) {
+                    Some((column.to_string(), format!("{column}_right")))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        self.scope.borrow_mut().insert(name, renamed);
+        Ok(())
+    }
+
     /// execute the 'SELECT' part of the query
     fn execute_select(&mut self, select_stmt: &Select) -> PolarsResult<LazyFrame> {
         // Determine involved dataframe
@@ -198,6 +327,12 @@ impl SQLContext {
             })
             .collect::<PolarsResult<_>>()?;
 
+        let having = select_stmt
+            .having
+            .as_ref()
+            .map(|expr| parse_sql_expr(expr, self))
+            .transpose()?;
+
         // Check for group by
         // After projection since there might be number.
         let groupby_keys: Vec<Expr> = select_stmt
@@ -224,9 +359,13 @@ impl SQLContext {
             .collect::<PolarsResult<_>>()?;
 
         if groupby_keys.is_empty() {
+            polars_ensure!(
+                having.is_none(),
+                ComputeError: "HAVING clause without GROUP BY is not supported"
+            );
             Ok(lf.select(projections))
         } else {
-            self.process_groupby(lf, contains_wildcard, &groupby_keys, &projections)
+            self.process_groupby(lf, contains_wildcard, &groupby_keys, &projections, having)
         }
     }
 
@@ -239,19 +378,18 @@ impl SQLContext {
         } = stmt
         {
             let tbl_name = name.0.get(0).unwrap().value.as_str();
-            // CREATE TABLE IF NOT EXISTS
+            let out = df! {
+                "Response" => ["Create Table"]
+            }
+            .unwrap()
+            .lazy();
+            // CREATE TABLE IF NOT EXISTS keeps the existing table
             if *if_not_exists && self.table_map.contains_key(tbl_name) {
-                polars_bail!(ComputeError: "relation {} already exists", tbl_name);
-                // CREATE OR REPLACE TABLE
+                return Ok(out);
             }
             if let Some(query) = query {
                 let lf = self.execute_query(query)?;
                 self.register(tbl_name, lf);
-                let out = df! {
-                    "Response" => ["Create Table"]
-                }
-                .unwrap()
-                .lazy();
                 Ok(out)
             } else {
                 polars_bail!(ComputeError: "only CREATE TABLE AS SELECT is supported");
@@ -261,6 +399,90 @@ impl SQLContext {
         }
     }
 
+    // Parses the rest of a `COPY source TO 'path' [WITH] [(FORMAT format)]` statement. This is
+    // done here, as the source of a COPY can only be a table in the sql parser.
+    #[cfg_attr(
+        not(any(feature = "parquet", feature = "ipc")),
+        allow(unused_variables)
+    )]
+    fn execute_copy(&mut self, parser: &mut Parser) -> PolarsResult<LazyFrame> {
+        let lf = if parser.consume_token(&Token::LParen) {
+            let query = parser.parse_query().map_err(to_compute_err)?;
+            parser
+                .expect_token(&Token::RParen)
+                .map_err(to_compute_err)?;
+            self.execute_query(&query)?
+        } else {
+            let name = parser.parse_object_name().map_err(to_compute_err)?;
+            let tbl_name = name.0.get(0).unwrap().value.as_str();
+            self.table_map
+                .get(tbl_name)
+                .cloned()
+                .ok_or_else(|| polars_err!(ComputeError: "relation '{}' was not found", tbl_name))?
+        };
+        polars_ensure!(
+            parser.parse_keyword(Keyword::TO),
+            ComputeError: "only COPY ... TO 'path' is supported"
+        );
+        let path = parser.parse_literal_string().map_err(to_compute_err)?;
+
+        let mut format = None;
+        let with_options = parser.parse_keyword(Keyword::WITH);
+        if parser.consume_token(&Token::LParen) {
+            loop {
+                let option = parser.parse_identifier().map_err(to_compute_err)?;
+                let value = parser.parse_identifier().map_err(to_compute_err)?;
+                match option.value.to_lowercase().as_str() {
+                    "format" => format = Some(value.value.to_lowercase()),
+                    _ => polars_bail!(ComputeError: "COPY option {} is not supported", option),
+                }
+                if !parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
+            parser
+                .expect_token(&Token::RParen)
+                .map_err(to_compute_err)?;
+        } else {
+            polars_ensure!(!with_options, ComputeError: "expected COPY options after WITH");
+        }
+        parser.consume_token(&Token::SemiColon);
+        parser.expect_token(&Token::EOF).map_err(to_compute_err)?;
+
+        let path = std::path::PathBuf::from(path);
+        let format = format.unwrap_or_else(|| {
+            path.extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+                .unwrap_or_default()
+        });
+        match format.as_str() {
+            #[cfg(feature = "parquet")]
+            "parquet" => {
+                let options = ParquetWriteOptions {
+                    maintain_order: true,
+                    ..Default::default()
+                };
+                lf.sink_parquet(path, options)?
+            }
+            #[cfg(feature = "ipc")]
+            "ipc" | "arrow" | "feather" => {
+                let options = IpcWriterOptions {
+                    maintain_order: true,
+                    ..Default::default()
+                };
+                lf.sink_ipc(path, options)?
+            }
+            _ => polars_bail!(
+                ComputeError: "COPY to '{}' is not supported, use a parquet or ipc file", format
+            ),
+        }
+        Ok(df! {
+            "Response" => ["Copy"]
+        }
+        .unwrap()
+        .lazy())
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -271,11 +493,26 @@ impl SQLContext {
                 }
                 let tbl_name = name.0.get(0).unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
-                    Ok((tbl_name.to_string(), lf))
+                    let name = alias
+                        .as_ref()
+                        .map_or(tbl_name, |alias| alias.name.value.as_str());
+                    Ok((name.to_string(), lf))
                 } else {
                     polars_bail!(ComputeError: "relation '{}' was not found", tbl_name);
                 }
             }
+            TableFactor::Derived {
+                lateral,
+                subquery,
+                alias,
+            } => {
+                polars_ensure!(!lateral, ComputeError: "LATERAL subqueries are not supported");
+                let alias = alias.as_ref().ok_or_else(
+                    || polars_err!(ComputeError: "a subquery in the FROM clause needs an alias"),
+                )?;
+                let lf = self.execute_query(subquery)?;
+                Ok((alias.name.value.clone(), lf))
+            }
             // Support bare table, optional with alias for now
             _ => polars_bail!(ComputeError: "not implemented"),
         }
@@ -288,8 +525,23 @@ impl SQLContext {
         args: &[FunctionArg],
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.get(0).unwrap().value.as_str();
-        let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
-        let (tbl_name, lf) = read_fn.execute(args)?;
+        let (tbl_name, lf) = match self.get_function(tbl_fn) {
+            Some(function) => {
+                let args = args
+                    .iter()
+                    .map(|arg| match arg {
+                        FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => match arg {
+                            FunctionArgExpr::Expr(expr) => parse_sql_expr(expr, self),
+                            _ => polars_bail!(
+                                ComputeError: "unsupported argument of table function '{}': {}", tbl_fn, arg
+                            ),
+                        },
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                (tbl_fn.to_string(), function.call_table(tbl_fn, &args)?)
+            }
+            None => tbl_fn.parse::<PolarsTableFunctions>()?.execute(args)?,
+        };
         let tbl_name = alias
             .as_ref()
             .map(|a| a.name.value.clone())
@@ -326,6 +578,7 @@ impl SQLContext {
         contains_wildcard: bool,
         groupby_keys: &[Expr],
         projections: &[Expr],
+        having: Option<Expr>,
     ) -> PolarsResult<LazyFrame> {
         // check groupby and projection due to difference between SQL and polars
         // Return error on wild card, shouldn't process this
@@ -358,7 +611,15 @@ impl SQLContext {
             }
         }
 
-        let aggregated = lf.groupby(groupby_keys).agg(&aggregation_projection);
+        // HAVING is aggregated to a boolean per group
+        let filter_groups = having.is_some();
+        if let Some(having) = having {
+            aggregation_projection.push(having.alias(HAVING_COLUMN));
+        }
+        let mut aggregated = lf.groupby(groupby_keys).agg(&aggregation_projection);
+        if filter_groups {
+            aggregated = aggregated.filter(col(HAVING_COLUMN));
+        }
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
         // a final projection to get the proper order
@@ -408,6 +669,8 @@ impl SQLContext {
             table_map,
             tables,
             cte_map: RefCell::new(PlHashMap::new()),
+            scope: RefCell::new(PlHashMap::new()),
+            functions: PlHashMap::new(),
         }
     }
 }
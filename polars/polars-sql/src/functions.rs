@@ -1,9 +1,13 @@
-use polars_core::prelude::{polars_bail, polars_err, PolarsError, PolarsResult};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, IdxSize, PolarsError, PolarsResult,
+    RankMethod, RankOptions, SortOptions, IDX_DTYPE,
+};
 use polars_lazy::dsl::Expr;
-use polars_plan::dsl::count;
+use polars_lazy::prelude::{lit, Duration, RollingOptions};
+use polars_plan::dsl::{arg_sort_by, count};
 use sqlparser::ast::{
     Expr as SqlExpr, Function as SQLFunction, FunctionArg, FunctionArgExpr, Value as SqlValue,
-    WindowSpec,
+    WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 
 use crate::sql_expr::parse_sql_expr;
@@ -232,6 +236,39 @@ pub(crate) enum PolarsSqlFunctions {
     /// SELECT ARRAY_CONTAINS(column_1, 'foo') from df;
     /// ```
     ArrayContains,
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function
+    /// The number of the row in its partition, starting at 1
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function
+    /// The rank of the row in its partition, with gaps after ties
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function
+    /// The rank of the row in its partition, without gaps after ties
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    DenseRank,
+    /// SQL 'lag' function
+    /// The value of the row `offset` (default 1) rows before the row in its partition
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// The value of the row `offset` (default 1) rows after the row in its partition
+    /// ```sql
+    /// SELECT LEAD(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    Lead,
 }
 impl PolarsSqlFunctions {
     pub(crate) fn keywords() -> &'static [&'static str] {
@@ -275,6 +312,11 @@ impl PolarsSqlFunctions {
             "unnest",
             "array_get",
             "array_contains",
+            "row_number",
+            "rank",
+            "dense_rank",
+            "lag",
+            "lead",
         ]
     }
 }
@@ -334,6 +376,14 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
             "unnest" => Self::Explode,
             "array_get" => Self::ArrayGet,
             "array_contains" => Self::ArrayContains,
+            // ----
+            // Window functions
+            // ----
+            "row_number" => Self::RowNumber,
+            "rank" => Self::Rank,
+            "dense_rank" => Self::DenseRank,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
             other => polars_bail!(InvalidOperation: "unsupported SQL function: {}", other),
         })
     }
@@ -342,6 +392,19 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
 impl SqlFunctionVisitor<'_> {
     pub(crate) fn visit_function(&self) -> PolarsResult<Expr> {
         let function = self.func;
+        let name = function.name.0[0].value.as_str();
+        if let Some(udf) = self.ctx.get_function(name) {
+            let args = extract_args(function);
+            let exprs = args
+                .iter()
+                .map(|arg| match arg {
+                    FunctionArgExpr::Expr(sql_expr) => parse_sql_expr(sql_expr, self.ctx),
+                    _ => not_supported_error(name, &args),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let expr = udf.call_scalar(name, exprs)?;
+            return self.apply_window_spec(expr, &function.over);
+        }
 
         let function_name: PolarsSqlFunctions = function.try_into()?;
         use PolarsSqlFunctions::*;
@@ -388,11 +451,12 @@ impl SqlFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
+            Count if self.is_ordered_window() => self.visit_aggregate(FrameAggregate::Count),
             Count => self.visit_count(),
-            Sum => self.visit_unary(Expr::sum),
-            Min => self.visit_unary(Expr::min),
-            Max => self.visit_unary(Expr::max),
-            Avg => self.visit_unary(Expr::mean),
+            Sum => self.visit_aggregate(FrameAggregate::Sum),
+            Min => self.visit_aggregate(FrameAggregate::Min),
+            Max => self.visit_aggregate(FrameAggregate::Max),
+            Avg => self.visit_aggregate(FrameAggregate::Mean),
             StdDev => self.visit_unary(|e| e.std(1)),
             Variance => self.visit_unary(|e| e.var(1)),
             First => self.visit_unary(Expr::first),
@@ -410,7 +474,121 @@ impl SqlFunctionVisitor<'_> {
             Explode => self.visit_unary(|e| e.explode()),
             ArrayContains => self.visit_binary::<Expr>(|e, s| e.arr().contains(s)),
             ArrayGet => self.visit_binary(|e, i| e.arr().get(i)),
+            // ----
+            // Window functions
+            // ----
+            RowNumber => self.visit_ranking(|order| Ok(order.row_number())),
+            Rank => self.visit_ranking(|order| order.rank(RankMethod::Min)),
+            DenseRank => self.visit_ranking(|order| order.rank(RankMethod::Dense)),
+            Lag => self.visit_shift(1),
+            Lead => self.visit_shift(-1),
+        }
+    }
+
+    /// Whether the function has an `OVER` clause with an `ORDER BY`.
+    fn is_ordered_window(&self) -> bool {
+        matches!(&self.func.over, Some(window_spec) if !window_spec.order_by.is_empty())
+    }
+
+    fn window_order(&self, window_spec: &WindowSpec) -> PolarsResult<WindowOrder> {
+        let mut by = Vec::with_capacity(window_spec.order_by.len());
+        let mut descending = Vec::with_capacity(window_spec.order_by.len());
+        for ob in &window_spec.order_by {
+            by.push(parse_sql_expr(&ob.expr, self.ctx)?);
+            descending.push(ob.asc == Some(false));
+        }
+        Ok(WindowOrder { by, descending })
+    }
+
+    /// Visit an aggregation, which is computed over the window frame if the window is ordered.
+    fn visit_aggregate(&self, agg: FrameAggregate) -> PolarsResult<Expr> {
+        let window_spec = match &self.func.over {
+            Some(window_spec) if !window_spec.order_by.is_empty() => window_spec,
+            _ => return self.visit_unary(|e| agg.aggregate(e)),
+        };
+        let order = self.window_order(window_spec)?;
+        let args = extract_args(self.func);
+        let expr = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] if !self.func.distinct => {
+                parse_sql_expr(sql_expr, self.ctx)?
+            }
+            // count(*) counts a column without nulls
+            [FunctionArgExpr::Wildcard] if agg == FrameAggregate::Count => {
+                order.by[0].clone().is_null()
+            }
+            _ => return not_supported_error(self.func.name.0[0].value.as_str(), &args),
+        };
+
+        use WindowFrameBound::*;
+        let out = match &window_spec.window_frame {
+            // the default frame of an ordered window
+            None => order.to_peers(agg.cumulative(order.sorted(expr)))?,
+            Some(frame) => match (&frame.units, &frame.start_bound, &frame.end_bound) {
+                (_, Preceding(None), Some(Following(None))) => agg.aggregate(expr),
+                (WindowFrameUnits::Range, Preceding(None), None | Some(CurrentRow)) => {
+                    order.to_peers(agg.cumulative(order.sorted(expr)))?
+                }
+                (WindowFrameUnits::Rows, Preceding(None), None | Some(CurrentRow)) => {
+                    order.to_rows(agg.cumulative(order.sorted(expr)))
+                }
+                (WindowFrameUnits::Rows, Preceding(Some(n)), None | Some(CurrentRow)) => {
+                    let n = f64::from_sql_expr(n, self.ctx)? as usize;
+                    order.to_rows(agg.rolling(order.sorted(expr), n + 1))
+                }
+                _ => polars_bail!(
+                    InvalidOperation: "window frame {:?} is not supported in polars-sql", frame
+                ),
+            },
+        };
+        self.apply_window_spec(out, &self.func.over)
+    }
+
+    /// Visit a ranking function, which needs an ordered window.
+    fn visit_ranking(&self, f: impl Fn(WindowOrder) -> PolarsResult<Expr>) -> PolarsResult<Expr> {
+        let name = self.func.name.0[0].value.as_str();
+        let window_spec = match &self.func.over {
+            Some(window_spec) if !window_spec.order_by.is_empty() => window_spec,
+            _ => polars_bail!(
+                InvalidOperation: "function `{}` needs an OVER clause with an ORDER BY", name
+            ),
+        };
+        let args = extract_args(self.func);
+        if !args.is_empty() {
+            return not_supported_error(name, &args);
         }
+        let expr = f(self.window_order(window_spec)?)?;
+        self.apply_window_spec(expr, &self.func.over)
+    }
+
+    /// Visit `LAG` or `LEAD`, where `direction` is the sign of the shift.
+    fn visit_shift(&self, direction: i64) -> PolarsResult<Expr> {
+        let name = self.func.name.0[0].value.as_str();
+        let args = extract_args(self.func);
+        let (expr, periods, default) = match args.as_slice() {
+            [FunctionArgExpr::Expr(expr)] => (expr, 1, None),
+            [FunctionArgExpr::Expr(expr), FunctionArgExpr::Expr(n)] => {
+                (expr, f64::from_sql_expr(n, self.ctx)? as i64, None)
+            }
+            [FunctionArgExpr::Expr(expr), FunctionArgExpr::Expr(n), FunctionArgExpr::Expr(default)] =>
+            {
+                let default = parse_sql_expr(default, self.ctx)?;
+                (expr, f64::from_sql_expr(n, self.ctx)? as i64, Some(default))
+            }
+            _ => return not_supported_error(name, &args),
+        };
+        let shift = |e: Expr| match default {
+            Some(default) => e.shift_and_fill(direction * periods, default),
+            None => e.shift(direction * periods),
+        };
+        let expr = parse_sql_expr(expr, self.ctx)?;
+        let out = match &self.func.over {
+            Some(window_spec) if !window_spec.order_by.is_empty() => {
+                let order = self.window_order(window_spec)?;
+                order.to_rows(shift(order.sorted(expr)))
+            }
+            _ => shift(expr),
+        };
+        self.apply_window_spec(out, &self.func.over)
     }
 
     fn visit_unary(&self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
@@ -484,21 +662,119 @@ impl SqlFunctionVisitor<'_> {
         window_spec: &Option<WindowSpec>,
     ) -> PolarsResult<Expr> {
         Ok(match &window_spec {
+            // without partitions, the window is the whole frame
+            Some(window_spec) if window_spec.partition_by.is_empty() => expr,
             Some(window_spec) => {
-                // Process for simple window specification, partition by first
                 let partition_by = window_spec
                     .partition_by
                     .iter()
                     .map(|p| parse_sql_expr(p, self.ctx))
                     .collect::<PolarsResult<Vec<_>>>()?;
                 expr.over(partition_by)
-                // Order by and Row range may not be supported at the moment
             }
             None => expr,
         })
     }
 }
 
+/// The `ORDER BY` of a window. Ordered window functions are evaluated on the rows of the
+/// partition sorted by the window order, after which the results are mapped back to the rows.
+struct WindowOrder {
+    by: Vec<Expr>,
+    descending: Vec<bool>,
+}
+
+impl WindowOrder {
+    fn sorted(&self, expr: Expr) -> Expr {
+        expr.sort_by(&self.by, &self.descending)
+    }
+
+    /// The position of every row in the window order.
+    fn positions(&self) -> Expr {
+        arg_sort_by(&self.by, &self.descending).arg_sort(SortOptions::default())
+    }
+
+    /// Map the results of the sorted rows back to the rows.
+    fn to_rows(&self, sorted: Expr) -> Expr {
+        sorted.take(self.positions())
+    }
+
+    /// Map the results of the sorted rows back to the rows, where every row gets the result of
+    /// its last peer, i.e. the last row with the same value in the window order.
+    fn to_peers(&self, sorted: Expr) -> PolarsResult<Expr> {
+        let last_peer = self.rank(RankMethod::Max)? - lit(1 as IdxSize);
+        Ok(sorted.take(last_peer))
+    }
+
+    fn row_number(&self) -> Expr {
+        self.positions() + lit(1 as IdxSize)
+    }
+
+    fn rank(&self, method: RankMethod) -> PolarsResult<Expr> {
+        polars_ensure!(
+            self.by.len() == 1,
+            InvalidOperation: "ranking by more than one ORDER BY expression is not supported in polars-sql"
+        );
+        let options = RankOptions {
+            method,
+            descending: self.descending[0],
+        };
+        Ok(self.by[0].clone().rank(options, None))
+    }
+}
+
+/// An aggregation that can be computed over the frame of an ordered window.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FrameAggregate {
+    Sum,
+    Min,
+    Max,
+    Mean,
+    Count,
+}
+
+impl FrameAggregate {
+    fn aggregate(self, expr: Expr) -> Expr {
+        match self {
+            Self::Sum => expr.sum(),
+            Self::Min => expr.min(),
+            Self::Max => expr.max(),
+            Self::Mean => expr.mean(),
+            Self::Count => expr.count(),
+        }
+    }
+
+    /// The aggregation of every row and the rows before it.
+    fn cumulative(self, expr: Expr) -> Expr {
+        match self {
+            Self::Sum => expr.cumsum(false),
+            Self::Min => expr.cummin(false),
+            Self::Max => expr.cummax(false),
+            Self::Mean => {
+                expr.clone().cast(DataType::Float64).cumsum(false)
+                    / Self::Count.cumulative(expr).cast(DataType::Float64)
+            }
+            Self::Count => expr.is_not_null().cast(IDX_DTYPE).cumsum(false),
+        }
+    }
+
+    /// The aggregation of every row and the `window - 1` rows before it.
+    fn rolling(self, expr: Expr, window: usize) -> Expr {
+        let options = RollingOptions {
+            window_size: Duration::new(window as i64),
+            min_periods: 1,
+            ..Default::default()
+        };
+        match self {
+            Self::Sum => expr.rolling_sum(options),
+            Self::Min => expr.rolling_min(options),
+            Self::Max => expr.rolling_max(options),
+            Self::Mean => expr.rolling_mean(options),
+            Self::Count => expr.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+        }
+    }
+}
+
 fn not_supported_error(function_name: &str, args: &Vec<&FunctionArgExpr>) -> PolarsResult<Expr> {
     polars_bail!(
         InvalidOperation:
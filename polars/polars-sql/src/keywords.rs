@@ -53,6 +53,16 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::NOT,
         keywords::IN,
         keywords::WITH,
+        keywords::OVER,
+        keywords::PARTITION,
+        keywords::ROWS,
+        keywords::RANGE,
+        keywords::BETWEEN,
+        keywords::UNBOUNDED,
+        keywords::PRECEDING,
+        keywords::FOLLOWING,
+        keywords::CURRENT,
+        keywords::ROW,
     ];
     keywords.extend_from_slice(sql_keywords);
     keywords
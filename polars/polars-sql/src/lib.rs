@@ -6,8 +6,10 @@ mod functions;
 pub mod keywords;
 mod sql_expr;
 mod table_functions;
+mod udf;
 
 pub use context::SQLContext;
+pub use udf::UserDefinedFunction;
 
 #[cfg(test)]
 mod test {
@@ -706,6 +708,29 @@ mod test {
         assert_eq!(df_2.width(), 4);
     }
 
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_copy_to_parquet() -> PolarsResult<()> {
+        let df = create_sample_df()?;
+        let mut context = SQLContext::new();
+        context.register("df", df.clone().lazy());
+        let file =
+            std::env::temp_dir().join(format!("polars_sql_copy_{}.parquet", std::process::id()));
+        let path = file.to_string_lossy();
+
+        let sql = format!("COPY (SELECT a, b FROM df WHERE a < 10) TO '{path}' (FORMAT parquet)");
+        let df_sql = context.execute(&sql)?.collect()?;
+        assert!(df_sql.frame_equal(&df! {"Response" => ["Copy"]}?));
+
+        let df_copy = context
+            .execute(&format!("SELECT * FROM read_parquet('{path}')"))?
+            .collect()?;
+        let expected = df.lazy().filter(col("a").lt(lit(10))).collect()?;
+        assert!(df_copy.frame_equal(&expected));
+        std::fs::remove_file(&file)?;
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "csv")]
     fn iss_7436() {
@@ -818,6 +843,187 @@ mod test {
         Ok(())
     }
 
+    fn create_join_context() -> PolarsResult<SQLContext> {
+        let orders = df![
+            "id" => [1, 2, 3, 4],
+            "customer" => [10, 10, 20, 30],
+            "amount" => [5, 15, 25, 35],
+        ]?;
+        let customers = df![
+            "id" => [10, 20, 40],
+            "name" => ["a", "b", "d"],
+        ]?;
+        let mut context = SQLContext::new();
+        context.register("orders", orders.lazy());
+        context.register("customers", customers.lazy());
+        Ok(context)
+    }
+
+    #[test]
+    fn test_join_aliases() -> PolarsResult<()> {
+        let mut context = create_join_context()?;
+        let sql = r#"
+            SELECT o.id, c.id AS customer_id, c.name
+            FROM orders AS o
+            INNER JOIN customers c ON (o.customer = c.id AND c.id = o.customer)
+            WHERE o.amount > 10"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let expected = df![
+            "id" => [2, 3],
+            "customer_id" => [10, 20],
+            "name" => ["a", "b"],
+        ]?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let sql = r#"
+            SELECT o.id, c.name, o.amount
+            FROM orders AS o
+            LEFT JOIN customers c ON o.customer = c.id
+            ORDER BY o.id"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let expected = df![
+            "id" => [1, 2, 3, 4],
+            "name" => [Some("a"), Some("a"), Some("b"), None],
+            "amount" => [5, 15, 25, 35],
+        ]?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        // only equalities are supported
+        let sql = "SELECT * FROM orders o JOIN customers c ON o.customer < c.id";
+        assert!(context.execute(sql).is_err());
+
+        // the subquery is joined under its alias
+        let sql = r#"
+            SELECT big.id
+            FROM (SELECT * FROM orders WHERE amount > 10) AS big
+            JOIN customers ON big.customer = customers.id
+            ORDER BY big.id"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        assert_eq!(Vec::from(df_sql.column("id")?.i32()?), &[Some(2), Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_having() -> PolarsResult<()> {
+        let mut context = create_join_context()?;
+        let sql = r#"
+            SELECT customer, sum(amount) AS total
+            FROM orders
+            GROUP BY customer
+            HAVING sum(amount) > 20 AND count(id) < 2
+            ORDER BY customer"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let expected = df![
+            "customer" => [20, 30],
+            "total" => [25, 35],
+        ]?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let sql = "SELECT amount FROM orders HAVING sum(amount) > 20";
+        assert!(context.execute(sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_subqueries() -> PolarsResult<()> {
+        let mut context = create_join_context()?;
+        let sql = r#"
+            SELECT id, amount - (SELECT min(amount) FROM orders) AS above_min
+            FROM orders
+            WHERE customer IN (SELECT id FROM customers)
+            AND EXISTS (SELECT * FROM customers WHERE name = 'd')
+            ORDER BY id"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let expected = df![
+            "id" => [1, 2, 3],
+            "above_min" => [0, 10, 20],
+        ]?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let sql = "SELECT id FROM orders WHERE NOT EXISTS (SELECT * FROM customers)";
+        assert_eq!(context.execute(sql)?.collect()?.height(), 0);
+        // a scalar subquery must return one value
+        let sql = "SELECT id + (SELECT id FROM customers) FROM orders";
+        assert!(context.execute(sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_functions() -> PolarsResult<()> {
+        let df = df![
+            "p" => ["a", "a", "a", "b", "b"],
+            "o" => [3, 1, 2, 2, 1],
+            "v" => [10, 20, 30, 40, 50],
+        ]?;
+        let mut context = SQLContext::new();
+        context.register("df", df.lazy());
+        let sql = r#"
+            SELECT
+                ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) AS rn,
+                LAG(v) OVER (PARTITION BY p ORDER BY o) AS prev,
+                LEAD(v, 1, 0) OVER (PARTITION BY p ORDER BY o) AS next,
+                SUM(v) OVER (PARTITION BY p ORDER BY o) AS running,
+                SUM(v) OVER (
+                    PARTITION BY p ORDER BY o ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+                ) AS pair,
+                MAX(v) OVER (PARTITION BY p) AS max
+            FROM df
+            ORDER BY p, o"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let column = |name: &str| -> PolarsResult<Vec<Option<i64>>> {
+            let s = df_sql.column(name)?.cast(&DataType::Int64)?;
+            Ok(s.i64()?.into_iter().collect())
+        };
+        assert_eq!(
+            column("rn")?,
+            &[Some(1), Some(2), Some(3), Some(1), Some(2)]
+        );
+        assert_eq!(column("prev")?, &[None, Some(20), Some(30), None, Some(50)]);
+        assert_eq!(
+            column("next")?,
+            &[Some(30), Some(10), Some(0), Some(40), Some(0)]
+        );
+        assert_eq!(
+            column("running")?,
+            &[Some(20), Some(50), Some(60), Some(50), Some(90)]
+        );
+        assert_eq!(
+            column("pair")?,
+            &[Some(20), Some(50), Some(40), Some(50), Some(90)]
+        );
+        assert_eq!(
+            column("max")?,
+            &[Some(30), Some(30), Some(30), Some(50), Some(50)]
+        );
+
+        // rows with equal keys are peers
+        let df = df![
+            "g" => [2, 1, 1],
+            "v" => [3, 1, 2],
+        ]?;
+        context.register("peers", df.lazy());
+        let sql = r#"
+            SELECT
+                RANK() OVER (ORDER BY g) AS rank,
+                DENSE_RANK() OVER (ORDER BY g) AS dense_rank,
+                SUM(v) OVER (ORDER BY g) AS running,
+                COUNT(*) OVER (ORDER BY g ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS n
+            FROM peers"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let column = |name: &str| -> PolarsResult<Vec<Option<i64>>> {
+            let s = df_sql.column(name)?.cast(&DataType::Int64)?;
+            Ok(s.i64()?.into_iter().collect())
+        };
+        assert_eq!(column("rank")?, &[Some(3), Some(1), Some(1)]);
+        assert_eq!(column("dense_rank")?, &[Some(2), Some(1), Some(1)]);
+        assert_eq!(column("running")?, &[Some(6), Some(3), Some(3)]);
+        assert_eq!(column("n")?, &[Some(3), Some(1), Some(2)]);
+
+        let sql = "SELECT ROW_NUMBER() OVER (PARTITION BY g) FROM peers";
+        assert!(context.execute(sql).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "csv")]
     fn test_ctes() -> PolarsResult<()> {
@@ -855,4 +1061,35 @@ mod test {
         assert!(s.series_equal(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_register_function() -> PolarsResult<()> {
+        let df = create_sample_df()?;
+        let mut context = SQLContext::new();
+        context.register("df", df.clone().lazy());
+        context.register_function(
+            "times",
+            UserDefinedFunction::scalar(|s| Ok(Some(&s[0] * &s[1])), GetOutput::same_type()),
+        );
+        context.register_function(
+            "head_of",
+            UserDefinedFunction::table(move |args| {
+                let n = args[0].extract::<u32>().unwrap_or(0);
+                Ok(df.clone().lazy().limit(n))
+            }),
+        );
+        let df_sql = context
+            .execute("SELECT b, TIMES(b, b + 1) AS c FROM head_of(5) WHERE b > 2")?
+            .collect()?;
+        let expected = df!(
+            "b" => [3i64, 4, 5],
+            "c" => [12i64, 20, 30],
+        )?;
+        assert!(df_sql.frame_equal(&expected));
+
+        // a scalar function can't produce a table and vice versa
+        assert!(context.execute("SELECT * FROM times(1, 2)").is_err());
+        assert!(context.execute("SELECT head_of(a) FROM df").is_err());
+        Ok(())
+    }
 }
@@ -3,7 +3,7 @@ use polars_lazy::dsl::Expr;
 use polars_lazy::prelude::*;
 use sqlparser::ast::{
     ArrayAgg, BinaryOperator as SQLBinaryOperator, BinaryOperator, DataType as SQLDataType,
-    Expr as SqlExpr, Function as SQLFunction, JoinConstraint, OrderByExpr, TrimWhereField,
+    Expr as SqlExpr, Function as SQLFunction, JoinConstraint, OrderByExpr, Query, TrimWhereField,
     UnaryOperator, Value as SqlValue,
 };
 
@@ -57,6 +57,12 @@ impl SqlExprVisitor<'_> {
             SqlExpr::Value(value) => self.visit_literal(value),
             SqlExpr::IsNull(expr) => Ok(self.visit_expr(expr)?.is_null()),
             SqlExpr::IsNotNull(expr) => Ok(self.visit_expr(expr)?.is_not_null()),
+            SqlExpr::IsDistinctFrom(left, right) => {
+                Ok(self.visit_expr(left)?.neq_missing(self.visit_expr(right)?))
+            }
+            SqlExpr::IsNotDistinctFrom(left, right) => {
+                Ok(self.visit_expr(left)?.eq_missing(self.visit_expr(right)?))
+            }
             SqlExpr::Floor { expr, .. } => Ok(self.visit_expr(expr)?.floor()),
             SqlExpr::Ceil { expr, .. } => Ok(self.visit_expr(expr)?.ceil()),
             SqlExpr::ArrayAgg(expr) => self.visit_arr_agg(expr),
@@ -84,6 +90,16 @@ impl SqlExprVisitor<'_> {
                 list,
                 negated,
             } => self.visit_is_in(expr, list, *negated),
+            SqlExpr::Subquery(subquery) => self.visit_subquery(subquery),
+            SqlExpr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => self.visit_in_subquery(expr, subquery, *negated),
+            SqlExpr::Exists { subquery, negated } => {
+                let df = self.ctx.execute_subquery(subquery)?;
+                Ok(lit((df.height() > 0) != *negated))
+            }
             other => polars_bail!(ComputeError: "SQL expression {:?} is not yet supported", other),
         }
     }
@@ -96,15 +112,7 @@ impl SqlExprVisitor<'_> {
             idents.len() == 2,
             ComputeError: "compound identifier {:?} is not yet supported", idents,
         );
-        let tbl_name = &idents[0].value;
-        let refers_main_table =
-            { self.ctx.table_map.len() == 1 && self.ctx.table_map.contains_key(tbl_name) };
-        polars_ensure!(
-            refers_main_table, ComputeError:
-            "compound identifier {:?} is not yet supported if multiple tables are registered",
-            idents
-        );
-        Ok(col(&idents[1].value))
+        self.ctx.resolve_column(&idents[0].value, &idents[1].value)
     }
     fn visit_unary_op(&self, op: &UnaryOperator, expr: &SqlExpr) -> PolarsResult<Expr> {
         let expr = self.visit_expr(expr)?;
@@ -325,6 +333,49 @@ impl SqlExprVisitor<'_> {
         }
     }
 
+    /// Visit a scalar subquery
+    ///
+    /// e.g. `(SELECT max(b) FROM df)`
+    fn visit_subquery(&self, subquery: &Query) -> PolarsResult<Expr> {
+        let s = self.subquery_column(subquery)?;
+        polars_ensure!(
+            s.len() <= 1,
+            ComputeError: "a scalar subquery must return at most one row, got {}", s.len()
+        );
+        if s.is_empty() {
+            Ok(lit(Series::full_null(s.name(), 1, s.dtype())))
+        } else {
+            Ok(lit(s))
+        }
+    }
+
+    /// Visit a SQL `IN` expression with a subquery
+    ///
+    /// e.g. `a IN (SELECT a FROM df)`
+    fn visit_in_subquery(
+        &self,
+        expr: &SqlExpr,
+        subquery: &Query,
+        negated: bool,
+    ) -> PolarsResult<Expr> {
+        let expr = self.visit_expr(expr)?;
+        let s = self.subquery_column(subquery)?;
+        if negated {
+            Ok(expr.is_in(lit(s)).not())
+        } else {
+            Ok(expr.is_in(lit(s)))
+        }
+    }
+
+    fn subquery_column(&self, subquery: &Query) -> PolarsResult<Series> {
+        let df = self.ctx.execute_subquery(subquery)?;
+        polars_ensure!(
+            df.width() == 1,
+            ComputeError: "a subquery in an expression must return one column, got {}", df.width()
+        );
+        Ok(df.get_columns()[0].clone())
+    }
+
     fn visit_order_by(&self, order_by: &OrderByExpr) -> PolarsResult<(Expr, bool)> {
         let expr = self.visit_expr(&order_by.expr)?;
         let descending = order_by.asc.unwrap_or(false);
@@ -341,35 +392,79 @@ pub(crate) fn parse_sql_expr(expr: &SqlExpr, ctx: &SQLContext) -> PolarsResult<E
     visitor.visit_expr(expr)
 }
 
+/// The keys of a join with the table (or alias) `right_name`. The left keys are resolved in
+/// the tables that are already in the FROM clause.
 pub(super) fn process_join_constraint(
     constraint: &JoinConstraint,
-    left_name: &str,
     right_name: &str,
-) -> PolarsResult<(Expr, Expr)> {
-    if let JoinConstraint::On(SqlExpr::BinaryOp { left, op, right }) = constraint {
-        match (left.as_ref(), right.as_ref()) {
-            (SqlExpr::CompoundIdentifier(left), SqlExpr::CompoundIdentifier(right)) => {
-                if left.len() == 2 && right.len() == 2 {
-                    let tbl_a = &left[0].value;
-                    let col_a = &left[1].value;
-
-                    let tbl_b = &right[0].value;
-                    let col_b = &right[1].value;
+    ctx: &SQLContext,
+) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+    let mut left_on = vec![];
+    let mut right_on = vec![];
+    match constraint {
+        JoinConstraint::On(expr) => {
+            collect_join_keys(expr, right_name, ctx, &mut left_on, &mut right_on)?
+        }
+        JoinConstraint::Using(idents) => {
+            for ident in idents {
+                left_on.push(col(&ident.value));
+                right_on.push(col(&ident.value));
+            }
+        }
+        _ => polars_bail!(
+            ComputeError: "SQL join constraint {:?} is not yet supported", constraint
+        ),
+    }
+    Ok((left_on, right_on))
+}
 
-                    if let BinaryOperator::Eq = op {
-                        if left_name == tbl_a && right_name == tbl_b {
-                            return Ok((col(col_a), col(col_b)));
-                        } else if left_name == tbl_b && right_name == tbl_a {
-                            return Ok((col(col_b), col(col_a)));
-                        }
-                    }
-                }
+/// Collect the keys of `ON` equalities, which may be combined with `AND`.
+fn collect_join_keys(
+    expr: &SqlExpr,
+    right_name: &str,
+    ctx: &SQLContext,
+    left_on: &mut Vec<Expr>,
+    right_on: &mut Vec<Expr>,
+) -> PolarsResult<()> {
+    match expr {
+        SqlExpr::Nested(expr) => collect_join_keys(expr, right_name, ctx, left_on, right_on),
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            collect_join_keys(left, right_name, ctx, left_on, right_on)?;
+            collect_join_keys(right, right_name, ctx, left_on, right_on)
+        }
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => match (left.as_ref(), right.as_ref()) {
+            (SqlExpr::CompoundIdentifier(a), SqlExpr::CompoundIdentifier(b))
+                if a.len() == 2 && b.len() == 2 =>
+            {
+                let (left, right) = if b[0].value == right_name {
+                    (a, b)
+                } else if a[0].value == right_name {
+                    (b, a)
+                } else {
+                    polars_bail!(
+                        ComputeError: "join constraint {} doesn't refer to table '{}'",
+                        expr, right_name
+                    )
+                };
+                left_on.push(ctx.resolve_column(&left[0].value, &left[1].value)?);
+                right_on.push(col(&right[1].value));
+                Ok(())
             }
             (SqlExpr::Identifier(left), SqlExpr::Identifier(right)) => {
-                return Ok((col(&left.value), col(&right.value)))
+                left_on.push(col(&left.value));
+                right_on.push(col(&right.value));
+                Ok(())
             }
-            _ => {}
-        }
+            _ => polars_bail!(ComputeError: "SQL join constraint {} is not yet supported", expr),
+        },
+        _ => polars_bail!(ComputeError: "SQL join constraint {} is not yet supported", expr),
     }
-    polars_bail!(ComputeError: "SQL join constraint {:?} is not yet supported", constraint);
 }
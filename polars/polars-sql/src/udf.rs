@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+type ScalarFn = dyn Fn(&mut [Series]) -> PolarsResult<Option<Series>> + Send + Sync;
+type TableFn = dyn Fn(&[AnyValue<'static>]) -> PolarsResult<LazyFrame> + Send + Sync;
+
+/// A Rust function that can be called from SQL, see [`SQLContext::register_function`].
+///
+/// [`SQLContext::register_function`]: crate::SQLContext::register_function
+#[derive(Clone)]
+pub enum UserDefinedFunction {
+    /// A function of the columns (or expressions) that are its arguments, e.g.
+    /// ```sql
+    /// SELECT my_fn(a, b + 1) FROM df
+    /// ```
+    /// It is evaluated with [`map_multiple`] on the whole columns.
+    Scalar {
+        /// The function of the series of the arguments.
+        function: Arc<ScalarFn>,
+        /// The dtype of the output.
+        output_type: GetOutput,
+    },
+    /// A function that produces a table in the FROM clause, e.g.
+    /// ```sql
+    /// SELECT * FROM my_fn('path/to/file', 10)
+    /// ```
+    /// The arguments must be literals.
+    Table(Arc<TableFn>),
+}
+
+impl UserDefinedFunction {
+    /// A scalar function, see [`UserDefinedFunction::Scalar`].
+    pub fn scalar<F>(function: F, output_type: GetOutput) -> Self
+    where
+        F: Fn(&mut [Series]) -> PolarsResult<Option<Series>> + Send + Sync + 'static,
+    {
+        UserDefinedFunction::Scalar {
+            function: Arc::new(function),
+            output_type,
+        }
+    }
+
+    /// A table function, see [`UserDefinedFunction::Table`].
+    pub fn table<F>(function: F) -> Self
+    where
+        F: Fn(&[AnyValue<'static>]) -> PolarsResult<LazyFrame> + Send + Sync + 'static,
+    {
+        UserDefinedFunction::Table(Arc::new(function))
+    }
+
+    pub(crate) fn call_scalar(&self, name: &str, args: Vec<Expr>) -> PolarsResult<Expr> {
+        match self {
+            UserDefinedFunction::Scalar {
+                function,
+                output_type,
+            } => {
+                let function = function.clone();
+                Ok(map_multiple(
+                    move |s: &mut [Series]| function(s),
+                    args,
+                    output_type.clone(),
+                ))
+            }
+            UserDefinedFunction::Table(_) => polars_bail!(
+                ComputeError: "'{}' is a table function, it can only be used in the FROM clause", name
+            ),
+        }
+    }
+
+    pub(crate) fn call_table(&self, name: &str, args: &[Expr]) -> PolarsResult<LazyFrame> {
+        match self {
+            UserDefinedFunction::Table(function) => {
+                let args = args
+                    .iter()
+                    .map(|arg| match arg {
+                        Expr::Literal(value) => value
+                            .to_anyvalue()
+                            .ok_or_else(|| {
+                                polars_err!(ComputeError: "unsupported argument of '{}': {:?}", name, arg)
+                            })?
+                            .into_static(),
+                        _ => polars_bail!(
+                            ComputeError: "the arguments of table function '{}' must be literals, got {:?}",
+                            name, arg
+                        ),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                function(&args)
+            }
+            UserDefinedFunction::Scalar { .. } => polars_bail!(
+                ComputeError: "'{}' is a scalar function, it can't be used in the FROM clause", name
+            ),
+        }
+    }
+}
@@ -2,6 +2,7 @@
 use arrow::temporal_conversions::parse_offset;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
+use polars_arrow::export::arrow::temporal_conversions::{NANOSECONDS, SECONDS_IN_DAY};
 use polars_arrow::time_zone::PolarsTimeZone;
 use polars_arrow::utils::CustomIterTools;
 use polars_core::export::rayon::prelude::*;
@@ -53,6 +54,23 @@ impl Default for DynamicGroupOptions {
     }
 }
 
+impl DynamicGroupOptions {
+    /// Whether the windows start on the truncated timestamps, independent of the first value of
+    /// the index column. The windows can then be computed per part of a sorted index column with
+    /// [`groupby_windows_aligned`].
+    pub fn is_aligned(&self) -> bool {
+        let every = &self.every;
+        let day = NANOSECONDS * SECONDS_IN_DAY;
+        matches!(self.start_by, StartBy::WindowBound)
+            && !every.negative
+            && !every.is_zero()
+            && (every.months_only()
+                || (every.months() == 0
+                    && every.weeks() == 0
+                    && every.days() * day + every.nanoseconds() <= day))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RollingGroupOptions {
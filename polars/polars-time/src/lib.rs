@@ -2,9 +2,13 @@
 pub mod chunkedarray;
 mod date_range;
 mod groupby;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+mod month_ops;
 pub mod prelude;
 mod round;
 pub mod series;
+#[cfg(feature = "timezones")]
+mod time_zones;
 mod truncate;
 mod upsample;
 mod utils;
@@ -13,7 +17,11 @@ mod windows;
 pub use date_range::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use groupby::dynamic::*;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+pub use month_ops::*;
 pub use round::*;
+#[cfg(feature = "timezones")]
+pub use time_zones::*;
 pub use truncate::*;
 pub use upsample::*;
 pub use windows::calendar::date_range as date_range_vec;
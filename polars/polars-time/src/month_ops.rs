@@ -0,0 +1,245 @@
+//! Navigation over calendar months, computed on the number of days since the epoch with integer
+//! arithmetic instead of converting every value to a chrono date.
+use polars_arrow::export::arrow::temporal_conversions::{
+    MICROSECONDS, MILLISECONDS, NANOSECONDS, SECONDS_IN_DAY,
+};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What [`PolarsMonthOps::add_months`] does when the day of the month doesn't exist in the
+/// target month, e.g. for Jan 31 + 1 month.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AddMonthsStrategy {
+    /// Take the last day of the target month, Jan 31 + 1 month is Feb 28 (or 29).
+    #[default]
+    Clamp,
+    /// Carry the surplus days into the next month, Jan 31 + 1 month is Mar 3 (or 2).
+    Overflow,
+    /// Raise an error.
+    Error,
+}
+
+/// The days since the epoch of a proleptic Gregorian date.
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The proleptic Gregorian date of a number of days since the epoch.
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn month_start_days(days: i64) -> PolarsResult<i64> {
+    let (year, month, _) = civil_from_days(days);
+    Ok(days_from_civil(year, month, 1))
+}
+
+fn month_end_days(days: i64) -> PolarsResult<i64> {
+    let (year, month, _) = civil_from_days(days);
+    Ok(days_from_civil(year, month, days_in_month(year, month)))
+}
+
+fn quarter_start_days(days: i64) -> PolarsResult<i64> {
+    let (year, month, _) = civil_from_days(days);
+    Ok(days_from_civil(year, (month - 1) / 3 * 3 + 1, 1))
+}
+
+fn add_months_days(days: i64, n: i64, strategy: AddMonthsStrategy) -> PolarsResult<i64> {
+    let (year, month, day) = civil_from_days(days);
+    let months = year * 12 + (month - 1) as i64 + n;
+    let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
+    let last_day = days_in_month(year, month);
+    if day <= last_day {
+        return Ok(days_from_civil(year, month, day));
+    }
+    match strategy {
+        AddMonthsStrategy::Clamp => Ok(days_from_civil(year, month, last_day)),
+        AddMonthsStrategy::Overflow => {
+            Ok(days_from_civil(year, month, last_day) + (day - last_day) as i64)
+        }
+        AddMonthsStrategy::Error => polars_bail!(
+            ComputeError: "cannot add {} months to day {} of the month: {}-{:02} has {} days",
+            n, day, year, month, last_day
+        ),
+    }
+}
+
+pub trait PolarsMonthOps {
+    /// The first day of the month. The time of day of datetimes is preserved.
+    fn month_start(&self) -> PolarsResult<Self>
+    where
+        Self: Sized;
+
+    /// The last day of the month. The time of day of datetimes is preserved.
+    fn month_end(&self) -> PolarsResult<Self>
+    where
+        Self: Sized;
+
+    /// The first day of the quarter. The time of day of datetimes is preserved.
+    fn quarter_start(&self) -> PolarsResult<Self>
+    where
+        Self: Sized;
+
+    /// Add `n` (possibly negative) calendar months. `strategy` determines the result if the day
+    /// of the month doesn't exist in the target month.
+    fn add_months(&self, n: i64, strategy: AddMonthsStrategy) -> PolarsResult<Self>
+    where
+        Self: Sized;
+}
+
+/// Apply `f` on the days since the epoch of the local wall time, keeping the time of day.
+#[cfg(feature = "dtype-datetime")]
+fn apply_on_days<F: Fn(i64) -> PolarsResult<i64>>(
+    ca: &DatetimeChunked,
+    f: F,
+) -> PolarsResult<DatetimeChunked> {
+    #[cfg(feature = "timezones")]
+    if let Some(tz) = ca.time_zone() {
+        let local = ca.replace_time_zone(None, None)?;
+        return apply_on_days(&local, f)?.replace_time_zone(Some(tz), None);
+    }
+    let units_per_day = match ca.time_unit() {
+        TimeUnit::Nanoseconds => NANOSECONDS * SECONDS_IN_DAY,
+        TimeUnit::Microseconds => MICROSECONDS * SECONDS_IN_DAY,
+        TimeUnit::Milliseconds => MILLISECONDS * SECONDS_IN_DAY,
+    };
+    let f = &f;
+    Ok(ca
+        .try_apply(move |t| {
+            let days = f(t.div_euclid(units_per_day))?;
+            Ok(days * units_per_day + t.rem_euclid(units_per_day))
+        })?
+        .into_datetime(ca.time_unit(), ca.time_zone().clone()))
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl PolarsMonthOps for DatetimeChunked {
+    fn month_start(&self) -> PolarsResult<Self> {
+        apply_on_days(self, month_start_days)
+    }
+
+    fn month_end(&self) -> PolarsResult<Self> {
+        apply_on_days(self, month_end_days)
+    }
+
+    fn quarter_start(&self) -> PolarsResult<Self> {
+        apply_on_days(self, quarter_start_days)
+    }
+
+    fn add_months(&self, n: i64, strategy: AddMonthsStrategy) -> PolarsResult<Self> {
+        apply_on_days(self, |days| add_months_days(days, n, strategy))
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl PolarsMonthOps for DateChunked {
+    fn month_start(&self) -> PolarsResult<Self> {
+        Ok(self
+            .try_apply(|t| Ok(month_start_days(t as i64)? as i32))?
+            .into_date())
+    }
+
+    fn month_end(&self) -> PolarsResult<Self> {
+        Ok(self
+            .try_apply(|t| Ok(month_end_days(t as i64)? as i32))?
+            .into_date())
+    }
+
+    fn quarter_start(&self) -> PolarsResult<Self> {
+        Ok(self
+            .try_apply(|t| Ok(quarter_start_days(t as i64)? as i32))?
+            .into_date())
+    }
+
+    fn add_months(&self, n: i64, strategy: AddMonthsStrategy) -> PolarsResult<Self> {
+        Ok(self
+            .try_apply(move |t| Ok(add_months_days(t as i64, n, strategy)? as i32))?
+            .into_date())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn days(year: i32, month: u32, day: u32) -> i64 {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        (NaiveDate::from_ymd_opt(year, month, day).unwrap() - epoch).num_days()
+    }
+
+    #[test]
+    fn test_civil_days() {
+        for d in (-800_000..800_000).step_by(37) {
+            let (year, month, day) = civil_from_days(d);
+            assert_eq!(days(year as i32, month, day), d);
+            assert_eq!(days_from_civil(year, month, day), d);
+        }
+    }
+
+    #[test]
+    fn test_month_ops() -> PolarsResult<()> {
+        let d = days(2024, 5, 17);
+        assert_eq!(month_start_days(d)?, days(2024, 5, 1));
+        assert_eq!(month_end_days(d)?, days(2024, 5, 31));
+        assert_eq!(quarter_start_days(d)?, days(2024, 4, 1));
+
+        let jan_31 = days(2023, 1, 31);
+        let add = |n, strategy| add_months_days(jan_31, n, strategy);
+        assert_eq!(add(1, AddMonthsStrategy::Clamp)?, days(2023, 2, 28));
+        assert_eq!(add(1, AddMonthsStrategy::Overflow)?, days(2023, 3, 3));
+        assert!(add(1, AddMonthsStrategy::Error).is_err());
+        assert_eq!(add(13, AddMonthsStrategy::Clamp)?, days(2024, 2, 29));
+        assert_eq!(add(-1, AddMonthsStrategy::Error)?, days(2022, 12, 31));
+        assert_eq!(add(-14, AddMonthsStrategy::Clamp)?, days(2021, 11, 30));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_month_ops_datetime() -> PolarsResult<()> {
+        let d = days(2024, 5, 17);
+        let ca = Int64Chunked::new("", &[Some(d * 86_400_000 + 3_600_000), None])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        let out = ca.month_end()?;
+        assert_eq!(
+            Vec::from(&out.0),
+            &[Some(days(2024, 5, 31) * 86_400_000 + 3_600_000), None]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+//! Time zone conversions where every row has its own time zone, e.g. for events that are
+//! recorded in the local time of the region where they occur.
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use chrono::{LocalResult, NaiveDateTime, TimeZone as _};
+use chrono_tz::Tz;
+use polars_arrow::export::arrow;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What to do with a local datetime that occurs twice in its time zone, because the clocks are
+/// turned back, or not at all, because the clocks are turned forward.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Ambiguous {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Take the earliest of the two datetimes. Non-existent datetimes raise an error.
+    Earliest,
+    /// Take the latest of the two datetimes. Non-existent datetimes raise an error.
+    Latest,
+    /// Set ambiguous and non-existent datetimes to null.
+    Null,
+}
+
+/// Parses the time zones of the rows. There are few distinct time zones, so each is parsed once.
+struct TzCache<'a> {
+    parsed: PlHashMap<&'a str, Tz>,
+}
+
+impl<'a> TzCache<'a> {
+    fn new() -> Self {
+        Self {
+            parsed: PlHashMap::new(),
+        }
+    }
+
+    fn get(&mut self, name: &'a str) -> PolarsResult<Tz> {
+        if let Some(tz) = self.parsed.get(name) {
+            return Ok(*tz);
+        }
+        let tz = name
+            .parse::<Tz>()
+            .map_err(|_| polars_err!(ComputeError: "unable to parse time zone: '{}'", name))?;
+        self.parsed.insert(name, tz);
+        Ok(tz)
+    }
+}
+
+fn to_naive_datetime(t: i64, tu: TimeUnit) -> NaiveDateTime {
+    match tu {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime(t),
+        TimeUnit::Microseconds => timestamp_us_to_datetime(t),
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime(t),
+    }
+}
+
+fn to_timestamp(ndt: NaiveDateTime, tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => ndt.timestamp_nanos(),
+        TimeUnit::Microseconds => ndt.timestamp_micros(),
+        TimeUnit::Milliseconds => ndt.timestamp_millis(),
+    }
+}
+
+/// Apply `f` on the timestamps and the time zones of the rows. A time zone of length 1 applies
+/// to all rows.
+fn apply_per_row<'a, F>(
+    ca: &DatetimeChunked,
+    time_zones: &'a Utf8Chunked,
+    mut f: F,
+) -> PolarsResult<Int64Chunked>
+where
+    F: FnMut(i64, Tz) -> PolarsResult<Option<i64>>,
+{
+    let mut cache = TzCache::new();
+    let mut apply = |t: Option<i64>, tz: Option<&'a str>| match (t, tz) {
+        (Some(t), Some(tz)) => f(t, cache.get(tz)?),
+        _ => Ok(None),
+    };
+    let out = match time_zones.len() {
+        1 => {
+            let tz = time_zones.get(0);
+            ca.into_iter()
+                .map(|t| apply(t, tz))
+                .collect::<PolarsResult<Vec<_>>>()?
+        }
+        len => {
+            polars_ensure!(
+                len == ca.len(),
+                ComputeError: "expected {} time zones, got {}", ca.len(), len
+            );
+            ca.into_iter()
+                .zip(time_zones)
+                .map(|(t, tz)| apply(t, tz))
+                .collect::<PolarsResult<Vec<_>>>()?
+        }
+    };
+    Ok(Int64Chunked::from_iter_options(ca.name(), out.into_iter()))
+}
+
+/// Interpret the wall time of every row in the time zone of the row, and return the instants
+/// in UTC. The wall time of a tz-aware datetime is the wall time in its time zone.
+pub fn replace_time_zone_per_row(
+    ca: &DatetimeChunked,
+    time_zones: &Utf8Chunked,
+    ambiguous: Ambiguous,
+) -> PolarsResult<DatetimeChunked> {
+    let tu = ca.time_unit();
+    let local;
+    let ca = match ca.time_zone() {
+        Some(_) => {
+            local = ca.replace_time_zone(None, None)?;
+            &local
+        }
+        None => ca,
+    };
+    let out = apply_per_row(ca, time_zones, |t, tz| {
+        let ndt = to_naive_datetime(t, tu);
+        let utc = match tz.from_local_datetime(&ndt) {
+            LocalResult::Single(dt) => dt.naive_utc(),
+            LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+                Ambiguous::Earliest => earliest.naive_utc(),
+                Ambiguous::Latest => latest.naive_utc(),
+                Ambiguous::Null => return Ok(None),
+                Ambiguous::Raise => polars_bail!(
+                    ComputeError: "datetime '{}' is ambiguous in time zone '{}'", ndt, tz
+                ),
+            },
+            LocalResult::None => match ambiguous {
+                Ambiguous::Null => return Ok(None),
+                _ => polars_bail!(
+                    ComputeError: "datetime '{}' is non-existent in time zone '{}'", ndt, tz
+                ),
+            },
+        };
+        Ok(Some(to_timestamp(utc, tu)))
+    })?;
+    Ok(out.into_datetime(tu, Some("UTC".to_string())))
+}
+
+/// The wall time of every row in the time zone of the row. The datetimes must be tz-aware, the
+/// result is tz-naive.
+pub fn convert_time_zone_per_row(
+    ca: &DatetimeChunked,
+    time_zones: &Utf8Chunked,
+) -> PolarsResult<DatetimeChunked> {
+    polars_ensure!(
+        ca.time_zone().is_some(),
+        ComputeError: "cannot convert a tz-naive datetime, set a time zone first with 'replace_time_zone'"
+    );
+    let tu = ca.time_unit();
+    let out = apply_per_row(ca, time_zones, |t, tz| {
+        let local = tz
+            .from_utc_datetime(&to_naive_datetime(t, tu))
+            .naive_local();
+        Ok(Some(to_timestamp(local, tu)))
+    })?;
+    Ok(out.into_datetime(tu, None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_time_zones_per_row() -> PolarsResult<()> {
+        let ms = |s: &str| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M")
+                .unwrap()
+                .timestamp_millis()
+        };
+        let ca = Int64Chunked::new(
+            "t",
+            &[
+                Some(ms("2023-06-01 12:00")),
+                Some(ms("2023-06-01 12:00")),
+                // the clocks are turned back at 03:00 in Europe/Amsterdam
+                Some(ms("2023-10-29 02:30")),
+                None,
+            ],
+        )
+        .into_datetime(TimeUnit::Milliseconds, None);
+        let tz = Utf8Chunked::new(
+            "tz",
+            &[
+                Some("Europe/Amsterdam"),
+                Some("America/New_York"),
+                Some("Europe/Amsterdam"),
+                Some("Asia/Tokyo"),
+            ],
+        );
+
+        assert!(replace_time_zone_per_row(&ca, &tz, Ambiguous::Raise).is_err());
+        let utc = replace_time_zone_per_row(&ca, &tz, Ambiguous::Earliest)?;
+        assert_eq!(utc.time_zone().as_deref(), Some("UTC"));
+        assert_eq!(
+            Vec::from(&utc.0),
+            &[
+                Some(ms("2023-06-01 10:00")),
+                Some(ms("2023-06-01 16:00")),
+                Some(ms("2023-10-29 00:30")),
+                None
+            ]
+        );
+        let out = replace_time_zone_per_row(&ca, &tz, Ambiguous::Null)?;
+        assert_eq!(out.null_count(), 2);
+
+        // back to the wall times
+        let local = convert_time_zone_per_row(&utc, &tz)?;
+        assert_eq!(Vec::from(&local.0), Vec::from(&ca.0));
+
+        let unknown = Utf8Chunked::new("tz", &["Mars/Olympus_Mons"]);
+        assert!(convert_time_zone_per_row(&utc, &unknown).is_err());
+        Ok(())
+    }
+}
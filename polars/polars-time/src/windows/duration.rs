@@ -37,8 +37,24 @@ pub struct Duration {
     pub(crate) negative: bool,
     // indicates if an integer string was passed. e.g. "2i"
     pub parsed_int: bool,
+    // the day weeks start on, in days since Monday. e.g. "1w_tue"
+    #[cfg_attr(feature = "serde", serde(default))]
+    week_start: u8,
+    // indicates if months are anchored to the end of the month. e.g. "1mo_end"
+    #[cfg_attr(feature = "serde", serde(default))]
+    month_end: bool,
 }
 
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
 impl PartialOrd<Self> for Duration {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.duration_ns().partial_cmp(&other.duration_ns())
@@ -61,6 +77,8 @@ impl Duration {
             nsecs: fixed_slots.abs(),
             negative: fixed_slots < 0,
             parsed_int: true,
+            week_start: 0,
+            month_end: false,
         }
     }
 
@@ -90,9 +108,20 @@ impl Duration {
     /// * `y`:  calendar year
     /// * `i`:  index value (only for {Int32, Int64} dtypes)
     ///
+    /// A duration may end with an anchor that aligns calendar windows:
+    ///
+    /// * `_mon`, `_tue`, ..., `_sun`: weeks start on the given weekday, e.g. `"1w_sun"`. By
+    ///   default weeks start on Monday.
+    /// * `_end`: months are anchored to the end of the month, e.g. `"1mo_end"`. Adding a month
+    ///   moves to the last day of the month, and windows start on the last day of a month.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
+        let (duration, anchor) = match duration.split_once('_') {
+            Some((duration, anchor)) => (duration, Some(anchor)),
+            None => (duration, None),
+        };
         let num_minus_signs = duration.matches('-').count();
         if num_minus_signs > 1 {
             panic!("a Duration string can only have a single minus sign")
@@ -165,6 +194,27 @@ impl Duration {
                 unit.clear();
             }
         }
+        let mut week_start = 0;
+        let mut month_end = false;
+        match anchor {
+            None => {}
+            Some("end") => {
+                if months == 0 {
+                    panic!("the '_end' anchor can only be used with a duration in months or years")
+                }
+                month_end = true
+            }
+            Some(anchor) => {
+                let weekday = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"]
+                    .iter()
+                    .position(|day| *day == anchor)
+                    .unwrap_or_else(|| panic!("anchor: '{anchor}' not supported. Available anchors are: 'mon', 'tue', 'wed', 'thu', 'fri', 'sat', 'sun', 'end'"));
+                if weeks == 0 {
+                    panic!("the '_{anchor}' anchor can only be used with a duration in weeks")
+                }
+                week_start = weekday as u8
+            }
+        }
         Duration {
             nsecs: nsecs.abs(),
             days: days.abs(),
@@ -172,6 +222,8 @@ impl Duration {
             months: months.abs(),
             negative,
             parsed_int,
+            week_start,
+            month_end,
         }
     }
 
@@ -238,6 +290,8 @@ impl Duration {
             nsecs,
             negative,
             parsed_int: false,
+            week_start: 0,
+            month_end: false,
         }
     }
 
@@ -251,6 +305,8 @@ impl Duration {
             nsecs: 0,
             negative,
             parsed_int: false,
+            week_start: 0,
+            month_end: false,
         }
     }
 
@@ -264,6 +320,8 @@ impl Duration {
             nsecs: 0,
             negative,
             parsed_int: false,
+            week_start: 0,
+            month_end: false,
         }
     }
 
@@ -277,6 +335,8 @@ impl Duration {
             nsecs: 0,
             negative,
             parsed_int: false,
+            week_start: 0,
+            month_end: false,
         }
     }
 
@@ -309,6 +369,16 @@ impl Duration {
         self.days
     }
 
+    /// The day the weeks of the `Duration` start on.
+    pub fn week_start(&self) -> Weekday {
+        WEEKDAYS[self.week_start as usize]
+    }
+
+    /// `true` if the months of the `Duration` are anchored to the end of the month.
+    pub fn month_end(&self) -> bool {
+        self.month_end
+    }
+
     /// Returns the nanoseconds from the `Duration` without the weeks or months part.
     pub fn nanoseconds(&self) -> i64 {
         self.nsecs
@@ -384,7 +454,7 @@ impl Duration {
                     Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz).date(),
                     _ => timestamp_to_datetime(t).date(),
                 };
-                let week_timestamp = dt.week(Weekday::Mon);
+                let week_timestamp = dt.week(self.week_start());
                 let first_day_of_week =
                     week_timestamp.first_day() - chrono::Duration::weeks(self.weeks - 1);
                 match tz {
@@ -428,7 +498,17 @@ impl Duration {
                     Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
                     _ => timestamp_to_datetime(t),
                 };
-                let (year, month) = (ts.year(), ts.month());
+                let (mut year, mut month) = (ts.year(), ts.month());
+                // windows anchored to the end of the month start at the last month end
+                if self.month_end
+                    && last_day_of_month(year, month).map(|d| d.day()) != Some(ts.day())
+                {
+                    (year, month) = if month == 1 {
+                        (year - 1, 12)
+                    } else {
+                        (year, month - 1)
+                    };
+                }
 
                 // determine the total number of months and truncate
                 // the number of months by the duration amount
@@ -439,8 +519,13 @@ impl Duration {
                 // recreate a new time from the year and month combination
                 let (year, month) = ((total / 12), ((total % 12) + 1) as u32);
 
-                let dt = new_datetime(year, month, 1, 0, 0, 0, 0).ok_or(polars_err!(
-                    ComputeError: format!("date '{}-{}-1' does not exist", year, month)
+                let day = if self.month_end {
+                    last_day_of_month(year, month).map_or(1, |d| d.day())
+                } else {
+                    1
+                };
+                let dt = new_datetime(year, month, day, 0, 0, 0, 0).ok_or(polars_err!(
+                    ComputeError: format!("date '{}-{}-{}' does not exist", year, month, day)
                 ))?;
                 match tz {
                     #[cfg(feature = "timezones")]
@@ -521,7 +606,6 @@ impl Duration {
             };
             let mut year = ts.year();
             let mut month = ts.month() as i32;
-            let day = ts.day();
             year += (months / 12) as i32;
             month += (months % 12) as i32;
 
@@ -535,6 +619,11 @@ impl Duration {
                 year -= 1;
                 month += 12;
             }
+            let day = if d.month_end {
+                last_day_of_month(year, month as u32).map_or(ts.day(), |d| d.day())
+            } else {
+                ts.day()
+            };
 
             // Retrieve the original time and construct a data
             // with the new year, month and day
@@ -643,6 +732,16 @@ impl Mul<i64> for Duration {
     }
 }
 
+/// The last day of the given month.
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let (year, month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)?.pred_opt()
+}
+
 fn new_datetime(
     year: i32,
     month: u32,
@@ -676,6 +775,44 @@ mod test {
         assert!(out.negative);
         let out = Duration::parse("5w");
         assert_eq!(out.weeks(), 5);
+        let out = Duration::parse("2w_sun");
+        assert_eq!(out.weeks(), 2);
+        assert_eq!(out.week_start(), Weekday::Sun);
+        let out = Duration::parse("1mo_end");
+        assert!(out.month_end());
+    }
+
+    #[test]
+    fn test_anchors() {
+        use polars_arrow::time_zone::NO_TIMEZONE;
+        let ts = |y, m, d| datetime_to_timestamp_ns(new_datetime(y, m, d, 0, 0, 0, 0).unwrap());
+
+        // 2023-03-15 is a Wednesday
+        let t = ts(2023, 3, 15);
+        let out = Duration::parse("1w_tue")
+            .truncate_ns(t, NO_TIMEZONE)
+            .unwrap();
+        assert_eq!(out, ts(2023, 3, 14));
+        let out = Duration::parse("1w").truncate_ns(t, NO_TIMEZONE).unwrap();
+        assert_eq!(out, ts(2023, 3, 13));
+
+        let month_end = Duration::parse("1mo_end");
+        assert_eq!(
+            month_end.truncate_ns(t, NO_TIMEZONE).unwrap(),
+            ts(2023, 2, 28)
+        );
+        assert_eq!(
+            month_end.truncate_ns(ts(2023, 3, 31), NO_TIMEZONE).unwrap(),
+            ts(2023, 3, 31)
+        );
+        assert_eq!(
+            month_end.add_ns(ts(2023, 1, 31), NO_TIMEZONE).unwrap(),
+            ts(2023, 2, 28)
+        );
+        assert_eq!(
+            month_end.add_ns(ts(2023, 2, 28), NO_TIMEZONE).unwrap(),
+            ts(2023, 3, 31)
+        );
     }
 
     #[test]
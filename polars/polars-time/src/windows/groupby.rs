@@ -224,6 +224,38 @@ pub fn groupby_windows(
     (groups, lower_bound, upper_bound)
 }
 
+/// Like [`groupby_windows`] with [`StartBy::WindowBound`], but for a part of a sorted,
+/// tz-naive time column. The windows start on the truncated timestamps, also the windows that
+/// start before the first value of `time`, so that the windows of consecutive parts can be merged
+/// on their lower bounds. Returns the groups and the lower and upper bounds of the windows.
+pub fn groupby_windows_aligned(
+    window: Window,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    let mut lower_bound = vec![];
+    let mut upper_bound = vec![];
+    let mut groups = vec![];
+    if time.is_empty() {
+        return Ok((groups, lower_bound, upper_bound));
+    }
+    // +1 because left or closed boundary could match the next window if it is on the boundary
+    let boundary = Bounds::new_checked(time[0], time[time.len() - 1] + 1);
+    update_groups_and_bounds(
+        window.get_aligned_bounds_iter(boundary, tu, NO_TIMEZONE)?,
+        0,
+        time,
+        closed_window,
+        true,
+        true,
+        &mut lower_bound,
+        &mut upper_bound,
+        &mut groups,
+    );
+    Ok((groups, lower_bound, upper_bound))
+}
+
 // this assumes that the starting point is alwa
 pub(crate) fn groupby_values_iter_full_lookbehind<'a>(
     period: Duration,
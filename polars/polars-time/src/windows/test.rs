@@ -867,3 +867,67 @@ fn test_groupby_windows_offsets_3776() {
     );
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_groupby_windows_aligned() {
+    let start = NaiveDate::from_ymd_opt(2021, 12, 16)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2021, 12, 16)
+        .unwrap()
+        .and_hms_opt(4, 0, 0)
+        .unwrap();
+    let ts = date_range_vec(
+        start.timestamp_nanos(),
+        stop.timestamp_nanos(),
+        Duration::parse("30m"),
+        ClosedWindow::Both,
+        TimeUnit::Nanoseconds,
+        NO_TIMEZONE,
+    )
+    .unwrap();
+    let w = Window::new(
+        Duration::parse("1h"),
+        Duration::parse("2h"),
+        Duration::parse("0ns"),
+    );
+
+    let (groups, lower, _) = groupby_windows(
+        w,
+        &ts,
+        ClosedWindow::Left,
+        TimeUnit::Nanoseconds,
+        &None,
+        true,
+        true,
+        Default::default(),
+    );
+    let (aligned_groups, aligned_lower, _) =
+        groupby_windows_aligned(w, &ts, ClosedWindow::Left, TimeUnit::Nanoseconds).unwrap();
+    // the window of the previous hour also contains the first values
+    let hour = 3_600_000_000_000;
+    assert_eq!(aligned_lower[0], start.timestamp_nanos() - hour);
+    assert_eq!(aligned_groups[0], [0, 2]);
+    assert_eq!(&aligned_lower[1..], lower.as_slice());
+    assert_eq!(&aligned_groups[1..], groups.as_slice());
+
+    // the windows of the parts add up to the windows of the whole
+    let (first, second) = ts.split_at(5);
+    let (first_groups, first_lower, _) =
+        groupby_windows_aligned(w, first, ClosedWindow::Left, TimeUnit::Nanoseconds).unwrap();
+    let (second_groups, second_lower, _) =
+        groupby_windows_aligned(w, second, ClosedWindow::Left, TimeUnit::Nanoseconds).unwrap();
+    for (lower, [_, len]) in aligned_lower.iter().zip(&aligned_groups) {
+        let len_of = |lowers: &[i64], groups: &[[IdxSize; 2]]| {
+            lowers
+                .iter()
+                .position(|l| l == lower)
+                .map_or(0, |i| groups[i][1])
+        };
+        assert_eq!(
+            len_of(&first_lower, &first_groups) + len_of(&second_lower, &second_groups),
+            *len
+        );
+    }
+}
@@ -182,6 +182,45 @@ impl Window {
     ) -> PolarsResult<BoundsIter<'a, T>> {
         BoundsIter::new(*self, boundary, tu, tz, start_by)
     }
+
+    /// The bounds of the windows that overlap with `boundary`, also the windows that start before
+    /// `boundary.start`. The windows start on the truncated timestamps, independent of
+    /// `boundary.start`, so consecutive parts of a sorted time column have the same windows.
+    pub(crate) fn get_aligned_bounds_iter<'a, T: PolarsTimeZone>(
+        &self,
+        boundary: Bounds,
+        tu: TimeUnit,
+        tz: Option<&'a T>,
+    ) -> PolarsResult<BoundsIter<'a, T>> {
+        #[allow(clippy::type_complexity)]
+        let (truncate, add): (
+            fn(&Window, i64, Option<&'a T>) -> PolarsResult<i64>,
+            fn(&Duration, i64, Option<&'a T>) -> PolarsResult<i64>,
+        ) = match tu {
+            TimeUnit::Nanoseconds => (Window::truncate_ns, Duration::add_ns),
+            TimeUnit::Microseconds => (Window::truncate_us, Duration::add_us),
+            TimeUnit::Milliseconds => (Window::truncate_ms, Duration::add_ms),
+        };
+        polars_ensure!(!self.every.is_zero(), ComputeError: "'every' argument must be non-zero");
+        let back = self.every * -1;
+        let mut start = truncate(self, boundary.start, tz)?;
+        // go back to the earliest window that may contain the first value
+        loop {
+            let prev = add(&back, start, tz)?;
+            if add(&self.period, prev, tz)? < boundary.start {
+                break;
+            }
+            start = prev;
+        }
+        let bi = Bounds::new_checked(start, add(&self.period, start, tz)?);
+        Ok(BoundsIter {
+            window: *self,
+            boundary,
+            bi,
+            tu,
+            tz,
+        })
+    }
 }
 
 pub struct BoundsIter<'a, T: PolarsTimeZone> {
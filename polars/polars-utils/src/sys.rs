@@ -1,7 +1,7 @@
 use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
-use sysinfo::{System, SystemExt};
+use sysinfo::{ProcessExt, System, SystemExt};
 
 /// Startup system is expensive, so we do it once
 pub struct MemInfo {
@@ -15,6 +15,16 @@ impl MemInfo {
         sys.refresh_memory();
         sys.available_memory()
     }
+
+    /// The resident memory of this process in bytes, or `0` if it can't be determined.
+    pub fn process_memory(&self) -> u64 {
+        let Ok(pid) = sysinfo::get_current_pid() else {
+            return 0;
+        };
+        let mut sys = self.sys.lock().unwrap();
+        sys.refresh_process(pid);
+        sys.process(pid).map_or(0, |process| process.memory())
+    }
 }
 
 pub static MEMINFO: Lazy<MemInfo> = Lazy::new(|| MemInfo {
@@ -172,11 +172,14 @@
 //! * `streaming` - Be able to process datasets that are larger than RAM.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from `DataFrame` to `ndarray`
+//! * `arrow-rs` - Convert a `DataFrame` to and from the `RecordBatch` of arrow-rs without copying
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for `Utf8Chunked`
 //!     - `string_justify` - `zfill`, `ljust`, `rjust`
 //!     - `string_from_radix` - `parse_int`
+//!     - `string_anonymize` - `mask`, `hash_sha256`, `pseudonymize`
+//!     - `string_to_number` - `to_float`, `to_decimal`
 //! * `object` - Support for generic ChunkedArrays called `ObjectChunked<T>` (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -195,6 +198,7 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `flight` - Read from an Arrow Flight server
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -207,6 +211,7 @@
 //!     - `rows` - Create `DataFrame` from rows and extract rows from `DataFrames`.
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
+//!     - `interval_join` - Join intervals to the points that fall within them.
 //!     - `cross_join` - Create the cartesian product of two DataFrames.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
 //!     - `groupby_list` - Allow groupby operation on keys of type List.
@@ -215,6 +220,8 @@
 //!     - `horizontal_concat` - Concat horizontally and extend with null values if lengths don't match
 //!     - `dataframe_arithmetic` - Arithmetic on (Dataframe and DataFrames) and (DataFrame on Series)
 //!     - `partition_by` - Split into multiple DataFrames partitioned by groups.
+//!     - `compare` - Compare two DataFrames on key columns and report added, removed and changed cells.
+//!     - `set_operations` - Difference, intersection and union of the rows of two DataFrames.
 //! * `Series`/`Expression` operations:
 //!     - `is_in` - [Check for membership in `Series`](crate::chunked_array::ops::IsIn)
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip)
@@ -246,6 +253,8 @@
 //!     - `log` - Logarithms for `Series`.
 //!     - `list_to_struct` - Convert `List` to `Struct` dtypes.
 //!     - `list_count` - Count elements in lists.
+//!     - `list_contains_multi` - Check if lists contain any or all of multiple values.
+//!     - `list_sets` - Compute the union, intersection and difference of lists.
 //!     - `list_eval` - Apply expressions over list elements.
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
@@ -254,6 +263,7 @@
 //!     - `trigonometry` Trigonometric functions.
 //!     - `sign` Compute the element-wise sign of a Series.
 //!     - `propagate_nans` NaN propagating min/max aggregations.
+//!     - `plugins` Load expressions from the shared libraries of plugins.
 //! * `DataFrame` pretty printing
 //!     - `fmt` - Activate DataFrame formatting
 //!
@@ -368,6 +378,8 @@
 //! * `POLARS_NO_PARQUET_STATISTICS` -> if set, statistics in parquet files are ignored.
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
+//! * `POLARS_TEMP_DIR` -> directory that the out-of-core operations of the streaming engine spill to
+//!                        (default = `~/.polars`). `polars_core::config::set_temp_dir` takes precedence.
 //!
 //!
 //! ## User Guide
@@ -381,8 +393,8 @@ pub mod prelude;
 pub mod sql;
 
 pub use polars_core::{
-    apply_method_all_arrow_series, chunked_array, datatypes, df, doc, error, frame, functions,
-    series, testing,
+    apply_method_all_arrow_series, chunked_array, config, datatypes, df, doc, error, frame,
+    functions, series, testing,
 };
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn test_compare() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3, 4],
+        "a" => [Some("x"), Some("y"), None, Some("w")],
+        "b" => [1.0, 2.0, 3.0, 4.25]
+    ]?;
+    let right = df![
+        "id" => [2, 3, 4, 5],
+        "a" => [Some("y"), Some("z"), Some("w"), Some("v")],
+        "b" => [2.0, 3.0, 4.5, 5.0]
+    ]?;
+
+    let out = left.compare(&right, ["id"])?;
+    assert!(!out.is_empty());
+    assert_eq!(Vec::from(out.removed.column("id")?.i32()?), &[Some(1)]);
+    assert_eq!(Vec::from(out.added.column("id")?.i32()?), &[Some(5)]);
+    assert_eq!(out.added.get_column_names(), &["id", "a", "b"]);
+
+    let changed = out.changed.sort(["id"], false)?;
+    assert_eq!(
+        changed.get_column_names(),
+        &["id", "a_left", "a_right", "b_left", "b_right"]
+    );
+    assert_eq!(Vec::from(changed.column("id")?.i32()?), &[Some(3), Some(4)]);
+    // the values keep their dtypes, unchanged cells are null on both sides
+    assert_eq!(Vec::from(changed.column("a_left")?.utf8()?), &[None, None]);
+    assert_eq!(
+        Vec::from(changed.column("a_right")?.utf8()?),
+        &[Some("z"), None]
+    );
+    assert_eq!(
+        Vec::from(changed.column("b_left")?.f64()?),
+        &[None, Some(4.25)]
+    );
+    assert_eq!(
+        Vec::from(changed.column("b_right")?.f64()?),
+        &[None, Some(4.5)]
+    );
+
+    let out = left.compare(&left, ["id"])?;
+    assert!(out.is_empty());
+    assert_eq!(out.changed.width(), 5);
+
+    // the keys must identify a single row
+    let duplicated = df!["id" => [1, 1], "a" => ["x", "y"], "b" => [1.0, 2.0]]?;
+    assert!(left.compare(&duplicated, ["id"]).is_err());
+    assert!(duplicated.compare(&left, ["id"]).is_err());
+    Ok(())
+}
@@ -94,3 +94,268 @@ fn test_sorted_groupby() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_groupby_heavy_hitter() -> PolarsResult<()> {
+    // One key holds most of the rows and is spread over the threads by row range.
+    let n = 100_000;
+    let values = (0..n)
+        .map(|i| match i {
+            0 => None,
+            i if i % 10 == 0 => Some(i),
+            _ => Some(-1),
+        })
+        .collect::<Vec<_>>();
+    let expected_heavy = (0..n as IdxSize)
+        .filter(|i| i % 10 != 0)
+        .collect::<Vec<_>>();
+    for s in [
+        Series::new("a", &values),
+        Series::new(
+            "a",
+            values.iter().map(|v| v.unwrap_or(0)).collect::<Vec<_>>(),
+        ),
+    ] {
+        let out = s.group_tuples(true, true)?;
+        let groups = out.unwrap_idx();
+        assert_eq!(groups.len(), n as usize / 10 + 1);
+        assert_eq!(groups.first()[..2], [0, 1]);
+        assert_eq!(groups.all()[1], expected_heavy);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sorted_groupby_utf8_and_multiple_keys() -> PolarsResult<()> {
+    let mut s = Series::new("a", &[None, Some("a"), Some("a"), Some("b"), Some("c")]);
+    s.set_sorted_flag(IsSorted::Ascending);
+    for mt in [true, false] {
+        let out = s.group_tuples(mt, false)?;
+        assert_eq!(out.unwrap_slice(), &[[0, 1], [1, 2], [3, 1], [4, 1]]);
+    }
+
+    let mut a = Series::new("a", &[1, 1, 1, 2, 2, 3]);
+    a.set_sorted_flag(IsSorted::Ascending);
+    let mut b = Series::new("b", &["x", "x", "y", "y", "y", "z"]);
+    b.set_sorted_flag(IsSorted::Ascending);
+    let df = DataFrame::new(vec![a, b, Series::new("c", &[1, 2, 3, 4, 5, 6])])?;
+    let gb = df.groupby(["a", "b"])?;
+    assert_eq!(
+        gb.get_groups().unwrap_slice(),
+        &[[0, 2], [2, 1], [3, 2], [5, 1]]
+    );
+    let out = gb.select(["c"]).sum()?;
+    assert_eq!(
+        Vec::from(out.column("c_sum")?.i32()?),
+        &[Some(3), Some(3), Some(9), Some(6)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_groupby_multiple_keys_cardinality() -> PolarsResult<()> {
+    // Few groups run on a single partition, many groups on all partitions.
+    for n_groups in [3, 50_000] {
+        let n = 100_000;
+        let a = (0..n).map(|i| i % n_groups).collect::<Vec<_>>();
+        let b = (0..n).map(|i| (i % n_groups) as f64).collect::<Vec<_>>();
+        let df = df![
+            "a" => a,
+            "b" => b,
+        ]?;
+        let gb = df.groupby_stable(["a", "b"])?;
+        let groups = gb.get_groups().unwrap_idx();
+        assert_eq!(groups.len(), n_groups as usize);
+        assert!(groups
+            .iter()
+            .enumerate()
+            .all(|(i, (first, all))| first as usize == i && all.len() == (n / n_groups) as usize));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_groups_proxy_subset() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 1, 3, 2],
+    ]?;
+    let gb = df.groupby_stable(["a"])?;
+    let groups = gb.get_groups();
+
+    let head = groups.head(2);
+    assert_eq!(head.unwrap_idx().all(), &[vec![0, 2], vec![1, 4]]);
+
+    let taken = groups.take(&[2, 0])?;
+    assert_eq!(taken.unwrap_idx().first(), &[3, 0]);
+    assert!(groups.take(&[3]).is_err());
+
+    let mask = BooleanChunked::new("", &[Some(true), None, Some(true)]);
+    let filtered = groups.filter(&mask)?;
+    assert_eq!(filtered.unwrap_idx().all(), &[vec![0, 2], vec![3]]);
+    assert!(groups.filter(&BooleanChunked::new("", &[true])).is_err());
+
+    let slices = GroupsProxy::Slice {
+        groups: vec![[0, 2], [2, 1], [3, 2]],
+        rolling: false,
+    };
+    assert_eq!(slices.head(5).unwrap_slice(), slices.unwrap_slice());
+    assert_eq!(slices.take(&[1])?.unwrap_slice(), &[[2, 1]]);
+    Ok(())
+}
+
+#[test]
+fn test_groupby_approx_n_unique_eager() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 1, 1, 2, 2],
+        "v" => [Some(1), Some(1), Some(2), None, Some(3)],
+    ]?;
+    let out = df.groupby_stable(["k"])?.select(["v"]).approx_n_unique()?;
+    // small groups are counted exactly, nulls count as a value
+    assert_eq!(
+        Vec::from(out.column("v_approx_n_unique")?.idx()?),
+        &[Some(2), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_group_by_indices() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "y", "x", "z", "y"],
+        "b" => [1, 2, 3, 4, 5],
+    ]?;
+    let (keys, groups) = df.group_by_indices(["a"], true)?;
+    assert_eq!(keys.get_column_names(), &["a"]);
+    assert_eq!(
+        Vec::from(keys.column("a")?.utf8()?),
+        &[Some("x"), Some("y"), Some("z")]
+    );
+    let groups = groups.unwrap_idx();
+    assert_eq!(groups.all(), &[vec![0, 2], vec![1, 4], vec![3]]);
+    Ok(())
+}
+
+#[test]
+fn test_groupby_dense_integer_keys() -> PolarsResult<()> {
+    // the keys span fewer values than there are rows, so they are grouped by their offset
+    let s = Series::new(
+        "a",
+        &[Some(12u32), Some(10), None, Some(12), Some(11), None],
+    );
+    let groups = s.group_tuples(false, true)?;
+    assert_eq!(groups.unwrap_idx().first(), &[0, 1, 2, 4]);
+    assert_eq!(
+        groups.unwrap_idx().all(),
+        &[vec![0, 3], vec![1], vec![2, 5], vec![4]]
+    );
+
+    let n = 10_000;
+    let s = Series::new(
+        "a",
+        (0..n)
+            .map(|i: u32| ((i * 7) % 100) as u16)
+            .collect::<Vec<_>>(),
+    );
+    let groups = s.group_tuples(true, true)?;
+    let groups = groups.unwrap_idx();
+    assert_eq!(groups.len(), 100);
+    assert!(groups
+        .iter()
+        .all(|(first, all)| all.len() == 100 && all[0] == first && first < 100));
+    Ok(())
+}
+
+#[test]
+fn test_groupby_join_cached_hashes() -> PolarsResult<()> {
+    let keys = Series::new("k", &[Some("a"), Some("b"), None, Some("a"), Some("b")]);
+    let cached = keys.with_cached_hashes()?;
+    let df = DataFrame::new(vec![cached.clone(), Series::new("v", &[1i32, 2, 3, 4, 5])])?;
+
+    for multithreaded in [false, true] {
+        let groups = cached.group_tuples(multithreaded, true)?;
+        assert_eq!(
+            groups.unwrap_idx().all(),
+            &[vec![0, 3], vec![1, 4], vec![2]]
+        );
+    }
+    let out = df.groupby_stable(["k"])?.sum()?;
+    assert_eq!(
+        Vec::from(out.column("v_sum")?.i32()?),
+        &[Some(5), Some(7), Some(3)]
+    );
+
+    // the other side doesn't have cached hashes
+    let right = df!("k" => ["b", "c", "a"], "w" => [10, 20, 30])?;
+    let out = df.inner_join(&right, ["k"], ["k"])?;
+    let mut pairs = out
+        .column("v")?
+        .i32()?
+        .into_no_null_iter()
+        .zip(out.column("w")?.i32()?.into_no_null_iter())
+        .collect::<Vec<_>>();
+    pairs.sort_unstable();
+    assert_eq!(pairs, &[(1, 30), (2, 10), (4, 30), (5, 10)]);
+    let out = right.left_join(&df, ["k"], ["k"])?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(2), Some(5), None, Some(1), Some(4)]
+    );
+
+    assert!(Series::new("k", &[1, 2]).with_cached_hashes().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_groupby_boolean_keys() -> PolarsResult<()> {
+    let df = df!(
+        "k" => [Some(true), None, Some(false), Some(true), None, Some(true)],
+        "v" => [Some(true), Some(false), None, Some(false), Some(true), Some(true)]
+    )?;
+    for multithreaded in [false, true] {
+        let groups = df.column("k")?.group_tuples(multithreaded, false)?;
+        assert_eq!(
+            groups.unwrap_idx().all(),
+            &[vec![0, 3, 5], vec![1, 4], vec![2]]
+        );
+    }
+    let out = df.groupby_stable(["k"])?.sum()?;
+    assert_eq!(
+        Vec::from(out.column("k")?.bool()?),
+        &[Some(true), None, Some(false)]
+    );
+    // the group of `false` only has a null value
+    assert_eq!(
+        Vec::from(out.column("v_sum")?.idx()?),
+        &[Some(2), Some(1), None]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_groupby_dense_signed_keys() -> PolarsResult<()> {
+    // keys around zero are dense once their sign bit is flipped
+    let s = Series::new(
+        "a",
+        &[Some(-1i32), Some(1), Some(0), Some(-1), None, Some(1)],
+    );
+    let groups = s.group_tuples(false, false)?;
+    assert_eq!(groups.unwrap_idx().first(), &[0, 1, 2, 4]);
+    assert_eq!(
+        groups.unwrap_idx().all(),
+        &[vec![0, 3], vec![1, 5], vec![2], vec![4]]
+    );
+
+    let n = 10_000;
+    let s = Series::new("a", (0..n).map(|i: i64| i % 21 - 10).collect::<Vec<_>>());
+    let groups = s.group_tuples(true, true)?;
+    let groups = groups.unwrap_idx();
+    assert_eq!(groups.len(), 21);
+    assert!(groups
+        .iter()
+        .all(|(first, all)| all[0] == first && all.iter().all(|i| i % 21 == first % 21)));
+    assert_eq!(
+        groups.iter().map(|(_, all)| all.len()).sum::<usize>(),
+        n as usize
+    );
+    Ok(())
+}
@@ -614,3 +614,28 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+fn test_join_heavy_hitter() -> PolarsResult<()> {
+    // The build side (the smaller frame) is dominated by a single key.
+    let n = 100_000;
+    let right = df![
+        "k" => (0..n).map(|i| if i % 10 == 0 { i } else { -1 }).collect::<Vec<_>>(),
+        "r" => (0..n).collect::<Vec<_>>(),
+    ]?;
+    let left = df![
+        "k" => (-1..2 * n).collect::<Vec<_>>(),
+    ]?;
+    let out = left.inner_join(&right, ["k"], ["k"])?;
+    assert_eq!(out.height(), n as usize);
+
+    let out = out.filter(&out.column("k")?.equal(-1)?)?;
+    let r = out
+        .column("r")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    let expected = (0..n).filter(|i| i % 10 != 0).collect::<Vec<_>>();
+    assert_eq!(r, expected);
+    Ok(())
+}
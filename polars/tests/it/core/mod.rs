@@ -1,3 +1,5 @@
+#[cfg(feature = "compare")]
+mod compare;
 mod date_like;
 mod groupby;
 mod joins;
@@ -7,6 +9,8 @@ mod pivot;
 #[cfg(feature = "rolling_window")]
 mod rolling_window;
 mod series;
+#[cfg(feature = "set_operations")]
+mod set_operations;
 mod utils;
 
 use polars::prelude::*;
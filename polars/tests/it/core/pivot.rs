@@ -1,6 +1,6 @@
 use polars::export::chrono::NaiveDate;
 use polars::prelude::*;
-use polars_ops::pivot::{pivot, pivot_stable, PivotAgg};
+use polars_ops::pivot::{pivot, pivot_stable, pivot_table, PivotAgg};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -245,3 +245,126 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_table_margins() -> PolarsResult<()> {
+    let df = df![
+        "foo" => ["A", "A", "B", "B", "C"],
+        "N" => [1, 2, 2, 4, 2],
+        "bar" => ["k", "l", "m", "m", "l"]
+    ]?;
+
+    let out = pivot_table(
+        &df,
+        ["N"],
+        ["foo"],
+        ["bar"],
+        false,
+        &[PivotAgg::Sum, PivotAgg::Count],
+        Some("All"),
+        None,
+    )?;
+    let expected = df![
+        "foo" => ["A", "B", "C", "All"],
+        "sum_N_bar_k" => [Some(1), None, None, Some(1)],
+        "sum_N_bar_l" => [Some(2), None, Some(2), Some(4)],
+        "sum_N_bar_m" => [None, Some(6), None, Some(6)],
+        "sum_N_bar_All" => [3, 6, 2, 11],
+        "count_N_bar_k" => [Some(1 as IdxSize), None, None, Some(1)],
+        "count_N_bar_l" => [Some(1 as IdxSize), None, Some(1), Some(2)],
+        "count_N_bar_m" => [None, Some(2 as IdxSize), None, Some(2)],
+        "count_N_bar_All" => [2 as IdxSize, 2, 1, 5],
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+
+    // a single aggregation keeps the names of `pivot`
+    let out = pivot_table(
+        &df,
+        ["N"],
+        ["foo"],
+        ["bar"],
+        true,
+        &[PivotAgg::Max],
+        Some("total"),
+        None,
+    )?;
+    assert_eq!(out.get_column_names(), &["foo", "k", "l", "m", "total"]);
+    assert_eq!(
+        Vec::from(out.column("total")?.i32()?),
+        &[Some(2), Some(4), Some(2), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pivot_table_expr_aggs() -> PolarsResult<()> {
+    use polars_core::frame::groupby::expr::PhysicalAggExpr;
+
+    struct AggSum;
+
+    impl PhysicalAggExpr for AggSum {
+        fn evaluate(&self, df: &DataFrame, groups: &GroupsProxy) -> PolarsResult<Series> {
+            Ok(unsafe { df.get_columns()[0].agg_sum(groups) })
+        }
+
+        fn root_name(&self) -> PolarsResult<&str> {
+            Ok("")
+        }
+    }
+
+    let df = df![
+        "foo" => ["A", "A", "B", "B", "C"],
+        "N" => [1, 2, 2, 4, 2],
+        "bar" => ["k", "l", "m", "m", "l"]
+    ]?;
+
+    // different expressions do not collide, and their margins merge the groups of the cells
+    let out = pivot_table(
+        &df,
+        ["N"],
+        ["foo"],
+        ["bar"],
+        false,
+        &[
+            PivotAgg::Expr(Arc::new(AggSum)),
+            PivotAgg::Expr(Arc::new(AggSum)),
+            PivotAgg::First,
+        ],
+        Some("All"),
+        None,
+    )?;
+    assert_eq!(
+        out.get_column_names()[1..5],
+        [
+            "expr_0_N_bar_k",
+            "expr_0_N_bar_l",
+            "expr_0_N_bar_m",
+            "expr_0_N_bar_All"
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("expr_1_N_bar_All")?.i32()?),
+        &[Some(3), Some(6), Some(2), Some(11)]
+    );
+    assert_eq!(
+        Vec::from(out.column("first_N_bar_All")?.i32()?),
+        &[Some(1), Some(2), Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("first_N_bar_l")?.i32()?),
+        &[Some(2), None, Some(2), Some(2)]
+    );
+
+    let out = pivot_table(
+        &df,
+        ["N"],
+        ["foo"],
+        ["bar"],
+        false,
+        &[PivotAgg::Sum, PivotAgg::Sum],
+        None,
+        None,
+    );
+    assert!(out.is_err());
+    Ok(())
+}
@@ -36,3 +36,59 @@ fn test_min_max_sorted_desc() {
     assert_eq!(a.max(), Some(4));
     assert_eq!(a.min(), Some(1));
 }
+
+#[test]
+fn test_equal_missing() -> PolarsResult<()> {
+    let a = Series::new("a", &[Some(1), None, Some(3), None]);
+    let b = Series::new("b", &[Some(1), Some(2), None, None]);
+    assert_eq!(
+        Vec::from(&a.equal_missing(&b)?),
+        &[Some(true), Some(false), Some(false), Some(true)]
+    );
+    assert_eq!(
+        Vec::from(&a.not_equal_missing(&b)?),
+        &[Some(false), Some(true), Some(true), Some(false)]
+    );
+
+    let mut builder = get_list_builder(&DataType::Int32, 8, 3, "a")?;
+    builder.append_opt_series(Some(&Series::new("", &[Some(1), None])));
+    builder.append_opt_series(None);
+    builder.append_opt_series(Some(&Series::new("", &[2, 3])));
+    let a = builder.finish().into_series();
+    let mut builder = get_list_builder(&DataType::Int32, 8, 3, "b")?;
+    builder.append_opt_series(Some(&Series::new("", &[Some(1), None])));
+    builder.append_opt_series(None);
+    builder.append_opt_series(Some(&Series::new("", &[2])));
+    let b = builder.finish().into_series();
+    assert_eq!(
+        Vec::from(&a.equal_missing(&b)?),
+        &[Some(true), Some(true), Some(false)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_equal_missing_struct() -> PolarsResult<()> {
+    let a = StructChunked::new(
+        "a",
+        &[
+            Series::new("x", &[Some(1), None, Some(3)]),
+            Series::new("y", &[Some("a"), Some("b"), None]),
+        ],
+    )?
+    .into_series();
+    let b = StructChunked::new(
+        "b",
+        &[
+            Series::new("x", &[Some(1), None, Some(3)]),
+            Series::new("y", &[Some("a"), Some("c"), None]),
+        ],
+    )?
+    .into_series();
+    assert_eq!(
+        Vec::from(&a.equal_missing(&b)?),
+        &[Some(true), Some(false), Some(true)]
+    );
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn test_set_operations() -> PolarsResult<()> {
+    let left = df![
+        "a" => [Some(1), Some(2), Some(2), None],
+        "b" => ["x", "y", "y", "z"]
+    ]?;
+    // same rows, different column order
+    let right = df![
+        "b" => ["y", "z", "w"],
+        "a" => [Some(2), None, Some(3)]
+    ]?;
+
+    let out = left.set_difference(&right)?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1)]);
+
+    // nulls compare equal and duplicates are removed
+    let out = left.set_intersection(&right)?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(2), None]);
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+
+    let out = left.set_union(&right, true)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(2), None, Some(3)]
+    );
+    let out = left.set_union(&right, false)?;
+    assert_eq!(out.height(), 7);
+
+    let other = df!["a" => [1.0], "b" => ["x"]]?;
+    assert!(left.set_difference(&other).is_err());
+    Ok(())
+}
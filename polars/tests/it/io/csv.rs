@@ -1,5 +1,6 @@
 use std::io::Cursor;
 
+use polars::io::mmap::MmapBytesReader;
 use polars::io::RowCount;
 
 use super::*;
@@ -1087,3 +1088,217 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::Float64(4.1));
     Ok(())
 }
+
+#[test]
+fn test_header_normalization() -> PolarsResult<()> {
+    let csv = "a,a,a_duplicated_0,b\n1,2,3,4\n";
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    assert_eq!(
+        df.get_column_names(),
+        &["a", "a_duplicated_1", "a_duplicated_0", "b"]
+    );
+
+    let csv = " Unit%20Price ,unit  price,Total\tAmount,ID\n1.5,2.5,3,4\n";
+    let normalization = HeaderNormalization {
+        percent_decode: true,
+        trim: true,
+        replace_whitespace: Some("_".to_string()),
+        lowercase: true,
+        duplicates: DuplicateNames::Numbered,
+    };
+    let mut schema = Schema::new();
+    schema.with_column("id".into(), DataType::Utf8);
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_header_normalization(Some(normalization))
+        .with_dtypes(Some(Arc::new(schema)))
+        .finish()?;
+    assert_eq!(
+        df.get_column_names(),
+        &["unit_price", "unit_price_1", "total_amount", "id"]
+    );
+    assert_eq!(df.column("id")?.dtype(), &DataType::Utf8);
+    Ok(())
+}
+
+#[test]
+fn test_multi_char_delimiter() -> PolarsResult<()> {
+    let csv = "a||b||c\n1||x|y||\"p||q\"\n2||z||r\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_delimiter(CsvDelimiter::try_from("||")?)
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => ["x|y", "z"],
+        "c" => ["p||q", "r"],
+    ]?;
+    assert!(df.frame_equal(&expected));
+
+    // the fields that contain the delimiter are quoted when written
+    let mut buf = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_delimiter(CsvDelimiter::try_from("||")?)
+        .finish(&mut df.clone())?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "a||b||c\n1||x|y||\"p||q\"\n2||z||r\n"
+    );
+
+    assert!(CsvDelimiter::try_from("").is_err());
+    assert!(CsvReader::new(Cursor::new(csv))
+        .with_delimiter(CsvDelimiter::try_from("|\n")?)
+        .finish()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_comment_and_quotes() -> PolarsResult<()> {
+    // the quote in the comment doesn't start a string field
+    let csv = "a,b\n# don't \"quote\n1,2\n3,\"#4\n#5\"\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_comment_char(Some(b'#'))
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 3],
+        "b" => ["2", "#4\n#5"],
+    ]?;
+    assert!(df.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_skip_rows_after_header_quoted() -> PolarsResult<()> {
+    let csv = "a,b\n\"x\ny\",1\nz,2\nw,3\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_skip_rows_after_header(1)
+        .finish()?;
+    let expected = df![
+        "a" => ["z", "w"],
+        "b" => [2i64, 3],
+    ]?;
+    assert!(df.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_formats() -> PolarsResult<()> {
+    let csv = "datetime,date,text\n01.02.2023 10:30,01/02/2023,a\n15.03.2023 08:00,15/03/2023,b\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_try_parse_dates(true)
+        .with_datetime_formats(Some(vec![
+            "%d/%m/%Y".to_string(),
+            "%d.%m.%Y %H:%M".to_string(),
+        ]))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::Datetime(TimeUnit::Microseconds, None),
+            DataType::Date,
+            DataType::Utf8
+        ]
+    );
+    assert_eq!(
+        df.column("datetime")?.cast(&DataType::Utf8)?.utf8()?.get(0),
+        Some("2023-02-01 10:30:00")
+    );
+    assert_eq!(
+        df.column("date")?.cast(&DataType::Utf8)?.utf8()?.get(1),
+        Some("2023-03-15")
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_datetime_formats_resolved_once() -> PolarsResult<()> {
+    // the first rows only match the first format, which is used for the whole column, also in
+    // the chunks where all values would match the second format
+    let mut csv = "date\n".to_string();
+    for i in 0..10_000 {
+        csv.push_str(if i < 5_000 || i % 2 == 0 {
+            "01/02/2023\n"
+        } else {
+            "13/02/2023\n"
+        });
+    }
+    let formats = Some(vec!["%m/%d/%Y".to_string(), "%d/%m/%Y".to_string()]);
+    let df = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_try_parse_dates(true)
+        .with_datetime_formats(formats.clone())
+        .with_n_threads(Some(4))
+        .with_chunk_size(100)
+        .finish()?;
+    let dates = df.column("date")?.cast(&DataType::Utf8)?;
+    let dates = dates.utf8()?;
+    assert_eq!(dates.null_count(), 2_500);
+    assert!(dates.into_iter().flatten().all(|date| date == "2023-01-02"));
+
+    let file: Box<dyn MmapBytesReader> = Box::new(Cursor::new(csv.into_bytes()));
+    let mut reader = CsvReader::new(file)
+        .with_try_parse_dates(true)
+        .with_datetime_formats(formats)
+        .with_chunk_size(100);
+    let mut reader = reader.batched_borrowed_mmap()?;
+    let mut null_count = 0;
+    while let Some(batches) = reader.next_batches(8)? {
+        for df in batches {
+            let dates = df.column("date")?.cast(&DataType::Utf8)?;
+            null_count += dates.null_count();
+            assert!(dates
+                .utf8()?
+                .into_iter()
+                .flatten()
+                .all(|date| date == "2023-01-02"));
+        }
+    }
+    assert_eq!(null_count, 2_500);
+    Ok(())
+}
+
+#[test]
+fn test_infer_schema_full_scan() -> PolarsResult<()> {
+    let mut csv = "a,b\n".to_string();
+    for i in 0..20_000 {
+        csv.push_str(&format!("{i},{i}\n"));
+    }
+    csv.push_str("x,1\n");
+
+    let df = CsvReader::new(Cursor::new(csv.as_str()))
+        .infer_schema(None)
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Utf8, DataType::Int64]);
+    assert_eq!(df.height(), 20_001);
+
+    // the first rows are inferred as integers
+    assert!(CsvReader::new(Cursor::new(csv.as_str()))
+        .infer_schema(Some(100))
+        .finish()
+        .is_err());
+    // unless the dtype is set by name
+    let df = CsvReader::new(Cursor::new(csv.as_str()))
+        .infer_schema(Some(100))
+        .with_dtypes(Some(Arc::new(Schema::from_iter([Field::new(
+            "a",
+            DataType::Utf8,
+        )]))))
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Utf8, DataType::Int64]);
+    Ok(())
+}
+
+#[test]
+fn test_number_format() -> PolarsResult<()> {
+    let csv = "item,price,qty\na,\"$1,234.50\",\"1,000\"\nb,-$12,2\nc,,3\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_number_format(Some(NumberFormat::new(Some(b','), vec!["$".to_string()])))
+        .finish()?;
+    let expected = df![
+        "item" => ["a", "b", "c"],
+        "price" => [Some(1234.5), Some(-12.0), None],
+        "qty" => [1000i64, 2, 3],
+    ]?;
+    assert!(df.frame_equal_missing(&expected));
+    Ok(())
+}
@@ -10,6 +10,26 @@ mod test {
 
     use crate::io::create_df;
 
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_ipc_stream_round_trip() -> PolarsResult<()> {
+        let s = Int128Chunked::from_slice_options("a", &[Some(12345), None, Some(-99)])
+            .into_decimal(Some(10), 2)?
+            .into_series();
+        let mut df = DataFrame::new(vec![s])?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcStreamWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcStreamReader::new(buf).finish()?;
+        assert_eq!(
+            df_read.column("a")?.dtype(),
+            &DataType::Decimal(Some(10), Some(2))
+        );
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
     #[test]
     fn write_and_read_ipc_stream() {
         // Vec<T> : Write + Read
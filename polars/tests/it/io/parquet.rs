@@ -19,6 +19,70 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_decimal_parquet_round_trip() -> PolarsResult<()> {
+    let s = Int128Chunked::from_slice_options("a", &[Some(12345), None, Some(-99)])
+        .into_decimal(Some(10), 2)?
+        .into_series();
+    let mut df = DataFrame::new(vec![s])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(
+        read_df.column("a")?.dtype(),
+        &DataType::Decimal(Some(10), Some(2))
+    );
+    assert!(df.frame_equal_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_array_parquet_round_trip() -> PolarsResult<()> {
+    let s = Series::new("a", [1i32, 2, 3, 4, 5, 6]).reshape(&[-1, 2])?;
+    let s = s.cast(&DataType::Array(Box::new(DataType::Int32), 2))?;
+    let mut df = DataFrame::new(vec![s.clone()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    let out = read_df.column("a")?;
+    assert_eq!(out.dtype(), s.dtype());
+    let list_dtype = DataType::List(Box::new(DataType::Int32));
+    assert!(out
+        .cast(&list_dtype)?
+        .series_equal_missing(&s.cast(&list_dtype)?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_enum_parquet_round_trip() -> PolarsResult<()> {
+    let dtype = DataType::new_enum(["low", "mid", "high"])?;
+    let s = Series::new(
+        "a",
+        [Some("high"), Some("low"), None, Some("mid"), Some("high")],
+    )
+    .strict_cast(&dtype)?;
+    let mut df = DataFrame::new(vec![s])?;
+    let mut buf = Cursor::new(Vec::new());
+    // several row groups, which are read as separate chunks
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+
+    let out = read_df.column("a")?;
+    assert_eq!(out.dtype(), &dtype);
+    assert!(df.frame_equal_missing(&read_df));
+    let sorted = out.sort(false).cast(&DataType::Utf8)?;
+    assert_eq!(
+        Vec::from(sorted.utf8()?),
+        &[None, Some("low"), Some("mid"), Some("high"), Some("high")]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_files() -> PolarsResult<()> {
     let files_to_load_set = vec![
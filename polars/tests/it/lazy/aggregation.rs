@@ -95,3 +95,45 @@ fn test_apply_multiple_error() {
         .collect()
         .unwrap();
 }
+
+#[test]
+fn test_count_len_null_semantics() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "a" => [Some(1), None, None, None]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").count().alias("count"), col("a").len().alias("len")])
+        .collect()?;
+    assert_eq!(out.column("count")?.get(0)?, AnyValue::from(1 as IdxSize));
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::from(4 as IdxSize));
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("a").count().alias("count"), col("a").len().alias("len")])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("count")?.idx()?), &[Some(1), Some(0)]);
+    assert_eq!(Vec::from(out.column("len")?.idx()?), &[Some(2), Some(2)]);
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").count().over([col("g")]).alias("count"),
+            col("a").len().over([col("g")]).alias("len"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[Some(1), Some(1), Some(0), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("len")?.idx()?),
+        &[Some(2), Some(2), Some(2), Some(2)]
+    );
+    Ok(())
+}
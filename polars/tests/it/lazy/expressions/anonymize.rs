@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn test_str_anonymize() -> PolarsResult<()> {
+    let df = df![
+        "email" => [Some("john@doe.com"), Some("ab"), None, Some("john@doe.com")]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("email").str().mask(2, 4, '*').alias("masked"),
+            col("email").str().hash_sha256("salt").alias("hashed"),
+            col("email").str().pseudonymize(42).alias("token"),
+        ])
+        .collect()?;
+
+    let masked = out.column("masked")?;
+    assert_eq!(
+        Vec::from(masked.utf8()?),
+        &[Some("jo******.com"), Some("**"), None, Some("jo******.com")]
+    );
+
+    let hashed = out.column("hashed")?.utf8()?;
+    assert_eq!(hashed.get(0).unwrap().len(), 64);
+    assert_eq!(hashed.get(0), hashed.get(3));
+    assert_ne!(hashed.get(0), hashed.get(1));
+
+    let token = out.column("token")?.utf8()?;
+    assert_eq!(token.get(0), token.get(3));
+    assert_ne!(token.get(0), token.get(1));
+    assert_eq!(token.get(2), None);
+    Ok(())
+}
@@ -44,6 +44,45 @@ fn ternary_expand_sizes() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn ternary_scalar_broadcast() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [Some(1), None, Some(3)],
+    }?;
+    let out = df
+        .lazy()
+        .select([
+            // null in the mask takes the otherwise branch
+            when(col("a").gt(lit(1)))
+                .then(lit(10))
+                .otherwise(lit(20))
+                .alias("both_scalar"),
+            when(col("a").gt(lit(1)))
+                .then(col("a"))
+                .otherwise(lit(0))
+                .alias("falsy_scalar"),
+            // a scalar mask selects a branch as a whole
+            when(lit(false))
+                .then(col("a"))
+                .otherwise(lit(0))
+                .alias("scalar_mask"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("both_scalar")?.i32()?),
+        &[Some(20), Some(20), Some(10)]
+    );
+    assert_eq!(
+        Vec::from(out.column("falsy_scalar")?.i32()?),
+        &[Some(0), Some(0), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("scalar_mask")?.i32()?),
+        &[Some(0), Some(0), Some(0)]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "strings")]
 fn includes_null_predicate_3038() -> PolarsResult<()> {
@@ -384,3 +423,26 @@ fn test_binary_group_consistency() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_eq_missing() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, Some(3), None],
+        "b" => [Some(1), Some(2), None, None],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").eq_missing(col("b")).alias("eq"),
+            col("a").neq_missing(col("b")).alias("neq"),
+            col("a").eq_missing(NULL.lit()).alias("eq_null"),
+        ])
+        .collect()?;
+    let expected = df![
+        "eq" => [true, false, false, true],
+        "neq" => [false, true, true, false],
+        "eq_null" => [false, true, false, true],
+    ]?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn test_array_namespace() -> PolarsResult<()> {
+    let dtype = DataType::Array(Box::new(DataType::Int32), 2);
+    let a = Series::new("a", [1i32, 2, 3, 4])
+        .reshape(&[-1, 2])?
+        .cast(&dtype)?;
+    let b = Series::new("b", [5i32, 6, 7, 8])
+        .reshape(&[-1, 2])?
+        .cast(&dtype)?;
+    let df = DataFrame::new(vec![a, b])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").arr().sum().alias("sum"),
+            col("a").arr().mean().alias("mean"),
+            col("a").arr().get(lit(-1)).alias("last"),
+            col("a").arr().dot(col("b")).alias("dot"),
+            col("a").arr().cosine_similarity(col("a")).alias("cosine"),
+            col("a").arr().l2_distance(col("b")).alias("l2"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("sum")?.i32()?), &[Some(3), Some(7)]);
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(1.5), Some(3.5)]
+    );
+    assert_eq!(Vec::from(out.column("last")?.i32()?), &[Some(2), Some(4)]);
+    assert_eq!(
+        Vec::from(out.column("dot")?.f64()?),
+        &[Some(17.0), Some(53.0)]
+    );
+    let cosine = out.column("cosine")?.f64()?;
+    assert!(cosine.into_no_null_iter().all(|v| (v - 1.0).abs() < 1e-12));
+    assert_eq!(
+        Vec::from(out.column("l2")?.f64()?),
+        &[Some(32f64.sqrt()), Some(32f64.sqrt())]
+    );
+
+    let query = Series::new("", [4i32, 4]).reshape(&[1, 2])?;
+    let out = df
+        .lazy()
+        .select([col("a")
+            .arr()
+            .top_k_nearest(lit(query), 1, DistanceMetric::L2)
+            .alias("nearest")])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("nearest")?.idx()?), &[Some(1)]);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_list_contains_any_all() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        [
+            Series::new("", [1, 2, 3]),
+            Series::new("", [3, 3]),
+            Series::new("", [4]),
+            Series::new_empty("", &DataType::Int32),
+        ],
+    );
+    let df = DataFrame::new(vec![s])?;
+    let needles = Series::new("needles", [1i32, 3, 3]);
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .arr()
+                .contains_any(lit(needles.clone()))
+                .alias("any"),
+            col("a").arr().contains_all(lit(needles)).alias("all"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("any")?.bool()?),
+        &[Some(true), Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("all")?.bool()?),
+        &[Some(true), Some(false), Some(false), Some(false)]
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn test_list_eval() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        [
+            Series::new("", [1i32, 8, 3]),
+            Series::new("", [3i32, 2]),
+            Series::new("", [4i32]),
+        ],
+    );
+    let df = DataFrame::new(vec![s])?;
+
+    for parallel in [false, true] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                col("a")
+                    .list()
+                    .eval(col("").rank(Default::default(), None), parallel)
+                    .alias("rank"),
+                col("a")
+                    .list()
+                    .eval(col("").filter(col("").gt(lit(2))), parallel)
+                    .alias("filter"),
+                col("a")
+                    .list()
+                    .eval(col("") * lit(2), parallel)
+                    .alias("mul"),
+            ])
+            .collect()?;
+
+        let rank = out.column("rank")?.explode()?;
+        assert_eq!(
+            Vec::from(rank.idx()?),
+            &[Some(1), Some(3), Some(2), Some(2), Some(1), Some(1)]
+        );
+        let filter = out.column("filter")?.explode()?;
+        assert_eq!(
+            Vec::from(filter.i32()?),
+            &[Some(8), Some(3), Some(3), Some(4)]
+        );
+        let mul = out.column("mul")?.explode()?;
+        assert_eq!(
+            Vec::from(mul.i32()?),
+            &[Some(2), Some(16), Some(6), Some(6), Some(4), Some(8)]
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_list_set_operations() -> PolarsResult<()> {
+    let df = df![
+        "a" => [
+            Series::new("", [1i64, 2, 2]),
+            Series::new("", [3i64]),
+            Series::new("", [4i64, 5]),
+        ],
+        "b" => [
+            Series::new("", [2i32, 3]),
+            Series::new("", [3i32, 4]),
+            Series::new("", [6i32]),
+        ]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").list().set_union(col("b")).alias("union"),
+            col("a")
+                .list()
+                .set_intersection(col("b"))
+                .alias("intersection"),
+            col("a").list().set_difference(col("b")).alias("difference"),
+        ])
+        .collect()?;
+
+    let get = |name: &str| -> PolarsResult<Vec<Vec<Option<i64>>>> {
+        Ok(out
+            .column(name)?
+            .list()?
+            .into_iter()
+            .map(|s| Vec::from(s.unwrap().i64().unwrap()))
+            .collect())
+    };
+    assert_eq!(
+        get("union")?,
+        &[
+            vec![Some(1), Some(2), Some(3)],
+            vec![Some(3), Some(4)],
+            vec![Some(4), Some(5), Some(6)]
+        ]
+    );
+    assert_eq!(
+        get("intersection")?,
+        &[vec![Some(2)], vec![Some(3)], vec![]]
+    );
+    assert_eq!(
+        get("difference")?,
+        &[vec![Some(1)], vec![], vec![Some(4), Some(5)]]
+    );
+    Ok(())
+}
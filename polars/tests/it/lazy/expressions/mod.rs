@@ -1,10 +1,22 @@
+#[cfg(feature = "string_anonymize")]
+mod anonymize;
 mod apply;
 mod arity;
+#[cfg(feature = "dtype-array")]
+mod array;
 mod expand;
 mod filter;
 #[cfg(feature = "is_in")]
 mod is_in;
+#[cfg(feature = "list_contains_multi")]
+mod list_contains;
+#[cfg(feature = "list_eval")]
+mod list_eval;
+#[cfg(feature = "list_sets")]
+mod list_sets;
 mod slice;
+#[cfg(feature = "string_to_number")]
+mod to_number;
 mod window;
 
 use super::*;
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_str_to_number() -> PolarsResult<()> {
+    let df = df![
+        "amount" => [Some("$1,234.50"), Some(" -$12 "), None, Some("n/a")]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("amount").str().to_float(Some(","), &["$"], false)])
+        .collect()?;
+    let expected = Series::new("amount", &[Some(1234.5), Some(-12.0), None, None]);
+    assert!(out.column("amount")?.series_equal_missing(&expected));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("amount").str().to_float(Some(","), &["$"], true)])
+        .collect();
+    assert!(out.is_err());
+
+    #[cfg(feature = "dtype-decimal")]
+    {
+        let out = df
+            .lazy()
+            .select([col("amount").str().to_decimal(2, Some(","), &["$"], false)])
+            .collect()?;
+        let amount = out.column("amount")?;
+        assert_eq!(amount.dtype(), &DataType::Decimal(None, Some(2)));
+        let values = amount.decimal()?.0.into_iter().collect::<Vec<_>>();
+        assert_eq!(values, &[Some(123450), Some(-1200), None, None]);
+    }
+    Ok(())
+}
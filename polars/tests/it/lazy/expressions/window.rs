@@ -401,3 +401,178 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+fn test_window_frames() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "a", "b"],
+        "t" => [1, 2, 1, 5, 2],
+        "x" => [1, 2, 3, 4, 5]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("x")
+                .sum()
+                .over([col("g")])
+                .rows_between(-1, 0)
+                .alias("rows"),
+            col("x")
+                .sum()
+                .over([col("g")])
+                .range_between(col("t"), -2, 0)
+                .alias("range"),
+            col("x").sum().rows_between(0, 1).alias("global"),
+            col("x")
+                .max()
+                .over([col("g")])
+                .rows_between(1, 2)
+                .alias("following"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("rows")?.i32()?),
+        &[Some(1), Some(3), Some(3), Some(6), Some(8)]
+    );
+    assert_eq!(
+        Vec::from(out.column("range")?.i32()?),
+        &[Some(1), Some(3), Some(3), Some(4), Some(8)]
+    );
+    assert_eq!(
+        Vec::from(out.column("global")?.i32()?),
+        &[Some(3), Some(5), Some(7), Some(9), Some(5)]
+    );
+    // the last rows of a partition have an empty frame
+    assert_eq!(
+        Vec::from(out.column("following")?.i32()?),
+        &[Some(4), Some(4), Some(5), None, None]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_window_mapping_strategies() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["b", "a", "b", "a"],
+        "x" => [1, 2, 3, 4]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("x")
+                .over_with_options([col("g")], WindowMapping::Join)
+                .alias("join"),
+            col("x")
+                .sum()
+                .over_with_options([col("g")], WindowMapping::Join)
+                .alias("join_sum"),
+        ])
+        .collect()?;
+    let join = out.column("join")?.list()?;
+    assert_eq!(Vec::from(join.get(0).unwrap().i32()?), &[Some(1), Some(3)]);
+    assert_eq!(Vec::from(join.get(1).unwrap().i32()?), &[Some(2), Some(4)]);
+    let join_sum = out.column("join_sum")?.explode()?;
+    assert_eq!(
+        Vec::from(join_sum.i32()?),
+        &[Some(4), Some(6), Some(4), Some(6)]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").over_with_options([col("g")], WindowMapping::Explode)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(1), Some(3), Some(2), Some(4)]
+    );
+
+    // sorted utf8 keys take the run detection fast path
+    let out = df
+        .lazy()
+        .sort("g", Default::default())
+        .select([
+            col("g"),
+            col("x").max().over([col("g")]).alias("max"),
+            col("x").cumsum(false).over([col("g")]).alias("cumsum"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("max")?.i32()?),
+        &[Some(4), Some(4), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("cumsum")?.i32()?),
+        &[Some(2), Some(6), Some(1), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn test_window_reducing_aggregations() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "b", "b"],
+        "x" => [Some(1), None, Some(1), Some(2), Some(3)]
+    ]?;
+    let exprs = || {
+        [
+            col("x").n_unique().over([col("g")]).alias("n_unique"),
+            col("x").null_count().over([col("g")]).alias("null_count"),
+            col("x")
+                .quantile(lit(0.5), QuantileInterpolOptions::Linear)
+                .over([col("g")])
+                .alias("quantile"),
+            col("x")
+                .entropy(std::f64::consts::E, true)
+                .over([col("g")])
+                .alias("entropy"),
+        ]
+    };
+
+    let out = df.clone().lazy().select(exprs()).collect()?;
+    assert_eq!(
+        Vec::from(out.column("n_unique")?.idx()?),
+        &[Some(1), Some(3), Some(1), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("null_count")?.idx()?),
+        &[Some(0), Some(1), Some(0), Some(1), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("quantile")?.f64()?),
+        &[Some(1.0), Some(2.5), Some(1.0), Some(2.5), Some(2.5)]
+    );
+    let entropy = out.column("entropy")?.f64()?;
+    let (a, b) = (
+        std::f64::consts::LN_2,
+        -(0.4f64.ln() * 0.4 + 0.6f64.ln() * 0.6),
+    );
+    for (value, expected) in entropy.into_no_null_iter().zip([a, b, a, b, b]) {
+        assert!((value - expected).abs() < 1e-9);
+    }
+
+    // sorted keys take a different path
+    let out = df
+        .lazy()
+        .sort("g", Default::default())
+        .select(exprs())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("n_unique")?.idx()?),
+        &[Some(1), Some(1), Some(3), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("null_count")?.idx()?),
+        &[Some(0), Some(0), Some(1), Some(1), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("quantile")?.f64()?),
+        &[Some(1.0), Some(1.0), Some(2.5), Some(2.5), Some(2.5)]
+    );
+    Ok(())
+}
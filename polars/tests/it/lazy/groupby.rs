@@ -1,5 +1,6 @@
 #[cfg(feature = "rank")]
 use polars_core::series::ops::NullBehavior;
+use polars_core::series::IsSorted;
 // used only if feature="dtype-duration", "dtype-struct"
 #[allow(unused_imports)]
 use polars_core::SINGLE_LOCK;
@@ -177,3 +178,235 @@ fn test_filter_aggregated_expression() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_groupby_rollup_cube() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y"],
+        "b" => [1, 2, 1],
+        "v" => [1, 2, 3]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_rollup([col("a"), col("b")], [col("v").sum()])?
+        .sort_by_exprs([col(GROUPING_ID), col("a"), col("b")], [false; 3], false)
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", GROUPING_ID, "v"]);
+    assert_eq!(
+        Vec::from(out.column(GROUPING_ID)?.u32()?),
+        &[Some(0), Some(0), Some(0), Some(1), Some(1), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(3), Some(3), Some(6)]
+    );
+
+    // these aggregations are rolled up from the groups of the finest set
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_rollup(
+            [col("a")],
+            [col("v").mean().alias("mean"), col("v").min(), count()],
+        )?
+        .sort_by_exprs([col(GROUPING_ID), col("a")], [false; 2], false)
+        .collect()?;
+    let expected = df![
+        "a" => [Some("x"), Some("y"), None],
+        GROUPING_ID => [0u32, 0, 1],
+        "mean" => [1.5, 3.0, 2.0],
+        "v" => [1, 3, 1],
+        "count" => [2 as IdxSize, 1, 3],
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+
+    let out = df
+        .lazy()
+        .groupby_cube([col("a"), col("b")], [col("v").sum()])?
+        .collect()?;
+    // 3 (a, b) + 2 (a) + 2 (b) + 1 ()
+    assert_eq!(out.height(), 8);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_groupby_agg_struct_unnest() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y"],
+        "v" => [1, 2, 3]
+    ]?;
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("a")])
+        .agg([
+            as_struct(&[col("v").min().alias("min"), col("v").max().alias("max")])
+                .alias("v")
+                .struct_()
+                .unnest(),
+        ])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "v_min", "v_max"]);
+    assert_eq!(Vec::from(out.column("v_min")?.i32()?), &[Some(1), Some(3)]);
+    assert_eq!(Vec::from(out.column("v_max")?.i32()?), &[Some(2), Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_groupby_agg_list_bulk_gather() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 2, 1, 2, 3],
+        "s" => [Some("a"), Some("b"), None, Some("d"), Some("e")],
+        "b" => [Some(true), None, Some(false), Some(true), Some(false)],
+    ]?;
+
+    // Idx groups
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("k")])
+        .agg([col("s"), col("b")])
+        .collect()?;
+    let s = out.column("s")?.explode()?;
+    assert_eq!(
+        Vec::from(s.utf8()?),
+        &[Some("a"), None, Some("b"), Some("d"), Some("e")]
+    );
+    let b = out.column("b")?.explode()?;
+    assert_eq!(
+        Vec::from(b.bool()?),
+        &[Some(true), Some(false), None, Some(true), Some(false)]
+    );
+
+    // Slice groups of a sorted key
+    let out = df
+        .lazy()
+        .sort("k", Default::default())
+        .with_column(col("k").set_sorted_flag(IsSorted::Ascending))
+        .groupby_stable([col("k")])
+        .agg([col("s")])
+        .collect()?;
+    let s = out.column("s")?;
+    let lengths = s
+        .list()?
+        .into_iter()
+        .map(|s| s.unwrap().len())
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, &[2, 2, 1]);
+    assert_eq!(
+        Vec::from(s.explode()?.utf8()?),
+        &[Some("a"), None, Some("b"), Some("d"), Some("e")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_groupby_first_last_only() -> PolarsResult<()> {
+    let df = df![
+        "k1" => [1, 2, 1, 2, 1, 3],
+        "k2" => ["a", "b", "a", "b", "c", "a"],
+        "v" => [Some(1), None, Some(3), Some(4), Some(5), Some(6)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("k1"), col("k2")])
+        .agg([
+            col("v").first().alias("first"),
+            col("v").last().alias("last"),
+        ])
+        .collect()?;
+    // The count needs all indices of a group, so this runs the default path.
+    let expected = df
+        .lazy()
+        .groupby_stable([col("k1"), col("k2")])
+        .agg([
+            col("v").first().alias("first"),
+            col("v").last().alias("last"),
+            col("v").count().alias("count"),
+        ])
+        .select([col("k1"), col("k2"), col("first"), col("last")])
+        .collect()?;
+    assert!(out.frame_equal_missing(&expected));
+    assert_eq!(
+        Vec::from(out.column("first")?.i32()?),
+        &[Some(1), None, Some(5), Some(6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("last")?.i32()?),
+        &[Some(3), Some(4), Some(5), Some(6)]
+    );
+
+    // a single key is hashed by its values
+    let df = df![
+        "k" => [Some(2), None, Some(2), Some(1), None],
+        "s" => ["b", "a", "b", "c", "a"],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+    for key in ["k", "s"] {
+        let out = df
+            .clone()
+            .lazy()
+            .groupby_stable([col(key)])
+            .agg([col("v").first(), col("v").last().alias("last")])
+            .slice(1, 2)
+            .collect()?;
+        assert_eq!(out.get_column_names(), &[key, "v", "last"]);
+        assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(2), Some(4)]);
+        assert_eq!(Vec::from(out.column("last")?.i32()?), &[Some(5), Some(4)]);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_groupby_approx_n_unique() -> PolarsResult<()> {
+    let n = 30_000;
+    let df = df![
+        "k" => (0..n).map(|i| i % 3).collect::<Vec<_>>(),
+        "v" => (0..n).map(|i| i % (1000 * (1 + i % 3))).collect::<Vec<_>>(),
+    ]?;
+
+    let check = |out: DataFrame| -> PolarsResult<()> {
+        let exact = out.column("exact")?.cast(&IDX_DTYPE)?;
+        let approx = out.column("approx")?;
+        for (exact, approx) in exact
+            .idx()?
+            .into_no_null_iter()
+            .zip(approx.idx()?.into_no_null_iter())
+        {
+            let error = (exact as f64 - approx as f64).abs() / exact as f64;
+            assert!(error < 0.05, "exact: {exact}, approx: {approx}");
+        }
+        Ok(())
+    };
+    let aggs = [
+        col("v").n_unique().alias("exact"),
+        col("v").approx_n_unique().alias("approx"),
+    ];
+
+    // partitioned
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("k")])
+        .agg(aggs.clone())
+        .collect()?;
+    check(out)?;
+
+    // not partitionable
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("k") * lit(1)])
+        .agg(aggs.clone())
+        .collect()?;
+    check(out)?;
+
+    let out = df.lazy().select(aggs).collect()?;
+    check(out)?;
+    Ok(())
+}
@@ -221,3 +221,65 @@ fn test_predicate_on_join_select_4884() -> PolarsResult<()> {
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy_regex")]
+fn test_filter_conjunctions_reordered() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+        "s" => ["foo", "bar", "fooba", "baz", "foo"],
+    ]?;
+
+    for pushdown in [true, false] {
+        let out = df
+            .clone()
+            .lazy()
+            .with_predicate_pushdown(pushdown)
+            .filter(
+                col("s")
+                    .str()
+                    .contains(lit("^fo+"), false)
+                    .and(col("a").gt(lit(1)))
+                    .and(col("s").is_not_null()),
+            )
+            .collect()?;
+        let expected = df![
+            "a" => [3, 5],
+            "s" => ["fooba", "foo"],
+        ]?;
+        assert!(out.frame_equal(&expected));
+
+        // No rows are left after the first predicate.
+        let out = df
+            .clone()
+            .lazy()
+            .with_predicate_pushdown(pushdown)
+            .filter(
+                col("s")
+                    .str()
+                    .contains(lit("^fo+"), false)
+                    .and(col("a").gt(lit(10))),
+            )
+            .collect()?;
+        assert_eq!(out.height(), 0);
+        assert_eq!(out.get_column_names(), &["a", "s"]);
+
+        // Depends on the other rows, so the predicate is evaluated at once.
+        let out = df
+            .clone()
+            .lazy()
+            .with_predicate_pushdown(pushdown)
+            .filter(
+                col("a")
+                    .gt(col("a").mean())
+                    .and(col("s").str().contains(lit("^fo+"), false)),
+            )
+            .collect()?;
+        let expected = df![
+            "a" => [5],
+            "s" => ["foo"],
+        ]?;
+        assert!(out.frame_equal(&expected));
+    }
+    Ok(())
+}
@@ -270,3 +270,236 @@ fn test_groupby_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_validate() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 5, 10, 20],
+        "lower" => [0, 6, 0, 0],
+        "b" => [Some("x"), None, None, Some("y")]
+    ]?;
+
+    let out = df.lazy().validate(
+        [
+            col("a")
+                .is_between(col("lower"), lit(10))
+                .alias("a_in_range"),
+            col("b").is_not_null().alias("b_present"),
+            col("a")
+                .is_in_ranges([(lit(0), lit(5)), (lit(15), lit(25))])
+                .alias("a_in_ranges"),
+        ],
+        1,
+    )?;
+
+    assert_eq!(
+        Vec::from(out.column("constraint")?.utf8()?),
+        &[Some("a_in_range"), Some("b_present"), Some("a_in_ranges")]
+    );
+    assert_eq!(
+        out.column("violations")?
+            .cast(&DataType::UInt32)?
+            .u32()?
+            .to_vec(),
+        &[Some(2), Some(2), Some(1)]
+    );
+    let example_rows = out.column("example_rows")?.explode()?;
+    assert_eq!(
+        example_rows.cast(&DataType::UInt32)?.u32()?.to_vec(),
+        &[Some(1), Some(1), Some(2)]
+    );
+    let examples = out.column("examples")?.explode()?;
+    assert_eq!(
+        Vec::from(examples.utf8()?),
+        &[Some("a=5, lower=6"), Some("b=null"), Some("a=10")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_with_context() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3]
+    ]?;
+    let reference = df![
+        "b" => [10, 20]
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_context([reference.lazy()])
+        .select([(col("a") + col("b").sum()).alias("c")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("c")?.i32()?),
+        &[Some(31), Some(32), Some(33)]
+    );
+
+    // in a groupby the context columns are aligned by index or broadcasted
+    let df = df![
+        "g" => ["a", "b", "a"],
+        "a" => [1, 2, 3]
+    ]?;
+    let weights = df![
+        "w" => [1, 10, 100]
+    ]?;
+    let offset = df![
+        "o" => [1000]
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .with_context([weights.lazy(), offset.clone().lazy()])
+        .groupby_stable([col("g")])
+        .agg([
+            (col("a") * col("w")).sum().alias("weighted"),
+            (col("a") + col("o")).max().alias("offset"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("weighted")?.i32()?),
+        &[Some(301), Some(20)]
+    );
+    assert_eq!(
+        Vec::from(out.column("offset")?.i32()?),
+        &[Some(1003), Some(1002)]
+    );
+
+    let out = df
+        .lazy()
+        .with_context([df!["w" => [1, 2]]?.lazy()])
+        .groupby([col("g")])
+        .agg([(col("a") * col("w")).sum()])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_with_options() -> PolarsResult<()> {
+    let inner = StructChunked::new(
+        "b",
+        &[Series::new("c", &[3, 4]), Series::new("d", &["x", "y"])],
+    )?
+    .into_series();
+    let s = StructChunked::new("s", &[Series::new("a", &[1, 2]), inner])?.into_series();
+    let df = DataFrame::new(vec![Series::new("id", &[0, 1]), s])?;
+
+    let options = UnnestOptions {
+        separator: Some(".".into()),
+        max_depth: usize::MAX,
+        ..Default::default()
+    };
+    let lf = df.clone().lazy().unnest_with_options(["s"], options);
+    let expected = ["id", "s.a", "s.b.c", "s.b.d"];
+    assert_eq!(
+        lf.schema()?
+            .iter_names()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>(),
+        expected
+    );
+    let out = lf.collect()?;
+    assert_eq!(out.get_column_names(), expected);
+    assert_eq!(Vec::from(out.column("s.b.c")?.i32()?), &[Some(3), Some(4)]);
+
+    // only the selected field, and nested structs are kept by default
+    let options = UnnestOptions {
+        fields: Some(vec!["b".into()]),
+        ..Default::default()
+    };
+    let out = df.lazy().unnest_with_options(["s"], options).collect()?;
+    assert_eq!(out.get_column_names(), &["id", "b"]);
+    assert!(matches!(out.column("b")?.dtype(), DataType::Struct(_)));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_json_normalize() -> PolarsResult<()> {
+    let order = |items: &[&str], amounts: &[i32]| -> PolarsResult<Series> {
+        let price = StructChunked::new("price", &[Series::new("amount", amounts)])?;
+        let s = StructChunked::new("", &[Series::new("item", items), price.into_series()])?;
+        Ok(s.into_series())
+    };
+    let orders = Series::new(
+        "orders",
+        &[order(&["a", "b"], &[1, 2])?, order(&["c"], &[3])?],
+    );
+    let df = DataFrame::new(vec![
+        Series::new("id", &[0, 1]),
+        Series::new("other", &[true, false]),
+        orders,
+    ])?;
+
+    let options = JsonNormalizeOptions {
+        meta: Some(vec!["id".into()]),
+        ..Default::default()
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .json_normalize(["orders"], options)?
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["id", "orders.item", "orders.price.amount"]
+    );
+    assert_eq!(
+        Vec::from(out.column("id")?.i32()?),
+        &[Some(0), Some(0), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("orders.price.amount")?.i32()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+
+    // only flatten the records, not the nested price struct
+    let options = JsonNormalizeOptions {
+        max_depth: 1,
+        ..Default::default()
+    };
+    let out = df.lazy().json_normalize(["orders"], options)?.collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["id", "other", "orders.item", "orders.price"]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_struct_with_fields_and_field_wildcard() -> PolarsResult<()> {
+    let s = StructChunked::new(
+        "s",
+        &[
+            Series::new("a", &[1, 2]),
+            Series::new("b", &["x", "y"]),
+            Series::new("ab", &[3, 4]),
+        ],
+    )?
+    .into_series();
+    let df = DataFrame::new(vec![Series::new("id", &[0, 1]), s])?;
+
+    let lf = df.lazy().with_column(col("s").struct_().with_fields([
+        (col("s").struct_().field_by_name("a") * lit(2)).alias("a"),
+        col("id").alias("c"),
+    ]));
+    let out = lf
+        .clone()
+        .select([col("s").struct_().field_by_name("*")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", "ab", "c"]);
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(2), Some(4)]);
+    assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(0), Some(1)]);
+
+    #[cfg(feature = "lazy_regex")]
+    {
+        let out = lf
+            .select([col("s").struct_().field_by_name("^a.*$")])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["a", "ab"]);
+    }
+    Ok(())
+}
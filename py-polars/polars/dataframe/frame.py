@@ -2418,9 +2418,7 @@ class DataFrame:
         >>> df.write_csv(path, separator=",")
 
         """
-        if len(separator) > 1:
-            raise ValueError("only single byte separator is allowed")
-        elif len(quote) > 1:
+        if len(quote) > 1:
             raise ValueError("only single byte quote char is allowed")
         elif null_value == "":
             null_value = None
@@ -2430,7 +2428,7 @@ class DataFrame:
             self._df.write_csv(
                 buffer,
                 has_header,
-                ord(separator),
+                separator,
                 ord(quote),
                 batch_size,
                 datetime_format,
@@ -2447,7 +2445,7 @@ class DataFrame:
         self._df.write_csv(
             file,
             has_header,
-            ord(separator),
+            separator,
             ord(quote),
             batch_size,
             datetime_format,
@@ -5079,6 +5077,9 @@ class DataFrame:
           - A "forward" search selects the first row in the right DataFrame whose
             'on' key is greater than or equal to the left's key.
 
+          - A "nearest" search selects the row in the right DataFrame whose
+            'on' key is nearest to the left's key.
+
         The default is "backward".
 
         Parameters
@@ -5098,7 +5099,7 @@ class DataFrame:
             join on these columns before doing asof join
         by_right
             join on these columns before doing asof join
-        strategy : {'backward', 'forward'}
+        strategy : {'backward', 'forward', 'nearest'}
             Join strategy.
         suffix
             Suffix to append to columns with a duplicate name.
@@ -1078,19 +1078,21 @@ class Expr:
 
     def count(self) -> Self:
         """
-        Count the number of values in this expression.
+        Count the number of non-null values in this expression.
+
+        Use :func:`len` to count the null values as well.
 
         Examples
         --------
         >>> df = pl.DataFrame({"a": [8, 9, 10], "b": [None, 4, 4]})
-        >>> df.select(pl.all().count())  # counts nulls
+        >>> df.select(pl.all().count())  # does not count nulls
         shape: (1, 2)
         ┌─────┬─────┐
         │ a   ┆ b   │
         │ --- ┆ --- │
         │ u32 ┆ u32 │
         ╞═════╪═════╡
-        │ 3   ┆ 3   │
+        │ 3   ┆ 2   │
         └─────┴─────┘
 
         """
@@ -1098,9 +1100,9 @@ class Expr:
 
     def len(self) -> Self:
         """
-        Count the number of values in this expression.
+        Count the number of values in this expression, including the null values.
 
-        Alias for :func:`count`.
+        Use :func:`count` to count only the non-null values.
 
         Examples
         --------
@@ -1121,7 +1123,7 @@ class Expr:
         └─────┴─────┘
 
         """
-        return self.count()
+        return self._from_pyexpr(self._pyexpr.len())
 
     def slice(self, offset: int | Expr, length: int | Expr | None = None) -> Self:
         """
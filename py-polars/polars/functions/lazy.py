@@ -327,7 +327,7 @@ def count(column: str | Series | None = None) -> Expr | int:
         If dtype is:
 
         * ``pl.Series`` : count the values in the series.
-        * ``str`` : count the values in this column.
+        * ``str`` : count the non-null values in this column.
         * ``None`` : count the number of values in this context.
 
     Examples
@@ -88,7 +88,7 @@ def read_csv(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as delimiter in the file.
+        Character(s) to use as delimiter in the file, at most 4 bytes, e.g. ``"||"``.
     comment_char
         Single byte character that indicates the start of a comment line,
         for instance ``#``.
@@ -181,7 +181,6 @@ def read_csv(
     scan_csv : Lazily read from a CSV file or multiple files via glob patterns.
 
     """
-    _check_arg_is_1byte("separator", separator, False)
     _check_arg_is_1byte("comment_char", comment_char, False)
     _check_arg_is_1byte("quote_char", quote_char, True)
     _check_arg_is_1byte("eol_char", eol_char, False)
@@ -441,7 +440,7 @@ def read_csv_batched(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as delimiter in the file.
+        Character(s) to use as delimiter in the file, at most 4 bytes, e.g. ``"||"``.
     comment_char
         Single byte character that indicates the start of a comment line,
         for instance ``#``.
@@ -721,7 +720,7 @@ def scan_csv(
         following format: ``column_x``, with ``x`` being an
         enumeration over every column in the dataset starting at 1.
     separator
-        Single byte character to use as delimiter in the file.
+        Character(s) to use as delimiter in the file, at most 4 bytes, e.g. ``"||"``.
     comment_char
         Single byte character that indicates the start of a comment line,
         for instance ``#``.
@@ -866,7 +865,6 @@ def scan_csv(
         def with_column_names(_cols: list[str]) -> list[str]:
             return new_columns  # type: ignore[return-value]
 
-    _check_arg_is_1byte("separator", separator, False)
     _check_arg_is_1byte("comment_char", comment_char, False)
     _check_arg_is_1byte("quote_char", quote_char, True)
 
@@ -91,13 +91,14 @@ impl PyBatchedCsv {
                 .collect::<Vec<_>>()
         });
 
+        let delimiter = CsvDelimiter::try_from(separator).map_err(PyPolarsErr::from)?;
         let file = std::fs::File::open(path).map_err(PyPolarsErr::from)?;
         let reader = Box::new(file) as Box<dyn MmapBytesReader>;
         let reader = CsvReader::new(reader)
             .infer_schema(infer_schema_length)
             .has_header(has_header)
             .with_n_rows(n_rows)
-            .with_delimiter(separator.as_bytes()[0])
+            .with_delimiter(delimiter)
             .with_skip_rows(skip_rows)
             .with_ignore_errors(ignore_errors)
             .with_projection(projection)
@@ -200,12 +200,13 @@ impl PyDataFrame {
                 .collect::<Vec<_>>()
         });
 
+        let delimiter = CsvDelimiter::try_from(separator).map_err(PyPolarsErr::from)?;
         let mmap_bytes_r = get_mmap_bytes_reader(py_f)?;
         let df = CsvReader::new(mmap_bytes_r)
             .infer_schema(infer_schema_length)
             .has_header(has_header)
             .with_n_rows(n_rows)
-            .with_delimiter(separator.as_bytes()[0])
+            .with_delimiter(delimiter)
             .with_skip_rows(skip_rows)
             .with_ignore_errors(ignore_errors)
             .with_projection(projection)
@@ -515,7 +516,7 @@ impl PyDataFrame {
         py: Python,
         py_f: PyObject,
         has_header: bool,
-        separator: u8,
+        separator: &str,
         quote: u8,
         batch_size: usize,
         datetime_format: Option<String>,
@@ -525,6 +526,7 @@ impl PyDataFrame {
         null_value: Option<String>,
     ) -> PyResult<()> {
         let null = null_value.unwrap_or_default();
+        let delimiter = CsvDelimiter::try_from(separator).map_err(PyPolarsErr::from)?;
 
         if let Ok(s) = py_f.extract::<&str>(py) {
             py.allow_threads(|| {
@@ -532,7 +534,7 @@ impl PyDataFrame {
                 // no need for a buffered writer, because the csv writer does internal buffering
                 CsvWriter::new(f)
                     .has_header(has_header)
-                    .with_delimiter(separator)
+                    .with_delimiter(delimiter)
                     .with_quoting_char(quote)
                     .with_batch_size(batch_size)
                     .with_datetime_format(datetime_format)
@@ -547,7 +549,7 @@ impl PyDataFrame {
             let mut buf = get_file_like(py_f, true)?;
             CsvWriter::new(&mut buf)
                 .has_header(has_header)
-                .with_delimiter(separator)
+                .with_delimiter(delimiter)
                 .with_quoting_char(quote)
                 .with_batch_size(batch_size)
                 .with_datetime_format(datetime_format)
@@ -656,32 +658,32 @@ impl PyDataFrame {
         match st {
             DataType::UInt32 => self
                 .df
-                .to_ndarray::<UInt32Type>()
+                .to_ndarray::<UInt32Type>(IndexOrder::Fortran)
                 .ok()
                 .map(|arr| arr.into_pyarray(py).into_py(py)),
             DataType::UInt64 => self
                 .df
-                .to_ndarray::<UInt64Type>()
+                .to_ndarray::<UInt64Type>(IndexOrder::Fortran)
                 .ok()
                 .map(|arr| arr.into_pyarray(py).into_py(py)),
             DataType::Int32 => self
                 .df
-                .to_ndarray::<Int32Type>()
+                .to_ndarray::<Int32Type>(IndexOrder::Fortran)
                 .ok()
                 .map(|arr| arr.into_pyarray(py).into_py(py)),
             DataType::Int64 => self
                 .df
-                .to_ndarray::<Int64Type>()
+                .to_ndarray::<Int64Type>(IndexOrder::Fortran)
                 .ok()
                 .map(|arr| arr.into_pyarray(py).into_py(py)),
             DataType::Float32 => self
                 .df
-                .to_ndarray::<Float32Type>()
+                .to_ndarray::<Float32Type>(IndexOrder::Fortran)
                 .ok()
                 .map(|arr| arr.into_pyarray(py).into_py(py)),
             DataType::Float64 => self
                 .df
-                .to_ndarray::<Float64Type>()
+                .to_ndarray::<Float64Type>(IndexOrder::Fortran)
                 .ok()
                 .map(|arr| arr.into_pyarray(py).into_py(py)),
             _ => None,
@@ -206,6 +206,9 @@ impl PyExpr {
     pub fn count(&self) -> PyExpr {
         self.clone().inner.count().into()
     }
+    pub fn len(&self) -> PyExpr {
+        self.clone().inner.len().into()
+    }
     pub fn value_counts(&self, multithreaded: bool, sorted: bool) -> PyExpr {
         self.inner
             .clone()
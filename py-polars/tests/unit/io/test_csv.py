@@ -699,6 +699,18 @@ def test_write_csv_delimiter() -> None:
     assert f.read() == b"a\tb\n1\t1\n2\t2\n3\t3\n"
 
 
+def test_multi_byte_separator() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x|y", "p||q"]})
+    f = io.BytesIO()
+    df.write_csv(f, separator="||")
+    f.seek(0)
+    assert f.read() == b'a||b\n1||x|y\n2||"p||q"\n'
+    f.seek(0)
+    assert_frame_equal(pl.read_csv(f, separator="||"), df)
+    with pytest.raises(ComputeError):
+        pl.read_csv(f, separator="|||||")
+
+
 def test_escaped_null_values() -> None:
     csv = textwrap.dedent(
         """\
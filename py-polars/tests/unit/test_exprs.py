@@ -154,6 +154,19 @@ def test_count_expr() -> None:
     assert out["count"].to_list() == [4, 1]
 
 
+def test_count_len_nulls() -> None:
+    df = pl.DataFrame({"a": [1, None, 3, None], "b": ["x", "x", "y", "y"]})
+
+    out = df.select(pl.col("a").count().alias("count"), pl.col("a").len().alias("len"))
+    assert out.to_dict(False) == {"count": [2], "len": [4]}
+    assert df.select(pl.count("a")).item() == 2
+
+    out = df.groupby("b", maintain_order=True).agg(
+        pl.col("a").count().alias("count"), pl.col("a").len().alias("len")
+    )
+    assert out.to_dict(False) == {"b": ["x", "y"], "count": [1, 1], "len": [2, 2]}
+
+
 def test_shuffle() -> None:
     # setting 'random.seed' should lead to reproducible results
     s = pl.Series("a", range(20))
@@ -245,6 +245,21 @@ def test_ooc_sort(monkeypatch: Any) -> None:
         assert_series_equal(out, s.sort(descending=descending))
 
 
+@pytest.mark.slow()
+def test_ooc_join(monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_FORCE_OOC_JOIN", "1")
+
+    idx = pl.arange(0, 10_000, eager=True)
+    left = pl.DataFrame({"a": idx % 1000, "b": idx})
+    right = pl.DataFrame({"a": idx[:2000], "c": idx[:2000] * 2})
+
+    for how in ["inner", "left"]:
+        q = left.lazy().join(right.lazy(), on="a", how=how)  # type: ignore[arg-type]
+        # the order of the rows is not maintained once the join spills
+        out = q.collect(streaming=True).sort("b")
+        assert_frame_equal(out, q.collect(streaming=False).sort("b"))
+
+
 def test_streaming_literal_expansion() -> None:
     df = pl.DataFrame(
         {